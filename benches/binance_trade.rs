@@ -0,0 +1,142 @@
+//! Benchmarks the Binance trade hot path: deserialising a raw trade payload and transforming it
+//! into a normalised [`MarketEvent<PublicTrade>`](barter_data::event::MarketEvent) via the
+//! [`StatelessTransformer`], for a connection subscribed to many markets.
+//!
+//! Note: [`MarketEvent`](barter_data::event::MarketEvent) owns its
+//! [`Instrument`], so one [`Instrument`] clone per event is inherent to the output.
+
+use barter_data::{
+    exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+    subscription::{trade::PublicTrades, Map},
+    transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+};
+use barter_integration::{
+    model::{Instrument, InstrumentKind, SubscriptionId},
+    Transformer,
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use tokio::sync::mpsc;
+
+/// [`System`] allocator that counts every allocation, used to report the number of allocations
+/// per message alongside the timings, since they are far less noisy.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Count the allocations made by the provided closure.
+fn allocations(f: impl FnOnce()) -> usize {
+    let start = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - start
+}
+
+/// Number of distinct markets subscribed to on the benchmarked connection.
+const MARKETS: usize = 500;
+
+type BinanceTradeTransformer = StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade>;
+
+fn payloads() -> Vec<String> {
+    (0..MARKETS)
+        .map(|market| {
+            format!(
+                r#"{{"e":"trade","E":1649324825173,"s":"COIN{market}USDT","t":{market},"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1649324825173,"m":false,"M":true}}"#
+            )
+        })
+        .collect()
+}
+
+fn transformer() -> BinanceTradeTransformer {
+    let instrument_map = (0..MARKETS)
+        .map(|market| {
+            (
+                SubscriptionId::from(format!("@trade|COIN{market}USDT")),
+                Instrument::from((
+                    format!("coin{market}"),
+                    "usdt".to_string(),
+                    InstrumentKind::Spot,
+                )),
+            )
+        })
+        .collect::<Map<Instrument>>();
+
+    let (ws_sink_tx, _) = mpsc::unbounded_channel();
+    futures::executor::block_on(<BinanceTradeTransformer as ExchangeTransformer<
+        BinanceSpot,
+        PublicTrades,
+    >>::new(ws_sink_tx, instrument_map))
+    .unwrap()
+}
+
+fn bench_binance_trade(c: &mut Criterion) {
+    let payloads = payloads();
+    let mut group = c.benchmark_group("binance_trade");
+    group.throughput(Throughput::Elements(payloads.len() as u64));
+
+    group.bench_function("deserialise", |b| {
+        b.iter(|| {
+            for payload in &payloads {
+                criterion::black_box(serde_json::from_str::<BinanceTrade>(payload).unwrap());
+            }
+        })
+    });
+
+    let mut transformer = transformer();
+
+    // Report the steady state allocations per message (ie/ excluding first sequence inserts)
+    for payload in &payloads {
+        transformer.transform(serde_json::from_str::<BinanceTrade>(payload).unwrap());
+    }
+    let deserialise = allocations(|| {
+        for payload in &payloads {
+            criterion::black_box(serde_json::from_str::<BinanceTrade>(payload).unwrap());
+        }
+    });
+    let transform = allocations(|| {
+        for payload in &payloads {
+            let trade = serde_json::from_str::<BinanceTrade>(payload).unwrap();
+            criterion::black_box(transformer.transform(trade));
+        }
+    });
+    println!(
+        "binance_trade allocations per message: deserialise {:.2}, deserialise_transform {:.2}",
+        deserialise as f64 / MARKETS as f64,
+        transform as f64 / MARKETS as f64,
+    );
+
+    group.bench_function("deserialise_transform", |b| {
+        b.iter(|| {
+            for payload in &payloads {
+                let trade = serde_json::from_str::<BinanceTrade>(payload).unwrap();
+                criterion::black_box(transformer.transform(trade));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_binance_trade);
+criterion_main!(benches);
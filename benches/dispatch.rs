@@ -0,0 +1,143 @@
+//! Benchmarks dispatching a synthetic Binance trade firehose to a consumer: via the default
+//! unbounded [`EventSender`] channel to a consumer task, versus direct dispatch to an
+//! [`EventHandler`](barter_data::streams::handler::EventHandler) called inline, as used by
+//! [`StreamBuilder::init_with_handler`](barter_data::streams::builder::StreamBuilder::init_with_handler).
+//!
+//! Each iteration deserialises & transforms every payload before dispatching the normalised
+//! [`MarketEvent<PublicTrade>`](MarketEvent), and completes once the consumer has received every
+//! event.
+
+use barter_data::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+    streams::{
+        channel::{ChannelCapacity, EventSender},
+        handler::HandlerSender,
+    },
+    subscription::{
+        trade::{PublicTrade, PublicTrades},
+        Map,
+    },
+    transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+};
+use barter_integration::{
+    model::{Instrument, InstrumentKind, SubscriptionId},
+    Transformer,
+};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokio::sync::mpsc;
+
+/// Number of distinct markets subscribed to on the benchmarked connection.
+const MARKETS: usize = 500;
+
+type BinanceTradeTransformer = StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade>;
+
+fn payloads() -> Vec<String> {
+    (0..MARKETS)
+        .map(|market| {
+            format!(
+                r#"{{"e":"trade","E":1649324825173,"s":"COIN{market}USDT","t":{market},"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1649324825173,"m":false,"M":true}}"#
+            )
+        })
+        .collect()
+}
+
+fn transformer() -> BinanceTradeTransformer {
+    let instrument_map = (0..MARKETS)
+        .map(|market| {
+            (
+                SubscriptionId::from(format!("@trade|COIN{market}USDT")),
+                Instrument::from((
+                    format!("coin{market}"),
+                    "usdt".to_string(),
+                    InstrumentKind::Spot,
+                )),
+            )
+        })
+        .collect::<Map<Instrument>>();
+
+    let (ws_sink_tx, _) = mpsc::unbounded_channel();
+    futures::executor::block_on(<BinanceTradeTransformer as ExchangeTransformer<
+        BinanceSpot,
+        PublicTrades,
+    >>::new(ws_sink_tx, instrument_map))
+    .unwrap()
+}
+
+/// Deserialise, transform & dispatch every payload via the provided [`EventSender`].
+async fn dispatch(
+    transformer: &mut BinanceTradeTransformer,
+    payloads: &[String],
+    exchange_tx: &EventSender<MarketEvent<PublicTrade>>,
+) {
+    for payload in payloads {
+        let trade = serde_json::from_str::<BinanceTrade>(payload).unwrap();
+        for event in transformer.transform(trade) {
+            match event {
+                Ok(event) => exchange_tx.send(event).await.unwrap(),
+                Err(error) => exchange_tx.send_error(error),
+            }
+        }
+    }
+}
+
+fn bench_dispatch(c: &mut Criterion) {
+    let payloads = payloads();
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(2)
+        .enable_all()
+        .build()
+        .unwrap();
+
+    let mut group = c.benchmark_group("dispatch");
+    group.throughput(Throughput::Elements(payloads.len() as u64));
+
+    let mut transformer = transformer();
+    group.bench_function("channel", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                let (exchange_tx, mut exchange_rx) = ChannelCapacity::Unbounded.channel();
+                let consumer = tokio::spawn(async move {
+                    let mut received = 0;
+                    while let Some(event) = exchange_rx.recv().await {
+                        criterion::black_box(event);
+                        received += 1;
+                    }
+                    received
+                });
+
+                dispatch(&mut transformer, &payloads, &exchange_tx).await;
+                drop(exchange_tx);
+                assert_eq!(consumer.await.unwrap(), MARKETS);
+            })
+        })
+    });
+
+    let received = Arc::new(AtomicUsize::new(0));
+    let exchange_tx = EventSender::Handler(HandlerSender::new({
+        let received = Arc::clone(&received);
+        move |event: Result<MarketEvent<PublicTrade>, DataError>| {
+            criterion::black_box(event.unwrap());
+            received.fetch_add(1, Ordering::Relaxed);
+        }
+    }));
+    group.bench_function("handler", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                received.store(0, Ordering::Relaxed);
+                dispatch(&mut transformer, &payloads, &exchange_tx).await;
+                assert_eq!(received.load(Ordering::Relaxed), MARKETS);
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_dispatch);
+criterion_main!(benches);
@@ -1,10 +1,10 @@
-use barter_data::subscription::Interval;
 use barter_data::{
-    exchange::{binance::spot::BinanceSpot, ExchangeId},
+    exchange::binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
     streams::Streams,
-    subscription::candle::Candles,
+    subscription::{candle::Candles, Interval},
 };
 use barter_integration::model::InstrumentKind;
+use futures::StreamExt;
 use tracing::info;
 
 #[rustfmt::skip]
@@ -13,27 +13,27 @@ async fn main() {
     // Initialise INFO Tracing log subscriber
     init_logging();
 
-    // Initialise PublicTrades Streams for BinanceFuturesUsd only
+    // Initialise Candles Streams for BinanceSpot & BinanceFuturesUsd
     // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
-    let mut streams = Streams::<Candles>::builder()
-        // Separate WebSocket connection for BTC_USDT stream since it's very high volume
+    let streams = Streams::<Candles>::builder()
         .subscribe([
             (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
         ])
+        .subscribe([
+            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, Candles(Interval::Minute1)),
+        ])
         .init()
         .await
         .unwrap();
 
-    // Select the ExchangeId::BinanceFuturesUsd stream
+    // Join all exchange Candles streams into a single tokio_stream::StreamMap
     // Notes:
     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
-    let mut binance_stream = streams
-        .select(ExchangeId::BinanceSpot)
-        .unwrap();
+    //  - Use `streams.join()` to join all exchange streams into a single EventReceiver!
+    let mut joined_stream = streams.join_map().await;
 
-    while let Some(trade) = binance_stream.recv().await {
-        info!("MarketEvent<PublicTrade>: {trade:?}");
+    while let Some((exchange, candle)) = joined_stream.next().await {
+        info!("Exchange: {exchange}, MarketEvent<Candle>: {candle:?}");
     }
 }
 
@@ -0,0 +1,64 @@
+use barter_data::{
+    exchange::{binance::spot::BinanceSpot, gateio::spot::GateioSpot, kraken::Kraken, okx::Okx},
+    streams::Streams,
+    subscription::{candle::Candles, Interval},
+};
+use barter_integration::model::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise Candles Streams for various exchanges
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    // '--> every exchange must implement StreamSelector<Candles>, else it fails to compile
+    let streams = Streams::<Candles>::builder()
+        .subscribe([
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
+        ])
+        .subscribe([
+            (GateioSpot::default(), "btc", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
+        ])
+        .subscribe([
+            (Kraken, "xbt", "usd", InstrumentKind::Spot, Candles(Interval::Minute1)),
+        ])
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
+            (Okx, "btc", "usdt", InstrumentKind::FuturePerpetual, Candles(Interval::Minute1)),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange Candles streams into a single EventReceiver
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join_map()` to join all exchange streams into a tokio_stream::StreamMap!
+    //  - The MarketEvent<Candle> exchange field distinguishes the source of each Candle
+    let mut joined_stream = streams.join().await;
+
+    while let Some(candle) = joined_stream.recv().await {
+        info!("Exchange: {}, MarketEvent<Candle>: {candle:?}", candle.exchange);
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
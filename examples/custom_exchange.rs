@@ -0,0 +1,279 @@
+use barter_data::{
+    decoder::PlainText,
+    event::{IntoMarketIter, MarketEvent, MarketIter},
+    exchange::{AckMode, Connector, ExchangeId, ExchangeSub, StreamSelector},
+    streams::Streams,
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{
+        number::{from_f64, Number},
+        trade::{PublicTrade, PublicTrades},
+        Subscription,
+    },
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream, Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Validator,
+};
+use barter_macro::{DeExchange, SerExchange};
+use chrono::{DateTime, Utc};
+use futures::{SinkExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::OnceLock;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::info;
+use url::Url;
+
+/// Url of the local fake exchange server, bound to a random port when the example starts.
+static FAKE_EXCHANGE_URL: OnceLock<String> = OnceLock::new();
+
+/// Minimal exchange implemented outside of barter-data, identified by an
+/// [`ExchangeId::Custom`] name.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct FakeExchange;
+
+impl Connector for FakeExchange {
+    const ID: ExchangeId = ExchangeId::Custom("fake_exchange");
+    // One "subscribed" response is sent per channel & market combination
+    const ACK_MODE: AckMode = AckMode::PerSubscription;
+    type Channel = FakeChannel;
+    type Market = FakeMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = FakeSubResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        let url = FAKE_EXCHANGE_URL
+            .get()
+            .expect("fake exchange server is running");
+        Url::parse(url).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        let args = exchange_subs
+            .iter()
+            .map(|exchange_sub| exchange_sub.id().0)
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::Text(
+            json!({ "op": "subscribe", "args": args }).to_string(),
+        )]
+    }
+}
+
+impl StreamSelector<PublicTrades> for FakeExchange {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, FakeTrade>>;
+}
+
+/// [`FakeExchange`] channel, eg/ "trades".
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FakeChannel(&'static str);
+
+impl AsRef<str> for FakeChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+impl Identifier<FakeChannel> for Subscription<FakeExchange, PublicTrades> {
+    fn id(&self) -> FakeChannel {
+        FakeChannel("trades")
+    }
+}
+
+/// [`FakeExchange`] market, eg/ "BTC-USD".
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct FakeMarket(String);
+
+impl AsRef<str> for FakeMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Identifier<FakeMarket> for Subscription<FakeExchange, PublicTrades> {
+    fn id(&self) -> FakeMarket {
+        FakeMarket(format!("{}-{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+/// [`FakeExchange`] subscription response, eg/ `{"event":"subscribed","arg":"trades|BTC-USD"}`.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum FakeSubResponse {
+    Subscribed { arg: String },
+    Error { message: String },
+}
+
+impl Validator for FakeSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            FakeSubResponse::Subscribed { .. } => Ok(self),
+            FakeSubResponse::Error { message } => Err(SocketError::Subscribe(message)),
+        }
+    }
+}
+
+/// [`FakeExchange`] trade message, eg/
+/// `{"arg":"trades|BTC-USD","id":"1","price":"100.0","amount":"1.5","side":"buy","time":1700000000000}`.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct FakeTrade {
+    pub arg: SubscriptionId,
+    pub id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+    pub side: Side,
+    #[serde(with = "chrono::serde::ts_milliseconds")]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for FakeTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.arg.clone())
+    }
+}
+
+impl IntoMarketIter<PublicTrade> for FakeTrade {
+    fn into_market_iter(
+        self,
+        exchange: ExchangeId,
+        instrument: Instrument,
+    ) -> MarketIter<PublicTrade> {
+        MarketIter(vec![Ok(MarketEvent {
+            exchange_time: self.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: PublicTrade {
+                id: self.id,
+                price: self.price,
+                amount: self.amount,
+                side: self.side,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        })])
+    }
+}
+
+/// Serve a single [`FakeExchange`] connection: acknowledge each subscribed channel & market,
+/// then publish a trade for each of them.
+async fn run_fake_exchange_server(listener: TcpListener) {
+    let (tcp, _) = listener.accept().await.unwrap();
+    let mut websocket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+
+    while let Some(Ok(message)) = websocket.next().await {
+        let Message::Text(request) = message else {
+            continue;
+        };
+
+        let request = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+        let args = request["args"].as_array().cloned().unwrap_or_default();
+
+        for arg in &args {
+            let response = json!({ "event": "subscribed", "arg": arg });
+            websocket
+                .send(Message::Text(response.to_string()))
+                .await
+                .unwrap();
+        }
+
+        for (index, arg) in args.iter().enumerate() {
+            let trade = json!({
+                "arg": arg,
+                "id": index.to_string(),
+                "price": format!("{}.5", 100 + index),
+                "amount": "1.0",
+                "side": "buy",
+                "time": Utc::now().timestamp_millis(),
+            });
+            websocket
+                .send(Message::Text(trade.to_string()))
+                .await
+                .unwrap();
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Run the local FakeExchange WebSocket server
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    FAKE_EXCHANGE_URL
+        .set(format!("ws://{}", listener.local_addr().unwrap()))
+        .unwrap();
+    tokio::spawn(run_fake_exchange_server(listener));
+
+    // Initialise PublicTrades Streams for the FakeExchange, exactly as for any built-in exchange
+    let mut streams = Streams::<PublicTrades>::builder()
+        .subscribe([
+            (FakeExchange, "btc", "usd", InstrumentKind::Spot, PublicTrades),
+            (FakeExchange, "eth", "usd", InstrumentKind::Spot, PublicTrades),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Select the FakeExchange stream via its ExchangeId::Custom identifier
+    let mut fake_stream = streams
+        .select(FakeExchange::ID)
+        .unwrap();
+
+    let mut trades = Vec::new();
+    while let Some(trade) = fake_stream.recv().await {
+        info!("MarketEvent<PublicTrade>: {trade:?}");
+        assert_eq!(trade.exchange, Exchange::from("fake_exchange"));
+        trades.push((trade.instrument.base.to_string(), trade.kind.price));
+
+        if trades.len() == 2 {
+            break;
+        }
+    }
+
+    trades.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        trades,
+        vec![
+            ("btc".to_string(), from_f64(100.5)),
+            ("eth".to_string(), from_f64(101.5)),
+        ]
+    );
+    info!("received a PublicTrade for every FakeExchange Subscription");
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
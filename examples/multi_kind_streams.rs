@@ -0,0 +1,66 @@
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    exchange::binance::spot::BinanceSpot,
+    streams::Streams,
+    subscription::{candle::Candles, trade::PublicTrades, Interval},
+};
+use barter_integration::model::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise MarketEvent<DataKind> Streams containing both PublicTrades & Candles for BinanceSpot
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams: Streams<MarketEvent<DataKind>> = Streams::builder_multi()
+
+        // Add PublicTrades Stream for BinanceSpot
+        .add(Streams::<PublicTrades>::builder()
+            .subscribe([
+                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades),
+                (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
+            ])
+        )
+
+        // Add Candles Stream for BinanceSpot
+        .add(Streams::<Candles>::builder()
+            .subscribe([
+                (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
+                (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, Candles(Interval::Minute1)),
+            ])
+        )
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange Streams into a single EventReceiver<MarketEvent<DataKind>>
+    let mut joined_rx = streams.join().await;
+
+    while let Some(event) = joined_rx.recv().await {
+        match &event.kind {
+            DataKind::Trade(trade) => info!(instrument = %event.instrument, ?trade, "MarketEvent<PublicTrade>"),
+            DataKind::Candle(candle) => info!(instrument = %event.instrument, ?candle, "MarketEvent<Candle>"),
+            _ => info!(?event, "MarketEvent<DataKind>"),
+        }
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
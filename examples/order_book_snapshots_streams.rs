@@ -0,0 +1,61 @@
+use barter_data::{
+    exchange::{binance::spot::BinanceSpot, okx::Okx},
+    streams::Streams,
+    subscription::book::{Depth, OrderBookSnapshots},
+};
+use barter_integration::model::InstrumentKind;
+use futures::StreamExt;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise OrderBookSnapshots Streams for BinanceSpot & Okx
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams = Streams::<OrderBookSnapshots>::builder()
+
+        // Separate WebSocket connection for BinanceSpot top 10 levels snapshots
+        .subscribe([
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, OrderBookSnapshots::new(Depth::Top10)),
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, OrderBookSnapshots::new(Depth::Top10)),
+        ])
+
+        // Separate WebSocket connection for Okx top 5 levels snapshots (Okx only supports Top5)
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Spot, OrderBookSnapshots::new(Depth::Top5)),
+            (Okx, "eth", "usdt", InstrumentKind::Spot, OrderBookSnapshots::new(Depth::Top5)),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange OrderBookSnapshots streams into a single tokio_stream::StreamMap
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single EventReceiver!
+    let mut joined_stream = streams.join_map().await;
+
+    while let Some((exchange, snapshot)) = joined_stream.next().await {
+        info!("Exchange: {exchange}, MarketEvent<OrderBookSnapshot>: {snapshot:?}");
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,58 @@
+use barter_data::{
+    exchange::{huobi::HuobiSpot, ExchangeId},
+    streams::Streams,
+    subscription::trade::PublicTrades,
+};
+use barter_integration::model::InstrumentKind;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise PublicTrades Streams for HuobiSpot only
+    // '--> HuobiSpot gzip compresses every WebSocket message, which is transparently decoded
+    let mut streams = Streams::<PublicTrades>::builder()
+
+        // Separate WebSocket connection for BTC_USDT stream since it's very high volume
+        .subscribe([
+            (HuobiSpot, "btc", "usdt", InstrumentKind::Spot, PublicTrades),
+        ])
+
+        // Lower volume Instruments can share a WebSocket connection
+        .subscribe([
+            (HuobiSpot, "eth", "usdt", InstrumentKind::Spot, PublicTrades),
+            (HuobiSpot, "xrp", "usdt", InstrumentKind::Spot, PublicTrades),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Select the ExchangeId::HuobiSpot stream
+    let mut huobi_stream = streams
+        .select(ExchangeId::HuobiSpot)
+        .unwrap();
+
+    while let Some(trade) = huobi_stream.recv().await {
+        info!("MarketEvent<PublicTrade>: {trade:?}");
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
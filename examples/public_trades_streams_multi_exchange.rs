@@ -4,6 +4,7 @@ use barter_data::{
         coinbase::Coinbase,
         gateio::spot::GateioSpot,
         okx::Okx,
+        ExchangeId,
     },
     streams::Streams,
     subscription::trade::PublicTrades,
@@ -20,7 +21,7 @@ async fn main() {
 
     // Initialise PublicTrades Streams for various exchanges
     // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
-    let streams = Streams::<PublicTrades>::builder()
+    let mut streams = Streams::<PublicTrades>::builder()
         .subscribe([
             (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, PublicTrades),
             (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, PublicTrades),
@@ -47,10 +48,26 @@ async fn main() {
         .await
         .unwrap();
 
-    // Join all exchange PublicTrades streams into a single tokio_stream::StreamMap
+    // Select the Binance PublicTrades streams, returned in the requested order
     // Notes:
-    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-    //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+    //  - Fails with a StreamError naming any exchange that was not initialised!
+    //  - Use `streams.select(ExchangeId)` to interact with an individual exchange stream!
+    //  - Use `streams.select_all()` to take every remaining (ExchangeId, EventReceiver) pair!
+    let binance_streams = streams
+        .select_many(&[ExchangeId::BinanceSpot, ExchangeId::BinanceFuturesUsd])
+        .unwrap();
+
+    for mut binance_stream in binance_streams {
+        tokio::spawn(async move {
+            while let Some(trade) = binance_stream.recv().await {
+                info!("Binance MarketEvent<PublicTrade>: {trade:?}");
+            }
+        });
+    }
+
+    // Join the remaining exchange PublicTrades streams into a single tokio_stream::StreamMap
+    // Notes:
+    //  - Use `streams.join()` to join all exchange streams into a single EventReceiver!
     let mut joined_stream = streams.join_map().await;
 
     while let Some((exchange, trade)) = joined_stream.next().await {
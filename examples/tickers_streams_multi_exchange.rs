@@ -0,0 +1,65 @@
+use barter_data::{
+    exchange::{
+        binance::{futures::BinanceFuturesUsd, spot::BinanceSpot},
+        okx::Okx,
+    },
+    streams::Streams,
+    subscription::ticker::Tickers,
+};
+use barter_integration::model::InstrumentKind;
+use futures::StreamExt;
+use tracing::info;
+
+#[rustfmt::skip]
+#[tokio::main]
+async fn main() {
+    // Initialise INFO Tracing log subscriber
+    init_logging();
+
+    // Initialise Tickers Streams for various exchanges
+    // '--> each call to StreamBuilder::subscribe() creates a separate WebSocket connection
+    let streams = Streams::<Tickers>::builder()
+        .subscribe([
+            (BinanceSpot::default(), "btc", "usdt", InstrumentKind::Spot, Tickers),
+            (BinanceSpot::default(), "eth", "usdt", InstrumentKind::Spot, Tickers),
+        ])
+        .subscribe([
+            (BinanceFuturesUsd::default(), "btc", "usdt", InstrumentKind::FuturePerpetual, Tickers),
+            (BinanceFuturesUsd::default(), "eth", "usdt", InstrumentKind::FuturePerpetual, Tickers),
+        ])
+        .subscribe([
+            (Okx, "btc", "usdt", InstrumentKind::Spot, Tickers),
+            (Okx, "eth", "usdt", InstrumentKind::Spot, Tickers),
+            (Okx, "btc", "usdt", InstrumentKind::FuturePerpetual, Tickers),
+        ])
+        .init()
+        .await
+        .unwrap();
+
+    // Join all exchange Tickers streams into a single tokio_stream::StreamMap
+    // Notes:
+    //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
+    //  - Use `streams.join()` to join all exchange streams into a single EventReceiver!
+    let mut joined_stream = streams.join_map().await;
+
+    while let Some((exchange, ticker)) = joined_stream.next().await {
+        info!("Exchange: {exchange}, MarketEvent<Ticker>: {ticker:?}");
+    }
+}
+
+// Initialise an INFO `Subscriber` for `Tracing` Json logs and install it as the global default.
+fn init_logging() {
+    tracing_subscriber::fmt()
+        // Filter messages based on the INFO
+        .with_env_filter(
+            tracing_subscriber::filter::EnvFilter::builder()
+                .with_default_directive(tracing_subscriber::filter::LevelFilter::INFO.into())
+                .from_env_lossy(),
+        )
+        // Disable colours on release builds
+        .with_ansi(cfg!(debug_assertions))
+        // Enable Json formatting
+        .json()
+        // Install this Tracing subscriber as global default
+        .init()
+}
@@ -0,0 +1,335 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// Epoch values below this threshold (2001-09-09T01:46:40Z in milliseconds) are assumed to be
+/// seconds rather than milliseconds by [`datetime_utc_from_epoch_ms`].
+pub const EPOCH_MS_THRESHOLD: u64 = 1_000_000_000_000;
+
+/// Convert an epoch milliseconds value into a [`DateTime<Utc>`], returning `None` if it is out of
+/// the [`DateTime<Utc>`] range (eg/ a pathological payload).
+///
+/// Some venues occasionally send second resolution timestamps in place of milliseconds, so
+/// values below the [`EPOCH_MS_THRESHOLD`] are converted from seconds rather than producing
+/// year-1970 datetimes.
+pub fn datetime_utc_from_epoch_ms(epoch: u64) -> Option<DateTime<Utc>> {
+    match epoch < EPOCH_MS_THRESHOLD {
+        true => datetime_utc_from_epoch_us(epoch.checked_mul(1_000_000)?),
+        false => datetime_utc_from_epoch_us(epoch.checked_mul(1_000)?),
+    }
+}
+
+/// Convert an epoch microseconds value into a [`DateTime<Utc>`], returning `None` if it is out
+/// of the [`DateTime<Utc>`] range.
+pub fn datetime_utc_from_epoch_us(epoch_us: u64) -> Option<DateTime<Utc>> {
+    let secs = i64::try_from(epoch_us / 1_000_000).ok()?;
+    let nanos = u32::try_from(epoch_us % 1_000_000).ok()? * 1_000;
+    chrono::TimeZone::timestamp_opt(&Utc, secs, nanos).single()
+}
+
+/// Deserialize a u64 epoch milliseconds value as a [`DateTime<Utc>`], detecting second resolution
+/// values (see [`datetime_utc_from_epoch_ms`]). Out of range values fail to deserialize.
+pub fn de_u64_epoch_ms_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let epoch = <u64 as Deserialize>::deserialize(deserializer)?;
+    datetime_utc_from_epoch_ms(epoch).ok_or_else(|| epoch_out_of_range(epoch))
+}
+
+/// Deserialize an optional u64 epoch milliseconds value as an `Option<DateTime<Utc>>`, detecting
+/// second resolution values (see [`datetime_utc_from_epoch_ms`]). Out of range values fail to
+/// deserialize.
+pub fn de_option_u64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <Option<u64> as Deserialize>::deserialize(deserializer)?
+        .map(|epoch| datetime_utc_from_epoch_ms(epoch).ok_or_else(|| epoch_out_of_range(epoch)))
+        .transpose()
+}
+
+/// Deserialize a String u64 epoch milliseconds value (eg/ "1597026383085") as a
+/// [`DateTime<Utc>`], detecting second resolution values (see [`datetime_utc_from_epoch_ms`]).
+/// Out of range values fail to deserialize.
+pub fn de_str_u64_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let epoch = barter_integration::de::de_str::<D, u64>(deserializer)?;
+    datetime_utc_from_epoch_ms(epoch).ok_or_else(|| epoch_out_of_range(epoch))
+}
+
+/// Convert an epoch seconds value into a [`DateTime<Utc>`], returning `None` if it is out of the
+/// [`DateTime<Utc>`] range.
+pub fn datetime_utc_from_epoch_s(epoch_s: u64) -> Option<DateTime<Utc>> {
+    datetime_utc_from_epoch_us(epoch_s.checked_mul(1_000_000)?)
+}
+
+/// Convert a fractional epoch seconds value (eg/ 1549578911.1234) into a [`DateTime<Utc>`] with
+/// nanosecond precision, returning `None` if it is negative, non-finite, or out of the
+/// [`DateTime<Utc>`] range.
+pub fn datetime_utc_from_f64_epoch_s(epoch_s: f64) -> Option<DateTime<Utc>> {
+    let duration = std::time::Duration::try_from_secs_f64(epoch_s).ok()?;
+    let secs = i64::try_from(duration.as_secs()).ok()?;
+    chrono::TimeZone::timestamp_opt(&Utc, secs, duration.subsec_nanos()).single()
+}
+
+/// Deserialize a u64 epoch seconds value as a [`DateTime<Utc>`]. Out of range values fail to
+/// deserialize.
+pub fn de_u64_epoch_s_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let epoch = <u64 as Deserialize>::deserialize(deserializer)?;
+    datetime_utc_from_epoch_s(epoch).ok_or_else(|| epoch_out_of_range(epoch))
+}
+
+/// Deserialize a String fractional epoch seconds value (eg/ "1549578911.1234") as a
+/// [`DateTime<Utc>`] (see [`datetime_utc_from_f64_epoch_s`]). Negative, non-finite & out of range
+/// values fail to deserialize.
+pub fn de_str_f64_epoch_s_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let epoch_s = barter_integration::de::de_str::<D, f64>(deserializer)?;
+    datetime_utc_from_f64_epoch_s(epoch_s).ok_or_else(|| {
+        serde::de::Error::invalid_value(
+            serde::de::Unexpected::Float(epoch_s),
+            &"an epoch within the DateTime<Utc> range",
+        )
+    })
+}
+
+/// [`serde::de::Error`] for an epoch value out of the [`DateTime<Utc>`] range.
+pub fn epoch_out_of_range<E>(epoch: u64) -> E
+where
+    E: serde::de::Error,
+{
+    E::invalid_value(
+        serde::de::Unexpected::Unsigned(epoch),
+        &"an epoch within the DateTime<Utc> range",
+    )
+}
+
+/// Convert a (possibly fractional) epoch milliseconds `&str` (eg/ "1669107766504.563") into a
+/// [`DateTime<Utc>`] with microsecond precision.
+///
+/// The value is parsed without an intermediate `f64`, so no precision is lost. Fractional digits
+/// beyond microsecond precision are truncated, and out of range values return `None`.
+pub fn datetime_utc_from_str_epoch_ms(epoch_ms: &str) -> Option<DateTime<Utc>> {
+    let (millis, fraction) = epoch_ms.split_once('.').unwrap_or((epoch_ms, ""));
+    if !fraction.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    let micros = fraction
+        .bytes()
+        .chain(std::iter::repeat(b'0'))
+        .take(3)
+        .fold(0, |micros, digit| micros * 10 + u64::from(digit - b'0'));
+
+    millis
+        .parse::<u64>()
+        .ok()?
+        .checked_mul(1000)?
+        .checked_add(micros)
+        .and_then(datetime_utc_from_epoch_us)
+}
+
+/// Deserialize a (possibly fractional) epoch milliseconds value as a [`DateTime<Utc>`] with
+/// microsecond precision, sent as either a `String` (eg/ "1669107766504.563") or a number (eg/
+/// 1669107766504 or 1669107766504.563).
+///
+/// See [`datetime_utc_from_str_epoch_ms`].
+pub fn de_fractional_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    struct EpochMsVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for EpochMsVisitor {
+        type Value = DateTime<Utc>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            formatter.write_str("epoch milliseconds as a String or number")
+        }
+
+        fn visit_str<E>(self, epoch_ms: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            datetime_utc_from_str_epoch_ms(epoch_ms)
+                .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(epoch_ms), &self))
+        }
+
+        fn visit_u64<E>(self, epoch_ms: u64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            self.visit_str(&epoch_ms.to_string())
+        }
+
+        fn visit_f64<E>(self, epoch_ms: f64) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            // Shortest round-trip representation, avoiding f64 arithmetic rounding errors
+            self.visit_str(&epoch_ms.to_string())
+        }
+    }
+
+    deserializer.deserialize_any(EpochMsVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_epoch_out_of_range_does_not_panic() {
+        #[derive(Debug, Deserialize)]
+        struct Payload {
+            #[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")]
+            ms: DateTime<Utc>,
+            #[serde(deserialize_with = "de_str_u64_epoch_ms_as_datetime_utc")]
+            str_ms: DateTime<Utc>,
+            #[serde(deserialize_with = "de_u64_epoch_s_as_datetime_utc")]
+            s: DateTime<Utc>,
+            #[serde(deserialize_with = "de_str_f64_epoch_s_as_datetime_utc")]
+            str_f64_s: DateTime<Utc>,
+        }
+
+        struct TestCase {
+            input: &'static str,
+            expected: Option<[&'static str; 4]>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid epochs
+                input: r#"{"ms":1606292218213,"str_ms":"1606292218213","s":1606292218,"str_f64_s":"1606292218.5"}"#,
+                expected: Some([
+                    "2020-11-25T08:16:58.213Z",
+                    "2020-11-25T08:16:58.213Z",
+                    "2020-11-25T08:16:58Z",
+                    "2020-11-25T08:16:58.5Z",
+                ]),
+            },
+            TestCase {
+                // TC1: u64 milliseconds out of range
+                input: r#"{"ms":18446744073709551615,"str_ms":"1606292218213","s":1606292218,"str_f64_s":"1606292218"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC2: String u64 milliseconds out of range
+                input: r#"{"ms":1606292218213,"str_ms":"18446744073709551615","s":1606292218,"str_f64_s":"1606292218"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC3: u64 seconds out of range
+                input: r#"{"ms":1606292218213,"str_ms":"1606292218213","s":18446744073709551615,"str_f64_s":"1606292218"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC4: String f64 seconds out of range
+                input: r#"{"ms":1606292218213,"str_ms":"1606292218213","s":1606292218,"str_f64_s":"1e300"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC5: String f64 seconds negative
+                input: r#"{"ms":1606292218213,"str_ms":"1606292218213","s":1606292218,"str_f64_s":"-1.5"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC6: String f64 seconds non-finite
+                input: r#"{"ms":1606292218213,"str_ms":"1606292218213","s":1606292218,"str_f64_s":"NaN"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<Payload>(test.input)
+                .ok()
+                .map(|payload| [payload.ms, payload.str_ms, payload.s, payload.str_f64_s]);
+            let expected = test
+                .expected
+                .map(|times| times.map(|time| time.parse::<DateTime<Utc>>().unwrap()));
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_de_fractional_epoch_ms_as_datetime_utc() {
+        #[derive(Debug, Deserialize)]
+        struct Payload {
+            #[serde(deserialize_with = "de_fractional_epoch_ms_as_datetime_utc")]
+            time: DateTime<Utc>,
+        }
+
+        struct TestCase {
+            input: &'static str,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: fractional milliseconds String keeps microsecond precision
+                input: r#"{"time": "1669107766504.563"}"#,
+                expected: Some("2022-11-22T09:02:46.504563Z"),
+            },
+            TestCase {
+                // TC1: digits beyond microsecond precision are truncated
+                input: r#"{"time": "1606292218213.4578"}"#,
+                expected: Some("2020-11-25T08:16:58.213457Z"),
+            },
+            TestCase {
+                // TC2: single fractional digit is tenths of a millisecond
+                input: r#"{"time": "1606292218213.4"}"#,
+                expected: Some("2020-11-25T08:16:58.213400Z"),
+            },
+            TestCase {
+                // TC3: whole milliseconds String
+                input: r#"{"time": "1606292218213"}"#,
+                expected: Some("2020-11-25T08:16:58.213Z"),
+            },
+            TestCase {
+                // TC4: whole milliseconds number
+                input: r#"{"time": 1545136464123}"#,
+                expected: Some("2018-12-18T12:34:24.123Z"),
+            },
+            TestCase {
+                // TC5: fractional milliseconds number
+                input: r#"{"time": 1669107766504.563}"#,
+                expected: Some("2022-11-22T09:02:46.504563Z"),
+            },
+            TestCase {
+                // TC6: non-numeric String is rejected
+                input: r#"{"time": "1669107766504.5x"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC7: negative number is rejected
+                input: r#"{"time": -1}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<Payload>(test.input)
+                .ok()
+                .map(|payload| payload.time);
+            let expected = test
+                .expected
+                .map(|time| time.parse::<DateTime<Utc>>().unwrap());
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+}
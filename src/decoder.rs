@@ -0,0 +1,240 @@
+use barter_integration::{
+    error::SocketError,
+    protocol::{
+        websocket::{process_text, WebSocket, WebSocketParser, WsError, WsMessage},
+        StreamParser,
+    },
+};
+use flate2::read::GzDecoder;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{borrow::Cow, io::Read, marker::PhantomData};
+
+/// Defines how to decode the raw bytes of a binary WebSocket payload into the text that is
+/// deserialised into exchange specific types (eg/ decompressing gzip frames).
+///
+/// Declared by each [`Connector`](crate::exchange::Connector) via the associated
+/// [`Connector::Decoder`](crate::exchange::Connector::Decoder) type.
+pub trait PayloadDecoder: Send + Sync + Unpin {
+    fn decode(payload: &[u8]) -> Result<Cow<'_, str>, SocketError>;
+}
+
+/// [`PayloadDecoder`] for exchanges that send uncompressed UTF-8 JSON payloads.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct PlainText;
+
+impl PayloadDecoder for PlainText {
+    fn decode(payload: &[u8]) -> Result<Cow<'_, str>, SocketError> {
+        std::str::from_utf8(payload)
+            .map(Cow::Borrowed)
+            .map_err(|error| SocketError::DeserialiseBinary {
+                error: serde::de::Error::custom(error),
+                payload: payload.to_vec(),
+            })
+    }
+}
+
+/// [`PayloadDecoder`] for exchanges that send gzip compressed binary payloads
+/// (eg/ [`HuobiSpot`](crate::exchange::huobi::HuobiSpot)).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Gzip;
+
+impl PayloadDecoder for Gzip {
+    fn decode(payload: &[u8]) -> Result<Cow<'_, str>, SocketError> {
+        let mut text = String::new();
+        GzDecoder::new(payload)
+            .read_to_string(&mut text)
+            .map(|_| Cow::Owned(text))
+            .map_err(|error| SocketError::DeserialiseBinary {
+                error: serde_json::Error::io(error),
+                payload: payload.to_vec(),
+            })
+    }
+}
+
+/// [`StreamParser`] for [`WebSocket`]s that decodes binary payloads with the provided
+/// [`PayloadDecoder`] before deserialisation. All other [`WsMessage`]s are parsed by the
+/// standard [`WebSocketParser`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DecodingParser<Decoder>(PhantomData<Decoder>);
+
+impl<Decoder> StreamParser for DecodingParser<Decoder>
+where
+    Decoder: PayloadDecoder,
+{
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsMessage::Binary(payload)) => match Decoder::decode(&payload) {
+                Ok(text) => process_text(text.into_owned()),
+                Err(error) => Some(Err(error)),
+            },
+            input => WebSocketParser::parse(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{write::GzEncoder, Compression};
+    use std::io::Write;
+
+    fn gzip(payload: &str) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(payload.as_bytes()).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_plain_text_decode() {
+        struct TestCase {
+            input: &'static [u8],
+            expected: Result<&'static str, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid UTF-8 payload is borrowed as is
+                input: br#"{"ping":1492420473027}"#,
+                expected: Ok(r#"{"ping":1492420473027}"#),
+            },
+            TestCase {
+                // TC1: invalid UTF-8 payload fails to decode
+                input: &[0xff, 0xfe, 0xfd],
+                expected: Err(SocketError::Sink),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = PlainText::decode(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert!(matches!(actual, Cow::Borrowed(_)), "TC{} failed", index);
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(SocketError::DeserialiseBinary { .. }), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gzip_decode() {
+        struct TestCase {
+            input: Vec<u8>,
+            expected: Result<&'static str, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: HuobiSpot gzip compressed ping fixture
+                input: vec![
+                    0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0xff, 0xab, 0x56, 0x2a,
+                    0xc8, 0xcc, 0x4b, 0x57, 0xb2, 0x32, 0x34, 0xb1, 0x34, 0x32, 0x31, 0x32, 0x30,
+                    0x31, 0x37, 0x36, 0x30, 0x32, 0xaf, 0x05, 0x00, 0x6c, 0x9f, 0xbb, 0x7a, 0x16,
+                    0x00, 0x00, 0x00,
+                ],
+                expected: Ok(r#"{"ping":1492420473027}"#),
+            },
+            TestCase {
+                // TC1: gzip compressed trade payload round trips
+                input: gzip(
+                    r#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175,"tick":{"id":137005445109,"ts":1630994963173,"data":[]}}"#,
+                ),
+                expected: Ok(
+                    r#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175,"tick":{"id":137005445109,"ts":1630994963173,"data":[]}}"#,
+                ),
+            },
+            TestCase {
+                // TC2: uncompressed payload fails to decode
+                input: br#"{"ping":1492420473027}"#.to_vec(),
+                expected: Err(SocketError::Sink),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Gzip::decode(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(SocketError::DeserialiseBinary { .. }), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_decoding_parser_parse() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Ping {
+            ping: u64,
+        }
+
+        struct TestCase {
+            input: WsMessage,
+            expected: Option<Result<Ping, SocketError>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: gzip compressed binary payload is decoded & deserialised
+                input: WsMessage::Binary(gzip(r#"{"ping":1492420473027}"#)),
+                expected: Some(Ok(Ping {
+                    ping: 1492420473027,
+                })),
+            },
+            TestCase {
+                // TC1: text payload is deserialised as is
+                input: WsMessage::Text(r#"{"ping":1492420473027}"#.to_string()),
+                expected: Some(Ok(Ping {
+                    ping: 1492420473027,
+                })),
+            },
+            TestCase {
+                // TC2: uncompressed binary payload fails to decode
+                input: WsMessage::Binary(br#"{"ping":1492420473027}"#.to_vec()),
+                expected: Some(Err(SocketError::Sink)),
+            },
+            TestCase {
+                // TC3: protocol-level pings are skipped
+                input: WsMessage::Ping(vec![]),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = DecodingParser::<Gzip>::parse::<Ping>(Ok(test.input));
+            match (actual, test.expected) {
+                (Some(Ok(actual)), Some(Ok(expected))) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Some(Err(_)), Some(Err(_))) | (None, None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
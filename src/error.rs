@@ -1,4 +1,11 @@
+use crate::{
+    exchange::ExchangeId,
+    subscriber::validator::SubscriptionOutcome,
+    subscription::{candle::Candle, Interval, InvalidSubscription},
+};
 use barter_integration::error::SocketError;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
 use thiserror::Error;
 
 /// All errors generated in `barter-data`.
@@ -17,6 +24,110 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error("SequenceGap: expected sequence {expected} but received {actual}")]
+    SequenceGap { expected: u64, actual: u64 },
+
+    #[error("InvalidChecksum: expected checksum {expected} but calculated {actual}")]
+    InvalidChecksum { expected: i32, actual: i32 },
+
+    #[error("Exchange error code {code}: {message}")]
+    Exchange {
+        code: String,
+        message: String,
+        fatal: bool,
+    },
+
+    #[error("InvalidSubscription: {0}")]
+    InvalidSubscription(InvalidSubscription),
+
+    #[error("SubscriptionRejected: {0}")]
+    SubscriptionRejected(SubscriptionOutcome),
+
+    #[error("InvalidCandle: {reason}")]
+    InvalidCandle { reason: String, candle: Box<Candle> },
+
+    #[error(
+        "TimestampOutOfRange: exchange_time {exchange_time} is outside the {tolerance:?} \
+        tolerance of received_time {received_time}"
+    )]
+    TimestampOutOfRange {
+        exchange_time: DateTime<Utc>,
+        received_time: DateTime<Utc>,
+        tolerance: Duration,
+    },
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("StreamPanicked: {exchange} MarketStream panicked: {detail}")]
+    StreamPanicked {
+        exchange: ExchangeId,
+        detail: String,
+    },
+}
+
+/// Errors generated when selecting exchange receivers from [`Streams`](crate::streams::Streams).
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+pub enum StreamError {
+    #[error(
+        "ExchangeNotInitialised: {requested} stream was not initialised or has already been \
+        selected, available: [{}]",
+        display_exchanges(available)
+    )]
+    ExchangeNotInitialised {
+        requested: ExchangeId,
+        available: Vec<ExchangeId>,
+    },
+
+    #[error(
+        "ExchangesNotInitialised: [{}] streams were not initialised or have already been \
+        selected, available: [{}]",
+        display_exchanges(missing),
+        display_exchanges(available)
+    )]
+    ExchangesNotInitialised {
+        missing: Vec<ExchangeId>,
+        available: Vec<ExchangeId>,
+    },
+}
+
+/// Error generated when parsing an [`ExchangeId`] from an unrecognised string (eg/ CLI input).
+#[derive(Clone, Eq, PartialEq, Debug, Error)]
+#[error(
+    "ParseExchangeIdError: unknown exchange \"{input}\", expected one of: [{}]",
+    display_exchanges(ExchangeId::all())
+)]
+pub struct ParseExchangeIdError {
+    pub input: String,
+}
+
+/// Error generated when converting a Barter [`Interval`] into the candle interval of an exchange
+/// that does not support it (eg/ [`Interval::Month3`] for
+/// [`Binance`](crate::exchange::binance::Binance)).
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Error)]
+#[error("UnsupportedInterval: {exchange} does not support candle interval {interval}")]
+pub struct UnsupportedIntervalError {
+    pub exchange: &'static str,
+    pub interval: Interval,
+}
+
+impl From<UnsupportedIntervalError> for SocketError {
+    fn from(error: UnsupportedIntervalError) -> Self {
+        SocketError::Unsupported {
+            entity: error.exchange,
+            item: format!("candle interval {}", error.interval),
+        }
+    }
+}
+
+/// Comma separated [`ExchangeId`]s (eg/ "binance_spot, okx") used by [`StreamError`] messages.
+fn display_exchanges(exchanges: &[ExchangeId]) -> String {
+    exchanges
+        .iter()
+        .map(ExchangeId::as_str)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 impl DataError {
@@ -25,6 +136,9 @@ impl DataError {
     pub fn is_terminal(&self) -> bool {
         match self {
             DataError::InvalidSequence { .. } => true,
+            DataError::Socket(SocketError::Terminated(_) | SocketError::WebSocket(_)) => true,
+            DataError::Exchange { fatal, .. } => *fatal,
+            DataError::StreamPanicked { .. } => true,
             _ => false,
         }
     }
@@ -55,6 +169,60 @@ mod tests {
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
+            TestCase {
+                // TC2: is terminal w/ DataError::Socket(SocketError::Terminated)
+                input: DataError::Socket(SocketError::Terminated("1000".to_string())),
+                expected: true,
+            },
+            TestCase {
+                // TC3: is terminal w/ DataError::Socket(SocketError::WebSocket)
+                input: DataError::Socket(SocketError::WebSocket(
+                    barter_integration::protocol::websocket::WsError::ConnectionClosed,
+                )),
+                expected: true,
+            },
+            TestCase {
+                // TC4: is not terminal w/ DataError::InvalidChecksum
+                input: DataError::InvalidChecksum {
+                    expected: 1,
+                    actual: 2,
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC5: is not terminal w/ DataError::SequenceGap
+                input: DataError::SequenceGap {
+                    expected: 1,
+                    actual: 3,
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC6: is not terminal w/ recoverable DataError::Exchange
+                input: DataError::Exchange {
+                    code: "60012".to_string(),
+                    message: "Invalid request".to_string(),
+                    fatal: false,
+                },
+                expected: false,
+            },
+            TestCase {
+                // TC7: is terminal w/ fatal DataError::Exchange
+                input: DataError::Exchange {
+                    code: "-1003".to_string(),
+                    message: "Too many requests".to_string(),
+                    fatal: true,
+                },
+                expected: true,
+            },
+            TestCase {
+                // TC8: is terminal w/ DataError::StreamPanicked
+                input: DataError::StreamPanicked {
+                    exchange: ExchangeId::BinanceSpot,
+                    detail: "unexpected null".to_string(),
+                },
+                expected: true,
+            },
         ];
 
         for (index, test) in tests.into_iter().enumerate() {
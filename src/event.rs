@@ -1,9 +1,13 @@
 use crate::subscription::liquidation::Liquidation;
 use crate::{
     error::DataError,
+    exchange::ExchangeId,
     subscription::{
-        book::{OrderBook, OrderBookL1},
+        book::{OrderBook, OrderBookL1, OrderBookSnapshot},
         candle::Candle,
+        mark_price::MarkPrice,
+        option::OptionSummary,
+        ticker::{IndexTicker, Ticker},
         trade::PublicTrade,
     },
 };
@@ -24,6 +28,27 @@ impl<T> FromIterator<Result<MarketEvent<T>, DataError>> for MarketIter<T> {
     }
 }
 
+/// Translates an exchange specific message into the [`MarketIter<T>`](MarketIter) of normalised
+/// [`MarketEvent<T>`](MarketEvent)s it contains.
+///
+/// Implemented by the message types of exchanges defined outside of barter-data, since the orphan
+/// rule forbids them from implementing `From<(ExchangeId, Instrument, Message)>` for
+/// [`MarketIter<T>`](MarketIter), which is required by the
+/// [`StatelessTransformer`](crate::transformer::stateless::StatelessTransformer). Every
+/// implementation provides the equivalent `From` implementation.
+pub trait IntoMarketIter<T> {
+    fn into_market_iter(self, exchange: ExchangeId, instrument: Instrument) -> MarketIter<T>;
+}
+
+impl<T, Message> From<(ExchangeId, Instrument, Message)> for MarketIter<T>
+where
+    Message: IntoMarketIter<T>,
+{
+    fn from((exchange, instrument, message): (ExchangeId, Instrument, Message)) -> Self {
+        message.into_market_iter(exchange, instrument)
+    }
+}
+
 /// Normalised Barter [`MarketEvent<T>`](Self) wrapping the `T` data variant in metadata.
 ///
 /// Note: `T` can be an enum such as the [`DataKind`] if required.
@@ -40,9 +65,44 @@ pub struct MarketEvent<T> {
     pub received_time: DateTime<Utc>,
     pub exchange: Exchange,
     pub instrument: Instrument,
+    /// Exchange sequence number (eg/ trade id) of this event, if the exchange provides a
+    /// monotonically increasing one. Used to detect gaps via the
+    /// [`SequenceValidator`](crate::transformer::sequence::SequenceValidator).
+    ///
+    /// Always `None` for Okx, since its `seqId` is only sent on order book channels & is not
+    /// contiguous, so it is instead exposed as the
+    /// [`OrderBook`](crate::subscription::book::OrderBook) update id range.
+    #[serde(default)]
+    pub sequence: Option<u64>,
+    /// Opaque user tag of the [`Subscription`](crate::subscription::Subscription) that yielded
+    /// this event, if tagged via
+    /// [`Subscription::with_tag`](crate::subscription::Subscription::with_tag).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
     pub kind: T,
 }
 
+/// Source of the `exchange_time` of a [`MarketEvent`].
+///
+/// Exchanges that do not timestamp a payload (eg/ BinanceSpot bookTicker) have their
+/// `exchange_time` set equal to the `received_time`, so latency calculations must skip events
+/// with a [`TimeSource::Receiver`] rather than report zero latency.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeSource {
+    /// Time provided by the exchange in the payload.
+    #[default]
+    Exchange,
+    /// Time the payload was received, since the exchange did not provide one.
+    Receiver,
+    /// Initial snapshot fetched via the exchange REST API before any live events (see
+    /// [`StreamBuilder::with_initial_snapshots`](crate::streams::builder::StreamBuilder::with_initial_snapshots)).
+    /// Timed by the exchange if the REST response includes a time, otherwise by the receiver.
+    Snapshot,
+}
+
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
 ///
 /// ### Notes
@@ -58,8 +118,13 @@ pub enum DataKind {
     Trade(PublicTrade),
     OrderBookL1(OrderBookL1),
     OrderBook(OrderBook),
+    OrderBookSnapshot(OrderBookSnapshot),
     Candle(Candle),
     Liquidation(Liquidation),
+    Ticker(Ticker),
+    IndexTicker(IndexTicker),
+    MarkPrice(MarkPrice),
+    OptionSummary(OptionSummary),
 }
 
 impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
@@ -69,6 +134,8 @@ impl From<MarketEvent<PublicTrade>> for MarketEvent<DataKind> {
             received_time: event.received_time,
             exchange: event.exchange,
             instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
             kind: DataKind::Trade(event.kind),
         }
     }
@@ -81,6 +148,8 @@ impl From<MarketEvent<OrderBookL1>> for MarketEvent<DataKind> {
             received_time: event.received_time,
             exchange: event.exchange,
             instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
             kind: DataKind::OrderBookL1(event.kind),
         }
     }
@@ -93,11 +162,27 @@ impl From<MarketEvent<OrderBook>> for MarketEvent<DataKind> {
             received_time: event.received_time,
             exchange: event.exchange,
             instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
             kind: DataKind::OrderBook(event.kind),
         }
     }
 }
 
+impl From<MarketEvent<OrderBookSnapshot>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<OrderBookSnapshot>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
+            kind: DataKind::OrderBookSnapshot(event.kind),
+        }
+    }
+}
+
 impl From<MarketEvent<Candle>> for MarketEvent<DataKind> {
     fn from(event: MarketEvent<Candle>) -> Self {
         Self {
@@ -105,6 +190,8 @@ impl From<MarketEvent<Candle>> for MarketEvent<DataKind> {
             received_time: event.received_time,
             exchange: event.exchange,
             instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
             kind: DataKind::Candle(event.kind),
         }
     }
@@ -117,7 +204,65 @@ impl From<MarketEvent<Liquidation>> for MarketEvent<DataKind> {
             received_time: event.received_time,
             exchange: event.exchange,
             instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
             kind: DataKind::Liquidation(event.kind),
         }
     }
 }
+
+impl From<MarketEvent<Ticker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<Ticker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
+            kind: DataKind::Ticker(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<IndexTicker>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<IndexTicker>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
+            kind: DataKind::IndexTicker(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<MarkPrice>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<MarkPrice>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
+            kind: DataKind::MarkPrice(event.kind),
+        }
+    }
+}
+
+impl From<MarketEvent<OptionSummary>> for MarketEvent<DataKind> {
+    fn from(event: MarketEvent<OptionSummary>) -> Self {
+        Self {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange,
+            instrument: event.instrument,
+            sequence: event.sequence,
+            tag: event.tag,
+            kind: DataKind::OptionSummary(event.kind),
+        }
+    }
+}
@@ -1,15 +1,68 @@
 use crate::{
-    event::{MarketEvent, MarketIter},
-    exchange::{binance::channel::BinanceChannel, subscription::ExchangeSub, ExchangeId},
-    subscription::book::{Level, OrderBookL1},
+    error::DataError,
+    event::{MarketEvent, MarketIter, TimeSource},
+    exchange::{
+        binance::{channel::BinanceChannel, market::BinanceMarket, Binance},
+        subscription::ExchangeSub,
+        ExchangeId, ExchangeServer,
+    },
+    streams::snapshot::SnapshotClient,
+    subscription::{
+        book::{Level, OrderBookL1, OrderBooksL1},
+        number::Number,
+        Subscription,
+    },
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, SubscriptionId};
-use chrono::Utc;
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// [`BinanceSpot`](super::super::spot::BinanceSpot) HTTP book ticker (top of book) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#symbol-order-book-ticker>
+pub const HTTP_BOOK_TICKER_URL_BINANCE_SPOT: &str =
+    "https://api.binance.com/api/v3/ticker/bookTicker";
+
+/// [`BinanceFuturesUsd`](super::super::futures::BinanceFuturesUsd) HTTP book ticker (top of
+/// book) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#symbol-order-book-ticker>
+pub const HTTP_BOOK_TICKER_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/ticker/bookTicker";
+
+/// [`BinanceFuturesCoin`](super::super::futures::BinanceFuturesCoin) HTTP book ticker (top of
+/// book) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#symbol-order-book-ticker>
+pub const HTTP_BOOK_TICKER_URL_BINANCE_FUTURES_COIN: &str =
+    "https://dapi.binance.com/dapi/v1/ticker/bookTicker";
+
+/// [`BinanceUs`](super::super::spot::BinanceUs) HTTP book ticker (top of book) url.
+///
+/// See docs: <https://docs.binance.us/#get-order-book-ticker>
+pub const HTTP_BOOK_TICKER_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/ticker/bookTicker";
+
+/// [`BinanceSpotTestnet`](super::super::spot::BinanceSpotTestnet) HTTP book ticker (top of book)
+/// url.
+pub const HTTP_BOOK_TICKER_URL_BINANCE_SPOT_TESTNET: &str =
+    "https://testnet.binance.vision/api/v3/ticker/bookTicker";
+
+/// [`BinanceFuturesUsdTestnet`](super::super::futures::BinanceFuturesUsdTestnet) HTTP book
+/// ticker (top of book) url.
+pub const HTTP_BOOK_TICKER_URL_BINANCE_FUTURES_USD_TESTNET: &str =
+    "https://testnet.binancefuture.com/fapi/v1/ticker/bookTicker";
+
 /// [`Binance`](super::super::Binance) real-time OrderBook Level1 (top of book) message.
 ///
+/// ### Notes
+/// BinanceFuturesUsd payloads include the transaction time "T" & event time "E", whereas
+/// BinanceSpot payloads include neither. See [`BinanceOrderBookL1::exchange_time`].
+///
 /// ### Raw Payload Examples
 /// #### BinanceSpot OrderBookL1
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-book-ticker-streams>
@@ -28,12 +81,15 @@ use serde::{Deserialize, Serialize};
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
 /// ```json
 /// {
-///     "u":22606535573,
-///     "s":"ETHUSDT",
-///     "b":"1215.27000000",
-///     "B":"32.49110000",
-///     "a":"1215.28000000",
-///     "A":"13.93900000"
+///     "e":"bookTicker",
+///     "u":2286618712950,
+///     "s":"BTCUSDT",
+///     "b":"16858.90",
+///     "B":"13.692",
+///     "a":"16859.00",
+///     "A":"30.219",
+///     "T":1671621244670,
+///     "E":1671621244673
 /// }
 /// ```
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
@@ -41,13 +97,35 @@ pub struct BinanceOrderBookL1 {
     #[serde(alias = "s", deserialize_with = "de_ob_l1_subscription_id")]
     pub subscription_id: SubscriptionId,
     #[serde(alias = "b", deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_price: f64,
+    pub best_bid_price: Number,
     #[serde(alias = "B", deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_amount: f64,
+    pub best_bid_amount: Number,
     #[serde(alias = "a", deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_price: f64,
+    pub best_ask_price: Number,
     #[serde(alias = "A", deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_amount: f64,
+    pub best_ask_amount: Number,
+    /// Transaction time, only provided by BinanceFuturesUsd.
+    #[serde(
+        alias = "T",
+        default,
+        deserialize_with = "crate::de::de_option_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub transaction_time: Option<DateTime<Utc>>,
+    /// Event time, only provided by BinanceFuturesUsd.
+    #[serde(
+        alias = "E",
+        default,
+        deserialize_with = "crate::de::de_option_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub event_time: Option<DateTime<Utc>>,
+}
+
+impl BinanceOrderBookL1 {
+    /// Time the top of book was updated by the exchange, ie/ the transaction time "T", falling
+    /// back to the event time "E". `None` for BinanceSpot payloads, which include neither.
+    pub fn exchange_time(&self) -> Option<DateTime<Utc>> {
+        self.transaction_time.or(self.event_time)
+    }
 }
 
 impl Identifier<Option<SubscriptionId>> for BinanceOrderBookL1 {
@@ -58,23 +136,172 @@ impl Identifier<Option<SubscriptionId>> for BinanceOrderBookL1 {
 
 impl From<(ExchangeId, Instrument, BinanceOrderBookL1)> for MarketIter<OrderBookL1> {
     fn from((exchange_id, instrument, book): (ExchangeId, Instrument, BinanceOrderBookL1)) -> Self {
-        // Create timestamp to be used for all required time fields that are not present
-        let time_now = Utc::now();
+        let received_time = Utc::now();
+
+        // BinanceSpot payloads are not timestamped, so fall back to the received time
+        let (exchange_time, time_source) = match book.exchange_time() {
+            Some(exchange_time) => (exchange_time, TimeSource::Exchange),
+            None => (received_time, TimeSource::Receiver),
+        };
 
         Self(vec![Ok(MarketEvent {
-            exchange_time: time_now,
-            received_time: time_now,
+            exchange_time,
+            received_time,
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: None,
+            tag: None,
             kind: OrderBookL1 {
-                last_update_time: time_now,
+                last_update_time: exchange_time,
                 best_bid: Level::new(book.best_bid_price, book.best_bid_amount),
                 best_ask: Level::new(book.best_ask_price, book.best_ask_amount),
+                time_source,
             },
         })])
     }
 }
 
+/// [`Binance`] HTTP book ticker (top of book), as returned by the book ticker endpoint.
+///
+/// ### Notes
+/// Futures payloads include the transaction time "time", whereas spot payloads do not.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#symbol-order-book-ticker>
+/// ```json
+/// {
+///     "symbol": "LTCBTC",
+///     "bidPrice": "4.00000000",
+///     "bidQty": "431.00000000",
+///     "askPrice": "4.00000200",
+///     "askQty": "9.00000000"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceBookTicker {
+    pub symbol: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub bid_price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub bid_qty: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub ask_price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub ask_qty: Number,
+    #[serde(
+        default,
+        deserialize_with = "crate::de::de_option_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl From<(ExchangeId, Instrument, BinanceBookTicker)> for MarketEvent<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, ticker): (ExchangeId, Instrument, BinanceBookTicker),
+    ) -> Self {
+        let received_time = Utc::now();
+        let exchange_time = ticker.time.unwrap_or(received_time);
+
+        Self {
+            exchange_time,
+            received_time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: OrderBookL1 {
+                last_update_time: exchange_time,
+                best_bid: Level::new(ticker.bid_price, ticker.bid_qty),
+                best_ask: Level::new(ticker.ask_price, ticker.ask_qty),
+                time_source: TimeSource::Snapshot,
+            },
+        }
+    }
+}
+
+/// [`Binance`] book ticker endpoint response, which is a list for
+/// [`BinanceFuturesCoin`](super::super::futures::BinanceFuturesCoin).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+#[serde(untagged)]
+enum BinanceBookTickerResponse {
+    Single(BinanceBookTicker),
+    Many(Vec<BinanceBookTicker>),
+}
+
+/// [`Binance`] HTTP [`SnapshotClient`] fetching the top of book of [`OrderBooksL1`]
+/// [`Subscription`]s from the book ticker endpoint.
+///
+/// The `book_ticker_url` is injectable so an alternative server (eg/ a mock server) can be used.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BinanceOrderBookL1Client {
+    pub book_ticker_url: String,
+}
+
+impl BinanceOrderBookL1Client {
+    /// Construct a new [`Self`] using the provided book ticker endpoint
+    /// (eg/ "https://api.binance.com/api/v3/ticker/bookTicker").
+    pub fn new<S: Into<String>>(book_ticker_url: S) -> Self {
+        Self {
+            book_ticker_url: book_ticker_url.into(),
+        }
+    }
+
+    /// Construct a new [`Self`] using the book ticker endpoint of the provided [`ExchangeServer`].
+    pub fn server<Server: ExchangeServer>() -> Self {
+        match Server::ID {
+            ExchangeId::BinanceFuturesUsd => Self::new(HTTP_BOOK_TICKER_URL_BINANCE_FUTURES_USD),
+            ExchangeId::BinanceFuturesCoin => Self::new(HTTP_BOOK_TICKER_URL_BINANCE_FUTURES_COIN),
+            ExchangeId::BinanceFuturesUsdTestnet => {
+                Self::new(HTTP_BOOK_TICKER_URL_BINANCE_FUTURES_USD_TESTNET)
+            }
+            ExchangeId::BinanceSpotTestnet => Self::new(HTTP_BOOK_TICKER_URL_BINANCE_SPOT_TESTNET),
+            ExchangeId::BinanceUs => Self::new(HTTP_BOOK_TICKER_URL_BINANCE_US),
+            _ => Self::new(HTTP_BOOK_TICKER_URL_BINANCE_SPOT),
+        }
+    }
+}
+
+#[async_trait]
+impl<Server> SnapshotClient<Binance<Server>, OrderBooksL1> for BinanceOrderBookL1Client
+where
+    Server: ExchangeServer + Sync,
+{
+    async fn fetch(
+        &self,
+        subscription: &Subscription<Binance<Server>, OrderBooksL1>,
+    ) -> Result<MarketEvent<OrderBookL1>, DataError> {
+        let market = Identifier::<BinanceMarket>::id(subscription);
+        let url = format!("{}?symbol={}", self.book_ticker_url, market.as_ref());
+
+        let response = reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<BinanceBookTickerResponse>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        let ticker = match response {
+            BinanceBookTickerResponse::Single(ticker) => Some(ticker),
+            BinanceBookTickerResponse::Many(tickers) => tickers
+                .into_iter()
+                .find(|ticker| ticker.symbol == market.as_ref()),
+        }
+        .ok_or_else(|| {
+            DataError::Socket(SocketError::Unsupported {
+                entity: Server::ID.as_str(),
+                item: format!("book ticker of market {}", market.as_ref()),
+            })
+        })?;
+
+        Ok(MarketEvent::from((
+            Server::ID,
+            subscription.instrument.clone(),
+            ticker,
+        )))
+    }
+}
+
 /// Deserialize a [`BinanceOrderBookL1`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
 ///
 /// eg/ "@bookTicker|BTCUSDT"
@@ -89,6 +316,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
+    use barter_integration::model::InstrumentKind;
+    use chrono::TimeZone;
 
     mod de {
         use super::*;
@@ -115,10 +345,12 @@ mod tests {
                 "#,
                     expected: BinanceOrderBookL1 {
                         subscription_id: SubscriptionId::from("@bookTicker|ETHUSDT"),
-                        best_bid_price: 1215.27000000,
-                        best_bid_amount: 32.49110000,
-                        best_ask_price: 1215.28000000,
-                        best_ask_amount: 13.93900000,
+                        best_bid_price: from_f64(1215.27000000),
+                        best_bid_amount: from_f64(32.49110000),
+                        best_ask_price: from_f64(1215.28000000),
+                        best_ask_amount: from_f64(13.93900000),
+                        transaction_time: None,
+                        event_time: None,
                     },
                 },
                 TestCase {
@@ -137,10 +369,12 @@ mod tests {
                     }"#,
                     expected: BinanceOrderBookL1 {
                         subscription_id: SubscriptionId::from("@bookTicker|BTCUSDT"),
-                        best_bid_price: 16858.90,
-                        best_bid_amount: 13.692,
-                        best_ask_price: 16859.00,
-                        best_ask_amount: 30.219,
+                        best_bid_price: from_f64(16858.90),
+                        best_bid_amount: from_f64(13.692),
+                        best_ask_price: from_f64(16859.00),
+                        best_ask_amount: from_f64(30.219),
+                        transaction_time: Some(Utc.timestamp_millis_opt(1671621244670).unwrap()),
+                        event_time: Some(Utc.timestamp_millis_opt(1671621244673).unwrap()),
                     },
                 },
             ];
@@ -155,4 +389,144 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_binance_order_book_l1_time_source() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<(DateTime<Utc>, TimeSource)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Spot payload is not timestamped, so the received time is used
+                input: r#"{"u":22606535573,"s":"ETHUSDT","b":"1215.27","B":"32.49","a":"1215.28","A":"13.93"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC1: FuturePerpetual payload uses the transaction time "T"
+                input: r#"{"e":"bookTicker","u":2286618712950,"s":"BTCUSDT","b":"16858.90","B":"13.692","a":"16859.00","A":"30.219","T":1671621244670,"E":1671621244673}"#,
+                expected: Some((
+                    Utc.timestamp_millis_opt(1671621244670).unwrap(),
+                    TimeSource::Exchange,
+                )),
+            },
+            TestCase {
+                // TC2: FuturePerpetual payload without "T" falls back to the event time "E"
+                input: r#"{"e":"bookTicker","u":2286618712950,"s":"BTCUSDT","b":"16858.90","B":"13.692","a":"16859.00","A":"30.219","E":1671621244673}"#,
+                expected: Some((
+                    Utc.timestamp_millis_opt(1671621244673).unwrap(),
+                    TimeSource::Exchange,
+                )),
+            },
+        ];
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let book = serde_json::from_str::<BinanceOrderBookL1>(test.input).unwrap();
+            let event = MarketIter::<OrderBookL1>::from((
+                ExchangeId::BinanceFuturesUsd,
+                instrument.clone(),
+                book,
+            ))
+            .0
+            .remove(0)
+            .unwrap();
+
+            // last_update_time is always consistent with the MarketEvent exchange_time
+            assert_eq!(event.kind.last_update_time, event.exchange_time);
+
+            match test.expected {
+                Some(expected) => {
+                    let actual = (event.exchange_time, event.kind.time_source);
+                    assert_eq!(actual, expected, "TC{} failed", index);
+                }
+                None => {
+                    assert_eq!(
+                        event.exchange_time, event.received_time,
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(
+                        event.kind.time_source,
+                        TimeSource::Receiver,
+                        "TC{} failed",
+                        index
+                    );
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_order_book_l1_client_from_mock_server() {
+        use crate::{
+            exchange::binance::{futures::BinanceFuturesCoin, spot::BinanceSpot},
+            streams::backfill::tests::mock_http_server,
+        };
+
+        // BinanceSpot book ticker is not timestamped, so falls back to the received time
+        let (url, request) = mock_http_server(
+            r#"{"symbol":"BTCUSDT","bidPrice":"16858.90","bidQty":"13.692","askPrice":"16859.00","askQty":"30.219"}"#,
+        )
+        .await;
+        let spot = BinanceOrderBookL1Client::new(format!("{url}/api/v3/ticker/bookTicker"))
+            .fetch(&Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                OrderBooksL1,
+            )))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            spot.kind,
+            OrderBookL1 {
+                last_update_time: spot.received_time,
+                best_bid: Level::new(from_f64(16858.90), from_f64(13.692)),
+                best_ask: Level::new(from_f64(16859.00), from_f64(30.219)),
+                time_source: TimeSource::Snapshot,
+            }
+        );
+        assert_eq!(spot.exchange_time, spot.received_time);
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("GET /api/v3/ticker/bookTicker?symbol=BTCUSDT "));
+
+        // BinanceFuturesCoin book ticker is a timestamped list
+        let (url, request) = mock_http_server(
+            r#"[{"lastUpdateId":1027024,"symbol":"BTCUSD_PERP","pair":"BTCUSD","bidPrice":"37012.4","bidQty":"1540","askPrice":"37012.5","askQty":"311","time":1700000000095}]"#,
+        )
+        .await;
+        let futures = BinanceOrderBookL1Client::new(format!("{url}/dapi/v1/ticker/bookTicker"))
+            .fetch(&Subscription::from((
+                BinanceFuturesCoin::default(),
+                "btc",
+                "usd",
+                InstrumentKind::FuturePerpetual,
+                OrderBooksL1,
+            )))
+            .await
+            .unwrap();
+
+        let time = Utc.timestamp_millis_opt(1700000000095).unwrap();
+        assert_eq!(
+            futures.kind,
+            OrderBookL1 {
+                last_update_time: time,
+                best_bid: Level::new(from_f64(37012.4), from_f64(1540.0)),
+                best_ask: Level::new(from_f64(37012.5), from_f64(311.0)),
+                time_source: TimeSource::Snapshot,
+            }
+        );
+        assert_eq!(futures.exchange_time, time);
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("GET /dapi/v1/ticker/bookTicker?symbol=BTCUSD_PERP "));
+    }
 }
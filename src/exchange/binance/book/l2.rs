@@ -1,11 +1,15 @@
 use super::super::channel::BinanceChannel;
 use super::BinanceLevel;
 use crate::{
+    error::DataError,
     exchange::subscription::ExchangeSub,
     subscription::book::{OrderBook, OrderBookSide},
     Identifier,
 };
-use barter_integration::model::{Side, SubscriptionId};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, Side, SubscriptionId},
+};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
@@ -58,10 +62,67 @@ impl From<BinanceOrderBookL2Snapshot> for OrderBook {
             last_update_time: Utc::now(),
             bids: OrderBookSide::new(Side::Buy, snapshot.bids),
             asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+            first_update_id: None,
+            last_update_id: Some(snapshot.last_update_id),
         }
     }
 }
 
+/// Fetch a [`BinanceOrderBookL2Snapshot`] for the provided [`Instrument`] via HTTP from the
+/// provided depth endpoint (eg/ "https://api.binance.com/api/v3/depth").
+///
+/// The `snapshot_url` is injectable so an alternative server (eg/ a mock server) can be used.
+pub async fn fetch_snapshot(
+    snapshot_url: &str,
+    instrument: &Instrument,
+) -> Result<BinanceOrderBookL2Snapshot, DataError> {
+    // Construct initial OrderBook snapshot GET url
+    let snapshot_url = format!(
+        "{}?symbol={}{}&limit=100",
+        snapshot_url,
+        instrument.base.as_ref().to_uppercase(),
+        instrument.quote.as_ref().to_uppercase()
+    );
+
+    // Fetch initial OrderBook snapshot via HTTP
+    reqwest::get(snapshot_url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BinanceOrderBookL2Snapshot>()
+        .await
+        .map_err(SocketError::Http)
+        .map_err(DataError::from)
+}
+
+/// [`Binance`](super::super::Binance) combined stream message, wrapping each OrderBook Level2
+/// diff depth message with the associated stream name (eg/ "btcusdt@depth@500ms").
+///
+/// Diff depth messages do not contain the [`UpdateSpeed`](super::UpdateSpeed) they were
+/// subscribed with, so the [`SubscriptionId`] is derived from the stream name.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+/// ```json
+/// {
+///     "stream": "ethusdt@depth@100ms",
+///     "data": {
+///         "e": "depthUpdate",
+///         "E": 1671656397761,
+///         "s": "ETHUSDT",
+///         "U": 22611425143,
+///         "u": 22611425151,
+///         "b": [["1209.67000000", "85.48210000"]],
+///         "a": []
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize)]
+pub struct BinanceCombinedStream<T> {
+    #[serde(deserialize_with = "super::snapshot::de_stream_subscription_id")]
+    pub stream: SubscriptionId,
+    pub data: T,
+}
+
 /// Deserialize a
 /// [`BinanceSpotOrderBookL2Delta`](super::super::spot::l2::BinanceSpotOrderBookL2Delta) or
 /// [`BinanceFuturesOrderBookL2Delta`](super::super::futures::l2::BinanceFuturesOrderBookL2Delta)
@@ -77,8 +138,65 @@ where
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
+
+    /// Spawn a mock HTTP server that responds to a single request with the provided JSON `body`,
+    /// returning the base url of the server.
+    pub(crate) fn mock_snapshot_server(body: &'static str) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/depth", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        url
+    }
+
+    #[tokio::test]
+    async fn test_fetch_snapshot() {
+        let url = mock_snapshot_server(
+            r#"{"lastUpdateId": 100, "bids": [["4.0", "431.0"]], "asks": [["4.1", "12.0"]]}"#,
+        );
+
+        let actual = fetch_snapshot(
+            &url,
+            &Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::InstrumentKind::Spot,
+            )),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            actual,
+            BinanceOrderBookL2Snapshot {
+                last_update_id: 100,
+                bids: vec![BinanceLevel {
+                    price: from_f64(4.0),
+                    amount: from_f64(431.0)
+                }],
+                asks: vec![BinanceLevel {
+                    price: from_f64(4.1),
+                    amount: from_f64(12.0)
+                }],
+            }
+        );
+    }
 
     mod de {
         use super::*;
@@ -113,12 +231,12 @@ mod tests {
                     expected: BinanceOrderBookL2Snapshot {
                         last_update_id: 1027024,
                         bids: vec![BinanceLevel {
-                            price: 4.0,
-                            amount: 431.0,
+                            price: from_f64(4.0),
+                            amount: from_f64(431.0),
                         }],
                         asks: vec![BinanceLevel {
-                            price: 4.00000200,
-                            amount: 12.0,
+                            price: from_f64(4.00000200),
+                            amount: from_f64(12.0),
                         }],
                     },
                 },
@@ -146,12 +264,12 @@ mod tests {
                     expected: BinanceOrderBookL2Snapshot {
                         last_update_id: 1027024,
                         bids: vec![BinanceLevel {
-                            price: 4.0,
-                            amount: 431.0,
+                            price: from_f64(4.0),
+                            amount: from_f64(431.0),
                         }],
                         asks: vec![BinanceLevel {
-                            price: 4.00000200,
-                            amount: 12.0,
+                            price: from_f64(4.00000200),
+                            amount: from_f64(12.0),
                         }],
                     },
                 },
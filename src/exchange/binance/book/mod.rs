@@ -1,4 +1,4 @@
-use crate::subscription::book::Level;
+use crate::subscription::{book::Level, number::Number};
 use serde::{Deserialize, Serialize};
 
 /// Level 1 OrderBook types (top of book).
@@ -7,6 +7,9 @@ pub mod l1;
 /// Level 2 OrderBook types (top of book).
 pub mod l2;
 
+/// Partial book depth (top-N snapshot) types.
+pub mod snapshot;
+
 /// [`Binance`](super::Binance) OrderBook level.
 ///
 /// #### Raw Payload Examples
@@ -17,9 +20,66 @@ pub mod l2;
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BinanceLevel {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
+}
+
+/// [`Binance`](super::Binance) OrderBook Level2 diff depth stream update speed, configured via
+/// [`Binance::with_update_speed`](super::Binance::with_update_speed).
+///
+/// ### Notes
+/// - [`BinanceSpot`](super::spot::BinanceSpot) supports [`Self::Ms100`] & [`Self::Ms1000`].
+/// - [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) supports [`Self::Ms0`],
+///   [`Self::Ms100`], [`Self::Ms250`] & [`Self::Ms500`].
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateSpeed {
+    /// Real-time updates, [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) only.
+    Ms0,
+    #[default]
+    Ms100,
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) only.
+    Ms250,
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) only.
+    Ms500,
+    /// [`BinanceSpot`](super::spot::BinanceSpot) only.
+    Ms1000,
+}
+
+impl UpdateSpeed {
+    /// [`UpdateSpeed`]s supported by the spot servers.
+    pub const SPOT: [UpdateSpeed; 2] = [UpdateSpeed::Ms100, UpdateSpeed::Ms1000];
+
+    /// [`UpdateSpeed`]s supported by the futures servers.
+    pub const FUTURES: [UpdateSpeed; 4] = [
+        UpdateSpeed::Ms0,
+        UpdateSpeed::Ms100,
+        UpdateSpeed::Ms250,
+        UpdateSpeed::Ms500,
+    ];
+
+    /// Interval between each update in milliseconds.
+    pub fn millis(&self) -> u64 {
+        match self {
+            UpdateSpeed::Ms0 => 0,
+            UpdateSpeed::Ms100 => 100,
+            UpdateSpeed::Ms250 => 250,
+            UpdateSpeed::Ms500 => 500,
+            UpdateSpeed::Ms1000 => 1000,
+        }
+    }
+}
+
+impl std::fmt::Display for UpdateSpeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}ms", self.millis())
+    }
 }
 
 impl From<BinanceLevel> for Level {
@@ -34,6 +94,7 @@ impl From<BinanceLevel> for Level {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
@@ -44,8 +105,8 @@ mod tests {
             assert_eq!(
                 serde_json::from_str::<BinanceLevel>(input).unwrap(),
                 BinanceLevel {
-                    price: 4.00000200,
-                    amount: 12.0
+                    price: from_f64(4.00000200),
+                    amount: from_f64(12.0)
                 },
             )
         }
@@ -0,0 +1,233 @@
+use super::BinanceLevel;
+use crate::{
+    de::datetime_utc_from_epoch_ms,
+    event::{MarketEvent, MarketIter},
+    exchange::{subscription::ExchangeSub, ExchangeId},
+    subscription::book::{Level, OrderBookSnapshot},
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::super::Binance) partial book depth (top-N snapshot) combined stream message.
+///
+/// Partial book depth messages sent by [`BinanceSpot`](super::super::spot::BinanceSpot) do not
+/// contain the market, so these streams are consumed via the combined stream endpoint which
+/// wraps each message with the associated stream name.
+///
+/// ### Raw Payload Examples
+/// #### BinanceSpot OrderBookSnapshot
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+/// ```json
+/// {
+///     "stream": "btcusdt@depth5@100ms",
+///     "data": {
+///         "lastUpdateId": 160,
+///         "bids": [["0.0024", "10"]],
+///         "asks": [["0.0026", "100"]]
+///     }
+/// }
+/// ```
+///
+/// #### BinanceFuturesUsd OrderBookSnapshot
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+/// ```json
+/// {
+///     "stream": "btcusdt@depth5@100ms",
+///     "data": {
+///         "e": "depthUpdate",
+///         "E": 1571889248277,
+///         "T": 1571889248276,
+///         "s": "BTCUSDT",
+///         "U": 390497796,
+///         "u": 390497878,
+///         "pu": 390497794,
+///         "b": [["7403.89", "0.002"]],
+///         "a": [["7405.96", "3.340"]]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderBookSnapshot {
+    #[serde(rename = "stream", deserialize_with = "de_stream_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub data: BinanceOrderBookSnapshotData,
+}
+
+/// [`Binance`](super::super::Binance) partial book depth (top-N snapshot) data.
+///
+/// See [`BinanceOrderBookSnapshot`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderBookSnapshotData {
+    /// Transaction time, only provided by
+    /// [`BinanceFuturesUsd`](super::super::futures::BinanceFuturesUsd).
+    #[serde(rename = "T", default)]
+    pub time: Option<u64>,
+    #[serde(alias = "b")]
+    pub bids: Vec<BinanceLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<BinanceLevel>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceOrderBookSnapshot {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceOrderBookSnapshot)> for MarketIter<OrderBookSnapshot> {
+    fn from(
+        (exchange_id, instrument, snapshot): (ExchangeId, Instrument, BinanceOrderBookSnapshot),
+    ) -> Self {
+        let received_time = Utc::now();
+        let exchange_time = snapshot
+            .data
+            .time
+            .and_then(datetime_utc_from_epoch_ms)
+            .unwrap_or(received_time);
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time,
+            received_time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: OrderBookSnapshot {
+                last_update_time: exchange_time,
+                bids: snapshot.data.bids.into_iter().map(Level::from).collect(),
+                asks: snapshot.data.asks.into_iter().map(Level::from).collect(),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceOrderBookSnapshot`] "stream" (eg/ "btcusdt@depth5@100ms") as the
+/// associated [`SubscriptionId`].
+///
+/// eg/ "@depth5@100ms|BTCUSDT"
+pub fn de_stream_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let stream = <&str as Deserialize>::deserialize(deserializer)?;
+
+    stream
+        .split_once('@')
+        .map(|(market, channel)| {
+            ExchangeSub::from((format!("@{channel}"), market.to_uppercase())).id()
+        })
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(stream),
+                &"Binance stream name in the format {market}@{channel}",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_binance_order_book_snapshot() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceOrderBookSnapshot, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid BinanceSpot OrderBookSnapshot
+                    input: r#"
+                    {
+                        "stream": "btcusdt@depth5@100ms",
+                        "data": {
+                            "lastUpdateId": 160,
+                            "bids": [["0.0024", "10"]],
+                            "asks": [["0.0026", "100"]]
+                        }
+                    }
+                    "#,
+                    expected: Ok(BinanceOrderBookSnapshot {
+                        subscription_id: SubscriptionId::from("@depth5@100ms|BTCUSDT"),
+                        data: BinanceOrderBookSnapshotData {
+                            time: None,
+                            bids: vec![BinanceLevel {
+                                price: from_f64(0.0024),
+                                amount: from_f64(10.0),
+                            }],
+                            asks: vec![BinanceLevel {
+                                price: from_f64(0.0026),
+                                amount: from_f64(100.0),
+                            }],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: valid BinanceFuturesUsd OrderBookSnapshot
+                    input: r#"
+                    {
+                        "stream": "ethusdt@depth20@100ms",
+                        "data": {
+                            "e": "depthUpdate",
+                            "E": 1571889248277,
+                            "T": 1571889248276,
+                            "s": "ETHUSDT",
+                            "U": 390497796,
+                            "u": 390497878,
+                            "pu": 390497794,
+                            "b": [["7403.89", "0.002"]],
+                            "a": [["7405.96", "3.340"]]
+                        }
+                    }
+                    "#,
+                    expected: Ok(BinanceOrderBookSnapshot {
+                        subscription_id: SubscriptionId::from("@depth20@100ms|ETHUSDT"),
+                        data: BinanceOrderBookSnapshotData {
+                            time: Some(1571889248276),
+                            bids: vec![BinanceLevel {
+                                price: from_f64(7403.89),
+                                amount: from_f64(0.002),
+                            }],
+                            asks: vec![BinanceLevel {
+                                price: from_f64(7405.96),
+                                amount: from_f64(3.34),
+                            }],
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid stream name w/o channel
+                    input: r#"{"stream": "btcusdt", "data": {"bids": [], "asks": []}}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceOrderBookSnapshot>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,1245 @@
+use super::{channel::BinanceKlineInterval, market::BinanceMarket, Binance};
+use crate::{
+    de::datetime_utc_from_epoch_ms,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeServer},
+    streams::backfill::{candle_backfill_key, latest_closed_candles, BackfillClient, BackfillKey},
+    subscription::{
+        candle::{Candle, CandleSource, Candles, ClosedCandles, VolumeDenomination},
+        number::Number,
+        Subscription,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{Exchange, Instrument, SubscriptionId},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP klines (candles) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+pub const HTTP_KLINES_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/klines";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP klines (candles) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-data>
+pub const HTTP_KLINES_URL_BINANCE_FUTURES_USD: &str = "https://fapi.binance.com/fapi/v1/klines";
+
+/// [`BinanceFuturesCoin`](super::futures::BinanceFuturesCoin) HTTP klines (candles) url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#kline-candlestick-data>
+pub const HTTP_KLINES_URL_BINANCE_FUTURES_COIN: &str = "https://dapi.binance.com/dapi/v1/klines";
+
+/// [`BinanceUs`](super::spot::BinanceUs) HTTP klines (candles) url.
+///
+/// See docs: <https://docs.binance.us/#get-candlestick-data>
+pub const HTTP_KLINES_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/klines";
+
+/// [`BinanceSpotTestnet`](super::spot::BinanceSpotTestnet) HTTP klines (candles) url.
+pub const HTTP_KLINES_URL_BINANCE_SPOT_TESTNET: &str =
+    "https://testnet.binance.vision/api/v3/klines";
+
+/// [`BinanceFuturesUsdTestnet`](super::futures::BinanceFuturesUsdTestnet) HTTP klines (candles)
+/// url.
+pub const HTTP_KLINES_URL_BINANCE_FUTURES_USD_TESTNET: &str =
+    "https://testnet.binancefuture.com/fapi/v1/klines";
+
+/// Maximum number of klines [`Binance`](super::Binance) returns from a single HTTP request.
+pub const HTTP_KLINES_MAX_LIMIT_BINANCE: usize = 1000;
+
+/// [`Binance`](super::Binance) real-time kline (candle) message.
+///
+/// ### Raw Payload Examples
+/// #### BinanceSpot Kline
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 123456789,
+///     "s": "BNBBTC",
+///     "k": {
+///         "t": 123400000,
+///         "T": 123460000,
+///         "s": "BNBBTC",
+///         "i": "1m",
+///         "f": 100,
+///         "L": 200,
+///         "o": "0.0010",
+///         "c": "0.0020",
+///         "h": "0.0025",
+///         "l": "0.0015",
+///         "v": "1000",
+///         "n": 100,
+///         "x": false,
+///         "q": "1.0000",
+///         "V": "500",
+///         "Q": "0.500",
+///         "B": "123456"
+///     }
+/// }
+/// ```
+///
+/// #### BinanceFuturesUsd Kline
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 1672515782136,
+///     "s": "BTCUSDT",
+///     "k": {
+///         "t": 1672515780000,
+///         "T": 1672515839999,
+///         "s": "BTCUSDT",
+///         "i": "1m",
+///         "f": 3283120165,
+///         "L": 3283120203,
+///         "o": "16541.60",
+///         "c": "16541.90",
+///         "h": "16542.00",
+///         "l": "16541.60",
+///         "v": "5.344",
+///         "n": 39,
+///         "x": false,
+///         "q": "88400.69200",
+///         "V": "3.932",
+///         "Q": "65043.09090",
+///         "B": "0"
+///     }
+/// }
+/// ```
+///
+/// #### BinanceFuturesUsd Continuous Kline
+/// Continuous klines identify the market using the "ps" (pair) field rather than "s" (symbol),
+/// and include the "ct" contract type.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-streams>
+/// ```json
+/// {
+///     "e": "continuous_kline",
+///     "E": 1607443058651,
+///     "ps": "BTCUSDT",
+///     "ct": "PERPETUAL",
+///     "k": { ... }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCandle {
+    #[serde(alias = "s", alias = "ps")]
+    pub symbol: String,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "k")]
+    pub candle: BinanceCandleInner,
+}
+
+/// [`Binance`](super::Binance) kline data contained within a [`BinanceCandle`] message.
+///
+/// See [`BinanceCandle`] for full raw payload examples.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceCandleInner {
+    #[serde(
+        alias = "t",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub start: DateTime<Utc>,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub end: DateTime<Utc>,
+    #[serde(alias = "i")]
+    pub interval: String,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: Number,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: Number,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: Number,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: Number,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quote_volume: Number,
+    #[serde(alias = "V", deserialize_with = "barter_integration::de::de_str")]
+    pub taker_buy_volume: Number,
+    #[serde(alias = "Q", deserialize_with = "barter_integration::de::de_str")]
+    pub taker_buy_quote_volume: Number,
+    #[serde(alias = "n")]
+    pub trades: u64,
+    #[serde(alias = "x")]
+    pub closed: bool,
+}
+
+/// Derives the [`SubscriptionId`] from the kline "i" interval, matching the interval specific
+/// [`BinanceChannel::candles`](super::channel::BinanceChannel::candles) channel (eg/ "@kline_1m")
+/// used to key the instrument [`Map`](crate::subscription::Map) when subscribing. This ensures
+/// multiple intervals of the same market on one connection are routed to their own
+/// [`Subscription`].
+impl Identifier<Option<SubscriptionId>> for BinanceCandle {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(SubscriptionId(format!(
+            "@kline_{}|{}",
+            self.candle.interval, self.symbol
+        )))
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceCandle)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candle): (ExchangeId, Instrument, BinanceCandle)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: candle.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: denominate(
+                exchange_id,
+                Candle {
+                    start_time: candle.candle.start,
+                    close_time: candle.candle.end,
+                    open: candle.candle.open,
+                    high: candle.candle.high,
+                    low: candle.candle.low,
+                    close: candle.candle.close,
+                    volume: candle.candle.volume,
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: Some(candle.candle.volume),
+                    quote_volume: Some(candle.candle.quote_volume),
+                    taker_buy_volume: Some(candle.candle.taker_buy_volume),
+                    taker_buy_quote_volume: Some(candle.candle.taker_buy_quote_volume),
+                    trade_count: candle.candle.trades,
+                    closed: candle.candle.closed,
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: false,
+                },
+            ),
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, ClosedCandles<BinanceCandle>)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, ClosedCandles(candles)): (
+            ExchangeId,
+            Instrument,
+            ClosedCandles<BinanceCandle>,
+        ),
+    ) -> Self {
+        Self::from((exchange_id, instrument, candles)).retain_closed()
+    }
+}
+
+/// [`Binance`](super::Binance) HTTP kline (candle), as returned by the klines endpoint.
+///
+/// ### Raw Payload Examples
+/// Format: \[open_time, o, h, l, c, v, close_time, q, n, V, Q, ignore\]
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+/// ```json
+/// [
+///     1499040000000,
+///     "0.01634790",
+///     "0.80000000",
+///     "0.01575800",
+///     "0.01577100",
+///     "148976.11427815",
+///     1499644799999,
+///     "2434.19055334",
+///     308,
+///     "1756.87402397",
+///     "28.46694368",
+///     "0"
+/// ]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BinanceKline {
+    pub start: DateTime<Utc>,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
+    pub end: DateTime<Utc>,
+    pub quote_volume: Number,
+    pub trades: u64,
+    pub taker_buy_volume: Number,
+    pub taker_buy_quote_volume: Number,
+}
+
+impl<'de> serde::Deserialize<'de> for BinanceKline {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BinanceKline;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BinanceKline struct from the Binance HTTP API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // BinanceKline Sequence Format:
+                // [open_time, o, h, l, c, v, close_time, q, n, V, Q, ignore]
+                // <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-data>
+                let de_time = |epoch: u64| -> Result<DateTime<Utc>, SeqAccessor::Error> {
+                    datetime_utc_from_epoch_ms(epoch).ok_or_else(|| {
+                        serde::de::Error::custom(format!(
+                            "epoch {epoch} out of DateTime<Utc> range"
+                        ))
+                    })
+                };
+                let de_number = |value: String| -> Result<Number, SeqAccessor::Error> {
+                    value.parse().map_err(serde::de::Error::custom)
+                };
+
+                let start = de_time(extract_next(&mut seq, "open_time")?)?;
+                let open = de_number(extract_next(&mut seq, "open")?)?;
+                let high = de_number(extract_next(&mut seq, "high")?)?;
+                let low = de_number(extract_next(&mut seq, "low")?)?;
+                let close = de_number(extract_next(&mut seq, "close")?)?;
+                let volume = de_number(extract_next(&mut seq, "volume")?)?;
+                let end = de_time(extract_next(&mut seq, "close_time")?)?;
+                let quote_volume = de_number(extract_next(&mut seq, "quote_volume")?)?;
+                let trades = extract_next(&mut seq, "trades")?;
+                let taker_buy_volume = de_number(extract_next(&mut seq, "taker_buy_volume")?)?;
+                let taker_buy_quote_volume =
+                    de_number(extract_next(&mut seq, "taker_buy_quote_volume")?)?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BinanceKline {
+                    start,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    end,
+                    quote_volume,
+                    trades,
+                    taker_buy_volume,
+                    taker_buy_quote_volume,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BinanceKline
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceKline)> for MarketEvent<Candle> {
+    fn from((exchange_id, instrument, kline): (ExchangeId, Instrument, BinanceKline)) -> Self {
+        Self {
+            exchange_time: kline.end,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: denominate(
+                exchange_id,
+                Candle {
+                    start_time: kline.start,
+                    close_time: kline.end,
+                    open: kline.open,
+                    high: kline.high,
+                    low: kline.low,
+                    close: kline.close,
+                    volume: kline.volume,
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: Some(kline.volume),
+                    quote_volume: Some(kline.quote_volume),
+                    taker_buy_volume: Some(kline.taker_buy_volume),
+                    taker_buy_quote_volume: Some(kline.taker_buy_quote_volume),
+                    trade_count: kline.trades,
+                    closed: kline.end < Utc::now(),
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: false,
+                },
+            ),
+        }
+    }
+}
+
+/// Re-denominate the volumes of a [`Candle`] built from base denominated Binance kline fields.
+///
+/// [`BinanceFuturesCoin`](ExchangeId::BinanceFuturesCoin) klines report `v` & `V` in contracts
+/// and `q` & `Q` in the base asset, so there is no quote denominated volume.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#kline-candlestick-streams>
+fn denominate(exchange_id: ExchangeId, candle: Candle) -> Candle {
+    match exchange_id {
+        ExchangeId::BinanceFuturesCoin => Candle {
+            volume_denomination: VolumeDenomination::Contracts,
+            base_volume: candle.quote_volume,
+            quote_volume: None,
+            taker_buy_volume: candle.taker_buy_quote_volume,
+            taker_buy_quote_volume: None,
+            ..candle
+        },
+        _ => candle,
+    }
+}
+
+/// [`Binance`](super::Binance) HTTP klines [`BackfillClient`] used to backfill [`Candles`]
+/// streams.
+///
+/// The `klines_url` is injectable so an alternative server (eg/ a mock server) can be used.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BinanceCandlesClient {
+    pub klines_url: String,
+}
+
+impl BinanceCandlesClient {
+    /// Construct a new [`Self`] using the provided klines endpoint
+    /// (eg/ "https://api.binance.com/api/v3/klines").
+    pub fn new<S: Into<String>>(klines_url: S) -> Self {
+        Self {
+            klines_url: klines_url.into(),
+        }
+    }
+
+    /// Construct a new [`Self`] using the klines endpoint of the provided [`ExchangeServer`].
+    pub fn server<Server: ExchangeServer>() -> Self {
+        match Server::ID {
+            ExchangeId::BinanceFuturesUsd => Self::new(HTTP_KLINES_URL_BINANCE_FUTURES_USD),
+            ExchangeId::BinanceFuturesCoin => Self::new(HTTP_KLINES_URL_BINANCE_FUTURES_COIN),
+            ExchangeId::BinanceFuturesUsdTestnet => {
+                Self::new(HTTP_KLINES_URL_BINANCE_FUTURES_USD_TESTNET)
+            }
+            ExchangeId::BinanceSpotTestnet => Self::new(HTTP_KLINES_URL_BINANCE_SPOT_TESTNET),
+            ExchangeId::BinanceUs => Self::new(HTTP_KLINES_URL_BINANCE_US),
+            _ => Self::new(HTTP_KLINES_URL_BINANCE_SPOT),
+        }
+    }
+}
+
+#[async_trait]
+impl<Server> BackfillClient<Binance<Server>, Candles> for BinanceCandlesClient
+where
+    Server: ExchangeServer + Sync,
+{
+    async fn fetch(
+        &self,
+        subscription: &Subscription<Binance<Server>, Candles>,
+        limit: usize,
+    ) -> Result<Vec<MarketEvent<Candle>>, DataError> {
+        let interval =
+            BinanceKlineInterval::try_from(subscription.kind.0).map_err(SocketError::from)?;
+
+        // Request an additional kline since the latest is usually still in-progress
+        let url = format!(
+            "{}?symbol={}&interval={}&limit={}",
+            self.klines_url,
+            Identifier::<BinanceMarket>::id(subscription).as_ref(),
+            interval.code,
+            limit.saturating_add(1).min(HTTP_KLINES_MAX_LIMIT_BINANCE)
+        );
+
+        let klines = reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<Vec<BinanceKline>>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        let candles = klines
+            .into_iter()
+            .map(|kline| MarketEvent::from((Server::ID, subscription.instrument.clone(), kline)))
+            .collect();
+
+        Ok(latest_closed_candles(candles, limit))
+    }
+
+    fn key(&self, event: &MarketEvent<Candle>) -> BackfillKey {
+        candle_backfill_key(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use crate::subscription::Interval;
+
+    mod de {
+        use super::*;
+        use crate::{
+            exchange::binance::spot::BinanceSpot,
+            test_util::{parse_frame, Fixtures},
+        };
+        use barter_integration::de::datetime_utc_from_epoch_duration;
+        use barter_integration::error::SocketError;
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_kline() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceKline, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid kline
+                    input: r#"
+                    [
+                        1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100",
+                        "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+                        "28.46694368", "0"
+                    ]
+                    "#,
+                    expected: Ok(BinanceKline {
+                        start: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1499040000000,
+                        )),
+                        open: from_f64(0.01634790),
+                        high: from_f64(0.80000000),
+                        low: from_f64(0.01575800),
+                        close: from_f64(0.01577100),
+                        volume: from_f64(148976.11427815),
+                        end: datetime_utc_from_epoch_duration(Duration::from_millis(1499644799999)),
+                        quote_volume: from_f64(2434.19055334),
+                        trades: 308,
+                        taker_buy_volume: from_f64(1756.87402397),
+                        taker_buy_quote_volume: from_f64(28.46694368),
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid kline w/ non-numeric open
+                    input: r#"
+                    [
+                        1499040000000, "gibberish", "0.80000000", "0.01575800", "0.01577100",
+                        "148976.11427815", 1499644799999, "2434.19055334", 308, "1756.87402397",
+                        "28.46694368", "0"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceKline>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_binance_candle() {
+            struct TestCase {
+                fixture: &'static str,
+                expected: Result<BinanceCandle, SocketError>,
+            }
+
+            let fixtures = Fixtures::load("binance", "candles");
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Spot kline valid
+                    fixture: "spot_kline",
+                    expected: Ok(BinanceCandle {
+                        symbol: "BNBBTC".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672516000000,
+                        )),
+                        candle: BinanceCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515960000,
+                            )),
+                            end: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672516019999,
+                            )),
+                            interval: "1m".to_string(),
+                            open: from_f64(0.0010),
+                            close: from_f64(0.0020),
+                            high: from_f64(0.0025),
+                            low: from_f64(0.0015),
+                            volume: from_f64(1000.0),
+                            quote_volume: from_f64(1.0000),
+                            taker_buy_volume: from_f64(500.0),
+                            taker_buy_quote_volume: from_f64(0.500),
+                            trades: 100,
+                            closed: false,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: FuturePerpetual kline valid
+                    fixture: "futures_kline_closed",
+                    expected: Ok(BinanceCandle {
+                        symbol: "BTCUSDT".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        candle: BinanceCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515780000,
+                            )),
+                            end: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515839999,
+                            )),
+                            interval: "1m".to_string(),
+                            open: from_f64(16541.60),
+                            close: from_f64(16541.90),
+                            high: from_f64(16542.00),
+                            low: from_f64(16541.60),
+                            volume: from_f64(5.344),
+                            quote_volume: from_f64(88400.69200),
+                            taker_buy_volume: from_f64(3.932),
+                            taker_buy_quote_volume: from_f64(65043.09090),
+                            trades: 39,
+                            closed: true,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: FuturePerpetual continuous kline valid w/ "ps" pair field
+                    fixture: "futures_continuous_kline",
+                    expected: Ok(BinanceCandle {
+                        symbol: "BTCUSDT".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1607443058651,
+                        )),
+                        candle: BinanceCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1607443020000,
+                            )),
+                            end: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1607443079999,
+                            )),
+                            interval: "1m".to_string(),
+                            open: from_f64(18787.00),
+                            close: from_f64(18804.04),
+                            high: from_f64(18804.04),
+                            low: from_f64(18786.54),
+                            volume: from_f64(197.664),
+                            quote_volume: from_f64(3715253.19494),
+                            taker_buy_volume: from_f64(184.769),
+                            taker_buy_quote_volume: from_f64(3472925.84746),
+                            trades: 543,
+                            closed: false,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC3: kline malformed w/ non-numeric open price
+                    fixture: "malformed_open",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC4: kline w/ second resolution epochs converted from seconds
+                    fixture: "kline_epoch_seconds",
+                    expected: Ok(BinanceCandle {
+                        symbol: "BTCUSDT".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs(1672515782)),
+                        candle: BinanceCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1672515780,
+                            )),
+                            end: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672515839999,
+                            )),
+                            interval: "1m".to_string(),
+                            open: from_f64(16541.60),
+                            close: from_f64(16541.90),
+                            high: from_f64(16542.00),
+                            low: from_f64(16541.60),
+                            volume: from_f64(5.344),
+                            quote_volume: from_f64(88400.69200),
+                            taker_buy_volume: from_f64(3.932),
+                            taker_buy_quote_volume: from_f64(65043.09090),
+                            trades: 39,
+                            closed: true,
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC5: truncated kline frame
+                    fixture: "malformed_truncated",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC6: kline malformed w/ string event time & trade count
+                    fixture: "malformed_wrong_types",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = parse_frame::<BinanceSpot, BinanceCandle>(fixtures.get(test.fixture));
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (
+                        Err(SocketError::Deserialise { .. }),
+                        Err(SocketError::Deserialise { .. }),
+                    ) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_candle_to_market_iter_time_fields() {
+        let input = r#"
+        {
+            "e": "kline", "E": 1672515782136, "s": "BTCUSDT",
+            "k": {
+                "t": 1672515780000, "T": 1672515839999, "s": "BTCUSDT", "i": "1m",
+                "o": "16541.60", "c": "16541.90", "h": "16542.00", "l": "16541.60",
+                "v": "5.344", "q": "88400.69200", "V": "3.932", "Q": "65043.09090",
+                "n": 39, "x": false
+            }
+        }
+        "#;
+
+        let candle = serde_json::from_str::<BinanceCandle>(input).unwrap();
+        let instrument = Instrument::from((
+            "btc",
+            "usdt",
+            barter_integration::model::InstrumentKind::Spot,
+        ));
+        let actual = MarketIter::<Candle>::from((ExchangeId::BinanceSpot, instrument, candle))
+            .0
+            .remove(0)
+            .unwrap();
+
+        // Candle start & close times are the UTC boundaries of the 1m Interval, where the close
+        // time is the last millisecond of the Interval
+        assert_eq!(actual.kind.start_time.timestamp_millis(), 1672515780000);
+        assert_eq!(actual.kind.close_time.timestamp_millis(), 1672515839999);
+        assert_eq!(
+            actual.kind.close_time + chrono::Duration::milliseconds(1) - actual.kind.start_time,
+            Interval::Minute1.duration()
+        );
+
+        // Exchange time is the kline event time, not the Interval end
+        assert_eq!(actual.exchange_time.timestamp_millis(), 1672515782136);
+    }
+
+    #[test]
+    fn test_binance_candle_validate() {
+        struct TestCase {
+            input: String,
+            expected_invalid: Option<&'static str>,
+        }
+
+        let kline = |start: u64, end: u64, ohlcv: [&str; 5], trades: u64| {
+            let [open, high, low, close, volume] = ohlcv;
+            format!(
+                r#"{{
+                    "e": "kline", "E": 1672515782136, "s": "BTCUSDT",
+                    "k": {{
+                        "t": {start}, "T": {end}, "s": "BTCUSDT", "i": "1m",
+                        "o": "{open}", "c": "{close}", "h": "{high}", "l": "{low}",
+                        "v": "{volume}", "q": "0", "V": "0", "Q": "0",
+                        "n": {trades}, "x": true
+                    }}
+                }}"#
+            )
+        };
+        let (start, end) = (1672515780000, 1672515839999);
+
+        #[cfg_attr(feature = "decimal", allow(unused_mut))]
+        let mut tests = vec![
+            TestCase {
+                // TC0: valid candle
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16542.0", "16541.0", "16541.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: None,
+            },
+            TestCase {
+                // TC1: valid candle w/ zero volume & zero trades
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16541.6", "16541.6", "16541.6", "0"],
+                    0,
+                ),
+                expected_invalid: None,
+            },
+            TestCase {
+                // TC2: invalid candle w/ high < low
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16540.0", "16542.0", "16541.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("high"),
+            },
+            TestCase {
+                // TC3: invalid candle w/ open above high
+                input: kline(
+                    start,
+                    end,
+                    ["16543.0", "16542.0", "16541.0", "16541.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("open"),
+            },
+            TestCase {
+                // TC4: invalid candle w/ open below low
+                input: kline(
+                    start,
+                    end,
+                    ["16540.0", "16542.0", "16541.0", "16541.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("open"),
+            },
+            TestCase {
+                // TC5: invalid candle w/ close above high
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16542.0", "16541.0", "16542.1", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("close"),
+            },
+            TestCase {
+                // TC6: invalid candle w/ close below low
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16542.0", "16541.0", "16540.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("close"),
+            },
+            TestCase {
+                // TC7: invalid candle w/ negative volume
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16542.0", "16541.0", "16541.9", "-1"],
+                    39,
+                ),
+                expected_invalid: Some("volume"),
+            },
+            TestCase {
+                // TC8: invalid candle w/ close_time before start_time
+                input: kline(
+                    end,
+                    start,
+                    ["16541.6", "16542.0", "16541.0", "16541.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("close_time"),
+            },
+            TestCase {
+                // TC9: invalid candle w/ close_time equal to start_time
+                input: kline(
+                    start,
+                    start,
+                    ["16541.6", "16542.0", "16541.0", "16541.9", "5.3"],
+                    39,
+                ),
+                expected_invalid: Some("close_time"),
+            },
+            TestCase {
+                // TC10: invalid candle w/ trades but zero volume
+                input: kline(
+                    start,
+                    end,
+                    ["16541.6", "16542.0", "16541.0", "16541.9", "0"],
+                    39,
+                ),
+                expected_invalid: Some("trade_count"),
+            },
+        ];
+
+        // TC11: invalid candle w/ non-finite price, which is only representable by an f64 Number
+        let non_finite = kline(
+            start,
+            end,
+            ["NaN", "16542.0", "16541.0", "16541.9", "5.3"],
+            39,
+        );
+        #[cfg(not(feature = "decimal"))]
+        tests.push(TestCase {
+            input: non_finite,
+            expected_invalid: Some("non-finite"),
+        });
+        #[cfg(feature = "decimal")]
+        assert!(serde_json::from_str::<BinanceCandle>(&non_finite).is_err());
+
+        let instrument = Instrument::from((
+            "btc",
+            "usdt",
+            barter_integration::model::InstrumentKind::Spot,
+        ));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let candle = serde_json::from_str::<BinanceCandle>(&test.input).unwrap();
+            let event =
+                MarketIter::<Candle>::from((ExchangeId::BinanceSpot, instrument.clone(), candle))
+                    .0
+                    .remove(0)
+                    .unwrap();
+
+            match (event.kind.validate(), test.expected_invalid) {
+                (Ok(()), None) => {}
+                (Err(DataError::InvalidCandle { reason, candle }), Some(expected)) => {
+                    assert!(
+                        reason.starts_with(expected),
+                        "TC{index} failed: unexpected reason {reason}"
+                    );
+                    assert_eq!(
+                        (candle.start_time, candle.close_time, candle.trade_count),
+                        (
+                            event.kind.start_time,
+                            event.kind.close_time,
+                            event.kind.trade_count
+                        ),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_candles_multiple_intervals_route_to_subscriptions() {
+        use crate::{
+            exchange::binance::spot::BinanceSpot,
+            subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::InstrumentKind, Transformer};
+
+        let subscriptions = [Interval::Minute1, Interval::Minute5].map(|interval| {
+            Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(interval),
+            ))
+        });
+        let instrument_map = WebSocketSubMapper::map(&subscriptions).instrument_map;
+
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer =
+            <StatelessTransformer<BinanceSpot, Candles, BinanceCandle> as ExchangeTransformer<
+                BinanceSpot,
+                Candles,
+            >>::new(ws_sink_tx, instrument_map)
+            .await
+            .unwrap();
+
+        // Interleaved 1m & 5m klines for the same Instrument
+        let kline = |interval: &str, start: u64, end: u64| {
+            format!(
+                r#"{{"e":"kline","E":{end},"s":"BTCUSDT","k":{{"t":{start},"T":{end},"s":"BTCUSDT","i":"{interval}","o":"1.0","c":"1.0","h":"1.0","l":"1.0","v":"1.0","q":"1.0","V":"1.0","Q":"1.0","n":1,"x":true}}}}"#
+            )
+        };
+        let inputs = [
+            kline("1m", 1672515780000, 1672515839999),
+            kline("5m", 1672515600000, 1672515899999),
+            kline("1m", 1672515840000, 1672515899999),
+            kline("5m", 1672515900000, 1672516199999),
+        ];
+
+        let actual = inputs
+            .iter()
+            .flat_map(|input| {
+                transformer.transform(serde_json::from_str::<BinanceCandle>(input).unwrap())
+            })
+            .map(|candle| {
+                let candle = candle.unwrap();
+                candle.kind.close_time + chrono::Duration::milliseconds(1) - candle.kind.start_time
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                Interval::Minute1.duration(),
+                Interval::Minute5.duration(),
+                Interval::Minute1.duration(),
+                Interval::Minute5.duration(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_binance_candle_subscription_id_matches_exchange_sub_id() {
+        use crate::exchange::{binance::channel::BinanceChannel, subscription::ExchangeSub};
+
+        for interval in Interval::ALL {
+            // SubscriptionId stored in the instrument Map when subscribing
+            let expected = ExchangeSub::from((BinanceChannel::candles(interval), "BTCUSDT")).id();
+
+            // SubscriptionId derived from the "k.i" interval of each incoming kline
+            let channel = BinanceChannel::candles(interval);
+            let payload_interval = channel.0.trim_start_matches("@kline_");
+            let input = format!(
+                r#"{{"e":"kline","E":1,"s":"BTCUSDT","k":{{"t":0,"T":1,"s":"BTCUSDT","i":"{payload_interval}","o":"1.0","c":"1.0","h":"1.0","l":"1.0","v":"1.0","q":"1.0","V":"1.0","Q":"1.0","n":1,"x":true}}}}"#
+            );
+            let actual = serde_json::from_str::<BinanceCandle>(&input).unwrap().id();
+
+            assert_eq!(actual, Some(expected), "{interval:?} failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_futures_candles_intervals_route_to_respective_instruments() {
+        use crate::{
+            exchange::binance::futures::BinanceFuturesUsd,
+            subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+            subscription::candle::CandleUpdates,
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::InstrumentKind, Transformer};
+
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::FuturePerpetual));
+
+        // 1m klines for BTCUSDT & 5m klines for ETHUSDT on the same connection
+        let kline = |symbol: &str, interval: &str, closed: bool| {
+            format!(
+                r#"{{"e":"kline","E":1672515782136,"s":"{symbol}","k":{{"t":1672515780000,"T":1672515839999,"s":"{symbol}","i":"{interval}","o":"1.0","c":"1.0","h":"1.0","l":"1.0","v":"1.0","q":"1.0","V":"1.0","Q":"1.0","n":1,"x":{closed}}}}}"#
+            )
+        };
+
+        struct TestCase {
+            input: String,
+            expected: Option<Instrument>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m kline routes to the 1m Subscription Instrument
+                input: kline("BTCUSDT", "1m", true),
+                expected: Some(btc.clone()),
+            },
+            TestCase {
+                // TC1: 5m kline routes to the 5m Subscription Instrument
+                input: kline("ETHUSDT", "5m", true),
+                expected: Some(eth.clone()),
+            },
+            TestCase {
+                // TC2: kline for an unsubscribed interval is Unidentifiable
+                input: kline("BTCUSDT", "5m", true),
+                expected: None,
+            },
+        ];
+
+        let (btc_interval, eth_interval) = (Interval::Minute1, Interval::Minute5);
+
+        // Candles
+        let subscriptions = [
+            Subscription::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                Candles(btc_interval),
+            )),
+            Subscription::from((
+                BinanceFuturesUsd::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                Candles(eth_interval),
+            )),
+        ];
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut candles = <StatelessTransformer<BinanceFuturesUsd, Candles, BinanceCandle> as ExchangeTransformer<BinanceFuturesUsd, Candles>>::new(
+            ws_sink_tx,
+            WebSocketSubMapper::map(&subscriptions).instrument_map,
+        )
+        .await
+        .unwrap();
+
+        // CandleUpdates
+        let subscriptions = [
+            Subscription::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                CandleUpdates(btc_interval),
+            )),
+            Subscription::from((
+                BinanceFuturesUsd::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                CandleUpdates(eth_interval),
+            )),
+        ];
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut closed = <StatelessTransformer<
+            BinanceFuturesUsd,
+            CandleUpdates,
+            ClosedCandles<BinanceCandle>,
+        > as ExchangeTransformer<BinanceFuturesUsd, CandleUpdates>>::new(
+            ws_sink_tx,
+            WebSocketSubMapper::map(&subscriptions).instrument_map,
+        )
+        .await
+        .unwrap();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = candles
+                .transform(serde_json::from_str::<BinanceCandle>(&test.input).unwrap())
+                .into_iter()
+                .chain(closed.transform(
+                    serde_json::from_str::<ClosedCandles<BinanceCandle>>(&test.input).unwrap(),
+                ))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual.len(), 2, "TC{} failed", index);
+            for actual in actual {
+                match (actual, &test.expected) {
+                    (Ok(event), Some(expected)) => {
+                        assert_eq!(&event.instrument, expected, "TC{} failed", index)
+                    }
+                    (Err(DataError::Socket(SocketError::Unidentifiable(_))), None) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        panic!("TC{index} failed. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_candle_fixtures_transform() {
+        use crate::{
+            exchange::{
+                binance::{channel::BinanceChannel, spot::BinanceSpot},
+                subscription::ExchangeSub,
+            },
+            test_util::{assert_deserialize_roundtrip, instrument_map, transform_frames, Fixtures},
+            transformer::stateless::StatelessTransformer,
+        };
+        use barter_integration::{error::SocketError, model::InstrumentKind};
+
+        let fixtures = Fixtures::load("binance", "candles");
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let instrument_map = instrument_map(&[Subscription::from((
+            BinanceSpot::default(),
+            instrument.clone(),
+            Candles(Interval::Minute1),
+        ))]);
+
+        let actual = transform_frames::<
+            BinanceSpot,
+            Candles,
+            StatelessTransformer<BinanceSpot, Candles, BinanceCandle>,
+            _,
+        >(
+            instrument_map,
+            [
+                "futures_kline_closed",
+                "malformed_truncated",
+                "malformed_wrong_types",
+                "unknown_symbol",
+            ]
+            .map(|name| fixtures.get(name)),
+        )
+        .await;
+        assert_eq!(actual.len(), 4);
+
+        // Valid frame yields a normalised closed Candle that survives a serde roundtrip
+        let candle = actual[0].as_ref().unwrap();
+        assert_eq!(candle.instrument, instrument);
+        assert_eq!(candle.kind.start_time.timestamp_millis(), 1672515780000);
+        assert_eq!(candle.kind.trade_count, 39);
+        assert!(candle.kind.closed);
+        assert_deserialize_roundtrip(candle);
+
+        // Malformed frames yield deserialisation errors
+        for index in [1, 2] {
+            assert!(
+                matches!(
+                    &actual[index],
+                    Err(DataError::Socket(SocketError::Deserialise { .. }))
+                ),
+                "frame {index} failed: {:?}",
+                actual[index]
+            );
+        }
+
+        // Candles of an unsubscribed symbol are unidentifiable
+        let expected =
+            ExchangeSub::from((BinanceChannel::candles(Interval::Minute1), "XRPUSDT")).id();
+        assert!(matches!(
+            &actual[3],
+            Err(DataError::Socket(SocketError::Unidentifiable(subscription_id)))
+                if subscription_id == &expected
+        ));
+    }
+}
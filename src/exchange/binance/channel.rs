@@ -1,17 +1,24 @@
-use super::{futures::BinanceFuturesUsd, Binance};
-use crate::subscription::Interval;
+use super::{
+    book::UpdateSpeed,
+    futures::{BinanceFuturesCoin, BinanceFuturesUsd},
+    Binance,
+};
 use crate::{
+    error::UnsupportedIntervalError,
+    exchange::ExchangeServer,
     subscription::{
-        book::{OrderBooksL1, OrderBooksL2},
-        candle::Candles,
-        liquidation::Liquidations,
-        trade::PublicTrades,
-        Subscription,
+        book::{Depth, OrderBookSnapshots, OrderBooksL1, OrderBooksL2},
+        candle::{CandleUpdates, Candles},
+        liquidation::{Liquidations, LiquidationsAll},
+        mark_price::MarkPrices,
+        ticker::Tickers,
+        trade::{PublicTrades, PublicTradesAggregated},
+        Interval, Subscription,
     },
     Identifier,
 };
 use serde::Serialize;
-use std::fmt::{Debug, Display};
+use std::fmt::Debug;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`](super::Binance)
 /// channel to be subscribed to.
@@ -33,24 +40,169 @@ impl BinanceChannel {
     /// See discord: <https://discord.com/channels/910237311332151317/923160222711812126/975712874582388757>
     pub const TRADES: Self = Self("@trade");
 
+    /// [`Binance`](super::Binance) real-time aggregated trades channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+    pub const AGG_TRADES: Self = Self("@aggTrade");
+
     /// [`Binance`](super::Binance) real-time OrderBook Level1 (top of book) channel name.
     ///
     /// See docs:<https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-book-ticker-streams>
     /// See docs:<https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-book-ticker-streams>
     pub const ORDER_BOOK_L1: Self = Self("@bookTicker");
 
-    /// [`Binance`](super::Binance) OrderBook Level2 channel name (100ms delta updates).
+    /// [`Binance`](super::Binance) OrderBook Level2 channel name (100ms delta updates), used for
+    /// the default [`UpdateSpeed::Ms100`].
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
     pub const ORDER_BOOK_L2: Self = Self("@depth@100ms");
 
+    /// [`Binance`](super::Binance) OrderBook Level2 channel used for [`UpdateSpeed`]s the server
+    /// does not support. Subscriptions to this channel are rejected during validation.
+    pub const ORDER_BOOK_L2_UNSUPPORTED: Self = Self("@depth@unsupported");
+
+    /// [`Binance`](super::Binance) OrderBook snapshots channel used for unsupported [`Depth`]s.
+    /// Subscriptions to this channel are rejected during validation.
+    pub const ORDER_BOOK_SNAPSHOTS_UNSUPPORTED: Self = Self("@depth-unsupported");
+
+    /// [`Binance`](super::Binance) kline channel used for unsupported [`Interval`]s. Subscriptions
+    /// to this channel are rejected during validation.
+    pub const CANDLES_UNSUPPORTED: Self = Self("@kline_unsupported");
+
+    /// [`Binance`](super::Binance) individual symbol rolling 24h ticker statistics channel name.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+    pub const TICKERS: Self = Self("@ticker");
+
     /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) liquidation orders channel name.
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
 
-    pub const CANDLES: Self = Self("@kline_");
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) liquidation orders channel name
+    /// for every market, subscribed to without a market.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#all-market-liquidation-order-streams>
+    pub const LIQUIDATIONS_ALL: Self = Self("!forceOrder@arr");
+
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) mark price & funding rate channel
+    /// name (1s updates).
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+    pub const MARK_PRICES: Self = Self("@markPrice@1s");
+
+    /// [`Binance`](super::Binance) partial book depth (top-N snapshots every 100ms) channel name
+    /// for the provided [`Depth`].
+    ///
+    /// Note: Binance only supports [`Depth::Top5`], [`Depth::Top10`] & [`Depth::Top20`].
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
+    pub fn order_book_snapshots(depth: Depth) -> Self {
+        match depth {
+            Depth::Top5 => Self("@depth5@100ms"),
+            Depth::Top10 => Self("@depth10@100ms"),
+            Depth::Top20 => Self("@depth20@100ms"),
+            Depth::Top50 => Self::ORDER_BOOK_SNAPSHOTS_UNSUPPORTED,
+        }
+    }
+
+    /// [`Binance`](super::Binance) OrderBook Level2 channel name for the provided [`UpdateSpeed`]
+    /// on the spot or futures server.
+    ///
+    /// Note: the unsuffixed "@depth" channel updates every 1000ms on spot, but every 250ms on
+    /// futures.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#diff-depth-stream>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#diff-book-depth-streams>
+    pub fn order_book_l2(update_speed: UpdateSpeed, futures: bool) -> Self {
+        match (update_speed, futures) {
+            (UpdateSpeed::Ms100, _) => Self::ORDER_BOOK_L2,
+            (UpdateSpeed::Ms1000, false) | (UpdateSpeed::Ms250, true) => Self("@depth"),
+            (UpdateSpeed::Ms0, true) => Self("@depth@0ms"),
+            (UpdateSpeed::Ms500, true) => Self("@depth@500ms"),
+            _ => Self::ORDER_BOOK_L2_UNSUPPORTED,
+        }
+    }
+
+    /// Determine if the [`BinanceChannel`] is an all market stream (eg/ "!forceOrder@arr"), which
+    /// is subscribed to without a market.
+    pub fn is_all_markets(&self) -> bool {
+        self.0.starts_with('!')
+    }
+
+    /// Determine if the [`BinanceChannel`] is an OrderBook Level2 diff depth channel (eg/
+    /// "@depth@100ms").
+    pub fn is_order_book_l2(&self) -> bool {
+        self.0 == "@depth" || self.0.starts_with("@depth@")
+    }
+
+    /// Determine if the [`BinanceChannel`] is a partial book depth (top-N snapshots) channel.
+    pub fn is_order_book_snapshots(&self) -> bool {
+        self.0.starts_with("@depth") && !self.is_order_book_l2()
+    }
+
+    /// [`Binance`](super::Binance) kline (candle) channel name for the provided [`Interval`].
+    ///
+    /// [`Interval`]s without a [`BinanceKlineInterval`] map to [`Self::CANDLES_UNSUPPORTED`].
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#kline-candlestick-streams>
+    pub fn candles(interval: Interval) -> Self {
+        BinanceKlineInterval::try_from(interval)
+            .map_or(Self::CANDLES_UNSUPPORTED, |kline| kline.channel)
+    }
+}
+
+/// [`Binance`](super::Binance) kline interval (eg/ "1h") of a Barter [`Interval`].
+///
+/// Note: Binance does not support 3 month klines, so [`Interval::Month3`] is unsupported.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#kline-candlestick-streams>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BinanceKlineInterval {
+    /// Kline interval code, as used by the REST API (eg/ "1h").
+    pub code: &'static str,
+    /// Kline channel of the interval (eg/ "@kline_1h").
+    pub channel: BinanceChannel,
+}
+
+impl TryFrom<Interval> for BinanceKlineInterval {
+    type Error = UnsupportedIntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let (code, channel) = match interval {
+            Interval::Minute1 => ("1m", "@kline_1m"),
+            Interval::Minute3 => ("3m", "@kline_3m"),
+            Interval::Minute5 => ("5m", "@kline_5m"),
+            Interval::Minute15 => ("15m", "@kline_15m"),
+            Interval::Minute30 => ("30m", "@kline_30m"),
+            Interval::Hour1 => ("1h", "@kline_1h"),
+            Interval::Hour2 => ("2h", "@kline_2h"),
+            Interval::Hour4 => ("4h", "@kline_4h"),
+            Interval::Hour6 => ("6h", "@kline_6h"),
+            Interval::Hour8 => ("8h", "@kline_8h"),
+            Interval::Hour12 => ("12h", "@kline_12h"),
+            Interval::Day1 => ("1d", "@kline_1d"),
+            Interval::Day3 => ("3d", "@kline_3d"),
+            Interval::Week1 => ("1w", "@kline_1w"),
+            Interval::Month1 => ("1M", "@kline_1M"),
+            Interval::Month3 => {
+                return Err(UnsupportedIntervalError {
+                    exchange: "binance",
+                    interval,
+                })
+            }
+        };
+
+        Ok(Self {
+            code,
+            channel: BinanceChannel(channel),
+        })
+    }
 }
 
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, PublicTrades> {
@@ -59,26 +211,21 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Public
     }
 }
 
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, PublicTradesAggregated> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::AGG_TRADES
+    }
+}
+
 impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Candles> {
     fn id(&self) -> BinanceChannel {
-        match self.kind.0 {
-            Interval::Minute1 => BinanceChannel("@kline_1m"),
-            Interval::Minute3 => BinanceChannel("@kline_3m"),
-            Interval::Minute5 => BinanceChannel("@kline_5m"),
-            Interval::Minute15 => BinanceChannel("@kline_15m"),
-            Interval::Minute30 => BinanceChannel("@kline_30m"),
-            Interval::Hour1 => BinanceChannel("@kline_1h"),
-            Interval::Hour2 => BinanceChannel("@kline_2h"),
-            Interval::Hour4 => BinanceChannel("@kline_4h"),
-            Interval::Hour6 => BinanceChannel("@kline_6h"),
-            Interval::Hour8 => BinanceChannel("@kline_8h"),
-            Interval::Hour12 => BinanceChannel("@kline_12h"),
-            Interval::Day1 => BinanceChannel("@kline_1d"),
-            Interval::Day3 => BinanceChannel("@kline_3d"),
-            Interval::Week1 => BinanceChannel("@kline_1w"),
-            Interval::Month1 => BinanceChannel("@kline_1M"),
-            Interval::Month3 => BinanceChannel("@kline_3M"),
-        }
+        BinanceChannel::candles(self.kind.0)
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, CandleUpdates> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::candles(self.kind.0)
     }
 }
 
@@ -88,9 +235,24 @@ impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderB
     }
 }
 
-impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL2> {
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBooksL2>
+where
+    Server: ExchangeServer,
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::order_book_l2(self.exchange.update_speed(), Server::ID.supports_futures())
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, OrderBookSnapshots> {
     fn id(&self) -> BinanceChannel {
-        BinanceChannel::ORDER_BOOK_L2
+        BinanceChannel::order_book_snapshots(self.kind.depth)
+    }
+}
+
+impl<Server> Identifier<BinanceChannel> for Subscription<Binance<Server>, Tickers> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::TICKERS
     }
 }
 
@@ -100,8 +262,93 @@ impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, Liquidations
     }
 }
 
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesCoin, Liquidations> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::LIQUIDATIONS
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, LiquidationsAll> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::LIQUIDATIONS_ALL
+    }
+}
+
+impl Identifier<BinanceChannel> for Subscription<BinanceFuturesUsd, MarkPrices> {
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::MARK_PRICES
+    }
+}
+
 impl AsRef<str> for BinanceChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{binance::spot::BinanceSpot, Connector};
+
+    #[test]
+    fn test_binance_kline_interval_try_from_interval() {
+        // Expected kline channel of every Interval, in Interval::ALL order
+        let tests = [
+            (Interval::Minute1, Some("@kline_1m")),
+            (Interval::Minute3, Some("@kline_3m")),
+            (Interval::Minute5, Some("@kline_5m")),
+            (Interval::Minute15, Some("@kline_15m")),
+            (Interval::Minute30, Some("@kline_30m")),
+            (Interval::Hour1, Some("@kline_1h")),
+            (Interval::Hour2, Some("@kline_2h")),
+            (Interval::Hour4, Some("@kline_4h")),
+            (Interval::Hour6, Some("@kline_6h")),
+            (Interval::Hour8, Some("@kline_8h")),
+            (Interval::Hour12, Some("@kline_12h")),
+            (Interval::Day1, Some("@kline_1d")),
+            (Interval::Day3, Some("@kline_3d")),
+            (Interval::Week1, Some("@kline_1w")),
+            (Interval::Month1, Some("@kline_1M")),
+            (Interval::Month3, None),
+        ];
+        assert_eq!(tests.map(|(interval, _)| interval), Interval::ALL);
+
+        for (interval, expected) in tests {
+            let actual = BinanceKlineInterval::try_from(interval);
+            match expected {
+                Some(channel) => {
+                    let kline = actual.unwrap();
+                    assert_eq!(kline.channel, BinanceChannel(channel), "{interval} failed");
+                    assert_eq!(
+                        format!("@kline_{}", kline.code),
+                        channel,
+                        "{interval} failed"
+                    );
+                    assert_eq!(BinanceChannel::candles(interval), kline.channel);
+                }
+                None => {
+                    assert_eq!(
+                        actual,
+                        Err(UnsupportedIntervalError {
+                            exchange: "binance",
+                            interval
+                        }),
+                        "{interval} failed"
+                    );
+                    assert_eq!(
+                        BinanceChannel::candles(interval),
+                        BinanceChannel::CANDLES_UNSUPPORTED
+                    );
+                }
+            }
+
+            // Binance supported_intervals are exactly those with a BinanceKlineInterval
+            assert_eq!(
+                BinanceSpot::supported_intervals().contains(&interval),
+                expected.is_some(),
+                "{interval} failed"
+            );
+        }
+    }
+}
@@ -1,4 +1,7 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::{fetch_snapshot, BinanceCombinedStream},
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
     subscription::book::OrderBook,
@@ -7,7 +10,6 @@ use crate::{
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
     model::{Instrument, SubscriptionId},
     protocol::websocket::WsMessage,
 };
@@ -18,10 +20,13 @@ use tokio::sync::mpsc;
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) HTTP OrderBook L2 snapshot url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#order-book>
-pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://fapi.binance.com/fapi/v1/depth";
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/depth";
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) OrderBook Level2 deltas WebSocket message.
 ///
+/// Received wrapped in a [`BinanceCombinedStream`], which determines the [`SubscriptionId`].
+///
 /// ### Raw Payload Examples
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#partial-book-depth-streams>
 /// ```json
@@ -42,6 +47,7 @@ pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://fapi.binance.c
 /// }
 /// ```
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct BinanceFuturesOrderBookL2Delta {
     #[serde(
         alias = "s",
@@ -60,6 +66,38 @@ pub struct BinanceFuturesOrderBookL2Delta {
     pub asks: Vec<BinanceLevel>,
 }
 
+impl<'de> Deserialize<'de> for BinanceFuturesOrderBookL2Delta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Data(
+            #[serde(with = "BinanceFuturesOrderBookL2Delta")] BinanceFuturesOrderBookL2Delta,
+        );
+
+        // SubscriptionId is derived from the stream name, which contains the UpdateSpeed
+        let BinanceCombinedStream {
+            stream,
+            data: Data(delta),
+        } = BinanceCombinedStream::deserialize(deserializer)?;
+
+        Ok(Self {
+            subscription_id: stream,
+            ..delta
+        })
+    }
+}
+
+impl Serialize for BinanceFuturesOrderBookL2Delta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
+}
+
 impl Identifier<Option<SubscriptionId>> for BinanceFuturesOrderBookL2Delta {
     fn id(&self) -> Option<SubscriptionId> {
         Some(self.subscription_id.clone())
@@ -86,6 +124,8 @@ impl Identifier<Option<SubscriptionId>> for BinanceFuturesOrderBookL2Delta {
 ///
 /// Notes:
 ///  - Receiving an event that removes a price level that is not in your local order book can happen and is normal.
+///  - A sequence gap yields a terminal [`DataError::InvalidSequence`], causing the
+///    [`MarketStream`](crate::MarketStream) to re-initialise and re-sync from a fresh snapshot.
 ///  - Uppercase U => first_update_id
 ///  - Lowercase u => last_update_id,
 ///  - Lowercase pu => prev_last_update_id
@@ -153,6 +193,21 @@ impl BinanceFuturesBookUpdater {
             })
         }
     }
+
+    /// Initialise the [`InstrumentOrderBook`] for the provided [`Instrument`] using an
+    /// OrderBook snapshot fetched from the provided `snapshot_url` (eg/ a mock server in tests).
+    pub async fn init_with_snapshot_url(
+        snapshot_url: &str,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError> {
+        let snapshot = fetch_snapshot(snapshot_url, &instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+        })
+    }
 }
 
 #[async_trait]
@@ -168,27 +223,8 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         Exchange: Send,
         Kind: Send,
     {
-        // Construct initial OrderBook snapshot GET url
-        let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
-            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
-        );
-
-        // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
+        Self::init_with_snapshot_url(HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_FUTURES_USD, instrument)
             .await
-            .map_err(SocketError::Http)?;
-
-        Ok(InstrumentOrderBook {
-            instrument,
-            updater: Self::new(snapshot.last_update_id),
-            book: OrderBook::from(snapshot),
-        })
     }
 
     fn update(
@@ -219,6 +255,9 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
         book.last_update_time = Utc::now();
         book.bids.upsert(update.bids);
         book.asks.upsert(update.asks);
+        // Futures update ids are not contiguous between events, so continuity follows pu
+        book.first_update_id = Some(update.prev_last_update_id + 1);
+        book.last_update_id = Some(update.last_update_id);
 
         // Update OrderBookUpdater metadata
         self.updates_processed += 1;
@@ -231,53 +270,81 @@ impl OrderBookUpdater for BinanceFuturesBookUpdater {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
 
         #[test]
         fn test_binance_futures_order_book_l2_deltas() {
-            let input = r#"
-            {
-                "e": "depthUpdate",
-                "E": 123456789,
-                "T": 123456788,
-                "s": "BTCUSDT",
-                "U": 157,
-                "u": 160,
-                "pu": 149,
-                "b": [
-                    [
-                        "0.0024",
-                        "10"
-                    ]
-                ],
-                "a": [
-                    [
-                        "0.0026",
-                        "100"
-                    ]
-                ]
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceFuturesOrderBookL2Delta,
             }
-        "#;
 
-            assert_eq!(
-                serde_json::from_str::<BinanceFuturesOrderBookL2Delta>(input).unwrap(),
-                BinanceFuturesOrderBookL2Delta {
-                    subscription_id: SubscriptionId::from("@depth@100ms|BTCUSDT"),
-                    first_update_id: 157,
-                    last_update_id: 160,
-                    prev_last_update_id: 149,
-                    bids: vec![BinanceLevel {
-                        price: 0.0024,
-                        amount: 10.0
-                    },],
-                    asks: vec![BinanceLevel {
-                        price: 0.0026,
-                        amount: 100.0
-                    },]
-                }
-            );
+            let delta = |subscription_id: &str| BinanceFuturesOrderBookL2Delta {
+                subscription_id: SubscriptionId::from(subscription_id),
+                first_update_id: 157,
+                last_update_id: 160,
+                prev_last_update_id: 149,
+                bids: vec![BinanceLevel {
+                    price: from_f64(0.0024),
+                    amount: from_f64(10.0),
+                }],
+                asks: vec![BinanceLevel {
+                    price: from_f64(0.0026),
+                    amount: from_f64(100.0),
+                }],
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: default 100ms UpdateSpeed stream
+                    input: r#"
+                    {
+                        "stream": "btcusdt@depth@100ms",
+                        "data": {
+                            "e": "depthUpdate",
+                            "E": 123456789,
+                            "T": 123456788,
+                            "s": "BTCUSDT",
+                            "U": 157,
+                            "u": 160,
+                            "pu": 149,
+                            "b": [["0.0024", "10"]],
+                            "a": [["0.0026", "100"]]
+                        }
+                    }
+                    "#,
+                    expected: delta("@depth@100ms|BTCUSDT"),
+                },
+                TestCase {
+                    // TC1: 0ms UpdateSpeed stream
+                    input: r#"
+                    {
+                        "stream": "btcusdt@depth@0ms",
+                        "data": {
+                            "e": "depthUpdate",
+                            "E": 123456789,
+                            "T": 123456788,
+                            "s": "BTCUSDT",
+                            "U": 157,
+                            "u": 160,
+                            "pu": 149,
+                            "b": [["0.0024", "10"]],
+                            "a": [["0.0026", "100"]]
+                        }
+                    }
+                    "#,
+                    expected: delta("@depth@0ms|BTCUSDT"),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    serde_json::from_str::<BinanceFuturesOrderBookL2Delta>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
         }
     }
 
@@ -406,8 +473,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(_), Ok(_)) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -469,8 +536,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(_), Ok(_)) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -483,6 +550,79 @@ mod tests {
             }
         }
 
+        #[tokio::test]
+        async fn test_init_with_snapshot_url_sequence() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<Option<()>, DataError>,
+            }
+
+            let url = crate::exchange::binance::book::l2::tests::mock_snapshot_server(
+                r#"{"lastUpdateId": 100, "bids": [["50.0", "1.0"]], "asks": [["100.0", "1.0"]]}"#,
+            );
+            let mut book = BinanceFuturesBookUpdater::init_with_snapshot_url(
+                &url,
+                Instrument::from((
+                    "btc",
+                    "usdt",
+                    barter_integration::model::InstrumentKind::FuturePerpetual,
+                )),
+            )
+            .await
+            .unwrap();
+            assert_eq!(book.updater, BinanceFuturesBookUpdater::new(100));
+
+            let tests = vec![
+                TestCase {
+                    // TC0: delta older than the snapshot is discarded
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"T":1,"s":"BTCUSDT","U":80,"u":90,"pu":79,"b":[["50.0","0"]],"a":[]}}"#,
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC1: first delta overlapping the snapshot is applied
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"T":1,"s":"BTCUSDT","U":95,"u":105,"pu":94,"b":[["60.0","1.0"]],"a":[]}}"#,
+                    expected: Ok(Some(())),
+                },
+                TestCase {
+                    // TC2: next sequential delta is applied
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"T":1,"s":"BTCUSDT","U":106,"u":110,"pu":105,"b":[["60.0","2.0"]],"a":[]}}"#,
+                    expected: Ok(Some(())),
+                },
+                TestCase {
+                    // TC3: sequence gap is terminal, triggering a re-sync
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"T":1,"s":"BTCUSDT","U":120,"u":130,"pu":119,"b":[["50.0","0"]],"a":[]}}"#,
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 110,
+                        first_update_id: 120,
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let update =
+                    serde_json::from_str::<BinanceFuturesOrderBookL2Delta>(test.input).unwrap();
+                let actual = book.updater.update(&mut book.book, update);
+
+                match (actual, test.expected) {
+                    (Ok(Some(_)), Ok(Some(_))) | (Ok(None), Ok(None)) => {
+                        // Test passed
+                    }
+                    (Err(actual), Err(_)) => {
+                        assert!(actual.is_terminal(), "TC{} failed", index)
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+
+            assert_eq!(
+                book.book.snapshot().bids,
+                OrderBookSide::new(Side::Buy, vec![Level::new(60, 2), Level::new(50, 1)]),
+            );
+        }
+
         #[test]
         fn update() {
             struct TestCase {
@@ -505,6 +645,8 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
                         asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                        first_update_id: None,
+                        last_update_id: None,
                     },
                     input_update: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -532,6 +674,8 @@ mod tests {
                             Side::Sell,
                             vec![Level::new(150, 1), Level::new(110, 1), Level::new(120, 1)],
                         ),
+                        first_update_id: None,
+                        last_update_id: None,
                     },
                     input_update: BinanceFuturesOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -541,25 +685,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: from_f64(80.0),
+                                amount: from_f64(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: from_f64(90.0),
+                                amount: from_f64(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: from_f64(200.0),
+                                amount: from_f64(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: from_f64(500.0),
+                                amount: from_f64(0.0),
                             },
                         ],
                     },
@@ -578,6 +722,8 @@ mod tests {
                                 Level::new(200, 1),
                             ],
                         ),
+                        first_update_id: Some(101),
+                        last_update_id: Some(110),
                     })),
                 },
             ];
@@ -1,13 +1,30 @@
-use super::super::BinanceChannel;
+use super::{super::BinanceChannel, BinanceFuturesUsd};
 use crate::{
+    error::DataError,
     event::{MarketEvent, MarketIter},
-    exchange::ExchangeId,
-    subscription::liquidation::Liquidation,
+    exchange::{Connector, ExchangeId},
+    streams::metrics::MetricsHook,
+    subscription::{
+        liquidation::{Liquidation, LiquidationStatus, LiquidationsAll},
+        Map,
+    },
+    transformer::ExchangeTransformer,
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// Quote assets of [`BinanceFuturesUsd`] perpetual markets, used to resolve the [`Instrument`]
+/// of a market (eg/ "BTCUSDT") received via the [`BinanceChannel::LIQUIDATIONS_ALL`] stream.
+pub const QUOTE_ASSETS_BINANCE_FUTURES_USD: [&str; 3] = ["USDT", "USDC", "BUSD"];
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) Liquidation order message.
 ///
@@ -68,9 +85,23 @@ pub struct BinanceLiquidationOrder {
     pub price: f64,
     #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
     pub quantity: f64,
+    #[serde(
+        alias = "ap",
+        deserialize_with = "barter_integration::de::de_str",
+        default
+    )]
+    pub average_price: f64,
+    #[serde(alias = "X", default)]
+    pub status: String,
+    #[serde(
+        alias = "z",
+        deserialize_with = "barter_integration::de::de_str",
+        default
+    )]
+    pub filled_quantity: f64,
     #[serde(
         alias = "T",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
 }
@@ -90,16 +121,112 @@ impl From<(ExchangeId, Instrument, BinanceLiquidation)> for MarketIter<Liquidati
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: None,
+            tag: None,
             kind: Liquidation {
                 side: liquidation.order.side,
                 price: liquidation.order.price,
-                quantity: liquidation.order.quantity,
+                // Unfilled liquidation orders have an average price of 0
+                average_price: (liquidation.order.average_price > 0.0)
+                    .then_some(liquidation.order.average_price),
+                filled_quantity: liquidation.order.filled_quantity,
+                total_quantity: liquidation.order.quantity,
+                status: liquidation.order.liquidation_status(),
                 time: liquidation.order.time,
             },
         })])
     }
 }
 
+impl BinanceLiquidationOrder {
+    /// Determine the [`LiquidationStatus`] of the liquidated order from its order status (eg/
+    /// "PARTIALLY_FILLED").
+    ///
+    /// Liquidation orders are IOC, so any other order status (eg/ "EXPIRED") is determined from
+    /// the filled & total quantities.
+    pub fn liquidation_status(&self) -> LiquidationStatus {
+        match self.status.as_str() {
+            "FILLED" => LiquidationStatus::Filled,
+            "PARTIALLY_FILLED" => LiquidationStatus::PartiallyFilled,
+            _ => LiquidationStatus::from_quantities(self.filled_quantity, self.quantity),
+        }
+    }
+
+    /// Market of the liquidated order (eg/ "BTCUSDT").
+    pub fn market(&self) -> &str {
+        self.subscription_id
+            .0
+            .split_once('|')
+            .map_or(self.subscription_id.0.as_str(), |(_, market)| market)
+    }
+}
+
+/// Resolve the [`InstrumentKind::FuturePerpetual`] [`Instrument`] of a [`BinanceFuturesUsd`]
+/// market (eg/ "BTCUSDT" -> btc_usdt), using the known [`QUOTE_ASSETS_BINANCE_FUTURES_USD`].
+///
+/// Returns `None` for markets that cannot be resolved (eg/ quarterly "BTCUSDT_240628").
+pub fn perpetual_instrument_from_market(market: &str) -> Option<Instrument> {
+    QUOTE_ASSETS_BINANCE_FUTURES_USD.iter().find_map(|quote| {
+        let base = market.strip_suffix(quote)?;
+        (!base.is_empty() && base.chars().all(|char| char.is_ascii_alphanumeric())).then(|| {
+            Instrument::from((
+                base.to_lowercase(),
+                quote.to_lowercase(),
+                InstrumentKind::FuturePerpetual,
+            ))
+        })
+    })
+}
+
+/// [`BinanceFuturesUsd`] [`LiquidationsAll`] [`ExchangeTransformer`] that resolves the
+/// [`Instrument`] of each [`BinanceLiquidation`] from its market (eg/ "BTCUSDT") via
+/// [`perpetual_instrument_from_market`], rather than from the [`Map`] of subscribed
+/// [`Instrument`]s.
+///
+/// Markets that cannot be resolved yield a recoverable [`SocketError::Unidentifiable`], so the
+/// stream continues with the next liquidation.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BinanceLiquidationsAllTransformer {
+    metrics: MetricsHook,
+}
+
+#[async_trait]
+impl ExchangeTransformer<BinanceFuturesUsd, LiquidationsAll> for BinanceLiquidationsAllTransformer {
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        _: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self::default())
+    }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        self.metrics = metrics;
+    }
+}
+
+impl Transformer for BinanceLiquidationsAllTransformer {
+    type Error = DataError;
+    type Input = BinanceLiquidation;
+    type Output = MarketEvent<Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let subscription_id = input.order.subscription_id.clone();
+
+        let output = match perpetual_instrument_from_market(input.order.market()) {
+            Some(instrument) => {
+                MarketIter::<Liquidation>::from((BinanceFuturesUsd::ID, instrument, input)).0
+            }
+            None => vec![Err(DataError::Socket(SocketError::Unidentifiable(
+                subscription_id.clone(),
+            )))],
+        };
+
+        self.metrics.report(&subscription_id, &output);
+        output
+    }
+}
+
 /// Deserialize a [`BinanceLiquidationOrder`] "s" (eg/ "BTCUSDT") as the associated
 /// [`SubscriptionId`].
 ///
@@ -116,6 +243,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::exchange::binance::message::BinanceMessage;
 
     mod de {
         use super::*;
@@ -152,6 +280,9 @@ mod tests {
                         side: Side::Sell,
                         price: 18917.15,
                         quantity: 0.009,
+                        average_price: 18990.0,
+                        status: "FILLED".to_string(),
+                        filled_quantity: 0.009,
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1665523974217,
                         )),
@@ -160,4 +291,207 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_binance_liquidation_fills() {
+        struct TestCase {
+            input: &'static str,
+            expected: (Option<f64>, f64, f64, LiquidationStatus),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: filled liquidation order
+                input: r#"{"e":"forceOrder","E":1665523974222,"o":{"s":"BTCUSDT","S":"SELL","o":"LIMIT","f":"IOC","q":"0.009","p":"18917.15","ap":"18990.00","X":"FILLED","l":"0.009","z":"0.009","T":1665523974217}}"#,
+                expected: (Some(18990.0), 0.009, 0.009, LiquidationStatus::Filled),
+            },
+            TestCase {
+                // TC1: partially filled liquidation order w/ z < q
+                input: r#"{"e":"forceOrder","E":1665523974222,"o":{"s":"BTCUSDT","S":"SELL","o":"LIMIT","f":"IOC","q":"0.009","p":"18917.15","ap":"18985.50","X":"PARTIALLY_FILLED","l":"0.004","z":"0.004","T":1665523974217}}"#,
+                expected: (
+                    Some(18985.5),
+                    0.004,
+                    0.009,
+                    LiquidationStatus::PartiallyFilled,
+                ),
+            },
+            TestCase {
+                // TC2: expired IOC liquidation order w/ z < q is partially filled
+                input: r#"{"e":"forceOrder","E":1665523974222,"o":{"s":"BTCUSDT","S":"BUY","o":"LIMIT","f":"IOC","q":"1.5","p":"19100.00","ap":"19050.00","X":"EXPIRED","l":"0.5","z":"0.5","T":1665523974217}}"#,
+                expected: (Some(19050.0), 0.5, 1.5, LiquidationStatus::PartiallyFilled),
+            },
+            TestCase {
+                // TC3: expired & unfilled liquidation order has no average price
+                input: r#"{"e":"forceOrder","E":1665523974222,"o":{"s":"BTCUSDT","S":"BUY","o":"LIMIT","f":"IOC","q":"1.5","p":"19100.00","ap":"0.00","X":"EXPIRED","l":"0","z":"0","T":1665523974217}}"#,
+                expected: (None, 0.0, 1.5, LiquidationStatus::Unknown),
+            },
+        ];
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<BinanceLiquidation>(test.input).unwrap();
+            let liquidation = MarketIter::<Liquidation>::from((
+                ExchangeId::BinanceFuturesUsd,
+                instrument.clone(),
+                input,
+            ))
+            .0
+            .remove(0)
+            .unwrap()
+            .kind;
+
+            let actual = (
+                liquidation.average_price,
+                liquidation.filled_quantity,
+                liquidation.total_quantity,
+                liquidation.status,
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_perpetual_instrument_from_market() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Instrument>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: USDT margined market
+                input: "BTCUSDT",
+                expected: Some(Instrument::from((
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                ))),
+            },
+            TestCase {
+                // TC1: USDC margined market
+                input: "ETHUSDC",
+                expected: Some(Instrument::from((
+                    "eth",
+                    "usdc",
+                    InstrumentKind::FuturePerpetual,
+                ))),
+            },
+            TestCase {
+                // TC2: numeric base prefix
+                input: "1000PEPEUSDT",
+                expected: Some(Instrument::from((
+                    "1000pepe",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                ))),
+            },
+            TestCase {
+                // TC3: quarterly delivery market cannot be resolved
+                input: "BTCUSDT_240628",
+                expected: None,
+            },
+            TestCase {
+                // TC4: market without a base cannot be resolved
+                input: "USDT",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = perpetual_instrument_from_market(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_binance_liquidations_all_transformer() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = <BinanceLiquidationsAllTransformer as ExchangeTransformer<
+            BinanceFuturesUsd,
+            LiquidationsAll,
+        >>::new(ws_sink_tx, Map::from_iter([]))
+        .await
+        .unwrap();
+
+        let liquidation = |market: &str, side: &str, time: u64| {
+            format!(
+                r#"{{"e":"forceOrder","E":{time},"o":{{"s":"{market}","S":"{side}","o":"LIMIT","f":"IOC","q":"0.009","p":"18917.15","ap":"18990.00","X":"FILLED","l":"0.009","z":"0.009","T":{time}}}}}"#
+            )
+        };
+
+        struct TestCase {
+            input: String,
+            expected: Result<(Instrument, Side), SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTCUSDT liquidation resolves to btc_usdt without a Map entry
+                input: liquidation("BTCUSDT", "SELL", 1665523974217),
+                expected: Ok((
+                    Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    Side::Sell,
+                )),
+            },
+            TestCase {
+                // TC1: ETHUSDT liquidation resolves to eth_usdt
+                input: liquidation("ETHUSDT", "BUY", 1665523974218),
+                expected: Ok((
+                    Instrument::from(("eth", "usdt", InstrumentKind::FuturePerpetual)),
+                    Side::Buy,
+                )),
+            },
+            TestCase {
+                // TC2: unresolvable quarterly market yields a recoverable error
+                input: liquidation("BTCUSDT_240628", "SELL", 1665523974219),
+                expected: Err(SubscriptionId::from("@forceOrder|BTCUSDT_240628")),
+            },
+            TestCase {
+                // TC3: stream continues with the next liquidation
+                input: liquidation("1000PEPEUSDC", "BUY", 1665523974220),
+                expected: Ok((
+                    Instrument::from(("1000pepe", "usdc", InstrumentKind::FuturePerpetual)),
+                    Side::Buy,
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<BinanceLiquidation>(&test.input).unwrap();
+            let actual = transformer.transform(input);
+            assert_eq!(actual.len(), 1, "TC{} failed", index);
+
+            match (actual.into_iter().next().unwrap(), test.expected) {
+                (Ok(event), Ok((instrument, side))) => {
+                    assert_eq!(event.instrument, instrument, "TC{} failed", index);
+                    assert_eq!(event.kind.side, side, "TC{} failed", index);
+                }
+                (Err(error), Err(subscription_id)) => {
+                    assert!(!error.is_terminal(), "TC{} failed", index);
+                    assert!(
+                        matches!(
+                            error,
+                            DataError::Socket(SocketError::Unidentifiable(actual)) if actual == subscription_id
+                        ),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+
+        // Liquidations are also delivered within the BinanceMessage wrapper of the stream
+        let message = serde_json::from_str::<BinanceMessage<BinanceLiquidation>>(&liquidation(
+            "SOLUSDT",
+            "SELL",
+            1665523974221,
+        ))
+        .unwrap();
+        assert!(matches!(message, BinanceMessage::Data(_)));
+    }
 }
@@ -0,0 +1,168 @@
+use super::super::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{subscription::ExchangeSub, ExchangeId},
+    subscription::mark_price::MarkPrice,
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) mark price & funding rate message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#mark-price-stream>
+/// ```json
+/// {
+///     "e": "markPriceUpdate",
+///     "E": 1562305380000,
+///     "s": "BTCUSDT",
+///     "p": "11794.15000000",
+///     "i": "11784.62659091",
+///     "P": "11784.25641265",
+///     "r": "0.00038167",
+///     "T": 1562306400000
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceMarkPrice {
+    #[serde(alias = "s", deserialize_with = "de_mark_price_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(alias = "i", deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+    #[serde(alias = "r", deserialize_with = "barter_integration::de::de_str")]
+    pub funding_rate: f64,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceMarkPrice {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceMarkPrice)> for MarketIter<MarkPrice> {
+    fn from(
+        (exchange_id, instrument, mark_price): (ExchangeId, Instrument, BinanceMarkPrice),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: mark_price.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: MarkPrice {
+                mark_price: mark_price.mark_price,
+                index_price: Some(mark_price.index_price),
+                funding_rate: Some(mark_price.funding_rate),
+                next_funding_time: Some(mark_price.next_funding_time),
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceMarkPrice`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
+///
+/// eg/ "@markPrice@1s|BTCUSDT"
+pub fn de_mark_price_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::MARK_PRICES, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_mark_price() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceMarkPrice, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid BinanceMarkPrice
+                    input: r#"
+                    {
+                        "e": "markPriceUpdate",
+                        "E": 1562305380000,
+                        "s": "BTCUSDT",
+                        "p": "11794.15000000",
+                        "i": "11784.62659091",
+                        "P": "11784.25641265",
+                        "r": "0.00038167",
+                        "T": 1562306400000
+                    }
+                    "#,
+                    expected: Ok(BinanceMarkPrice {
+                        subscription_id: SubscriptionId::from("@markPrice@1s|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1562305380000,
+                        )),
+                        mark_price: 11794.15,
+                        index_price: 11784.62659091,
+                        funding_rate: 0.00038167,
+                        next_funding_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1562306400000,
+                        )),
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid BinanceMarkPrice w/ missing funding rate
+                    input: r#"
+                    {
+                        "e": "markPriceUpdate",
+                        "E": 1562305380000,
+                        "s": "BTCUSDT",
+                        "p": "11794.15000000",
+                        "i": "11784.62659091",
+                        "T": 1562306400000
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceMarkPrice>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,10 +1,17 @@
-use self::{l2::BinanceFuturesBookUpdater, liquidation::BinanceLiquidation};
-use super::{Binance, ExchangeServer};
+use self::{
+    l2::BinanceFuturesBookUpdater,
+    liquidation::{BinanceLiquidation, BinanceLiquidationsAllTransformer},
+    mark_price::BinanceMarkPrice,
+};
+use super::{Binance, BinanceWsStream, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, liquidation::Liquidations},
+    subscription::{
+        book::OrderBooksL2,
+        liquidation::{Liquidations, LiquidationsAll},
+        mark_price::MarkPrices,
+    },
     transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
-    ExchangeWsStream,
 };
 
 /// Level 2 OrderBook types (top of book) and futures
@@ -14,6 +21,9 @@ pub mod l2;
 /// Liquidation types.
 pub mod liquidation;
 
+/// Mark price & funding rate types.
+pub mod mark_price;
+
 /// [`BinanceFuturesUsd`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
@@ -32,13 +42,286 @@ impl ExchangeServer for BinanceServerFuturesUsd {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD
     }
+
+    fn websocket_url_sandbox() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD_TESTNET)
+    }
+}
+
+/// [`BinanceFuturesUsdTestnet`] WebSocket server base url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#general-info>
+pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD_TESTNET: &str =
+    "wss://stream.binancefuture.com/ws";
+
+/// [`Binance`](super::Binance) futures usd testnet exchange.
+pub type BinanceFuturesUsdTestnet = Binance<BinanceServerFuturesUsdTestnet>;
+
+/// [`Binance`](super::Binance) futures usd testnet
+/// [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceServerFuturesUsdTestnet;
+
+impl ExchangeServer for BinanceServerFuturesUsdTestnet {
+    const ID: ExchangeId = ExchangeId::BinanceFuturesUsdTestnet;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD_TESTNET
+    }
+
+    fn websocket_url_sandbox() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_FUTURES_USD_TESTNET)
+    }
+}
+
+/// [`BinanceFuturesCoin`] WebSocket server base url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#websocket-market-streams>
+pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_COIN: &str = "wss://dstream.binance.com/ws";
+
+/// [`BinanceFuturesCoin`] testnet WebSocket server base url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#general-info>
+pub const WEBSOCKET_BASE_URL_BINANCE_FUTURES_COIN_TESTNET: &str =
+    "wss://dstream.binancefuture.com/ws";
+
+/// [`Binance`](super::Binance) coin margined futures exchange.
+///
+/// ### Notes
+/// - Perpetual markets are identified with a "_PERP" suffix (eg/ "BTCUSD_PERP"), see
+///   [`BinanceMarket`](super::market::BinanceMarket).
+/// - Trade, liquidation & kline quantities (eg/ trade "q", kline "v") are denominated in
+///   contracts (eg/ 100 USD for BTCUSD_PERP), not in the base asset. They are exposed as is,
+///   so [`PublicTrade::amount`](crate::subscription::trade::PublicTrade::amount) &
+///   [`Candle::volume`](crate::subscription::candle::Candle::volume) are contract counts, and
+///   the kline base asset volume "q" is the [`Candle::quote_volume`](crate::subscription::candle::Candle::quote_volume).
+pub type BinanceFuturesCoin = Binance<BinanceServerFuturesCoin>;
+
+/// [`Binance`](super::Binance) coin margined futures
+/// [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceServerFuturesCoin;
+
+impl ExchangeServer for BinanceServerFuturesCoin {
+    const ID: ExchangeId = ExchangeId::BinanceFuturesCoin;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BINANCE_FUTURES_COIN
+    }
+
+    fn websocket_url_sandbox() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_FUTURES_COIN_TESTNET)
+    }
 }
 
 impl StreamSelector<OrderBooksL2> for BinanceFuturesUsd {
     type Stream =
-        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
+        BinanceWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceFuturesBookUpdater>>;
 }
 
 impl StreamSelector<Liquidations> for BinanceFuturesUsd {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
+    type Stream = BinanceWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
+}
+
+impl StreamSelector<Liquidations> for BinanceFuturesCoin {
+    type Stream = BinanceWsStream<StatelessTransformer<Self, Liquidations, BinanceLiquidation>>;
+}
+
+impl StreamSelector<LiquidationsAll> for BinanceFuturesUsd {
+    type Stream = BinanceWsStream<BinanceLiquidationsAllTransformer>;
+}
+
+impl StreamSelector<MarkPrices> for BinanceFuturesUsd {
+    type Stream = BinanceWsStream<StatelessTransformer<Self, MarkPrices, BinanceMarkPrice>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{channel::BinanceChannel, market::BinanceMarket},
+        *,
+    };
+    use crate::{
+        exchange::{binance::candle::BinanceCandle, Connector, ExchangeSub},
+        subscription::{
+            book::OrderBooksL1,
+            candle::{Candles, VolumeDenomination},
+            number::from_f64,
+            trade::PublicTrades,
+            Interval, SubKind, Subscription,
+        },
+        test_util::{instrument_map, transform_frames, Fixtures},
+        transformer::ExchangeTransformer,
+        Identifier,
+    };
+    use barter_integration::model::{Instrument, InstrumentKind, Side};
+    use chrono::{TimeZone, Utc};
+    use std::fmt::Debug;
+
+    /// Transform the only fixture of the provided `BinanceFuturesCoin` kind directory (eg/
+    /// "trades") for a BTC/USD perpetual subscription.
+    async fn transform_fixture<Kind, Transformer>(kind: Kind, fixtures: &str) -> Kind::Event
+    where
+        Kind: SubKind,
+        Kind::Event: Debug,
+        Transformer: ExchangeTransformer<BinanceFuturesCoin, Kind>,
+        Subscription<BinanceFuturesCoin, Kind>: Identifier<BinanceChannel>,
+    {
+        let fixtures = Fixtures::load("binance_futures_coin", fixtures);
+        let instrument_map = instrument_map::<BinanceFuturesCoin, Kind>(&[Subscription::from((
+            BinanceFuturesCoin::default(),
+            "btc",
+            "usd",
+            InstrumentKind::FuturePerpetual,
+            kind,
+        ))]);
+
+        let mut actual = transform_frames::<BinanceFuturesCoin, Kind, Transformer, _>(
+            instrument_map,
+            fixtures.frames.values(),
+        )
+        .await;
+        assert_eq!(actual.len(), 1, "{actual:?}");
+
+        let event = actual.remove(0).unwrap();
+        assert_eq!(
+            event.instrument,
+            Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual))
+        );
+        event.kind
+    }
+
+    #[test]
+    fn test_binance_futures_coin_market() {
+        struct TestCase {
+            input: BinanceMarket,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceFuturesCoin perpetual markets have a "_PERP" suffix
+                input: Subscription::from((
+                    BinanceFuturesCoin::default(),
+                    "btc",
+                    "usd",
+                    InstrumentKind::FuturePerpetual,
+                    PublicTrades,
+                ))
+                .id(),
+                expected: "BTCUSD_PERP",
+            },
+            TestCase {
+                // TC1: BinanceFuturesCoin perpetual markets of every SubKind are suffixed
+                input: Subscription::from((
+                    BinanceFuturesCoin::default(),
+                    "eth",
+                    "usd",
+                    InstrumentKind::FuturePerpetual,
+                    Liquidations,
+                ))
+                .id(),
+                expected: "ETHUSD_PERP",
+            },
+            TestCase {
+                // TC2: BinanceFuturesUsd perpetual markets are not suffixed
+                input: Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    PublicTrades,
+                ))
+                .id(),
+                expected: "BTCUSDT",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input.as_ref(), test.expected, "TC{} failed", index);
+        }
+
+        // Suffixed markets are subscribed to in lowercase & received in uppercase
+        let subscription = Subscription::from((
+            BinanceFuturesCoin::default(),
+            "btc",
+            "usd",
+            InstrumentKind::FuturePerpetual,
+            Candles(Interval::Minute1),
+        ));
+        let exchange_sub = ExchangeSub::<BinanceChannel, BinanceMarket>::new(&subscription);
+        assert_eq!(exchange_sub.id().0, "@kline_1m|BTCUSD_PERP");
+        assert_eq!(
+            BinanceFuturesCoin::requests(vec![exchange_sub]),
+            vec![barter_integration::protocol::websocket::WsMessage::Text(
+                serde_json::json!({
+                    "method": "SUBSCRIBE",
+                    "params": ["btcusd_perp@kline_1m"],
+                    "id": 1
+                })
+                .to_string()
+            )]
+        );
+        assert_eq!(
+            BinanceFuturesCoin::url().unwrap().as_str(),
+            "wss://dstream.binance.com/ws"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_binance_futures_coin_fixtures() {
+        use crate::exchange::binance::{book::l1::BinanceOrderBookL1, trade::BinanceTrade};
+
+        let time = |ms| Utc.timestamp_millis_opt(ms).unwrap();
+
+        // Trade quantities are denominated in contracts
+        let trade = transform_fixture::<
+            PublicTrades,
+            StatelessTransformer<BinanceFuturesCoin, PublicTrades, BinanceTrade>,
+        >(PublicTrades, "trades")
+        .await;
+        assert_eq!(trade.id, "523441227");
+        assert_eq!(trade.price, from_f64(37012.5));
+        assert_eq!(trade.amount, from_f64(12.0));
+        assert_eq!(trade.side, Side::Sell);
+
+        let book = transform_fixture::<
+            OrderBooksL1,
+            StatelessTransformer<BinanceFuturesCoin, OrderBooksL1, BinanceOrderBookL1>,
+        >(OrderBooksL1, "order_books_l1")
+        .await;
+        assert_eq!(book.last_update_time, time(1700000000095));
+        assert_eq!(book.best_bid.price, from_f64(37012.4));
+        assert_eq!(book.best_bid.amount, from_f64(1540.0));
+        assert_eq!(book.best_ask.price, from_f64(37012.5));
+        assert_eq!(book.best_ask.amount, from_f64(311.0));
+
+        let liquidation = transform_fixture::<
+            Liquidations,
+            StatelessTransformer<BinanceFuturesCoin, Liquidations, BinanceLiquidation>,
+        >(Liquidations, "liquidations")
+        .await;
+        assert_eq!(liquidation.side, Side::Sell);
+        assert_eq!(liquidation.price, 36950.0);
+        assert_eq!(liquidation.average_price, Some(36980.0));
+        assert_eq!(liquidation.total_quantity, 25.0);
+        assert_eq!(liquidation.time, time(1700000000095));
+
+        // Kline "v" volume is denominated in contracts, and "q" in the base asset
+        let candle = transform_fixture::<
+            Candles,
+            StatelessTransformer<BinanceFuturesCoin, Candles, BinanceCandle>,
+        >(Candles(Interval::Minute1), "candles")
+        .await;
+        assert_eq!(candle.start_time, time(1699999980000));
+        assert_eq!(candle.close, from_f64(37012.5));
+        assert_eq!(candle.volume, from_f64(5880.0));
+        assert_eq!(candle.volume_denomination, VolumeDenomination::Contracts);
+        assert_eq!(candle.base_volume, Some(from_f64(15.88904931)));
+        assert_eq!(candle.quote_volume, None);
+        assert_eq!(candle.taker_buy_volume, Some(from_f64(7.99538479)));
+        assert_eq!(candle.taker_buy_quote_volume, None);
+        assert_eq!(candle.trade_count, 28);
+        assert!(!candle.closed);
+    }
 }
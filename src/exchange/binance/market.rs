@@ -1,21 +1,39 @@
 use super::Binance;
-use crate::{subscription::Subscription, Identifier};
+use crate::{
+    exchange::{ExchangeId, ExchangeServer},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::InstrumentKind};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a [`Binance`](super::Binance)
 /// market that can be subscribed to.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#websocket-market-streams>
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#websocket-market-streams>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BinanceMarket(pub String);
 
-impl<Server, Kind> Identifier<BinanceMarket> for Subscription<Binance<Server>, Kind> {
+impl<Server, Kind> Identifier<BinanceMarket> for Subscription<Binance<Server>, Kind>
+where
+    Server: ExchangeServer,
+{
     fn id(&self) -> BinanceMarket {
         // Notes:
         // - Must be lowercase when subscribing (transformed to lowercase by Binance fn requests).
         // - Must be uppercase since Binance sends message with uppercase MARKET (eg/ BTCUSDT).
-        BinanceMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+        // - BinanceFuturesCoin perpetual markets have a "_PERP" suffix (eg/ BTCUSD_PERP).
+        let market = format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase();
+
+        match (Server::ID, self.instrument.kind) {
+            (ExchangeId::BinanceFuturesCoin, InstrumentKind::FuturePerpetual) => {
+                BinanceMarket(format!("{market}_PERP"))
+            }
+            _ => BinanceMarket(market),
+        }
     }
 }
 
@@ -24,3 +42,126 @@ impl AsRef<str> for BinanceMarket {
         &self.0
     }
 }
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// [`BinanceFuturesCoin`](super::futures::BinanceFuturesCoin) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/delivery/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_COIN: &str =
+    "https://dapi.binance.com/dapi/v1/exchangeInfo";
+
+/// [`BinanceUs`](super::spot::BinanceUs) HTTP exchange information url.
+///
+/// See docs: <https://docs.binance.us/#get-exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_US: &str = "https://api.binance.us/api/v3/exchangeInfo";
+
+/// [`BinanceSpotTestnet`](super::spot::BinanceSpotTestnet) HTTP exchange information url.
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT_TESTNET: &str =
+    "https://testnet.binance.vision/api/v3/exchangeInfo";
+
+/// [`BinanceFuturesUsdTestnet`](super::futures::BinanceFuturesUsdTestnet) HTTP exchange
+/// information url.
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD_TESTNET: &str =
+    "https://testnet.binancefuture.com/fapi/v1/exchangeInfo";
+
+/// [`Binance`](super::Binance) HTTP exchange information response, listing every market.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "ETHBTC",
+///             "status": "TRADING",
+///             "baseAsset": "ETH",
+///             "quoteAsset": "BTC"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// [`Binance`](super::Binance) market listed in the [`BinanceExchangeInfo`].
+///
+/// [`BinanceFuturesCoin`](super::futures::BinanceFuturesCoin) markets provide the status via
+/// "contractStatus".
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolInfo {
+    pub symbol: String,
+    #[serde(alias = "contractStatus")]
+    pub status: String,
+}
+
+impl BinanceExchangeInfo {
+    /// Fetch the [`BinanceExchangeInfo`] from the provided url.
+    pub async fn fetch(url: &str) -> Result<Self, SocketError> {
+        reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<Self>()
+            .await
+            .map_err(SocketError::Http)
+    }
+
+    /// Every actively trading market, formatted as a [`BinanceMarket`] (eg/ "BTCUSDT",
+    /// "BTCUSD_PERP").
+    pub fn markets(self) -> HashSet<String> {
+        self.symbols
+            .into_iter()
+            .filter(|info| info.status == "TRADING")
+            .map(|info| info.symbol.to_uppercase())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::backfill::tests::mock_http_server;
+
+    #[tokio::test]
+    async fn test_binance_exchange_info_fetch_from_mock_server() {
+        let (url, request) = mock_http_server(
+            r#"{
+                "timezone": "UTC",
+                "serverTime": 1565246363776,
+                "symbols": [
+                    {"symbol": "ETHBTC", "status": "TRADING", "baseAsset": "ETH", "quoteAsset": "BTC"},
+                    {"symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC", "quoteAsset": "USDT"},
+                    {"symbol": "LUNAUSDT", "status": "BREAK", "baseAsset": "LUNA", "quoteAsset": "USDT"}
+                ]
+            }"#,
+        )
+        .await;
+
+        let markets = BinanceExchangeInfo::fetch(&format!("{url}/api/v3/exchangeInfo"))
+            .await
+            .unwrap()
+            .markets();
+
+        assert_eq!(
+            markets,
+            HashSet::from(["ETHBTC".to_string(), "BTCUSDT".to_string()])
+        );
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("GET /api/v3/exchangeInfo "));
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{error::DataError, transformer::error::ExchangeMessage};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) error code sent when the request weight limit is exceeded,
+/// including when the IP address has been banned.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#error-codes>
+pub const BINANCE_ERROR_CODE_TOO_MANY_REQUESTS: i64 = -1003;
+
+/// [`Binance`](super::Binance) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#error-messages>
+///
+/// #### Invalid Request Error
+/// ```json
+/// {
+///     "error": {
+///         "code": 2,
+///         "msg": "Invalid request: unknown variant `subscribe`"
+///     },
+///     "id": 1
+/// }
+/// ```
+///
+/// #### Rate Limit Ban Error
+/// ```json
+/// {
+///     "id": 1,
+///     "status": 418,
+///     "error": {
+///         "code": -1003,
+///         "msg": "Way too much request weight used; IP banned until 1659146400000."
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BinanceMessage<T> {
+    Data(T),
+    Error(BinanceError),
+}
+
+impl<T> ExchangeMessage for BinanceMessage<T> {
+    type Data = T;
+
+    fn into_data(self) -> Result<T, DataError> {
+        match self {
+            BinanceMessage::Data(data) => Ok(data),
+            BinanceMessage::Error(error) => Err(DataError::from(error)),
+        }
+    }
+}
+
+/// [`Binance`](super::Binance) error message sent by the exchange server.
+///
+/// See [`BinanceMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceError {
+    pub error: BinanceErrorDetail,
+}
+
+/// [`Binance`](super::Binance) error code & message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceErrorDetail {
+    pub code: i64,
+    #[serde(rename = "msg")]
+    pub message: String,
+}
+
+impl BinanceError {
+    /// Determine if the error requires the connection to be re-initialised (eg/ rate limit ban),
+    /// rather than only affecting an individual request.
+    pub fn is_fatal(&self) -> bool {
+        self.error.code == BINANCE_ERROR_CODE_TOO_MANY_REQUESTS
+    }
+}
+
+impl From<BinanceError> for DataError {
+    fn from(error: BinanceError) -> Self {
+        let fatal = error.is_fatal();
+        DataError::Exchange {
+            code: error.error.code.to_string(),
+            message: error.error.message,
+            fatal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::binance::trade::BinanceTrade;
+
+        #[test]
+        fn test_binance_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceMessage<BinanceTrade>, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input is invalid request BinanceError
+                    input: r#"{"error":{"code":2,"msg":"Invalid request: unknown variant `subscribe`"},"id":1}"#,
+                    expected: Ok(BinanceMessage::Error(BinanceError {
+                        error: BinanceErrorDetail {
+                            code: 2,
+                            message: "Invalid request: unknown variant `subscribe`".to_string(),
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input is rate limit ban BinanceError
+                    input: r#"{"id":1,"status":418,"error":{"code":-1003,"msg":"Way too much request weight used"}}"#,
+                    expected: Ok(BinanceMessage::Error(BinanceError {
+                        error: BinanceErrorDetail {
+                            code: -1003,
+                            message: "Way too much request weight used".to_string(),
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC2: input is neither data nor error
+                    input: r#"{"result":null,"id":1}"#,
+                    expected: Err(serde::de::Error::custom("")),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceMessage<BinanceTrade>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_error_is_fatal() {
+        let error = |code| BinanceError {
+            error: BinanceErrorDetail {
+                code,
+                message: String::new(),
+            },
+        };
+
+        assert!(!error(2).is_fatal());
+        assert!(error(BINANCE_ERROR_CODE_TOO_MANY_REQUESTS).is_fatal());
+    }
+}
@@ -1,34 +1,70 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
-    subscription::BinanceSubResponse, trade::BinanceTrade,
+    book::{
+        l1::{BinanceOrderBookL1, BinanceOrderBookL1Client},
+        snapshot::BinanceOrderBookSnapshot,
+        UpdateSpeed,
+    },
+    candle::{BinanceCandle, BinanceCandlesClient},
+    channel::BinanceChannel,
+    market::{
+        BinanceExchangeInfo, BinanceMarket, HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_COIN,
+        HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD,
+        HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD_TESTNET, HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT,
+        HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT_TESTNET, HTTP_EXCHANGE_INFO_URL_BINANCE_US,
+    },
+    message::BinanceMessage,
+    subscription::BinanceSubResponse,
+    ticker::BinanceTicker,
+    trade::{BinanceAggTrade, BinanceTrade},
+    validator::{BinanceSubValidator, BINANCE_SUBSCRIBE_REQUEST_ID},
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeServer, ExchangeSub, StreamSelector},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Map},
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    decoder::PlainText,
+    exchange::{
+        AckMode, Connector, Environment, ExchangeId, ExchangeServer, ExchangeSub, MarketsFuture,
+        StreamSelector,
+    },
+    streams::{backfill::BackfillClient, snapshot::SnapshotClient},
+    subscriber::WebSocketSubscriber,
+    subscription::{
+        book::{OrderBookSnapshots, OrderBooksL1},
+        candle::{CandleUpdates, Candles, ClosedCandles},
+        ticker::Tickers,
+        trade::{PublicTrades, PublicTradesAggregated},
+        Interval, Subscription,
+    },
+    transformer::{error::ErrorTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream, Identifier,
 };
-use barter_integration::{error::SocketError, model::Instrument, protocol::websocket::WsMessage};
-use std::{fmt::Debug, marker::PhantomData};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use url::Url;
 
 /// OrderBook types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod book;
 
+/// Candle types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
-/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd) and
+/// [`BinanceFuturesCoin`](futures::BinanceFuturesCoin).
 pub mod futures;
 
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// WebSocket message variants, including error messages, common to both
+/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod message;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BinanceSpot`](spot::BinanceSpot).
 pub mod spot;
@@ -38,18 +74,82 @@ pub mod spot;
 /// and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod subscription;
 
+/// Ticker (24h rolling statistics) types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod ticker;
+
 /// Public trade types common to both [`BinanceSpot`](spot::BinanceSpot) and
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
 
+/// [`SubscriptionValidator`](crate::subscriber::validator::SubscriptionValidator) implementation
+/// for [`Binance`] correlating subscription responses with the request id.
+pub mod validator;
+
+/// Maximum number of streams [`Binance`] supports on a single connection.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-limits>
+pub const MAX_STREAMS_PER_CONNECTION_BINANCE: usize = 1024;
+
+/// Maximum number of streams actioned by a single [`Binance`] SUBSCRIBE or UNSUBSCRIBE request,
+/// keeping each request well below the maximum payload size accepted by the exchange server.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#live-subscribing-unsubscribing-to-streams>
+pub const MAX_SUBS_PER_REQUEST_BINANCE: usize = 200;
+
+/// Maximum lifetime of a [`Binance`] connection before it is proactively rotated, leaving an
+/// hour of headroom before the exchange server disconnects every connection after 24 hours.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
+pub const MAX_CONNECTION_AGE_BINANCE: Duration = Duration::from_secs(23 * 60 * 60);
+
+/// Convenient type alias for a [`Binance`] [`ExchangeWsStream`] that surfaces
+/// [`BinanceMessage::Error`]s as [`DataError::Exchange`](crate::error::DataError::Exchange)s.
+pub type BinanceWsStream<Transformer> = ExchangeWsStream<
+    ErrorTransformer<
+        Transformer,
+        BinanceMessage<<Transformer as barter_integration::Transformer>::Input>,
+    >,
+>;
+
 /// Generic [`Binance<Server>`](Binance) exchange.
 ///
 /// ### Notes
 /// A `Server` [`ExchangeServer`](super::ExchangeServer) implementations exists for
-/// [`BinanceSpot`](spot::BinanceSpot) and [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+/// [`BinanceSpot`](spot::BinanceSpot), [`BinanceFuturesUsd`](futures::BinanceFuturesUsd),
+/// [`BinanceFuturesCoin`](futures::BinanceFuturesCoin), [`BinanceUs`](spot::BinanceUs), and the [`BinanceSpotTestnet`](spot::BinanceSpotTestnet) &
+/// [`BinanceFuturesUsdTestnet`](futures::BinanceFuturesUsdTestnet) integration testing servers.
+///
+/// Every [`Binance<Server>`](Binance) supports the generic [`StreamSelector`]s (eg/
+/// [`PublicTrades`], [`OrderBooksL1`], [`Candles`]), whereas [`OrderBooksL2`](crate::subscription::book::OrderBooksL2)
+/// & futures specific [`SubKind`](crate::subscription::SubKind)s are production server only.
+///
+/// The [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) diff depth [`UpdateSpeed`]
+/// defaults to [`UpdateSpeed::Ms100`], see [`Binance::with_update_speed`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
 pub struct Binance<Server> {
     server: PhantomData<Server>,
+    update_speed: UpdateSpeed,
+}
+
+impl<Server> Binance<Server> {
+    /// Subscribe to the [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) diff depth
+    /// stream with the provided [`UpdateSpeed`] (eg/ [`UpdateSpeed::Ms1000`] on constrained
+    /// links, or [`UpdateSpeed::Ms0`] on futures).
+    ///
+    /// [`UpdateSpeed`]s unsupported by the `Server` are rejected during
+    /// [`Connector::validate_subscriptions`].
+    pub fn with_update_speed(self, update_speed: UpdateSpeed) -> Self {
+        Self {
+            update_speed,
+            ..self
+        }
+    }
+
+    /// [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) diff depth [`UpdateSpeed`].
+    pub fn update_speed(&self) -> UpdateSpeed {
+        self.update_speed
+    }
 }
 
 impl<Server> Connector for Binance<Server>
@@ -57,58 +157,280 @@ where
     Server: ExchangeServer,
 {
     const ID: ExchangeId = Server::ID;
+    const MAX_STREAMS_PER_CONNECTION: usize = MAX_STREAMS_PER_CONNECTION_BINANCE;
+    const MAX_SUBS_PER_REQUEST: usize = MAX_SUBS_PER_REQUEST_BINANCE;
+    const ACK_MODE: AckMode = AckMode::PerRequest;
     type Channel = BinanceChannel;
     type Market = BinanceMarket;
     type Subscriber = WebSocketSubscriber;
-    type SubValidator = WebSocketSubValidator;
+    type SubValidator = BinanceSubValidator;
     type SubResponse = BinanceSubResponse;
+    type Decoder = PlainText;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        match environment {
+            Environment::Production => Self::url(),
+            Environment::Sandbox => Server::websocket_url_sandbox()
+                .ok_or_else(|| SocketError::Unsupported {
+                    entity: Self::ID.as_str(),
+                    item: format!("{environment} environment"),
+                })
+                .and_then(|url| Url::parse(url).map_err(SocketError::UrlParse)),
+        }
+    }
+
+    fn supported_intervals() -> &'static [Interval] {
+        &[
+            Interval::Minute1,
+            Interval::Minute3,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Hour1,
+            Interval::Hour2,
+            Interval::Hour4,
+            Interval::Hour6,
+            Interval::Hour8,
+            Interval::Hour12,
+            Interval::Day1,
+            Interval::Day3,
+            Interval::Week1,
+            Interval::Month1,
+        ]
+    }
+
+    fn max_connection_age() -> Option<Duration> {
+        Some(MAX_CONNECTION_AGE_BINANCE)
+    }
+
+    fn subscription_url<Kind>(
+        environment: Environment,
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<Url, SocketError>
+    where
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        let mut url = Self::url_for(environment)?;
+
+        // Partial book depth messages do not always contain the market, and diff depth messages
+        // do not contain the update speed, so use the combined stream endpoint which wraps each
+        // message with the associated stream name
+        if subscriptions.first().is_some_and(|subscription| {
+            let channel: BinanceChannel = subscription.id();
+            channel.is_order_book_snapshots() || channel.is_order_book_l2()
+        }) {
+            url.set_path("/stream");
+        }
+
+        Ok(url)
+    }
+
+    fn validate_subscriptions<Kind>(
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<(), SocketError>
+    where
+        Kind: Debug,
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        if let Some(unsupported) = subscriptions.iter().find(|subscription| {
+            subscription.id() == BinanceChannel::ORDER_BOOK_SNAPSHOTS_UNSUPPORTED
+        }) {
+            return Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} (supported depths: top5, top10, top20)",
+                    unsupported.kind
+                ),
+            });
+        }
+
+        match subscriptions
+            .iter()
+            .find(|subscription| subscription.id() == BinanceChannel::ORDER_BOOK_L2_UNSUPPORTED)
+        {
+            Some(unsupported) => Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} update speed {} (supported update speeds: {})",
+                    unsupported.kind,
+                    unsupported.exchange.update_speed,
+                    supported_update_speeds(Self::ID)
+                        .iter()
+                        .map(UpdateSpeed::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn fetch_markets() -> Option<MarketsFuture> {
+        let url = match Server::ID {
+            ExchangeId::BinanceFuturesUsd => HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD,
+            ExchangeId::BinanceFuturesCoin => HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_COIN,
+            ExchangeId::BinanceFuturesUsdTestnet => {
+                HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD_TESTNET
+            }
+            ExchangeId::BinanceSpotTestnet => HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT_TESTNET,
+            ExchangeId::BinanceUs => HTTP_EXCHANGE_INFO_URL_BINANCE_US,
+            _ => HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT,
+        };
+
+        Some(Box::pin(async move {
+            BinanceExchangeInfo::fetch(url)
+                .await
+                .map(BinanceExchangeInfo::markets)
+        }))
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        let stream_names = exchange_subs
-            .into_iter()
-            .map(|sub| {
-                // Note:
-                // Market must be lowercase when subscribing, but lowercase in general since
-                // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
-                format!(
-                    "{}{}",
-                    sub.market.as_ref().to_lowercase(),
-                    sub.channel.as_ref()
-                )
-            })
-            .collect::<Vec<String>>();
-
-        vec![WsMessage::Text(
-            serde_json::json!({
-                "method": "SUBSCRIBE",
-                "params": stream_names,
-                "id": 1
-            })
-            .to_string(),
-        )]
-    }
-
-    fn expected_responses(_: &Map<Instrument>) -> usize {
-        1
+        binance_requests("SUBSCRIBE", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        binance_requests("UNSUBSCRIBE", exchange_subs)
+    }
+}
+
+/// [`UpdateSpeed`]s of the [`OrderBooksL2`](crate::subscription::book::OrderBooksL2) diff depth
+/// stream supported by the provided [`Binance`] server.
+pub fn supported_update_speeds(exchange: ExchangeId) -> &'static [UpdateSpeed] {
+    match exchange.supports_futures() {
+        true => &UpdateSpeed::FUTURES,
+        false => &UpdateSpeed::SPOT,
     }
 }
 
+/// Construct the [`Binance`] [`WsMessage`] payloads that action the provided `method` (eg/
+/// "SUBSCRIBE", "UNSUBSCRIBE") for a collection of [`ExchangeSub`]s.
+///
+/// [`ExchangeSub`]s are ordered by stream name & [`SubscriptionId`], and actioned in chunks of
+/// [`MAX_SUBS_PER_REQUEST_BINANCE`], each with an incrementing request "id" starting from
+/// [`BINANCE_SUBSCRIBE_REQUEST_ID`] (see [`BinanceSubValidator::ack_subscriptions`]).
+///
+/// All market streams (eg/ "!forceOrder@arr") are actioned without a market, and only once
+/// per request regardless of the number of [`ExchangeSub`]s.
+fn binance_requests(
+    method: &str,
+    exchange_subs: Vec<ExchangeSub<BinanceChannel, BinanceMarket>>,
+) -> Vec<WsMessage> {
+    let mut stream_names = exchange_subs
+        .iter()
+        .map(|sub| {
+            (
+                stream_name(sub.channel.as_ref(), sub.market.as_ref()),
+                sub.id(),
+            )
+        })
+        .collect::<Vec<_>>();
+    stream_names.sort();
+
+    stream_names
+        .chunks(MAX_SUBS_PER_REQUEST_BINANCE)
+        .zip(BINANCE_SUBSCRIBE_REQUEST_ID..)
+        .map(|(chunk, id)| {
+            let params = chunk.iter().map(|(stream_name, _)| stream_name).fold(
+                Vec::<&String>::new(),
+                |mut params, stream_name| {
+                    if !params.contains(&stream_name) {
+                        params.push(stream_name);
+                    }
+                    params
+                },
+            );
+
+            WsMessage::Text(
+                serde_json::json!({
+                    "method": method,
+                    "params": params,
+                    "id": id
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Determine the [`Binance`] stream name of the provided channel & market (eg/ "btcusdt@trade").
+///
+/// All market streams (eg/ "!forceOrder@arr") have no market, see
+/// [`BinanceChannel::is_all_markets`].
+fn stream_name(channel: &str, market: &str) -> String {
+    if channel.starts_with('!') {
+        return channel.to_string();
+    }
+
+    // Note:
+    // Market must be lowercase when subscribing, but lowercase in general since
+    // Binance sends message with uppercase MARKET (eg/ BTCUSDT).
+    format!("{}{}", market.to_lowercase(), channel)
+}
+
 impl<Server> StreamSelector<PublicTrades> for Binance<Server>
 where
     Server: ExchangeServer + Debug + Send + Sync,
 {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BinanceTrade>>;
+    type Stream = BinanceWsStream<StatelessTransformer<Self, PublicTrades, BinanceTrade>>;
+}
+
+impl<Server> StreamSelector<PublicTradesAggregated> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        BinanceWsStream<StatelessTransformer<Self, PublicTradesAggregated, BinanceAggTrade>>;
 }
 
 impl<Server> StreamSelector<OrderBooksL1> for Binance<Server>
 where
     Server: ExchangeServer + Debug + Send + Sync,
 {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BinanceOrderBookL1>>;
+    type Stream = BinanceWsStream<StatelessTransformer<Self, OrderBooksL1, BinanceOrderBookL1>>;
+
+    fn snapshot_client() -> Option<Box<dyn SnapshotClient<Self, OrderBooksL1>>> {
+        Some(Box::new(BinanceOrderBookL1Client::server::<Server>()))
+    }
+}
+
+impl<Server> StreamSelector<OrderBookSnapshots> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        BinanceWsStream<StatelessTransformer<Self, OrderBookSnapshots, BinanceOrderBookSnapshot>>;
+}
+
+impl<Server> StreamSelector<Tickers> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = BinanceWsStream<StatelessTransformer<Self, Tickers, BinanceTicker>>;
+}
+
+impl<Server> StreamSelector<Candles> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream =
+        BinanceWsStream<StatelessTransformer<Self, Candles, ClosedCandles<BinanceCandle>>>;
+
+    fn backfill_client() -> Option<Box<dyn BackfillClient<Self, Candles>>> {
+        Some(Box::new(BinanceCandlesClient::server::<Server>()))
+    }
+}
+
+impl<Server> StreamSelector<CandleUpdates> for Binance<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = BinanceWsStream<StatelessTransformer<Self, CandleUpdates, BinanceCandle>>;
 }
 
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
@@ -145,3 +467,369 @@ where
         serializer.serialize_str(exchange_id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{
+            futures::{BinanceFuturesUsd, BinanceFuturesUsdTestnet},
+            spot::{BinanceSpot, BinanceSpotTestnet, BinanceUs},
+        },
+        subscription::{
+            book::{Depth, OrderBooksL2},
+            liquidation::{Liquidations, LiquidationsAll},
+            Interval,
+        },
+    };
+    use barter_integration::{model::InstrumentKind, Validator};
+
+    #[test]
+    fn test_binance_server_variants() {
+        assert_eq!(
+            BinanceUs::url().unwrap().as_str(),
+            "wss://stream.binance.us:9443/ws"
+        );
+        assert_eq!(
+            BinanceSpotTestnet::url().unwrap().as_str(),
+            "wss://testnet.binance.vision/ws"
+        );
+        assert_eq!(
+            BinanceFuturesUsdTestnet::url().unwrap().as_str(),
+            "wss://stream.binancefuture.com/ws"
+        );
+
+        // Config files can specify the ExchangeId of each server variant
+        for (input, expected) in [
+            ("\"binance_us\"", ExchangeId::BinanceUs),
+            ("\"binanceus\"", ExchangeId::BinanceUs),
+            ("\"binance_spot_testnet\"", ExchangeId::BinanceSpotTestnet),
+            (
+                "\"binance_futures_usd_testnet\"",
+                ExchangeId::BinanceFuturesUsdTestnet,
+            ),
+            ("\"binance_futures_coin\"", ExchangeId::BinanceFuturesCoin),
+        ] {
+            assert_eq!(serde_json::from_str::<ExchangeId>(input).unwrap(), expected);
+        }
+        assert_eq!(
+            serde_json::from_str::<BinanceUs>("\"binance_us\"").unwrap(),
+            BinanceUs::default()
+        );
+    }
+
+    #[test]
+    fn test_binance_us_validate_instrument_kind() {
+        struct TestCase {
+            input: InstrumentKind,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Spot is supported by Binance US
+                input: InstrumentKind::Spot,
+                expected: true,
+            },
+            TestCase {
+                // TC1: FuturePerpetual is not supported by Binance US
+                input: InstrumentKind::FuturePerpetual,
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription =
+                Subscription::from((BinanceUs::default(), "btc", "usd", test.input, PublicTrades));
+            assert_eq!(
+                subscription.validate().is_ok(),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_binance_validate_subscriptions_order_book_snapshots() {
+        struct TestCase {
+            input: Depth,
+            expected: Result<(), SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Top5 snapshots are supported
+                input: Depth::Top5,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Top20 snapshots are supported
+                input: Depth::Top20,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: Top50 snapshots are unsupported
+                input: Depth::Top50,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription = Subscription::from((
+                BinanceSpot::default(),
+                ("btc", "usdt", InstrumentKind::Spot),
+                OrderBookSnapshots::new(test.input),
+            ));
+            let actual = BinanceSpot::validate_subscriptions(&[subscription]);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_binance_order_book_l2_update_speed_channels() {
+        struct TestCase {
+            input: BinanceChannel,
+            expected: &'static str,
+        }
+
+        let spot = |update_speed| -> BinanceChannel {
+            Subscription::from((
+                BinanceSpot::default().with_update_speed(update_speed),
+                ("btc", "usdt", InstrumentKind::Spot),
+                OrderBooksL2,
+            ))
+            .id()
+        };
+        let futures = |update_speed| -> BinanceChannel {
+            Subscription::from((
+                BinanceFuturesUsd::default().with_update_speed(update_speed),
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                OrderBooksL2,
+            ))
+            .id()
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot default 100ms
+                input: spot(UpdateSpeed::default()),
+                expected: "@depth@100ms",
+            },
+            TestCase {
+                // TC1: BinanceSpot 1000ms is the unsuffixed channel
+                input: spot(UpdateSpeed::Ms1000),
+                expected: "@depth",
+            },
+            TestCase {
+                // TC2: BinanceSpot 0ms is unsupported
+                input: spot(UpdateSpeed::Ms0),
+                expected: "@depth@unsupported",
+            },
+            TestCase {
+                // TC3: BinanceSpot 500ms is unsupported
+                input: spot(UpdateSpeed::Ms500),
+                expected: "@depth@unsupported",
+            },
+            TestCase {
+                // TC4: BinanceFuturesUsd 0ms
+                input: futures(UpdateSpeed::Ms0),
+                expected: "@depth@0ms",
+            },
+            TestCase {
+                // TC5: BinanceFuturesUsd default 100ms
+                input: futures(UpdateSpeed::default()),
+                expected: "@depth@100ms",
+            },
+            TestCase {
+                // TC6: BinanceFuturesUsd 250ms is the unsuffixed channel
+                input: futures(UpdateSpeed::Ms250),
+                expected: "@depth",
+            },
+            TestCase {
+                // TC7: BinanceFuturesUsd 500ms
+                input: futures(UpdateSpeed::Ms500),
+                expected: "@depth@500ms",
+            },
+            TestCase {
+                // TC8: BinanceFuturesUsd 1000ms is unsupported
+                input: futures(UpdateSpeed::Ms1000),
+                expected: "@depth@unsupported",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input.as_ref(), test.expected, "TC{} failed", index);
+            assert!(test.input.is_order_book_l2(), "TC{} failed", index);
+            assert!(!test.input.is_order_book_snapshots(), "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_validate_subscriptions_order_book_l2_update_speed() {
+        let spot = |update_speed| {
+            BinanceSpot::validate_subscriptions(&[Subscription::from((
+                BinanceSpot::default().with_update_speed(update_speed),
+                ("btc", "usdt", InstrumentKind::Spot),
+                OrderBooksL2,
+            ))])
+        };
+        let futures = |update_speed| {
+            BinanceFuturesUsd::validate_subscriptions(&[Subscription::from((
+                BinanceFuturesUsd::default().with_update_speed(update_speed),
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                OrderBooksL2,
+            ))])
+        };
+
+        // Every UpdateSpeed supported by each server is valid
+        for update_speed in UpdateSpeed::SPOT {
+            assert!(spot(update_speed).is_ok(), "{update_speed} failed");
+        }
+        for update_speed in UpdateSpeed::FUTURES {
+            assert!(futures(update_speed).is_ok(), "{update_speed} failed");
+        }
+
+        // 0ms only exists on futures, and 1000ms only exists on spot
+        match spot(UpdateSpeed::Ms0) {
+            Err(SocketError::Unsupported { entity, item }) => {
+                assert_eq!(entity, "binance_spot");
+                assert_eq!(
+                    item,
+                    "OrderBooksL2 update speed 0ms (supported update speeds: 100ms, 1000ms)"
+                );
+            }
+            other => panic!("expected SocketError::Unsupported, got {other:?}"),
+        }
+        assert!(matches!(
+            futures(UpdateSpeed::Ms1000),
+            Err(SocketError::Unsupported { .. })
+        ));
+    }
+
+    #[test]
+    fn test_binance_subscription_url() {
+        // Trades are served via the raw stream endpoint
+        let trades = vec![Subscription::from((
+            BinanceSpot::default(),
+            ("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        ))];
+        assert_eq!(
+            BinanceSpot::subscription_url(Environment::Production, &trades)
+                .unwrap()
+                .as_str(),
+            "wss://stream.binance.com:9443/ws"
+        );
+
+        // OrderBook snapshots are served via the combined stream endpoint
+        let snapshots = vec![Subscription::from((
+            BinanceFuturesUsd::default(),
+            ("btc", "usdt", InstrumentKind::FuturePerpetual),
+            OrderBookSnapshots::new(Depth::Top10),
+        ))];
+        assert_eq!(
+            BinanceFuturesUsd::subscription_url(Environment::Production, &snapshots)
+                .unwrap()
+                .as_str(),
+            "wss://fstream.binance.com/stream"
+        );
+
+        // OrderBook diff depth deltas of every UpdateSpeed are served via the combined stream
+        // endpoint, since messages do not contain the UpdateSpeed
+        let books = vec![Subscription::from((
+            BinanceSpot::default().with_update_speed(UpdateSpeed::Ms1000),
+            ("btc", "usdt", InstrumentKind::Spot),
+            OrderBooksL2,
+        ))];
+        assert_eq!(
+            BinanceSpot::subscription_url(Environment::Production, &books)
+                .unwrap()
+                .as_str(),
+            "wss://stream.binance.com:9443/stream"
+        );
+    }
+
+    #[test]
+    fn test_binance_unsubscribe_requests() {
+        let exchange_subs = vec![
+            ExchangeSub::new(&Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))),
+            ExchangeSub::new(&Subscription::from((
+                BinanceSpot::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            ))),
+        ];
+
+        assert_eq!(
+            BinanceSpot::unsubscribe_requests(exchange_subs),
+            vec![WsMessage::Text(
+                serde_json::json!({
+                    "method": "UNSUBSCRIBE",
+                    "params": ["btcusdt@trade", "ethusdt@kline_1m"],
+                    "id": 1
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_binance_requests_all_markets() {
+        let exchange_subs = vec![
+            ExchangeSub::new(&Subscription::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                LiquidationsAll,
+            ))),
+            ExchangeSub::new(&Subscription::from((
+                BinanceFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                Liquidations,
+            ))),
+            ExchangeSub::new(&Subscription::from((
+                BinanceFuturesUsd::default(),
+                "eth",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                LiquidationsAll,
+            ))),
+        ];
+
+        // All market streams are subscribed to once, without a market
+        assert_eq!(
+            BinanceFuturesUsd::requests(exchange_subs),
+            vec![WsMessage::Text(
+                serde_json::json!({
+                    "method": "SUBSCRIBE",
+                    "params": ["!forceOrder@arr", "btcusdt@forceOrder"],
+                    "id": 1
+                })
+                .to_string()
+            )]
+        );
+    }
+}
@@ -1,4 +1,7 @@
-use super::super::book::{l2::BinanceOrderBookL2Snapshot, BinanceLevel};
+use super::super::book::{
+    l2::{fetch_snapshot, BinanceCombinedStream},
+    BinanceLevel,
+};
 use crate::{
     error::DataError,
     subscription::book::OrderBook,
@@ -7,7 +10,6 @@ use crate::{
 };
 use async_trait::async_trait;
 use barter_integration::{
-    error::SocketError,
     model::{Instrument, SubscriptionId},
     protocol::websocket::WsMessage,
 };
@@ -22,6 +24,8 @@ pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://api.binance.co
 
 /// [`BinanceSpot`](super::BinanceSpot) OrderBook Level2 deltas WebSocket message.
 ///
+/// Received wrapped in a [`BinanceCombinedStream`], which determines the [`SubscriptionId`].
+///
 /// ### Raw Payload Examples
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#partial-book-depth-streams>
 /// ```json
@@ -39,6 +43,7 @@ pub const HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT: &str = "https://api.binance.co
 /// }
 /// ```
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 pub struct BinanceSpotOrderBookL2Delta {
     #[serde(
         alias = "s",
@@ -55,6 +60,36 @@ pub struct BinanceSpotOrderBookL2Delta {
     pub asks: Vec<BinanceLevel>,
 }
 
+impl<'de> Deserialize<'de> for BinanceSpotOrderBookL2Delta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Data(#[serde(with = "BinanceSpotOrderBookL2Delta")] BinanceSpotOrderBookL2Delta);
+
+        // SubscriptionId is derived from the stream name, which contains the UpdateSpeed
+        let BinanceCombinedStream {
+            stream,
+            data: Data(delta),
+        } = BinanceCombinedStream::deserialize(deserializer)?;
+
+        Ok(Self {
+            subscription_id: stream,
+            ..delta
+        })
+    }
+}
+
+impl Serialize for BinanceSpotOrderBookL2Delta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        Self::serialize(self, serializer)
+    }
+}
+
 impl Identifier<Option<SubscriptionId>> for BinanceSpotOrderBookL2Delta {
     fn id(&self) -> Option<SubscriptionId> {
         Some(self.subscription_id.clone())
@@ -81,6 +116,8 @@ impl Identifier<Option<SubscriptionId>> for BinanceSpotOrderBookL2Delta {
 ///
 /// Notes:
 ///  - Receiving an event that removes a price level that is not in your local order book can happen and is normal.
+///  - A sequence gap yields a terminal [`DataError::InvalidSequence`], causing the
+///    [`MarketStream`](crate::MarketStream) to re-initialise and re-sync from a fresh snapshot.
 ///  - Uppercase U => first_update_id
 ///  - Lowercase u => last_update_id,
 ///
@@ -149,6 +186,21 @@ impl BinanceSpotBookUpdater {
             })
         }
     }
+
+    /// Initialise the [`InstrumentOrderBook`] for the provided [`Instrument`] using an
+    /// OrderBook snapshot fetched from the provided `snapshot_url` (eg/ a mock server in tests).
+    pub async fn init_with_snapshot_url(
+        snapshot_url: &str,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError> {
+        let snapshot = fetch_snapshot(snapshot_url, &instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.last_update_id),
+            book: OrderBook::from(snapshot),
+        })
+    }
 }
 
 #[async_trait]
@@ -164,27 +216,7 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         Exchange: Send,
         Kind: Send,
     {
-        // Construct initial OrderBook snapshot GET url
-        let snapshot_url = format!(
-            "{}?symbol={}{}&limit=100",
-            HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT,
-            instrument.base.as_ref().to_uppercase(),
-            instrument.quote.as_ref().to_uppercase()
-        );
-
-        // Fetch initial OrderBook snapshot via HTTP
-        let snapshot = reqwest::get(snapshot_url)
-            .await
-            .map_err(SocketError::Http)?
-            .json::<BinanceOrderBookL2Snapshot>()
-            .await
-            .map_err(SocketError::Http)?;
-
-        Ok(InstrumentOrderBook {
-            instrument,
-            updater: Self::new(snapshot.last_update_id),
-            book: OrderBook::from(snapshot),
-        })
+        Self::init_with_snapshot_url(HTTP_BOOK_L2_SNAPSHOT_URL_BINANCE_SPOT, instrument).await
     }
 
     fn update(
@@ -215,6 +247,8 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         book.last_update_time = Utc::now();
         book.bids.upsert(update.bids);
         book.asks.upsert(update.asks);
+        book.first_update_id = Some(update.first_update_id);
+        book.last_update_id = Some(update.last_update_id);
 
         // Update OrderBookUpdater metadata
         self.updates_processed += 1;
@@ -228,46 +262,85 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
 
         #[test]
         fn test_binance_spot_order_book_l2_delta() {
-            let input = r#"
-            {
-                "e":"depthUpdate",
-                "E":1671656397761,
-                "s":"ETHUSDT",
-                "U":22611425143,
-                "u":22611425151,
-                "b":[
-                    ["1209.67000000","85.48210000"],
-                    ["1209.66000000","20.68790000"]
-                ],
-                "a":[]
+            struct TestCase {
+                input: &'static str,
+                expected: BinanceSpotOrderBookL2Delta,
             }
-            "#;
 
-            assert_eq!(
-                serde_json::from_str::<BinanceSpotOrderBookL2Delta>(input).unwrap(),
-                BinanceSpotOrderBookL2Delta {
-                    subscription_id: SubscriptionId::from("@depth@100ms|ETHUSDT"),
-                    first_update_id: 22611425143,
-                    last_update_id: 22611425151,
-                    bids: vec![
-                        BinanceLevel {
-                            price: 1209.67000000,
-                            amount: 85.48210000
-                        },
-                        BinanceLevel {
-                            price: 1209.66000000,
-                            amount: 20.68790000
-                        },
-                    ],
-                    asks: vec![]
-                }
-            );
+            let delta = |subscription_id: &str| BinanceSpotOrderBookL2Delta {
+                subscription_id: SubscriptionId::from(subscription_id),
+                first_update_id: 22611425143,
+                last_update_id: 22611425151,
+                bids: vec![
+                    BinanceLevel {
+                        price: from_f64(1209.67000000),
+                        amount: from_f64(85.48210000),
+                    },
+                    BinanceLevel {
+                        price: from_f64(1209.66000000),
+                        amount: from_f64(20.68790000),
+                    },
+                ],
+                asks: vec![],
+            };
+
+            let tests = vec![
+                TestCase {
+                    // TC0: default 100ms UpdateSpeed stream
+                    input: r#"
+                    {
+                        "stream":"ethusdt@depth@100ms",
+                        "data":{
+                            "e":"depthUpdate",
+                            "E":1671656397761,
+                            "s":"ETHUSDT",
+                            "U":22611425143,
+                            "u":22611425151,
+                            "b":[
+                                ["1209.67000000","85.48210000"],
+                                ["1209.66000000","20.68790000"]
+                            ],
+                            "a":[]
+                        }
+                    }
+                    "#,
+                    expected: delta("@depth@100ms|ETHUSDT"),
+                },
+                TestCase {
+                    // TC1: 1000ms UpdateSpeed stream is routed via the unsuffixed channel
+                    input: r#"
+                    {
+                        "stream":"ethusdt@depth",
+                        "data":{
+                            "e":"depthUpdate",
+                            "E":1671656397761,
+                            "s":"ETHUSDT",
+                            "U":22611425143,
+                            "u":22611425151,
+                            "b":[
+                                ["1209.67000000","85.48210000"],
+                                ["1209.66000000","20.68790000"]
+                            ],
+                            "a":[]
+                        }
+                    }
+                    "#,
+                    expected: delta("@depth|ETHUSDT"),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    serde_json::from_str::<BinanceSpotOrderBookL2Delta>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
         }
     }
 
@@ -397,8 +470,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_first_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(_), Ok(_)) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -460,8 +533,8 @@ mod tests {
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = test.updater.validate_next_update(&test.input);
                 match (actual, test.expected) {
-                    (Ok(actual), Ok(expected)) => {
-                        assert_eq!(actual, expected, "TC{} failed", index)
+                    (Ok(_), Ok(_)) => {
+                        // Test passed
                     }
                     (Err(_), Err(_)) => {
                         // Test passed
@@ -474,6 +547,79 @@ mod tests {
             }
         }
 
+        #[tokio::test]
+        async fn test_init_with_snapshot_url_sequence() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<Option<()>, DataError>,
+            }
+
+            let url = crate::exchange::binance::book::l2::tests::mock_snapshot_server(
+                r#"{"lastUpdateId": 100, "bids": [["50.0", "1.0"]], "asks": [["100.0", "1.0"]]}"#,
+            );
+            let mut book = BinanceSpotBookUpdater::init_with_snapshot_url(
+                &url,
+                Instrument::from((
+                    "btc",
+                    "usdt",
+                    barter_integration::model::InstrumentKind::Spot,
+                )),
+            )
+            .await
+            .unwrap();
+            assert_eq!(book.updater, BinanceSpotBookUpdater::new(100));
+
+            let tests = vec![
+                TestCase {
+                    // TC0: delta older than the snapshot is discarded
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"s":"BTCUSDT","U":90,"u":100,"b":[["50.0","0"]],"a":[]}}"#,
+                    expected: Ok(None),
+                },
+                TestCase {
+                    // TC1: first delta overlapping the snapshot is applied
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"s":"BTCUSDT","U":95,"u":105,"b":[["60.0","1.0"]],"a":[]}}"#,
+                    expected: Ok(Some(())),
+                },
+                TestCase {
+                    // TC2: next sequential delta is applied
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"s":"BTCUSDT","U":106,"u":110,"b":[["60.0","2.0"]],"a":[]}}"#,
+                    expected: Ok(Some(())),
+                },
+                TestCase {
+                    // TC3: sequence gap is terminal, triggering a re-sync
+                    input: r#"{"stream":"btcusdt@depth@100ms","data":{"e":"depthUpdate","E":1,"s":"BTCUSDT","U":120,"u":130,"b":[["50.0","0"]],"a":[]}}"#,
+                    expected: Err(DataError::InvalidSequence {
+                        prev_last_update_id: 110,
+                        first_update_id: 120,
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let update =
+                    serde_json::from_str::<BinanceSpotOrderBookL2Delta>(test.input).unwrap();
+                let actual = book.updater.update(&mut book.book, update);
+
+                match (actual, test.expected) {
+                    (Ok(Some(_)), Ok(Some(_))) | (Ok(None), Ok(None)) => {
+                        // Test passed
+                    }
+                    (Err(actual), Err(_)) => {
+                        assert!(actual.is_terminal(), "TC{} failed", index)
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+
+            assert_eq!(
+                book.book.snapshot().bids,
+                OrderBookSide::new(Side::Buy, vec![Level::new(60, 2), Level::new(50, 1)]),
+            );
+        }
+
         #[test]
         fn update() {
             struct TestCase {
@@ -497,6 +643,8 @@ mod tests {
                         last_update_time: time,
                         bids: OrderBookSide::new(Side::Buy, vec![Level::new(50, 1)]),
                         asks: OrderBookSide::new(Side::Sell, vec![Level::new(100, 1)]),
+                        first_update_id: None,
+                        last_update_id: None,
                     },
                     input_update: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -524,6 +672,8 @@ mod tests {
                             Side::Sell,
                             vec![Level::new(150, 1), Level::new(110, 1), Level::new(120, 1)],
                         ),
+                        first_update_id: None,
+                        last_update_id: None,
                     },
                     input_update: BinanceSpotOrderBookL2Delta {
                         subscription_id: SubscriptionId::from("subscription_id"),
@@ -532,25 +682,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: from_f64(80.0),
+                                amount: from_f64(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: from_f64(90.0),
+                                amount: from_f64(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: from_f64(200.0),
+                                amount: from_f64(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: from_f64(500.0),
+                                amount: from_f64(0.0),
                             },
                         ],
                     },
@@ -569,6 +719,8 @@ mod tests {
                                 Level::new(200, 1),
                             ],
                         ),
+                        first_update_id: Some(101),
+                        last_update_id: Some(110),
                     })),
                 },
             ];
@@ -1,23 +1,15 @@
 use self::l2::BinanceSpotBookUpdater;
-use super::{Binance, ExchangeServer};
-use crate::exchange::binance::spot::candles::BinanceCandle;
-use crate::subscription::Interval;
-use crate::transformer::stateless::StatelessTransformer;
+use super::{Binance, BinanceWsStream, ExchangeServer};
 use crate::{
     exchange::{ExchangeId, StreamSelector},
-    subscription::{book::OrderBooksL2, candle::Candles},
+    subscription::book::OrderBooksL2,
     transformer::book::MultiBookTransformer,
-    ExchangeWsStream,
 };
 
 /// Level 2 OrderBook types (top of book) and spot
 /// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
 pub mod l2;
 
-/// Candles types
-/// [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater) implementation.
-pub mod candles;
-
 /// [`BinanceSpot`] WebSocket server base url.
 ///
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
@@ -36,13 +28,56 @@ impl ExchangeServer for BinanceServerSpot {
     fn websocket_url() -> &'static str {
         WEBSOCKET_BASE_URL_BINANCE_SPOT
     }
+
+    fn websocket_url_sandbox() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_SPOT_TESTNET)
+    }
 }
 
-impl StreamSelector<OrderBooksL2> for BinanceSpot {
-    type Stream =
-        ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
+/// [`BinanceUs`] WebSocket server base url.
+///
+/// See docs: <https://docs.binance.us/#websocket-streams>
+pub const WEBSOCKET_BASE_URL_BINANCE_US: &str = "wss://stream.binance.us:9443/ws";
+
+/// [`Binance`](super::Binance) US spot exchange.
+pub type BinanceUs = Binance<BinanceServerUs>;
+
+/// [`Binance`](super::Binance) US spot [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceServerUs;
+
+impl ExchangeServer for BinanceServerUs {
+    const ID: ExchangeId = ExchangeId::BinanceUs;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BINANCE_US
+    }
 }
 
-impl StreamSelector<Candles> for BinanceSpot {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, BinanceCandle>>;
+/// [`BinanceSpotTestnet`] WebSocket server base url.
+///
+/// See docs: <https://testnet.binance.vision/>
+pub const WEBSOCKET_BASE_URL_BINANCE_SPOT_TESTNET: &str = "wss://testnet.binance.vision/ws";
+
+/// [`Binance`](super::Binance) spot testnet exchange.
+pub type BinanceSpotTestnet = Binance<BinanceServerSpotTestnet>;
+
+/// [`Binance`](super::Binance) spot testnet [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BinanceServerSpotTestnet;
+
+impl ExchangeServer for BinanceServerSpotTestnet {
+    const ID: ExchangeId = ExchangeId::BinanceSpotTestnet;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BINANCE_SPOT_TESTNET
+    }
+
+    fn websocket_url_sandbox() -> Option<&'static str> {
+        Some(WEBSOCKET_BASE_URL_BINANCE_SPOT_TESTNET)
+    }
+}
+
+impl StreamSelector<OrderBooksL2> for BinanceSpot {
+    type Stream = BinanceWsStream<MultiBookTransformer<Self, OrderBooksL2, BinanceSpotBookUpdater>>;
 }
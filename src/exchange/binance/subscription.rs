@@ -1,3 +1,4 @@
+use super::message::BinanceErrorDetail;
 use barter_integration::{error::SocketError, Validator};
 use serde::{Deserialize, Serialize};
 
@@ -20,10 +21,22 @@ use serde::{Deserialize, Serialize};
 ///     "result":[]
 /// }
 /// ```
+///
+/// #### Subscription Error
+/// ```json
+/// {
+///     "id":1,
+///     "error":{"code":2,"msg":"Invalid request: unknown variant `subscribe`"}
+/// }
+/// ```
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BinanceSubResponse {
-    result: Option<Vec<String>>,
-    id: u32,
+    #[serde(default)]
+    pub result: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<BinanceErrorDetail>,
+    /// Request id echoed back by the exchange, used to correlate the response with the request.
+    pub id: u32,
 }
 
 impl Validator for BinanceSubResponse {
@@ -31,12 +44,15 @@ impl Validator for BinanceSubResponse {
     where
         Self: Sized,
     {
-        if self.result.is_none() {
-            Ok(self)
-        } else {
-            Err(SocketError::Subscribe(
+        match (&self.result, &self.error) {
+            (None, None) => Ok(self),
+            (_, Some(error)) => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                error.code, error.message
+            ))),
+            (Some(_), None) => Err(SocketError::Subscribe(
                 "received failure subscription response".to_owned(),
-            ))
+            )),
         }
     }
 }
@@ -61,6 +77,7 @@ mod tests {
                     input: r#"{"id":1,"result":null}"#,
                     expected: Ok(BinanceSubResponse {
                         result: None,
+                        error: None,
                         id: 1,
                     }),
                 },
@@ -69,9 +86,30 @@ mod tests {
                     input: r#"{"result": [], "id": 1}"#,
                     expected: Ok(BinanceSubResponse {
                         result: Some(vec![]),
+                        error: None,
                         id: 1,
                     }),
                 },
+                TestCase {
+                    // TC2: input response is error subscription
+                    input: r#"{"id":2,"error":{"code":2,"msg":"Invalid request"}}"#,
+                    expected: Ok(BinanceSubResponse {
+                        result: None,
+                        error: Some(BinanceErrorDetail {
+                            code: 2,
+                            message: "Invalid request".to_string(),
+                        }),
+                        id: 2,
+                    }),
+                },
+                TestCase {
+                    // TC3: input is market data without a request id
+                    input: r#"{"e":"trade","E":1649324825173,"s":"ETHUSDT"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
             ];
 
             for (index, test) in cases.into_iter().enumerate() {
@@ -104,6 +142,7 @@ mod tests {
                 // TC0: input response is successful subscription
                 input_response: BinanceSubResponse {
                     result: None,
+                    error: None,
                     id: 1,
                 },
                 is_valid: true,
@@ -112,6 +151,19 @@ mod tests {
                 // TC1: input response is failed subscription
                 input_response: BinanceSubResponse {
                     result: Some(vec![]),
+                    error: None,
+                    id: 1,
+                },
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: input response is error subscription
+                input_response: BinanceSubResponse {
+                    result: None,
+                    error: Some(BinanceErrorDetail {
+                        code: 2,
+                        message: "Invalid request".to_string(),
+                    }),
                     id: 1,
                 },
                 is_valid: false,
@@ -0,0 +1,234 @@
+use super::channel::BinanceChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{subscription::ExchangeSub, ExchangeId},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) real-time individual symbol rolling 24h ticker message.
+///
+/// ### Raw Payload Examples
+/// #### BinanceSpot Ticker
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#individual-symbol-ticker-streams>
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "x": "0.0009",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "b": "0.0024",
+///     "B": "10",
+///     "a": "0.0026",
+///     "A": "100",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+///
+/// #### BinanceFuturesUsd Ticker
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#individual-symbol-ticker-streams>
+/// ```json
+/// {
+///     "e": "24hrTicker",
+///     "E": 123456789,
+///     "s": "BTCUSDT",
+///     "p": "0.0015",
+///     "P": "250.00",
+///     "w": "0.0018",
+///     "c": "0.0025",
+///     "Q": "10",
+///     "o": "0.0010",
+///     "h": "0.0025",
+///     "l": "0.0010",
+///     "v": "10000",
+///     "q": "18",
+///     "O": 0,
+///     "C": 86400000,
+///     "F": 0,
+///     "L": 18150,
+///     "n": 18151
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceTicker {
+    #[serde(alias = "s", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "E",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub quote_volume: f64,
+    #[serde(alias = "P", deserialize_with = "barter_integration::de::de_str")]
+    pub price_change_percent: f64,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, BinanceTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: Ticker {
+                last_price: ticker.last_price,
+                open_24h: ticker.open,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                volume_24h: ticker.volume,
+                quote_volume_24h: Some(ticker.quote_volume),
+                price_change_percent_24h: ticker.price_change_percent,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`BinanceTicker`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
+///
+/// eg/ "@ticker|BTCUSDT"
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::TICKERS, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_binance_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Spot BinanceTicker
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BNBBTC", "p": "0.0015",
+                        "P": "250.00", "w": "0.0018", "x": "0.0009", "c": "0.0025", "Q": "10",
+                        "b": "0.0024", "B": "10", "a": "0.0026", "A": "100", "o": "0.0010",
+                        "h": "0.0025", "l": "0.0010", "v": "10000", "q": "18", "O": 0,
+                        "C": 86400000, "F": 0, "L": 18150, "n": 18151
+                    }
+                    "#,
+                    expected: Ok(BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last_price: 0.0025,
+                        open: 0.0010,
+                        high: 0.0025,
+                        low: 0.0010,
+                        volume: 10000.0,
+                        quote_volume: 18.0,
+                        price_change_percent: 250.0,
+                    }),
+                },
+                TestCase {
+                    // TC1: valid FuturePerpetual BinanceTicker
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BTCUSDT", "p": "-100.00",
+                        "P": "-0.60", "w": "16600.1", "c": "16550.0", "Q": "0.010",
+                        "o": "16650.0", "h": "16800.5", "l": "16400.0", "v": "250000.123",
+                        "q": "4150000000.50", "O": 0, "C": 86400000, "F": 0, "L": 18150,
+                        "n": 18151
+                    }
+                    "#,
+                    expected: Ok(BinanceTicker {
+                        subscription_id: SubscriptionId::from("@ticker|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        last_price: 16550.0,
+                        open: 16650.0,
+                        high: 16800.5,
+                        low: 16400.0,
+                        volume: 250000.123,
+                        quote_volume: 4150000000.50,
+                        price_change_percent: -0.60,
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid BinanceTicker w/ non-numeric last price
+                    input: r#"
+                    {
+                        "e": "24hrTicker", "E": 1672515782136, "s": "BTCUSDT", "P": "-0.60",
+                        "c": "invalid", "o": "16650.0", "h": "16800.5", "l": "16400.0",
+                        "v": "250000.123", "q": "4150000000.50"
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@ use super::BinanceChannel;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::{number::Number, trade::PublicTrade},
     Identifier,
 };
 use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
@@ -56,17 +56,26 @@ pub struct BinanceTrade {
     pub subscription_id: SubscriptionId,
     #[serde(
         alias = "T",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     #[serde(alias = "t")]
     pub id: u64,
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
-    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
+    pub amount: Number,
+    #[serde(
+        alias = "m",
+        deserialize_with = "crate::subscription::trade::de_side_from_buyer_is_maker"
+    )]
     pub side: Side,
+    /// Buyer order id, only provided by [`BinanceSpot`](super::spot::BinanceSpot).
+    #[serde(alias = "b", default)]
+    pub buyer_order_id: Option<u64>,
+    /// Seller order id, only provided by [`BinanceSpot`](super::spot::BinanceSpot).
+    #[serde(alias = "a", default)]
+    pub seller_order_id: Option<u64>,
 }
 
 impl Identifier<Option<SubscriptionId>> for BinanceTrade {
@@ -82,11 +91,108 @@ impl From<(ExchangeId, Instrument, BinanceTrade)> for MarketIter<PublicTrade> {
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: Some(trade.id),
+            tag: None,
             kind: PublicTrade {
                 id: trade.id.to_string(),
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                buyer_order_id: trade.buyer_order_id.map(|id| id.to_string()),
+                seller_order_id: trade.seller_order_id.map(|id| id.to_string()),
+                taker_order_kind: None,
+            },
+        })])
+    }
+}
+
+/// Binance real-time aggregated trade message, compressing fills of the same taker order at the
+/// same price & side.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#aggregate-trade-streams>
+/// #### Spot Side::Sell AggTrade
+/// ```json
+/// {
+///     "e": "aggTrade",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "a": 12345,
+///     "p": "0.001",
+///     "q": "100",
+///     "f": 100,
+///     "l": 105,
+///     "T": 1672515782136,
+///     "m": true,
+///     "M": true
+/// }
+/// ```
+///
+/// #### FuturePerpetual Side::Buy AggTrade
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#aggregate-trade-streams>
+/// ```json
+/// {
+///     "e": "aggTrade",
+///     "E": 123456789,
+///     "s": "BTCUSDT",
+///     "a": 5933014,
+///     "p": "0.001",
+///     "q": "100",
+///     "f": 100,
+///     "l": 105,
+///     "T": 123456785,
+///     "m": false
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceAggTrade {
+    #[serde(alias = "s", deserialize_with = "de_agg_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "a")]
+    pub id: u64,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+    #[serde(alias = "f")]
+    pub first_trade_id: u64,
+    #[serde(alias = "l")]
+    pub last_trade_id: u64,
+    #[serde(
+        alias = "m",
+        deserialize_with = "crate::subscription::trade::de_side_from_buyer_is_maker"
+    )]
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceAggTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, BinanceAggTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, BinanceAggTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: Some(trade.id),
+            tag: None,
+            kind: PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
             },
         })])
     }
@@ -102,30 +208,27 @@ where
         .map(|market| ExchangeSub::from((BinanceChannel::TRADES, market)).id())
 }
 
-/// Deserialize a [`BinanceTrade`] "buyer_is_maker" boolean field to a Barter [`Side`].
-///
-/// Variants:
-/// buyer_is_maker => Side::Sell
-/// !buyer_is_maker => Side::Buy
-pub fn de_side_from_buyer_is_maker<'de, D>(deserializer: D) -> Result<Side, D::Error>
+/// Deserialize a [`BinanceAggTrade`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`]
+/// (eg/ "@aggTrade|BTCUSDT").
+pub fn de_agg_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    Deserialize::deserialize(deserializer).map(|buyer_is_maker| {
-        if buyer_is_maker {
-            Side::Sell
-        } else {
-            Side::Buy
-        }
-    })
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((BinanceChannel::AGG_TRADES, market)).id())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
+        use crate::{
+            exchange::binance::spot::BinanceSpot,
+            test_util::{parse_frame, Fixtures},
+        };
         use barter_integration::de::datetime_utc_from_epoch_duration;
         use barter_integration::error::SocketError;
         use serde::de::Error;
@@ -134,38 +237,32 @@ mod tests {
         #[test]
         fn test_binance_trade() {
             struct TestCase {
-                input: &'static str,
+                fixture: &'static str,
                 expected: Result<BinanceTrade, SocketError>,
             }
 
+            let fixtures = Fixtures::load("binance", "trades");
+
             let tests = vec![
                 TestCase {
                     // TC0: Spot trade valid
-                    input: r#"
-                    {
-                        "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,
-                        "p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,
-                        "T":1749354825200,"m":false,"M":true
-                    }
-                    "#,
+                    fixture: "spot_trade",
                     expected: Ok(BinanceTrade {
                         subscription_id: SubscriptionId::from("@trade|ETHUSDT"),
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: from_f64(10000.19),
+                        amount: from_f64(0.239000),
                         side: Side::Buy,
+                        buyer_order_id: Some(10108767791),
+                        seller_order_id: Some(10108764858),
                     }),
                 },
                 TestCase {
                     // TC1: Spot trade malformed w/ "yes" is_buyer_maker field
-                    input: r#"{
-                        "e":"trade","E":1649324825173,"s":"ETHUSDT","t":1000000000,
-                        "p":"10000.19000000","q":"0.239000","b":10108767791,"a":10108764858,
-                        "T":1649324825173,"m":"yes","M":true
-                    }"#,
+                    fixture: "malformed_is_buyer_maker",
                     expected: Err(SocketError::Deserialise {
                         error: serde_json::Error::custom(""),
                         payload: "".to_owned(),
@@ -173,63 +270,180 @@ mod tests {
                 },
                 TestCase {
                     // TC2: FuturePerpetual trade w/ type MARKET
-                    input: r#"
-                    {
-                        "e": "trade","E": 1649839266194,"T": 1749354825200,"s": "ETHUSDT",
-                        "t": 1000000000,"p":"10000.19","q":"0.239000","X": "MARKET","m": true
-                    }
-                    "#,
+                    fixture: "futures_trade_market",
                     expected: Ok(BinanceTrade {
                         subscription_id: SubscriptionId::from("@trade|ETHUSDT"),
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: from_f64(10000.19),
+                        amount: from_f64(0.239000),
                         side: Side::Sell,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
                 TestCase {
                     // TC3: FuturePerpetual trade w/ type LIQUIDATION
-                    input: r#"
-                    {
-                        "e": "trade","E": 1649839266194,"T": 1749354825200,"s": "ETHUSDT",
-                        "t": 1000000000,"p":"10000.19","q":"0.239000","X": "LIQUIDATION","m": false
-                    }
-                    "#,
+                    fixture: "futures_trade_liquidation",
                     expected: Ok(BinanceTrade {
                         subscription_id: SubscriptionId::from("@trade|ETHUSDT"),
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: from_f64(10000.19),
+                        amount: from_f64(0.239000),
                         side: Side::Buy,
+                        buyer_order_id: None,
+                        seller_order_id: None,
                     }),
                 },
                 TestCase {
-                    // TC4: FuturePerpetual trade w/ type LIQUIDATION
-                    input: r#"{
-                        "e": "trade","E": 1649839266194,"T": 1749354825200,"s": "ETHUSDT",
-                        "t": 1000000000,"p":"10000.19","q":"0.239000","X": "INSURANCE_FUND","m": false
-                    }"#,
+                    // TC4: FuturePerpetual trade w/ type INSURANCE_FUND
+                    fixture: "futures_trade_insurance_fund",
                     expected: Ok(BinanceTrade {
                         subscription_id: SubscriptionId::from("@trade|ETHUSDT"),
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: from_f64(10000.19),
+                        amount: from_f64(0.239000),
+                        side: Side::Buy,
+                        buyer_order_id: None,
+                        seller_order_id: None,
+                    }),
+                },
+                TestCase {
+                    // TC5: Spot trade w/ second resolution trade time converted from seconds
+                    fixture: "spot_trade_epoch_seconds",
+                    expected: Ok(BinanceTrade {
+                        subscription_id: SubscriptionId::from("@trade|ETHUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_secs(1649324825)),
+                        id: 1000000000,
+                        price: from_f64(10000.19),
+                        amount: from_f64(0.239000),
+                        side: Side::Sell,
+                        buyer_order_id: None,
+                        seller_order_id: None,
+                    }),
+                },
+                TestCase {
+                    // TC6: truncated trade frame
+                    fixture: "malformed_truncated",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC7: trade malformed w/ numeric price & string trade id
+                    fixture: "malformed_wrong_types",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC8: trade malformed w/ trade time out of the DateTime<Utc> range, which
+                    // previously panicked the transformer
+                    fixture: "malformed_time_out_of_range",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = parse_frame::<BinanceSpot, BinanceTrade>(fixtures.get(test.fixture));
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (
+                        Err(SocketError::Deserialise { .. }),
+                        Err(SocketError::Deserialise { .. }),
+                    ) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_binance_agg_trade() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BinanceAggTrade, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Spot aggTrade valid
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true
+                    }
+                    "#,
+                    expected: Ok(BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BNBBTC"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782136,
+                        )),
+                        id: 12345,
+                        price: from_f64(0.001),
+                        amount: from_f64(100.0),
+                        first_trade_id: 100,
+                        last_trade_id: 105,
+                        side: Side::Sell,
+                    }),
+                },
+                TestCase {
+                    // TC1: FuturePerpetual aggTrade valid w/o "M" field
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":1672515782136,"s":"BTCUSDT","a":5933014,"p":"0.001",
+                        "q":"100","f":100,"l":105,"T":1672515782132,"m":false
+                    }
+                    "#,
+                    expected: Ok(BinanceAggTrade {
+                        subscription_id: SubscriptionId::from("@aggTrade|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672515782132,
+                        )),
+                        id: 5933014,
+                        price: from_f64(0.001),
+                        amount: from_f64(100.0),
+                        first_trade_id: 100,
+                        last_trade_id: 105,
                         side: Side::Buy,
                     }),
                 },
+                TestCase {
+                    // TC2: aggTrade malformed w/ missing aggregate trade id
+                    input: r#"
+                    {
+                        "e":"aggTrade","E":123456789,"s":"BTCUSDT","p":"0.001","q":"100",
+                        "f":100,"l":105,"T":123456785,"m":false
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
-                let actual = serde_json::from_str::<BinanceTrade>(test.input);
+                let actual = serde_json::from_str::<BinanceAggTrade>(test.input);
                 match (actual, test.expected) {
                     (Ok(actual), Ok(expected)) => {
                         assert_eq!(actual, expected, "TC{} failed", index)
@@ -245,4 +459,92 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_binance_agg_trade_subscription_id_does_not_collide_with_trades() {
+        let trade = serde_json::from_str::<BinanceTrade>(
+            r#"{"s":"BTCUSDT","T":1,"t":1,"p":"1.0","q":"1.0","m":true}"#,
+        )
+        .unwrap();
+        let agg_trade = serde_json::from_str::<BinanceAggTrade>(
+            r#"{"s":"BTCUSDT","T":1,"a":1,"p":"1.0","q":"1.0","f":1,"l":1,"m":true}"#,
+        )
+        .unwrap();
+
+        assert_ne!(trade.subscription_id, agg_trade.subscription_id);
+        assert_eq!(
+            agg_trade.subscription_id,
+            ExchangeSub::from((BinanceChannel::AGG_TRADES, "BTCUSDT")).id()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_binance_trade_fixtures_transform() {
+        use crate::{
+            error::DataError,
+            exchange::binance::spot::BinanceSpot,
+            subscription::{trade::PublicTrades, Subscription},
+            test_util::{assert_deserialize_roundtrip, instrument_map, transform_frames, Fixtures},
+            transformer::stateless::StatelessTransformer,
+        };
+        use barter_integration::{error::SocketError, model::InstrumentKind};
+
+        let fixtures = Fixtures::load("binance", "trades");
+        let instrument_map = instrument_map(&[Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))]);
+
+        let actual = transform_frames::<
+            BinanceSpot,
+            PublicTrades,
+            StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade>,
+            _,
+        >(
+            instrument_map,
+            [
+                "spot_trade",
+                "malformed_truncated",
+                "malformed_wrong_types",
+                "unknown_symbol",
+                "spot_trade_epoch_seconds",
+            ]
+            .map(|name| fixtures.get(name)),
+        )
+        .await;
+        assert_eq!(actual.len(), 5);
+
+        // Valid frames yield normalised trades that survive a serde roundtrip
+        for (index, id) in [(0, "1000000000"), (4, "1000000000")] {
+            let event = actual[index].as_ref().unwrap();
+            assert_eq!(
+                event.instrument,
+                Instrument::from(("eth", "usdt", InstrumentKind::Spot))
+            );
+            assert_eq!(event.kind.id, id);
+            assert_deserialize_roundtrip(event);
+        }
+
+        // Malformed frames yield deserialisation errors
+        for index in [1, 2] {
+            assert!(
+                matches!(
+                    &actual[index],
+                    Err(DataError::Socket(SocketError::Deserialise { .. }))
+                ),
+                "frame {index} failed: {:?}",
+                actual[index]
+            );
+        }
+
+        // Trades of an unsubscribed symbol are unidentifiable
+        assert!(matches!(
+            &actual[3],
+            Err(DataError::Socket(SocketError::Unidentifiable(subscription_id)))
+                if subscription_id == &SubscriptionId::from("@trade|XRPUSDT")
+        ));
+    }
 }
@@ -0,0 +1,197 @@
+use super::{stream_name, subscription::BinanceSubResponse, MAX_SUBS_PER_REQUEST_BINANCE};
+use crate::{
+    exchange::Connector,
+    subscriber::validator::{
+        validate_acks, AckTracker, SubscriptionOutcome, SubscriptionValidator,
+    },
+    subscription::{Map, SubKind},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WebSocket,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+use tracing::debug;
+
+/// [`Binance`](super::Binance) request id of the first subscribe request actioned per
+/// connection, incremented for each subsequent request (see [`Binance::requests`](super::Binance)).
+pub const BINANCE_SUBSCRIBE_REQUEST_ID: u32 = 1;
+
+/// [`Binance`](super::Binance) specific [`SubscriptionValidator`].
+///
+/// ### Notes
+/// - The streams of a connection are actioned in as few requests as possible (respecting the
+///   Binance incoming message rate limit), each with at most [`MAX_SUBS_PER_REQUEST_BINANCE`]
+///   streams. The [`BinanceSubResponse`] echoing a request "id" acknowledges, or rejects, every
+///   [`Subscription`](crate::subscription::Subscription) actioned by that request.
+/// - Responses with an unexpected "id" are ignored.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BinanceSubValidator;
+
+impl BinanceSubValidator {
+    /// Map each expected [`BinanceSubResponse`] request id to the instrument map
+    /// [`SubscriptionId`]s it acknowledges.
+    ///
+    /// Mirrors the requests actioned by [`Binance::requests`](super::Binance): subscriptions are
+    /// ordered by stream name & [`SubscriptionId`], and chunked by
+    /// [`MAX_SUBS_PER_REQUEST_BINANCE`].
+    pub fn ack_subscriptions(map: &Map<Instrument>) -> BTreeMap<u32, Vec<SubscriptionId>> {
+        let mut subscription_ids = map
+            .0
+            .keys()
+            .map(|subscription_id| {
+                let (channel, market) = subscription_id
+                    .as_ref()
+                    .split_once('|')
+                    .unwrap_or((subscription_id.as_ref(), ""));
+                (stream_name(channel, market), subscription_id.clone())
+            })
+            .collect::<Vec<_>>();
+        subscription_ids.sort();
+
+        subscription_ids
+            .chunks(MAX_SUBS_PER_REQUEST_BINANCE)
+            .zip(BINANCE_SUBSCRIBE_REQUEST_ID..)
+            .map(|(chunk, id)| {
+                let subscription_ids = chunk
+                    .iter()
+                    .map(|(_, subscription_id)| subscription_id.clone())
+                    .collect::<Vec<_>>();
+                (id, subscription_ids)
+            })
+            .collect()
+    }
+
+    /// Record the [`BinanceSubResponse`] against the request id it echoes, returning false if
+    /// the request id was not pending.
+    pub fn on_response(response: BinanceSubResponse, tracker: &mut AckTracker<u32>) -> bool {
+        match response {
+            BinanceSubResponse {
+                error: Some(error),
+                id,
+                ..
+            } => tracker.reject(&id, &format!("{}: {}", error.code, error.message)),
+            BinanceSubResponse {
+                result: Some(result),
+                id,
+                ..
+            } => tracker.reject(&id, &format!("unexpected result: {result:?}")),
+            BinanceSubResponse { id, .. } => tracker.confirm(&id),
+        }
+    }
+}
+
+#[async_trait]
+impl SubscriptionValidator for BinanceSubValidator {
+    async fn validate<Exchange, Kind>(
+        instrument_map: Map<Instrument>,
+        websocket: &mut WebSocket,
+        timeout: Duration,
+    ) -> Result<(Map<Instrument>, SubscriptionOutcome), SocketError>
+    where
+        Exchange: Connector + Send,
+        Kind: SubKind + Send,
+    {
+        let tracker = AckTracker::new(Self::ack_subscriptions(&instrument_map));
+
+        let outcome = validate_acks::<Exchange, BinanceSubResponse, _, _>(
+            tracker,
+            websocket,
+            timeout,
+            |response, tracker| {
+                let id = response.id;
+                if !Self::on_response(response, tracker) {
+                    debug!(
+                        exchange = %Exchange::ID,
+                        %id,
+                        "received subscription response for unexpected request id",
+                    );
+                }
+            },
+        )
+        .await?;
+
+        Ok((outcome.retain_confirmed(instrument_map), outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscriber::validator::RejectedSubscription;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_binance_sub_validator_on_response() {
+        struct TestCase {
+            input: Vec<&'static str>,
+            expected: SubscriptionOutcome,
+        }
+
+        let ids = |ids: &[&str]| {
+            ids.iter()
+                .copied()
+                .map(SubscriptionId::from)
+                .collect::<Vec<_>>()
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: success response confirms every subscription
+                input: vec![r#"{"result":null,"id":1}"#],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["@trade|BTCUSDT", "@trade|ETHUSDT"]),
+                    rejected: vec![],
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC1: error response rejects every subscription w/ exchange message
+                input: vec![r#"{"id":1,"error":{"code":2,"msg":"Invalid request"}}"#],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: ["@trade|BTCUSDT", "@trade|ETHUSDT"]
+                        .into_iter()
+                        .map(|id| RejectedSubscription {
+                            subscription_id: SubscriptionId::from(id),
+                            reason: "2: Invalid request".to_string(),
+                        })
+                        .collect(),
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC2: response w/ unexpected request id is ignored
+                input: vec![r#"{"result":null,"id":7}"#],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: vec![],
+                    timed_out: ids(&["@trade|BTCUSDT", "@trade|ETHUSDT"]),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let map = ["@trade|ETHUSDT", "@trade|BTCUSDT"]
+                .into_iter()
+                .map(|id| {
+                    (
+                        SubscriptionId::from(id),
+                        Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    )
+                })
+                .collect::<Map<Instrument>>();
+
+            let mut tracker = AckTracker::new(BinanceSubValidator::ack_subscriptions(&map));
+            for input in test.input {
+                let response = serde_json::from_str::<BinanceSubResponse>(input).unwrap();
+                BinanceSubValidator::on_response(response, &mut tracker);
+            }
+
+            assert_eq!(tracker.into_outcome(), test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use super::BitfinexOrder;
+use crate::{
+    error::DataError,
+    exchange::bitfinex::{
+        market::bitfinex_symbol,
+        message::{BitfinexChannelMessage, BitfinexChannelPayload},
+    },
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide},
+        number::Number,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, Side},
+    protocol::websocket::WsMessage,
+};
+use chrono::Utc;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Bitfinex`](super::super::Bitfinex) HTTP raw OrderBook snapshot url.
+///
+/// See docs: <https://docs.bitfinex.com/reference/rest-public-raw-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BITFINEX: &str = "https://api-pub.bitfinex.com/v2/book";
+
+/// Number of orders requested for each side of a [`Bitfinex`](super::super::Bitfinex) raw
+/// OrderBook, both for the WebSocket subscription and the HTTP snapshot.
+pub const BITFINEX_BOOK_L2_LEN: u32 = 100;
+
+/// Terse type alias for a [`Bitfinex`](super::super::Bitfinex) raw OrderBook WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Snapshot
+/// ```json
+/// [17082,[[34006738527,7385.1,0.25],[34006738528,7385.2,-1.5]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [17082,[34006738527,7385.1,0.5]]
+/// ```
+///
+/// #### Heartbeat
+/// ```json
+/// [17082,"hb"]
+/// ```
+pub type BitfinexOrderBookL2 = BitfinexChannelMessage<BitfinexOrder>;
+
+/// [`Bitfinex`](super::super::Bitfinex) [`OrderBookUpdater`].
+///
+/// Bitfinex: How To Maintain A Local Raw OrderBook
+///
+/// 1. Fetch an initial raw OrderBook snapshot via HTTP, since the WebSocket snapshot sent after
+///    subscribing is consumed during subscription validation.
+/// 2. Apply each subsequent order update to the local OrderBook:
+///    - If the price is 0, remove the order.
+///    - Otherwise, add or replace the order (amount > 0 is a bid & amount < 0 is an ask).
+/// 3. Aggregate the orders of each affected price level into a normalised [`Level`].
+///
+/// ## Notes:
+/// - Any WebSocket snapshot received (eg/ after re-subscribing) replaces the local OrderBook.
+/// - Raw OrderBook messages do not contain a timestamp, so the time each update is applied is used.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BitfinexBookUpdater {
+    pub orders: HashMap<u64, BitfinexOrder>,
+}
+
+impl BitfinexBookUpdater {
+    /// Construct a new Bitfinex [`OrderBookUpdater`] from a raw OrderBook snapshot, returning
+    /// the associated aggregated [`OrderBook`].
+    pub fn new(snapshot: Vec<BitfinexOrder>) -> (Self, OrderBook) {
+        let mut updater = Self::default();
+        let mut book = OrderBook {
+            last_update_time: Utc::now(),
+            bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+            first_update_id: None,
+            last_update_id: None,
+        };
+        updater.apply_snapshot(&mut book, snapshot);
+
+        (updater, book)
+    }
+
+    /// Initialise the [`InstrumentOrderBook`] using a raw OrderBook snapshot fetched from the
+    /// provided url (eg/ [`HTTP_BOOK_L2_SNAPSHOT_URL_BITFINEX`]).
+    pub async fn init_with_snapshot_url(
+        snapshot_url: &str,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError> {
+        let snapshot = fetch_snapshot(snapshot_url, &instrument).await?;
+        let (updater, book) = Self::new(snapshot);
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater,
+            book,
+        })
+    }
+
+    /// Replace the local orders & [`OrderBook`] with the provided raw OrderBook snapshot.
+    pub fn apply_snapshot(&mut self, book: &mut OrderBook, snapshot: Vec<BitfinexOrder>) {
+        self.orders = snapshot
+            .into_iter()
+            .filter(|order| !order.is_removed())
+            .map(|order| (order.id, order))
+            .collect();
+
+        book.bids = OrderBookSide::new(Side::Buy, self.levels(Side::Buy));
+        book.asks = OrderBookSide::new(Side::Sell, self.levels(Side::Sell));
+    }
+
+    /// Apply a single raw order update to the local orders & [`OrderBook`].
+    pub fn apply_update(&mut self, book: &mut OrderBook, order: BitfinexOrder) {
+        // Remove any previous state of the order from its price level
+        if let Some(previous) = self.orders.remove(&order.id) {
+            self.upsert_level(book, previous.side, previous.price);
+        }
+
+        if !order.is_removed() {
+            self.orders.insert(order.id, order);
+            self.upsert_level(book, order.side, order.price);
+        }
+    }
+
+    /// Upsert the aggregated [`Level`] of the provided [`Side`] & price into the [`OrderBook`],
+    /// removing it if no orders remain.
+    fn upsert_level(&self, book: &mut OrderBook, side: Side, price: Number) {
+        let amount = self
+            .orders
+            .values()
+            .filter(|order| order.side == side && order.price == price)
+            .map(|order| order.amount)
+            .sum::<Number>();
+
+        match side {
+            Side::Buy => book.bids.upsert_single(Level::new(price, amount)),
+            Side::Sell => book.asks.upsert_single(Level::new(price, amount)),
+        }
+    }
+
+    /// Aggregate the local orders of the provided [`Side`] into price [`Level`]s.
+    fn levels(&self, side: Side) -> Vec<Level> {
+        self.orders
+            .values()
+            .filter(|order| order.side == side)
+            .fold(Vec::<Level>::new(), |mut levels, order| {
+                match levels.iter_mut().find(|level| level.eq_price(order.price)) {
+                    Some(level) => level.amount += order.amount,
+                    None => levels.push(Level::new(order.price, order.amount)),
+                }
+                levels
+            })
+    }
+}
+
+/// Fetch a [`Bitfinex`](super::super::Bitfinex) raw OrderBook snapshot via HTTP from the
+/// provided book endpoint (eg/ [`HTTP_BOOK_L2_SNAPSHOT_URL_BITFINEX`]).
+///
+/// The `snapshot_url` is injectable so an alternative server (eg/ a mock server) can be used.
+pub async fn fetch_snapshot(
+    snapshot_url: &str,
+    instrument: &Instrument,
+) -> Result<Vec<BitfinexOrder>, DataError> {
+    // Construct initial raw OrderBook snapshot GET url
+    let snapshot_url = format!(
+        "{}/{}/R0?len={}",
+        snapshot_url,
+        bitfinex_symbol(instrument),
+        BITFINEX_BOOK_L2_LEN
+    );
+
+    // Fetch initial raw OrderBook snapshot via HTTP
+    reqwest::get(snapshot_url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<Vec<BitfinexOrder>>()
+        .await
+        .map_err(SocketError::Http)
+        .map_err(DataError::from)
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitfinexBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BitfinexOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        Self::init_with_snapshot_url(HTTP_BOOK_L2_SNAPSHOT_URL_BITFINEX, instrument).await
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        match update.payload {
+            BitfinexChannelPayload::Heartbeat => return Ok(None),
+            BitfinexChannelPayload::Snapshot(snapshot) => self.apply_snapshot(book, snapshot),
+            BitfinexChannelPayload::Update(order) => self.apply_update(book, order),
+        }
+
+        book.last_update_time = Utc::now();
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use crate::{streams::backfill::tests::mock_http_server, Identifier};
+    use barter_integration::model::{InstrumentKind, SubscriptionId};
+
+    fn order(id: u64, price: f64, amount: f64, side: Side) -> BitfinexOrder {
+        BitfinexOrder {
+            id,
+            price: from_f64(price),
+            amount: from_f64(amount),
+            side,
+        }
+    }
+
+    #[test]
+    fn test_de_bitfinex_order_book_l2() {
+        struct TestCase {
+            input: &'static str,
+            expected: BitfinexOrderBookL2,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot
+                input: r#"[17082,[[34006738527,7385.1,0.25],[34006738528,7385.2,-1.5]]]"#,
+                expected: BitfinexOrderBookL2 {
+                    channel_id: 17082,
+                    payload: BitfinexChannelPayload::Snapshot(vec![
+                        order(34006738527, 7385.1, 0.25, Side::Buy),
+                        order(34006738528, 7385.2, 1.5, Side::Sell),
+                    ]),
+                },
+            },
+            TestCase {
+                // TC1: update
+                input: r#"[17082,[34006738527,7385.1,0.5]]"#,
+                expected: BitfinexOrderBookL2 {
+                    channel_id: 17082,
+                    payload: BitfinexChannelPayload::Update(order(
+                        34006738527,
+                        7385.1,
+                        0.5,
+                        Side::Buy,
+                    )),
+                },
+            },
+            TestCase {
+                // TC2: removed order update
+                input: r#"[17082,[34006738528,0,-1]]"#,
+                expected: BitfinexOrderBookL2 {
+                    channel_id: 17082,
+                    payload: BitfinexChannelPayload::Update(order(
+                        34006738528,
+                        0.0,
+                        1.0,
+                        Side::Sell,
+                    )),
+                },
+            },
+            TestCase {
+                // TC3: heartbeat
+                input: r#"[17082,"hb"]"#,
+                expected: BitfinexOrderBookL2 {
+                    channel_id: 17082,
+                    payload: BitfinexChannelPayload::Heartbeat,
+                },
+            },
+            TestCase {
+                // TC4: checksum is treated as a heartbeat
+                input: r#"[17082,"cs",-1323474320]"#,
+                expected: BitfinexOrderBookL2 {
+                    channel_id: 17082,
+                    payload: BitfinexChannelPayload::Heartbeat,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitfinexOrderBookL2>(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_order_book_l2_id() {
+        let heartbeat = serde_json::from_str::<BitfinexOrderBookL2>(r#"[17082,"hb"]"#).unwrap();
+        assert_eq!(heartbeat.id(), None);
+
+        let update =
+            serde_json::from_str::<BitfinexOrderBookL2>(r#"[17082,[34006738527,7385.1,0.5]]"#)
+                .unwrap();
+        assert_eq!(update.id(), Some(SubscriptionId::from("17082")));
+    }
+
+    #[test]
+    fn test_bitfinex_book_updater_update() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<(Vec<Level>, Vec<Level>)>,
+        }
+
+        let (mut updater, mut book) = BitfinexBookUpdater::new(vec![
+            order(1, 100.0, 1.0, Side::Buy),
+            order(2, 100.0, 2.0, Side::Buy),
+            order(3, 99.0, 1.0, Side::Buy),
+            order(4, 101.0, 1.5, Side::Sell),
+        ]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: heartbeat yields no OrderBook
+                input: r#"[17082,"hb"]"#,
+                expected: None,
+            },
+            TestCase {
+                // TC1: new ask order creates a new Level
+                input: r#"[17082,[5,102.0,-0.5]]"#,
+                expected: Some((
+                    vec![
+                        Level::new(from_f64(100.0), from_f64(3.0)),
+                        Level::new(from_f64(99.0), from_f64(1.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(101.0), from_f64(1.5)),
+                        Level::new(from_f64(102.0), from_f64(0.5)),
+                    ],
+                )),
+            },
+            TestCase {
+                // TC2: existing bid order amount change updates the aggregated Level
+                input: r#"[17082,[2,100.0,0.5]]"#,
+                expected: Some((
+                    vec![
+                        Level::new(from_f64(100.0), from_f64(1.5)),
+                        Level::new(from_f64(99.0), from_f64(1.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(101.0), from_f64(1.5)),
+                        Level::new(from_f64(102.0), from_f64(0.5)),
+                    ],
+                )),
+            },
+            TestCase {
+                // TC3: removing the only order of a Level removes the Level
+                input: r#"[17082,[3,0,1]]"#,
+                expected: Some((
+                    vec![Level::new(from_f64(100.0), from_f64(1.5))],
+                    vec![
+                        Level::new(from_f64(101.0), from_f64(1.5)),
+                        Level::new(from_f64(102.0), from_f64(0.5)),
+                    ],
+                )),
+            },
+            TestCase {
+                // TC4: snapshot replaces the OrderBook
+                input: r#"[17082,[[6,98.0,2.0],[7,103.0,-1.0],[8,103.0,-2.0]]]"#,
+                expected: Some((
+                    vec![Level::new(from_f64(98.0), from_f64(2.0))],
+                    vec![Level::new(from_f64(103.0), from_f64(3.0))],
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let update = serde_json::from_str::<BitfinexOrderBookL2>(test.input).unwrap();
+            let actual = updater.update(&mut book, update).unwrap();
+            let expected = test.expected.map(|(bids, asks)| OrderBook {
+                last_update_time: book.last_update_time,
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+                first_update_id: None,
+                last_update_id: None,
+            });
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bitfinex_book_updater_init_with_snapshot_url() {
+        let (url, request) =
+            mock_http_server(r#"[[1,100.0,1.0],[2,100.0,2.0],[3,101.0,-1.5]]"#).await;
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+
+        let mut book = BitfinexBookUpdater::init_with_snapshot_url(&url, instrument.clone())
+            .await
+            .unwrap();
+
+        assert!(request
+            .await
+            .unwrap()
+            .starts_with("GET /tBTCUSD/R0?len=100 "));
+        assert_eq!(book.instrument, instrument);
+        assert_eq!(book.updater.orders.len(), 3);
+        assert_eq!(
+            book.book.snapshot(),
+            OrderBook {
+                last_update_time: book.book.last_update_time,
+                bids: OrderBookSide::new(
+                    Side::Buy,
+                    vec![Level::new(from_f64(100.0), from_f64(3.0))]
+                ),
+                asks: OrderBookSide::new(
+                    Side::Sell,
+                    vec![Level::new(from_f64(101.0), from_f64(1.5))]
+                ),
+                first_update_id: None,
+                last_update_id: None,
+            }
+        );
+    }
+}
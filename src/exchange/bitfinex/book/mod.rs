@@ -0,0 +1,87 @@
+use crate::subscription::number::{self, Number};
+use barter_integration::{de::extract_next, model::Side};
+use serde::Serialize;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Bitfinex`](super::Bitfinex) raw OrderBook order.
+///
+/// ### Raw Payload Examples
+/// Format: \[ORDER_ID, PRICE, AMOUNT\], <br> where +/- of amount indicates Side
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+/// #### Side::Buy Order
+/// ```json
+/// [34006738527,7385.1,0.25]
+/// ```
+///
+/// #### Side::Sell Order Removed
+/// ```json
+/// [34006738527,0,-1]
+/// ```
+///
+/// ## Notes:
+/// - A price of 0 indicates the order has been removed from the OrderBook.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexOrder {
+    pub id: u64,
+    pub price: Number,
+    pub amount: Number,
+    pub side: Side,
+}
+
+impl BitfinexOrder {
+    /// Determine if this [`BitfinexOrder`] indicates the order has been removed.
+    pub fn is_removed(&self) -> bool {
+        self.price == number::ZERO
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexOrder {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexOrder;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexOrder struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Order: [ORDER_ID, PRICE, AMOUNT]
+                let id = extract_next(&mut seq, "id")?;
+                let price = extract_next(&mut seq, "price")?;
+                let amount: Number = extract_next(&mut seq, "amount")?;
+                let side = match amount.is_sign_positive() {
+                    true => Side::Buy,
+                    false => Side::Sell,
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexOrder {
+                    id,
+                    price,
+                    amount: amount.abs(),
+                    side,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexOrder message
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
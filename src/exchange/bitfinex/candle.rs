@@ -0,0 +1,478 @@
+use super::{
+    channel::BitfinexCandleTimeframe,
+    message::{BitfinexChannelMessage, BitfinexChannelPayload},
+    Bitfinex,
+};
+use crate::{
+    de::{datetime_utc_from_epoch_ms, epoch_out_of_range},
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeId},
+    streams::metrics::MetricsHook,
+    subscription::{
+        candle::{Candle, CandleSource, VolumeDenomination},
+        number::Number,
+        Interval, Map, SubKind,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{Exchange, Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+/// Terse type alias for a [`Bitfinex`](super::Bitfinex) real-time candles WebSocket message.
+pub type BitfinexCandles = BitfinexChannelMessage<BitfinexCandle>;
+
+/// [`Bitfinex`](super::Bitfinex) real-time candle.
+///
+/// ### Raw Payload Examples
+/// Format: \[MTS, OPEN, CLOSE, HIGH, LOW, VOLUME\], <br> where MTS is the candle open time
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+/// #### Snapshot
+/// ```json
+/// [343351,[[1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482]]]
+/// ```
+///
+/// #### Update
+/// ```json
+/// [343351,[1574698200000,7399.9,7379.7,7399.9,7371.8,41.63633658]]
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexCandle {
+    pub start_time: DateTime<Utc>,
+    pub open: Number,
+    pub close: Number,
+    pub high: Number,
+    pub low: Number,
+    pub volume: Number,
+}
+
+/// Determine the [`Bitfinex`](super::Bitfinex) `CHANNEL_ID` [`SubscriptionId`] & candle
+/// [`Interval`] from a validated candles [`SubscriptionId`] (eg/ "343351|trade:1m:tBTCUSD").
+///
+/// See [`BitfinexWebSocketSubValidator`](super::validator::BitfinexWebSocketSubValidator).
+fn parse_candle_subscription_id(
+    subscription_id: &SubscriptionId,
+) -> Result<(SubscriptionId, Interval), DataError> {
+    subscription_id
+        .as_ref()
+        .split_once('|')
+        .and_then(|(channel_id, key)| {
+            let timeframe = key.split(':').nth(1)?;
+            Interval::ALL
+                .into_iter()
+                .find(|interval| {
+                    BitfinexCandleTimeframe::try_from(*interval)
+                        .is_ok_and(|candle_timeframe| candle_timeframe.code == timeframe)
+                })
+                .map(|interval| (SubscriptionId::from(channel_id), interval))
+        })
+        .ok_or_else(|| {
+            DataError::Socket(SocketError::Unsupported {
+                entity: Bitfinex::ID.as_str(),
+                item: format!("candle subscription: {subscription_id}"),
+            })
+        })
+}
+
+/// [`Bitfinex`](super::Bitfinex) [`CandleUpdates`](crate::subscription::candle::CandleUpdates)
+/// [`ExchangeTransformer`].
+///
+/// Bitfinex does not indicate if a candle update is final, so
+/// [`Candles`](crate::subscription::candle::Candles) streams wrap this
+/// transformer in a [`HeldCandleTransformer`](crate::transformer::candle::HeldCandleTransformer).
+///
+/// Bitfinex candle messages are only identified by their `CHANNEL_ID`, so the candle [`Interval`]
+/// required to determine each [`Candle`] close time is captured from the candle "key" of the
+/// subscription response during subscription validation.
+///
+/// Every transformed event is reported to the installed [`MetricsHook`], if any.
+#[derive(Clone, PartialEq, Debug)]
+pub struct BitfinexCandleTransformer {
+    instrument_map: Map<(Instrument, Interval)>,
+    metrics: MetricsHook,
+}
+
+#[async_trait]
+impl<Kind> ExchangeTransformer<Bitfinex, Kind> for BitfinexCandleTransformer
+where
+    Kind: SubKind<Event = Candle>,
+{
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        let instrument_map = instrument_map
+            .0
+            .into_iter()
+            .map(|(subscription_id, instrument)| {
+                parse_candle_subscription_id(&subscription_id)
+                    .map(|(channel_id, interval)| (channel_id, (instrument, interval)))
+            })
+            .collect::<Result<Map<_>, DataError>>()?;
+
+        Ok(Self {
+            instrument_map,
+            metrics: MetricsHook::default(),
+        })
+    }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        self.metrics = metrics;
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.instrument_map.remove(subscription_ids);
+    }
+}
+
+impl Transformer for BitfinexCandleTransformer {
+    type Error = DataError;
+    type Input = BitfinexCandles;
+    type Output = MarketEvent<Candle>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument & Interval associated with Input
+        let (instrument, interval) = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => {
+                let error = DataError::Socket(unidentifiable);
+                self.metrics.report_error(&error);
+                return vec![Err(error)];
+            }
+        };
+
+        let candles = match input.payload {
+            BitfinexChannelPayload::Heartbeat => vec![],
+            BitfinexChannelPayload::Snapshot(candles) => candles,
+            BitfinexChannelPayload::Update(candle) => vec![candle],
+        };
+
+        let output = candles
+            .into_iter()
+            .map(|candle| {
+                Ok(MarketEvent {
+                    exchange_time: candle.start_time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(ExchangeId::Bitfinex),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: Candle {
+                        start_time: candle.start_time,
+                        close_time: interval.close_time(candle.start_time),
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume,
+                        volume_denomination: VolumeDenomination::Base,
+                        base_volume: Some(candle.volume),
+                        quote_volume: None,
+                        taker_buy_volume: None,
+                        taker_buy_quote_volume: None,
+                        // Bitfinex does not provide the number of trades
+                        trade_count: 0,
+                        // Bitfinex does not indicate if a candle update is final
+                        closed: false,
+                        source: CandleSource::LastTrade,
+                        synthetic: false,
+                        derived: false,
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+
+        self.metrics.report(&subscription_id, &output);
+        output
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BitfinexCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = BitfinexCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexCandle struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Candle: [MTS, OPEN, CLOSE, HIGH, LOW, VOLUME]
+                let start_millis = extract_next(&mut seq, "mts")?;
+                let start_time = datetime_utc_from_epoch_ms(start_millis)
+                    .ok_or_else(|| epoch_out_of_range(start_millis))?;
+                let open = extract_next(&mut seq, "open")?;
+                let close = extract_next(&mut seq, "close")?;
+                let high = extract_next(&mut seq, "high")?;
+                let low = extract_next(&mut seq, "low")?;
+                let volume = extract_next(&mut seq, "volume")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(BitfinexCandle {
+                    start_time,
+                    open,
+                    close,
+                    high,
+                    low,
+                    volume,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the BitfinexCandle message
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{candle::CandleUpdates, number::from_f64};
+    use barter_integration::{de::datetime_utc_from_epoch_duration, model::InstrumentKind};
+    use chrono::Duration;
+    use std::collections::HashMap;
+
+    fn candle(
+        start_millis: u64,
+        open: f64,
+        close: f64,
+        high: f64,
+        low: f64,
+        volume: f64,
+    ) -> BitfinexCandle {
+        BitfinexCandle {
+            start_time: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+                start_millis,
+            )),
+            open: from_f64(open),
+            close: from_f64(close),
+            high: from_f64(high),
+            low: from_f64(low),
+            volume: from_f64(volume),
+        }
+    }
+
+    #[test]
+    fn test_de_bitfinex_candles() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<BitfinexCandles, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot
+                input: r#"[343351,[[1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482],[1574698200000,7399.9,7379.7,7399.9,7371.8,41.63633658]]]"#,
+                expected: Ok(BitfinexCandles {
+                    channel_id: 343351,
+                    payload: BitfinexChannelPayload::Snapshot(vec![
+                        candle(
+                            1574698260000,
+                            7379.785503,
+                            7383.8,
+                            7388.3,
+                            7379.785503,
+                            1.68829482,
+                        ),
+                        candle(1574698200000, 7399.9, 7379.7, 7399.9, 7371.8, 41.63633658),
+                    ]),
+                }),
+            },
+            TestCase {
+                // TC1: update
+                input: r#"[343351,[1574698200000,7399.9,7379.7,7399.9,7371.8,41.63633658]]"#,
+                expected: Ok(BitfinexCandles {
+                    channel_id: 343351,
+                    payload: BitfinexChannelPayload::Update(candle(
+                        1574698200000,
+                        7399.9,
+                        7379.7,
+                        7399.9,
+                        7371.8,
+                        41.63633658,
+                    )),
+                }),
+            },
+            TestCase {
+                // TC2: heartbeat
+                input: r#"[343351,"hb"]"#,
+                expected: Ok(BitfinexCandles {
+                    channel_id: 343351,
+                    payload: BitfinexChannelPayload::Heartbeat,
+                }),
+            },
+            TestCase {
+                // TC3: unknown message tag
+                input: r#"[343351,"te",[1225484398,1665452200022,0.08980641,19027.02807752]]"#,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BitfinexCandles>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bitfinex_candle_transformer() {
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let instrument_map = Map(HashMap::from([(
+            SubscriptionId::from("343351|trade:1m:tBTCUSD"),
+            instrument.clone(),
+        )]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        let mut transformer = <BitfinexCandleTransformer as ExchangeTransformer<
+            Bitfinex,
+            CandleUpdates,
+        >>::new(ws_sink_tx, instrument_map)
+        .await
+        .unwrap();
+
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<(DateTime<Utc>, Number)>,
+        }
+
+        let start_time =
+            |millis| datetime_utc_from_epoch_duration(std::time::Duration::from_millis(millis));
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot yields a Candle for each snapshot candle
+                input: r#"[343351,[[1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482],[1574698200000,7399.9,7379.7,7399.9,7371.8,41.63633658]]]"#,
+                expected: vec![
+                    (start_time(1574698260000), from_f64(7383.8)),
+                    (start_time(1574698200000), from_f64(7379.7)),
+                ],
+            },
+            TestCase {
+                // TC1: update yields a single Candle
+                input: r#"[343351,[1574698320000,7383.8,7390.1,7391.0,7383.8,0.5]]"#,
+                expected: vec![(start_time(1574698320000), from_f64(7390.1))],
+            },
+            TestCase {
+                // TC2: heartbeat yields nothing
+                input: r#"[343351,"hb"]"#,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let output = transformer.transform(serde_json::from_str(test.input).unwrap());
+            let actual = output
+                .into_iter()
+                .map(|event| {
+                    let event = event.unwrap();
+                    assert_eq!(event.instrument, instrument, "TC{index} failed");
+                    assert_eq!(
+                        event.kind.close_time,
+                        event.kind.start_time + Duration::minutes(1) - Duration::milliseconds(1),
+                        "TC{index} failed"
+                    );
+                    (event.kind.start_time, event.kind.close)
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        // Candles for an unknown CHANNEL_ID are unidentifiable
+        let output = transformer.transform(
+            serde_json::from_str(r#"[1,[1574698320000,7383.8,7390.1,7391.0,7383.8,0.5]]"#).unwrap(),
+        );
+        assert!(matches!(output.as_slice(), [Err(DataError::Socket(_))]));
+    }
+
+    #[test]
+    fn test_parse_candle_subscription_id() {
+        struct TestCase {
+            input: SubscriptionId,
+            expected: Result<(SubscriptionId, Interval), DataError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m candles
+                input: SubscriptionId::from("343351|trade:1m:tBTCUSD"),
+                expected: Ok((SubscriptionId::from("343351"), Interval::Minute1)),
+            },
+            TestCase {
+                // TC1: 1D candles
+                input: SubscriptionId::from("42|trade:1D:tETHUSD"),
+                expected: Ok((SubscriptionId::from("42"), Interval::Day1)),
+            },
+            TestCase {
+                // TC2: SubscriptionId without a candle key
+                input: SubscriptionId::from("343351"),
+                expected: Err(DataError::Socket(SocketError::Unsupported {
+                    entity: "bitfinex",
+                    item: "candle subscription: 343351".to_string(),
+                })),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = parse_candle_subscription_id(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
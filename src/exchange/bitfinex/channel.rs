@@ -1,6 +1,12 @@
 use super::Bitfinex;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    error::UnsupportedIntervalError,
+    subscription::{
+        book::OrderBooksL2,
+        candle::{CandleUpdates, Candles},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +23,72 @@ impl BitfinexChannel {
     ///
     /// See docs: <https://docs.bitfinex.com/reference/ws-public-trades>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Bitfinex`] real-time candles channel.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+    pub const CANDLES: Self = Self("candles");
+
+    /// [`Bitfinex`] placeholder channel for a candle [`Interval`] that Bitfinex does not support,
+    /// which is rejected by [`Bitfinex`] subscription validation.
+    pub const CANDLES_UNSUPPORTED: Self = Self("candles-unsupported");
+
+    /// [`Bitfinex`] real-time raw OrderBook channel.
+    ///
+    /// See docs: <https://docs.bitfinex.com/reference/ws-public-raw-books>
+    pub const ORDER_BOOK_L2: Self = Self("book");
+
+    /// [`Bitfinex`] real-time candles channel for the provided [`Interval`].
+    ///
+    /// [`Interval`]s without a [`BitfinexCandleTimeframe`] map to [`Self::CANDLES_UNSUPPORTED`].
+    pub fn candles(interval: Interval) -> Self {
+        BitfinexCandleTimeframe::try_from(interval)
+            .map_or(Self::CANDLES_UNSUPPORTED, |_| Self::CANDLES)
+    }
+}
+
+/// [`Bitfinex`] candle timeframe of a Barter [`Interval`], as used in the candle "key" (eg/
+/// "trade:1D:tBTCUSD").
+///
+/// Note: only 1m, 5m, 15m, 30m, 1h, 6h, 12h, 1d, 1w & 1M [`Interval`]s are supported.
+///
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct BitfinexCandleTimeframe {
+    /// Candle timeframe code (eg/ "1D").
+    pub code: &'static str,
+}
+
+impl TryFrom<Interval> for BitfinexCandleTimeframe {
+    type Error = UnsupportedIntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let code = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour6 => "6h",
+            Interval::Hour12 => "12h",
+            Interval::Day1 => "1D",
+            Interval::Week1 => "1W",
+            Interval::Month1 => "1M",
+            Interval::Minute3
+            | Interval::Hour2
+            | Interval::Hour4
+            | Interval::Hour8
+            | Interval::Day3
+            | Interval::Month3 => {
+                return Err(UnsupportedIntervalError {
+                    exchange: "bitfinex",
+                    interval,
+                })
+            }
+        };
+
+        Ok(Self { code })
+    }
 }
 
 impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
@@ -25,8 +97,91 @@ impl Identifier<BitfinexChannel> for Subscription<Bitfinex, PublicTrades> {
     }
 }
 
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, Candles> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::candles(self.kind.0)
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, CandleUpdates> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::candles(self.kind.0)
+    }
+}
+
+impl Identifier<BitfinexChannel> for Subscription<Bitfinex, OrderBooksL2> {
+    fn id(&self) -> BitfinexChannel {
+        BitfinexChannel::ORDER_BOOK_L2
+    }
+}
+
 impl AsRef<str> for BitfinexChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::Connector;
+
+    #[test]
+    fn test_bitfinex_candle_timeframe_try_from_interval() {
+        // Expected candle timeframe of every Interval, in Interval::ALL order
+        let tests = [
+            (Interval::Minute1, Some("1m")),
+            (Interval::Minute3, None),
+            (Interval::Minute5, Some("5m")),
+            (Interval::Minute15, Some("15m")),
+            (Interval::Minute30, Some("30m")),
+            (Interval::Hour1, Some("1h")),
+            (Interval::Hour2, None),
+            (Interval::Hour4, None),
+            (Interval::Hour6, Some("6h")),
+            (Interval::Hour8, None),
+            (Interval::Hour12, Some("12h")),
+            (Interval::Day1, Some("1D")),
+            (Interval::Day3, None),
+            (Interval::Week1, Some("1W")),
+            (Interval::Month1, Some("1M")),
+            (Interval::Month3, None),
+        ];
+        assert_eq!(tests.map(|(interval, _)| interval), Interval::ALL);
+
+        for (interval, expected) in tests {
+            let actual = BitfinexCandleTimeframe::try_from(interval);
+            match expected {
+                Some(code) => {
+                    assert_eq!(
+                        actual,
+                        Ok(BitfinexCandleTimeframe { code }),
+                        "{interval} failed"
+                    );
+                    assert_eq!(BitfinexChannel::candles(interval), BitfinexChannel::CANDLES);
+                }
+                None => {
+                    assert_eq!(
+                        actual,
+                        Err(UnsupportedIntervalError {
+                            exchange: "bitfinex",
+                            interval
+                        }),
+                        "{interval} failed"
+                    );
+                    assert_eq!(
+                        BitfinexChannel::candles(interval),
+                        BitfinexChannel::CANDLES_UNSUPPORTED
+                    );
+                }
+            }
+
+            // Bitfinex supported_intervals are exactly those with a BitfinexCandleTimeframe
+            assert_eq!(
+                Bitfinex::supported_intervals().contains(&interval),
+                expected.is_some(),
+                "{interval} failed"
+            );
+        }
+    }
+}
@@ -1,21 +1,53 @@
-use super::Bitfinex;
-use crate::{subscription::Subscription, Identifier};
+use super::{channel::BitfinexCandleTimeframe, Bitfinex};
+use crate::{
+    subscription::{
+        book::OrderBooksL2,
+        candle::{CandleUpdates, Candles, CandlesFromTrades},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
+    Identifier,
+};
+use barter_integration::model::Instrument;
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Bitfinex`](super::Bitfinex) market that can be subscribed to.
 ///
+/// Candle markets are the Bitfinex candle "key" (eg/ "trade:1m:tBTCUSD"), since the candle
+/// [`Interval`](crate::subscription::Interval) is part of the market identifier.
+///
 /// See docs: <https://docs.bitfinex.com/docs/ws-public>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexMarket(pub String);
 
-impl<Kind> Identifier<BitfinexMarket> for Subscription<Bitfinex, Kind> {
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, PublicTrades> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket(bitfinex_symbol(&self.instrument))
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, CandlesFromTrades> {
     fn id(&self) -> BitfinexMarket {
-        BitfinexMarket(format!(
-            "t{}{}",
-            self.instrument.base.to_string().to_uppercase(),
-            self.instrument.quote.to_string().to_uppercase()
-        ))
+        BitfinexMarket(bitfinex_symbol(&self.instrument))
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, OrderBooksL2> {
+    fn id(&self) -> BitfinexMarket {
+        BitfinexMarket(bitfinex_symbol(&self.instrument))
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, Candles> {
+    fn id(&self) -> BitfinexMarket {
+        bitfinex_candle_key(self.kind.0, &self.instrument)
+    }
+}
+
+impl Identifier<BitfinexMarket> for Subscription<Bitfinex, CandleUpdates> {
+    fn id(&self) -> BitfinexMarket {
+        bitfinex_candle_key(self.kind.0, &self.instrument)
     }
 }
 
@@ -24,3 +56,24 @@ impl AsRef<str> for BitfinexMarket {
         &self.0
     }
 }
+
+/// Determine the [`Bitfinex`](super::Bitfinex) trading pair symbol of the provided
+/// [`Instrument`] (eg/ "tBTCUSD").
+pub fn bitfinex_symbol(instrument: &Instrument) -> String {
+    format!(
+        "t{}{}",
+        instrument.base.to_string().to_uppercase(),
+        instrument.quote.to_string().to_uppercase()
+    )
+}
+
+/// Determine the [`Bitfinex`](super::Bitfinex) candle "key" of the provided [`Interval`] &
+/// [`Instrument`] (eg/ "trade:1m:tBTCUSD").
+fn bitfinex_candle_key(interval: Interval, instrument: &Instrument) -> BitfinexMarket {
+    BitfinexMarket(format!(
+        "trade:{}:{}",
+        BitfinexCandleTimeframe::try_from(interval)
+            .map_or("unsupported", |timeframe| timeframe.code),
+        bitfinex_symbol(instrument)
+    ))
+}
@@ -6,7 +6,7 @@ use barter_integration::{
     de::extract_next,
     model::{Instrument, SubscriptionId},
 };
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// [`Bitfinex`](super::Bitfinex) message received over
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
@@ -129,9 +129,139 @@ impl<'de> serde::Deserialize<'de> for BitfinexMessage {
     }
 }
 
+/// Generic [`Bitfinex`](super::Bitfinex) channel message received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) relating to an active
+/// [`Subscription`](crate::Subscription) that sends an initial snapshot followed by single
+/// updates (eg/ candles & raw books).
+///
+/// The message is associated with the original [`Subscription`](crate::Subscription) using the
+/// `channel_id` field as the [`SubscriptionId`](barter_integration::model::SubscriptionId).
+///
+/// ### Raw Payload Examples
+/// #### Heartbeat
+/// See docs: <https://docs.bitfinex.com/docs/ws-general#heartbeating>
+/// ```json
+/// [343351,"hb"]
+/// ```
+///
+/// #### Snapshot
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+/// ```json
+/// [343351,[[1574698260000,7379.785503,7383.8,7388.3,7379.785503,1.68829482]]]
+/// ```
+///
+/// #### Update
+/// See docs: <https://docs.bitfinex.com/reference/ws-public-candles>
+/// ```json
+/// [343351,[1574698200000,7399.9,7379.7,7399.9,7371.8,41.63633658]]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct BitfinexChannelMessage<T> {
+    pub channel_id: u32,
+    pub payload: BitfinexChannelPayload<T>,
+}
+
+/// [`Bitfinex`](super::Bitfinex) channel message variants associated with an
+/// active [`Subscription`](crate::Subscription).
+///
+/// See [`BitfinexChannelMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub enum BitfinexChannelPayload<T> {
+    Heartbeat,
+    Snapshot(Vec<T>),
+    Update(T),
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BitfinexChannelMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self.payload {
+            BitfinexChannelPayload::Heartbeat => None,
+            BitfinexChannelPayload::Snapshot(_) | BitfinexChannelPayload::Update(_) => {
+                Some(SubscriptionId::from(self.channel_id.to_string()))
+            }
+        }
+    }
+}
+
+impl<'de, T> serde::Deserialize<'de> for BitfinexChannelMessage<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        /// Second element of a [`BitfinexChannelMessage`] sequence.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Element<T> {
+            Tag(String),
+            Snapshot(Vec<T>),
+            Update(T),
+        }
+
+        struct SeqVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> serde::de::Visitor<'de> for SeqVisitor<T>
+        where
+            T: DeserializeOwned,
+        {
+            type Value = BitfinexChannelMessage<T>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("BitfinexChannelMessage struct from the Bitfinex WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // Snapshot: [CHANNEL_ID, [[...], [...]]]
+                // Update: [CHANNEL_ID, [...]]
+                // Heartbeat: [CHANNEL_ID, "hb"]
+                // Checksum: [CHANNEL_ID, "cs", CHECKSUM]
+
+                // Extract CHANNEL_ID used to identify SubscriptionId: 1st element of the sequence
+                let channel_id: u32 = extract_next(&mut seq, "channel_id")?;
+
+                // Extract payload: 2nd element of the sequence
+                let payload = match extract_next(&mut seq, "payload")? {
+                    // Checksums are only sent if requested, so treat as an additional Heartbeat
+                    Element::Tag(tag) if tag == "hb" || tag == "cs" => {
+                        BitfinexChannelPayload::Heartbeat
+                    }
+                    Element::Tag(other) => {
+                        return Err(serde::de::Error::unknown_variant(
+                            &other,
+                            &["heartbeat (hb)", "checksum (cs)"],
+                        ))
+                    }
+                    Element::Snapshot(snapshot) => BitfinexChannelPayload::Snapshot(snapshot),
+                    Element::Update(update) => BitfinexChannelPayload::Update(update),
+                };
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Bitfinex may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+                Ok(BitfinexChannelMessage {
+                    channel_id,
+                    payload,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the WebSocket BitfinexChannelMessage
+        deserializer.deserialize_seq(SeqVisitor(std::marker::PhantomData))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
     use barter_integration::de::datetime_utc_from_epoch_duration;
     use barter_integration::error::SocketError;
     use barter_integration::model::Side;
@@ -160,8 +290,8 @@ mod tests {
                             1665452200022,
                         )),
                         side: Side::Sell,
-                        price: 19027.02807752,
-                        amount: 0.08980641,
+                        price: from_f64(19027.02807752),
+                        amount: from_f64(0.08980641),
                     }),
                 }),
             },
@@ -176,8 +306,8 @@ mod tests {
                             1665452200022,
                         )),
                         side: Side::Buy,
-                        price: 19027.02807752,
-                        amount: 0.08980641,
+                        price: from_f64(19027.02807752),
+                        amount: from_f64(0.08980641),
                     }),
                 }),
             },
@@ -14,27 +14,51 @@
 //! - The user is allowed up to 20 connections per minute on the public API.
 //! - Each connection can be used to connect up to 25 different channels.
 //!
+//! - Candle subscriptions retain the candle "key" in the `SubscriptionId(CHANNEL_ID|key)`
+//!   (eg/ SubscriptionId("343351|trade:1m:tBTCUSD")), since the candle interval is not part of
+//!   the candle messages themselves.
+//!
 //! #### Trade Variants
 //! - Bitfinex trades subscriptions results in receiving tag="te" & tag="tu" trades.
 //! - Both appear to be identical payloads, but "te" arriving marginally faster.
 //! - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 
 use self::{
-    channel::BitfinexChannel, market::BitfinexMarket, message::BitfinexMessage,
-    subscription::BitfinexPlatformEvent, validator::BitfinexWebSocketSubValidator,
+    book::l2::{BitfinexBookUpdater, BITFINEX_BOOK_L2_LEN},
+    candle::BitfinexCandleTransformer,
+    channel::BitfinexChannel,
+    market::BitfinexMarket,
+    message::BitfinexMessage,
+    subscription::BitfinexPlatformEvent,
+    validator::BitfinexWebSocketSubValidator,
 };
 use crate::{
+    decoder::PlainText,
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::WebSocketSubscriber,
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::OrderBooksL2,
+        candle::{CandleUpdates, Candles},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
+    transformer::{
+        book::MultiBookTransformer, candle::HeldCandleTransformer, stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, Identifier,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
+use std::fmt::Debug;
 use url::Url;
 
+/// OrderBook types for [`Bitfinex`].
+pub mod book;
+
+/// Candle types for [`Bitfinex`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -77,28 +101,195 @@ impl Connector for Bitfinex {
     type Subscriber = WebSocketSubscriber;
     type SubValidator = BitfinexWebSocketSubValidator;
     type SubResponse = BitfinexPlatformEvent;
+    type Decoder = PlainText;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(BASE_URL_BITFINEX).map_err(SocketError::UrlParse)
     }
 
+    fn supported_intervals() -> &'static [Interval] {
+        &[
+            Interval::Minute1,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Hour1,
+            Interval::Hour6,
+            Interval::Hour12,
+            Interval::Day1,
+            Interval::Week1,
+            Interval::Month1,
+        ]
+    }
+
+    fn validate_subscriptions<Kind>(
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<(), SocketError>
+    where
+        Kind: Debug,
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        match subscriptions
+            .iter()
+            .find(|subscription| subscription.id() == BitfinexChannel::CANDLES_UNSUPPORTED)
+        {
+            Some(unsupported) => Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} (supported candle intervals: 1m, 5m, 15m, 30m, 1h, 6h, 12h, 1d, 1w, 1M)",
+                    unsupported.kind
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         exchange_subs
             .into_iter()
             .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
+                let payload = match channel {
+                    // Candle markets are the candle "key" (eg/ "trade:1m:tBTCUSD")
+                    BitfinexChannel::CANDLES => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "key": market.as_ref(),
+                    }),
+                    // Raw OrderBooks (ie/ individual orders) with real-time updates
+                    BitfinexChannel::ORDER_BOOK_L2 => json!({
                         "event": "subscribe",
                         "channel": channel.as_ref(),
                         "symbol": market.as_ref(),
-                    })
-                    .to_string(),
-                )
+                        "prec": "R0",
+                        "freq": "F0",
+                        "len": BITFINEX_BOOK_L2_LEN.to_string(),
+                    }),
+                    _ => json!({
+                        "event": "subscribe",
+                        "channel": channel.as_ref(),
+                        "symbol": market.as_ref(),
+                    }),
+                };
+
+                WsMessage::Text(payload.to_string())
             })
             .collect()
     }
+
+    fn live_requests(
+        _: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Result<Vec<WsMessage>, SocketError> {
+        // Messages are identified by channel ids only assigned whilst validating subscriptions
+        Err(SocketError::Unsupported {
+            entity: Self::ID.as_str(),
+            item: String::from("subscribing on a live connection"),
+        })
+    }
 }
 
 impl StreamSelector<PublicTrades> for Bitfinex {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BitfinexMessage>>;
 }
+
+impl StreamSelector<Candles> for Bitfinex {
+    type Stream = ExchangeWsStream<HeldCandleTransformer<BitfinexCandleTransformer>>;
+}
+
+impl StreamSelector<CandleUpdates> for Bitfinex {
+    type Stream = ExchangeWsStream<BitfinexCandleTransformer>;
+}
+
+impl StreamSelector<OrderBooksL2> for Bitfinex {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, BitfinexBookUpdater>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::Interval;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_bitfinex_validate_subscriptions() {
+        struct TestCase {
+            input: Vec<Subscription<Bitfinex, Candles>>,
+            expected: Result<(), SocketError>,
+        }
+
+        let candles = |interval| {
+            Subscription::from((
+                Bitfinex,
+                ("btc", "usd", InstrumentKind::Spot),
+                Candles(interval),
+            ))
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: supported candle intervals
+                input: vec![candles(Interval::Minute1), candles(Interval::Month1)],
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: unsupported 4h candle interval
+                input: vec![candles(Interval::Minute1), candles(Interval::Hour4)],
+                expected: Err(SocketError::Unsupported {
+                    entity: "bitfinex",
+                    item: "Candles(Hour4)".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Bitfinex::validate_subscriptions(&test.input);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitfinex_requests() {
+        let candles = Subscription::from((
+            Bitfinex,
+            ("btc", "usd", InstrumentKind::Spot),
+            Candles(Interval::Day1),
+        ));
+        let book =
+            Subscription::from((Bitfinex, ("btc", "usd", InstrumentKind::Spot), OrderBooksL2));
+
+        let requests =
+            Bitfinex::requests(vec![ExchangeSub::new(&candles), ExchangeSub::new(&book)]);
+
+        assert_eq!(
+            requests,
+            vec![
+                WsMessage::Text(
+                    json!({
+                        "event": "subscribe",
+                        "channel": "candles",
+                        "key": "trade:1D:tBTCUSD",
+                    })
+                    .to_string()
+                ),
+                WsMessage::Text(
+                    json!({
+                        "event": "subscribe",
+                        "channel": "book",
+                        "symbol": "tBTCUSD",
+                        "prec": "R0",
+                        "freq": "F0",
+                        "len": "100",
+                    })
+                    .to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -130,6 +130,16 @@ pub enum Status {
 /// }
 /// ```
 ///
+/// #### Subscription Candles Success
+/// ``` json
+/// {
+///   event: "subscribed",
+///   channel: "candles",
+///   chanId: CHANNEL_ID,
+///   key: "trade:1m:tBTCUSD"
+/// }
+/// ```
+///
 /// #### Subscription Failure
 /// ``` json
 /// {
@@ -140,7 +150,7 @@ pub enum Status {
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexSubResponse {
     pub channel: String,
-    #[serde(rename = "symbol")]
+    #[serde(rename = "symbol", alias = "key")]
     pub market: String,
     #[serde(rename = "chanId")]
     pub channel_id: BitfinexChannelId,
@@ -251,7 +261,25 @@ mod tests {
                     market: "tBTCUSD".to_owned(),
                 })),
             },
-            // TC2: Input response is error
+            // TC2: successful candles channel subscription
+            TestCase {
+                input: r#"{"event": "subscribed", "channel": "candles", "chanId": 343351, "key": "trade:1m:tBTCUSD"}"#,
+                expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
+                    channel: "candles".to_string(),
+                    channel_id: BitfinexChannelId(343351),
+                    market: "trade:1m:tBTCUSD".to_owned(),
+                })),
+            },
+            // TC3: successful raw book channel subscription
+            TestCase {
+                input: r#"{"event": "subscribed", "channel": "book", "chanId": 17082, "symbol": "tBTCUSD", "prec": "R0", "freq": "F0", "len": "100", "pair": "BTCUSD"}"#,
+                expected: Ok(BitfinexPlatformEvent::Subscribed(BitfinexSubResponse {
+                    channel: "book".to_string(),
+                    channel_id: BitfinexChannelId(17082),
+                    market: "tBTCUSD".to_owned(),
+                })),
+            },
+            // TC4: Input response is error
             TestCase {
                 input: r#"{"event": "error", "msg": "Already subscribed", "code": 10202}"#,
                 expected: Ok(BitfinexPlatformEvent::Error(BitfinexError {
@@ -1,10 +1,14 @@
 use crate::{
+    de::{datetime_utc_from_epoch_ms, epoch_out_of_range},
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
-    subscription::trade::PublicTrade,
+    subscription::{
+        number::Number,
+        trade::{PublicTrade, TakerSide},
+    },
 };
 use barter_integration::{
-    de::{datetime_utc_from_epoch_duration, extract_next},
+    de::extract_next,
     model::{Exchange, Instrument, Side},
 };
 use chrono::{DateTime, Utc};
@@ -31,7 +35,7 @@ use serde::Serialize;
 ///
 /// ## Notes:
 /// - [`Bitfinex`](super::Bitfinex) trades subscriptions results in receiving tag="te" & tag="tu"
-/// trades, both of which are identical.
+///   trades, both of which are identical.
 /// - "te" trades arrive marginally faster.
 /// - Therefore, tag="tu" trades are filtered out and considered only as additional Heartbeats.
 ///
@@ -41,8 +45,8 @@ pub struct BitfinexTrade {
     pub id: u64,
     pub time: DateTime<Utc>,
     pub side: Side,
-    pub price: f64,
-    pub amount: f64,
+    pub price: Number,
+    pub amount: Number,
 }
 
 impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
@@ -52,11 +56,16 @@ impl From<(ExchangeId, Instrument, BitfinexTrade)> for MarketIter<PublicTrade> {
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: None,
+            tag: None,
             kind: PublicTrade {
                 id: trade.id.to_string(),
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
             },
         })])
     }
@@ -86,12 +95,11 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
                 // Trade: [ID, TIME, AMOUNT,PRICE]
                 let id = extract_next(&mut seq, "id")?;
                 let time_millis = extract_next(&mut seq, "time")?;
-                let amount: f64 = extract_next(&mut seq, "amount")?;
+                let time = datetime_utc_from_epoch_ms(time_millis)
+                    .ok_or_else(|| epoch_out_of_range(time_millis))?;
+                let amount: Number = extract_next(&mut seq, "amount")?;
                 let price = extract_next(&mut seq, "price")?;
-                let side = match amount.is_sign_positive() {
-                    true => Side::Buy,
-                    false => Side::Sell,
-                };
+                let side = Side::from_signed_amount(amount);
 
                 // Ignore any additional elements or SerDe will fail
                 //  '--> Bitfinex may add fields without warning
@@ -99,9 +107,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
 
                 Ok(BitfinexTrade {
                     id,
-                    time: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
-                        time_millis,
-                    )),
+                    time,
                     price,
                     amount: amount.abs(),
                     side,
@@ -1,7 +1,11 @@
-use super::subscription::{BitfinexPlatformEvent, BitfinexSubResponse};
+use super::{
+    channel::BitfinexChannel,
+    subscription::{BitfinexPlatformEvent, BitfinexSubResponse},
+};
 use crate::{
+    decoder::DecodingParser,
     exchange::{Connector, ExchangeSub},
-    subscriber::validator::SubscriptionValidator,
+    subscriber::validator::{SubscriptionOutcome, SubscriptionValidator},
     subscription::{Map, SubKind},
     Identifier,
 };
@@ -9,14 +13,12 @@ use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
     model::{Instrument, SubscriptionId},
-    protocol::{
-        websocket::{WebSocket, WebSocketParser},
-        StreamParser,
-    },
+    protocol::{websocket::WebSocket, StreamParser},
     Validator,
 };
 use futures::StreamExt;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use tracing::debug;
 
 /// [`Bitfinex`](super::Bitfinex) specific [`SubscriptionValidator`].
@@ -30,25 +32,33 @@ use tracing::debug;
 /// - Therefore the [`SubscriptionId`] format must change during [`BitfinexWebSocketSubValidator::validate`]
 ///   to use the [`BitfinexChannelId`](super::subscription::BitfinexChannelId)
 ///   (see module level "SubscriptionId" documentation notes for more details).
+/// - Candle [`SubscriptionId`]s also retain the candle "key" (eg/ "343351|trade:1m:tBTCUSD"), since
+///   the candle [`Interval`](crate::subscription::Interval) is required to normalise each candle.
+/// - Any failure or timeout fails validation of every actioned
+///   [`Subscription`](crate::subscription::Subscription), rather than reporting a partial
+///   [`SubscriptionOutcome`].
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct BitfinexWebSocketSubValidator;
 
 #[async_trait]
 impl SubscriptionValidator for BitfinexWebSocketSubValidator {
-    type Parser = WebSocketParser;
-
     async fn validate<Exchange, Kind>(
         mut map: Map<Instrument>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+        timeout: Duration,
+    ) -> Result<(Map<Instrument>, SubscriptionOutcome), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
     {
         // Establish exchange specific subscription validation parameters
-        let timeout = Exchange::subscription_timeout();
+        let deadline = tokio::time::Instant::now() + timeout;
         let expected_responses = Exchange::expected_responses(&map);
 
+        // Outcome is reported using the channel-market SubscriptionIds actioned, since the
+        // instrument map is re-keyed by BitfinexChannelId during validation
+        let outcome = SubscriptionOutcome::confirmed_all(&map);
+
         // Parameter to keep track of successful Subscription outcomes
         // '--> Bitfinex sends snapshots as the first message, so count them also
         let mut success_responses = 0usize;
@@ -60,12 +70,12 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                 && init_snapshots_received == expected_responses
             {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(map);
+                break Ok((map, outcome));
             }
 
             tokio::select! {
                 // If timeout reached, return SubscribeError
-                _ = tokio::time::sleep(timeout) => {
+                _ = tokio::time::sleep_until(deadline) => {
                     break Err(SocketError::Subscribe(
                         format!("subscription validation timeout reached: {:?}", timeout)
                     ))
@@ -77,7 +87,7 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                         None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
                     };
 
-                    match Self::Parser::parse::<BitfinexPlatformEvent>(response) {
+                    match DecodingParser::<Exchange::Decoder>::parse::<BitfinexPlatformEvent>(response) {
                         Some(Ok(response)) => match response.validate() {
                             // Bitfinex server is online
                             Ok(BitfinexPlatformEvent::PlatformStatus(status)) => {
@@ -96,10 +106,17 @@ impl SubscriptionValidator for BitfinexWebSocketSubValidator {
                                 let BitfinexSubResponse { channel, market, channel_id } = &response;
                                 let subscription_id = ExchangeSub::from((channel, market)).id();
 
-                                // Replace SubscriptionId with SubscriptionId(channel_id)
+                                // Replace SubscriptionId with SubscriptionId(channel_id), retaining
+                                // the candle key so the candle Interval can be determined
+                                let channel_subscription_id = if channel == BitfinexChannel::CANDLES.as_ref() {
+                                    SubscriptionId(format!("{}|{market}", channel_id.0))
+                                } else {
+                                    SubscriptionId(channel_id.0.to_string())
+                                };
+
                                 if let Some(subscription) = map.0.remove(&subscription_id) {
                                     success_responses += 1;
-                                    map.0.insert(SubscriptionId(channel_id.0.to_string()), subscription);
+                                    map.0.insert(channel_subscription_id, subscription);
 
                                     debug!(
                                         exchange = %Exchange::ID,
@@ -0,0 +1,349 @@
+use super::BitstampLevel;
+use crate::{
+    error::DataError,
+    exchange::bitstamp::message::{de_str_epoch_us_as_datetime_utc, BitstampMessage},
+    subscription::book::{OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, Side},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Bitstamp`](super::super::Bitstamp) HTTP OrderBook L2 snapshot url.
+///
+/// See docs: <https://www.bitstamp.net/api/#tag/Order-book>
+pub const HTTP_BOOK_L2_SNAPSHOT_URL_BITSTAMP: &str = "https://www.bitstamp.net/api/v2/order_book";
+
+/// Terse type alias for a [`Bitstamp`](super::super::Bitstamp) real-time OrderBook Level2 diff
+/// WebSocket message.
+pub type BitstampOrderBookL2 = BitstampMessage<BitstampOrderBookL2Diff>;
+
+/// [`Bitstamp`](super::super::Bitstamp) OrderBook Level2 diff containing the absolute amount of
+/// every changed level, where an amount of 0 means the level should be removed.
+///
+/// See [`BitstampMessage`] for full raw payload examples.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampOrderBookL2Diff {
+    #[serde(
+        alias = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub bids: Vec<BitstampLevel>,
+    pub asks: Vec<BitstampLevel>,
+}
+
+/// [`Bitstamp`](super::super::Bitstamp) OrderBook Level2 snapshot HTTP message.
+///
+/// Used as the starting [`OrderBook`] before OrderBook Level2 diff WebSocket updates are
+/// applied.
+///
+/// ### Payload Examples
+/// See docs: <https://www.bitstamp.net/api/#tag/Order-book>
+/// ```json
+/// {
+///     "timestamp": "1680522427",
+///     "microtimestamp": "1680522427432000",
+///     "bids": [["28074", "0.25000000"]],
+///     "asks": [["28076", "1.00000000"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampOrderBookL2Snapshot {
+    #[serde(
+        alias = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub bids: Vec<BitstampLevel>,
+    pub asks: Vec<BitstampLevel>,
+}
+
+impl From<BitstampOrderBookL2Snapshot> for OrderBook {
+    fn from(snapshot: BitstampOrderBookL2Snapshot) -> Self {
+        Self {
+            last_update_time: snapshot.time,
+            bids: OrderBookSide::new(Side::Buy, snapshot.bids),
+            asks: OrderBookSide::new(Side::Sell, snapshot.asks),
+            first_update_id: None,
+            last_update_id: None,
+        }
+    }
+}
+
+/// Fetch a [`BitstampOrderBookL2Snapshot`] for the provided [`Instrument`] via HTTP from the
+/// provided order book endpoint (eg/ "https://www.bitstamp.net/api/v2/order_book").
+///
+/// The `snapshot_url` is injectable so an alternative server (eg/ a mock server) can be used.
+pub async fn fetch_snapshot(
+    snapshot_url: &str,
+    instrument: &Instrument,
+) -> Result<BitstampOrderBookL2Snapshot, DataError> {
+    // Construct initial OrderBook snapshot GET url
+    let snapshot_url = format!(
+        "{}/{}{}/",
+        snapshot_url,
+        instrument.base.as_ref().to_lowercase(),
+        instrument.quote.as_ref().to_lowercase()
+    );
+
+    // Fetch initial OrderBook snapshot via HTTP
+    reqwest::get(snapshot_url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BitstampOrderBookL2Snapshot>()
+        .await
+        .map_err(SocketError::Http)
+        .map_err(DataError::from)
+}
+
+/// [`Bitstamp`](super::super::Bitstamp) [`OrderBookUpdater`].
+///
+/// Bitstamp: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "diff_order_book_{market}" channel & buffer the diffs received.
+/// 2. Fetch an OrderBook snapshot via the HTTP "order_book" endpoint.
+/// 3. Drop any diff with a "microtimestamp" <= the "microtimestamp" of the snapshot.
+/// 4. The data in each diff is the absolute amount for a price level.
+/// 5. If the amount is 0, remove the price level.
+///
+/// ## Notes:
+/// - Diffs are not sequenced, so gaps cannot be detected. A "bts:request_reconnect" message
+///   re-initialises the [`MarketStream`](crate::MarketStream), re-syncing from a fresh snapshot.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitstampBookUpdater {
+    pub last_update_time: DateTime<Utc>,
+}
+
+impl BitstampBookUpdater {
+    /// Construct a new Bitstamp [`OrderBookUpdater`] using the provided "microtimestamp" of a
+    /// HTTP snapshot.
+    pub fn new(last_update_time: DateTime<Utc>) -> Self {
+        Self { last_update_time }
+    }
+
+    /// Initialise the [`InstrumentOrderBook`] for the provided [`Instrument`] using an
+    /// OrderBook snapshot fetched from the provided `snapshot_url` (eg/ a mock server in tests).
+    pub async fn init_with_snapshot_url(
+        snapshot_url: &str,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError> {
+        let snapshot = fetch_snapshot(snapshot_url, &instrument).await?;
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(snapshot.time),
+            book: OrderBook::from(snapshot),
+        })
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for BitstampBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = BitstampOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        Self::init_with_snapshot_url(HTTP_BOOK_L2_SNAPSHOT_URL_BITSTAMP, instrument).await
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        // Bitstamp: How To Maintain A Local OrderBook
+        // See Self's Rust Docs for more information on each numbered step
+        let diff = match update {
+            BitstampMessage::Data(payload) => payload.data,
+            BitstampMessage::Event(_) => return Ok(None),
+        };
+
+        // 3. Drop any diff with a microtimestamp <= the microtimestamp of the snapshot:
+        if diff.time <= self.last_update_time {
+            return Ok(None);
+        }
+
+        // Update OrderBook metadata & Levels:
+        // 4. The data in each diff is the absolute amount for a price level.
+        // 5. If the amount is 0, remove the price level.
+        book.last_update_time = diff.time;
+        book.bids.upsert(diff.bids);
+        book.asks.upsert(diff.asks);
+
+        // Update OrderBookUpdater metadata
+        self.last_update_time = diff.time;
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use crate::{exchange::bitstamp::message::BitstampPayload, subscription::book::Level};
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        model::{InstrumentKind, SubscriptionId},
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitstamp_order_book_l2_diff() {
+            let input = r#"
+            {
+                "data": {
+                    "timestamp": "1680522427",
+                    "microtimestamp": "1680522427554120",
+                    "bids": [["28074", "0.25000000"]],
+                    "asks": [["28076", "0"]]
+                },
+                "channel": "diff_order_book_btcusd",
+                "event": "data"
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<BitstampOrderBookL2>(input).unwrap(),
+                BitstampOrderBookL2::Data(BitstampPayload {
+                    subscription_id: SubscriptionId::from("diff_order_book|btcusd"),
+                    data: BitstampOrderBookL2Diff {
+                        time: datetime_utc_from_epoch_duration(Duration::from_micros(
+                            1680522427554120
+                        )),
+                        bids: vec![BitstampLevel {
+                            price: from_f64(28074.0),
+                            amount: from_f64(0.25)
+                        }],
+                        asks: vec![BitstampLevel {
+                            price: from_f64(28076.0),
+                            amount: from_f64(0.0)
+                        }],
+                    },
+                })
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_snapshot() {
+        let url = crate::exchange::binance::book::l2::tests::mock_snapshot_server(
+            r#"{"timestamp": "1680522427", "microtimestamp": "1680522427432000", "bids": [["28074", "0.25"]], "asks": [["28076", "1.0"]]}"#,
+        );
+
+        let actual = fetch_snapshot(
+            &url,
+            &Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            actual,
+            BitstampOrderBookL2Snapshot {
+                time: datetime_utc_from_epoch_duration(Duration::from_micros(1680522427432000)),
+                bids: vec![BitstampLevel {
+                    price: from_f64(28074.0),
+                    amount: from_f64(0.25)
+                }],
+                asks: vec![BitstampLevel {
+                    price: from_f64(28076.0),
+                    amount: from_f64(1.0)
+                }],
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_init_with_snapshot_url_diffs() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<()>,
+        }
+
+        let url = crate::exchange::binance::book::l2::tests::mock_snapshot_server(
+            r#"{"timestamp": "1680522427", "microtimestamp": "1680522427432000", "bids": [["50.0", "1.0"]], "asks": [["100.0", "1.0"]]}"#,
+        );
+        let mut book = BitstampBookUpdater::init_with_snapshot_url(
+            &url,
+            Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            book.updater,
+            BitstampBookUpdater::new(datetime_utc_from_epoch_duration(Duration::from_micros(
+                1680522427432000
+            )))
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: diff older than the snapshot is discarded
+                input: r#"{"data":{"timestamp":"1680522427","microtimestamp":"1680522427400000","bids":[["50.0","0"]],"asks":[]},"channel":"diff_order_book_btcusd","event":"data"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC1: diff at the snapshot microtimestamp is discarded
+                input: r#"{"data":{"timestamp":"1680522427","microtimestamp":"1680522427432000","bids":[["50.0","0"]],"asks":[]},"channel":"diff_order_book_btcusd","event":"data"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC2: diff newer than the snapshot is applied
+                input: r#"{"data":{"timestamp":"1680522427","microtimestamp":"1680522427554120","bids":[["60.0","1.0"]],"asks":[["100.0","0"]]},"channel":"diff_order_book_btcusd","event":"data"}"#,
+                expected: Some(()),
+            },
+            TestCase {
+                // TC3: next diff is applied
+                input: r#"{"data":{"timestamp":"1680522428","microtimestamp":"1680522428000000","bids":[["60.0","2.0"]],"asks":[["110.0","3.0"]]},"channel":"diff_order_book_btcusd","event":"data"}"#,
+                expected: Some(()),
+            },
+            TestCase {
+                // TC4: late unsubscription success is skipped
+                input: r#"{"event":"bts:unsubscription_succeeded","channel":"diff_order_book_btcusd","data":{}}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let update = serde_json::from_str::<BitstampOrderBookL2>(test.input).unwrap();
+            let actual = book.updater.update(&mut book.book, update).unwrap();
+            assert_eq!(actual.map(|_| ()), test.expected, "TC{} failed", index);
+        }
+
+        let snapshot = book.book.snapshot();
+        assert_eq!(
+            snapshot.last_update_time,
+            datetime_utc_from_epoch_duration(Duration::from_micros(1680522428000000))
+        );
+        assert_eq!(
+            snapshot.bids,
+            OrderBookSide::new(Side::Buy, vec![Level::new(60, 2), Level::new(50, 1)]),
+        );
+        assert_eq!(
+            snapshot.asks,
+            OrderBookSide::new(Side::Sell, vec![Level::new(110, 3)]),
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use crate::subscription::{book::Level, number::Number};
+use serde::{Deserialize, Serialize};
+
+/// Level 2 OrderBook types & [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater)
+/// implementation.
+pub mod l2;
+
+/// [`Bitstamp`](super::Bitstamp) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+/// ```json
+/// ["28074", "0.25000000"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<BitstampLevel> for Level {
+    fn from(level: BitstampLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitstamp_level() {
+            let input = r#"["28074", "0.25000000"]"#;
+            assert_eq!(
+                serde_json::from_str::<BitstampLevel>(input).unwrap(),
+                BitstampLevel {
+                    price: from_f64(28074.0),
+                    amount: from_f64(0.25)
+                },
+            )
+        }
+    }
+}
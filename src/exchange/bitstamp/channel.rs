@@ -0,0 +1,46 @@
+use super::Bitstamp;
+use crate::{
+    subscription::{book::OrderBooksL2, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Bitstamp`](super::Bitstamp) channel to be subscribed to.
+///
+/// ### Notes
+/// The channel is suffixed with the market when subscribing (eg/ "live_trades_btcusd").
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct BitstampChannel(pub &'static str);
+
+impl BitstampChannel {
+    /// [`Bitstamp`](super::Bitstamp) real-time trades channel name.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const TRADES: Self = Self("live_trades");
+
+    /// [`Bitstamp`](super::Bitstamp) real-time OrderBook Level2 diff channel name.
+    ///
+    /// See docs: <https://www.bitstamp.net/websocket/v2/>
+    pub const ORDER_BOOK_L2: Self = Self("diff_order_book");
+}
+
+impl Identifier<BitstampChannel> for Subscription<Bitstamp, PublicTrades> {
+    fn id(&self) -> BitstampChannel {
+        BitstampChannel::TRADES
+    }
+}
+
+impl Identifier<BitstampChannel> for Subscription<Bitstamp, OrderBooksL2> {
+    fn id(&self) -> BitstampChannel {
+        BitstampChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for BitstampChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
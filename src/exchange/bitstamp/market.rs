@@ -0,0 +1,22 @@
+use super::Bitstamp;
+use crate::{subscription::Subscription, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Bitstamp`](super::Bitstamp) market that can be subscribed to.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitstampMarket(pub String);
+
+impl<Kind> Identifier<BitstampMarket> for Subscription<Bitstamp, Kind> {
+    fn id(&self) -> BitstampMarket {
+        BitstampMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_lowercase())
+    }
+}
+
+impl AsRef<str> for BitstampMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
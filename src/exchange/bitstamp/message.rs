@@ -0,0 +1,339 @@
+use crate::{
+    de::{datetime_utc_from_epoch_us, epoch_out_of_range},
+    error::DataError,
+    exchange::ExchangeSub,
+    transformer::error::ExchangeMessage,
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitstamp`](super::Bitstamp) market data message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+///
+/// #### Trades
+/// ```json
+/// {
+///     "data": {
+///         "id": 276012539,
+///         "timestamp": "1680522427",
+///         "amount": 0.0051,
+///         "amount_str": "0.00510000",
+///         "price": 28075,
+///         "price_str": "28075",
+///         "type": 1,
+///         "microtimestamp": "1680522427432000",
+///         "buy_order_id": 1610404213260288,
+///         "sell_order_id": 1610404214116353
+///     },
+///     "channel": "live_trades_btcusd",
+///     "event": "trade"
+/// }
+/// ```
+///
+/// #### OrderBookL2 Diff
+/// ```json
+/// {
+///     "data": {
+///         "timestamp": "1680522427",
+///         "microtimestamp": "1680522427554120",
+///         "bids": [["28074", "0.25000000"]],
+///         "asks": [["28076", "0"]]
+///     },
+///     "channel": "diff_order_book_btcusd",
+///     "event": "data"
+/// }
+/// ```
+///
+/// #### Unsubscription Success
+/// ```json
+/// {
+///     "event": "bts:unsubscription_succeeded",
+///     "channel": "live_trades_btcusd",
+///     "data": {}
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitstampMessage<T> {
+    Data(BitstampPayload<T>),
+    Event(BitstampEvent),
+}
+
+/// [`Bitstamp`](super::Bitstamp) market data payload containing the generic `T` data.
+///
+/// See [`BitstampMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampPayload<T> {
+    #[serde(
+        alias = "channel",
+        deserialize_with = "de_bitstamp_channel_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+/// [`Bitstamp`](super::Bitstamp) non market data message that is skipped once the
+/// [`MarketStream`](crate::MarketStream) is initialised.
+///
+/// See [`BitstampMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event")]
+pub enum BitstampEvent {
+    #[serde(rename = "bts:subscription_succeeded")]
+    Subscribed { channel: String },
+    #[serde(rename = "bts:unsubscription_succeeded")]
+    Unsubscribed { channel: String },
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BitstampMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BitstampMessage::Data(payload) => Some(payload.subscription_id.clone()),
+            BitstampMessage::Event(_) => None,
+        }
+    }
+}
+
+/// [`Bitstamp`](super::Bitstamp) WebSocket message that is either a [`BitstampMessage`], or a
+/// [`BitstampControl`] message sent by the exchange server mid-stream.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+///
+/// #### Request Reconnect
+/// ```json
+/// {
+///     "event": "bts:request_reconnect",
+///     "channel": "",
+///     "data": ""
+/// }
+/// ```
+///
+/// #### Error
+/// ```json
+/// {
+///     "event": "bts:error",
+///     "channel": "",
+///     "data": {
+///         "code": null,
+///         "message": "Bad subscription string."
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BitstampWsMessage<T> {
+    Data(T),
+    Control(BitstampControl),
+}
+
+/// [`Bitstamp`](super::Bitstamp) control message sent by the exchange server mid-stream.
+///
+/// See [`BitstampWsMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event")]
+pub enum BitstampControl {
+    /// Sent before the exchange server is taken down for maintenance, requesting clients to
+    /// re-connect to another server.
+    #[serde(rename = "bts:request_reconnect")]
+    RequestReconnect,
+    #[serde(rename = "bts:error")]
+    Error { data: BitstampError },
+}
+
+/// [`Bitstamp`](super::Bitstamp) error message contents.
+///
+/// See [`BitstampWsMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BitstampError {
+    pub code: Option<i64>,
+    pub message: String,
+}
+
+impl<T> ExchangeMessage for BitstampWsMessage<T> {
+    type Data = T;
+
+    /// A [`BitstampControl::RequestReconnect`] is translated into a terminal
+    /// [`SocketError::Terminated`], taking the same re-connection path as a server initiated
+    /// close, whereas a [`BitstampControl::Error`] is a recoverable [`DataError::Exchange`].
+    fn into_data(self) -> Result<T, DataError> {
+        match self {
+            BitstampWsMessage::Data(data) => Ok(data),
+            BitstampWsMessage::Control(BitstampControl::RequestReconnect) => Err(DataError::from(
+                SocketError::Terminated("bts:request_reconnect".to_string()),
+            )),
+            BitstampWsMessage::Control(BitstampControl::Error { data }) => {
+                Err(DataError::Exchange {
+                    code: data.code.map(|code| code.to_string()).unwrap_or_default(),
+                    message: data.message,
+                    fatal: false,
+                })
+            }
+        }
+    }
+}
+
+/// Deserialize a [`BitstampPayload`] "channel" (eg/ "live_trades_btcusd") as the associated
+/// [`SubscriptionId`] (eg/ "live_trades|btcusd").
+pub fn de_bitstamp_channel_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let channel = <&str as Deserialize>::deserialize(deserializer)?;
+
+    channel
+        .rsplit_once('_')
+        .filter(|(_, market)| !market.is_empty())
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(channel),
+                &"Bitstamp channel in the format {channel}_{market}",
+            )
+        })
+}
+
+/// Deserialize a [`Bitstamp`](super::Bitstamp) String epoch microseconds "microtimestamp"
+/// (eg/ "1680522427432000") as a [`DateTime<Utc>`].
+pub fn de_str_epoch_us_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let epoch_us = barter_integration::de::de_str::<D, u64>(deserializer)?;
+    datetime_utc_from_epoch_us(epoch_us).ok_or_else(|| epoch_out_of_range(epoch_us))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::bitstamp::trade::BitstampTrades;
+
+        #[test]
+        fn test_bitstamp_ws_message_control() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitstampWsMessage<BitstampTrades>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input reconnect request is BitstampControl::RequestReconnect
+                    input: r#"{"event":"bts:request_reconnect","channel":"","data":""}"#,
+                    expected: Ok(BitstampWsMessage::Control(
+                        BitstampControl::RequestReconnect,
+                    )),
+                },
+                TestCase {
+                    // TC1: input error is BitstampControl::Error
+                    input: r#"{"event":"bts:error","channel":"","data":{"code":null,"message":"Bad subscription string."}}"#,
+                    expected: Ok(BitstampWsMessage::Control(BitstampControl::Error {
+                        data: BitstampError {
+                            code: None,
+                            message: "Bad subscription string.".to_string(),
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC2: input unsubscription success is BitstampMessage::Event
+                    input: r#"{"event":"bts:unsubscription_succeeded","channel":"live_trades_btcusd","data":{}}"#,
+                    expected: Ok(BitstampWsMessage::Data(BitstampMessage::Event(
+                        BitstampEvent::Unsubscribed {
+                            channel: "live_trades_btcusd".to_string(),
+                        },
+                    ))),
+                },
+                TestCase {
+                    // TC3: input unknown event is invalid
+                    input: r#"{"event":"bts:unknown","channel":"","data":""}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitstampWsMessage<BitstampTrades>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bitstamp_ws_message_into_data() {
+        // Reconnect request takes the re-connection path
+        let reconnect = BitstampWsMessage::<()>::Control(BitstampControl::RequestReconnect)
+            .into_data()
+            .unwrap_err();
+        assert!(matches!(
+            reconnect,
+            DataError::Socket(SocketError::Terminated(_))
+        ));
+        assert!(reconnect.is_terminal());
+
+        // Error is surfaced as a recoverable DataError::Exchange
+        let error = BitstampWsMessage::<()>::Control(BitstampControl::Error {
+            data: BitstampError {
+                code: None,
+                message: "Bad subscription string.".to_string(),
+            },
+        })
+        .into_data()
+        .unwrap_err();
+        assert!(matches!(error, DataError::Exchange { fatal: false, .. }));
+        assert!(!error.is_terminal());
+    }
+
+    #[test]
+    fn test_de_bitstamp_channel_as_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades channel
+                input: r#""live_trades_btcusd""#,
+                expected: Some(SubscriptionId::from("live_trades|btcusd")),
+            },
+            TestCase {
+                // TC1: order book l2 diff channel
+                input: r#""diff_order_book_ethusd""#,
+                expected: Some(SubscriptionId::from("diff_order_book|ethusd")),
+            },
+            TestCase {
+                // TC2: empty channel is invalid
+                input: r#""""#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut deserializer = serde_json::Deserializer::from_str(test.input);
+            let actual = de_bitstamp_channel_as_subscription_id(&mut deserializer).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use self::{
+    book::l2::BitstampBookUpdater, channel::BitstampChannel, market::BitstampMarket,
+    message::BitstampWsMessage, subscription::BitstampSubResponse, trade::BitstampTrades,
+};
+use crate::{
+    decoder::PlainText,
+    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL2, trade::PublicTrades},
+    transformer::{
+        book::MultiBookTransformer, error::ErrorTransformer, stateless::StatelessTransformer,
+    },
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// Order book types for [`Bitstamp`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`BitstampMessage`](message::BitstampMessage) & [`BitstampWsMessage`] types for
+/// [`Bitstamp`].
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Bitstamp`].
+pub mod subscription;
+
+/// Public trade types for [`Bitstamp`].
+pub mod trade;
+
+/// [`Bitstamp`] server base url.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+pub const BASE_URL_BITSTAMP: &str = "wss://ws.bitstamp.net";
+
+/// Convenient type alias for a [`Bitstamp`] [`ExchangeWsStream`] that surfaces
+/// [`BitstampWsMessage::Control`] messages as [`DataError`](crate::error::DataError)s.
+pub type BitstampWsStream<Transformer> = ExchangeWsStream<
+    ErrorTransformer<
+        Transformer,
+        BitstampWsMessage<<Transformer as barter_integration::Transformer>::Input>,
+    >,
+>;
+
+/// [`Bitstamp`] exchange.
+///
+/// ### Notes
+/// - Each channel is subscribed to in a distinct request, which is acknowledged with a
+///   distinct [`BitstampSubResponse`].
+/// - [`Bitstamp`] sends a "bts:request_reconnect" message before maintenance, which is
+///   translated into a terminal error so the [`MarketStream`](crate::MarketStream) re-connects.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Bitstamp;
+
+impl Connector for Bitstamp {
+    const ID: ExchangeId = ExchangeId::Bitstamp;
+    type Channel = BitstampChannel;
+    type Market = BitstampMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = BitstampSubResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_BITSTAMP).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        bitstamp_requests("bts:subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        bitstamp_requests("bts:unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`Bitstamp`] [`WsMessage`] payloads that action the provided event (eg/
+/// "bts:subscribe", "bts:unsubscribe") for a collection of [`ExchangeSub`]s.
+fn bitstamp_requests(
+    event: &str,
+    exchange_subs: Vec<ExchangeSub<BitstampChannel, BitstampMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            WsMessage::Text(
+                json!({
+                    "event": event,
+                    "data": {
+                        "channel": format!("{}_{}", channel.as_ref(), market.as_ref()),
+                    },
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+impl StreamSelector<PublicTrades> for Bitstamp {
+    type Stream = BitstampWsStream<StatelessTransformer<Self, PublicTrades, BitstampTrades>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Bitstamp {
+    type Stream = BitstampWsStream<MultiBookTransformer<Self, OrderBooksL2, BitstampBookUpdater>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{subscription::Subscription, Identifier};
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_bitstamp_requests() {
+        let exchange_subs = || {
+            vec![
+                ExchangeSub::from((
+                    BitstampChannel::TRADES,
+                    BitstampMarket("btcusd".to_string()),
+                )),
+                ExchangeSub::from((
+                    BitstampChannel::ORDER_BOOK_L2,
+                    BitstampMarket("ethusd".to_string()),
+                )),
+            ]
+        };
+
+        assert_eq!(
+            Bitstamp::requests(exchange_subs()),
+            vec![
+                WsMessage::Text(
+                    json!({"event": "bts:subscribe", "data": {"channel": "live_trades_btcusd"}})
+                        .to_string()
+                ),
+                WsMessage::Text(
+                    json!({"event": "bts:subscribe", "data": {"channel": "diff_order_book_ethusd"}})
+                        .to_string()
+                ),
+            ]
+        );
+
+        assert_eq!(
+            Bitstamp::unsubscribe_requests(exchange_subs())[0],
+            WsMessage::Text(
+                json!({"event": "bts:unsubscribe", "data": {"channel": "live_trades_btcusd"}})
+                    .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn test_bitstamp_subscription_id() {
+        // PublicTrades Subscription id matches the channel of the trade message
+        let sub = Subscription::<_, PublicTrades>::new(
+            Bitstamp,
+            ("btc", "usd", InstrumentKind::Spot),
+            PublicTrades,
+        );
+
+        assert_eq!(
+            Some(ExchangeSub::<BitstampChannel, BitstampMarket>::new(&sub).id()),
+            serde_json::from_str::<BitstampTrades>(
+                r#"{"data":{"id":1,"amount":0.1,"price":28075,"type":0,"microtimestamp":"1680522427432000","buy_order_id":2,"sell_order_id":3},"channel":"live_trades_btcusd","event":"trade"}"#,
+            )
+            .unwrap()
+            .id(),
+        );
+    }
+}
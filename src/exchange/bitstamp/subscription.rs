@@ -0,0 +1,145 @@
+use super::message::BitstampError;
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Bitstamp`](super::Bitstamp) subscription response message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "event": "bts:subscription_succeeded",
+///     "channel": "live_trades_btcusd",
+///     "data": {}
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "event": "bts:error",
+///     "channel": "",
+///     "data": {
+///         "code": null,
+///         "message": "Bad subscription string."
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event")]
+pub enum BitstampSubResponse {
+    #[serde(rename = "bts:subscription_succeeded")]
+    Subscribed { channel: String },
+    #[serde(rename = "bts:error")]
+    Error { data: BitstampError },
+}
+
+impl Validator for BitstampSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            BitstampSubResponse::Subscribed { .. } => Ok(self),
+            BitstampSubResponse::Error { data } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response with message: {}",
+                data.message,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bitstamp_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitstampSubResponse, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input response is subscription success
+                    input: r#"{"event":"bts:subscription_succeeded","channel":"live_trades_btcusd","data":{}}"#,
+                    expected: Ok(BitstampSubResponse::Subscribed {
+                        channel: "live_trades_btcusd".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"{"event":"bts:error","channel":"","data":{"code":null,"message":"Bad subscription string."}}"#,
+                    expected: Ok(BitstampSubResponse::Error {
+                        data: BitstampError {
+                            code: None,
+                            message: "Bad subscription string.".to_string(),
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC2: input reconnect request is not a subscription response
+                    input: r#"{"event":"bts:request_reconnect","channel":"","data":""}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitstampSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_bitstamp_sub_response() {
+        struct TestCase {
+            input_response: BitstampSubResponse,
+            is_valid: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: BitstampSubResponse::Subscribed {
+                    channel: "diff_order_book_btcusd".to_string(),
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: BitstampSubResponse::Error {
+                    data: BitstampError {
+                        code: None,
+                        message: "Bad subscription string.".to_string(),
+                    },
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TC{} failed", index);
+        }
+    }
+}
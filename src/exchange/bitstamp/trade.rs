@@ -0,0 +1,176 @@
+use super::message::{de_str_epoch_us_as_datetime_utc, BitstampMessage};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{number::Number, trade::PublicTrade},
+};
+use barter_integration::model::{Exchange, Instrument, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bitstamp`](super::Bitstamp) real-time trades WebSocket message.
+pub type BitstampTrades = BitstampMessage<BitstampTrade>;
+
+/// [`Bitstamp`](super::Bitstamp) real-time trade.
+///
+/// See [`BitstampMessage`] for full raw payload examples.
+///
+/// ## Notes:
+/// - "type" is the taker side of the trade, where 0 is a buy and 1 is a sell.
+///
+/// See docs: <https://www.bitstamp.net/websocket/v2/>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BitstampTrade {
+    pub id: u64,
+    #[serde(
+        alias = "microtimestamp",
+        deserialize_with = "de_str_epoch_us_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub price: Number,
+    pub amount: Number,
+    #[serde(alias = "type", deserialize_with = "de_side_from_trade_type")]
+    pub side: Side,
+    pub buy_order_id: u64,
+    pub sell_order_id: u64,
+}
+
+impl From<(ExchangeId, Instrument, BitstampTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, BitstampTrades)) -> Self {
+        match trades {
+            BitstampMessage::Data(payload) => Self(vec![Ok(MarketEvent {
+                exchange_time: payload.data.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                sequence: None,
+                tag: None,
+                kind: PublicTrade {
+                    id: payload.data.id.to_string(),
+                    price: payload.data.price,
+                    amount: payload.data.amount,
+                    side: payload.data.side,
+                    buyer_order_id: Some(payload.data.buy_order_id.to_string()),
+                    seller_order_id: Some(payload.data.sell_order_id.to_string()),
+                    taker_order_kind: None,
+                },
+            })]),
+            BitstampMessage::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+/// Deserialize a [`BitstampTrade`] "type" taker indicator (eg/ 0) as the associated [`Side`].
+pub fn de_side_from_trade_type<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <u8 as Deserialize>::deserialize(deserializer)? {
+        0 => Ok(Side::Buy),
+        1 => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Unsigned(other as u64),
+            &"Bitstamp trade type of 0 (buy) or 1 (sell)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::exchange::bitstamp::message::{BitstampEvent, BitstampPayload};
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_bitstamp_message_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitstampTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input trade is deserialised
+                    input: r#"
+                    {
+                        "data": {
+                            "id": 276012539,
+                            "timestamp": "1680522427",
+                            "amount": 0.0051,
+                            "amount_str": "0.00510000",
+                            "price": 28075,
+                            "price_str": "28075",
+                            "type": 1,
+                            "microtimestamp": "1680522427432000",
+                            "buy_order_id": 1610404213260288,
+                            "sell_order_id": 1610404214116353
+                        },
+                        "channel": "live_trades_btcusd",
+                        "event": "trade"
+                    }
+                    "#,
+                    expected: Ok(BitstampTrades::Data(BitstampPayload {
+                        subscription_id: SubscriptionId::from("live_trades|btcusd"),
+                        data: BitstampTrade {
+                            id: 276012539,
+                            time: datetime_utc_from_epoch_duration(Duration::from_micros(
+                                1680522427432000,
+                            )),
+                            price: from_f64(28075.0),
+                            amount: from_f64(0.0051),
+                            side: Side::Sell,
+                            buy_order_id: 1610404213260288,
+                            sell_order_id: 1610404214116353,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input subscription success is BitstampMessage::Event
+                    input: r#"{"event":"bts:subscription_succeeded","channel":"live_trades_btcusd","data":{}}"#,
+                    expected: Ok(BitstampTrades::Event(BitstampEvent::Subscribed {
+                        channel: "live_trades_btcusd".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input trade w/ unknown type fails to deserialise
+                    input: r#"{"data":{"id":1,"amount":0.1,"price":28075,"type":2,"microtimestamp":"1680522427432000","buy_order_id":2,"sell_order_id":3},"channel":"live_trades_btcusd","event":"trade"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: input trade w/ non-numeric microtimestamp fails to deserialise
+                    input: r#"{"data":{"id":1,"amount":0.1,"price":28075,"type":0,"microtimestamp":"invalid","buy_order_id":2,"sell_order_id":3},"channel":"live_trades_btcusd","event":"trade"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitstampTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use super::BybitLevel;
+use crate::{
+    event::{MarketEvent, MarketIter, TimeSource},
+    exchange::{bybit::message::BybitMessage, ExchangeId},
+    subscription::book::OrderBookL1,
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bybit`](super::super::Bybit) real-time OrderBook Level1 (top of book)
+/// WebSocket message.
+pub type BybitOrderBookL1 = BybitMessage<BybitOrderBookL1Inner>;
+
+/// [`Bybit`](super::super::Bybit) real-time OrderBook Level1 (top of book) data.
+///
+/// See [`BybitMessage`] for full raw payload examples.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+///
+/// ## Notes:
+/// - A "delta" message only contains the side of the book that changed. Since the
+///   [`StatelessTransformer`](crate::transformer::stateless::StatelessTransformer) holds no
+///   state, messages without both a best bid and best ask are skipped.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitOrderBookL1Inner {
+    #[serde(alias = "b")]
+    pub bids: Vec<BybitLevel>,
+    #[serde(alias = "a")]
+    pub asks: Vec<BybitLevel>,
+}
+
+impl From<(ExchangeId, Instrument, BybitOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, BybitOrderBookL1)) -> Self {
+        let payload = match book {
+            BybitMessage::Data(payload) => payload,
+            BybitMessage::Response(_) => return Self(vec![]),
+        };
+
+        match (payload.data.bids.first(), payload.data.asks.first()) {
+            (Some(best_bid), Some(best_ask)) => Self(vec![Ok(MarketEvent {
+                exchange_time: payload.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                sequence: None,
+                tag: None,
+                kind: OrderBookL1 {
+                    last_update_time: payload.time,
+                    best_bid: (*best_bid).into(),
+                    best_ask: (*best_ask).into(),
+                    time_source: TimeSource::Exchange,
+                },
+            })]),
+            _ => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use crate::{exchange::bybit::message::BybitPayload, subscription::book::Level};
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        error::SocketError,
+        model::{InstrumentKind, SubscriptionId},
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitOrderBookL1, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid BybitPerpetualsUsd snapshot BybitOrderBookL1
+                    input: r#"
+                    {
+                        "topic": "orderbook.1.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0.006"]],
+                            "a": [["16611.00", "0.029"]],
+                            "u": 18521288,
+                            "seq": 7961638724
+                        },
+                        "cts": 1672304484976
+                    }
+                    "#,
+                    expected: Ok(BybitMessage::Data(BybitPayload {
+                        subscription_id: SubscriptionId::from("orderbook.1|BTCUSDT"),
+                        kind: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484978,
+                        )),
+                        data: BybitOrderBookL1Inner {
+                            bids: vec![BybitLevel {
+                                price: from_f64(16493.50),
+                                amount: from_f64(0.006),
+                            }],
+                            asks: vec![BybitLevel {
+                                price: from_f64(16611.00),
+                                amount: from_f64(0.029),
+                            }],
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: valid BybitPerpetualsUsd delta BybitOrderBookL1 w/ only asks
+                    input: r#"
+                    {
+                        "topic": "orderbook.1.BTCUSDT",
+                        "type": "delta",
+                        "ts": 1672304484980,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [],
+                            "a": [["16611.50", "0.5"]],
+                            "u": 18521289,
+                            "seq": 7961638725
+                        },
+                        "cts": 1672304484978
+                    }
+                    "#,
+                    expected: Ok(BybitMessage::Data(BybitPayload {
+                        subscription_id: SubscriptionId::from("orderbook.1|BTCUSDT"),
+                        kind: "delta".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304484980,
+                        )),
+                        data: BybitOrderBookL1Inner {
+                            bids: vec![],
+                            asks: vec![BybitLevel {
+                                price: from_f64(16611.50),
+                                amount: from_f64(0.5),
+                            }],
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid BybitOrderBookL1 w/ missing asks
+                    input: r#"
+                    {
+                        "topic": "orderbook.1.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304484978,
+                        "data": {
+                            "s": "BTCUSDT",
+                            "b": [["16493.50", "0.006"]]
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitOrderBookL1>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_order_book_l1_into_market_iter() {
+        struct TestCase {
+            input: BybitOrderBookL1Inner,
+            expected: Option<(Level, Level)>,
+        }
+
+        let level = |price, amount| BybitLevel {
+            price: from_f64(price),
+            amount: from_f64(amount),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: both sides present yields OrderBookL1
+                input: BybitOrderBookL1Inner {
+                    bids: vec![level(100.0, 1.0)],
+                    asks: vec![level(101.0, 2.0)],
+                },
+                expected: Some((
+                    Level::new(from_f64(100.0), from_f64(1.0)),
+                    Level::new(from_f64(101.0), from_f64(2.0)),
+                )),
+            },
+            TestCase {
+                // TC1: missing bids yields nothing
+                input: BybitOrderBookL1Inner {
+                    bids: vec![],
+                    asks: vec![level(101.0, 2.0)],
+                },
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1672304484978));
+            let input = BybitMessage::Data(BybitPayload {
+                subscription_id: SubscriptionId::from("orderbook.1|BTCUSDT"),
+                kind: "snapshot".to_string(),
+                time,
+                data: test.input,
+            });
+
+            let actual = MarketIter::<OrderBookL1>::from((
+                ExchangeId::BybitPerpetualsUsd,
+                Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                input,
+            ))
+            .0
+            .into_iter()
+            .map(|event| {
+                let book = event.unwrap().kind;
+                (book.best_bid, book.best_ask)
+            })
+            .next();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
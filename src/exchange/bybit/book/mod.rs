@@ -0,0 +1,51 @@
+use crate::subscription::{book::Level, number::Number};
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// [`Bybit`](super::Bybit) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// ["16493.50", "0.006"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<BybitLevel> for Level {
+    fn from(level: BybitLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_level() {
+            let input = r#"["16493.50", "0.006"]"#;
+            assert_eq!(
+                serde_json::from_str::<BybitLevel>(input).unwrap(),
+                BybitLevel {
+                    price: from_f64(16493.50),
+                    amount: from_f64(0.006)
+                },
+            )
+        }
+    }
+}
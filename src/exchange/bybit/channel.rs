@@ -0,0 +1,43 @@
+use super::Bybit;
+use crate::{
+    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a [`Bybit`](super::Bybit)
+/// channel to be subscribed to.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct BybitChannel(pub &'static str);
+
+impl BybitChannel {
+    /// [`Bybit`](super::Bybit) real-time trades channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+    pub const TRADES: Self = Self("publicTrade");
+
+    /// [`Bybit`](super::Bybit) real-time OrderBook Level1 (top of book) channel name.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+    pub const ORDER_BOOK_L1: Self = Self("orderbook.1");
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, PublicTrades> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::TRADES
+    }
+}
+
+impl<Server> Identifier<BybitChannel> for Subscription<Bybit<Server>, OrderBooksL1> {
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L1
+    }
+}
+
+impl AsRef<str> for BybitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
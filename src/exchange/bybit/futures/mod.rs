@@ -0,0 +1,22 @@
+use super::Bybit;
+use crate::exchange::{ExchangeId, ExchangeServer};
+
+/// [`BybitPerpetualsUsd`] WebSocket server base url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+pub const WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD: &str = "wss://stream.bybit.com/v5/public/linear";
+
+/// [`Bybit`](super::Bybit) linear (USDT margined) perpetual exchange.
+pub type BybitPerpetualsUsd = Bybit<BybitServerPerpetualsUsd>;
+
+/// [`Bybit`](super::Bybit) linear perpetual [`ExchangeServer`](super::super::ExchangeServer).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct BybitServerPerpetualsUsd;
+
+impl ExchangeServer for BybitServerPerpetualsUsd {
+    const ID: ExchangeId = ExchangeId::BybitPerpetualsUsd;
+
+    fn websocket_url() -> &'static str {
+        WEBSOCKET_BASE_URL_BYBIT_PERPETUALS_USD
+    }
+}
@@ -0,0 +1,227 @@
+use super::subscription::BybitResponse;
+use crate::{exchange::ExchangeSub, Identifier};
+use barter_integration::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Bybit`](super::Bybit) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+///
+/// #### Trades
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+/// ```json
+/// {
+///     "topic": "publicTrade.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304486868,
+///     "data": [
+///         {
+///             "T": 1672304486865,
+///             "s": "BTCUSDT",
+///             "S": "Buy",
+///             "v": "0.001",
+///             "p": "16578.50",
+///             "L": "PlusTick",
+///             "i": "20f43950-d8dd-5b31-9112-a178eb6023af",
+///             "BT": false
+///         }
+///     ]
+/// }
+/// ```
+///
+/// #### OrderBookL1
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/orderbook>
+/// ```json
+/// {
+///     "topic": "orderbook.1.BTCUSDT",
+///     "type": "snapshot",
+///     "ts": 1672304484978,
+///     "data": {
+///         "s": "BTCUSDT",
+///         "b": [["16493.50", "0.006"]],
+///         "a": [["16611.00", "0.029"]],
+///         "u": 18521288,
+///         "seq": 7961638724
+///     },
+///     "cts": 1672304484976
+/// }
+/// ```
+///
+/// #### Pong
+/// ```json
+/// {
+///     "success": true,
+///     "ret_msg": "pong",
+///     "conn_id": "0970e817-426e-429a-a679-ff7f55e0b16a",
+///     "op": "ping"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum BybitMessage<T> {
+    Data(BybitPayload<T>),
+    Response(BybitResponse),
+}
+
+/// [`Bybit`](super::Bybit) market data payload containing the generic `T` data.
+///
+/// See [`BybitMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitPayload<T> {
+    #[serde(
+        alias = "topic",
+        deserialize_with = "de_bybit_topic_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(
+        alias = "ts",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for BybitMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            BybitMessage::Data(payload) => Some(payload.subscription_id.clone()),
+            BybitMessage::Response(_) => None,
+        }
+    }
+}
+
+/// Deserialize a [`BybitPayload`] "topic" (eg/ "orderbook.1.BTCUSDT") as the associated
+/// [`SubscriptionId`] (eg/ "orderbook.1|BTCUSDT").
+pub fn de_bybit_topic_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let topic = <&str as Deserialize>::deserialize(deserializer)?;
+
+    topic
+        .rsplit_once('.')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(topic),
+                &"Bybit topic in the format {channel}.{market}",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_bybit_message_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitMessage<serde_json::Value>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input linear pong is BybitMessage::Response
+                    input: r#"
+                    {
+                        "success": true,
+                        "ret_msg": "pong",
+                        "conn_id": "0970e817-426e-429a-a679-ff7f55e0b16a",
+                        "op": "ping"
+                    }
+                    "#,
+                    expected: Ok(BybitMessage::Response(BybitResponse {
+                        success: true,
+                        ret_msg: "pong".to_string(),
+                        op: "ping".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC1: input spot pong is BybitMessage::Response
+                    input: r#"
+                    {
+                        "op": "pong",
+                        "args": ["1675418560633"],
+                        "conn_id": "cfcb4ocsvfriu23r3er0-1b"
+                    }
+                    "#,
+                    expected: Ok(BybitMessage::Response(BybitResponse {
+                        success: false,
+                        ret_msg: "".to_string(),
+                        op: "pong".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input topic without a market is invalid
+                    input: r#"
+                    {
+                        "topic": "publicTrade",
+                        "type": "snapshot",
+                        "ts": 1672304486868,
+                        "data": []
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitMessage<serde_json::Value>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_bybit_topic_as_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades topic
+                input: r#""publicTrade.BTCUSDT""#,
+                expected: SubscriptionId::from("publicTrade|BTCUSDT"),
+            },
+            TestCase {
+                // TC1: order book l1 topic with a "." in the channel
+                input: r#""orderbook.1.ETHUSDT""#,
+                expected: SubscriptionId::from("orderbook.1|ETHUSDT"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut deserializer = serde_json::Deserializer::from_str(test.input);
+            let actual = de_bybit_topic_as_subscription_id(&mut deserializer).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
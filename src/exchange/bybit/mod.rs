@@ -0,0 +1,246 @@
+use self::{
+    book::l1::BybitOrderBookL1, channel::BybitChannel, market::BybitMarket,
+    subscription::BybitResponse, trade::BybitTrades,
+};
+use crate::{
+    decoder::PlainText,
+    exchange::{
+        AckMode, Connector, ExchangeId, ExchangeServer, ExchangeSub, PingInterval, StreamSelector,
+    },
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use serde_json::json;
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
+use url::Url;
+
+/// OrderBook types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// [`ExchangeServer`] and [`StreamSelector`] implementations for
+/// [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod futures;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`BybitMessage<T>`](message::BybitMessage) type common to both
+/// [`BybitSpot`](spot::BybitSpot) and [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod message;
+
+/// [`ExchangeServer`] and [`StreamSelector`] implementations for
+/// [`BybitSpot`](spot::BybitSpot).
+pub mod spot;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) common to both [`BybitSpot`](spot::BybitSpot)
+/// and [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod subscription;
+
+/// Public trade types common to both [`BybitSpot`](spot::BybitSpot) and
+/// [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+pub mod trade;
+
+/// Maximum number of topics [`Bybit`] accepts in a single subscription request.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-subscribe-to-topics>
+pub const MAX_TOPICS_PER_REQUEST_BYBIT: usize = 10;
+
+/// [`Bybit`] recommended interval between application-level pings to keep the connection alive.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-send-the-heartbeat-packet>
+pub const PING_INTERVAL_BYBIT: Duration = Duration::from_secs(20);
+
+/// Generic [`Bybit<Server>`](Bybit) exchange.
+///
+/// ### Notes
+/// A `Server` [`ExchangeServer`](super::ExchangeServer) implementations exists for
+/// [`BybitSpot`](spot::BybitSpot) and [`BybitPerpetualsUsd`](futures::BybitPerpetualsUsd).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct Bybit<Server> {
+    server: PhantomData<Server>,
+}
+
+impl<Server> Connector for Bybit<Server>
+where
+    Server: ExchangeServer,
+{
+    const ID: ExchangeId = Server::ID;
+    const MAX_SUBS_PER_REQUEST: usize = MAX_TOPICS_PER_REQUEST_BYBIT;
+    const ACK_MODE: AckMode = AckMode::PerRequest;
+    type Channel = BybitChannel;
+    type Market = BybitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = BybitResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_BYBIT),
+            ping: || WsMessage::Text(json!({ "op": "ping" }).to_string()),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        bybit_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        bybit_requests("unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`Bybit`] [`WsMessage`] payloads that action the provided `op` (eg/ "subscribe",
+/// "unsubscribe") for a collection of [`ExchangeSub`]s.
+fn bybit_requests(
+    op: &str,
+    exchange_subs: Vec<ExchangeSub<BybitChannel, BybitMarket>>,
+) -> Vec<WsMessage> {
+    let topics = exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| format!("{}.{}", channel.as_ref(), market.as_ref()))
+        .collect::<Vec<String>>();
+
+    topics
+        .chunks(MAX_TOPICS_PER_REQUEST_BYBIT)
+        .map(|topics| {
+            WsMessage::Text(
+                json!({
+                    "op": op,
+                    "args": topics,
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+impl<Server> StreamSelector<PublicTrades> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, BybitTrades>>;
+}
+
+impl<Server> StreamSelector<OrderBooksL1> for Bybit<Server>
+where
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, BybitOrderBookL1>>;
+}
+
+impl<'de, Server> serde::Deserialize<'de> for Bybit<Server>
+where
+    Server: ExchangeServer,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let input = <String as serde::Deserialize>::deserialize(deserializer)?;
+        let expected = Self::ID.as_str();
+
+        if input.as_str() == Self::ID.as_str() {
+            Ok(Self::default())
+        } else {
+            Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(input.as_str()),
+                &expected,
+            ))
+        }
+    }
+}
+
+impl<Server> serde::Serialize for Bybit<Server>
+where
+    Server: ExchangeServer,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let exchange_id = Self::ID.as_str();
+        serializer.serialize_str(exchange_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::bybit::spot::BybitSpot;
+
+    #[test]
+    fn test_bybit_requests() {
+        struct TestCase {
+            num_subs: usize,
+            expected_requests: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: single topic yields single request
+                num_subs: 1,
+                expected_requests: 1,
+            },
+            TestCase {
+                // TC1: max topics yields single request
+                num_subs: MAX_TOPICS_PER_REQUEST_BYBIT,
+                expected_requests: 1,
+            },
+            TestCase {
+                // TC2: max topics + 1 yields two requests
+                num_subs: MAX_TOPICS_PER_REQUEST_BYBIT + 1,
+                expected_requests: 2,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let exchange_subs = (0..test.num_subs)
+                .map(|i| {
+                    ExchangeSub::from((BybitChannel::TRADES, BybitMarket(format!("BTC{i}USDT"))))
+                })
+                .collect();
+
+            let actual = BybitSpot::requests(exchange_subs);
+            assert_eq!(actual.len(), test.expected_requests, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bybit_requests_payload() {
+        let actual = BybitSpot::requests(vec![
+            ExchangeSub::from((BybitChannel::TRADES, BybitMarket("BTCUSDT".to_string()))),
+            ExchangeSub::from((
+                BybitChannel::ORDER_BOOK_L1,
+                BybitMarket("ETHUSDT".to_string()),
+            )),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": ["publicTrade.BTCUSDT", "orderbook.1.ETHUSDT"],
+                })
+                .to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Bybit`](super::Bybit) operation response message, used for subscription responses and
+/// pongs.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-subscribe-to-topics>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "success": true,
+///     "ret_msg": "subscribe",
+///     "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+///     "req_id": "10001",
+///     "op": "subscribe"
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "success": false,
+///     "ret_msg": "error:handler not found",
+///     "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+///     "req_id": "",
+///     "op": "subscribe"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct BybitResponse {
+    #[serde(default)]
+    pub success: bool,
+    #[serde(default)]
+    pub ret_msg: String,
+    pub op: String,
+}
+
+impl Validator for BybitResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {}",
+                self.ret_msg
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_bybit_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input spot response is Subscribed
+                    input: r#"
+                    {
+                        "success": true,
+                        "ret_msg": "subscribe",
+                        "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+                        "req_id": "10001",
+                        "op": "subscribe"
+                    }
+                    "#,
+                    expected: Ok(BybitResponse {
+                        success: true,
+                        ret_msg: "subscribe".to_string(),
+                        op: "subscribe".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: input linear response is Subscribed
+                    input: r#"
+                    {
+                        "success": true,
+                        "ret_msg": "",
+                        "conn_id": "3cd84cb1-4d06-4a05-930a-2efe5fc70f0f",
+                        "req_id": "",
+                        "op": "subscribe"
+                    }
+                    "#,
+                    expected: Ok(BybitResponse {
+                        success: true,
+                        ret_msg: "".to_string(),
+                        op: "subscribe".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: input response is failed subscription
+                    input: r#"
+                    {
+                        "success": false,
+                        "ret_msg": "error:handler not found",
+                        "conn_id": "2324d924-aa4d-45b0-a858-7b8be29ab52b",
+                        "req_id": "",
+                        "op": "subscribe"
+                    }
+                    "#,
+                    expected: Ok(BybitResponse {
+                        success: false,
+                        ret_msg: "error:handler not found".to_string(),
+                        op: "subscribe".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: input response is malformed
+                    input: r#"{"success": true}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_bybit_sub_response() {
+        struct TestCase {
+            input_response: BybitResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: BybitResponse {
+                    success: true,
+                    ret_msg: "subscribe".to_string(),
+                    op: "subscribe".to_string(),
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: BybitResponse {
+                    success: false,
+                    ret_msg: "error:handler not found".to_string(),
+                    op: "subscribe".to_string(),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,282 @@
+use super::message::BybitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{number::Number, trade::PublicTrade},
+};
+use barter_integration::model::{Exchange, Instrument, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Bybit`](super::Bybit) real-time trades WebSocket message.
+pub type BybitTrades = BybitMessage<Vec<BybitTrade>>;
+
+/// [`Bybit`](super::Bybit) real-time trade.
+///
+/// See [`BybitMessage`] for full raw payload examples.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/public/trade>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BybitTrade {
+    #[serde(alias = "i")]
+    pub id: String,
+    #[serde(
+        alias = "T",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+    #[serde(
+        alias = "S",
+        deserialize_with = "crate::subscription::trade::de_side_from_taker_side"
+    )]
+    pub side: Side,
+}
+
+impl From<(ExchangeId, Instrument, BybitTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, BybitTrades)) -> Self {
+        match trades {
+            BybitMessage::Data(payload) => payload
+                .data
+                .into_iter()
+                .map(|trade| {
+                    Ok(MarketEvent {
+                        exchange_time: trade.time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        sequence: None,
+                        tag: None,
+                        kind: PublicTrade {
+                            id: trade.id,
+                            price: trade.price,
+                            amount: trade.amount,
+                            side: trade.side,
+                            buyer_order_id: None,
+                            seller_order_id: None,
+                            taker_order_kind: None,
+                        },
+                    })
+                })
+                .collect(),
+            BybitMessage::Response(_) => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::exchange::bybit::message::BybitPayload;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_bybit_message_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BybitTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid BybitPerpetualsUsd BybitTrades w/ single trade
+                    input: r#"
+                    {
+                        "topic": "publicTrade.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304486868,
+                        "data": [
+                            {
+                                "T": 1672304486865,
+                                "s": "BTCUSDT",
+                                "S": "Buy",
+                                "v": "0.001",
+                                "p": "16578.50",
+                                "L": "PlusTick",
+                                "i": "20f43950-d8dd-5b31-9112-a178eb6023af",
+                                "BT": false
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BybitMessage::Data(BybitPayload {
+                        subscription_id: SubscriptionId::from("publicTrade|BTCUSDT"),
+                        kind: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1672304486868,
+                        )),
+                        data: vec![BybitTrade {
+                            id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1672304486865,
+                            )),
+                            price: from_f64(16578.50),
+                            amount: from_f64(0.001),
+                            side: Side::Buy,
+                        }],
+                    })),
+                },
+                TestCase {
+                    // TC1: valid BybitSpot BybitTrades w/ multiple trades
+                    input: r#"
+                    {
+                        "topic": "publicTrade.ETHUSDT",
+                        "ts": 1687165000000,
+                        "type": "snapshot",
+                        "data": [
+                            {
+                                "i": "2290000000061666327",
+                                "T": 1687164999968,
+                                "p": "1727.06",
+                                "v": "0.35",
+                                "S": "Sell",
+                                "s": "ETHUSDT",
+                                "BT": false
+                            },
+                            {
+                                "i": "2290000000061666328",
+                                "T": 1687164999970,
+                                "p": "1727.07",
+                                "v": "1.2",
+                                "S": "Buy",
+                                "s": "ETHUSDT",
+                                "BT": false
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BybitMessage::Data(BybitPayload {
+                        subscription_id: SubscriptionId::from("publicTrade|ETHUSDT"),
+                        kind: "snapshot".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1687165000000,
+                        )),
+                        data: vec![
+                            BybitTrade {
+                                id: "2290000000061666327".to_string(),
+                                time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                    1687164999968,
+                                )),
+                                price: from_f64(1727.06),
+                                amount: from_f64(0.35),
+                                side: Side::Sell,
+                            },
+                            BybitTrade {
+                                id: "2290000000061666328".to_string(),
+                                time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                    1687164999970,
+                                )),
+                                price: from_f64(1727.07),
+                                amount: from_f64(1.2),
+                                side: Side::Buy,
+                            },
+                        ],
+                    })),
+                },
+                TestCase {
+                    // TC2: invalid BybitTrades w/ non-numeric price
+                    input: r#"
+                    {
+                        "topic": "publicTrade.BTCUSDT",
+                        "type": "snapshot",
+                        "ts": 1672304486868,
+                        "data": [
+                            {
+                                "T": 1672304486865,
+                                "s": "BTCUSDT",
+                                "S": "Buy",
+                                "v": "0.001",
+                                "p": "invalid",
+                                "i": "20f43950-d8dd-5b31-9112-a178eb6023af"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BybitTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bybit_trades_into_market_iter() {
+        let input = serde_json::from_str::<BybitTrades>(
+            r#"
+            {
+                "topic": "publicTrade.ETHUSDT",
+                "ts": 1687165000000,
+                "type": "snapshot",
+                "data": [
+                    {"i": "1", "T": 1687164999968, "p": "1727.06", "v": "0.35", "S": "Sell"},
+                    {"i": "2", "T": 1687164999970, "p": "1727.07", "v": "1.2", "S": "Buy"}
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        let instrument = Instrument::from((
+            "eth",
+            "usdt",
+            barter_integration::model::InstrumentKind::Spot,
+        ));
+        let actual = MarketIter::<PublicTrade>::from((ExchangeId::BybitSpot, instrument, input))
+            .0
+            .into_iter()
+            .map(|event| event.unwrap().kind)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                PublicTrade {
+                    id: "1".to_string(),
+                    price: from_f64(1727.06),
+                    amount: from_f64(0.35),
+                    side: Side::Sell,
+                    buyer_order_id: None,
+                    seller_order_id: None,
+                    taker_order_kind: None,
+                },
+                PublicTrade {
+                    id: "2".to_string(),
+                    price: from_f64(1727.07),
+                    amount: from_f64(1.2),
+                    side: Side::Buy,
+                    buyer_order_id: None,
+                    seller_order_id: None,
+                    taker_order_kind: None,
+                },
+            ]
+        );
+    }
+}
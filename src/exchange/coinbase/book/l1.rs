@@ -0,0 +1,360 @@
+use crate::{
+    event::{MarketEvent, MarketIter, TimeSource},
+    exchange::{
+        coinbase::{channel::CoinbaseChannel, heartbeat::CoinbaseHeartbeat},
+        ExchangeId, ExchangeSub,
+    },
+    subscription::{
+        book::{Level, OrderBookL1},
+        number::Number,
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level1 (top of book) WebSocket message, which
+/// is either a ticker, or a heartbeat from the "heartbeat" channel that is subscribed to
+/// alongside it.
+///
+/// See [`CoinbaseTicker`] & [`CoinbaseHeartbeat`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL1 {
+    Ticker(CoinbaseTicker),
+    Heartbeat(CoinbaseHeartbeat),
+}
+
+/// [`Coinbase`](super::super::Coinbase) real-time ticker, sent for every trade & containing the
+/// best bid & ask.
+///
+/// ### Notes
+/// Ticker messages of some older products omit the best bid & ask sizes, in which case they
+/// default to 0.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+/// ```json
+/// {
+///     "type": "ticker",
+///     "sequence": 37475248783,
+///     "product_id": "ETH-USD",
+///     "price": "1285.22",
+///     "open_24h": "1310.79",
+///     "volume_24h": "245532.79269678",
+///     "low_24h": "1280.52",
+///     "high_24h": "1313.8",
+///     "volume_30d": "9788783.60117027",
+///     "best_bid": "1285.04",
+///     "best_bid_size": "0.46688654",
+///     "best_ask": "1285.27",
+///     "best_ask_size": "1.56637040",
+///     "side": "buy",
+///     "time": "2022-10-19T23:28:22.061769Z",
+///     "trade_id": 370843401,
+///     "last_size": "11.4396987"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseTicker {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l1_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "best_bid",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_price: Number,
+    #[serde(
+        alias = "best_bid_size",
+        default,
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_amount: Number,
+    #[serde(
+        alias = "best_ask",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_price: Number,
+    #[serde(
+        alias = "best_ask_size",
+        default,
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_amount: Number,
+    #[serde(default)]
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            CoinbaseOrderBookL1::Ticker(ticker) => Some(ticker.subscription_id.clone()),
+            CoinbaseOrderBookL1::Heartbeat(_) => None,
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, CoinbaseOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, Instrument, CoinbaseOrderBookL1),
+    ) -> Self {
+        match book {
+            CoinbaseOrderBookL1::Ticker(ticker) => {
+                let received_time = Utc::now();
+
+                // Tickers of some products omit the time, so fall back to the received time
+                let (time, time_source) = match ticker.time {
+                    Some(time) => (time, TimeSource::Exchange),
+                    None => (received_time, TimeSource::Receiver),
+                };
+
+                Self(vec![Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    sequence: None,
+                    tag: None,
+                    kind: OrderBookL1 {
+                        last_update_time: time,
+                        best_bid: Level::new(ticker.best_bid_price, ticker.best_bid_amount),
+                        best_ask: Level::new(ticker.best_ask_price, ticker.best_ask_amount),
+                        time_source,
+                    },
+                })])
+            }
+            CoinbaseOrderBookL1::Heartbeat(_) => Self(vec![]),
+        }
+    }
+}
+
+/// Deserialize a [`CoinbaseTicker`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("ticker|BTC-USD")).
+pub fn de_ob_l1_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L1, product_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::coinbase::market::CoinbaseMarket, subscription::number::ZERO};
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseOrderBookL1, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid ticker
+                    input: r#"
+                    {
+                        "type": "ticker",
+                        "sequence": 37475248783,
+                        "product_id": "ETH-USD",
+                        "price": "1285.22",
+                        "open_24h": "1310.79",
+                        "volume_24h": "245532.79269678",
+                        "low_24h": "1280.52",
+                        "high_24h": "1313.8",
+                        "volume_30d": "9788783.60117027",
+                        "best_bid": "1285.04",
+                        "best_bid_size": "0.46688654",
+                        "best_ask": "1285.27",
+                        "best_ask_size": "1.56637040",
+                        "side": "buy",
+                        "time": "2022-10-19T23:28:22.061769Z",
+                        "trade_id": 370843401,
+                        "last_size": "11.4396987"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL1::Ticker(CoinbaseTicker {
+                        subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+                        best_bid_price: "1285.04".parse().unwrap(),
+                        best_bid_amount: "0.46688654".parse().unwrap(),
+                        best_ask_price: "1285.27".parse().unwrap(),
+                        best_ask_amount: "1.56637040".parse().unwrap(),
+                        time: Some("2022-10-19T23:28:22.061769Z".parse().unwrap()),
+                    })),
+                },
+                TestCase {
+                    // TC1: valid ticker of an older product without sizes
+                    input: r#"
+                    {
+                        "type": "ticker",
+                        "sequence": 5928281084,
+                        "product_id": "BTC-USD",
+                        "price": "10101.11",
+                        "best_bid": "10101.10",
+                        "best_ask": "10101.11",
+                        "time": "2019-08-14T20:42:27.265Z"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL1::Ticker(CoinbaseTicker {
+                        subscription_id: SubscriptionId::from("ticker|BTC-USD"),
+                        best_bid_price: "10101.10".parse().unwrap(),
+                        best_bid_amount: ZERO,
+                        best_ask_price: "10101.11".parse().unwrap(),
+                        best_ask_amount: ZERO,
+                        time: Some("2019-08-14T20:42:27.265Z".parse().unwrap()),
+                    })),
+                },
+                TestCase {
+                    // TC2: valid heartbeat
+                    input: r#"
+                    {
+                        "type": "heartbeat",
+                        "sequence": 90,
+                        "last_trade_id": 20,
+                        "product_id": "BTC-USD",
+                        "time": "2014-11-07T08:19:28.464459Z"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL1::Heartbeat(CoinbaseHeartbeat {
+                        product_id: CoinbaseMarket("BTC-USD".to_string()),
+                        sequence: 90,
+                        last_trade_id: 20,
+                        time: "2014-11-07T08:19:28.464459Z".parse().unwrap(),
+                    })),
+                },
+                TestCase {
+                    // TC3: invalid ticker w/ non-numeric best bid
+                    input: r#"
+                    {
+                        "type": "ticker",
+                        "product_id": "BTC-USD",
+                        "best_bid": "gibberish",
+                        "best_ask": "10101.11"
+                    }
+                    "#,
+                    expected: Err(serde::de::Error::custom("")),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL1>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_coinbase_order_book_l1_into_market_iter() {
+        let instrument = Instrument::from((
+            "eth",
+            "usd",
+            barter_integration::model::InstrumentKind::Spot,
+        ));
+        let time = "2022-10-19T23:28:22.061769Z".parse().unwrap();
+
+        let ticker = CoinbaseOrderBookL1::Ticker(CoinbaseTicker {
+            subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+            best_bid_price: "1285.04".parse().unwrap(),
+            best_bid_amount: "0.46688654".parse().unwrap(),
+            best_ask_price: "1285.27".parse().unwrap(),
+            best_ask_amount: ZERO,
+            time: Some(time),
+        });
+
+        let actual = MarketIter::<OrderBookL1>::from((ExchangeId::Coinbase, instrument, ticker))
+            .0
+            .remove(0)
+            .unwrap();
+
+        assert_eq!(actual.exchange_time, time);
+        assert_eq!(actual.sequence, None);
+        assert_eq!(actual.kind.last_update_time, time);
+        assert_eq!(
+            actual.kind.best_bid,
+            Level::new(
+                "1285.04".parse::<Number>().unwrap(),
+                "0.46688654".parse().unwrap()
+            )
+        );
+        assert_eq!(
+            actual.kind.best_ask,
+            Level::new("1285.27".parse::<Number>().unwrap(), ZERO)
+        );
+    }
+
+    #[test]
+    fn test_coinbase_order_book_l1_time_source() {
+        struct TestCase {
+            time: Option<DateTime<Utc>>,
+            expected: TimeSource,
+        }
+
+        let time = "2022-10-19T23:28:22.061769Z".parse().unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: ticker w/ time is timed by the exchange
+                time: Some(time),
+                expected: TimeSource::Exchange,
+            },
+            TestCase {
+                // TC1: ticker w/o time falls back to the received time
+                time: None,
+                expected: TimeSource::Receiver,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let ticker = CoinbaseOrderBookL1::Ticker(CoinbaseTicker {
+                subscription_id: SubscriptionId::from("ticker|ETH-USD"),
+                best_bid_price: "1285.04".parse().unwrap(),
+                best_bid_amount: "0.46688654".parse().unwrap(),
+                best_ask_price: "1285.27".parse().unwrap(),
+                best_ask_amount: ZERO,
+                time: test.time,
+            });
+            let instrument = Instrument::from((
+                "eth",
+                "usd",
+                barter_integration::model::InstrumentKind::Spot,
+            ));
+
+            let actual =
+                MarketIter::<OrderBookL1>::from((ExchangeId::Coinbase, instrument, ticker))
+                    .0
+                    .remove(0)
+                    .unwrap();
+
+            assert_eq!(actual.kind.time_source, test.expected, "TC{} failed", index);
+            assert_eq!(
+                actual.kind.last_update_time, actual.exchange_time,
+                "TC{} failed",
+                index
+            );
+            match test.time {
+                Some(time) => assert_eq!(actual.exchange_time, time, "TC{} failed", index),
+                None => assert_eq!(
+                    actual.exchange_time, actual.received_time,
+                    "TC{} failed",
+                    index
+                ),
+            }
+        }
+    }
+}
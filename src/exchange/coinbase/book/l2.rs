@@ -0,0 +1,439 @@
+use super::{CoinbaseLevel, CoinbaseLevelChange};
+use crate::{
+    error::DataError,
+    exchange::{
+        coinbase::{channel::CoinbaseChannel, heartbeat::CoinbaseHeartbeat},
+        ExchangeSub,
+    },
+    subscription::book::{Level, OrderBook, OrderBookSide},
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level2 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///     "type": "snapshot",
+///     "product_id": "BTC-USD",
+///     "bids": [["10101.10", "0.45054140"]],
+///     "asks": [["10102.55", "0.57753524"]]
+/// }
+/// ```
+///
+/// #### Update
+/// ```json
+/// {
+///     "type": "l2update",
+///     "product_id": "BTC-USD",
+///     "changes": [["buy", "10101.80000000", "0.162567"]],
+///     "time": "2019-08-14T20:42:27.265Z"
+/// }
+/// ```
+///
+/// #### Heartbeat
+/// See [`CoinbaseHeartbeat`], which is skipped since it only communicates missed trades.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CoinbaseOrderBookL2 {
+    Snapshot(CoinbaseOrderBookL2Snapshot),
+    #[serde(rename = "l2update")]
+    Update(CoinbaseOrderBookL2Update),
+    Heartbeat(CoinbaseHeartbeat),
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level2 snapshot, sent once per product after
+/// subscribing.
+///
+/// See [`CoinbaseOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL2Snapshot {
+    #[serde(
+        alias = "product_id",
+        deserialize_with = "de_order_book_l2_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub bids: Vec<CoinbaseLevel>,
+    pub asks: Vec<CoinbaseLevel>,
+    #[serde(default)]
+    pub time: Option<DateTime<Utc>>,
+}
+
+/// [`Coinbase`](super::super::Coinbase) OrderBook Level2 update containing batched level changes.
+///
+/// See [`CoinbaseOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL2Update {
+    #[serde(
+        alias = "product_id",
+        deserialize_with = "de_order_book_l2_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub changes: Vec<CoinbaseLevelChange>,
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            CoinbaseOrderBookL2::Snapshot(snapshot) => Some(snapshot.subscription_id.clone()),
+            CoinbaseOrderBookL2::Update(update) => Some(update.subscription_id.clone()),
+            CoinbaseOrderBookL2::Heartbeat(_) => None,
+        }
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL2`] "product_id" (eg/ "BTC-USD") as the associated
+/// [`SubscriptionId`] (eg/ SubscriptionId("level2_batch|BTC-USD")).
+pub fn de_order_book_l2_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L2, product_id)).id())
+}
+
+/// [`Coinbase`](super::super::Coinbase) [`OrderBookUpdater`].
+///
+/// Coinbase: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "level2_batch" channel, which first sends a full snapshot per product.
+/// 2. Apply each subsequent "l2update" change to the local OrderBook:
+///    - If the size is 0, remove the price level.
+///    - If the price level exists, replace the size.
+///    - If the price level does not exist, insert it.
+///
+/// ## Notes:
+/// - Coinbase multiplexes every product over the same channel, so each product is routed to its
+///   own OrderBook using the "product_id".
+/// - Updates received before the snapshot are skipped.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseBookUpdater {
+    pub awaiting_snapshot: bool,
+}
+
+impl Default for CoinbaseBookUpdater {
+    fn default() -> Self {
+        Self {
+            awaiting_snapshot: true,
+        }
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for CoinbaseBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = CoinbaseOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Coinbase sends the initial OrderBook snapshot over the WebSocket after subscribing
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::default(),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                first_update_id: None,
+                last_update_id: None,
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        match update {
+            CoinbaseOrderBookL2::Snapshot(snapshot) => {
+                book.last_update_time = snapshot.time.unwrap_or_else(Utc::now);
+                book.bids = OrderBookSide::new(Side::Buy, snapshot.bids);
+                book.asks = OrderBookSide::new(Side::Sell, snapshot.asks);
+                self.awaiting_snapshot = false;
+            }
+            CoinbaseOrderBookL2::Heartbeat(_) => {
+                // Heartbeats are not routed to an OrderBook, since they have no SubscriptionId
+                return Ok(None);
+            }
+            CoinbaseOrderBookL2::Update(_) if self.awaiting_snapshot => {
+                // Skip updates until the snapshot is received
+                return Ok(None);
+            }
+            CoinbaseOrderBookL2::Update(update) => {
+                book.last_update_time = update.time;
+                for change in update.changes {
+                    match change.side {
+                        Side::Buy => book.bids.upsert_single(change),
+                        Side::Sell => book.asks.upsert_single(change),
+                    }
+                }
+            }
+        }
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use crate::{
+        exchange::coinbase::{market::CoinbaseMarket, Coinbase},
+        subscription::{book::OrderBooksL2, Map},
+        transformer::{book::MultiBookTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{model::InstrumentKind, Transformer};
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseOrderBookL2, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "type": "snapshot",
+                        "product_id": "BTC-USD",
+                        "bids": [["10101.10", "0.45054140"]],
+                        "asks": [["10102.55", "0.57753524"]]
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::Snapshot(CoinbaseOrderBookL2Snapshot {
+                        subscription_id: SubscriptionId::from("level2_batch|BTC-USD"),
+                        bids: vec![CoinbaseLevel {
+                            price: from_f64(10101.10),
+                            amount: from_f64(0.45054140),
+                        }],
+                        asks: vec![CoinbaseLevel {
+                            price: from_f64(10102.55),
+                            amount: from_f64(0.57753524),
+                        }],
+                        time: None,
+                    })),
+                },
+                TestCase {
+                    // TC1: valid update for a second product
+                    input: r#"
+                    {
+                        "type": "l2update",
+                        "product_id": "ETH-USD",
+                        "changes": [["buy", "1800.10", "0.162567"], ["sell", "1800.50", "0"]],
+                        "time": "2019-08-14T20:42:27.265Z"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::Update(CoinbaseOrderBookL2Update {
+                        subscription_id: SubscriptionId::from("level2_batch|ETH-USD"),
+                        changes: vec![
+                            CoinbaseLevelChange {
+                                side: Side::Buy,
+                                price: from_f64(1800.10),
+                                amount: from_f64(0.162567),
+                            },
+                            CoinbaseLevelChange {
+                                side: Side::Sell,
+                                price: from_f64(1800.50),
+                                amount: from_f64(0.0),
+                            },
+                        ],
+                        time: "2019-08-14T20:42:27.265Z".parse().unwrap(),
+                    })),
+                },
+                TestCase {
+                    // TC2: unknown message type
+                    input: r#"{"type": "ticker", "product_id": "BTC-USD"}"#,
+                    expected: Err(serde::de::Error::custom("")),
+                },
+                TestCase {
+                    // TC3: valid heartbeat
+                    input: r#"
+                    {
+                        "type": "heartbeat",
+                        "sequence": 90,
+                        "last_trade_id": 20,
+                        "product_id": "BTC-USD",
+                        "time": "2014-11-07T08:19:28.464459Z"
+                    }
+                    "#,
+                    expected: Ok(CoinbaseOrderBookL2::Heartbeat(CoinbaseHeartbeat {
+                        product_id: CoinbaseMarket("BTC-USD".to_string()),
+                        sequence: 90,
+                        last_trade_id: 20,
+                        time: "2014-11-07T08:19:28.464459Z".parse().unwrap(),
+                    })),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coinbase_multi_book_transformer_routes_products() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<(Instrument, Vec<Level>, Vec<Level>)>,
+        }
+
+        let btc_usd = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+        let eth_usd = Instrument::from(("eth", "usd", InstrumentKind::Spot));
+
+        let map = Map::from_iter([
+            (
+                SubscriptionId::from("level2_batch|BTC-USD"),
+                btc_usd.clone(),
+            ),
+            (
+                SubscriptionId::from("level2_batch|ETH-USD"),
+                eth_usd.clone(),
+            ),
+        ]);
+
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer =
+            MultiBookTransformer::<Coinbase, OrderBooksL2, CoinbaseBookUpdater>::new(
+                ws_sink_tx, map,
+            )
+            .await
+            .unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTC-USD update before snapshot is skipped
+                input: r#"{"type": "l2update", "product_id": "BTC-USD", "changes": [["buy", "100.0", "1.0"]], "time": "2019-08-14T20:42:27.265Z"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC1: BTC-USD snapshot
+                input: r#"{"type": "snapshot", "product_id": "BTC-USD", "bids": [["99.0", "2.0"], ["100.0", "1.0"]], "asks": [["102.0", "3.0"], ["101.0", "1.5"]]}"#,
+                expected: Some((
+                    btc_usd.clone(),
+                    vec![
+                        Level::new(from_f64(100.0), from_f64(1.0)),
+                        Level::new(from_f64(99.0), from_f64(2.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(101.0), from_f64(1.5)),
+                        Level::new(from_f64(102.0), from_f64(3.0)),
+                    ],
+                )),
+            },
+            TestCase {
+                // TC2: ETH-USD snapshot does not affect BTC-USD book
+                input: r#"{"type": "snapshot", "product_id": "ETH-USD", "bids": [["10.0", "5.0"]], "asks": [["11.0", "6.0"]]}"#,
+                expected: Some((
+                    eth_usd.clone(),
+                    vec![Level::new(from_f64(10.0), from_f64(5.0))],
+                    vec![Level::new(from_f64(11.0), from_f64(6.0))],
+                )),
+            },
+            TestCase {
+                // TC3: BTC-USD update removes, replaces & inserts levels
+                input: r#"{"type": "l2update", "product_id": "BTC-USD", "changes": [["buy", "100.0", "0"], ["buy", "99.5", "4.0"], ["sell", "102.0", "0.5"]], "time": "2019-08-14T20:42:28.265Z"}"#,
+                expected: Some((
+                    btc_usd.clone(),
+                    vec![
+                        Level::new(from_f64(99.5), from_f64(4.0)),
+                        Level::new(from_f64(99.0), from_f64(2.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(101.0), from_f64(1.5)),
+                        Level::new(from_f64(102.0), from_f64(0.5)),
+                    ],
+                )),
+            },
+            TestCase {
+                // TC4: ETH-USD update applied to ETH-USD book only
+                input: r#"{"type": "l2update", "product_id": "ETH-USD", "changes": [["sell", "11.0", "0"], ["sell", "10.5", "1.0"]], "time": "2019-08-14T20:42:29.265Z"}"#,
+                expected: Some((
+                    eth_usd,
+                    vec![Level::new(from_f64(10.0), from_f64(5.0))],
+                    vec![Level::new(from_f64(10.5), from_f64(1.0))],
+                )),
+            },
+            TestCase {
+                // TC5: heartbeat is skipped rather than unidentifiable
+                input: r#"{"type": "heartbeat", "sequence": 90, "last_trade_id": 20, "product_id": "BTC-USD", "time": "2014-11-07T08:19:28.464459Z"}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let update = serde_json::from_str::<CoinbaseOrderBookL2>(test.input).unwrap();
+            let actual = transformer.transform(update);
+
+            match test.expected {
+                None => assert!(actual.is_empty(), "TC{} failed", index),
+                Some((instrument, bids, asks)) => {
+                    assert_eq!(actual.len(), 1, "TC{} failed", index);
+                    let event = actual.into_iter().next().unwrap().unwrap();
+                    assert_eq!(event.instrument, instrument, "TC{} failed", index);
+                    assert_eq!(
+                        event.kind.bids,
+                        OrderBookSide::new(Side::Buy, bids),
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(
+                        event.kind.asks,
+                        OrderBookSide::new(Side::Sell, asks),
+                        "TC{} failed",
+                        index
+                    );
+                }
+            }
+        }
+
+        // Update for a product that was never subscribed to is unidentifiable
+        let unknown = serde_json::from_str::<CoinbaseOrderBookL2>(
+            r#"{"type": "snapshot", "product_id": "SOL-USD", "bids": [], "asks": []}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            transformer.transform(unknown).as_slice(),
+            [Err(DataError::Socket(_))]
+        ));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::subscription::{book::Level, number::Number};
+use barter_integration::model::Side;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types.
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Coinbase`](super::Coinbase) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// ```json
+/// ["10101.10", "0.45054140"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevel {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<CoinbaseLevel> for Level {
+    fn from(level: CoinbaseLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+/// [`Coinbase`](super::Coinbase) OrderBook level change, where an amount of 0 means the level
+/// should be removed.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+/// ```json
+/// ["buy", "10101.80000000", "0.162567"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CoinbaseLevelChange {
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+}
+
+impl From<CoinbaseLevelChange> for Level {
+    fn from(change: CoinbaseLevelChange) -> Self {
+        Self {
+            price: change.price,
+            amount: change.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_coinbase_level() {
+            let input = r#"["10101.10", "0.45054140"]"#;
+            assert_eq!(
+                serde_json::from_str::<CoinbaseLevel>(input).unwrap(),
+                CoinbaseLevel {
+                    price: from_f64(10101.10),
+                    amount: from_f64(0.45054140)
+                },
+            )
+        }
+
+        #[test]
+        fn test_coinbase_level_change() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<CoinbaseLevelChange, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid buy change
+                    input: r#"["buy", "10101.80000000", "0.162567"]"#,
+                    expected: Ok(CoinbaseLevelChange {
+                        side: Side::Buy,
+                        price: from_f64(10101.8),
+                        amount: from_f64(0.162567),
+                    }),
+                },
+                TestCase {
+                    // TC1: valid sell removal
+                    input: r#"["sell", "10102.55", "0"]"#,
+                    expected: Ok(CoinbaseLevelChange {
+                        side: Side::Sell,
+                        price: from_f64(10102.55),
+                        amount: from_f64(0.0),
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid price
+                    input: r#"["sell", "gibberish", "0"]"#,
+                    expected: Err(serde::de::Error::custom("")),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<CoinbaseLevelChange>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
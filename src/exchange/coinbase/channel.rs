@@ -1,6 +1,10 @@
 use super::Coinbase;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+        Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +21,22 @@ impl CoinbaseChannel {
     ///
     /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#match>
     pub const TRADES: Self = Self("matches");
+
+    /// [`Coinbase`] OrderBook Level1 (top of book) channel, via the real-time ticker.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#ticker-channel>
+    pub const ORDER_BOOK_L1: Self = Self("ticker");
+
+    /// [`Coinbase`] OrderBook Level2 channel (batched 50ms updates).
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#level2-batch-channel>
+    pub const ORDER_BOOK_L2: Self = Self("level2_batch");
+
+    /// [`Coinbase`] heartbeat channel, subscribed to alongside every data channel in order to
+    /// detect missed messages.
+    ///
+    /// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#heartbeat-channel>
+    pub const HEARTBEAT: Self = Self("heartbeat");
 }
 
 impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
@@ -25,6 +45,18 @@ impl Identifier<CoinbaseChannel> for Subscription<Coinbase, PublicTrades> {
     }
 }
 
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL1> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L1
+    }
+}
+
+impl Identifier<CoinbaseChannel> for Subscription<Coinbase, OrderBooksL2> {
+    fn id(&self) -> CoinbaseChannel {
+        CoinbaseChannel::ORDER_BOOK_L2
+    }
+}
+
 impl AsRef<str> for CoinbaseChannel {
     fn as_ref(&self) -> &str {
         self.0
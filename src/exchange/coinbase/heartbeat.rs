@@ -0,0 +1,66 @@
+use super::{channel::CoinbaseChannel, market::CoinbaseMarket};
+use crate::{exchange::ExchangeSub, Identifier};
+use barter_integration::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Coinbase`](super::Coinbase) heartbeat WebSocket message, sent every second for each
+/// product subscribed to the "heartbeat" channel.
+///
+/// The `last_trade_id` is compared against the trade ids delivered by the "matches" channel in
+/// order to detect missed trades, see
+/// [`CoinbaseTradesTransformer`](super::trade::CoinbaseTradesTransformer).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#heartbeat-channel>
+/// ```json
+/// {
+///     "type": "heartbeat",
+///     "sequence": 90,
+///     "last_trade_id": 20,
+///     "product_id": "BTC-USD",
+///     "time": "2014-11-07T08:19:28.464459Z"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CoinbaseHeartbeat {
+    pub product_id: CoinbaseMarket,
+    pub sequence: u64,
+    pub last_trade_id: u64,
+    pub time: DateTime<Utc>,
+}
+
+impl CoinbaseHeartbeat {
+    /// [`SubscriptionId`] of the "matches" channel trades associated with this heartbeat's
+    /// product (eg/ SubscriptionId("matches|BTC-USD")).
+    pub fn trades_subscription_id(&self) -> SubscriptionId {
+        ExchangeSub::from((CoinbaseChannel::TRADES, &self.product_id)).id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_coinbase_heartbeat() {
+        let input = r#"
+        {
+            "type": "heartbeat",
+            "sequence": 90,
+            "last_trade_id": 20,
+            "product_id": "BTC-USD",
+            "time": "2014-11-07T08:19:28.464459Z"
+        }
+        "#;
+
+        let actual = serde_json::from_str::<CoinbaseHeartbeat>(input).unwrap();
+        assert_eq!(actual.product_id, CoinbaseMarket("BTC-USD".to_string()));
+        assert_eq!(actual.sequence, 90);
+        assert_eq!(actual.last_trade_id, 20);
+        assert_eq!(
+            actual.trades_subscription_id(),
+            SubscriptionId::from("matches|BTC-USD")
+        );
+    }
+}
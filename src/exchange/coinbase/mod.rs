@@ -1,19 +1,30 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
-    trade::CoinbaseTrade,
+    book::{l1::CoinbaseOrderBookL1, l2::CoinbaseBookUpdater},
+    channel::CoinbaseChannel,
+    market::CoinbaseMarket,
+    subscription::CoinbaseSubResponse,
+    trade::CoinbaseTradesTransformer,
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    decoder::PlainText,
+    exchange::{AckMode, Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+    transformer::{book::MultiBookTransformer, stateless::StatelessTransformer},
     ExchangeWsStream,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
 use url::Url;
 
+/// OrderBook types for [`Coinbase`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -26,6 +37,9 @@ pub mod market;
 /// [`Validator`](barter_integration::Validator) for [`Coinbase`].
 pub mod subscription;
 
+/// Heartbeat types for [`Coinbase`], used to detect missed trades.
+pub mod heartbeat;
+
 /// Public trade types for [`Coinbase`].
 pub mod trade;
 
@@ -44,33 +58,313 @@ pub struct Coinbase;
 
 impl Connector for Coinbase {
     const ID: ExchangeId = ExchangeId::Coinbase;
+    // Coinbase confirms every channel & product_id of a subscribe request in a single
+    // "subscriptions" message
+    const ACK_MODE: AckMode = AckMode::PerRequest;
     type Channel = CoinbaseChannel;
     type Market = CoinbaseMarket;
     type Subscriber = WebSocketSubscriber;
     type SubValidator = WebSocketSubValidator;
     type SubResponse = CoinbaseSubResponse;
+    type Decoder = PlainText;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(BASE_URL_COINBASE).map_err(SocketError::UrlParse)
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
+        coinbase_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        coinbase_requests("unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`Coinbase`] [`WsMessage`] payload that actions the provided message `type` (eg/
+/// "subscribe", "unsubscribe") for a collection of [`ExchangeSub`]s.
+///
+/// Every product_id of the same channel is coalesced into a single channel entry, so only one
+/// message is sent regardless of the number of [`ExchangeSub`]s, avoiding the Coinbase rate limit
+/// on incoming messages.
+///
+/// Every product_id is also actioned on the [`CoinbaseChannel::HEARTBEAT`] channel, which is used
+/// to detect missed trades (see [`CoinbaseTradesTransformer`]).
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-overview#subscribe>
+fn coinbase_requests(
+    message_type: &str,
+    exchange_subs: Vec<ExchangeSub<CoinbaseChannel, CoinbaseMarket>>,
+) -> Vec<WsMessage> {
+    if exchange_subs.is_empty() {
+        return vec![];
+    }
+
+    let mut channels = exchange_subs.into_iter().fold(
+        BTreeMap::<CoinbaseChannel, Vec<CoinbaseMarket>>::new(),
+        |mut channels, ExchangeSub { channel, market }| {
+            channels.entry(channel).or_default().push(market);
+            channels
+        },
+    );
+
+    let heartbeat_markets = channels
+        .values()
+        .flatten()
+        .cloned()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    channels.insert(CoinbaseChannel::HEARTBEAT, heartbeat_markets);
+
+    let channels = channels
+        .into_iter()
+        .map(|(channel, markets)| {
+            json!({
+                "name": channel.as_ref(),
+                "product_ids": markets.iter().map(AsRef::as_ref).collect::<Vec<&str>>(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    vec![WsMessage::Text(
+        json!({
+            "type": message_type,
+            "channels": channels,
+        })
+        .to_string(),
+    )]
+}
+
+impl StreamSelector<PublicTrades> for Coinbase {
+    type Stream = ExchangeWsStream<CoinbaseTradesTransformer>;
+}
+
+impl StreamSelector<OrderBooksL1> for Coinbase {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, CoinbaseOrderBookL1>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Coinbase {
+    type Stream = ExchangeWsStream<MultiBookTransformer<Self, OrderBooksL2, CoinbaseBookUpdater>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::DataError,
+        exchange::coinbase::trade::{CoinbaseTrade, CoinbaseTradeMessage},
+        subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+        subscription::Subscription,
+        transformer::ExchangeTransformer,
+    };
+    use barter_integration::{
+        model::{Instrument, InstrumentKind},
+        Transformer,
+    };
+
+    fn subscriptions() -> Vec<Subscription<Coinbase, PublicTrades>> {
+        [("btc", "usd"), ("eth", "usd"), ("sol", "usd")]
             .into_iter()
-            .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
-                        "type": "subscribe",
-                        "product_ids": [market.as_ref()],
-                        "channels": [channel.as_ref()],
-                    })
-                    .to_string(),
-                )
+            .map(|(base, quote)| {
+                Subscription::from((Coinbase, base, quote, InstrumentKind::Spot, PublicTrades))
             })
             .collect()
     }
-}
 
-impl StreamSelector<PublicTrades> for Coinbase {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, CoinbaseTrade>>;
+    #[test]
+    fn test_coinbase_requests() {
+        struct TestCase {
+            input: Vec<ExchangeSub<CoinbaseChannel, CoinbaseMarket>>,
+            expected: Vec<&'static str>,
+        }
+
+        let exchange_sub = |channel, market: &str| ExchangeSub {
+            channel,
+            market: CoinbaseMarket(market.to_string()),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: products of the same channel are coalesced into a single payload, and
+                // subscribed to the heartbeat channel
+                input: subscriptions().iter().map(ExchangeSub::new).collect(),
+                expected: vec![
+                    r#"{"channels":[{"name":"heartbeat","product_ids":["BTC-USD","ETH-USD","SOL-USD"]},{"name":"matches","product_ids":["BTC-USD","ETH-USD","SOL-USD"]}],"type":"subscribe"}"#,
+                ],
+            },
+            TestCase {
+                // TC1: products of different channels are grouped per channel entry
+                input: vec![
+                    exchange_sub(CoinbaseChannel::TRADES, "BTC-USD"),
+                    exchange_sub(CoinbaseChannel::ORDER_BOOK_L2, "ETH-USD"),
+                    exchange_sub(CoinbaseChannel::TRADES, "ETH-USD"),
+                ],
+                expected: vec![
+                    r#"{"channels":[{"name":"heartbeat","product_ids":["BTC-USD","ETH-USD"]},{"name":"level2_batch","product_ids":["ETH-USD"]},{"name":"matches","product_ids":["BTC-USD","ETH-USD"]}],"type":"subscribe"}"#,
+                ],
+            },
+            TestCase {
+                // TC2: no requests without any ExchangeSubs
+                input: vec![],
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Coinbase::requests(test.input);
+            let expected = test
+                .expected
+                .into_iter()
+                .map(|payload| WsMessage::Text(payload.to_string()))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coinbase_coalesced_subscriptions_route_to_instruments() {
+        // Single subscribe request & response expected for every (channel, product) pair
+        let meta = WebSocketSubMapper::map(&subscriptions());
+        assert_eq!(meta.subscriptions.len(), 1);
+        assert_eq!(meta.instrument_map.0.len(), 3);
+        assert_eq!(Coinbase::expected_responses(&meta.instrument_map), 1);
+
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer =
+            <StatelessTransformer<Coinbase, PublicTrades, CoinbaseTrade> as ExchangeTransformer<
+                Coinbase,
+                PublicTrades,
+            >>::new(ws_sink_tx, meta.instrument_map)
+            .await
+            .unwrap();
+
+        let trade = |product_id: &str, trade_id: u64| {
+            serde_json::from_str::<CoinbaseTrade>(&format!(
+                r#"{{"type":"match","trade_id":{trade_id},"sequence":50,"maker_order_id":"a","taker_order_id":"b","time":"2014-11-07T08:19:27.028459Z","product_id":"{product_id}","size":"1.0","price":"1.0","side":"sell"}}"#
+            ))
+            .unwrap()
+        };
+
+        struct TestCase {
+            input: CoinbaseTrade,
+            expected: Instrument,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTC-USD trade routes to btc_usd Instrument
+                input: trade("BTC-USD", 1),
+                expected: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
+            },
+            TestCase {
+                // TC1: ETH-USD trade routes to eth_usd Instrument
+                input: trade("ETH-USD", 1),
+                expected: Instrument::from(("eth", "usd", InstrumentKind::Spot)),
+            },
+            TestCase {
+                // TC2: SOL-USD trade routes to sol_usd Instrument
+                input: trade("SOL-USD", 1),
+                expected: Instrument::from(("sol", "usd", InstrumentKind::Spot)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = transformer
+                .transform(test.input)
+                .into_iter()
+                .collect::<Vec<_>>();
+            assert_eq!(actual.len(), 1, "TC{} failed", index);
+            match &actual[0] {
+                Ok(event) => assert_eq!(event.instrument, test.expected, "TC{} failed", index),
+                Err(error) => panic!("TC{index} failed: {error:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coinbase_heartbeat_detects_missed_trades() {
+        let meta = WebSocketSubMapper::map(&subscriptions());
+        let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+        let mut transformer = <CoinbaseTradesTransformer as ExchangeTransformer<
+            Coinbase,
+            PublicTrades,
+        >>::new(ws_sink_tx, meta.instrument_map)
+        .await
+        .unwrap();
+
+        let trade = |product_id: &str, trade_id: u64| {
+            format!(
+                r#"{{"type":"match","trade_id":{trade_id},"sequence":50,"maker_order_id":"a","taker_order_id":"b","time":"2014-11-07T08:19:27.028459Z","product_id":"{product_id}","size":"1.0","price":"1.0","side":"sell"}}"#
+            )
+        };
+        let heartbeat = |product_id: &str, last_trade_id: u64| {
+            format!(
+                r#"{{"type":"heartbeat","sequence":90,"last_trade_id":{last_trade_id},"product_id":"{product_id}","time":"2014-11-07T08:19:28.464459Z"}}"#
+            )
+        };
+
+        struct TestCase {
+            input: String,
+            expected: Result<usize, (u64, u64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTC-USD trade is delivered
+                input: trade("BTC-USD", 10),
+                expected: Ok(1),
+            },
+            TestCase {
+                // TC1: heartbeat reporting the last delivered trade is skipped
+                input: heartbeat("BTC-USD", 10),
+                expected: Ok(0),
+            },
+            TestCase {
+                // TC2: heartbeat reporting trades the transformer never saw yields a SequenceGap
+                input: heartbeat("BTC-USD", 12),
+                expected: Err((11, 13)),
+            },
+            TestCase {
+                // TC3: missed trades are only reported once
+                input: heartbeat("BTC-USD", 12),
+                expected: Ok(0),
+            },
+            TestCase {
+                // TC4: trade following the missed trades is delivered without a SequenceGap
+                input: trade("BTC-USD", 13),
+                expected: Ok(1),
+            },
+            TestCase {
+                // TC5: heartbeat of a product without any delivered trades is skipped
+                input: heartbeat("ETH-USD", 500),
+                expected: Ok(0),
+            },
+            TestCase {
+                // TC6: last_match message sent upon subscribing is delivered
+                input: trade("ETH-USD", 501).replace(r#""match""#, r#""last_match""#),
+                expected: Ok(1),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<CoinbaseTradeMessage>(&test.input).unwrap();
+            let actual = transformer.transform(input);
+
+            match (actual.as_slice(), test.expected) {
+                (events, Ok(num_events)) => {
+                    assert_eq!(events.len(), num_events, "TC{} failed", index);
+                    assert!(events.iter().all(Result::is_ok), "TC{} failed", index);
+                }
+                ([Err(DataError::SequenceGap { expected, actual })], Err(gap)) => {
+                    assert_eq!((*expected, *actual), gap, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
 }
@@ -1,13 +1,39 @@
-use super::CoinbaseChannel;
+use super::{heartbeat::CoinbaseHeartbeat, Coinbase, CoinbaseChannel};
 use crate::{
+    error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    streams::metrics::MetricsHook,
+    subscription::{
+        number::Number,
+        trade::{PublicTrade, PublicTrades},
+        Map,
+    },
+    transformer::{stateless::StatelessTransformer, ExchangeTransformer},
     Identifier,
 };
-use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+
+/// [`Coinbase`] WebSocket message received by a [`PublicTrades`] stream, which is either a trade
+/// from the "matches" channel, or a heartbeat from the "heartbeat" channel that is subscribed to
+/// alongside it.
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseTradeMessage {
+    #[serde(rename = "match", alias = "last_match")]
+    Trade(CoinbaseTrade),
+    Heartbeat(CoinbaseHeartbeat),
+}
 
 /// Coinbase real-time trade WebSocket message.
 ///
@@ -36,10 +62,14 @@ pub struct CoinbaseTrade {
     pub id: u64,
     pub time: DateTime<Utc>,
     #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
+    /// Taker [`Side`] of the trade, derived from the Coinbase maker order "side".
+    #[serde(deserialize_with = "crate::subscription::trade::de_side_from_maker_side")]
     pub side: Side,
+    pub maker_order_id: String,
+    pub taker_order_id: String,
 }
 
 impl Identifier<Option<SubscriptionId>> for CoinbaseTrade {
@@ -50,21 +80,106 @@ impl Identifier<Option<SubscriptionId>> for CoinbaseTrade {
 
 impl From<(ExchangeId, Instrument, CoinbaseTrade)> for MarketIter<PublicTrade> {
     fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, CoinbaseTrade)) -> Self {
+        let (buyer_order_id, seller_order_id) = match trade.side {
+            Side::Buy => (trade.taker_order_id, trade.maker_order_id),
+            Side::Sell => (trade.maker_order_id, trade.taker_order_id),
+        };
+
         Self(vec![Ok(MarketEvent {
             exchange_time: trade.time,
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: Some(trade.id),
+            tag: None,
             kind: PublicTrade {
                 id: trade.id.to_string(),
                 price: trade.price,
                 amount: trade.amount,
                 side: trade.side,
+                buyer_order_id: Some(buyer_order_id),
+                seller_order_id: Some(seller_order_id),
+                taker_order_kind: None,
             },
         })])
     }
 }
 
+/// [`Coinbase`] [`PublicTrades`] [`ExchangeTransformer`] that detects missed trades using the
+/// "heartbeat" channel.
+///
+/// Trades are delegated to the `inner` [`StatelessTransformer`]. Each [`CoinbaseHeartbeat`]
+/// `last_trade_id` is validated against the trade ids delivered so far, yielding a
+/// [`DataError::SequenceGap`] if any trades were missed. Channel consumers receive it via
+/// [`Streams::errors`](crate::streams::Streams::errors), since heartbeats yield no event.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CoinbaseTradesTransformer {
+    pub inner: StatelessTransformer<Coinbase, PublicTrades, CoinbaseTrade>,
+    metrics: MetricsHook,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Coinbase, PublicTrades> for CoinbaseTradesTransformer {
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            inner: <StatelessTransformer<Coinbase, PublicTrades, CoinbaseTrade> as ExchangeTransformer<Coinbase, PublicTrades>>::new(
+                ws_sink_tx,
+                instrument_map,
+            )
+            .await?,
+            metrics: MetricsHook::default(),
+        })
+    }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        ExchangeTransformer::<Coinbase, PublicTrades>::set_metrics(
+            &mut self.inner,
+            metrics.clone(),
+        );
+        self.metrics = metrics;
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        ExchangeTransformer::<Coinbase, PublicTrades>::unsubscribe(
+            &mut self.inner,
+            subscription_ids,
+        );
+    }
+
+    fn subscribe(&mut self, instrument_map: Map<Instrument>) -> Result<(), DataError> {
+        ExchangeTransformer::<Coinbase, PublicTrades>::subscribe(&mut self.inner, instrument_map)
+    }
+}
+
+impl Transformer for CoinbaseTradesTransformer {
+    type Error = DataError;
+    type Input = CoinbaseTradeMessage;
+    type Output = MarketEvent<PublicTrade>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            CoinbaseTradeMessage::Trade(trade) => self.inner.transform(trade),
+            CoinbaseTradeMessage::Heartbeat(heartbeat) => {
+                match self
+                    .inner
+                    .sequences_mut()
+                    .validate_last(&heartbeat.trades_subscription_id(), heartbeat.last_trade_id)
+                {
+                    Ok(()) => vec![],
+                    Err(gap) => {
+                        self.metrics.report_error(&gap);
+                        vec![Err(gap)]
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Deserialize a [`CoinbaseTrade`] "product_id" (eg/ "BTC-USD") as the associated [`SubscriptionId`]
 /// (eg/ SubscriptionId("matches|BTC-USD").
 pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
@@ -78,6 +193,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
     use barter_integration::error::SocketError;
     use chrono::NaiveDateTime;
     use serde::de::Error;
@@ -112,9 +228,11 @@ mod tests {
                 expected: Ok(CoinbaseTrade {
                     subscription_id: SubscriptionId::from("matches|BTC-USD"),
                     id: 10,
-                    price: 400.23,
-                    amount: 5.23512,
-                    side: Side::Sell,
+                    price: from_f64(400.23),
+                    amount: from_f64(5.23512),
+                    side: Side::Buy,
+                    maker_order_id: "ac928c66-ca53-498f-9c13-a110027a60e8".to_string(),
+                    taker_order_id: "132fb6ae-456b-4654-b4e0-d681ac05cea1".to_string(),
                     time: DateTime::from_utc(
                         NaiveDateTime::from_str("2014-11-07T08:19:27.028459").unwrap(),
                         Utc,
@@ -139,4 +257,59 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_coinbase_trade_order_ids() {
+        struct TestCase {
+            side: Side,
+            expected: (&'static str, &'static str),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: maker sell order, so the taker is the buyer
+                side: Side::Buy,
+                expected: ("taker", "maker"),
+            },
+            TestCase {
+                // TC1: maker buy order, so the taker is the seller
+                side: Side::Sell,
+                expected: ("maker", "taker"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let trade = CoinbaseTrade {
+                subscription_id: SubscriptionId::from("matches|BTC-USD"),
+                id: 10,
+                time: Utc::now(),
+                amount: from_f64(1.0),
+                price: from_f64(400.0),
+                side: test.side,
+                maker_order_id: "maker".to_string(),
+                taker_order_id: "taker".to_string(),
+            };
+            let instrument = Instrument::from((
+                "btc",
+                "usd",
+                barter_integration::model::InstrumentKind::Spot,
+            ));
+
+            let actual = MarketIter::<PublicTrade>::from((ExchangeId::Coinbase, instrument, trade))
+                .0
+                .remove(0)
+                .unwrap()
+                .kind;
+            assert_eq!(actual.side, test.side, "TC{} failed", index);
+            assert_eq!(
+                (
+                    actual.buyer_order_id.as_deref(),
+                    actual.seller_order_id.as_deref()
+                ),
+                (Some(test.expected.0), Some(test.expected.1)),
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
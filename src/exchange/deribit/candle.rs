@@ -0,0 +1,323 @@
+use super::{
+    channel::DeribitChartResolution,
+    message::{DeribitMessage, DeribitParams},
+};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        candle::{Candle, CandleSource, VolumeDenomination},
+        number::Number,
+        Interval,
+    },
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) real-time candles WebSocket message.
+pub type DeribitCandles = DeribitMessage<DeribitCandle>;
+
+/// [`Deribit`](super::Deribit) real-time candle, sent upon every trade in the candle interval.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#chart-trades-instrument_name-resolution>
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "method": "subscription",
+///   "params": {
+///     "channel": "chart.trades.BTC-PERPETUAL.1",
+///     "data": {
+///       "volume": 0.05219351,
+///       "tick": 1573645080000,
+///       "open": 8869.79,
+///       "low": 8788.25,
+///       "high": 8870.31,
+///       "cost": 460,
+///       "close": 8791.25
+///     }
+///   }
+/// }
+/// ```
+///
+/// ## Notes:
+/// - "volume" is denominated in the base currency, and "cost" in the quote currency.
+/// - [`Deribit`](super::Deribit) does not indicate if a candle is closed, nor provide the
+///   number of trades in a candle.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitCandle {
+    #[serde(
+        rename = "tick",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub start: DateTime<Utc>,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
+    pub cost: Number,
+}
+
+impl From<(ExchangeId, Instrument, DeribitCandles)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candles): (ExchangeId, Instrument, DeribitCandles)) -> Self {
+        let DeribitMessage {
+            params:
+                DeribitParams {
+                    subscription_id,
+                    data: candle,
+                },
+        } = candles;
+
+        // Determine the candle channel (eg/ "chart.trades.1") from the SubscriptionId
+        // (eg/ "chart.trades.1|BTC-PERPETUAL")
+        let channel = subscription_id
+            .as_ref()
+            .split('|')
+            .next()
+            .unwrap_or_default();
+
+        let close_time = match deribit_candle_close_time(channel, candle.start) {
+            Ok(close_time) => close_time,
+            Err(error) => return Self(vec![Err(error)]),
+        };
+
+        // Deribit does not provide a candle event time, so use the close time of historic
+        // candles, and the received time of in-progress candles that close in the future
+        let received_time = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: close_time.min(received_time),
+            received_time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: Candle {
+                start_time: candle.start,
+                close_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume,
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: Some(candle.volume),
+                quote_volume: Some(candle.cost),
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 0,
+                closed: false,
+                source: CandleSource::LastTrade,
+                synthetic: false,
+                derived: false,
+            },
+        })])
+    }
+}
+
+/// Determine the close time of a [`Deribit`](super::Deribit) candle using the candle channel
+/// (eg/ "chart.trades.60") and the candle start time.
+///
+/// Consistent with [`Binance`](crate::exchange::binance::Binance), the close time is the last
+/// millisecond of the candle interval (ie/ start + interval - 1ms).
+fn deribit_candle_close_time(
+    channel: &str,
+    start: DateTime<Utc>,
+) -> Result<DateTime<Utc>, DataError> {
+    Interval::ALL
+        .into_iter()
+        .find(|interval| {
+            DeribitChartResolution::try_from(*interval)
+                .is_ok_and(|chart| chart.channel.as_ref() == channel)
+        })
+        .map(|interval| interval.close_time(start))
+        .ok_or_else(|| {
+            DataError::Socket(SocketError::Unsupported {
+                entity: ExchangeId::Deribit.as_str(),
+                item: format!("candle channel: {channel}"),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        model::{InstrumentKind, SubscriptionId},
+    };
+    use chrono::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_deribit_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitCandles, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid chart update
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "chart.trades.BTC-PERPETUAL.1",
+                            "data": {
+                                "volume": 0.05219351, "tick": 1573645080000,
+                                "open": 8869.79, "low": 8788.25, "high": 8870.31,
+                                "cost": 460, "close": 8791.25
+                            }
+                        }
+                    }
+                    "#,
+                    expected: Ok(DeribitCandles {
+                        params: DeribitParams {
+                            subscription_id: SubscriptionId::from("chart.trades.1|BTC-PERPETUAL"),
+                            data: DeribitCandle {
+                                start: datetime_utc_from_epoch_duration(
+                                    std::time::Duration::from_millis(1573645080000),
+                                ),
+                                open: from_f64(8869.79),
+                                high: from_f64(8870.31),
+                                low: from_f64(8788.25),
+                                close: from_f64(8791.25),
+                                volume: from_f64(0.05219351),
+                                cost: from_f64(460.0),
+                            },
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid chart update w/ non-numeric open price
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "chart.trades.BTC-PERPETUAL.1",
+                            "data": {
+                                "volume": 0.05219351, "tick": 1573645080000,
+                                "open": "gibberish", "low": 8788.25, "high": 8870.31,
+                                "cost": 460, "close": 8791.25
+                            }
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deribit_candles_into_market_event() {
+        struct TestCase {
+            channel: &'static str,
+            expected_close_time: Option<DateTime<Utc>>,
+        }
+
+        let start = datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+            1672531200000, // 2023-01-01T00:00:00Z
+        ));
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m chart
+                channel: "chart.trades.1|BTC-PERPETUAL",
+                expected_close_time: Some(start + Duration::minutes(1) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC1: 6h chart denominated in minutes
+                channel: "chart.trades.360|BTC-PERPETUAL",
+                expected_close_time: Some(start + Duration::hours(6) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC2: 1D chart
+                channel: "chart.trades.1D|BTC-PERPETUAL",
+                expected_close_time: Some(start + Duration::days(1) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC3: unknown resolution
+                channel: "chart.trades.240|BTC-PERPETUAL",
+                expected_close_time: None,
+            },
+        ];
+
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = DeribitCandles {
+                params: DeribitParams {
+                    subscription_id: SubscriptionId::from(test.channel),
+                    data: DeribitCandle {
+                        start,
+                        open: from_f64(16530.1),
+                        high: from_f64(16545.9),
+                        low: from_f64(16521.3),
+                        close: from_f64(16541.2),
+                        volume: from_f64(1.5),
+                        cost: from_f64(24811.8),
+                    },
+                },
+            };
+
+            let MarketIter(events) =
+                MarketIter::<Candle>::from((ExchangeId::Deribit, instrument.clone(), input));
+            assert_eq!(events.len(), 1, "TC{} failed", index);
+
+            match (events.into_iter().next().unwrap(), test.expected_close_time) {
+                (Ok(event), Some(expected)) => {
+                    assert_eq!(event.kind.start_time, start, "TC{} failed", index);
+                    assert_eq!(event.kind.close_time, expected, "TC{} failed", index);
+                    assert_eq!(event.exchange_time, expected, "TC{} failed", index);
+                    assert_eq!(event.kind.volume, from_f64(1.5), "TC{} failed", index);
+                    assert_eq!(
+                        event.kind.quote_volume,
+                        Some(from_f64(24811.8)),
+                        "TC{} failed",
+                        index
+                    );
+                    assert!(!event.kind.closed, "TC{} failed", index);
+                }
+                (Err(_), None) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use super::Deribit;
+use crate::{
+    error::UnsupportedIntervalError,
+    subscription::{
+        candle::{CandleUpdates, Candles},
+        liquidation::Liquidations,
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
+    Identifier,
+};
+use barter_integration::model::SubscriptionId;
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Deribit`](super::Deribit) channel to be subscribed to.
+///
+/// Deribit channel names embed the instrument name (eg/ "trades.BTC-PERPETUAL.raw"), so
+/// [`Self`] is the channel name with the instrument segment removed (eg/ "trades.raw"). See
+/// [`Self::with_market`] for the full channel name.
+///
+/// See docs: <https://docs.deribit.com/#subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct DeribitChannel(pub &'static str);
+
+impl DeribitChannel {
+    /// [`Deribit`] real-time trades channel, sending every trade without aggregation.
+    ///
+    /// Note: liquidations are published as trades with a "liquidation" field on this channel.
+    ///
+    /// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+    pub const TRADES: Self = Self("trades.raw");
+
+    /// [`Deribit`] placeholder channel name for a candle [`Interval`] that Deribit does not
+    /// support, which is rejected by [`Deribit`] subscription validation.
+    pub const CANDLES_UNSUPPORTED: Self = Self("chart.trades.unsupported");
+
+    /// [`Deribit`] real-time candles channel for the provided [`Interval`].
+    ///
+    /// [`Interval`]s without a [`DeribitChartResolution`] map to [`Self::CANDLES_UNSUPPORTED`].
+    ///
+    /// See docs: <https://docs.deribit.com/#chart-trades-instrument_name-resolution>
+    pub fn candles(interval: Interval) -> Self {
+        DeribitChartResolution::try_from(interval)
+            .map_or(Self::CANDLES_UNSUPPORTED, |chart| chart.channel)
+    }
+
+    /// Full [`Deribit`] channel name of [`Self`] for the provided market
+    /// (eg/ "trades.raw" & "BTC-PERPETUAL" -> "trades.BTC-PERPETUAL.raw").
+    pub fn with_market(&self, market: &str) -> String {
+        deribit_channel(self.0, market)
+    }
+}
+
+/// [`Deribit`] chart resolution of a Barter [`Interval`], denominated in minutes except for
+/// daily charts (eg/ "60", "1D").
+///
+/// Note: only 1m, 3m, 5m, 15m, 30m, 1h, 2h, 6h, 12h & 1d [`Interval`]s are supported.
+///
+/// See docs: <https://docs.deribit.com/#chart-trades-instrument_name-resolution>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct DeribitChartResolution {
+    /// Chart resolution (eg/ "60").
+    pub resolution: &'static str,
+    /// Candles channel of the resolution (eg/ "chart.trades.60").
+    pub channel: DeribitChannel,
+}
+
+impl TryFrom<Interval> for DeribitChartResolution {
+    type Error = UnsupportedIntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let (resolution, channel) = match interval {
+            Interval::Minute1 => ("1", "chart.trades.1"),
+            Interval::Minute3 => ("3", "chart.trades.3"),
+            Interval::Minute5 => ("5", "chart.trades.5"),
+            Interval::Minute15 => ("15", "chart.trades.15"),
+            Interval::Minute30 => ("30", "chart.trades.30"),
+            Interval::Hour1 => ("60", "chart.trades.60"),
+            Interval::Hour2 => ("120", "chart.trades.120"),
+            Interval::Hour6 => ("360", "chart.trades.360"),
+            Interval::Hour12 => ("720", "chart.trades.720"),
+            Interval::Day1 => ("1D", "chart.trades.1D"),
+            Interval::Hour4
+            | Interval::Hour8
+            | Interval::Day3
+            | Interval::Week1
+            | Interval::Month1
+            | Interval::Month3 => {
+                return Err(UnsupportedIntervalError {
+                    exchange: "deribit",
+                    interval,
+                })
+            }
+        };
+
+        Ok(Self {
+            resolution,
+            channel: DeribitChannel(channel),
+        })
+    }
+}
+
+/// Index of the instrument segment within the full [`Deribit`] channel name of the provided
+/// channel (eg/ 1 for "trades.{instrument}.raw", 2 for "chart.trades.{instrument}.1").
+fn market_index(channel: &str) -> usize {
+    match channel.starts_with("chart.") {
+        true => 2,
+        false => 1,
+    }
+}
+
+/// Construct the full [`Deribit`] channel name by inserting the market into the provided
+/// [`DeribitChannel`] name (eg/ "chart.trades.1" & "BTC-PERPETUAL" ->
+/// "chart.trades.BTC-PERPETUAL.1").
+pub fn deribit_channel(channel: &str, market: &str) -> String {
+    let mut segments = channel.split('.').collect::<Vec<_>>();
+    segments.insert(market_index(channel).min(segments.len()), market);
+    segments.join(".")
+}
+
+/// Determine the [`SubscriptionId`] (eg/ "trades.raw|BTC-PERPETUAL") associated with a full
+/// [`Deribit`] channel name (eg/ "trades.BTC-PERPETUAL.raw"), counterpart to
+/// [`deribit_channel`].
+pub fn deribit_subscription_id(channel: &str) -> SubscriptionId {
+    let mut segments = channel.split('.').collect::<Vec<_>>();
+    let index = market_index(channel);
+
+    if index < segments.len() {
+        let market = segments.remove(index);
+        SubscriptionId::from(format!("{}|{market}", segments.join(".")))
+    } else {
+        SubscriptionId::from(channel)
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, PublicTrades> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TRADES
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, Liquidations> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::TRADES
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, Candles> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::candles(self.kind.0)
+    }
+}
+
+impl Identifier<DeribitChannel> for Subscription<Deribit, CandleUpdates> {
+    fn id(&self) -> DeribitChannel {
+        DeribitChannel::candles(self.kind.0)
+    }
+}
+
+impl AsRef<str> for DeribitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::Connector;
+
+    #[test]
+    fn test_deribit_channel_round_trip() {
+        struct TestCase {
+            channel: DeribitChannel,
+            market: &'static str,
+            expected_channel: &'static str,
+            expected_subscription_id: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades channel
+                channel: DeribitChannel::TRADES,
+                market: "BTC-PERPETUAL",
+                expected_channel: "trades.BTC-PERPETUAL.raw",
+                expected_subscription_id: "trades.raw|BTC-PERPETUAL",
+            },
+            TestCase {
+                // TC1: candles channel denominated in minutes
+                channel: DeribitChannel::candles(Interval::Hour1),
+                market: "ETH_USDC-PERPETUAL",
+                expected_channel: "chart.trades.ETH_USDC-PERPETUAL.60",
+                expected_subscription_id: "chart.trades.60|ETH_USDC-PERPETUAL",
+            },
+            TestCase {
+                // TC2: daily candles channel
+                channel: DeribitChannel::candles(Interval::Day1),
+                market: "BTC-PERPETUAL",
+                expected_channel: "chart.trades.BTC-PERPETUAL.1D",
+                expected_subscription_id: "chart.trades.1D|BTC-PERPETUAL",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let channel = test.channel.with_market(test.market);
+            assert_eq!(channel, test.expected_channel, "TC{} failed", index);
+            assert_eq!(
+                deribit_subscription_id(&channel),
+                SubscriptionId::from(test.expected_subscription_id),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_deribit_channel_candles() {
+        struct TestCase {
+            input: Interval,
+            expected: DeribitChannel,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m interval is supported
+                input: Interval::Minute1,
+                expected: DeribitChannel("chart.trades.1"),
+            },
+            TestCase {
+                // TC1: 12h interval is supported as 720 minutes
+                input: Interval::Hour12,
+                expected: DeribitChannel("chart.trades.720"),
+            },
+            TestCase {
+                // TC2: 1d interval is supported
+                input: Interval::Day1,
+                expected: DeribitChannel("chart.trades.1D"),
+            },
+            TestCase {
+                // TC3: 4h interval is unsupported
+                input: Interval::Hour4,
+                expected: DeribitChannel::CANDLES_UNSUPPORTED,
+            },
+            TestCase {
+                // TC4: 1w interval is unsupported
+                input: Interval::Week1,
+                expected: DeribitChannel::CANDLES_UNSUPPORTED,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = DeribitChannel::candles(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_deribit_chart_resolution_try_from_interval() {
+        // Expected candles channel of every Interval, in Interval::ALL order
+        let tests = [
+            (Interval::Minute1, Some("chart.trades.1")),
+            (Interval::Minute3, Some("chart.trades.3")),
+            (Interval::Minute5, Some("chart.trades.5")),
+            (Interval::Minute15, Some("chart.trades.15")),
+            (Interval::Minute30, Some("chart.trades.30")),
+            (Interval::Hour1, Some("chart.trades.60")),
+            (Interval::Hour2, Some("chart.trades.120")),
+            (Interval::Hour4, None),
+            (Interval::Hour6, Some("chart.trades.360")),
+            (Interval::Hour8, None),
+            (Interval::Hour12, Some("chart.trades.720")),
+            (Interval::Day1, Some("chart.trades.1D")),
+            (Interval::Day3, None),
+            (Interval::Week1, None),
+            (Interval::Month1, None),
+            (Interval::Month3, None),
+        ];
+        assert_eq!(tests.map(|(interval, _)| interval), Interval::ALL);
+
+        for (interval, expected) in tests {
+            let actual = DeribitChartResolution::try_from(interval);
+            match expected {
+                Some(channel) => {
+                    let chart = actual.unwrap();
+                    assert_eq!(chart.channel, DeribitChannel(channel), "{interval} failed");
+                    assert_eq!(
+                        format!("chart.trades.{}", chart.resolution),
+                        channel,
+                        "{interval} failed"
+                    );
+                    assert_eq!(DeribitChannel::candles(interval), chart.channel);
+                }
+                None => {
+                    assert_eq!(
+                        actual,
+                        Err(UnsupportedIntervalError {
+                            exchange: "deribit",
+                            interval
+                        }),
+                        "{interval} failed"
+                    );
+                    assert_eq!(
+                        DeribitChannel::candles(interval),
+                        DeribitChannel::CANDLES_UNSUPPORTED
+                    );
+                }
+            }
+
+            // Deribit supported_intervals are exactly those with a DeribitChartResolution
+            assert_eq!(
+                Deribit::supported_intervals().contains(&interval),
+                expected.is_some(),
+                "{interval} failed"
+            );
+        }
+    }
+}
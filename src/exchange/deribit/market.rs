@@ -0,0 +1,81 @@
+use super::Deribit;
+use crate::{subscription::Subscription, Identifier};
+use barter_integration::model::{Instrument, InstrumentKind};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Deribit`](super::Deribit) instrument name that can be subscribed to.
+///
+/// Barter [`Instrument`]s are mapped to:
+/// - [`InstrumentKind::Spot`]: "{BASE}_{QUOTE}" (eg/ "BTC_USDC").
+/// - [`InstrumentKind::FuturePerpetual`] w/ "usd" quote: inverse perpetual "{BASE}-PERPETUAL"
+///   (eg/ "BTC-PERPETUAL").
+/// - [`InstrumentKind::FuturePerpetual`] w/ any other quote: linear perpetual
+///   "{BASE}_{QUOTE}-PERPETUAL" (eg/ "ETH_USDC-PERPETUAL").
+///
+/// See docs: <https://docs.deribit.com/#public-get_instruments>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitMarket(pub String);
+
+impl<Kind> Identifier<DeribitMarket> for Subscription<Deribit, Kind> {
+    fn id(&self) -> DeribitMarket {
+        DeribitMarket::from(&self.instrument)
+    }
+}
+
+impl From<&Instrument> for DeribitMarket {
+    fn from(instrument: &Instrument) -> Self {
+        let (base, quote) = (
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase(),
+        );
+
+        Self(match instrument.kind {
+            InstrumentKind::Spot => format!("{base}_{quote}"),
+            InstrumentKind::FuturePerpetual if quote == "USD" => format!("{base}-PERPETUAL"),
+            InstrumentKind::FuturePerpetual => format!("{base}_{quote}-PERPETUAL"),
+        })
+    }
+}
+
+impl AsRef<str> for DeribitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deribit_market() {
+        struct TestCase {
+            input: Instrument,
+            expected: DeribitMarket,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: inverse perpetual w/ usd quote
+                input: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+                expected: DeribitMarket("BTC-PERPETUAL".to_string()),
+            },
+            TestCase {
+                // TC1: linear perpetual w/ usdc quote
+                input: Instrument::from(("eth", "usdc", InstrumentKind::FuturePerpetual)),
+                expected: DeribitMarket("ETH_USDC-PERPETUAL".to_string()),
+            },
+            TestCase {
+                // TC2: spot pair
+                input: Instrument::from(("btc", "usdc", InstrumentKind::Spot)),
+                expected: DeribitMarket("BTC_USDC".to_string()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = DeribitMarket::from(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
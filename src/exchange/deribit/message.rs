@@ -0,0 +1,49 @@
+use super::channel::deribit_subscription_id;
+use crate::Identifier;
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) JSON-RPC subscription notification WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#subscriptions>
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "method": "subscription",
+///   "params": {
+///     "channel": "trades.BTC-PERPETUAL.raw",
+///     "data": [...]
+///   }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitMessage<T> {
+    pub params: DeribitParams<T>,
+}
+
+/// [`Deribit`](super::Deribit) subscription notification "params", containing the full channel
+/// name & the channel specific data.
+///
+/// See [`DeribitMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitParams<T> {
+    #[serde(rename = "channel", deserialize_with = "de_channel_as_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for DeribitMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.params.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`DeribitMessage`] "channel" (eg/ "trades.BTC-PERPETUAL.raw") as the associated
+/// [`SubscriptionId`] (eg/ "trades.raw|BTC-PERPETUAL").
+pub fn de_channel_as_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer).map(deribit_subscription_id)
+}
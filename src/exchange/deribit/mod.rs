@@ -0,0 +1,265 @@
+use self::{
+    candle::DeribitCandles,
+    channel::DeribitChannel,
+    market::DeribitMarket,
+    subscription::DeribitSubResponse,
+    trade::DeribitTrades,
+    validator::{DeribitSubValidator, DERIBIT_SUBSCRIBE_REQUEST_ID},
+};
+use crate::{
+    decoder::PlainText,
+    exchange::{AckMode, Connector, ExchangeId, ExchangeSub, StreamSelector},
+    subscriber::WebSocketSubscriber,
+    subscription::{
+        candle::{CandleUpdates, Candles},
+        liquidation::Liquidations,
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
+    transformer::{candle::HeldCandleTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream, Identifier,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::fmt::Debug;
+use url::Url;
+
+/// Candle types for [`Deribit`].
+pub mod candle;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`DeribitMessage`](message::DeribitMessage) JSON-RPC notification type for [`Deribit`].
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Deribit`].
+pub mod subscription;
+
+/// Public trade & liquidation types for [`Deribit`].
+pub mod trade;
+
+/// [`SubscriptionValidator`](crate::subscriber::validator::SubscriptionValidator) implementation
+/// for [`Deribit`] correlating the subscribed channels with the JSON-RPC request id.
+pub mod validator;
+
+/// [`Deribit`] server base url.
+///
+/// See docs: <https://docs.deribit.com/#json-rpc>
+pub const BASE_URL_DERIBIT: &str = "wss://www.deribit.com/ws/api/v2";
+
+/// [`Deribit`] exchange.
+///
+/// ### Notes
+/// - Supports [`InstrumentKind::Spot`](barter_integration::model::InstrumentKind) and
+///   [`InstrumentKind::FuturePerpetual`](barter_integration::model::InstrumentKind) instruments
+///   (see [`DeribitMarket`] for the instrument name mapping).
+/// - Options are not supported since they cannot be expressed as a Barter
+///   [`Instrument`].
+///
+/// See docs: <https://docs.deribit.com/>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct Deribit;
+
+impl Connector for Deribit {
+    const ID: ExchangeId = ExchangeId::Deribit;
+    const ACK_MODE: AckMode = AckMode::PerRequest;
+    type Channel = DeribitChannel;
+    type Market = DeribitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = DeribitSubValidator;
+    type SubResponse = DeribitSubResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_DERIBIT).map_err(SocketError::UrlParse)
+    }
+
+    fn supported_intervals() -> &'static [Interval] {
+        &[
+            Interval::Minute1,
+            Interval::Minute3,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Hour1,
+            Interval::Hour2,
+            Interval::Hour6,
+            Interval::Hour12,
+            Interval::Day1,
+        ]
+    }
+
+    fn validate_subscriptions<Kind>(
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<(), SocketError>
+    where
+        Kind: Debug,
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        match subscriptions
+            .iter()
+            .find(|subscription| subscription.id() == DeribitChannel::CANDLES_UNSUPPORTED)
+        {
+            Some(unsupported) => Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} (supported candle intervals: 1m, 3m, 5m, 15m, 30m, 1h, 2h, 6h, 12h, 1d)",
+                    unsupported.kind
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        deribit_requests("public/subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        deribit_requests("public/unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`Deribit`] JSON-RPC [`WsMessage`] payload that actions the provided `method`
+/// (eg/ "public/subscribe", "public/unsubscribe") for a collection of [`ExchangeSub`]s.
+fn deribit_requests(
+    method: &str,
+    exchange_subs: Vec<ExchangeSub<DeribitChannel, DeribitMarket>>,
+) -> Vec<WsMessage> {
+    let channels = exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| channel.with_market(market.as_ref()))
+        .collect::<Vec<String>>();
+
+    vec![WsMessage::Text(
+        json!({
+            "jsonrpc": "2.0",
+            "id": DERIBIT_SUBSCRIBE_REQUEST_ID,
+            "method": method,
+            "params": { "channels": channels }
+        })
+        .to_string(),
+    )]
+}
+
+impl StreamSelector<PublicTrades> for Deribit {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, DeribitTrades>>;
+}
+
+impl StreamSelector<Liquidations> for Deribit {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, DeribitTrades>>;
+}
+
+impl StreamSelector<Candles> for Deribit {
+    type Stream = ExchangeWsStream<
+        HeldCandleTransformer<StatelessTransformer<Self, Candles, DeribitCandles>>,
+    >;
+}
+
+impl StreamSelector<CandleUpdates> for Deribit {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, CandleUpdates, DeribitCandles>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::Interval;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_deribit_validate_subscriptions() {
+        struct TestCase {
+            input: Vec<Subscription<Deribit, Candles>>,
+            expected: Result<(), SocketError>,
+        }
+
+        let candles = |interval| {
+            Subscription::from((
+                Deribit,
+                ("btc", "usd", InstrumentKind::FuturePerpetual),
+                Candles(interval),
+            ))
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: supported candle intervals
+                input: vec![candles(Interval::Minute1), candles(Interval::Day1)],
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: unsupported 4h candle interval
+                input: vec![candles(Interval::Minute1), candles(Interval::Hour4)],
+                expected: Err(SocketError::Unsupported {
+                    entity: "deribit",
+                    item: "Candles(Hour4)".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: unsupported 1w candle interval
+                input: vec![candles(Interval::Week1)],
+                expected: Err(SocketError::Unsupported {
+                    entity: "deribit",
+                    item: "Candles(Week1)".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Deribit::validate_subscriptions(&test.input);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deribit_requests() {
+        let requests = Deribit::requests(vec![
+            ExchangeSub::from((
+                DeribitChannel::TRADES,
+                DeribitMarket("BTC-PERPETUAL".to_string()),
+            )),
+            ExchangeSub::from((
+                DeribitChannel::candles(Interval::Hour1),
+                DeribitMarket("ETH_USDC-PERPETUAL".to_string()),
+            )),
+        ]);
+
+        assert_eq!(
+            requests,
+            vec![WsMessage::Text(
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "method": "public/subscribe",
+                    "params": {
+                        "channels": [
+                            "trades.BTC-PERPETUAL.raw",
+                            "chart.trades.ETH_USDC-PERPETUAL.60"
+                        ]
+                    }
+                })
+                .to_string()
+            )]
+        );
+    }
+}
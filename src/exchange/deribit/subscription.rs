@@ -0,0 +1,173 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`Deribit`](super::Deribit) JSON-RPC response received in response to WebSocket
+/// subscription requests.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#public-subscribe>
+/// #### Subscription Success
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "id": 1,
+///   "result": ["trades.BTC-PERPETUAL.raw", "chart.trades.BTC-PERPETUAL.60"]
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "id": 1,
+///   "error": {"code": -32602, "message": "Invalid params"}
+/// }
+/// ```
+///
+/// Note: channels that could not be subscribed to (eg/ unknown instrument) are omitted from the
+/// success "result".
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitSubResponse {
+    /// Request id echoed back by the exchange, used to correlate the response with the request.
+    pub id: u64,
+    #[serde(default)]
+    pub result: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<DeribitError>,
+}
+
+/// [`Deribit`](super::Deribit) JSON-RPC error code & message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl Validator for DeribitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match (&self.error, self.result.is_empty()) {
+            (Some(error), _) => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                error.code, error.message
+            ))),
+            (None, true) => Err(SocketError::Subscribe(
+                "received failure subscription response with no subscribed channels".to_owned(),
+            )),
+            (None, false) => Ok(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_deribit_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is Subscribed
+                    input: r#"{"jsonrpc":"2.0","id":1,"result":["trades.BTC-PERPETUAL.raw"],"usIn":1,"usOut":2,"usDiff":1,"testnet":false}"#,
+                    expected: Ok(DeribitSubResponse {
+                        id: 1,
+                        result: vec!["trades.BTC-PERPETUAL.raw".to_string()],
+                        error: None,
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is error subscription
+                    input: r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"Invalid params"}}"#,
+                    expected: Ok(DeribitSubResponse {
+                        id: 1,
+                        result: vec![],
+                        error: Some(DeribitError {
+                            code: -32602,
+                            message: "Invalid params".to_string(),
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC2: input is market data notification without a request id
+                    input: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.raw","data":[]}}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_deribit_sub_response() {
+        struct TestCase {
+            input_response: DeribitSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: DeribitSubResponse {
+                    id: 1,
+                    result: vec!["trades.BTC-PERPETUAL.raw".to_string()],
+                    error: None,
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response subscribed to no channels
+                input_response: DeribitSubResponse {
+                    id: 1,
+                    result: vec![],
+                    error: None,
+                },
+                is_valid: false,
+            },
+            TestCase {
+                // TC2: input response is error subscription
+                input_response: DeribitSubResponse {
+                    id: 1,
+                    result: vec![],
+                    error: Some(DeribitError {
+                        code: -32602,
+                        message: "Invalid params".to_string(),
+                    }),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
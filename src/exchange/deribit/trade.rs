@@ -0,0 +1,379 @@
+use super::message::DeribitMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        liquidation::{Liquidation, LiquidationStatus},
+        number::{self, Number},
+        trade::PublicTrade,
+    },
+};
+use barter_integration::model::{Exchange, Instrument, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Deribit`](super::Deribit) real-time trades WebSocket message, which
+/// contains a batch of [`DeribitTrade`]s.
+pub type DeribitTrades = DeribitMessage<Vec<DeribitTrade>>;
+
+/// [`Deribit`](super::Deribit) real-time trade.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+/// ```json
+/// {
+///   "jsonrpc": "2.0",
+///   "method": "subscription",
+///   "params": {
+///     "channel": "trades.BTC-PERPETUAL.raw",
+///     "data": [
+///       {
+///         "trade_seq": 30289442,
+///         "trade_id": "48079269",
+///         "timestamp": 1590484156350,
+///         "tick_direction": 2,
+///         "price": 8950.0,
+///         "mark_price": 8948.9,
+///         "instrument_name": "BTC-PERPETUAL",
+///         "index_price": 8955.88,
+///         "direction": "sell",
+///         "amount": 10.0,
+///         "liquidation": "M"
+///       }
+///     ]
+///   }
+/// }
+/// ```
+///
+/// Note: `amount` is denominated in USD for inverse perpetuals (eg/ "BTC-PERPETUAL"), and in the
+/// base currency otherwise.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct DeribitTrade {
+    #[serde(rename = "trade_id")]
+    pub id: String,
+    #[serde(rename = "trade_seq")]
+    pub sequence: u64,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub price: Number,
+    pub amount: Number,
+    #[serde(
+        rename = "direction",
+        deserialize_with = "crate::subscription::trade::de_side_from_taker_side"
+    )]
+    pub side: Side,
+    #[serde(default)]
+    pub liquidation: Option<DeribitLiquidation>,
+}
+
+/// [`Deribit`](super::Deribit) indication of which side of a [`DeribitTrade`] was under
+/// liquidation.
+///
+/// See docs: <https://docs.deribit.com/#trades-instrument_name-interval>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum DeribitLiquidation {
+    /// Maker side of the trade was under liquidation.
+    #[serde(rename = "M")]
+    Maker,
+    /// Taker side of the trade was under liquidation.
+    #[serde(rename = "T")]
+    Taker,
+    /// Both sides of the trade were under liquidation.
+    #[serde(rename = "MT")]
+    MakerTaker,
+}
+
+impl DeribitTrade {
+    /// Determine the [`Side`] of every liquidated order of [`Self`].
+    ///
+    /// The trade "direction" is the taker [`Side`], so a liquidated maker order is the opposite
+    /// [`Side`].
+    pub fn liquidated_sides(&self) -> Vec<Side> {
+        let maker = match self.side {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+
+        match self.liquidation {
+            None => vec![],
+            Some(DeribitLiquidation::Maker) => vec![maker],
+            Some(DeribitLiquidation::Taker) => vec![self.side],
+            Some(DeribitLiquidation::MakerTaker) => vec![maker, self.side],
+        }
+    }
+}
+
+impl From<(ExchangeId, Instrument, DeribitTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, DeribitTrades)) -> Self {
+        trades
+            .params
+            .data
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: PublicTrade {
+                        id: trade.id,
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                        buyer_order_id: None,
+                        seller_order_id: None,
+                        taker_order_kind: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, DeribitTrades)> for MarketIter<Liquidation> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, DeribitTrades)) -> Self {
+        trades
+            .params
+            .data
+            .into_iter()
+            .flat_map(|trade| {
+                trade
+                    .liquidated_sides()
+                    .into_iter()
+                    .map(move |side| (side, trade.price, trade.amount, trade.time))
+            })
+            .map(|(side, price, quantity, time)| {
+                Ok(MarketEvent {
+                    exchange_time: time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    // Liquidations are derived from liquidation trade fills
+                    kind: Liquidation {
+                        side,
+                        price: number::to_f64(price),
+                        average_price: Some(number::to_f64(price)),
+                        filled_quantity: number::to_f64(quantity),
+                        total_quantity: number::to_f64(quantity),
+                        status: LiquidationStatus::Filled,
+                        time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::deribit::message::DeribitParams;
+    use crate::subscription::number::from_f64;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        error::SocketError,
+        model::{InstrumentKind, SubscriptionId},
+    };
+    use std::time::Duration;
+
+    fn trade(sequence: u64, side: Side, liquidation: Option<DeribitLiquidation>) -> DeribitTrade {
+        DeribitTrade {
+            id: format!("{sequence}"),
+            sequence,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1590484156350)),
+            price: from_f64(8950.0),
+            amount: from_f64(10.0),
+            side,
+            liquidation,
+        }
+    }
+
+    fn trades(data: Vec<DeribitTrade>) -> DeribitTrades {
+        DeribitMessage {
+            params: DeribitParams {
+                subscription_id: SubscriptionId::from("trades.raw|BTC-PERPETUAL"),
+                data,
+            },
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_deribit_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<DeribitTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid batch of trades, including a liquidation
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "trades.BTC-PERPETUAL.raw",
+                            "data": [
+                                {
+                                    "trade_seq": 30289442, "trade_id": "30289442",
+                                    "timestamp": 1590484156350, "tick_direction": 2,
+                                    "price": 8950.0, "mark_price": 8948.9,
+                                    "instrument_name": "BTC-PERPETUAL", "index_price": 8955.88,
+                                    "direction": "sell", "amount": 10.0
+                                },
+                                {
+                                    "trade_seq": 30289443, "trade_id": "30289443",
+                                    "timestamp": 1590484156350, "tick_direction": 3,
+                                    "price": 8950.0, "mark_price": 8948.9,
+                                    "instrument_name": "BTC-PERPETUAL", "index_price": 8955.88,
+                                    "direction": "buy", "amount": 10.0, "liquidation": "M"
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: Ok(trades(vec![
+                        trade(30289442, Side::Sell, None),
+                        trade(30289443, Side::Buy, Some(DeribitLiquidation::Maker)),
+                    ])),
+                },
+                TestCase {
+                    // TC1: invalid trade w/ unknown liquidation indicator
+                    input: r#"
+                    {
+                        "jsonrpc": "2.0",
+                        "method": "subscription",
+                        "params": {
+                            "channel": "trades.BTC-PERPETUAL.raw",
+                            "data": [
+                                {
+                                    "trade_seq": 30289442, "trade_id": "30289442",
+                                    "timestamp": 1590484156350, "price": 8950.0,
+                                    "direction": "sell", "amount": 10.0, "liquidation": "X"
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: subscription response is not a trades message
+                    input: r#"{"jsonrpc":"2.0","id":1,"result":["trades.BTC-PERPETUAL.raw"]}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<DeribitTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deribit_trades_into_market_events() {
+        struct TestCase {
+            input: DeribitTrades,
+            expected_trades: Vec<&'static str>,
+            expected_liquidations: Vec<Side>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: batch yields every trade, but only liquidations as Liquidations
+                input: trades(vec![
+                    trade(1, Side::Sell, None),
+                    trade(2, Side::Sell, Some(DeribitLiquidation::Taker)),
+                    trade(3, Side::Buy, None),
+                ]),
+                expected_trades: vec!["1", "2", "3"],
+                expected_liquidations: vec![Side::Sell],
+            },
+            TestCase {
+                // TC1: liquidated maker order is the opposite side of the taker direction
+                input: trades(vec![trade(4, Side::Buy, Some(DeribitLiquidation::Maker))]),
+                expected_trades: vec!["4"],
+                expected_liquidations: vec![Side::Sell],
+            },
+            TestCase {
+                // TC2: both sides liquidated yields a Liquidation for each side
+                input: trades(vec![trade(
+                    5,
+                    Side::Sell,
+                    Some(DeribitLiquidation::MakerTaker),
+                )]),
+                expected_trades: vec!["5"],
+                expected_liquidations: vec![Side::Buy, Side::Sell],
+            },
+        ];
+
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let MarketIter(trades) = MarketIter::<PublicTrade>::from((
+                ExchangeId::Deribit,
+                instrument.clone(),
+                test.input.clone(),
+            ));
+            let actual_trades = trades
+                .into_iter()
+                .map(|event| event.unwrap().kind.id)
+                .collect::<Vec<_>>();
+            assert_eq!(actual_trades, test.expected_trades, "TC{} failed", index);
+
+            let MarketIter(liquidations) = MarketIter::<Liquidation>::from((
+                ExchangeId::Deribit,
+                instrument.clone(),
+                test.input.clone(),
+            ));
+            let actual_liquidations = liquidations
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                actual_liquidations
+                    .iter()
+                    .map(|liquidation| liquidation.side)
+                    .collect::<Vec<_>>(),
+                test.expected_liquidations,
+                "TC{} failed",
+                index
+            );
+            assert!(
+                actual_liquidations.iter().all(|liquidation| {
+                    liquidation.price == 8950.0 && liquidation.total_quantity == 10.0
+                }),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
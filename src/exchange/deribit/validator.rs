@@ -0,0 +1,209 @@
+use super::{channel::deribit_channel, subscription::DeribitSubResponse};
+use crate::{
+    exchange::Connector,
+    subscriber::validator::{
+        validate_acks, AckTracker, SubscriptionOutcome, SubscriptionValidator,
+    },
+    subscription::{Map, SubKind},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WebSocket,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, time::Duration};
+use tracing::debug;
+
+/// [`Deribit`](super::Deribit) JSON-RPC request id of the single subscribe request actioned per
+/// connection (see [`Deribit::requests`](super::Deribit)).
+pub const DERIBIT_SUBSCRIBE_REQUEST_ID: u64 = 1;
+
+/// [`Deribit`](super::Deribit) specific [`SubscriptionValidator`].
+///
+/// ### Notes
+/// - Every channel of a connection is actioned by a single JSON-RPC request, so the
+///   [`DeribitSubResponse`] echoing the request "id" acknowledges, or rejects, every
+///   [`Subscription`](crate::subscription::Subscription) of the connection.
+/// - A success response lists the channels subscribed to, so any channel missing from the
+///   "result" is rejected.
+/// - Responses with an unexpected "id" are ignored.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DeribitSubValidator;
+
+impl DeribitSubValidator {
+    /// Map each full [`Deribit`](super::Deribit) channel name (eg/ "trades.BTC-PERPETUAL.raw")
+    /// to the instrument map [`SubscriptionId`] it acknowledges.
+    pub fn ack_subscriptions(map: &Map<Instrument>) -> BTreeMap<String, Vec<SubscriptionId>> {
+        map.0
+            .keys()
+            .map(|subscription_id| {
+                let channel = match subscription_id.as_ref().split_once('|') {
+                    Some((channel, market)) => deribit_channel(channel, market),
+                    None => subscription_id.as_ref().to_owned(),
+                };
+
+                (channel, vec![subscription_id.clone()])
+            })
+            .collect()
+    }
+
+    /// Record the [`DeribitSubResponse`] against every pending channel, returning false if the
+    /// response does not echo the [`DERIBIT_SUBSCRIBE_REQUEST_ID`].
+    pub fn on_response(response: DeribitSubResponse, tracker: &mut AckTracker<String>) -> bool {
+        if response.id != DERIBIT_SUBSCRIBE_REQUEST_ID {
+            return false;
+        }
+
+        let pending = tracker.pending().cloned().collect::<Vec<_>>();
+
+        match response.error {
+            Some(error) => {
+                let reason = format!("{}: {}", error.code, error.message);
+                for channel in pending {
+                    tracker.reject(&channel, &reason);
+                }
+            }
+            None => {
+                for channel in pending {
+                    if response.result.contains(&channel) {
+                        tracker.confirm(&channel);
+                    } else {
+                        tracker.reject(&channel, "channel not subscribed");
+                    }
+                }
+            }
+        }
+
+        true
+    }
+}
+
+#[async_trait]
+impl SubscriptionValidator for DeribitSubValidator {
+    async fn validate<Exchange, Kind>(
+        instrument_map: Map<Instrument>,
+        websocket: &mut WebSocket,
+        timeout: Duration,
+    ) -> Result<(Map<Instrument>, SubscriptionOutcome), SocketError>
+    where
+        Exchange: Connector + Send,
+        Kind: SubKind + Send,
+    {
+        let tracker = AckTracker::new(Self::ack_subscriptions(&instrument_map));
+
+        let outcome = validate_acks::<Exchange, DeribitSubResponse, _, _>(
+            tracker,
+            websocket,
+            timeout,
+            |response, tracker| {
+                let id = response.id;
+                if !Self::on_response(response, tracker) {
+                    debug!(
+                        exchange = %Exchange::ID,
+                        %id,
+                        "received subscription response for unexpected request id",
+                    );
+                }
+            },
+        )
+        .await?;
+
+        Ok((outcome.retain_confirmed(instrument_map), outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscriber::validator::RejectedSubscription;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_deribit_sub_validator_on_response() {
+        struct TestCase {
+            input: Vec<&'static str>,
+            expected: SubscriptionOutcome,
+        }
+
+        let ids = |ids: &[&str]| {
+            ids.iter()
+                .copied()
+                .map(SubscriptionId::from)
+                .collect::<Vec<_>>()
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: success response confirms every subscribed channel
+                input: vec![
+                    r#"{"jsonrpc":"2.0","id":1,"result":["trades.BTC-PERPETUAL.raw","chart.trades.BTC-PERPETUAL.60"]}"#,
+                ],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["chart.trades.60|BTC-PERPETUAL", "trades.raw|BTC-PERPETUAL"]),
+                    rejected: vec![],
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC1: success response rejects channels missing from the result
+                input: vec![r#"{"jsonrpc":"2.0","id":1,"result":["trades.BTC-PERPETUAL.raw"]}"#],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["trades.raw|BTC-PERPETUAL"]),
+                    rejected: vec![RejectedSubscription {
+                        subscription_id: SubscriptionId::from("chart.trades.60|BTC-PERPETUAL"),
+                        reason: "channel not subscribed".to_string(),
+                    }],
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC2: error response rejects every subscription w/ exchange message
+                input: vec![
+                    r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32602,"message":"Invalid params"}}"#,
+                ],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: ["chart.trades.60|BTC-PERPETUAL", "trades.raw|BTC-PERPETUAL"]
+                        .into_iter()
+                        .map(|id| RejectedSubscription {
+                            subscription_id: SubscriptionId::from(id),
+                            reason: "-32602: Invalid params".to_string(),
+                        })
+                        .collect(),
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC3: response w/ unexpected request id is ignored
+                input: vec![r#"{"jsonrpc":"2.0","id":7,"result":["trades.BTC-PERPETUAL.raw"]}"#],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: vec![],
+                    timed_out: ids(&["chart.trades.60|BTC-PERPETUAL", "trades.raw|BTC-PERPETUAL"]),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let map = ["trades.raw|BTC-PERPETUAL", "chart.trades.60|BTC-PERPETUAL"]
+                .into_iter()
+                .map(|id| {
+                    (
+                        SubscriptionId::from(id),
+                        Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+                    )
+                })
+                .collect::<Map<Instrument>>();
+
+            let mut tracker = AckTracker::new(DeribitSubValidator::ack_subscriptions(&map));
+            for input in test.input {
+                let response = serde_json::from_str::<DeribitSubResponse>(input).unwrap();
+                DeribitSubValidator::on_response(response, &mut tracker);
+            }
+
+            assert_eq!(tracker.into_outcome(), test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,291 @@
+use super::message::GateioMessage;
+use crate::{
+    event::{MarketEvent, MarketIter, TimeSource},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::{
+        book::{Level, OrderBookL1},
+        number::Number,
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioSpot`](super::spot::GateioSpot),
+/// [`GateioFuturesUsd`](super::futures::GateioFuturesUsd) and
+/// [`GateioFuturesBtc`](super::futures::GateioFuturesBtc) real-time OrderBook Level1 (top of
+/// book) WebSocket message.
+pub type GateioOrderBookL1 = GateioMessage<GateioOrderBookL1Inner>;
+
+/// [`Gateio`](super::Gateio) real-time OrderBook Level1 (top of book) message, received via the
+/// "spot.book_ticker" & "futures.book_ticker" channels.
+///
+/// ### Notes
+/// Spot sizes are strings, whereas futures sizes are integer contract amounts.
+///
+/// ### Raw Payload Examples
+/// #### GateioSpot OrderBookL1
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+/// ```json
+/// {
+///     "t": 1606293275123,
+///     "u": 48733182,
+///     "s": "BTC_USDT",
+///     "b": "19177.79",
+///     "B": "0.0003341504",
+///     "a": "19179.38",
+///     "A": "0.09"
+/// }
+/// ```
+///
+/// #### GateioFuturesUsd OrderBookL1
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+/// ```json
+/// {
+///     "t": 1615366379123,
+///     "u": 2517661076,
+///     "s": "BTC_USDT",
+///     "b": "54696.6",
+///     "B": 37000,
+///     "a": "54696.7",
+///     "A": 47061
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL1Inner {
+    #[serde(rename = "s", alias = "contract")]
+    pub market: String,
+    #[serde(
+        rename = "t",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "b", deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid_price: Number,
+    #[serde(rename = "B", deserialize_with = "de_str_or_number")]
+    pub best_bid_amount: Number,
+    #[serde(rename = "a", deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask_price: Number,
+    #[serde(rename = "A", deserialize_with = "de_str_or_number")]
+    pub best_ask_amount: Number,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .as_ref()
+            .map(|book| ExchangeSub::from((&self.channel, &book.market)).id())
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, GateioOrderBookL1)) -> Self {
+        let Some(book) = book.data else {
+            return Self(vec![]);
+        };
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: book.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: OrderBookL1 {
+                last_update_time: book.time,
+                best_bid: Level::new(book.best_bid_price, book.best_bid_amount),
+                best_ask: Level::new(book.best_ask_price, book.best_ask_amount),
+                time_source: TimeSource::Exchange,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`Number`] encoded as either a string (eg/ "0.09") or a JSON number (eg/ 37000).
+pub fn de_str_or_number<'de, D>(deserializer: D) -> Result<Number, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StrOrNumber {
+        Str(String),
+        Number(Number),
+    }
+
+    match StrOrNumber::deserialize(deserializer)? {
+        StrOrNumber::Str(value) => value.parse().map_err(serde::de::Error::custom),
+        StrOrNumber::Number(value) => Ok(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use chrono::TimeZone;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL1,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: GateioSpot OrderBookL1 w/ string sizes
+                    input: r#"
+                    {
+                        "time": 1606293275,
+                        "time_ms": 1606293275723,
+                        "channel": "spot.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1606293275123,
+                            "u": 48733182,
+                            "s": "BTC_USDT",
+                            "b": "19177.79",
+                            "B": "0.0003341504",
+                            "a": "19179.38",
+                            "A": "0.09"
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        time: Utc.timestamp_millis_opt(1606293275723).single(),
+                        channel: "spot.book_ticker".to_string(),
+                        error: None,
+                        data: Some(GateioOrderBookL1Inner {
+                            market: "BTC_USDT".to_string(),
+                            time: Utc.timestamp_millis_opt(1606293275123).unwrap(),
+                            best_bid_price: from_f64(19177.79),
+                            best_bid_amount: from_f64(0.0003341504),
+                            best_ask_price: from_f64(19179.38),
+                            best_ask_amount: from_f64(0.09),
+                        }),
+                    },
+                },
+                TestCase {
+                    // TC1: GateioFuturesUsd OrderBookL1 w/ integer contract sizes
+                    input: r#"
+                    {
+                        "time": 1615366379,
+                        "time_ms": 1615366379123,
+                        "channel": "futures.book_ticker",
+                        "event": "update",
+                        "error": null,
+                        "result": {
+                            "t": 1615366379123,
+                            "u": 2517661076,
+                            "s": "BTC_USDT",
+                            "b": "54696.6",
+                            "B": 37000,
+                            "a": "54696.7",
+                            "A": 47061
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        time: Utc.timestamp_millis_opt(1615366379123).single(),
+                        channel: "futures.book_ticker".to_string(),
+                        error: None,
+                        data: Some(GateioOrderBookL1Inner {
+                            market: "BTC_USDT".to_string(),
+                            time: Utc.timestamp_millis_opt(1615366379123).unwrap(),
+                            best_bid_price: from_f64(54696.6),
+                            best_bid_amount: from_f64(37000.0),
+                            best_ask_price: from_f64(54696.7),
+                            best_ask_amount: from_f64(47061.0),
+                        }),
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL1>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    mod transformer {
+        use super::*;
+        use crate::{
+            exchange::gateio::spot::GateioSpot,
+            subscription::{book::OrderBooksL1, Map},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::InstrumentKind, Transformer};
+        use std::collections::HashMap;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn test_gateio_spot_order_book_l1_routes_multiple_markets() {
+            let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+            let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+            let instrument_map = Map(HashMap::from([
+                (
+                    SubscriptionId::from("spot.book_ticker|BTC_USDT"),
+                    btc.clone(),
+                ),
+                (
+                    SubscriptionId::from("spot.book_ticker|ETH_USDT"),
+                    eth.clone(),
+                ),
+            ]));
+            let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+            let mut transformer = <StatelessTransformer<GateioSpot, OrderBooksL1, GateioOrderBookL1> as ExchangeTransformer<GateioSpot, OrderBooksL1>>::new(
+                ws_sink_tx,
+                instrument_map,
+            )
+            .await
+            .unwrap();
+
+            struct TestCase {
+                input: &'static str,
+                expected: Option<(Instrument, f64, f64)>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: BTC_USDT top of book is routed to the btc Instrument
+                    input: r#"{"time":1606293275,"time_ms":1606293275723,"channel":"spot.book_ticker","event":"update","result":{"t":1606293275123,"u":48733182,"s":"BTC_USDT","b":"19177.79","B":"0.0003341504","a":"19179.38","A":"0.09"}}"#,
+                    expected: Some((btc, 19177.79, 19179.38)),
+                },
+                TestCase {
+                    // TC1: ETH_USDT top of book on the same connection is routed to the eth Instrument
+                    input: r#"{"time":1606293276,"time_ms":1606293276001,"channel":"spot.book_ticker","event":"update","result":{"t":1606293275999,"u":12345678,"s":"ETH_USDT","b":"590.1","B":"1.5","a":"590.2","A":"2.5"}}"#,
+                    expected: Some((eth, 590.1, 590.2)),
+                },
+                TestCase {
+                    // TC2: pong answering an application-level ping yields no events or errors
+                    input: r#"{"time":1606293277,"time_ms":1606293277000,"channel":"spot.pong","event":"","result":null}"#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let input = serde_json::from_str::<GateioOrderBookL1>(test.input).unwrap();
+                let actual = transformer
+                    .transform(input)
+                    .into_iter()
+                    .map(|result| {
+                        let event = result.unwrap();
+                        (
+                            event.instrument,
+                            crate::subscription::number::to_f64(event.kind.best_bid.price),
+                            crate::subscription::number::to_f64(event.kind.best_ask.price),
+                        )
+                    })
+                    .next();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
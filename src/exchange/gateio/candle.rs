@@ -0,0 +1,205 @@
+use crate::{
+    error::DataError,
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::{candle::CandleSource, number::Number, Interval},
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::SubscriptionId};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+
+/// Generate a [`Gateio`](super::Gateio) candle [`SubscriptionId`] from the message channel
+/// (eg/ "spot.candlesticks") and candle name (eg/ "1m_BTC_USDT").
+///
+/// The candle name embeds both the interval and the market, and is identical to the
+/// [`GateioMarket`](super::market::GateioMarket) used to subscribe.
+pub fn gateio_candle_subscription_id(channel: &str, name: &str) -> SubscriptionId {
+    ExchangeSub::from((channel, name)).id()
+}
+
+/// Determine the [`CandleSource`] of a [`Gateio`](super::Gateio) candle using the candle name,
+/// where mark & index price candle names prefix the market with "mark_" or "index_"
+/// (eg/ "1m_mark_BTC_USDT").
+pub fn gateio_candle_source(name: &str) -> CandleSource {
+    let market = name
+        .split_once('_')
+        .map(|(_, market)| market)
+        .unwrap_or_default();
+
+    if market.starts_with(GATEIO_CANDLE_PREFIX_MARK_PRICE) {
+        CandleSource::MarkPrice
+    } else if market.starts_with(GATEIO_CANDLE_PREFIX_INDEX_PRICE) {
+        CandleSource::IndexPrice
+    } else {
+        CandleSource::LastTrade
+    }
+}
+
+/// Candle [`Interval`]s published by the [`GateioSpot`](super::spot::GateioSpot) candles channel.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+pub const GATEIO_SPOT_CANDLE_INTERVALS: [Interval; 10] = [
+    Interval::Minute1,
+    Interval::Minute5,
+    Interval::Minute15,
+    Interval::Minute30,
+    Interval::Hour1,
+    Interval::Hour4,
+    Interval::Hour8,
+    Interval::Day1,
+    Interval::Week1,
+    Interval::Month1,
+];
+
+/// Candle [`Interval`]s published by the [`GateioFuturesUsd`](super::futures::GateioFuturesUsd)
+/// & [`GateioFuturesBtc`](super::futures::GateioFuturesBtc) candles channel, which has no 30d
+/// candles.
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-api>
+pub const GATEIO_FUTURES_CANDLE_INTERVALS: [Interval; 9] = [
+    Interval::Minute1,
+    Interval::Minute5,
+    Interval::Minute15,
+    Interval::Minute30,
+    Interval::Hour1,
+    Interval::Hour4,
+    Interval::Hour8,
+    Interval::Day1,
+    Interval::Week1,
+];
+
+/// [`Gateio`](super::Gateio) futures candle market prefix selecting mark price candles.
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-api>
+pub const GATEIO_CANDLE_PREFIX_MARK_PRICE: &str = "mark_";
+
+/// [`Gateio`](super::Gateio) futures candle market prefix selecting index price candles.
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-api>
+pub const GATEIO_CANDLE_PREFIX_INDEX_PRICE: &str = "index_";
+
+/// Determine the close time of a [`Gateio`](super::Gateio) candle using the candle name
+/// (eg/ "1m_BTC_USDT", "7d_BTC_USDT") and the candle start time.
+///
+/// Consistent with [`Binance`](crate::exchange::binance::Binance), the close time is the last
+/// millisecond of the candle interval (ie/ start + interval - 1ms).
+pub fn gateio_candle_close_time(
+    exchange: ExchangeId,
+    name: &str,
+    start: DateTime<Utc>,
+) -> Result<DateTime<Utc>, DataError> {
+    let unsupported = || {
+        DataError::Socket(SocketError::Unsupported {
+            entity: exchange.as_str(),
+            item: format!("candle name: {name}"),
+        })
+    };
+
+    // Extract interval from candle name (eg/ "10s_BTC_USDT" -> "10s")
+    let (interval, _) = name.split_once('_').ok_or_else(unsupported)?;
+
+    // Split interval into quantity & unit (eg/ "10s" -> 10, 's')
+    let unit = interval.chars().last().ok_or_else(unsupported)?;
+    let quantity = interval[..interval.len() - unit.len_utf8()]
+        .parse::<i64>()
+        .map_err(|_| unsupported())?;
+
+    let interval = match unit {
+        's' => Duration::seconds(quantity),
+        'm' => Duration::minutes(quantity),
+        'h' => Duration::hours(quantity),
+        'd' => Duration::days(quantity),
+        _ => return Err(unsupported()),
+    };
+
+    Ok(start + interval - Duration::milliseconds(1))
+}
+
+/// Deserialize an optional String as an `Option<Number>`.
+pub fn de_option_str_number<'de, D>(deserializer: D) -> Result<Option<Number>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|value| value.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+
+    #[test]
+    fn test_gateio_candle_close_time() {
+        struct TestCase {
+            name: &'static str,
+            expected: Option<DateTime<Utc>>,
+        }
+
+        let start = datetime_utc_from_epoch_duration(std::time::Duration::from_secs(1606292600));
+
+        let tests = vec![
+            TestCase {
+                // TC0: 10 second candle
+                name: "10s_BTC_USDT",
+                expected: Some(start + Duration::seconds(10) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC1: 4 hour candle
+                name: "4h_BTC_USDT",
+                expected: Some(start + Duration::hours(4) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC2: 7 day candle
+                name: "7d_BTC_USD",
+                expected: Some(start + Duration::days(7) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC3: invalid candle name w/o interval
+                name: "BTCUSDT",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = gateio_candle_close_time(ExchangeId::GateioSpot, test.name, start).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_gateio_candle_source() {
+        struct TestCase {
+            name: &'static str,
+            expected: CandleSource,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: last trade candle
+                name: "1m_BTC_USDT",
+                expected: CandleSource::LastTrade,
+            },
+            TestCase {
+                // TC1: mark price candle
+                name: "1m_mark_BTC_USDT",
+                expected: CandleSource::MarkPrice,
+            },
+            TestCase {
+                // TC2: index price candle
+                name: "5m_index_BTC_USDT",
+                expected: CandleSource::IndexPrice,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                gateio_candle_source(test.name),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,5 +1,12 @@
+use super::Gateio;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    error::UnsupportedIntervalError,
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleUpdates, Candles, SourcedCandles},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
     Identifier,
 };
 use barter_integration::model::InstrumentKind;
@@ -22,6 +29,79 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
     pub const FUTURE_PERPETUAL_TRADES: Self = Self("futures.trades");
+
+    /// Gateio [`InstrumentKind::Spot`] OrderBook Level1 (top of book) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+    pub const SPOT_ORDER_BOOK_L1: Self = Self("spot.book_ticker");
+
+    /// Gateio [`InstrumentKind::FuturePerpetual`] OrderBook Level1 (top of book) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+    pub const FUTURE_PERPETUAL_ORDER_BOOK_L1: Self = Self("futures.book_ticker");
+
+    /// Gateio [`InstrumentKind::Spot`] real-time candles channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+    pub const SPOT_CANDLES: Self = Self("spot.candlesticks");
+
+    /// Gateio [`InstrumentKind::FuturePerpetual`] real-time candles channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-api>
+    pub const FUTURE_PERPETUAL_CANDLES: Self = Self("futures.candlesticks");
+
+    /// Determine if the [`GateioChannel`] is a candles channel, which requires the subscription
+    /// payload to be formatted as `[interval, market]`.
+    pub fn is_candles(&self) -> bool {
+        self.0.ends_with(".candlesticks")
+    }
+}
+
+/// [`Gateio`](super::Gateio) candle interval of a Barter [`Interval`], as used in the candle
+/// name (eg/ "7d_BTC_USDT").
+///
+/// Note: only 1m, 5m, 15m, 30m, 1h, 4h, 8h, 1d, 7d & 30d [`Interval`]s are published by any Gateio
+/// server. See [`GATEIO_SPOT_CANDLE_INTERVALS`](super::candle::GATEIO_SPOT_CANDLE_INTERVALS) &
+/// [`GATEIO_FUTURES_CANDLE_INTERVALS`](super::candle::GATEIO_FUTURES_CANDLE_INTERVALS) for the
+/// [`Interval`]s published by each server.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct GateioCandleInterval {
+    /// Candle interval code (eg/ "7d").
+    pub code: &'static str,
+}
+
+impl TryFrom<Interval> for GateioCandleInterval {
+    type Error = UnsupportedIntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let code = match interval {
+            Interval::Minute1 => "1m",
+            Interval::Minute5 => "5m",
+            Interval::Minute15 => "15m",
+            Interval::Minute30 => "30m",
+            Interval::Hour1 => "1h",
+            Interval::Hour4 => "4h",
+            Interval::Hour8 => "8h",
+            Interval::Day1 => "1d",
+            Interval::Week1 => "7d",
+            Interval::Month1 => "30d",
+            Interval::Minute3
+            | Interval::Hour2
+            | Interval::Hour6
+            | Interval::Hour12
+            | Interval::Day3
+            | Interval::Month3 => {
+                return Err(UnsupportedIntervalError {
+                    exchange: "gateio",
+                    interval,
+                })
+            }
+        };
+
+        Ok(Self { code })
+    }
 }
 
 impl<Server> Identifier<GateioChannel> for Subscription<Server, PublicTrades> {
@@ -33,8 +113,113 @@ impl<Server> Identifier<GateioChannel> for Subscription<Server, PublicTrades> {
     }
 }
 
+impl<Server> Identifier<GateioChannel> for Subscription<Gateio<Server>, OrderBooksL1> {
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind {
+            InstrumentKind::Spot => GateioChannel::SPOT_ORDER_BOOK_L1,
+            InstrumentKind::FuturePerpetual => GateioChannel::FUTURE_PERPETUAL_ORDER_BOOK_L1,
+        }
+    }
+}
+
+impl<Server> Identifier<GateioChannel> for Subscription<Gateio<Server>, Candles> {
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind {
+            InstrumentKind::Spot => GateioChannel::SPOT_CANDLES,
+            InstrumentKind::FuturePerpetual => GateioChannel::FUTURE_PERPETUAL_CANDLES,
+        }
+    }
+}
+
+impl<Server> Identifier<GateioChannel> for Subscription<Gateio<Server>, CandleUpdates> {
+    fn id(&self) -> GateioChannel {
+        match self.instrument.kind {
+            InstrumentKind::Spot => GateioChannel::SPOT_CANDLES,
+            InstrumentKind::FuturePerpetual => GateioChannel::FUTURE_PERPETUAL_CANDLES,
+        }
+    }
+}
+
+/// Mark & index price [`SourcedCandles`] are only available via the
+/// [`InstrumentKind::FuturePerpetual`] candles channel.
+impl<Server> Identifier<GateioChannel> for Subscription<Gateio<Server>, SourcedCandles> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_PERPETUAL_CANDLES
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{
+        gateio::{futures::GateioFuturesUsd, spot::GateioSpot},
+        Connector,
+    };
+
+    #[test]
+    fn test_gateio_candle_interval_try_from_interval() {
+        // Expected candle interval of every Interval, in Interval::ALL order
+        let tests = [
+            (Interval::Minute1, Some("1m")),
+            (Interval::Minute3, None),
+            (Interval::Minute5, Some("5m")),
+            (Interval::Minute15, Some("15m")),
+            (Interval::Minute30, Some("30m")),
+            (Interval::Hour1, Some("1h")),
+            (Interval::Hour2, None),
+            (Interval::Hour4, Some("4h")),
+            (Interval::Hour6, None),
+            (Interval::Hour8, Some("8h")),
+            (Interval::Hour12, None),
+            (Interval::Day1, Some("1d")),
+            (Interval::Day3, None),
+            (Interval::Week1, Some("7d")),
+            (Interval::Month1, Some("30d")),
+            (Interval::Month3, None),
+        ];
+        assert_eq!(tests.map(|(interval, _)| interval), Interval::ALL);
+
+        for (interval, expected) in tests {
+            let actual = GateioCandleInterval::try_from(interval);
+            match expected {
+                Some(code) => {
+                    assert_eq!(
+                        actual,
+                        Ok(GateioCandleInterval { code }),
+                        "{interval} failed"
+                    );
+                }
+                None => {
+                    assert_eq!(
+                        actual,
+                        Err(UnsupportedIntervalError {
+                            exchange: "gateio",
+                            interval
+                        }),
+                        "{interval} failed"
+                    );
+                }
+            }
+
+            // GateioSpot supported_intervals are exactly those with a GateioCandleInterval
+            assert_eq!(
+                GateioSpot::supported_intervals().contains(&interval),
+                expected.is_some(),
+                "{interval} failed"
+            );
+
+            // GateioFuturesUsd supports every GateioCandleInterval apart from 30d
+            assert_eq!(
+                GateioFuturesUsd::supported_intervals().contains(&interval),
+                expected.is_some_and(|code| code != "30d"),
+                "{interval} failed"
+            );
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use super::super::{
+    candle::{
+        de_option_str_number, gateio_candle_close_time, gateio_candle_source,
+        gateio_candle_subscription_id,
+    },
+    message::GateioMessage,
+};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        candle::{Candle, ClosedCandles, VolumeDenomination},
+        number::Number,
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioFuturesUsd`](super::GateioFuturesUsd) and
+/// [`GateioFuturesBtc`](super::GateioFuturesBtc) real-time candles WebSocket message.
+pub type GateioFuturesCandles = GateioMessage<Vec<GateioFuturesCandleInner>>;
+
+/// [`GateioFuturesUsd`](super::GateioFuturesUsd) and [`GateioFuturesBtc`](super::GateioFuturesBtc)
+/// real-time candle WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-api>
+/// ```json
+/// {
+///   "t": 1545129300,
+///   "v": 27525555,
+///   "c": "95.4",
+///   "h": "96.9",
+///   "l": "89.5",
+///   "o": "94.3",
+///   "n": "1m_BTC_USD",
+///   "a": "314732.87412",
+///   "w": true
+/// }
+/// ```
+///
+/// ## Notes:
+/// - Unlike [`GateioSpot`](super::super::spot::GateioSpot), candles are delivered in an array,
+///   the start time "t" is an integer, and "v" is the volume in number of contracts.
+/// - The optional "a" is the base currency volume. If not provided, the number of contracts is
+///   used.
+/// - "w" is true when the candle window has closed.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioFuturesCandleInner {
+    #[serde(
+        rename = "t",
+        deserialize_with = "crate::de::de_u64_epoch_s_as_datetime_utc"
+    )]
+    pub start: DateTime<Utc>,
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: Number,
+    #[serde(rename = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: Number,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(rename = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: Number,
+    #[serde(rename = "v")]
+    pub volume_contracts: Number,
+    #[serde(rename = "a", default, deserialize_with = "de_option_str_number")]
+    pub volume: Option<Number>,
+    #[serde(rename = "w", default)]
+    pub closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioFuturesCandles {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .as_ref()
+            .and_then(|data| data.first())
+            .map(|candle| gateio_candle_subscription_id(&self.channel, &candle.name))
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioFuturesCandles)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, candles): (ExchangeId, Instrument, GateioFuturesCandles),
+    ) -> Self {
+        candles
+            .data
+            .into_iter()
+            .flatten()
+            .map(|candle| {
+                let close_time = gateio_candle_close_time(exchange_id, &candle.name, candle.start)?;
+
+                // Fallback to the earlier of the close & received time if no message time is
+                // provided
+                let received_time = Utc::now();
+
+                Ok(MarketEvent {
+                    exchange_time: candles.time.unwrap_or(close_time.min(received_time)),
+                    received_time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: Candle {
+                        start_time: candle.start,
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume.unwrap_or(candle.volume_contracts),
+                        volume_denomination: match candle.volume {
+                            Some(_) => VolumeDenomination::Base,
+                            None => VolumeDenomination::Contracts,
+                        },
+                        base_volume: candle.volume,
+                        quote_volume: None,
+                        taker_buy_volume: None,
+                        taker_buy_quote_volume: None,
+                        trade_count: 0,
+                        closed: candle.closed,
+                        source: gateio_candle_source(&candle.name),
+                        synthetic: false,
+                        derived: false,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, ClosedCandles<GateioFuturesCandles>)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, ClosedCandles(candles)): (
+            ExchangeId,
+            Instrument,
+            ClosedCandles<GateioFuturesCandles>,
+        ),
+    ) -> Self {
+        Self::from((exchange_id, instrument, candles)).retain_closed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::candle::CandleSource;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_message_futures_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<GateioFuturesCandles, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid in progress candle w/o base volume or window closed flag
+                    input: r#"
+                    {
+                        "time": 1542162490,
+                        "channel": "futures.candlesticks",
+                        "event": "update",
+                        "error": null,
+                        "result": [
+                            {
+                                "t": 1545129300, "v": 27525555, "c": "95.4", "h": "96.9",
+                                "l": "89.5", "o": "94.3", "n": "1m_BTC_USD"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(GateioFuturesCandles {
+                        time: None,
+                        channel: "futures.candlesticks".to_string(),
+                        error: None,
+                        data: Some(vec![GateioFuturesCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1545129300,
+                            )),
+                            name: "1m_BTC_USD".to_string(),
+                            open: from_f64(94.3),
+                            high: from_f64(96.9),
+                            low: from_f64(89.5),
+                            close: from_f64(95.4),
+                            volume_contracts: from_f64(27525555.0),
+                            volume: None,
+                            closed: false,
+                        }]),
+                    }),
+                },
+                TestCase {
+                    // TC1: valid closed candle w/ base volume
+                    input: r#"
+                    {
+                        "time": 1542162490,
+                        "channel": "futures.candlesticks",
+                        "event": "update",
+                        "error": null,
+                        "result": [
+                            {
+                                "t": 1545129300, "v": 27525555, "c": "95.4", "h": "96.9",
+                                "l": "89.5", "o": "94.3", "n": "1m_BTC_USDT",
+                                "a": "314732.87412", "w": true
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(GateioFuturesCandles {
+                        time: None,
+                        channel: "futures.candlesticks".to_string(),
+                        error: None,
+                        data: Some(vec![GateioFuturesCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1545129300,
+                            )),
+                            name: "1m_BTC_USDT".to_string(),
+                            open: from_f64(94.3),
+                            high: from_f64(96.9),
+                            low: from_f64(89.5),
+                            close: from_f64(95.4),
+                            volume_contracts: from_f64(27525555.0),
+                            volume: Some(from_f64(314732.87412)),
+                            closed: true,
+                        }]),
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid candle w/ string start time
+                    input: r#"
+                    {
+                        "time": 1542162490,
+                        "channel": "futures.candlesticks",
+                        "event": "update",
+                        "error": null,
+                        "result": [
+                            {
+                                "t": "1545129300", "v": 27525555, "c": "95.4", "h": "96.9",
+                                "l": "89.5", "o": "94.3", "n": "1m_BTC_USD"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioFuturesCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_futures_candles_to_market_iter() {
+        let candles = serde_json::from_str::<GateioFuturesCandles>(
+            r#"
+            {
+                "time": 1542162490,
+                "channel": "futures.candlesticks",
+                "event": "update",
+                "error": null,
+                "result": [
+                    {
+                        "t": 1545129300, "v": 100, "c": "95.4", "h": "96.9", "l": "89.5",
+                        "o": "94.3", "n": "1m_BTC_USDT", "a": "1.5", "w": true
+                    },
+                    {
+                        "t": 1545129360, "v": 100, "c": "95.4", "h": "96.9", "l": "89.5",
+                        "o": "94.3", "n": "1m_BTC_USDT", "a": "1.5", "w": false
+                    }
+                ]
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            candles.id(),
+            Some(SubscriptionId::from("futures.candlesticks|1m_BTC_USDT"))
+        );
+
+        let instrument = Instrument::from((
+            "btc",
+            "usdt",
+            barter_integration::model::InstrumentKind::FuturePerpetual,
+        ));
+        let actual =
+            MarketIter::<Candle>::from((ExchangeId::GateioFuturesUsd, instrument, candles)).0;
+
+        // Both candles are yielded, w/ base currency volume & window closed mapped to closed
+        assert_eq!(actual.len(), 2);
+        let candle = actual[0].as_ref().unwrap();
+        assert_eq!(candle.kind.volume, from_f64(1.5));
+        assert!(candle.kind.closed);
+        assert!(!actual[1].as_ref().unwrap().kind.closed);
+        assert_eq!(
+            candle.kind.close_time,
+            barter_integration::de::datetime_utc_from_epoch_duration(
+                std::time::Duration::from_millis(1545129359999)
+            )
+        );
+        assert_eq!(
+            candle.kind.start_time,
+            barter_integration::de::datetime_utc_from_epoch_duration(
+                std::time::Duration::from_secs(1545129300)
+            )
+        );
+    }
+
+    #[test]
+    fn test_gateio_futures_candles_source() {
+        struct TestCase {
+            input: &'static str,
+            expected_id: &'static str,
+            expected_source: CandleSource,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: last trade candle
+                input: r#"{"time":1542162490,"channel":"futures.candlesticks","event":"update","error":null,"result":[{"t":1545129300,"v":100,"c":"95.4","h":"96.9","l":"89.5","o":"94.3","n":"1m_BTC_USDT","a":"1.5","w":true}]}"#,
+                expected_id: "futures.candlesticks|1m_BTC_USDT",
+                expected_source: CandleSource::LastTrade,
+            },
+            TestCase {
+                // TC1: mark price candle w/o volume
+                input: r#"{"time":1542162490,"channel":"futures.candlesticks","event":"update","error":null,"result":[{"t":1545129300,"v":0,"c":"95.41","h":"96.92","l":"89.53","o":"94.34","n":"1m_mark_BTC_USDT","w":false}]}"#,
+                expected_id: "futures.candlesticks|1m_mark_BTC_USDT",
+                expected_source: CandleSource::MarkPrice,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let candles = serde_json::from_str::<GateioFuturesCandles>(test.input).unwrap();
+            assert_eq!(
+                candles.id(),
+                Some(SubscriptionId::from(test.expected_id)),
+                "TC{} failed",
+                index
+            );
+
+            let instrument = Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::InstrumentKind::FuturePerpetual,
+            ));
+            let actual =
+                MarketIter::<Candle>::from((ExchangeId::GateioFuturesUsd, instrument, candles)).0;
+            assert_eq!(
+                actual[0].as_ref().unwrap().kind.source,
+                test.expected_source,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
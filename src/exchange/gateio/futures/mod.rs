@@ -1,13 +1,20 @@
-use self::trade::GateioFuturesTrades;
-use super::Gateio;
+use self::{candle::GateioFuturesCandles, trade::GateioFuturesTrades};
+use super::{book::GateioOrderBookL1, Gateio};
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleUpdates, Candles, ClosedCandles, SourcedCandles},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
 use serde::{Deserialize, Serialize};
 
+/// Candle types.
+pub mod candle;
+
 /// Public trades types.
 pub mod trade;
 
@@ -35,6 +42,24 @@ impl StreamSelector<PublicTrades> for GateioFuturesUsd {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
 
+impl StreamSelector<OrderBooksL1> for GateioFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<Candles> for GateioFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Candles, ClosedCandles<GateioFuturesCandles>>>;
+}
+
+impl StreamSelector<CandleUpdates> for GateioFuturesUsd {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, CandleUpdates, GateioFuturesCandles>>;
+}
+
+impl StreamSelector<SourcedCandles> for GateioFuturesUsd {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, SourcedCandles, GateioFuturesCandles>>;
+}
+
 /// [`GateioFuturesBtc`] WebSocket server base url.
 ///
 /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/>
@@ -60,3 +85,21 @@ impl ExchangeServer for GateioServerFuturesBtc {
 impl StreamSelector<PublicTrades> for GateioFuturesBtc {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioFuturesTrades>>;
 }
+
+impl StreamSelector<OrderBooksL1> for GateioFuturesBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<Candles> for GateioFuturesBtc {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Candles, ClosedCandles<GateioFuturesCandles>>>;
+}
+
+impl StreamSelector<CandleUpdates> for GateioFuturesBtc {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, CandleUpdates, GateioFuturesCandles>>;
+}
+
+impl StreamSelector<SourcedCandles> for GateioFuturesBtc {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, SourcedCandles, GateioFuturesCandles>>;
+}
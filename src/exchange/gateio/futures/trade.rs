@@ -2,7 +2,10 @@ use super::super::message::GateioMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::{
+        number::Number,
+        trade::{PublicTrade, TakerSide},
+    },
     Identifier,
 };
 use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
@@ -34,20 +37,21 @@ pub struct GateioFuturesTradeInner {
     pub market: String,
     #[serde(
         rename = "create_time_ms",
-        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::de::de_fractional_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "size")]
-    pub amount: f64,
+    pub amount: Number,
 }
 
 impl Identifier<Option<SubscriptionId>> for GateioFuturesTrades {
     fn id(&self) -> Option<SubscriptionId> {
         self.data
-            .first()
+            .as_ref()
+            .and_then(|data| data.first())
             .map(|trade| ExchangeSub::from((&self.channel, &trade.market)).id())
     }
 }
@@ -59,21 +63,23 @@ impl From<(ExchangeId, Instrument, GateioFuturesTrades)> for MarketIter<PublicTr
         trades
             .data
             .into_iter()
+            .flatten()
             .map(|trade| {
                 Ok(MarketEvent {
                     exchange_time: trade.time,
                     received_time: Utc::now(),
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
                     kind: PublicTrade {
                         id: trade.id.to_string(),
                         price: trade.price,
-                        amount: trade.amount,
-                        side: if trade.amount.is_sign_positive() {
-                            Side::Buy
-                        } else {
-                            Side::Sell
-                        },
+                        amount: trade.amount.abs(),
+                        side: Side::from_signed_amount(trade.amount),
+                        buyer_order_id: None,
+                        seller_order_id: None,
+                        taker_order_kind: None,
                     },
                 })
             })
@@ -93,5 +99,110 @@ mod tests {
             let input = "{\"time\":1669843487,\"time_ms\":1669843487733,\"channel\":\"futures.trades\",\"event\":\"update\",\"result\":[{\"contract\":\"ETH_USDT\",\"create_time\":1669843487,\"create_time_ms\":1669843487724,\"id\":180276616,\"price\":\"1287\",\"size\":3}]}";
             serde_json::from_str::<GateioFuturesTrades>(input).unwrap();
         }
+
+        #[test]
+        fn test_gateio_futures_trade_id_and_time_precision() {
+            struct TestCase {
+                input: &'static str,
+                expected_id: &'static str,
+                expected_time: &'static str,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: whole millisecond create_time_ms
+                    input: r#"{"time":1669843487,"time_ms":1669843487733,"channel":"futures.trades","event":"update","result":[{"contract":"ETH_USDT","create_time":1669843487,"create_time_ms":1669843487724,"id":180276616,"price":"1287","size":3}]}"#,
+                    expected_id: "180276616",
+                    expected_time: "2022-11-30T21:24:47.724Z",
+                },
+                TestCase {
+                    // TC1: fractional create_time_ms keeps microsecond precision & id above 2^53
+                    // is not rounded
+                    input: r#"{"time":1669843487,"time_ms":1669843487733,"channel":"futures.trades","event":"update","result":[{"contract":"ETH_USDT","create_time":1669843487,"create_time_ms":1669843487724.125,"id":9007199254740993,"price":"1287","size":-3}]}"#,
+                    expected_id: "9007199254740993",
+                    expected_time: "2022-11-30T21:24:47.724125Z",
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let trades = serde_json::from_str::<GateioFuturesTrades>(test.input).unwrap();
+                let instrument = Instrument::from((
+                    "eth",
+                    "usdt",
+                    barter_integration::model::InstrumentKind::FuturePerpetual,
+                ));
+                let MarketIter(events) = MarketIter::<PublicTrade>::from((
+                    ExchangeId::GateioFuturesUsd,
+                    instrument,
+                    trades,
+                ));
+                let event = events.into_iter().next().unwrap().unwrap();
+
+                assert_eq!(event.kind.id, test.expected_id, "TC{} failed", index);
+                assert_eq!(
+                    event.exchange_time,
+                    test.expected_time.parse::<DateTime<Utc>>().unwrap(),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    mod transformer {
+        use super::*;
+        use crate::{
+            exchange::gateio::futures::GateioFuturesUsd,
+            subscription::{trade::PublicTrades, Map},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::InstrumentKind, Transformer};
+        use std::collections::HashMap;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn test_gateio_futures_pong_is_swallowed() {
+            let instrument_map = Map(HashMap::from([(
+                SubscriptionId::from("futures.trades|ETH_USDT"),
+                Instrument::from(("eth", "usdt", InstrumentKind::FuturePerpetual)),
+            )]));
+            let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+            let mut transformer = <StatelessTransformer<
+                GateioFuturesUsd,
+                PublicTrades,
+                GateioFuturesTrades,
+            > as ExchangeTransformer<GateioFuturesUsd, PublicTrades>>::new(
+                ws_sink_tx,
+                instrument_map,
+            )
+            .await
+            .unwrap();
+
+            struct TestCase {
+                input: &'static str,
+                expected_events: usize,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: pong answering an application-level ping yields no events or errors
+                    input: r#"{"time":1545404023,"time_ms":1545404023123,"channel":"futures.pong","event":"","error":null,"result":null}"#,
+                    expected_events: 0,
+                },
+                TestCase {
+                    // TC1: trades following the pong are transformed
+                    input: r#"{"time":1669843487,"time_ms":1669843487733,"channel":"futures.trades","event":"update","result":[{"contract":"ETH_USDT","create_time":1669843487,"create_time_ms":1669843487724,"id":180276616,"price":"1287","size":3}]}"#,
+                    expected_events: 1,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let input = serde_json::from_str::<GateioFuturesTrades>(test.input).unwrap();
+                let actual = transformer.transform(input);
+                assert_eq!(actual.len(), test.expected_events, "TC{} failed", index);
+                assert!(actual.iter().all(Result::is_ok), "TC{} failed", index);
+            }
+        }
     }
 }
@@ -1,5 +1,18 @@
-use super::Gateio;
-use crate::{subscription::Subscription, Identifier};
+use super::{
+    candle::{GATEIO_CANDLE_PREFIX_INDEX_PRICE, GATEIO_CANDLE_PREFIX_MARK_PRICE},
+    channel::{GateioCandleInterval, GateioChannel},
+    Gateio,
+};
+use crate::{
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleSource, CandleUpdates, Candles, CandlesFromTrades, SourcedCandles},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
+    Identifier,
+};
+use barter_integration::model::Instrument;
 use serde::{Deserialize, Serialize};
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
@@ -9,14 +22,310 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct GateioMarket(pub String);
 
-impl<Server, Kind> Identifier<GateioMarket> for Subscription<Gateio<Server>, Kind> {
+impl<Server> Identifier<GateioMarket> for Subscription<Gateio<Server>, PublicTrades> {
     fn id(&self) -> GateioMarket {
         GateioMarket(format!("{}_{}", self.instrument.base, self.instrument.quote).to_uppercase())
     }
 }
 
+impl<Server> Identifier<GateioMarket> for Subscription<Gateio<Server>, CandlesFromTrades> {
+    fn id(&self) -> GateioMarket {
+        GateioMarket(format!("{}_{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl<Server> Identifier<GateioMarket> for Subscription<Gateio<Server>, OrderBooksL1> {
+    fn id(&self) -> GateioMarket {
+        GateioMarket(format!("{}_{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl<Server> Identifier<GateioMarket> for Subscription<Gateio<Server>, Candles> {
+    fn id(&self) -> GateioMarket {
+        GateioMarket::candles(self.kind.0, &self.instrument)
+    }
+}
+
+impl<Server> Identifier<GateioMarket> for Subscription<Gateio<Server>, CandleUpdates> {
+    fn id(&self) -> GateioMarket {
+        GateioMarket::candles(self.kind.0, &self.instrument)
+    }
+}
+
+impl<Server> Identifier<GateioMarket> for Subscription<Gateio<Server>, SourcedCandles> {
+    fn id(&self) -> GateioMarket {
+        let SourcedCandles(interval, source) = self.kind;
+        GateioMarket::sourced_candles(interval, source, &self.instrument)
+    }
+}
+
+impl GateioMarket {
+    /// Construct a [`GateioMarket`] for a candles channel. Gateio candle markets embed the
+    /// interval (eg/ "1m_BTC_USDT"), which is identical to the candle name "n" field included in
+    /// each candle update.
+    ///
+    /// Note: Gateio supports a limited set of candle intervals (see [`GateioCandleInterval`]),
+    /// so subscriptions to any other [`Interval`] are rejected during validation.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+    pub fn candles(interval: Interval, instrument: &Instrument) -> Self {
+        Self::sourced_candles(interval, CandleSource::LastTrade, instrument)
+    }
+
+    /// Construct a [`GateioMarket`] for a futures candles channel using the provided
+    /// [`CandleSource`]. Mark & index price candle markets prefix the market with "mark_" or
+    /// "index_" (eg/ "1m_mark_BTC_USDT").
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#candlesticks-api>
+    pub fn sourced_candles(
+        interval: Interval,
+        source: CandleSource,
+        instrument: &Instrument,
+    ) -> Self {
+        // Unsupported Intervals are rejected during Subscription validation
+        let interval = GateioCandleInterval::try_from(interval).map_or_else(
+            |_| interval.to_string(),
+            |interval| interval.code.to_owned(),
+        );
+
+        let prefix = match source {
+            CandleSource::LastTrade => "",
+            CandleSource::MarkPrice => GATEIO_CANDLE_PREFIX_MARK_PRICE,
+            CandleSource::IndexPrice => GATEIO_CANDLE_PREFIX_INDEX_PRICE,
+        };
+
+        Self(format!(
+            "{interval}_{prefix}{}_{}",
+            instrument.base.as_ref().to_uppercase(),
+            instrument.quote.as_ref().to_uppercase()
+        ))
+    }
+
+    /// Construct the subscription payload for this [`GateioMarket`].
+    ///
+    /// Candle channels require the payload `[interval, market]` (eg/ `["1m", "BTC_USDT"]`),
+    /// whereas all other channels require `[market]` (eg/ `["BTC_USDT"]`).
+    pub fn payload(&self, channel: &GateioChannel) -> Vec<&str> {
+        match self.0.split_once('_') {
+            Some((interval, market)) if channel.is_candles() => vec![interval, market],
+            _ => vec![self.as_ref()],
+        }
+    }
+}
+
 impl AsRef<str> for GateioMarket {
     fn as_ref(&self) -> &str {
         &self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::gateio::{futures::GateioFuturesUsd, spot::GateioSpot},
+        subscription::SubKind,
+    };
+    use barter_integration::{error::SocketError, model::InstrumentKind, Validator};
+
+    #[test]
+    fn test_gateio_market_payload() {
+        struct TestCase {
+            channel: GateioChannel,
+            market: GateioMarket,
+            expected: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: GateioSpot PublicTrades
+                channel: GateioChannel::SPOT_TRADES,
+                market: Subscription::from((
+                    GateioSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+                .id(),
+                expected: vec!["BTC_USDT"],
+            },
+            TestCase {
+                // TC1: GateioSpot Candles
+                channel: GateioChannel::SPOT_CANDLES,
+                market: Subscription::from((
+                    GateioSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    Candles(Interval::Minute1),
+                ))
+                .id(),
+                expected: vec!["1m", "BTC_USDT"],
+            },
+            TestCase {
+                // TC2: GateioFuturesUsd Candles w/ Week1 mapped to "7d"
+                channel: GateioChannel::FUTURE_PERPETUAL_CANDLES,
+                market: Subscription::from((
+                    GateioFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    Candles(Interval::Week1),
+                ))
+                .id(),
+                expected: vec!["7d", "BTC_USDT"],
+            },
+            TestCase {
+                // TC3: GateioFuturesUsd mark price SourcedCandles
+                channel: GateioChannel::FUTURE_PERPETUAL_CANDLES,
+                market: Subscription::from((
+                    GateioFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    SourcedCandles(Interval::Minute1, CandleSource::MarkPrice),
+                ))
+                .id(),
+                expected: vec!["1m", "mark_BTC_USDT"],
+            },
+            TestCase {
+                // TC4: GateioFuturesUsd index price SourcedCandles
+                channel: GateioChannel::FUTURE_PERPETUAL_CANDLES,
+                market: Subscription::from((
+                    GateioFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    SourcedCandles(Interval::Minute5, CandleSource::IndexPrice),
+                ))
+                .id(),
+                expected: vec!["5m", "index_BTC_USDT"],
+            },
+            TestCase {
+                // TC5: GateioFuturesUsd OrderBooksL1
+                channel: GateioChannel::FUTURE_PERPETUAL_ORDER_BOOK_L1,
+                market: Subscription::from((
+                    GateioFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    OrderBooksL1,
+                ))
+                .id(),
+                expected: vec!["BTC_USDT"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.market.payload(&test.channel);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+    #[test]
+    fn test_gateio_futures_usd_candle_intervals() {
+        // Exhaustive so that adding an Interval variant requires its expected channel here
+        fn expected(interval: Interval) -> Result<&'static str, ()> {
+            match interval {
+                Interval::Minute1 => Ok("1m_BTC_USDT"),
+                Interval::Minute5 => Ok("5m_BTC_USDT"),
+                Interval::Minute15 => Ok("15m_BTC_USDT"),
+                Interval::Minute30 => Ok("30m_BTC_USDT"),
+                Interval::Hour1 => Ok("1h_BTC_USDT"),
+                Interval::Hour4 => Ok("4h_BTC_USDT"),
+                Interval::Hour8 => Ok("8h_BTC_USDT"),
+                Interval::Day1 => Ok("1d_BTC_USDT"),
+                Interval::Week1 => Ok("7d_BTC_USDT"),
+                Interval::Minute3
+                | Interval::Hour2
+                | Interval::Hour6
+                | Interval::Hour12
+                | Interval::Day3
+                | Interval::Month1
+                | Interval::Month3 => Err(()),
+            }
+        }
+
+        let supported = "1m, 5m, 15m, 30m, 1h, 4h, 8h, 1d, 1w";
+
+        for (index, interval) in Interval::ALL.into_iter().enumerate() {
+            let subscription = Subscription::from((
+                GateioFuturesUsd::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::FuturePerpetual,
+                Candles(interval),
+            ));
+            let actual = subscription.kind.validate_exchange::<GateioFuturesUsd>();
+
+            match (actual, expected(interval)) {
+                (Ok(()), Ok(expected)) => {
+                    let channel: GateioChannel = subscription.id();
+                    let market: GateioMarket = subscription.id();
+                    assert_eq!(
+                        channel,
+                        GateioChannel::FUTURE_PERPETUAL_CANDLES,
+                        "TC{} failed",
+                        index
+                    );
+                    assert_eq!(market.as_ref(), expected, "TC{} failed", index);
+                }
+                (Err(SocketError::Unsupported { entity, item }), Err(())) => {
+                    assert_eq!(entity, "gateio_futures_usd", "TC{} failed", index);
+                    assert_eq!(
+                        item,
+                        format!(
+                            "candle interval {interval} (supported candle intervals: {supported})"
+                        ),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_spot_candle_intervals() {
+        struct TestCase {
+            input: Interval,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: GateioSpot Week1 mapped to "7d"
+                input: Interval::Week1,
+                expected: Some("7d_BTC_USDT"),
+            },
+            TestCase {
+                // TC1: GateioSpot Month1 mapped to "30d"
+                input: Interval::Month1,
+                expected: Some("30d_BTC_USDT"),
+            },
+            TestCase {
+                // TC2: GateioSpot does not support 3M candles
+                input: Interval::Month3,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription = Subscription::from((
+                GateioSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(test.input),
+            ));
+            let actual = subscription
+                .validate()
+                .ok()
+                .map(|subscription| Identifier::<GateioMarket>::id(subscription).0);
+            assert_eq!(actual.as_deref(), test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// [`Gateio`](super::Gateio) WebSocket message.
@@ -53,13 +54,34 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// #### Spot Pong
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#application-ping-pong>
+/// ```json
+/// {
+///     "time": 1545404023,
+///     "time_ms": 1545404023123,
+///     "channel": "spot.pong",
+///     "event": "",
+///     "result": null
+/// }
+/// ```
+///
 /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#public-trades-channel>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct GateioMessage<T> {
+    /// Time the message was generated by the exchange server, if provided.
+    #[serde(
+        rename = "time_ms",
+        default,
+        deserialize_with = "crate::de::de_option_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: Option<DateTime<Utc>>,
     pub channel: String,
     pub error: Option<GateioError>,
+    /// Message data, which is `None` for pongs answering the application-level pings sent via
+    /// [`Connector::ping_interval`](crate::exchange::Connector::ping_interval).
     #[serde(rename = "result")]
-    pub data: T,
+    pub data: Option<T>,
 }
 
 /// [`Gateio`](super::Gateio) WebSocket error message.
@@ -70,3 +92,18 @@ pub struct GateioError {
     pub code: u8,
     pub message: String,
 }
+
+impl GateioError {
+    /// Description of the [`Gateio`](super::Gateio) error code.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-response>
+    pub fn description(&self) -> &'static str {
+        match self.code {
+            1 => "invalid request body format",
+            2 => "invalid argument provided",
+            3 => "server side error",
+            4 => "authentication failure",
+            _ => "unknown error",
+        }
+    }
+}
@@ -1,13 +1,30 @@
-use self::{channel::GateioChannel, market::GateioMarket, subscription::GateioSubResponse};
+use self::{
+    candle::{GATEIO_FUTURES_CANDLE_INTERVALS, GATEIO_SPOT_CANDLE_INTERVALS},
+    channel::GateioChannel,
+    market::GateioMarket,
+    subscription::GateioSubResponse,
+};
 use crate::{
-    exchange::{subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer},
+    decoder::PlainText,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, ExchangeServer, PingInterval},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::Interval,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use serde_json::json;
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
 use url::Url;
 
+/// OrderBook Level1 (top of book) types common to [`GateioSpot`](spot::GateioSpot),
+/// [`GateioFuturesUsd`](futures::GateioFuturesUsd) and
+/// [`GateioFuturesBtc`](futures::GateioFuturesBtc).
+pub mod book;
+
+/// Candle types common to [`GateioSpot`](spot::GateioSpot),
+/// [`GateioFuturesUsd`](futures::GateioFuturesUsd) and
+/// [`GateioFuturesBtc`](futures::GateioFuturesBtc).
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -36,6 +53,12 @@ pub mod spot;
 /// [`GateioFuturesBtc`](futures::GateioFuturesBtc).
 pub mod subscription;
 
+/// [`Gateio`] interval between application-level pings, keeping quiet connections alive since
+/// the server drops connections that have not sent a message recently.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#application-ping-pong>
+pub const PING_INTERVAL_GATEIO: Duration = Duration::from_secs(15);
+
 /// Generic [`Gateio<Server>`](Gateio) exchange.
 ///
 /// ### Notes
@@ -57,29 +80,77 @@ where
     type Subscriber = WebSocketSubscriber;
     type SubValidator = WebSocketSubValidator;
     type SubResponse = GateioSubResponse;
+    type Decoder = PlainText;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
-    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
+    fn supported_intervals() -> &'static [Interval] {
+        match Server::ID {
+            ExchangeId::GateioSpot => &GATEIO_SPOT_CANDLE_INTERVALS,
+            _ => &GATEIO_FUTURES_CANDLE_INTERVALS,
+        }
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_GATEIO),
+            ping: || {
                 WsMessage::Text(
                     json!({
-                        "time": chrono::Utc::now().timestamp_millis(),
-                        "channel": channel.as_ref(),
-                        "event": "subscribe",
-                        "payload": [market.as_ref()]
+                        "time": chrono::Utc::now().timestamp(),
+                        "channel": gateio_ping_channel(Server::ID),
                     })
                     .to_string(),
                 )
-            })
-            .collect()
+            },
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        gateio_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        gateio_requests("unsubscribe", exchange_subs)
     }
 }
 
+/// [`Gateio`] application-level ping channel of the provided [`ExchangeId`] server.
+///
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#ping-and-pong>
+fn gateio_ping_channel(exchange: ExchangeId) -> &'static str {
+    match exchange {
+        ExchangeId::GateioSpot => "spot.ping",
+        _ => "futures.ping",
+    }
+}
+
+/// Construct the [`Gateio`] [`WsMessage`] payloads that action the provided `event` (eg/
+/// "subscribe", "unsubscribe") for a collection of [`ExchangeSub`]s.
+fn gateio_requests(
+    event: &str,
+    exchange_subs: Vec<ExchangeSub<GateioChannel, GateioMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            WsMessage::Text(
+                json!({
+                    "time": chrono::Utc::now().timestamp_millis(),
+                    "channel": channel.as_ref(),
+                    "event": event,
+                    "payload": market.payload(&channel)
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Gateio<Server>
 where
     Server: ExchangeServer,
@@ -0,0 +1,361 @@
+use super::super::{
+    candle::{de_option_str_number, gateio_candle_close_time, gateio_candle_subscription_id},
+    message::GateioMessage,
+};
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        candle::{Candle, CandleSource, ClosedCandles, VolumeDenomination},
+        number::{self, Number},
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`GateioSpot`](super::GateioSpot) real-time candles WebSocket message.
+pub type GateioSpotCandle = GateioMessage<GateioSpotCandleInner>;
+
+/// [`GateioSpot`](super::GateioSpot) real-time candle WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#candlesticks-channel>
+/// ```json
+/// {
+///   "t": "1606292600",
+///   "v": "2362.32035",
+///   "c": "19128.1",
+///   "h": "19128.1",
+///   "l": "19128.1",
+///   "o": "19128.1",
+///   "n": "1m_BTC_USDT",
+///   "a": "0.1234",
+///   "w": true
+/// }
+/// ```
+///
+/// ## Notes:
+/// - "v" is the quote currency volume, whereas the optional "a" is the base currency volume. If
+///   "a" is not provided, the base currency volume is approximated as "v" / "c" (or zero if "c"
+///   is zero).
+/// - "w" is true when the candle window has closed.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioSpotCandleInner {
+    #[serde(
+        rename = "t",
+        deserialize_with = "crate::de::de_str_f64_epoch_s_as_datetime_utc"
+    )]
+    pub start: DateTime<Utc>,
+    #[serde(rename = "n")]
+    pub name: String,
+    #[serde(rename = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: Number,
+    #[serde(rename = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: Number,
+    #[serde(rename = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: Number,
+    #[serde(rename = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: Number,
+    #[serde(rename = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume_quote: Number,
+    #[serde(rename = "a", default, deserialize_with = "de_option_str_number")]
+    pub volume: Option<Number>,
+    #[serde(rename = "w", default)]
+    pub closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioSpotCandle {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .as_ref()
+            .map(|candle| gateio_candle_subscription_id(&self.channel, &candle.name))
+    }
+}
+
+impl From<(ExchangeId, Instrument, GateioSpotCandle)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, message): (ExchangeId, Instrument, GateioSpotCandle),
+    ) -> Self {
+        let Some(candle) = message.data else {
+            return Self(vec![]);
+        };
+
+        let close_time = match gateio_candle_close_time(exchange_id, &candle.name, candle.start) {
+            Ok(close_time) => close_time,
+            Err(error) => return Self(vec![Err(error)]),
+        };
+
+        // Fallback to the earlier of the close & received time if no message time is provided
+        let received_time = Utc::now();
+
+        Self(vec![Ok(MarketEvent {
+            exchange_time: message.time.unwrap_or(close_time.min(received_time)),
+            received_time,
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: Candle {
+                start_time: candle.start,
+                close_time,
+                open: candle.open,
+                high: candle.high,
+                low: candle.low,
+                close: candle.close,
+                volume: candle.volume.unwrap_or_else(|| {
+                    if candle.close == number::ZERO {
+                        number::ZERO
+                    } else {
+                        candle.volume_quote / candle.close
+                    }
+                }),
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: candle.volume,
+                quote_volume: Some(candle.volume_quote),
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 0,
+                closed: candle.closed,
+                source: CandleSource::LastTrade,
+                synthetic: false,
+                derived: false,
+            },
+        })])
+    }
+}
+
+impl From<(ExchangeId, Instrument, ClosedCandles<GateioSpotCandle>)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, ClosedCandles(candles)): (
+            ExchangeId,
+            Instrument,
+            ClosedCandles<GateioSpotCandle>,
+        ),
+    ) -> Self {
+        Self::from((exchange_id, instrument, candles)).retain_closed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use serde::de::Error;
+        use std::time::Duration;
+
+        #[test]
+        fn test_gateio_message_spot_candle() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<GateioSpotCandle, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid in progress candle w/o base volume or window closed flag
+                    input: r#"
+                    {
+                        "time": 1606292600,
+                        "time_ms": 1606292600376,
+                        "channel": "spot.candlesticks",
+                        "event": "update",
+                        "result": {
+                            "t": "1606292580", "v": "2362.32035", "c": "19128.1",
+                            "h": "19128.1", "l": "19128.1", "o": "19128.1", "n": "1m_BTC_USDT"
+                        }
+                    }
+                    "#,
+                    expected: Ok(GateioSpotCandle {
+                        time: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1606292600376,
+                        ))),
+                        channel: "spot.candlesticks".to_string(),
+                        error: None,
+                        data: Some(GateioSpotCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1606292580,
+                            )),
+                            name: "1m_BTC_USDT".to_string(),
+                            open: from_f64(19128.1),
+                            high: from_f64(19128.1),
+                            low: from_f64(19128.1),
+                            close: from_f64(19128.1),
+                            volume_quote: from_f64(2362.32035),
+                            volume: None,
+                            closed: false,
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC1: valid closed candle w/ base volume
+                    input: r#"
+                    {
+                        "time": 1606292640,
+                        "time_ms": 1606292640376,
+                        "channel": "spot.candlesticks",
+                        "event": "update",
+                        "result": {
+                            "t": "1606292580", "v": "2362.32035", "c": "19128.1",
+                            "h": "19130.0", "l": "19120.5", "o": "19125.0", "n": "1m_BTC_USDT",
+                            "a": "0.1235", "w": true
+                        }
+                    }
+                    "#,
+                    expected: Ok(GateioSpotCandle {
+                        time: Some(datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1606292640376,
+                        ))),
+                        channel: "spot.candlesticks".to_string(),
+                        error: None,
+                        data: Some(GateioSpotCandleInner {
+                            start: datetime_utc_from_epoch_duration(Duration::from_secs(
+                                1606292580,
+                            )),
+                            name: "1m_BTC_USDT".to_string(),
+                            open: from_f64(19125.0),
+                            high: from_f64(19130.0),
+                            low: from_f64(19120.5),
+                            close: from_f64(19128.1),
+                            volume_quote: from_f64(2362.32035),
+                            volume: Some(from_f64(0.1235)),
+                            closed: true,
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid candle w/ non-numeric close price
+                    input: r#"
+                    {
+                        "time": 1606292600,
+                        "time_ms": 1606292600376,
+                        "channel": "spot.candlesticks",
+                        "event": "update",
+                        "result": {
+                            "t": "1606292580", "v": "2362.32035", "c": "gibberish",
+                            "h": "19128.1", "l": "19128.1", "o": "19128.1", "n": "1m_BTC_USDT"
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC3: invalid candle w/ out of range message time_ms fails rather than panics
+                    input: r#"
+                    {
+                        "time": 1606292600,
+                        "time_ms": 18446744073709551615,
+                        "channel": "spot.candlesticks",
+                        "event": "update",
+                        "result": {
+                            "t": "1606292580", "v": "2362.32035", "c": "19128.1",
+                            "h": "19128.1", "l": "19128.1", "o": "19128.1", "n": "1m_BTC_USDT"
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioSpotCandle>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_spot_candle_subscription_id() {
+        let candle = serde_json::from_str::<GateioSpotCandle>(
+            r#"
+            {
+                "time": 1606292600,
+                "channel": "spot.candlesticks",
+                "event": "update",
+                "result": {
+                    "t": "1606292580", "v": "2362.32035", "c": "19128.1",
+                    "h": "19128.1", "l": "19128.1", "o": "19128.1", "n": "1m_BTC_USDT"
+                }
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            candle.id(),
+            Some(SubscriptionId::from("spot.candlesticks|1m_BTC_USDT"))
+        );
+    }
+
+    #[test]
+    fn test_gateio_spot_candle_approximated_base_volume() {
+        use barter_integration::model::InstrumentKind;
+
+        struct TestCase {
+            input: &'static str,
+            expected: Number,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: base volume provided, so quote volume / close is not used
+                input: r#"{"t":"1606292580","v":"2000","c":"0","h":"1","l":"0","o":"1","n":"1m_BTC_USDT","a":"5"}"#,
+                expected: from_f64(5.0),
+            },
+            TestCase {
+                // TC1: base volume approximated as quote volume / close
+                input: r#"{"t":"1606292580","v":"2000","c":"1000","h":"1000","l":"1000","o":"1000","n":"1m_BTC_USDT"}"#,
+                expected: from_f64(2.0),
+            },
+            TestCase {
+                // TC2: zero close price approximates zero base volume rather than dividing by zero
+                input: r#"{"t":"1606292580","v":"2000","c":"0","h":"1","l":"0","o":"1","n":"1m_BTC_USDT"}"#,
+                expected: number::ZERO,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let message = GateioSpotCandle {
+                time: None,
+                channel: "spot.candlesticks".to_string(),
+                error: None,
+                data: Some(serde_json::from_str(test.input).unwrap()),
+            };
+
+            let actual = MarketIter::<Candle>::from((
+                ExchangeId::GateioSpot,
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                message,
+            ))
+            .0
+            .remove(0)
+            .unwrap()
+            .kind
+            .volume;
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
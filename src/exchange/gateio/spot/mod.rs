@@ -1,13 +1,20 @@
-use self::trade::GateioSpotTrade;
-use super::Gateio;
+use self::{candle::GateioSpotCandle, trade::GateioSpotTrade};
+use super::{book::GateioOrderBookL1, Gateio};
 use crate::{
     exchange::{ExchangeId, ExchangeServer, StreamSelector},
-    subscription::trade::PublicTrades,
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleUpdates, Candles, ClosedCandles},
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
     ExchangeWsStream,
 };
 use barter_macro::{DeExchange, SerExchange};
 
+/// Candle types.
+pub mod candle;
+
 /// Public trades types.
 pub mod trade;
 
@@ -36,3 +43,16 @@ impl ExchangeServer for GateioServerSpot {
 impl StreamSelector<PublicTrades> for GateioSpot {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, GateioSpotTrade>>;
 }
+
+impl StreamSelector<OrderBooksL1> for GateioSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, GateioOrderBookL1>>;
+}
+
+impl StreamSelector<Candles> for GateioSpot {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, Candles, ClosedCandles<GateioSpotCandle>>>;
+}
+
+impl StreamSelector<CandleUpdates> for GateioSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, CandleUpdates, GateioSpotCandle>>;
+}
@@ -2,7 +2,7 @@ use super::super::message::GateioMessage;
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    subscription::{number::Number, trade::PublicTrade},
     Identifier,
 };
 use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
@@ -33,37 +33,49 @@ pub struct GateioSpotTradeInner {
     pub market: String,
     #[serde(
         rename = "create_time_ms",
-        deserialize_with = "barter_integration::de::de_str_f64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::de::de_fractional_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
 
     #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
     /// Taker [`Side`] of the trade.
+    #[serde(deserialize_with = "crate::subscription::trade::de_side_from_taker_side")]
     pub side: Side,
 }
 
 impl Identifier<Option<SubscriptionId>> for GateioSpotTrade {
     fn id(&self) -> Option<SubscriptionId> {
-        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+        self.data
+            .as_ref()
+            .map(|trade| ExchangeSub::from((&self.channel, &trade.market)).id())
     }
 }
 
 impl From<(ExchangeId, Instrument, GateioSpotTrade)> for MarketIter<PublicTrade> {
     fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, GateioSpotTrade)) -> Self {
+        let Some(trade) = trade.data else {
+            return Self(vec![]);
+        };
+
         Self(vec![Ok(MarketEvent {
-            exchange_time: trade.data.time,
+            exchange_time: trade.time,
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: None,
+            tag: None,
             kind: PublicTrade {
-                id: trade.data.id.to_string(),
-                price: trade.data.price,
-                amount: trade.data.amount,
-                side: trade.data.side,
+                id: trade.id.to_string(),
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
             },
         })])
     }
@@ -97,5 +109,102 @@ mod tests {
             "#;
             serde_json::from_str::<GateioSpotTrade>(input).unwrap();
         }
+
+        #[test]
+        fn test_gateio_spot_trade_id_and_time_precision() {
+            struct TestCase {
+                input: &'static str,
+                expected_id: &'static str,
+                expected_time: &'static str,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: fractional create_time_ms keeps microsecond precision
+                    input: r#"{"time":1669107766,"time_ms":1669107766512,"channel":"spot.trades","event":"update","result":{"id":4757305405,"create_time":1669107766,"create_time_ms":"1669107766504.563","side":"buy","currency_pair":"BTC_USDT","amount":"0.0055","price":"16172.9"}}"#,
+                    expected_id: "4757305405",
+                    expected_time: "2022-11-22T09:02:46.504563Z",
+                },
+                TestCase {
+                    // TC1: id above 2^53 is not rounded
+                    input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{"id":9007199254740993,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"sell","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705"}}"#,
+                    expected_id: "9007199254740993",
+                    expected_time: "2020-11-25T08:16:58.213457Z",
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let trade = serde_json::from_str::<GateioSpotTrade>(test.input).unwrap();
+                let instrument = Instrument::from((
+                    "btc",
+                    "usdt",
+                    barter_integration::model::InstrumentKind::Spot,
+                ));
+                let MarketIter(events) =
+                    MarketIter::<PublicTrade>::from((ExchangeId::GateioSpot, instrument, trade));
+                let event = events.into_iter().next().unwrap().unwrap();
+
+                assert_eq!(event.kind.id, test.expected_id, "TC{} failed", index);
+                assert_eq!(
+                    event.exchange_time,
+                    test.expected_time.parse::<DateTime<Utc>>().unwrap(),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+
+    mod transformer {
+        use super::*;
+        use crate::{
+            exchange::gateio::spot::GateioSpot,
+            subscription::{trade::PublicTrades, Map},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{model::InstrumentKind, Transformer};
+        use std::collections::HashMap;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn test_gateio_spot_pong_is_swallowed() {
+            let instrument_map = Map(HashMap::from([(
+                SubscriptionId::from("spot.trades|GT_USDT"),
+                Instrument::from(("gt", "usdt", InstrumentKind::Spot)),
+            )]));
+            let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+            let mut transformer = <StatelessTransformer<GateioSpot, PublicTrades, GateioSpotTrade> as ExchangeTransformer<GateioSpot, PublicTrades>>::new(
+                ws_sink_tx,
+                instrument_map,
+            )
+            .await
+            .unwrap();
+
+            struct TestCase {
+                input: &'static str,
+                expected_events: usize,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: pong answering an application-level ping yields no events or errors
+                    input: r#"{"time":1545404023,"time_ms":1545404023123,"channel":"spot.pong","event":"","result":null}"#,
+                    expected_events: 0,
+                },
+                TestCase {
+                    // TC1: trade following the pong is transformed
+                    input: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"sell","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705"}}"#,
+                    expected_events: 1,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let input = serde_json::from_str::<GateioSpotTrade>(test.input).unwrap();
+                let actual = transformer.transform(input);
+                assert_eq!(actual.len(), test.expected_events, "TC{} failed", index);
+                assert!(actual.iter().all(Result::is_ok), "TC{} failed", index);
+            }
+        }
     }
 }
@@ -1,15 +1,75 @@
-use super::message::GateioMessage;
+use super::message::GateioError;
 use barter_integration::{error::SocketError, Validator};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-/// Expected [`Gateio`](super::Gateio) [`Subscription`](crate::subscription::Subscription) response
-/// type wrapped in the generic [`GateioMessage<T>`](GateioMessage).
-pub type GateioSubResponse = GateioMessage<GateioSubResult>;
+/// [`Gateio`](super::Gateio) status of a successful
+/// [`Subscription`](crate::subscription::Subscription) response.
+pub const GATEIO_SUB_STATUS_SUCCESS: &str = "success";
 
 /// Expected [`Gateio`](super::Gateio) [`Subscription`](crate::subscription::Subscription)
-/// response type.
+/// response.
 ///
-/// See [`GateioMessage`](super::message::GateioMessage) for full raw payload examples.
+/// Only deserialises messages with a "subscribe" event, so interleaved market data messages
+/// (ie/ "update" events) are never mistaken for subscription responses.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-response>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "time": 1606292218,
+///     "time_ms": 1606292218231,
+///     "channel": "spot.trades",
+///     "event": "subscribe",
+///     "result": {
+///         "status": "success"
+///     }
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "time": 1606292218,
+///     "time_ms": 1606292218231,
+///     "channel": "futures.trades",
+///     "event": "subscribe",
+///     "error": {
+///         "code": 2,
+///         "message": "unknown contract BTC_XYZ"
+///     },
+///     "result": null
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct GateioSubResponse {
+    /// Time the message was generated by the exchange server, if provided.
+    #[serde(
+        rename = "time_ms",
+        default,
+        deserialize_with = "crate::de::de_option_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: Option<DateTime<Utc>>,
+    pub channel: String,
+    pub event: GateioSubEvent,
+    #[serde(default)]
+    pub error: Option<GateioError>,
+    #[serde(default)]
+    pub result: Option<GateioSubResult>,
+}
+
+/// [`Gateio`](super::Gateio) event of a [`GateioSubResponse`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum GateioSubEvent {
+    #[serde(rename = "subscribe")]
+    Subscribe,
+}
+
+/// Expected [`Gateio`](super::Gateio) [`Subscription`](crate::subscription::Subscription)
+/// response result.
+///
+/// See [`GateioSubResponse`] for full raw payload examples.
 ///
 /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#server-response>
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
@@ -22,11 +82,18 @@ impl Validator for GateioSubResponse {
     where
         Self: Sized,
     {
-        match &self.error {
-            None => Ok(self),
-            Some(failure) => Err(SocketError::Subscribe(format!(
-                "received failure subscription response code: {} with message: {}",
-                failure.code, failure.message,
+        match (&self.error, &self.result) {
+            (Some(error), _) => Err(SocketError::Subscribe(format!(
+                "received failure subscription response for channel: {} code: {} ({}) with message: {}",
+                self.channel,
+                error.code,
+                error.description(),
+                error.message,
+            ))),
+            (None, Some(result)) if result.status == GATEIO_SUB_STATUS_SUCCESS => Ok(self),
+            (None, result) => Err(SocketError::Subscribe(format!(
+                "received failure subscription response for channel: {} with result: {result:?}",
+                self.channel,
             ))),
         }
     }
@@ -35,10 +102,10 @@ impl Validator for GateioSubResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::exchange::gateio::message::GateioError;
 
     mod de {
         use super::*;
+        use barter_integration::de::datetime_utc_from_epoch_duration;
 
         #[test]
         fn test_gateio_sub_response() {
@@ -47,9 +114,10 @@ mod tests {
                 expected: Result<GateioSubResponse, SocketError>,
             }
 
-            let tests = vec![TestCase {
-                // TC0: input response is Subscribed
-                input: r#"
+            let tests = vec![
+                TestCase {
+                    // TC0: input response is Subscribed
+                    input: r#"
                     {
                         "time": 1606292218,
                         "time_ms": 1606292218231,
@@ -60,14 +128,71 @@ mod tests {
                         }
                     }
                     "#,
-                expected: Ok(GateioSubResponse {
-                    channel: "spot.trades".to_string(),
-                    error: None,
-                    data: GateioSubResult {
-                        status: "success".to_string(),
-                    },
-                }),
-            }];
+                    expected: Ok(GateioSubResponse {
+                        time: Some(datetime_utc_from_epoch_duration(
+                            std::time::Duration::from_millis(1606292218231),
+                        )),
+                        channel: "spot.trades".to_string(),
+                        event: GateioSubEvent::Subscribe,
+                        error: None,
+                        result: Some(GateioSubResult {
+                            status: "success".to_string(),
+                        }),
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is invalid contract failure w/ null result
+                    input: r#"
+                    {
+                        "time": 1606292218,
+                        "time_ms": 1606292218231,
+                        "channel": "futures.trades",
+                        "event": "subscribe",
+                        "error": {
+                            "code": 2,
+                            "message": "unknown contract BTC_XYZ"
+                        },
+                        "result": null
+                    }
+                    "#,
+                    expected: Ok(GateioSubResponse {
+                        time: Some(datetime_utc_from_epoch_duration(
+                            std::time::Duration::from_millis(1606292218231),
+                        )),
+                        channel: "futures.trades".to_string(),
+                        event: GateioSubEvent::Subscribe,
+                        error: Some(GateioError {
+                            code: 2,
+                            message: "unknown contract BTC_XYZ".to_string(),
+                        }),
+                        result: None,
+                    }),
+                },
+                TestCase {
+                    // TC2: input is an interleaved trade update, not a subscription response
+                    input: r#"
+                    {
+                        "time": 1606292218,
+                        "time_ms": 1606292218231,
+                        "channel": "spot.trades",
+                        "event": "update",
+                        "result": {
+                            "id": 309143071,
+                            "create_time": 1606292218,
+                            "create_time_ms": "1606292218213.4578",
+                            "side": "sell",
+                            "currency_pair": "GT_USDT",
+                            "amount": "16.4700000000",
+                            "price": "0.4705000000"
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
 
             for (index, test) in tests.into_iter().enumerate() {
                 let actual = serde_json::from_str::<GateioSubResponse>(test.input);
@@ -91,40 +216,176 @@ mod tests {
     fn test_validate_gateio_sub_response() {
         struct TestCase {
             input_response: GateioSubResponse,
-            is_valid: bool,
+            expected: Result<(), &'static str>,
         }
 
+        let response = |error: Option<GateioError>, status: Option<&str>| GateioSubResponse {
+            time: None,
+            channel: "futures.trades".to_string(),
+            event: GateioSubEvent::Subscribe,
+            error,
+            result: status.map(|status| GateioSubResult {
+                status: status.to_string(),
+            }),
+        };
+
         let cases = vec![
             TestCase {
                 // TC0: input response is successful subscription
-                input_response: GateioSubResponse {
-                    channel: "spot.trades".to_string(),
-                    error: None,
-                    data: GateioSubResult {
-                        status: "success".to_string(),
-                    },
-                },
-                is_valid: true,
+                input_response: response(None, Some("success")),
+                expected: Ok(()),
             },
             TestCase {
-                // TC1: input response is failed subscription
-                input_response: GateioSubResponse {
-                    channel: "spot.trades".to_string(),
-                    error: Some(GateioError {
-                        code: 0,
-                        message: "".to_string(),
+                // TC1: input response is invalid contract failure
+                input_response: response(
+                    Some(GateioError {
+                        code: 2,
+                        message: "unknown contract BTC_XYZ".to_string(),
                     }),
-                    data: GateioSubResult {
-                        status: "not used".to_string(),
-                    },
-                },
-                is_valid: false,
+                    None,
+                ),
+                expected: Err(
+                    "received failure subscription response for channel: futures.trades code: 2 \
+                     (invalid argument provided) with message: unknown contract BTC_XYZ",
+                ),
+            },
+            TestCase {
+                // TC2: input response has a non-success status
+                input_response: response(None, Some("fail")),
+                expected: Err(
+                    "received failure subscription response for channel: futures.trades with \
+                     result: Some(GateioSubResult { status: \"fail\" })",
+                ),
             },
         ];
 
         for (index, test) in cases.into_iter().enumerate() {
-            let actual = test.input_response.validate().is_ok();
-            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+            match (test.input_response.validate(), test.expected) {
+                (Ok(_), Ok(())) => {
+                    // Test passed
+                }
+                (Err(SocketError::Subscribe(actual)), Err(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_gateio_sub_validator_counts_only_successful_acks() {
+        use crate::{
+            exchange::gateio::spot::GateioSpot,
+            subscriber::validator::{
+                RejectedSubscription, SubscriptionOutcome, SubscriptionValidator,
+                WebSocketSubValidator,
+            },
+            subscription::{trade::PublicTrades, Map},
+        };
+        use barter_integration::{
+            model::{Instrument, InstrumentKind, SubscriptionId},
+            protocol::websocket::{connect, WsMessage},
+        };
+        use futures::SinkExt;
+        use std::time::Duration;
+
+        let ack = |result: &str| {
+            format!(r#"{{"time":1606292218,"channel":"spot.trades","event":"subscribe",{result}}}"#)
+        };
+        let success = ack(r#""result":{"status":"success"}"#);
+        let failure = ack(
+            r#""error":{"code":2,"message":"unknown currency pair GIBBERISH_USDT"},"result":null"#,
+        );
+        let update = r#"{"time":1606292218,"channel":"spot.trades","event":"update","result":{"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"sell","currency_pair":"BTC_USDT","amount":"16.47","price":"0.47"}}"#;
+
+        struct TestCase {
+            input: Vec<String>,
+            expected: SubscriptionOutcome,
+        }
+
+        let ids = ["spot.trades|BTC_USDT", "spot.trades|GIBBERISH_USDT"]
+            .map(SubscriptionId::from)
+            .to_vec();
+
+        let tests = vec![
+            TestCase {
+                // TC0: interleaved data message before all success acks is not counted
+                input: vec![success.clone(), update.to_string(), success.clone()],
+                expected: SubscriptionOutcome {
+                    confirmed: ids.clone(),
+                    rejected: vec![],
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC1: failure ack after an interleaved data message is surfaced
+                input: vec![success.clone(), update.to_string(), failure],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: ids
+                        .iter()
+                        .map(|subscription_id| RejectedSubscription {
+                            subscription_id: subscription_id.clone(),
+                            reason: "received failure subscription response for channel: spot.trades code: 2 \
+                                (invalid argument provided) with message: unknown currency pair \
+                                GIBBERISH_USDT"
+                                .to_string(),
+                        })
+                        .collect(),
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC2: interleaved data message is not counted as a success ack
+                input: vec![update.to_string(), success],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: vec![],
+                    timed_out: ids.clone(),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            // Mock Gateio server sending the test messages once connected
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("ws://{}", listener.local_addr().unwrap());
+            let server = tokio::spawn(async move {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(socket).await.unwrap();
+                for message in test.input {
+                    websocket.send(WsMessage::Text(message)).await.unwrap();
+                }
+                // Keep the connection open until validation completes
+                futures::future::pending::<()>().await;
+            });
+
+            let map = ids
+                .iter()
+                .map(|id| {
+                    (
+                        id.clone(),
+                        Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    )
+                })
+                .collect::<Map<Instrument>>();
+
+            let mut websocket = connect(url).await.unwrap();
+            let (_, actual) = WebSocketSubValidator::validate::<GateioSpot, PublicTrades>(
+                map,
+                &mut websocket,
+                Duration::from_millis(200),
+            )
+            .await
+            .unwrap();
+
+            let mut actual = actual;
+            actual.confirmed.sort();
+            actual.timed_out.sort();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+            server.abort();
         }
     }
 }
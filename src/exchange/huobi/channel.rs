@@ -0,0 +1,32 @@
+use super::HuobiSpot;
+use crate::{
+    subscription::{trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`HuobiSpot`](super::HuobiSpot) channel to be subscribed to.
+///
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#websocket-market-data>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct HuobiChannel(pub &'static str);
+
+impl HuobiChannel {
+    /// [`HuobiSpot`](super::HuobiSpot) real-time trades channel name.
+    ///
+    /// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#trade-detail>
+    pub const TRADES: Self = Self("trade.detail");
+}
+
+impl Identifier<HuobiChannel> for Subscription<HuobiSpot, PublicTrades> {
+    fn id(&self) -> HuobiChannel {
+        HuobiChannel::TRADES
+    }
+}
+
+impl AsRef<str> for HuobiChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,22 @@
+use super::HuobiSpot;
+use crate::{subscription::Subscription, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`HuobiSpot`](super::HuobiSpot) market that can be subscribed to.
+///
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#websocket-market-data>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HuobiMarket(pub String);
+
+impl<Kind> Identifier<HuobiMarket> for Subscription<HuobiSpot, Kind> {
+    fn id(&self) -> HuobiMarket {
+        HuobiMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_lowercase())
+    }
+}
+
+impl AsRef<str> for HuobiMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
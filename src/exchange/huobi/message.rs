@@ -0,0 +1,219 @@
+use crate::{exchange::ExchangeSub, Identifier};
+use barter_integration::{model::SubscriptionId, protocol::websocket::WsMessage};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// [`HuobiSpot`](super::HuobiSpot) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket), after the gzip compressed
+/// payload has been decoded.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#websocket-market-data>
+///
+/// #### Trades
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#trade-detail>
+/// ```json
+/// {
+///     "ch": "market.btcusdt.trade.detail",
+///     "ts": 1630994963175,
+///     "tick": {
+///         "id": 137005445109,
+///         "ts": 1630994963173,
+///         "data": [
+///             {
+///                 "id": 1.3700544510935929e+26,
+///                 "ts": 1630994963173,
+///                 "tradeId": 102523573486,
+///                 "amount": 0.006754,
+///                 "price": 52648.62,
+///                 "direction": "buy"
+///             }
+///         ]
+///     }
+/// }
+/// ```
+///
+/// #### Ping
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#heartbeat-and-connection>
+/// ```json
+/// {
+///     "ping": 1492420473027
+/// }
+/// ```
+///
+/// #### Unsubscription Response
+/// ```json
+/// {
+///     "id": "0",
+///     "status": "ok",
+///     "unsubbed": "market.btcusdt.trade.detail",
+///     "ts": 1494326028889
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum HuobiMessage<T> {
+    Ping(HuobiPing),
+    Data(HuobiPayload<T>),
+    Event(HuobiEvent),
+}
+
+/// [`HuobiSpot`](super::HuobiSpot) market data payload containing the generic `T` tick.
+///
+/// See [`HuobiMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HuobiPayload<T> {
+    #[serde(alias = "ch", deserialize_with = "de_huobi_channel_as_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub tick: T,
+}
+
+/// [`HuobiSpot`](super::HuobiSpot) application-level ping, which must be answered with a
+/// [`HuobiPing::pong`] containing the same timestamp to keep the connection alive.
+///
+/// See [`HuobiMessage`] for full raw payload examples.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HuobiPing {
+    pub ping: u64,
+}
+
+impl HuobiPing {
+    /// Construct the pong [`WsMessage`] that answers this [`HuobiPing`].
+    pub fn pong(&self) -> WsMessage {
+        WsMessage::Text(json!({ "pong": self.ping }).to_string())
+    }
+}
+
+/// [`HuobiSpot`](super::HuobiSpot) non market data message, such as a (un)subscription response
+/// received mid-stream.
+///
+/// See [`HuobiMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HuobiEvent {
+    pub status: String,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for HuobiMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            HuobiMessage::Data(payload) => Some(payload.subscription_id.clone()),
+            HuobiMessage::Ping(_) | HuobiMessage::Event(_) => None,
+        }
+    }
+}
+
+/// Deserialize a [`HuobiPayload`] "ch" (eg/ "market.btcusdt.trade.detail") as the associated
+/// [`SubscriptionId`] (eg/ "trade.detail|btcusdt").
+pub fn de_huobi_channel_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let ch = <&str as Deserialize>::deserialize(deserializer)?;
+
+    ch.strip_prefix("market.")
+        .and_then(|topic| topic.split_once('.'))
+        .map(|(market, channel)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(ch),
+                &"Huobi ch in the format market.{market}.{channel}",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_huobi_message_non_data() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<HuobiMessage<serde_json::Value>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input ping is HuobiMessage::Ping
+                    input: r#"{"ping":1492420473027}"#,
+                    expected: Ok(HuobiMessage::Ping(HuobiPing {
+                        ping: 1492420473027,
+                    })),
+                },
+                TestCase {
+                    // TC1: input unsubscription response is HuobiMessage::Event
+                    input: r#"{"id":"0","status":"ok","unsubbed":"market.btcusdt.trade.detail","ts":1494326028889}"#,
+                    expected: Ok(HuobiMessage::Event(HuobiEvent {
+                        status: "ok".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input ch without a channel is invalid
+                    input: r#"{"ch":"market.btcusdt","ts":1630994963175,"tick":{}}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<HuobiMessage<serde_json::Value>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_huobi_channel_as_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades ch
+                input: r#""market.btcusdt.trade.detail""#,
+                expected: SubscriptionId::from("trade.detail|btcusdt"),
+            },
+            TestCase {
+                // TC1: single segment channel
+                input: r#""market.ethusdt.bbo""#,
+                expected: SubscriptionId::from("bbo|ethusdt"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut deserializer = serde_json::Deserializer::from_str(test.input);
+            let actual = de_huobi_channel_as_subscription_id(&mut deserializer).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_huobi_ping_pong() {
+        let ping = serde_json::from_str::<HuobiPing>(r#"{"ping":1492420473027}"#).unwrap();
+        assert_eq!(
+            ping.pong(),
+            WsMessage::Text(r#"{"pong":1492420473027}"#.to_string())
+        );
+    }
+}
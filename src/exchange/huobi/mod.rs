@@ -0,0 +1,169 @@
+use self::{
+    channel::HuobiChannel, market::HuobiMarket, subscription::HuobiSubResponse, trade::HuobiTrades,
+    transformer::HuobiTransformer,
+};
+use crate::{
+    decoder::Gzip,
+    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::trade::PublicTrades,
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use url::Url;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`HuobiMessage`](message::HuobiMessage) type, including application-level pings, for
+/// [`HuobiSpot`].
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`HuobiSpot`].
+pub mod subscription;
+
+/// Public trade types for [`HuobiSpot`].
+pub mod trade;
+
+/// [`HuobiTransformer`](transformer::HuobiTransformer) answering the application-level pings of
+/// [`HuobiSpot`].
+pub mod transformer;
+
+/// [`HuobiSpot`] WebSocket server base url.
+///
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#websocket-market-data>
+pub const BASE_URL_HUOBI_SPOT: &str = "wss://api.huobi.pro/ws";
+
+/// [`HuobiSpot`] exchange.
+///
+/// ### Notes
+/// Every WebSocket message sent by the server is gzip compressed, hence the [`Gzip`]
+/// [`Connector::Decoder`].
+///
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#websocket-market-data>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct HuobiSpot;
+
+impl Connector for HuobiSpot {
+    const ID: ExchangeId = ExchangeId::HuobiSpot;
+    type Channel = HuobiChannel;
+    type Market = HuobiMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = HuobiSubResponse;
+    type Decoder = Gzip;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_HUOBI_SPOT).map_err(SocketError::UrlParse)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        huobi_requests("sub", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        huobi_requests("unsub", exchange_subs)
+    }
+}
+
+/// Construct the [`HuobiSpot`] [`WsMessage`] payloads that action the provided operation (eg/
+/// "sub", "unsub") for a collection of [`ExchangeSub`]s.
+fn huobi_requests(
+    operation: &str,
+    exchange_subs: Vec<ExchangeSub<HuobiChannel, HuobiMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .enumerate()
+        .map(|(index, ExchangeSub { channel, market })| {
+            WsMessage::Text(
+                json!({
+                    operation: format!("market.{}.{}", market.as_ref(), channel.as_ref()),
+                    "id": index.to_string(),
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+impl StreamSelector<PublicTrades> for HuobiSpot {
+    type Stream = ExchangeWsStream<
+        HuobiTransformer<StatelessTransformer<Self, PublicTrades, HuobiTrades>>,
+        Gzip,
+    >;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{subscription::Subscription, Identifier};
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_huobi_requests() {
+        let exchange_subs = || {
+            vec![
+                ExchangeSub::from((HuobiChannel::TRADES, HuobiMarket("btcusdt".to_string()))),
+                ExchangeSub::from((HuobiChannel::TRADES, HuobiMarket("ethusdt".to_string()))),
+            ]
+        };
+
+        assert_eq!(
+            HuobiSpot::requests(exchange_subs()),
+            vec![
+                WsMessage::Text(
+                    json!({"sub": "market.btcusdt.trade.detail", "id": "0"}).to_string()
+                ),
+                WsMessage::Text(
+                    json!({"sub": "market.ethusdt.trade.detail", "id": "1"}).to_string()
+                ),
+            ]
+        );
+
+        assert_eq!(
+            HuobiSpot::unsubscribe_requests(exchange_subs()),
+            vec![
+                WsMessage::Text(
+                    json!({"unsub": "market.btcusdt.trade.detail", "id": "0"}).to_string()
+                ),
+                WsMessage::Text(
+                    json!({"unsub": "market.ethusdt.trade.detail", "id": "1"}).to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_huobi_subscription_id() {
+        // PublicTrades Subscription id matches trades message ch
+        let sub = Subscription::<_, PublicTrades>::new(
+            HuobiSpot,
+            ("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        );
+
+        assert_eq!(
+            ExchangeSub::<HuobiChannel, HuobiMarket>::new(&sub).id(),
+            serde_json::from_str::<HuobiTrades>(
+                r#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175,"tick":{"data":[]}}"#,
+            )
+            .unwrap()
+            .id()
+            .unwrap(),
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`HuobiSpot`](super::HuobiSpot) subscription response message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#subscribe-to-topic>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "id": "0",
+///     "status": "ok",
+///     "subbed": "market.btcusdt.trade.detail",
+///     "ts": 1489474081631
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "id": "0",
+///     "status": "error",
+///     "err-code": "bad-request",
+///     "err-msg": "invalid topic market.invalidsymbol.trade.detail",
+///     "ts": 1494301904959
+/// }
+/// ```
+///
+/// ## Notes:
+/// - Application-level pings sent before the subscription responses fail to deserialise into a
+///   [`HuobiSubResponse`], and are therefore skipped by the
+///   [`WebSocketSubValidator`](crate::subscriber::validator::WebSocketSubValidator).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum HuobiSubResponse {
+    Ok {
+        subbed: String,
+    },
+    Error {
+        #[serde(rename = "err-code")]
+        code: String,
+        #[serde(rename = "err-msg")]
+        message: String,
+    },
+}
+
+impl Validator for HuobiSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match self {
+            HuobiSubResponse::Ok { .. } => Ok(self),
+            HuobiSubResponse::Error { code, message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {code} with message: {message}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_huobi_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<HuobiSubResponse, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input response is subscription success
+                    input: r#"{"id":"0","status":"ok","subbed":"market.btcusdt.trade.detail","ts":1489474081631}"#,
+                    expected: Ok(HuobiSubResponse::Ok {
+                        subbed: "market.btcusdt.trade.detail".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"
+                    {
+                        "id": "0",
+                        "status": "error",
+                        "err-code": "bad-request",
+                        "err-msg": "invalid topic market.invalidsymbol.trade.detail",
+                        "ts": 1494301904959
+                    }
+                    "#,
+                    expected: Ok(HuobiSubResponse::Error {
+                        code: "bad-request".to_string(),
+                        message: "invalid topic market.invalidsymbol.trade.detail".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: input ping is not a subscription response
+                    input: r#"{"ping":1492420473027}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<HuobiSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_huobi_sub_response() {
+        struct TestCase {
+            input_response: HuobiSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: HuobiSubResponse::Ok {
+                    subbed: "market.btcusdt.trade.detail".to_string(),
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: HuobiSubResponse::Error {
+                    code: "bad-request".to_string(),
+                    message: "invalid topic market.invalidsymbol.trade.detail".to_string(),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
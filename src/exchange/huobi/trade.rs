@@ -0,0 +1,203 @@
+use super::message::HuobiMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{number::Number, trade::PublicTrade},
+};
+use barter_integration::model::{Exchange, Instrument, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`HuobiSpot`](super::HuobiSpot) real-time trades WebSocket message.
+pub type HuobiTrades = HuobiMessage<HuobiTradeTick>;
+
+/// [`HuobiSpot`](super::HuobiSpot) real-time trades tick, containing every trade matched in the
+/// same engine tick.
+///
+/// See [`HuobiMessage`] for full raw payload examples.
+///
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#trade-detail>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HuobiTradeTick {
+    pub data: Vec<HuobiTrade>,
+}
+
+/// [`HuobiSpot`](super::HuobiSpot) real-time trade.
+///
+/// ## Notes:
+/// - The unique "tradeId" is used rather than the legacy "id", since the latter exceeds [`u64`].
+///
+/// See [`HuobiMessage`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HuobiTrade {
+    #[serde(rename = "tradeId")]
+    pub id: u64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub price: Number,
+    pub amount: Number,
+    #[serde(
+        rename = "direction",
+        deserialize_with = "crate::subscription::trade::de_side_from_taker_side"
+    )]
+    pub side: Side,
+}
+
+impl From<(ExchangeId, Instrument, HuobiTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, HuobiTrades)) -> Self {
+        match trades {
+            HuobiMessage::Data(payload) => payload
+                .tick
+                .data
+                .into_iter()
+                .map(|trade| {
+                    Ok(MarketEvent {
+                        exchange_time: trade.time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(exchange_id),
+                        instrument: instrument.clone(),
+                        sequence: None,
+                        tag: None,
+                        kind: PublicTrade {
+                            id: trade.id.to_string(),
+                            price: trade.price,
+                            amount: trade.amount,
+                            side: trade.side,
+                            buyer_order_id: None,
+                            seller_order_id: None,
+                            taker_order_kind: None,
+                        },
+                    })
+                })
+                .collect(),
+            HuobiMessage::Ping(_) | HuobiMessage::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::exchange::huobi::message::HuobiPayload;
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_huobi_message_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<HuobiTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input trades message with multiple trades
+                    input: r#"
+                    {
+                        "ch": "market.btcusdt.trade.detail",
+                        "ts": 1630994963175,
+                        "tick": {
+                            "id": 137005445109,
+                            "ts": 1630994963173,
+                            "data": [
+                                {
+                                    "id": 1.3700544510935929e+26,
+                                    "ts": 1630994963173,
+                                    "tradeId": 102523573486,
+                                    "amount": 0.006754,
+                                    "price": 52648.62,
+                                    "direction": "buy"
+                                },
+                                {
+                                    "id": 1.3700544510935929e+26,
+                                    "ts": 1630994963174,
+                                    "tradeId": 102523573487,
+                                    "amount": 0.5,
+                                    "price": 52648.0,
+                                    "direction": "sell"
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: Ok(HuobiMessage::Data(HuobiPayload {
+                        subscription_id: SubscriptionId::from("trade.detail|btcusdt"),
+                        tick: HuobiTradeTick {
+                            data: vec![
+                                HuobiTrade {
+                                    id: 102523573486,
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1630994963173,
+                                    )),
+                                    price: from_f64(52648.62),
+                                    amount: from_f64(0.006754),
+                                    side: Side::Buy,
+                                },
+                                HuobiTrade {
+                                    id: 102523573487,
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1630994963174,
+                                    )),
+                                    price: from_f64(52648.0),
+                                    amount: from_f64(0.5),
+                                    side: Side::Sell,
+                                },
+                            ],
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input trades message with invalid direction
+                    input: r#"
+                    {
+                        "ch": "market.btcusdt.trade.detail",
+                        "ts": 1630994963175,
+                        "tick": {
+                            "id": 137005445109,
+                            "ts": 1630994963173,
+                            "data": [
+                                {
+                                    "ts": 1630994963173,
+                                    "tradeId": 102523573486,
+                                    "amount": 0.006754,
+                                    "price": 52648.62,
+                                    "direction": "unknown"
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<HuobiTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use super::{message::HuobiMessage, HuobiSpot};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    streams::metrics::MetricsHook,
+    subscription::{Map, SubKind},
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+/// [`ExchangeTransformer`] wrapper that answers every [`HuobiSpot`] application-level
+/// [`HuobiPing`](super::message::HuobiPing) with the associated pong, delegating all messages
+/// to the `Inner` [`ExchangeTransformer`].
+///
+/// See docs: <https://huobiapi.github.io/docs/spot/v1/en/#heartbeat-and-connection>
+#[derive(Debug)]
+pub struct HuobiTransformer<Inner> {
+    pub inner: Inner,
+    ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+#[async_trait]
+impl<Kind, Inner, T> ExchangeTransformer<HuobiSpot, Kind> for HuobiTransformer<Inner>
+where
+    Kind: SubKind + Send,
+    Inner: ExchangeTransformer<HuobiSpot, Kind, Input = HuobiMessage<T>> + Send,
+    T: for<'de> Deserialize<'de>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            inner: Inner::new(ws_sink_tx.clone(), instrument_map).await?,
+            ws_sink_tx,
+        })
+    }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        self.inner.set_metrics(metrics);
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.inner.unsubscribe(subscription_ids);
+    }
+
+    fn subscribe(&mut self, instrument_map: Map<Instrument>) -> Result<(), DataError> {
+        self.inner.subscribe(instrument_map)
+    }
+}
+
+impl<Inner, T, Output> Transformer for HuobiTransformer<Inner>
+where
+    Inner: Transformer<Input = HuobiMessage<T>, Output = MarketEvent<Output>, Error = DataError>,
+    T: for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = HuobiMessage<T>;
+    type Output = MarketEvent<Output>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        if let HuobiMessage::Ping(ping) = &input {
+            // Failure to send only occurs when the connection is already closing
+            let _ = self.ws_sink_tx.send(ping.pong());
+        }
+
+        self.inner.transform(input).into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::huobi::trade::HuobiTrades, subscription::trade::PublicTrades,
+        transformer::stateless::StatelessTransformer,
+    };
+    use barter_integration::model::InstrumentKind;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_huobi_transformer() {
+        let instrument_map = Map(HashMap::from([(
+            SubscriptionId::from("trade.detail|btcusdt"),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        )]));
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+
+        let mut transformer = <HuobiTransformer<
+            StatelessTransformer<HuobiSpot, PublicTrades, HuobiTrades>,
+        > as ExchangeTransformer<HuobiSpot, PublicTrades>>::new(
+            ws_sink_tx, instrument_map
+        )
+        .await
+        .unwrap();
+
+        struct TestCase {
+            input: &'static str,
+            expected_events: usize,
+            expected_pong: Option<WsMessage>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: ping is answered with a pong & yields no events
+                input: r#"{"ping":1492420473027}"#,
+                expected_events: 0,
+                expected_pong: Some(WsMessage::Text(r#"{"pong":1492420473027}"#.to_string())),
+            },
+            TestCase {
+                // TC1: trades message yields an event per trade
+                input: r#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175,"tick":{"id":137005445109,"ts":1630994963173,"data":[{"ts":1630994963173,"tradeId":102523573486,"amount":0.006754,"price":52648.62,"direction":"buy"},{"ts":1630994963173,"tradeId":102523573487,"amount":0.1,"price":52648.0,"direction":"sell"}]}}"#,
+                expected_events: 2,
+                expected_pong: None,
+            },
+            TestCase {
+                // TC2: unsubscription response yields no events
+                input: r#"{"id":"0","status":"ok","unsubbed":"market.btcusdt.trade.detail","ts":1494326028889}"#,
+                expected_events: 0,
+                expected_pong: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<HuobiTrades>(test.input).unwrap();
+            let actual = transformer.transform(input);
+            assert_eq!(actual.len(), test.expected_events, "TC{} failed", index);
+            assert!(actual.iter().all(Result::is_ok), "TC{} failed", index);
+            assert_eq!(
+                ws_sink_rx.try_recv().ok(),
+                test.expected_pong,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
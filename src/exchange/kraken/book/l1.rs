@@ -2,9 +2,12 @@ use super::super::KrakenMessage;
 use crate::exchange::kraken::channel::KrakenChannel;
 use crate::exchange::subscription::ExchangeSub;
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, TimeSource},
     exchange::ExchangeId,
-    subscription::book::{Level, OrderBookL1},
+    subscription::{
+        book::{Level, OrderBookL1},
+        number::Number,
+    },
     Identifier,
 };
 use barter_integration::{
@@ -38,15 +41,15 @@ pub struct KrakenOrderBookL1Inner {
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct KrakenSpread {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_price: f64,
+    pub best_bid_price: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_price: f64,
-    #[serde(deserialize_with = "barter_integration::de::de_str_f64_epoch_s_as_datetime_utc")]
+    pub best_ask_price: Number,
+    #[serde(deserialize_with = "crate::de::de_str_f64_epoch_s_as_datetime_utc")]
     pub time: DateTime<Utc>,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_amount: f64,
+    pub best_bid_amount: Number,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_amount: f64,
+    pub best_ask_amount: Number,
 }
 
 impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL1Inner {
@@ -63,10 +66,13 @@ impl From<(ExchangeId, Instrument, KrakenOrderBookL1)> for MarketIter<OrderBookL
                 received_time: Utc::now(),
                 exchange: Exchange::from(exchange_id),
                 instrument,
+                sequence: None,
+                tag: None,
                 kind: OrderBookL1 {
                     last_update_time: book.spread.time,
                     best_bid: Level::new(book.spread.best_bid_price, book.spread.best_bid_amount),
                     best_ask: Level::new(book.spread.best_ask_price, book.spread.best_ask_amount),
+                    time_source: TimeSource::Exchange,
                 },
             })]),
             KrakenOrderBookL1::Event(_) => MarketIter(vec![]),
@@ -131,6 +137,7 @@ impl<'de> serde::de::Deserialize<'de> for KrakenOrderBookL1Inner {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
@@ -164,13 +171,13 @@ mod tests {
                 expected: Ok(KrakenOrderBookL1::Data(KrakenOrderBookL1Inner {
                     subscription_id: SubscriptionId::from("spread|XBT/USD"),
                     spread: KrakenSpread {
-                        best_bid_price: 5698.4,
-                        best_bid_amount: 1.01234567,
+                        best_bid_price: from_f64(5698.4),
+                        best_bid_amount: from_f64(1.01234567),
                         time: datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(
                             1542057299.545897,
                         )),
-                        best_ask_price: 5700.0,
-                        best_ask_amount: 0.98765432,
+                        best_ask_price: from_f64(5700.0),
+                        best_ask_amount: from_f64(0.98765432),
                     },
                 })),
             }];
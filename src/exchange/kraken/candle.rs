@@ -0,0 +1,601 @@
+use super::KrakenMessage;
+use crate::{
+    de::datetime_utc_from_f64_epoch_s,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{
+        candle::{Candle, CandleSource, VolumeDenomination},
+        number::Number,
+    },
+    Identifier,
+};
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{Exchange, Instrument, SubscriptionId},
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+/// Terse type alias for an [`Kraken`](super::Kraken) real-time candles WebSocket message.
+pub type KrakenCandles = KrakenMessage<KrakenCandlesInner>;
+
+/// [`KrakenCandle`] with an associated [`SubscriptionId`] (eg/ "ohlc-5|XBT/USD").
+///
+/// ### Raw Payload Examples
+/// Format: \[channelID, \[time, etime, open, high, low, close, vwap, volume, count\], channelName, pair\]
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+/// ```json
+/// [
+///   42,
+///   [
+///     "1542057314.748456",
+///     "1542057360.435743",
+///     "3586.70000",
+///     "3586.70000",
+///     "3586.60000",
+///     "3586.60000",
+///     "3586.68894",
+///     "0.03373000",
+///     2
+///   ],
+///   "ohlc-5",
+///   "XBT/USD"
+/// ]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenCandlesInner {
+    pub subscription_id: SubscriptionId,
+    pub candle: KrakenCandle,
+}
+
+/// [`Kraken`](super::Kraken) real-time candle.
+///
+/// See [`KrakenCandlesInner`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+///
+/// ## Notes:
+/// - The "time" field is the time of the last update, and the "etime" field is the end time of
+///   the candle interval.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct KrakenCandle {
+    pub time: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub vwap: Number,
+    pub volume: Number,
+    pub trade_count: u64,
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenCandlesInner {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, KrakenCandles)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candles): (ExchangeId, Instrument, KrakenCandles)) -> Self {
+        match candles {
+            KrakenCandles::Data(KrakenCandlesInner {
+                subscription_id,
+                candle,
+            }) => {
+                // Consistent with Binance, the close time is the last millisecond of the candle
+                // interval (ie/ etime - 1ms)
+                let close_time = kraken_candle_close_time(candle.end);
+                let start_time = match kraken_candle_start_time(&subscription_id, close_time) {
+                    Ok(start_time) => start_time,
+                    Err(error) => return Self(vec![Err(error)]),
+                };
+
+                Self(vec![Ok(MarketEvent {
+                    exchange_time: candle.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument,
+                    sequence: None,
+                    tag: None,
+                    kind: Candle {
+                        start_time,
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume: candle.volume,
+                        volume_denomination: VolumeDenomination::Base,
+                        base_volume: Some(candle.volume),
+                        quote_volume: None,
+                        taker_buy_volume: None,
+                        taker_buy_quote_volume: None,
+                        trade_count: candle.trade_count,
+                        // Kraken does not indicate if a candle update is final, so closed candles
+                        // are determined by the HeldCandleTransformer
+                        closed: false,
+                        source: CandleSource::LastTrade,
+                        synthetic: false,
+                        derived: false,
+                    },
+                })])
+            }
+            KrakenCandles::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+/// Determine the close time of a [`Kraken`](super::Kraken) candle from the candle interval end
+/// time, truncating any sub-second precision before subtracting 1ms.
+fn kraken_candle_close_time(end: DateTime<Utc>) -> DateTime<Utc> {
+    end - Duration::nanoseconds(i64::from(end.timestamp_subsec_nanos())) - Duration::milliseconds(1)
+}
+
+/// Determine the start time of a [`Kraken`](super::Kraken) candle using the interval minutes of
+/// the candle channel contained in the [`SubscriptionId`] (eg/ "ohlc-5|XBT/USD") and the candle
+/// close time.
+fn kraken_candle_start_time(
+    subscription_id: &SubscriptionId,
+    close_time: DateTime<Utc>,
+) -> Result<DateTime<Utc>, DataError> {
+    let channel = subscription_id
+        .as_ref()
+        .split('|')
+        .next()
+        .unwrap_or_default();
+
+    channel
+        .strip_prefix("ohlc-")
+        .and_then(|minutes| minutes.parse::<i64>().ok())
+        .map(|minutes| close_time + Duration::milliseconds(1) - Duration::minutes(minutes))
+        .ok_or_else(|| {
+            DataError::Socket(SocketError::Unsupported {
+                entity: ExchangeId::Kraken.as_str(),
+                item: format!("candle channel: {channel}"),
+            })
+        })
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenCandlesInner {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenCandlesInner;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenCandlesInner struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenCandles Sequence Format:
+                // [channelID, [time, etime, open, high, low, close, vwap, volume, count], channelName, pair]
+                // <https://docs.kraken.com/websockets/#message-ohlc>
+
+                // Extract deprecated channelID & ignore
+                let _: serde::de::IgnoredAny = extract_next(&mut seq, "channelID")?;
+
+                // Extract KrakenCandle
+                let candle = extract_next(&mut seq, "KrakenCandle")?;
+
+                // Extract channelName (eg/ "ohlc-5")
+                let channel = extract_next::<SeqAccessor, String>(&mut seq, "channelName")?;
+
+                // Extract pair (eg/ "XBT/USD") & map to SubscriptionId (ie/ "{channel}|{pair}")
+                let subscription_id = extract_next::<SeqAccessor, String>(&mut seq, "pair")
+                    .map(|pair| SubscriptionId::from(format!("{channel}|{pair}")))?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenCandlesInner {
+                    subscription_id,
+                    candle,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenCandles
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for KrakenCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = KrakenCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("KrakenCandle struct from the Kraken WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // KrakenCandle Sequence Format:
+                // [time, etime, open, high, low, close, vwap, volume, count]
+                // <https://docs.kraken.com/websockets/#message-ohlc>
+
+                // Extract String epoch seconds times & parse to DateTime<Utc>
+                let mut next_time =
+                    |field: &'static str| -> Result<DateTime<Utc>, SeqAccessor::Error> {
+                        extract_next::<SeqAccessor, String>(&mut seq, field)?
+                            .parse()
+                            .map_err(serde::de::Error::custom)
+                            .and_then(|time| {
+                                datetime_utc_from_f64_epoch_s(time).ok_or_else(|| {
+                                    serde::de::Error::invalid_value(
+                                        serde::de::Unexpected::Float(time),
+                                        &"an epoch within the DateTime<Utc> range",
+                                    )
+                                })
+                            })
+                    };
+                let time = next_time("time")?;
+                let end = next_time("etime")?;
+
+                // Extract String OHLC, vwap & volume values & parse to Number
+                let mut next_number = |field: &'static str| -> Result<Number, SeqAccessor::Error> {
+                    extract_next::<SeqAccessor, String>(&mut seq, field)?
+                        .parse()
+                        .map_err(serde::de::Error::custom)
+                };
+                let open = next_number("open")?;
+                let high = next_number("high")?;
+                let low = next_number("low")?;
+                let close = next_number("close")?;
+                let vwap = next_number("vwap")?;
+                let volume = next_number("volume")?;
+
+                // Extract u64 trade count
+                let trade_count = extract_next(&mut seq, "count")?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(KrakenCandle {
+                    time,
+                    end,
+                    open,
+                    high,
+                    low,
+                    close,
+                    vwap,
+                    volume,
+                    trade_count,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the KrakenCandle
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::kraken::Kraken,
+        subscription::{candle::Candles, number::from_f64, Map},
+        transformer::{
+            candle::HeldCandleTransformer, stateless::StatelessTransformer, ExchangeTransformer,
+        },
+    };
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, model::InstrumentKind, Transformer,
+    };
+    use tokio::sync::mpsc;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kraken_message_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenCandles, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KrakenCandles::Data(KrakenCandlesInner)
+                    input: r#"
+                    [
+                        42,
+                        [
+                            "1542057314.748456",
+                            "1542057360.000000",
+                            "3586.70000",
+                            "3586.70000",
+                            "3586.60000",
+                            "3586.60000",
+                            "3586.68894",
+                            "0.03373000",
+                            2
+                        ],
+                        "ohlc-5",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Ok(KrakenCandles::Data(KrakenCandlesInner {
+                        subscription_id: SubscriptionId::from("ohlc-5|XBT/USD"),
+                        candle: KrakenCandle {
+                            time: datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_secs_f64(1542057314.748456),
+                            ),
+                            end: datetime_utc_from_epoch_duration(std::time::Duration::from_secs(
+                                1542057360,
+                            )),
+                            open: from_f64(3586.7),
+                            high: from_f64(3586.7),
+                            low: from_f64(3586.6),
+                            close: from_f64(3586.6),
+                            vwap: from_f64(3586.68894),
+                            volume: from_f64(0.03373),
+                            trade_count: 2,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: invalid KrakenCandles::Data(KrakenCandlesInner) w/ non-numeric open
+                    input: r#"
+                    [
+                        42,
+                        [
+                            "1542057314.748456",
+                            "1542057360.000000",
+                            "not a number",
+                            "3586.70000",
+                            "3586.60000",
+                            "3586.60000",
+                            "3586.68894",
+                            "0.03373000",
+                            2
+                        ],
+                        "ohlc-5",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid KrakenCandles::Data(KrakenCandlesInner) w/ missing count
+                    input: r#"
+                    [
+                        42,
+                        [
+                            "1542057314.748456",
+                            "1542057360.000000",
+                            "3586.70000",
+                            "3586.70000",
+                            "3586.60000",
+                            "3586.60000",
+                            "3586.68894",
+                            "0.03373000"
+                        ],
+                        "ohlc-5",
+                        "XBT/USD"
+                    ]
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_kraken_held_candle_transformer() {
+        let instrument = Instrument::from(("xbt", "usd", InstrumentKind::Spot));
+        let instrument_map = Map(std::collections::HashMap::from([
+            (SubscriptionId::from("ohlc-1|XBT/USD"), instrument.clone()),
+            (SubscriptionId::from("ohlc-5|XBT/USD"), instrument),
+        ]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        let mut transformer = <HeldCandleTransformer<
+            StatelessTransformer<Kraken, Candles, KrakenCandles>,
+        > as ExchangeTransformer<Kraken, Candles>>::new(
+            ws_sink_tx, instrument_map
+        )
+        .await
+        .unwrap();
+
+        struct TestCase {
+            input: String,
+            expected: Vec<(i64, Number, bool)>,
+        }
+
+        // Kraken positional OHLC array: [time, etime, open, high, low, close, vwap, volume, count]
+        let ohlc = |channel: &str, time: &str, etime: &str, close: &str, volume: &str| {
+            format!(
+                r#"[42, ["{time}", "{etime}", "3586.70000", "3590.00000", "3580.00000", "{close}", "3586.68894", "{volume}", 2], "{channel}", "XBT/USD"]"#
+            )
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first in-progress 1m candle is held
+                input: ohlc(
+                    "ohlc-1",
+                    "1542057314.748456",
+                    "1542057360.000000",
+                    "3586.60000",
+                    "0.10000000",
+                ),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: in-progress 1m candle replayed on re-subscription w/ growing volume is held
+                input: ohlc(
+                    "ohlc-1",
+                    "1542057320.000000",
+                    "1542057360.000000",
+                    "3587.00000",
+                    "0.20000000",
+                ),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: in-progress 5m candle on the same connection is held independently
+                input: ohlc(
+                    "ohlc-5",
+                    "1542057321.000000",
+                    "1542057600.000000",
+                    "3587.00000",
+                    "5.00000000",
+                ),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: repeated in-progress 1m candle w/ growing volume is held
+                input: ohlc(
+                    "ohlc-1",
+                    "1542057350.000000",
+                    "1542057360.000000",
+                    "3588.00000",
+                    "0.30000000",
+                ),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: stale update for an earlier 1m candle is dropped
+                input: ohlc(
+                    "ohlc-1",
+                    "1542057290.000000",
+                    "1542057300.000000",
+                    "3580.00000",
+                    "9.00000000",
+                ),
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: first update of the next 1m candle emits the final held candle as closed
+                input: ohlc(
+                    "ohlc-1",
+                    "1542057361.000000",
+                    "1542057420.000000",
+                    "3589.00000",
+                    "0.01000000",
+                ),
+                expected: vec![(1542057359999, from_f64(0.3), true)],
+            },
+            TestCase {
+                // TC6: heartbeat yields no events
+                input: r#"{"event": "heartbeat"}"#.to_string(),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<KrakenCandles>(&test.input).unwrap();
+            let actual = transformer
+                .transform(input)
+                .into_iter()
+                .map(|result| {
+                    let candle = result.unwrap().kind;
+                    (
+                        candle.close_time.timestamp_millis(),
+                        candle.volume,
+                        candle.closed,
+                    )
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_kraken_candle_close_time() {
+        let end =
+            datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(1542057360.435743));
+        let expected =
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(1542057359999));
+
+        assert_eq!(kraken_candle_close_time(end), expected);
+    }
+
+    #[test]
+    fn test_kraken_candle_start_time() {
+        struct TestCase {
+            subscription_id: &'static str,
+            expected: Option<u64>,
+        }
+
+        let close_time =
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(1542057359999));
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m candle channel
+                subscription_id: "ohlc-1|XBT/USD",
+                expected: Some(1542057300000),
+            },
+            TestCase {
+                // TC1: 5m candle channel
+                subscription_id: "ohlc-5|XBT/USD",
+                expected: Some(1542057060000),
+            },
+            TestCase {
+                // TC2: non candle channel
+                subscription_id: "trade|XBT/USD",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                kraken_candle_start_time(&SubscriptionId::from(test.subscription_id), close_time)
+                    .ok()
+                    .map(|start_time| start_time.timestamp_millis() as u64);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use super::Kraken;
 use crate::{
-    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    error::UnsupportedIntervalError,
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleUpdates, Candles},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -22,6 +28,76 @@ impl KrakenChannel {
     ///
     /// See docs: <https://docs.kraken.com/websockets/#message-subscribe>
     pub const ORDER_BOOK_L1: Self = Self("spread");
+
+    /// [`Kraken`] placeholder channel name for a candle [`Interval`] that Kraken does not
+    /// support, which is rejected by [`Kraken`] subscription validation.
+    pub const CANDLES_UNSUPPORTED: Self = Self("ohlc-unsupported");
+
+    /// [`Kraken`] real-time candles channel name for the provided [`Interval`].
+    ///
+    /// [`Interval`]s without a [`KrakenOhlcInterval`] map to [`Self::CANDLES_UNSUPPORTED`].
+    ///
+    /// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+    pub fn candles(interval: Interval) -> Self {
+        KrakenOhlcInterval::try_from(interval)
+            .map_or(Self::CANDLES_UNSUPPORTED, |ohlc| ohlc.channel)
+    }
+
+    /// Determine the Kraken candle interval in minutes (eg/ "ohlc-5" -> 5), if this is a
+    /// supported candles channel.
+    pub fn candle_interval_minutes(&self) -> Option<u64> {
+        self.0
+            .strip_prefix("ohlc-")
+            .and_then(|minutes| minutes.parse().ok())
+    }
+}
+
+/// [`Kraken`] OHLC interval of a Barter [`Interval`], denominated in minutes (eg/ "ohlc-5").
+///
+/// Note: only 1m, 5m, 15m, 30m, 1h, 4h, 1d & 1w [`Interval`]s are supported.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-ohlc>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct KrakenOhlcInterval {
+    /// OHLC interval in minutes (eg/ 5).
+    pub minutes: u64,
+    /// OHLC channel of the interval (eg/ "ohlc-5").
+    pub channel: KrakenChannel,
+}
+
+impl TryFrom<Interval> for KrakenOhlcInterval {
+    type Error = UnsupportedIntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let (minutes, channel) = match interval {
+            Interval::Minute1 => (1, "ohlc-1"),
+            Interval::Minute5 => (5, "ohlc-5"),
+            Interval::Minute15 => (15, "ohlc-15"),
+            Interval::Minute30 => (30, "ohlc-30"),
+            Interval::Hour1 => (60, "ohlc-60"),
+            Interval::Hour4 => (240, "ohlc-240"),
+            Interval::Day1 => (1440, "ohlc-1440"),
+            Interval::Week1 => (10080, "ohlc-10080"),
+            Interval::Minute3
+            | Interval::Hour2
+            | Interval::Hour6
+            | Interval::Hour8
+            | Interval::Hour12
+            | Interval::Day3
+            | Interval::Month1
+            | Interval::Month3 => {
+                return Err(UnsupportedIntervalError {
+                    exchange: "kraken",
+                    interval,
+                })
+            }
+        };
+
+        Ok(Self {
+            minutes,
+            channel: KrakenChannel(channel),
+        })
+    }
 }
 
 impl Identifier<KrakenChannel> for Subscription<Kraken, PublicTrades> {
@@ -36,8 +112,92 @@ impl Identifier<KrakenChannel> for Subscription<Kraken, OrderBooksL1> {
     }
 }
 
+impl Identifier<KrakenChannel> for Subscription<Kraken, Candles> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::candles(self.kind.0)
+    }
+}
+
+impl Identifier<KrakenChannel> for Subscription<Kraken, CandleUpdates> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::candles(self.kind.0)
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::Connector;
+
+    #[test]
+    fn test_kraken_ohlc_interval_try_from_interval() {
+        // Expected OHLC channel of every Interval, in Interval::ALL order
+        let tests = [
+            (Interval::Minute1, Some("ohlc-1")),
+            (Interval::Minute3, None),
+            (Interval::Minute5, Some("ohlc-5")),
+            (Interval::Minute15, Some("ohlc-15")),
+            (Interval::Minute30, Some("ohlc-30")),
+            (Interval::Hour1, Some("ohlc-60")),
+            (Interval::Hour2, None),
+            (Interval::Hour4, Some("ohlc-240")),
+            (Interval::Hour6, None),
+            (Interval::Hour8, None),
+            (Interval::Hour12, None),
+            (Interval::Day1, Some("ohlc-1440")),
+            (Interval::Day3, None),
+            (Interval::Week1, Some("ohlc-10080")),
+            (Interval::Month1, None),
+            (Interval::Month3, None),
+        ];
+        assert_eq!(tests.map(|(interval, _)| interval), Interval::ALL);
+
+        for (interval, expected) in tests {
+            let actual = KrakenOhlcInterval::try_from(interval);
+            match expected {
+                Some(channel) => {
+                    let ohlc = actual.unwrap();
+                    assert_eq!(ohlc.channel, KrakenChannel(channel), "{interval} failed");
+                    assert_eq!(
+                        ohlc.channel.candle_interval_minutes(),
+                        Some(ohlc.minutes),
+                        "{interval} failed"
+                    );
+                    assert_eq!(
+                        i64::try_from(ohlc.minutes).ok(),
+                        Some(interval.duration().num_minutes()),
+                        "{interval} failed"
+                    );
+                    assert_eq!(KrakenChannel::candles(interval), ohlc.channel);
+                }
+                None => {
+                    assert_eq!(
+                        actual,
+                        Err(UnsupportedIntervalError {
+                            exchange: "kraken",
+                            interval
+                        }),
+                        "{interval} failed"
+                    );
+                    assert_eq!(
+                        KrakenChannel::candles(interval),
+                        KrakenChannel::CANDLES_UNSUPPORTED
+                    );
+                }
+            }
+
+            // Kraken supported_intervals are exactly those with a KrakenOhlcInterval
+            assert_eq!(
+                Kraken::supported_intervals().contains(&interval),
+                expected.is_some(),
+                "{interval} failed"
+            );
+        }
+    }
+}
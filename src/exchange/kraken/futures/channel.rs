@@ -0,0 +1,41 @@
+use super::KrakenFutures;
+use crate::{
+    subscription::{liquidation::Liquidations, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KrakenFutures`](super::KrakenFutures) feed to be subscribed to.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KrakenFuturesChannel(pub &'static str);
+
+impl KrakenFuturesChannel {
+    /// [`KrakenFutures`](super::KrakenFutures) real-time trades feed, sending an initial
+    /// "trade_snapshot" of recent trades followed by individual "trade" messages.
+    ///
+    /// Note: liquidations are published as trades of type "liquidation" on this feed.
+    ///
+    /// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+    pub const TRADES: Self = Self("trade");
+}
+
+impl Identifier<KrakenFuturesChannel> for Subscription<KrakenFutures, PublicTrades> {
+    fn id(&self) -> KrakenFuturesChannel {
+        KrakenFuturesChannel::TRADES
+    }
+}
+
+impl Identifier<KrakenFuturesChannel> for Subscription<KrakenFutures, Liquidations> {
+    fn id(&self) -> KrakenFuturesChannel {
+        KrakenFuturesChannel::TRADES
+    }
+}
+
+impl AsRef<str> for KrakenFuturesChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,78 @@
+use super::KrakenFutures;
+use crate::{subscription::Subscription, Identifier};
+use barter_integration::model::Instrument;
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KrakenFutures`](super::KrakenFutures) product id that can be subscribed to.
+///
+/// Barter [`Instrument`]s are mapped to inverse perpetual product ids (eg/ "PI_XBTUSD"), using
+/// the Kraken "XBT" symbol for bitcoin.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesMarket(pub String);
+
+impl<Kind> Identifier<KrakenFuturesMarket> for Subscription<KrakenFutures, Kind> {
+    fn id(&self) -> KrakenFuturesMarket {
+        KrakenFuturesMarket::from(&self.instrument)
+    }
+}
+
+impl From<&Instrument> for KrakenFuturesMarket {
+    fn from(instrument: &Instrument) -> Self {
+        let symbol = |symbol: &str| match symbol {
+            "btc" | "BTC" => "XBT".to_string(),
+            symbol => symbol.to_uppercase(),
+        };
+
+        Self(format!(
+            "PI_{}{}",
+            symbol(instrument.base.as_ref()),
+            symbol(instrument.quote.as_ref())
+        ))
+    }
+}
+
+impl AsRef<str> for KrakenFuturesMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_kraken_futures_market() {
+        struct TestCase {
+            input: Instrument,
+            expected: KrakenFuturesMarket,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: btc is mapped to the Kraken XBT symbol
+                input: Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual)),
+                expected: KrakenFuturesMarket("PI_XBTUSD".to_string()),
+            },
+            TestCase {
+                // TC1: other symbols are uppercased
+                input: Instrument::from(("eth", "usd", InstrumentKind::FuturePerpetual)),
+                expected: KrakenFuturesMarket("PI_ETHUSD".to_string()),
+            },
+            TestCase {
+                // TC2: uppercase btc is also mapped to XBT
+                input: Instrument::from(("BTC", "USD", InstrumentKind::FuturePerpetual)),
+                expected: KrakenFuturesMarket("PI_XBTUSD".to_string()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = KrakenFuturesMarket::from(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
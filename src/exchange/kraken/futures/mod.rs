@@ -0,0 +1,146 @@
+use self::{
+    channel::KrakenFuturesChannel, market::KrakenFuturesMarket,
+    subscription::KrakenFuturesSubResponse, trade::KrakenFuturesTrades,
+};
+use crate::{
+    decoder::PlainText,
+    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{liquidation::Liquidations, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`KrakenFutures`].
+pub mod subscription;
+
+/// Public trade & liquidation types for [`KrakenFutures`].
+pub mod trade;
+
+/// [`KrakenFutures`] server base url.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-websocket-api-introduction>
+pub const BASE_URL_KRAKEN_FUTURES: &str = "wss://futures.kraken.com/ws/v1";
+
+/// [`KrakenFutures`] requires a message at least every 60 seconds to keep the connection alive.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-websocket-api-introduction-subscriptions-keeping-the-connection-alive>
+pub const PING_INTERVAL_KRAKEN_FUTURES: Duration = Duration::from_secs(30);
+
+/// [`KrakenFutures`] (formerly Cryptofacilities) exchange.
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct KrakenFutures;
+
+impl Connector for KrakenFutures {
+    const ID: ExchangeId = ExchangeId::KrakenFutures;
+    type Channel = KrakenFuturesChannel;
+    type Market = KrakenFuturesMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KrakenFuturesSubResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KRAKEN_FUTURES).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_KRAKEN_FUTURES),
+            ping: || WsMessage::Ping(vec![]),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        kraken_futures_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        kraken_futures_requests("unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`KrakenFutures`] [`WsMessage`] payloads that action the provided `event` (eg/
+/// "subscribe", "unsubscribe") for a collection of [`ExchangeSub`]s.
+///
+/// One payload is sent per [`ExchangeSub`] so that exactly one "subscribed" response is received
+/// for each request.
+fn kraken_futures_requests(
+    event: &str,
+    exchange_subs: Vec<ExchangeSub<KrakenFuturesChannel, KrakenFuturesMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            WsMessage::Text(
+                json!({
+                    "event": event,
+                    "feed": channel.as_ref(),
+                    "product_ids": [market.as_ref()],
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+impl StreamSelector<PublicTrades> for KrakenFutures {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, KrakenFuturesTrades>>;
+}
+
+impl StreamSelector<Liquidations> for KrakenFutures {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Liquidations, KrakenFuturesTrades>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kraken_futures_requests() {
+        let requests = KrakenFutures::requests(vec![
+            ExchangeSub::from((
+                KrakenFuturesChannel::TRADES,
+                KrakenFuturesMarket("PI_XBTUSD".to_string()),
+            )),
+            ExchangeSub::from((
+                KrakenFuturesChannel::TRADES,
+                KrakenFuturesMarket("PI_ETHUSD".to_string()),
+            )),
+        ]);
+
+        assert_eq!(
+            requests,
+            vec![
+                WsMessage::Text(
+                    json!({"event": "subscribe", "feed": "trade", "product_ids": ["PI_XBTUSD"]})
+                        .to_string()
+                ),
+                WsMessage::Text(
+                    json!({"event": "subscribe", "feed": "trade", "product_ids": ["PI_ETHUSD"]})
+                        .to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`KrakenFutures`](super::KrakenFutures) message received in response to WebSocket
+/// subscription requests.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+/// #### Subscription Trade Success
+/// ```json
+/// {
+///   "event": "subscribed",
+///   "feed": "trade",
+///   "product_ids": ["PI_XBTUSD"]
+/// }
+/// ```
+///
+/// #### Subscription Trade Failure
+/// ```json
+/// {
+///   "event": "error",
+///   "message": "Invalid product id"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum KrakenFuturesSubResponse {
+    Subscribed {
+        feed: String,
+        product_ids: Vec<String>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl Validator for KrakenFuturesSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            KrakenFuturesSubResponse::Subscribed { .. } => Ok(self),
+            KrakenFuturesSubResponse::Error { message } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {message}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_futures_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenFuturesSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is Subscribed
+                    input: r#"{"event": "subscribed", "feed": "trade", "product_ids": ["PI_XBTUSD"]}"#,
+                    expected: Ok(KrakenFuturesSubResponse::Subscribed {
+                        feed: "trade".to_string(),
+                        product_ids: vec!["PI_XBTUSD".to_string()],
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"{"event": "error", "message": "Invalid product id"}"#,
+                    expected: Ok(KrakenFuturesSubResponse::Error {
+                        message: "Invalid product id".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: input is the info event sent upon connection, not a SubResponse
+                    input: r#"{"event": "info", "version": 1}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenFuturesSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_futures_sub_response_validate() {
+        struct TestCase {
+            input_response: KrakenFuturesSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: KrakenFuturesSubResponse::Subscribed {
+                    feed: "trade".to_string(),
+                    product_ids: vec!["PI_XBTUSD".to_string()],
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: KrakenFuturesSubResponse::Error {
+                    message: "Invalid product id".to_string(),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
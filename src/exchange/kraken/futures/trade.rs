@@ -0,0 +1,402 @@
+use super::channel::KrakenFuturesChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::{
+        liquidation::{Liquidation, LiquidationStatus},
+        number::{self, Number},
+        trade::PublicTrade,
+    },
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`KrakenFutures`](super::KrakenFutures) real-time trade feed message, either the initial
+/// snapshot of recent trades sent upon subscribing, or an individual trade.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+/// #### Trade Snapshot
+/// ```json
+/// {
+///   "feed": "trade_snapshot",
+///   "product_id": "PI_XBTUSD",
+///   "trades": [
+///     {
+///       "feed": "trade",
+///       "product_id": "PI_XBTUSD",
+///       "uid": "caa9c653-420b-4c24-a9f1-462a054d86f1",
+///       "side": "sell",
+///       "type": "fill",
+///       "seq": 655508,
+///       "time": 1612269657781,
+///       "qty": 440,
+///       "price": 34893
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### Trade
+/// ```json
+/// {
+///   "feed": "trade",
+///   "product_id": "PI_XBTUSD",
+///   "uid": "05af78ac-a774-478c-a50c-8b9c234e071e",
+///   "side": "sell",
+///   "type": "fill",
+///   "seq": 653355,
+///   "time": 1612266317519,
+///   "qty": 15000,
+///   "price": 34969.5
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(tag = "feed", rename_all = "snake_case")]
+pub enum KrakenFuturesTrades {
+    TradeSnapshot(KrakenFuturesTradeSnapshot),
+    Trade(KrakenFuturesTrade),
+}
+
+impl Identifier<Option<SubscriptionId>> for KrakenFuturesTrades {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::TradeSnapshot(snapshot) => Some(snapshot.subscription_id.clone()),
+            Self::Trade(trade) => Some(trade.subscription_id.clone()),
+        }
+    }
+}
+
+impl KrakenFuturesTrades {
+    /// Consume [`Self`], returning every [`KrakenFuturesTrade`] in ascending sequence order.
+    pub fn into_trades(self) -> Vec<KrakenFuturesTrade> {
+        match self {
+            Self::TradeSnapshot(snapshot) => {
+                let mut trades = snapshot.trades;
+                trades.sort_by_key(|trade| trade.sequence);
+                trades
+            }
+            Self::Trade(trade) => vec![trade],
+        }
+    }
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) snapshot of recent trades, sent upon subscribing to
+/// the trade feed.
+///
+/// See [`KrakenFuturesTrades`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesTradeSnapshot {
+    #[serde(rename = "product_id", deserialize_with = "de_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub trades: Vec<KrakenFuturesTrade>,
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) real-time trade.
+///
+/// Note: `amount` is the number of contracts traded (eg/ for "PI_XBTUSD" 1 contract is 1 USD).
+///
+/// See [`KrakenFuturesTrades`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KrakenFuturesTrade {
+    #[serde(rename = "product_id", deserialize_with = "de_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "uid")]
+    pub id: String,
+    #[serde(deserialize_with = "crate::subscription::trade::de_side_from_taker_side")]
+    pub side: Side,
+    #[serde(rename = "type")]
+    pub kind: KrakenFuturesTradeKind,
+    #[serde(rename = "seq")]
+    pub sequence: u64,
+    #[serde(deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "qty")]
+    pub amount: Number,
+    pub price: Number,
+}
+
+/// [`KrakenFutures`](super::KrakenFutures) classification of a [`KrakenFuturesTrade`].
+///
+/// See docs: <https://docs.futures.kraken.com/#websocket-api-public-feeds-trade>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenFuturesTradeKind {
+    Fill,
+    Liquidation,
+    Termination,
+    Block,
+}
+
+impl From<(ExchangeId, Instrument, KrakenFuturesTrades)> for MarketIter<PublicTrade> {
+    fn from(
+        (exchange_id, instrument, trades): (ExchangeId, Instrument, KrakenFuturesTrades),
+    ) -> Self {
+        trades
+            .into_trades()
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: PublicTrade {
+                        id: trade.id,
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                        buyer_order_id: None,
+                        seller_order_id: None,
+                        taker_order_kind: None,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<(ExchangeId, Instrument, KrakenFuturesTrades)> for MarketIter<Liquidation> {
+    fn from(
+        (exchange_id, instrument, trades): (ExchangeId, Instrument, KrakenFuturesTrades),
+    ) -> Self {
+        trades
+            .into_trades()
+            .into_iter()
+            .filter(|trade| trade.kind == KrakenFuturesTradeKind::Liquidation)
+            .map(|trade| {
+                Ok(MarketEvent {
+                    exchange_time: trade.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    // Liquidations are derived from liquidation trade fills
+                    kind: Liquidation {
+                        side: trade.side,
+                        price: number::to_f64(trade.price),
+                        average_price: Some(number::to_f64(trade.price)),
+                        filled_quantity: number::to_f64(trade.amount),
+                        total_quantity: number::to_f64(trade.amount),
+                        status: LiquidationStatus::Filled,
+                        time: trade.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize a [`KrakenFuturesTrade`] "product_id" (eg/ "PI_XBTUSD") as the associated
+/// [`SubscriptionId`] (eg/ "trade|PI_XBTUSD").
+pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((KrakenFuturesChannel::TRADES, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, error::SocketError, model::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    fn trade(sequence: u64, kind: KrakenFuturesTradeKind) -> KrakenFuturesTrade {
+        KrakenFuturesTrade {
+            subscription_id: SubscriptionId::from("trade|PI_XBTUSD"),
+            id: format!("uid-{sequence}"),
+            side: Side::Sell,
+            kind,
+            sequence,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1612269657781)),
+            amount: from_f64(440.0),
+            price: from_f64(34893.0),
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kraken_futures_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KrakenFuturesTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid trade snapshot
+                    input: r#"
+                    {
+                        "feed": "trade_snapshot",
+                        "product_id": "PI_XBTUSD",
+                        "trades": [
+                            {
+                                "feed": "trade", "product_id": "PI_XBTUSD",
+                                "uid": "uid-655508", "side": "sell", "type": "fill",
+                                "seq": 655508, "time": 1612269657781, "qty": 440,
+                                "price": 34893
+                            },
+                            {
+                                "feed": "trade", "product_id": "PI_XBTUSD",
+                                "uid": "uid-655507", "side": "sell", "type": "liquidation",
+                                "seq": 655507, "time": 1612269657781, "qty": 440,
+                                "price": 34893
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(KrakenFuturesTrades::TradeSnapshot(
+                        KrakenFuturesTradeSnapshot {
+                            subscription_id: SubscriptionId::from("trade|PI_XBTUSD"),
+                            trades: vec![
+                                trade(655508, KrakenFuturesTradeKind::Fill),
+                                trade(655507, KrakenFuturesTradeKind::Liquidation),
+                            ],
+                        },
+                    )),
+                },
+                TestCase {
+                    // TC1: valid individual trade
+                    input: r#"
+                    {
+                        "feed": "trade", "product_id": "PI_XBTUSD", "uid": "uid-655509",
+                        "side": "sell", "type": "fill", "seq": 655509,
+                        "time": 1612269657781, "qty": 440, "price": 34893.0
+                    }
+                    "#,
+                    expected: Ok(KrakenFuturesTrades::Trade(trade(
+                        655509,
+                        KrakenFuturesTradeKind::Fill,
+                    ))),
+                },
+                TestCase {
+                    // TC2: invalid trade w/ unknown type
+                    input: r#"
+                    {
+                        "feed": "trade", "product_id": "PI_XBTUSD", "uid": "uid-655509",
+                        "side": "sell", "type": "unknown", "seq": 655509,
+                        "time": 1612269657781, "qty": 440, "price": 34893.0
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: heartbeat feed is not a trade message
+                    input: r#"{"feed": "heartbeat", "time": 1612269657781}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KrakenFuturesTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_futures_trades_into_market_events() {
+        struct TestCase {
+            input: KrakenFuturesTrades,
+            expected_trades: Vec<&'static str>,
+            expected_liquidations: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: snapshot yields every trade in ascending sequence order
+                input: KrakenFuturesTrades::TradeSnapshot(KrakenFuturesTradeSnapshot {
+                    subscription_id: SubscriptionId::from("trade|PI_XBTUSD"),
+                    trades: vec![
+                        trade(3, KrakenFuturesTradeKind::Fill),
+                        trade(2, KrakenFuturesTradeKind::Liquidation),
+                        trade(1, KrakenFuturesTradeKind::Block),
+                    ],
+                }),
+                expected_trades: vec!["uid-1", "uid-2", "uid-3"],
+                expected_liquidations: vec!["uid-2"],
+            },
+            TestCase {
+                // TC1: individual fill is not a liquidation
+                input: KrakenFuturesTrades::Trade(trade(4, KrakenFuturesTradeKind::Fill)),
+                expected_trades: vec!["uid-4"],
+                expected_liquidations: vec![],
+            },
+            TestCase {
+                // TC2: individual liquidation is also a public trade
+                input: KrakenFuturesTrades::Trade(trade(5, KrakenFuturesTradeKind::Liquidation)),
+                expected_trades: vec!["uid-5"],
+                expected_liquidations: vec!["uid-5"],
+            },
+        ];
+
+        let instrument = Instrument::from(("btc", "usd", InstrumentKind::FuturePerpetual));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let MarketIter(trades) = MarketIter::<PublicTrade>::from((
+                ExchangeId::KrakenFutures,
+                instrument.clone(),
+                test.input.clone(),
+            ));
+            let actual_trades = trades
+                .into_iter()
+                .map(|event| event.unwrap().kind.id)
+                .collect::<Vec<_>>();
+            assert_eq!(actual_trades, test.expected_trades, "TC{} failed", index);
+
+            let MarketIter(liquidations) = MarketIter::<Liquidation>::from((
+                ExchangeId::KrakenFutures,
+                instrument.clone(),
+                test.input.clone(),
+            ));
+            let actual_liquidations = liquidations
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                actual_liquidations.len(),
+                test.expected_liquidations.len(),
+                "TC{} failed",
+                index
+            );
+            assert!(
+                actual_liquidations.iter().all(|liquidation| {
+                    liquidation.side == Side::Sell
+                        && liquidation.price == 34893.0
+                        && liquidation.total_quantity == 440.0
+                }),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
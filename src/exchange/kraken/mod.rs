@@ -1,26 +1,41 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::l1::KrakenOrderBookL1, candle::KrakenCandles, channel::KrakenChannel,
+    market::KrakenMarket, message::KrakenMessage, subscription::KrakenSubResponse,
+    trade::KrakenTrades,
 };
 use crate::{
+    decoder::PlainText,
     exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
     subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    subscription::{
+        book::OrderBooksL1,
+        candle::{CandleUpdates, Candles},
+        trade::PublicTrades,
+        Interval, Subscription,
+    },
+    transformer::{candle::HeldCandleTransformer, stateless::StatelessTransformer},
+    ExchangeWsStream, Identifier,
 };
 use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
+use std::fmt::Debug;
 use url::Url;
 
 /// Order book types for [`Kraken`]
 pub mod book;
 
+/// Candle types for [`Kraken`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// [`KrakenFutures`](futures::KrakenFutures) (formerly Cryptofacilities) [`Connector`] and
+/// [`StreamSelector`] implementations.
+pub mod futures;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`]  specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -55,28 +70,83 @@ impl Connector for Kraken {
     type Subscriber = WebSocketSubscriber;
     type SubValidator = WebSocketSubValidator;
     type SubResponse = KrakenSubResponse;
+    type Decoder = PlainText;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(BASE_URL_KRAKEN).map_err(SocketError::UrlParse)
     }
 
+    fn supported_intervals() -> &'static [Interval] {
+        &[
+            Interval::Minute1,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Hour1,
+            Interval::Hour4,
+            Interval::Day1,
+            Interval::Week1,
+        ]
+    }
+
+    fn validate_subscriptions<Kind>(
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<(), SocketError>
+    where
+        Kind: Debug,
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        match subscriptions
+            .iter()
+            .find(|subscription| subscription.id() == KrakenChannel::CANDLES_UNSUPPORTED)
+        {
+            Some(unsupported) => Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} (supported candle intervals: 1m, 5m, 15m, 30m, 1h, 4h, 1d, 1w)",
+                    unsupported.kind
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
-                WsMessage::Text(
-                    json!({
-                        "event": "subscribe",
-                        "pair": [market.as_ref()],
-                        "subscription": {
-                            "name": channel.as_ref()
-                        }
-                    })
-                    .to_string(),
-                )
-            })
-            .collect()
+        kraken_requests("subscribe", exchange_subs)
     }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        kraken_requests("unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`Kraken`] [`WsMessage`] payloads that action the provided `event` (eg/
+/// "subscribe", "unsubscribe") for a collection of [`ExchangeSub`]s.
+fn kraken_requests(
+    event: &str,
+    exchange_subs: Vec<ExchangeSub<KrakenChannel, KrakenMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            // Kraken candle subscriptions require the interval in minutes (eg/ "ohlc-5")
+            let subscription = match channel.candle_interval_minutes() {
+                Some(interval) => json!({ "name": "ohlc", "interval": interval }),
+                None => json!({ "name": channel.as_ref() }),
+            };
+
+            WsMessage::Text(
+                json!({
+                    "event": event,
+                    "pair": [market.as_ref()],
+                    "subscription": subscription
+                })
+                .to_string(),
+            )
+        })
+        .collect()
 }
 
 impl StreamSelector<PublicTrades> for Kraken {
@@ -86,3 +156,92 @@ impl StreamSelector<PublicTrades> for Kraken {
 impl StreamSelector<OrderBooksL1> for Kraken {
     type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, KrakenOrderBookL1>>;
 }
+
+impl StreamSelector<Candles> for Kraken {
+    type Stream =
+        ExchangeWsStream<HeldCandleTransformer<StatelessTransformer<Self, Candles, KrakenCandles>>>;
+}
+
+impl StreamSelector<CandleUpdates> for Kraken {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, CandleUpdates, KrakenCandles>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::Interval;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_kraken_validate_subscriptions() {
+        struct TestCase {
+            input: Vec<Subscription<Kraken, Candles>>,
+            expected: Result<(), SocketError>,
+        }
+
+        let candles = |interval| {
+            Subscription::from((
+                Kraken,
+                ("xbt", "usd", InstrumentKind::Spot),
+                Candles(interval),
+            ))
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: supported candle intervals
+                input: vec![candles(Interval::Minute1), candles(Interval::Week1)],
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: unsupported 3m candle interval
+                input: vec![candles(Interval::Minute1), candles(Interval::Minute3)],
+                expected: Err(SocketError::Unsupported {
+                    entity: "kraken",
+                    item: "Candles(Minute3)".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: unsupported 1M candle interval
+                input: vec![candles(Interval::Month1)],
+                expected: Err(SocketError::Unsupported {
+                    entity: "kraken",
+                    item: "Candles(Month1)".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Kraken::validate_subscriptions(&test.input);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kraken_requests_candles() {
+        let requests = Kraken::requests(vec![ExchangeSub::from((
+            KrakenChannel::candles(crate::subscription::Interval::Hour4),
+            KrakenMarket("XBT/USD".to_string()),
+        ))]);
+
+        assert_eq!(
+            requests,
+            vec![WsMessage::Text(
+                json!({
+                    "event": "subscribe",
+                    "pair": ["XBT/USD"],
+                    "subscription": { "name": "ohlc", "interval": 240 }
+                })
+                .to_string()
+            )]
+        );
+    }
+}
@@ -1,12 +1,16 @@
 use super::KrakenMessage;
 use crate::{
+    de::datetime_utc_from_f64_epoch_s,
     event::{MarketEvent, MarketIter},
     exchange::ExchangeId,
-    subscription::trade::PublicTrade,
+    subscription::{
+        number::Number,
+        trade::{PublicTrade, TakerOrderKind, TakerSide},
+    },
     Identifier,
 };
 use barter_integration::{
-    de::{datetime_utc_from_epoch_duration, extract_next},
+    de::extract_next,
     model::{Exchange, Instrument, Side, SubscriptionId},
 };
 use chrono::{DateTime, Utc};
@@ -33,11 +37,12 @@ pub struct KrakenTradesInner {
 /// See docs: <https://docs.kraken.com/websockets/#message-trade>
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Serialize)]
 pub struct KrakenTrade {
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "quantity")]
-    pub amount: f64,
+    pub amount: Number,
     pub time: DateTime<Utc>,
     pub side: Side,
+    pub order_kind: Option<TakerOrderKind>,
 }
 
 impl Identifier<Option<SubscriptionId>> for KrakenTradesInner {
@@ -70,11 +75,16 @@ impl From<(ExchangeId, Instrument, KrakenTrades)> for MarketIter<PublicTrade> {
                         received_time: Utc::now(),
                         exchange: Exchange::from(exchange_id),
                         instrument: instrument.clone(),
+                        sequence: None,
+                        tag: None,
                         kind: PublicTrade {
                             id: custom_kraken_trade_id(&trade),
                             price: trade.price,
                             amount: trade.amount,
                             side: trade.side,
+                            buyer_order_id: None,
+                            seller_order_id: None,
+                            taker_order_kind: trade.order_kind,
                         },
                     })
                 })
@@ -176,13 +186,34 @@ impl<'de> serde::de::Deserialize<'de> for KrakenTrade {
                 // Extract String price, parse to f64, map to DateTime<Utc>
                 let time = extract_next::<SeqAccessor, String>(&mut seq, "time")?
                     .parse()
-                    .map(|time| {
-                        datetime_utc_from_epoch_duration(std::time::Duration::from_secs_f64(time))
-                    })
-                    .map_err(serde::de::Error::custom)?;
-
-                // Extract Side
-                let side: Side = extract_next(&mut seq, "side")?;
+                    .map_err(serde::de::Error::custom)
+                    .and_then(|time| {
+                        datetime_utc_from_f64_epoch_s(time).ok_or_else(|| {
+                            serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Float(time),
+                                &"an epoch within the DateTime<Utc> range",
+                            )
+                        })
+                    })?;
+
+                // Extract String taker side (ie/ "b" or "s") & map to Side
+                let side =
+                    extract_next::<SeqAccessor, String>(&mut seq, "side").and_then(|side| {
+                        Side::from_taker_side(&side).ok_or_else(|| {
+                            serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Str(&side),
+                                &"Kraken taker side of b or s",
+                            )
+                        })
+                    })?;
+
+                // Extract String orderType (ie/ "m" or "l") & map to TakerOrderKind
+                let order_kind =
+                    match extract_next::<SeqAccessor, String>(&mut seq, "orderType")?.as_str() {
+                        "m" => Some(TakerOrderKind::Market),
+                        "l" => Some(TakerOrderKind::Limit),
+                        _ => None,
+                    };
 
                 // Ignore any additional elements or SerDe will fail
                 //  '--> Exchange may add fields without warning
@@ -193,6 +224,7 @@ impl<'de> serde::de::Deserialize<'de> for KrakenTrade {
                     amount,
                     time,
                     side,
+                    order_kind,
                 })
             }
         }
@@ -205,6 +237,7 @@ impl<'de> serde::de::Deserialize<'de> for KrakenTrade {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
@@ -250,20 +283,22 @@ mod tests {
                     subscription_id: SubscriptionId::from("trade|XBT/USD"),
                     trades: vec![
                         KrakenTrade {
-                            price: 5541.2,
-                            amount: 0.15850568,
+                            price: from_f64(5541.2),
+                            amount: from_f64(0.15850568),
                             time: datetime_utc_from_epoch_duration(
                                 std::time::Duration::from_secs_f64(1534614057.321597),
                             ),
                             side: Side::Sell,
+                            order_kind: Some(TakerOrderKind::Limit),
                         },
                         KrakenTrade {
-                            price: 6060.0,
-                            amount: 0.02455000,
+                            price: from_f64(6060.0),
+                            amount: from_f64(0.02455000),
                             time: datetime_utc_from_epoch_duration(
                                 std::time::Duration::from_secs_f64(1534614057.324998),
                             ),
                             side: Side::Buy,
+                            order_kind: Some(TakerOrderKind::Limit),
                         },
                     ],
                 })),
@@ -286,4 +321,33 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_kraken_trades_taker_order_kind() {
+        let trades = serde_json::from_str::<KrakenTrades>(
+            r#"[0,[["5541.2","0.1","1534614057.321597","s","m",""],["6060.0","0.2","1534614057.324998","b","l",""],["6060.0","0.2","1534614057.324998","b","x",""]],"trade","XBT/USD"]"#,
+        )
+        .unwrap();
+        let instrument = Instrument::from((
+            "xbt",
+            "usd",
+            barter_integration::model::InstrumentKind::Spot,
+        ));
+
+        let actual = MarketIter::<PublicTrade>::from((ExchangeId::Kraken, instrument, trades))
+            .0
+            .into_iter()
+            .map(|trade| trade.unwrap().kind.taker_order_kind)
+            .collect::<Vec<_>>();
+
+        // Unknown order types are mapped to None
+        assert_eq!(
+            actual,
+            vec![
+                Some(TakerOrderKind::Market),
+                Some(TakerOrderKind::Limit),
+                None
+            ]
+        );
+    }
 }
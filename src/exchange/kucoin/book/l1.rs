@@ -0,0 +1,166 @@
+use crate::{
+    event::{MarketEvent, MarketIter, TimeSource},
+    exchange::{kucoin::message::KucoinMessage, ExchangeId},
+    subscription::{
+        book::{Level, OrderBookL1},
+        number::Number,
+    },
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`KucoinSpot`](super::super::KucoinSpot) real-time OrderBook Level1
+/// (top of book) WebSocket message.
+pub type KucoinOrderBookL1 = KucoinMessage<KucoinOrderBookL1Inner>;
+
+/// [`KucoinSpot`](super::super::KucoinSpot) real-time OrderBook Level1 (top of book) ticker.
+///
+/// See [`KucoinMessage`] for full raw payload examples.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/ticker>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinOrderBookL1Inner {
+    #[serde(
+        alias = "Time",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "bestBid", deserialize_with = "barter_integration::de::de_str")]
+    pub best_bid_price: Number,
+    #[serde(
+        alias = "bestBidSize",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_amount: Number,
+    #[serde(alias = "bestAsk", deserialize_with = "barter_integration::de::de_str")]
+    pub best_ask_price: Number,
+    #[serde(
+        alias = "bestAskSize",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_amount: Number,
+}
+
+impl From<(ExchangeId, Instrument, KucoinOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, KucoinOrderBookL1)) -> Self {
+        match book {
+            KucoinMessage::Data(payload) => Self(vec![Ok(MarketEvent {
+                exchange_time: payload.data.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                sequence: None,
+                tag: None,
+                kind: OrderBookL1 {
+                    last_update_time: payload.data.time,
+                    best_bid: Level::new(payload.data.best_bid_price, payload.data.best_bid_amount),
+                    best_ask: Level::new(payload.data.best_ask_price, payload.data.best_ask_amount),
+                    time_source: TimeSource::Exchange,
+                },
+            })]),
+            KucoinMessage::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::exchange::kucoin::message::{KucoinEvent, KucoinPayload};
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_kucoin_message_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KucoinOrderBookL1, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input KucoinOrderBookL1 is deserialised
+                    input: r#"
+                    {
+                        "type": "message",
+                        "topic": "/market/ticker:BTC-USDT",
+                        "subject": "trade.ticker",
+                        "data": {
+                            "sequence": "1545896668986",
+                            "price": "0.08",
+                            "size": "0.011",
+                            "bestAsk": "0.08",
+                            "bestAskSize": "0.18",
+                            "bestBid": "0.049",
+                            "bestBidSize": "0.036",
+                            "Time": 1704873323416
+                        }
+                    }
+                    "#,
+                    expected: Ok(KucoinOrderBookL1::Data(KucoinPayload {
+                        subscription_id: SubscriptionId::from("/market/ticker|BTC-USDT"),
+                        data: KucoinOrderBookL1Inner {
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1704873323416,
+                            )),
+                            best_bid_price: from_f64(0.049),
+                            best_bid_amount: from_f64(0.036),
+                            best_ask_price: from_f64(0.08),
+                            best_ask_amount: from_f64(0.18),
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input welcome is KucoinMessage::Event
+                    input: r#"{"id": "hQvf8jkno", "type": "welcome"}"#,
+                    expected: Ok(KucoinOrderBookL1::Event(KucoinEvent::Welcome {
+                        id: "hQvf8jkno".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input KucoinOrderBookL1 w/ missing bestBid fails to deserialise
+                    input: r#"
+                    {
+                        "type": "message",
+                        "topic": "/market/ticker:BTC-USDT",
+                        "subject": "trade.ticker",
+                        "data": {
+                            "bestAsk": "0.08",
+                            "bestAskSize": "0.18",
+                            "bestBidSize": "0.036",
+                            "Time": 1704873323416
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinOrderBookL1>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use super::KucoinSpot;
+use crate::{
+    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KucoinSpot`](super::KucoinSpot) channel to be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KucoinChannel(pub &'static str);
+
+impl KucoinChannel {
+    /// [`KucoinSpot`](super::KucoinSpot) real-time trades channel name.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+    pub const TRADES: Self = Self("/market/match");
+
+    /// [`KucoinSpot`](super::KucoinSpot) real-time OrderBook Level1 (top of book) channel name.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/ticker>
+    pub const ORDER_BOOK_L1: Self = Self("/market/ticker");
+}
+
+impl Identifier<KucoinChannel> for Subscription<KucoinSpot, PublicTrades> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::TRADES
+    }
+}
+
+impl Identifier<KucoinChannel> for Subscription<KucoinSpot, OrderBooksL1> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::ORDER_BOOK_L1
+    }
+}
+
+impl AsRef<str> for KucoinChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
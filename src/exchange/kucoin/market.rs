@@ -0,0 +1,22 @@
+use super::KucoinSpot;
+use crate::{subscription::Subscription, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`KucoinSpot`](super::KucoinSpot) market that can be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinMarket(pub String);
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<KucoinSpot, Kind> {
+    fn id(&self) -> KucoinMarket {
+        KucoinMarket(format!("{}-{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for KucoinMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{exchange::ExchangeSub, Identifier};
+use barter_integration::model::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`KucoinSpot`](super::KucoinSpot) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+///
+/// #### Trades
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/market/match:BTC-USDT",
+///     "subject": "trade.l3match",
+///     "data": {
+///         "sequence": "1545896669145",
+///         "type": "match",
+///         "symbol": "BTC-USDT",
+///         "side": "buy",
+///         "price": "0.08200000000000000000",
+///         "size": "0.01022222000000000000",
+///         "tradeId": "5c24c5da03aa673885cd67aa",
+///         "takerOrderId": "5c24c5d903aa6772d55b371e",
+///         "makerOrderId": "5c2187d003aa677bd09d5c93",
+///         "time": "1545913818099033203"
+///     }
+/// }
+/// ```
+///
+/// #### OrderBookL1
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/ticker>
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/market/ticker:BTC-USDT",
+///     "subject": "trade.ticker",
+///     "data": {
+///         "sequence": "1545896668986",
+///         "price": "0.08",
+///         "size": "0.011",
+///         "bestAsk": "0.08",
+///         "bestAskSize": "0.18",
+///         "bestBid": "0.049",
+///         "bestBidSize": "0.036",
+///         "Time": 1704873323416
+///     }
+/// }
+/// ```
+///
+/// #### Pong
+/// ```json
+/// {
+///     "id": "1545910590801",
+///     "type": "pong"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum KucoinMessage<T> {
+    Data(KucoinPayload<T>),
+    Event(KucoinEvent),
+}
+
+/// [`KucoinSpot`](super::KucoinSpot) market data payload containing the generic `T` data.
+///
+/// See [`KucoinMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinPayload<T> {
+    #[serde(
+        alias = "topic",
+        deserialize_with = "de_kucoin_topic_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+/// [`KucoinSpot`](super::KucoinSpot) non market data message.
+///
+/// See [`KucoinMessage`] for full raw payload examples.
+///
+/// ## Notes:
+/// - Variants are explicit rather than catch-all so that malformed "message" payloads fail to
+///   deserialise instead of being silently skipped.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinEvent {
+    Welcome { id: String },
+    Ack { id: String },
+    Pong { id: String },
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for KucoinMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            KucoinMessage::Data(payload) => Some(payload.subscription_id.clone()),
+            KucoinMessage::Event(_) => None,
+        }
+    }
+}
+
+/// Deserialize a [`KucoinPayload`] "topic" (eg/ "/market/match:BTC-USDT") as the associated
+/// [`SubscriptionId`] (eg/ "/market/match|BTC-USDT").
+pub fn de_kucoin_topic_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let topic = <&str as Deserialize>::deserialize(deserializer)?;
+
+    topic
+        .split_once(':')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(topic),
+                &"Kucoin topic in the format {channel}:{market}",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_kucoin_message_event() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KucoinMessage<serde_json::Value>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input welcome is KucoinMessage::Event
+                    input: r#"{"id": "hQvf8jkno", "type": "welcome"}"#,
+                    expected: Ok(KucoinMessage::Event(KucoinEvent::Welcome {
+                        id: "hQvf8jkno".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC1: input pong is KucoinMessage::Event
+                    input: r#"{"id": "1545910590801", "type": "pong"}"#,
+                    expected: Ok(KucoinMessage::Event(KucoinEvent::Pong {
+                        id: "1545910590801".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input late subscription ack is KucoinMessage::Event
+                    input: r#"{"id": "1545910660739", "type": "ack"}"#,
+                    expected: Ok(KucoinMessage::Event(KucoinEvent::Ack {
+                        id: "1545910660739".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC3: input topic without a market is invalid
+                    input: r#"{"type": "message", "topic": "/market/match", "data": {}}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinMessage<serde_json::Value>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_kucoin_topic_as_subscription_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades topic
+                input: r#""/market/match:BTC-USDT""#,
+                expected: SubscriptionId::from("/market/match|BTC-USDT"),
+            },
+            TestCase {
+                // TC1: order book l1 topic
+                input: r#""/market/ticker:ETH-USDT""#,
+                expected: SubscriptionId::from("/market/ticker|ETH-USDT"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut deserializer = serde_json::Deserializer::from_str(test.input);
+            let actual = de_kucoin_topic_as_subscription_id(&mut deserializer).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
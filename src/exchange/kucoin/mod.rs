@@ -0,0 +1,230 @@
+use self::{
+    book::l1::KucoinOrderBookL1, channel::KucoinChannel, market::KucoinMarket,
+    subscriber::KucoinSubscriber, subscription::KucoinSubResponse, trade::KucoinTrades,
+};
+use crate::{
+    decoder::PlainText,
+    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    subscriber::validator::WebSocketSubValidator,
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use chrono::Utc;
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// Order book types for [`KucoinSpot`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`KucoinMessage`](message::KucoinMessage) type for [`KucoinSpot`].
+pub mod message;
+
+/// [`KucoinSubscriber`](subscriber::KucoinSubscriber) implementing the token-based connect
+/// handshake for [`KucoinSpot`].
+pub mod subscriber;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`KucoinSpot`].
+pub mod subscription;
+
+/// Public trade types for [`KucoinSpot`].
+pub mod trade;
+
+/// [`KucoinSpot`] default WebSocket server base url.
+///
+/// ### Notes
+/// A connection token is required to connect, so the actual [`Url`] (including the token) is
+/// resolved by the [`KucoinSubscriber`].
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/create-connection>
+pub const BASE_URL_KUCOIN_SPOT: &str = "wss://ws-api-spot.kucoin.com/";
+
+/// [`KucoinSpot`] interval between application-level pings, which must be less than the
+/// server provided "pingInterval" of 18s to keep the connection alive.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+pub const PING_INTERVAL_KUCOIN_SPOT: Duration = Duration::from_secs(15);
+
+/// [`KucoinSpot`] exchange.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/introduction>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct KucoinSpot;
+
+impl Connector for KucoinSpot {
+    const ID: ExchangeId = ExchangeId::KucoinSpot;
+    type Channel = KucoinChannel;
+    type Market = KucoinMarket;
+    type Subscriber = KucoinSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KucoinSubResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KUCOIN_SPOT).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_KUCOIN_SPOT),
+            ping: || {
+                WsMessage::Text(
+                    json!({
+                        "id": Utc::now().timestamp_millis().to_string(),
+                        "type": "ping",
+                    })
+                    .to_string(),
+                )
+            },
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        kucoin_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        kucoin_requests("unsubscribe", exchange_subs)
+    }
+}
+
+/// Construct the [`Kucoin`] [`WsMessage`] payloads that action the provided message `type` (eg/
+/// "subscribe", "unsubscribe") for a collection of [`ExchangeSub`]s.
+fn kucoin_requests(
+    message_type: &str,
+    exchange_subs: Vec<ExchangeSub<KucoinChannel, KucoinMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .enumerate()
+        .map(|(index, ExchangeSub { channel, market })| {
+            WsMessage::Text(
+                json!({
+                    "id": index.to_string(),
+                    "type": message_type,
+                    "topic": format!("{}:{}", channel.as_ref(), market.as_ref()),
+                    "privateChannel": false,
+                    "response": true,
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+impl StreamSelector<PublicTrades> for KucoinSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, KucoinTrades>>;
+}
+
+impl StreamSelector<OrderBooksL1> for KucoinSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, KucoinOrderBookL1>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{subscription::Subscription, Identifier};
+    use barter_integration::model::{InstrumentKind, SubscriptionId};
+
+    #[test]
+    fn test_kucoin_requests() {
+        let actual = KucoinSpot::requests(vec![
+            ExchangeSub::from((KucoinChannel::TRADES, KucoinMarket("BTC-USDT".to_string()))),
+            ExchangeSub::from((
+                KucoinChannel::ORDER_BOOK_L1,
+                KucoinMarket("ETH-USDT".to_string()),
+            )),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![
+                WsMessage::Text(
+                    json!({
+                        "id": "0",
+                        "type": "subscribe",
+                        "topic": "/market/match:BTC-USDT",
+                        "privateChannel": false,
+                        "response": true,
+                    })
+                    .to_string()
+                ),
+                WsMessage::Text(
+                    json!({
+                        "id": "1",
+                        "type": "subscribe",
+                        "topic": "/market/ticker:ETH-USDT",
+                        "privateChannel": false,
+                        "response": true,
+                    })
+                    .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_kucoin_subscription_id() {
+        struct TestCase {
+            input: SubscriptionId,
+            expected: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: PublicTrades Subscription id matches trades message topic
+                input: {
+                    let sub = Subscription::<_, PublicTrades>::new(
+                        KucoinSpot,
+                        ("btc", "usdt", InstrumentKind::Spot),
+                        PublicTrades,
+                    );
+                    ExchangeSub::<KucoinChannel, KucoinMarket>::new(&sub).id()
+                },
+                expected: serde_json::from_str::<KucoinTrades>(
+                    r#"{"type":"message","topic":"/market/match:BTC-USDT","subject":"trade.l3match","data":{"side":"buy","price":"1.0","size":"1.0","tradeId":"1","time":"1545913818099033203"}}"#,
+                )
+                .unwrap()
+                .id()
+                .unwrap(),
+            },
+            TestCase {
+                // TC1: OrderBooksL1 Subscription id matches ticker message topic
+                input: {
+                    let sub = Subscription::<_, OrderBooksL1>::new(
+                        KucoinSpot,
+                        ("eth", "usdt", InstrumentKind::Spot),
+                        OrderBooksL1,
+                    );
+                    ExchangeSub::<KucoinChannel, KucoinMarket>::new(&sub).id()
+                },
+                expected: serde_json::from_str::<KucoinOrderBookL1>(
+                    r#"{"type":"message","topic":"/market/ticker:ETH-USDT","subject":"trade.ticker","data":{"bestAsk":"1.0","bestAskSize":"1.0","bestBid":"1.0","bestBidSize":"1.0","Time":1704873323416}}"#,
+                )
+                .unwrap()
+                .id()
+                .unwrap(),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input, test.expected, "TC{} failed", index);
+        }
+    }
+}
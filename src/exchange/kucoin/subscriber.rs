@@ -0,0 +1,270 @@
+use crate::{
+    exchange::Connector,
+    subscriber::{
+        connection::ConnectionOptions, mapper::WebSocketSubMapper, validator::SubscriptionOutcome,
+        Subscriber, WebSocketSubscriber,
+    },
+    subscription::{Map, SubKind, Subscription},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{error::SocketError, model::Instrument, protocol::websocket::WebSocket};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::debug;
+use url::Url;
+
+/// [`KucoinSpot`](super::KucoinSpot) HTTP endpoint used to request a public WebSocket connection
+/// token and the associated WebSocket server endpoint.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+pub const HTTP_BULLET_PUBLIC_URL_KUCOIN: &str = "https://api.kucoin.com/api/v1/bullet-public";
+
+/// [`KucoinSpot`](super::KucoinSpot) HTTP response code indicating success.
+pub const HTTP_SUCCESS_CODE_KUCOIN: &str = "200000";
+
+/// [`Subscriber`] for [`KucoinSpot`](super::KucoinSpot) that resolves the WebSocket [`Url`] via
+/// the token-based connect handshake before subscribing.
+///
+/// ### Notes
+/// [`KucoinSpot`](super::KucoinSpot) requires a connection token, requested via HTTP, to be
+/// provided as a query parameter of the WebSocket [`Url`]. Once connected, subscribing is
+/// identical to the standard [`WebSocketSubscriber`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinSubscriber;
+
+#[async_trait]
+impl Subscriber for KucoinSubscriber {
+    type SubMapper = WebSocketSubMapper;
+
+    async fn subscribe<Exchange, Kind>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        timeout: Duration,
+        options: &ConnectionOptions,
+    ) -> Result<(WebSocket, Map<Instrument>, SubscriptionOutcome), SocketError>
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubKind + Send + Sync,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Exchange::validate_subscriptions(subscriptions)?;
+
+        // Kucoin has no public sandbox server, so reject any non-production Environment
+        Exchange::url_for(options.environment)?;
+
+        // Request a public connection token & WebSocket server endpoint
+        let bullet = reqwest::Client::new()
+            .post(HTTP_BULLET_PUBLIC_URL_KUCOIN)
+            .send()
+            .await
+            .map_err(SocketError::Http)?
+            .json::<KucoinBulletResponse>()
+            .await
+            .map_err(SocketError::Http)?
+            .validate()?;
+
+        let url = bullet.websocket_url(&Utc::now().timestamp_millis().to_string())?;
+        debug!(exchange = %Exchange::ID, %url, "resolved Kucoin WebSocket url");
+
+        WebSocketSubscriber::subscribe_via_url(url, subscriptions, timeout, options).await
+    }
+}
+
+/// [`KucoinSpot`](super::KucoinSpot) public connection token HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+/// ```json
+/// {
+///     "code": "200000",
+///     "data": {
+///         "token": "2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzUOPusi34M_wGoSf7iNyEWJ4aBZXpWhrmY9jKtqkdWoFa75w3istPvPtiYB9J6i9GjsxUuhPw3BlrzazF6ghq4L_u0MhKxG3x8TeN4aVbNiYo=.mvnekBb8DJegZIgYLs2FBQ==",
+///         "instanceServers": [
+///             {
+///                 "endpoint": "wss://ws-api-spot.kucoin.com/",
+///                 "encrypt": true,
+///                 "protocol": "websocket",
+///                 "pingInterval": 18000,
+///                 "pingTimeout": 10000
+///             }
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinBulletResponse {
+    pub code: String,
+    pub data: Option<KucoinBullet>,
+}
+
+impl KucoinBulletResponse {
+    /// Validate the [`KucoinBulletResponse`] was successful, returning the inner [`KucoinBullet`].
+    pub fn validate(self) -> Result<KucoinBullet, SocketError> {
+        match (self.code.as_str(), self.data) {
+            (HTTP_SUCCESS_CODE_KUCOIN, Some(bullet)) => Ok(bullet),
+            (code, _) => Err(SocketError::Subscribe(format!(
+                "failed to request Kucoin connection token, received response code: {code}"
+            ))),
+        }
+    }
+}
+
+/// [`KucoinSpot`](super::KucoinSpot) public connection token and the WebSocket servers it is
+/// valid for.
+///
+/// See [`KucoinBulletResponse`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinBullet {
+    pub token: String,
+    #[serde(alias = "instanceServers")]
+    pub instance_servers: Vec<KucoinInstanceServer>,
+}
+
+/// [`KucoinSpot`](super::KucoinSpot) WebSocket server instance.
+///
+/// See [`KucoinBulletResponse`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinInstanceServer {
+    pub endpoint: String,
+    #[serde(alias = "pingInterval")]
+    pub ping_interval_ms: u64,
+}
+
+impl KucoinBullet {
+    /// Construct the WebSocket [`Url`] to connect to using the first instance server endpoint,
+    /// the connection token, and the provided unique `connect_id`.
+    ///
+    /// eg/ "wss://ws-api-spot.kucoin.com/?token=xxx&connectId=1545910590801"
+    pub fn websocket_url(&self, connect_id: &str) -> Result<Url, SocketError> {
+        let server = self.instance_servers.first().ok_or_else(|| {
+            SocketError::Subscribe(
+                "Kucoin connection token response contained no instance servers".to_string(),
+            )
+        })?;
+
+        Url::parse_with_params(
+            &server.endpoint,
+            &[("token", self.token.as_str()), ("connectId", connect_id)],
+        )
+        .map_err(SocketError::UrlParse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kucoin_bullet_response_validate() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<KucoinBullet, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: successful response yields KucoinBullet
+                input: r#"
+                {
+                    "code": "200000",
+                    "data": {
+                        "token": "token123",
+                        "instanceServers": [
+                            {
+                                "endpoint": "wss://ws-api-spot.kucoin.com/",
+                                "encrypt": true,
+                                "protocol": "websocket",
+                                "pingInterval": 18000,
+                                "pingTimeout": 10000
+                            }
+                        ]
+                    }
+                }
+                "#,
+                expected: Ok(KucoinBullet {
+                    token: "token123".to_string(),
+                    instance_servers: vec![KucoinInstanceServer {
+                        endpoint: "wss://ws-api-spot.kucoin.com/".to_string(),
+                        ping_interval_ms: 18000,
+                    }],
+                }),
+            },
+            TestCase {
+                // TC1: failure response code yields error
+                input: r#"{"code": "429000", "msg": "Too Many Requests"}"#,
+                expected: Err(SocketError::Subscribe(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<KucoinBulletResponse>(test.input)
+                .unwrap()
+                .validate();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kucoin_bullet_websocket_url() {
+        struct TestCase {
+            input: KucoinBullet,
+            expected: Result<&'static str, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: url built from first instance server w/ token & connectId params
+                input: KucoinBullet {
+                    token: "token123".to_string(),
+                    instance_servers: vec![
+                        KucoinInstanceServer {
+                            endpoint: "wss://ws-api-spot.kucoin.com/".to_string(),
+                            ping_interval_ms: 18000,
+                        },
+                        KucoinInstanceServer {
+                            endpoint: "wss://other.kucoin.com/".to_string(),
+                            ping_interval_ms: 18000,
+                        },
+                    ],
+                },
+                expected: Ok("wss://ws-api-spot.kucoin.com/?token=token123&connectId=1"),
+            },
+            TestCase {
+                // TC1: no instance servers yields error
+                input: KucoinBullet {
+                    token: "token123".to_string(),
+                    instance_servers: vec![],
+                },
+                expected: Err(SocketError::Subscribe(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.websocket_url("1");
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.as_str(), expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use super::message::KucoinMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{number::Number, trade::PublicTrade},
+};
+use barter_integration::{
+    de::datetime_utc_from_epoch_duration,
+    model::{Exchange, Instrument, Side},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`KucoinSpot`](super::KucoinSpot) real-time trades WebSocket message.
+pub type KucoinTrades = KucoinMessage<KucoinTrade>;
+
+/// [`KucoinSpot`](super::KucoinSpot) real-time trade.
+///
+/// See [`KucoinMessage`] for full raw payload examples.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinTrade {
+    #[serde(alias = "tradeId")]
+    pub id: String,
+    #[serde(deserialize_with = "de_str_epoch_ns_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: Number,
+    #[serde(deserialize_with = "crate::subscription::trade::de_side_from_taker_side")]
+    pub side: Side,
+}
+
+impl From<(ExchangeId, Instrument, KucoinTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, KucoinTrades)) -> Self {
+        match trades {
+            KucoinMessage::Data(payload) => Self(vec![Ok(MarketEvent {
+                exchange_time: payload.data.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                sequence: None,
+                tag: None,
+                kind: PublicTrade {
+                    id: payload.data.id,
+                    price: payload.data.price,
+                    amount: payload.data.amount,
+                    side: payload.data.side,
+                    buyer_order_id: None,
+                    seller_order_id: None,
+                    taker_order_kind: None,
+                },
+            })]),
+            KucoinMessage::Event(_) => Self(vec![]),
+        }
+    }
+}
+
+/// Deserialize a [`KucoinTrade`] String epoch nanoseconds "time" (eg/ "1545913818099033203") as
+/// a [`DateTime<Utc>`].
+fn de_str_epoch_ns_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    barter_integration::de::de_str::<D, u64>(deserializer)
+        .map(|epoch_ns| datetime_utc_from_epoch_duration(std::time::Duration::from_nanos(epoch_ns)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::exchange::kucoin::message::{KucoinEvent, KucoinPayload};
+        use barter_integration::{error::SocketError, model::SubscriptionId};
+        use std::time::Duration;
+
+        #[test]
+        fn test_kucoin_message_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KucoinTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input KucoinTrades is deserialised
+                    input: r#"
+                    {
+                        "type": "message",
+                        "topic": "/market/match:BTC-USDT",
+                        "subject": "trade.l3match",
+                        "data": {
+                            "sequence": "1545896669145",
+                            "type": "match",
+                            "symbol": "BTC-USDT",
+                            "side": "buy",
+                            "price": "0.08200000000000000000",
+                            "size": "0.01022222000000000000",
+                            "tradeId": "5c24c5da03aa673885cd67aa",
+                            "takerOrderId": "5c24c5d903aa6772d55b371e",
+                            "makerOrderId": "5c2187d003aa677bd09d5c93",
+                            "time": "1545913818099033203"
+                        }
+                    }
+                    "#,
+                    expected: Ok(KucoinTrades::Data(KucoinPayload {
+                        subscription_id: SubscriptionId::from("/market/match|BTC-USDT"),
+                        data: KucoinTrade {
+                            id: "5c24c5da03aa673885cd67aa".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_nanos(
+                                1545913818099033203,
+                            )),
+                            price: from_f64(0.082),
+                            amount: from_f64(0.01022222),
+                            side: Side::Buy,
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input pong is KucoinMessage::Event
+                    input: r#"{"id": "1545910590801", "type": "pong"}"#,
+                    expected: Ok(KucoinTrades::Event(KucoinEvent::Pong {
+                        id: "1545910590801".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input KucoinTrades w/ invalid price fails to deserialise
+                    input: r#"
+                    {
+                        "type": "message",
+                        "topic": "/market/match:BTC-USDT",
+                        "subject": "trade.l3match",
+                        "data": {
+                            "side": "buy",
+                            "price": "not a number",
+                            "size": "0.01022222000000000000",
+                            "tradeId": "5c24c5da03aa673885cd67aa",
+                            "time": "1545913818099033203"
+                        }
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinTrades>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    mod transformer {
+        use super::*;
+        use crate::{
+            exchange::kucoin::KucoinSpot,
+            subscription::{trade::PublicTrades, Map},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::{
+            model::{InstrumentKind, SubscriptionId},
+            Transformer,
+        };
+        use std::collections::HashMap;
+        use tokio::sync::mpsc;
+
+        #[tokio::test]
+        async fn test_kucoin_pong_is_swallowed() {
+            let instrument_map = Map(HashMap::from([(
+                SubscriptionId::from("/market/match|BTC-USDT"),
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            )]));
+            let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+            let mut transformer = <StatelessTransformer<KucoinSpot, PublicTrades, KucoinTrades> as ExchangeTransformer<KucoinSpot, PublicTrades>>::new(
+                ws_sink_tx,
+                instrument_map,
+            )
+            .await
+            .unwrap();
+
+            struct TestCase {
+                input: &'static str,
+                expected_events: usize,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: pong answering an application-level ping yields no events or errors
+                    input: r#"{"id": "1545910590801", "type": "pong"}"#,
+                    expected_events: 0,
+                },
+                TestCase {
+                    // TC1: trade following the pong is transformed
+                    input: r#"{"type":"message","topic":"/market/match:BTC-USDT","subject":"trade.l3match","data":{"side":"buy","price":"0.082","size":"0.01","tradeId":"5c24c5da03aa673885cd67aa","time":"1545913818099033203"}}"#,
+                    expected_events: 1,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let input = serde_json::from_str::<KucoinTrades>(test.input).unwrap();
+                let actual = transformer.transform(input);
+                assert_eq!(actual.len(), test.expected_events, "TC{} failed", index);
+                assert!(actual.iter().all(Result::is_ok), "TC{} failed", index);
+            }
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    event::{MarketEvent, MarketIter, TimeSource},
+    exchange::{mexc::message::MexcMessage, ExchangeId},
+    subscription::{
+        book::{Level, OrderBookL1},
+        number::Number,
+    },
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`MexcSpot`](super::super::MexcSpot) real-time OrderBook Level1
+/// (top of book) WebSocket message.
+pub type MexcOrderBookL1 = MexcMessage<MexcBookTicker>;
+
+/// [`MexcSpot`](super::super::MexcSpot) real-time OrderBook Level1 (top of book) book ticker.
+///
+/// See [`MexcMessage`] for full decoded payload examples.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#individual-symbol-book-ticker-streams>
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcBookTicker {
+    #[serde(
+        alias = "bidPrice",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_price: Number,
+    #[serde(
+        alias = "bidQuantity",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_bid_amount: Number,
+    #[serde(
+        alias = "askPrice",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_price: Number,
+    #[serde(
+        alias = "askQuantity",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub best_ask_amount: Number,
+}
+
+impl From<(ExchangeId, Instrument, MexcOrderBookL1)> for MarketIter<OrderBookL1> {
+    fn from((exchange_id, instrument, book): (ExchangeId, Instrument, MexcOrderBookL1)) -> Self {
+        match book {
+            MexcMessage::Data(payload) => Self(vec![Ok(MarketEvent {
+                exchange_time: payload.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange_id),
+                instrument,
+                sequence: None,
+                tag: None,
+                kind: OrderBookL1 {
+                    last_update_time: payload.time,
+                    best_bid: Level::new(payload.data.best_bid_price, payload.data.best_bid_amount),
+                    best_ask: Level::new(payload.data.best_ask_price, payload.data.best_ask_amount),
+                    time_source: TimeSource::Exchange,
+                },
+            })]),
+            MexcMessage::Response(_) => Self(vec![]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::{
+            decoder::DecodingParser,
+            exchange::mexc::{
+                message::MexcPayload,
+                proto::{fixtures, MexcProtobuf},
+            },
+        };
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration,
+            error::SocketError,
+            model::SubscriptionId,
+            protocol::{websocket::WsMessage, StreamParser},
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_mexc_message_order_book_l1() {
+            struct TestCase {
+                input: WsMessage,
+                expected: Result<MexcOrderBookL1, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input binary book ticker frame is decoded & deserialised
+                    input: WsMessage::Binary(fixtures::BOOK_TICKER.to_vec()),
+                    expected: Ok(MexcOrderBookL1::Data(MexcPayload {
+                        subscription_id: SubscriptionId::from(
+                            "spot@public.bookTicker.v3.api.pb|BTCUSDT",
+                        ),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1736412092433,
+                        )),
+                        data: MexcBookTicker {
+                            best_bid_price: from_f64(93387.28),
+                            best_bid_amount: from_f64(3.73485),
+                            best_ask_price: from_f64(93387.29),
+                            best_ask_amount: from_f64(7.669875),
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input decoded book ticker w/ missing bidPrice fails to deserialise
+                    input: WsMessage::Text(
+                        r#"{"channel":"spot@public.bookTicker.v3.api.pb@BTCUSDT","sendTime":1736412092433,"publicBookTicker":{"bidQuantity":"3.73485","askPrice":"93387.29","askQuantity":"7.669875"}}"#
+                            .to_string(),
+                    ),
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    DecodingParser::<MexcProtobuf>::parse::<MexcOrderBookL1>(Ok(test.input))
+                        .unwrap();
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
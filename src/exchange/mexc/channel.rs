@@ -0,0 +1,47 @@
+use super::MexcSpot;
+use crate::{
+    subscription::{book::OrderBooksL1, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`MexcSpot`](super::MexcSpot) channel to be subscribed to.
+///
+/// ### Notes
+/// The ".pb" suffix selects the protobuf encoded variant of each channel, which is pushed in
+/// binary frames (see [`MexcProtobuf`](super::proto::MexcProtobuf)).
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct MexcChannel(pub &'static str);
+
+impl MexcChannel {
+    /// [`MexcSpot`](super::MexcSpot) real-time trades channel name.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+    pub const TRADES: Self = Self("spot@public.deals.v3.api.pb");
+
+    /// [`MexcSpot`](super::MexcSpot) real-time OrderBook Level1 (top of book) channel name.
+    ///
+    /// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#individual-symbol-book-ticker-streams>
+    pub const ORDER_BOOK_L1: Self = Self("spot@public.bookTicker.v3.api.pb");
+}
+
+impl Identifier<MexcChannel> for Subscription<MexcSpot, PublicTrades> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::TRADES
+    }
+}
+
+impl Identifier<MexcChannel> for Subscription<MexcSpot, OrderBooksL1> {
+    fn id(&self) -> MexcChannel {
+        MexcChannel::ORDER_BOOK_L1
+    }
+}
+
+impl AsRef<str> for MexcChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,22 @@
+use super::MexcSpot;
+use crate::{subscription::Subscription, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`MexcSpot`](super::MexcSpot) market that can be subscribed to.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MexcMarket(pub String);
+
+impl<Kind> Identifier<MexcMarket> for Subscription<MexcSpot, Kind> {
+    fn id(&self) -> MexcMarket {
+        MexcMarket(format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase())
+    }
+}
+
+impl AsRef<str> for MexcMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
@@ -0,0 +1,159 @@
+use super::subscription::MexcSubResponse;
+use crate::{exchange::ExchangeSub, Identifier};
+use barter_integration::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`MexcSpot`](super::MexcSpot) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// Market data is pushed in protobuf binary frames, which are decoded into their proto3 JSON
+/// mapping by the [`MexcProtobuf`](super::proto::MexcProtobuf) decoder before deserialisation.
+///
+/// #### Trades
+/// See [`MexcProtobuf`](super::proto::MexcProtobuf) for the full decoded payload example.
+///
+/// #### OrderBookL1
+/// ```json
+/// {
+///     "channel": "spot@public.bookTicker.v3.api.pb@BTCUSDT",
+///     "symbol": "BTCUSDT",
+///     "sendTime": 1736412092433,
+///     "publicBookTicker": {
+///         "bidPrice": "93387.28",
+///         "bidQuantity": "3.73485",
+///         "askPrice": "93387.29",
+///         "askQuantity": "7.669875"
+///     }
+/// }
+/// ```
+///
+/// #### Pong
+/// See [`MexcSubResponse`] for the JSON text frame payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum MexcMessage<T> {
+    Data(MexcPayload<T>),
+    Response(MexcSubResponse),
+}
+
+/// [`MexcSpot`](super::MexcSpot) market data payload containing the generic `T` data.
+///
+/// See [`MexcMessage`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcPayload<T> {
+    #[serde(
+        alias = "channel",
+        deserialize_with = "de_mexc_channel_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    #[serde(
+        alias = "sendTime",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(alias = "publicDeals", alias = "publicBookTicker")]
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for MexcMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            MexcMessage::Data(payload) => Some(payload.subscription_id.clone()),
+            MexcMessage::Response(_) => None,
+        }
+    }
+}
+
+/// Deserialize a [`MexcPayload`] "channel" (eg/ "spot@public.deals.v3.api.pb@BTCUSDT") as the
+/// associated [`SubscriptionId`] (eg/ "spot@public.deals.v3.api.pb|BTCUSDT").
+pub fn de_mexc_channel_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let channel = <&str as Deserialize>::deserialize(deserializer)?;
+
+    channel
+        .rsplit_once('@')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(channel),
+                &"Mexc channel in the format {channel}@{market}",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_mexc_message() {
+            #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+            struct Empty {}
+
+            struct TestCase {
+                input: &'static str,
+                expected: Result<MexcMessage<Empty>, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input is a pong text frame
+                    input: r#"{"id":0,"code":0,"msg":"PONG"}"#,
+                    expected: Ok(MexcMessage::Response(MexcSubResponse {
+                        id: 0,
+                        code: 0,
+                        msg: "PONG".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC1: input is decoded push data
+                    input: r#"{"channel":"spot@public.bookTicker.v3.api.pb@BTCUSDT","symbol":"BTCUSDT","sendTime":1736412092433,"publicBookTicker":{}}"#,
+                    expected: Ok(MexcMessage::Data(MexcPayload {
+                        subscription_id: SubscriptionId::from(
+                            "spot@public.bookTicker.v3.api.pb|BTCUSDT",
+                        ),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1736412092433,
+                        )),
+                        data: Empty {},
+                    })),
+                },
+                TestCase {
+                    // TC2: input is decoded push data w/ an invalid channel
+                    input: r#"{"channel":"BTCUSDT","sendTime":1736412092433,"publicBookTicker":{}}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<MexcMessage<Empty>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
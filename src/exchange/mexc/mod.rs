@@ -0,0 +1,206 @@
+use self::{
+    book::l1::MexcOrderBookL1, channel::MexcChannel, market::MexcMarket, proto::MexcProtobuf,
+    subscription::MexcSubResponse, trade::MexcTrades,
+};
+use crate::{
+    exchange::{Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// Order book types for [`MexcSpot`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`MexcMessage`](message::MexcMessage) type for [`MexcSpot`].
+pub mod message;
+
+/// [`MexcProtobuf`](proto::MexcProtobuf) decoder of the protobuf encoded binary push data sent
+/// by [`MexcSpot`].
+pub mod proto;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`MexcSpot`].
+pub mod subscription;
+
+/// Public trade types for [`MexcSpot`].
+pub mod trade;
+
+/// [`MexcSpot`] WebSocket server base url.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+pub const BASE_URL_MEXC_SPOT: &str = "wss://wbs-api.mexc.com/ws";
+
+/// [`MexcSpot`] server disconnects connections that have not received data for 60 seconds, so
+/// a [`PingInterval`] is required to keep quiet markets connected.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+pub const PING_INTERVAL_MEXC_SPOT: Duration = Duration::from_secs(20);
+
+/// [`MexcSpot`] exchange.
+///
+/// ### Notes
+/// - Subscription responses & pongs are sent in JSON text frames, whereas market data is pushed
+///   in protobuf binary frames, hence the [`MexcProtobuf`] [`Connector::Decoder`].
+/// - A maximum of 30 [`Subscription`](crate::subscription::Subscription)s are supported on a
+///   single connection.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#websocket-market-streams>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct MexcSpot;
+
+impl Connector for MexcSpot {
+    const ID: ExchangeId = ExchangeId::MexcSpot;
+    const MAX_STREAMS_PER_CONNECTION: usize = 30;
+    type Channel = MexcChannel;
+    type Market = MexcMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = MexcSubResponse;
+    type Decoder = MexcProtobuf;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_MEXC_SPOT).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_MEXC_SPOT),
+            ping: || WsMessage::Text(json!({ "method": "PING" }).to_string()),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        mexc_requests("SUBSCRIPTION", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        mexc_requests("UNSUBSCRIPTION", exchange_subs)
+    }
+}
+
+/// Construct the [`MexcSpot`] [`WsMessage`] payloads that action the provided method (eg/
+/// "SUBSCRIPTION", "UNSUBSCRIPTION") for a collection of [`ExchangeSub`]s.
+///
+/// Each [`ExchangeSub`] is actioned in a distinct request, since [`MexcSpot`] responds to every
+/// request with a single [`MexcSubResponse`].
+fn mexc_requests(
+    method: &str,
+    exchange_subs: Vec<ExchangeSub<MexcChannel, MexcMarket>>,
+) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .enumerate()
+        .map(|(index, ExchangeSub { channel, market })| {
+            WsMessage::Text(
+                json!({
+                    "method": method,
+                    "params": [format!("{}@{}", channel.as_ref(), market.as_ref())],
+                    "id": index,
+                })
+                .to_string(),
+            )
+        })
+        .collect()
+}
+
+impl StreamSelector<PublicTrades> for MexcSpot {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, PublicTrades, MexcTrades>, MexcProtobuf>;
+}
+
+impl StreamSelector<OrderBooksL1> for MexcSpot {
+    type Stream =
+        ExchangeWsStream<StatelessTransformer<Self, OrderBooksL1, MexcOrderBookL1>, MexcProtobuf>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{subscription::Subscription, Identifier};
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_mexc_requests() {
+        let exchange_subs = || {
+            vec![
+                ExchangeSub::from((MexcChannel::TRADES, MexcMarket("BTCUSDT".to_string()))),
+                ExchangeSub::from((
+                    MexcChannel::ORDER_BOOK_L1,
+                    MexcMarket("ETHUSDT".to_string()),
+                )),
+            ]
+        };
+
+        assert_eq!(
+            MexcSpot::requests(exchange_subs()),
+            vec![
+                WsMessage::Text(
+                    json!({
+                        "method": "SUBSCRIPTION",
+                        "params": ["spot@public.deals.v3.api.pb@BTCUSDT"],
+                        "id": 0
+                    })
+                    .to_string()
+                ),
+                WsMessage::Text(
+                    json!({
+                        "method": "SUBSCRIPTION",
+                        "params": ["spot@public.bookTicker.v3.api.pb@ETHUSDT"],
+                        "id": 1
+                    })
+                    .to_string()
+                ),
+            ]
+        );
+
+        assert_eq!(
+            MexcSpot::unsubscribe_requests(exchange_subs())[0],
+            WsMessage::Text(
+                json!({
+                    "method": "UNSUBSCRIPTION",
+                    "params": ["spot@public.deals.v3.api.pb@BTCUSDT"],
+                    "id": 0
+                })
+                .to_string()
+            ),
+        );
+    }
+
+    #[test]
+    fn test_mexc_subscription_id() {
+        // PublicTrades Subscription id matches the channel embedded in the push data header
+        let sub = Subscription::<_, PublicTrades>::new(
+            MexcSpot,
+            ("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        );
+
+        assert_eq!(
+            Some(ExchangeSub::<MexcChannel, MexcMarket>::new(&sub).id()),
+            serde_json::from_str::<MexcTrades>(
+                r#"{"channel":"spot@public.deals.v3.api.pb@BTCUSDT","sendTime":1736409765051,"publicDeals":{"deals":[]}}"#,
+            )
+            .unwrap()
+            .id(),
+        );
+    }
+}
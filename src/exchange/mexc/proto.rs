@@ -0,0 +1,387 @@
+use crate::decoder::PayloadDecoder;
+use barter_integration::error::SocketError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+
+/// [`PayloadDecoder`] for [`MexcSpot`](super::MexcSpot) protobuf encoded binary push data.
+///
+/// Decodes a binary `PushDataV3ApiWrapper` into its proto3 JSON mapping (eg/ camelCase field
+/// names), which is then deserialised as a [`MexcMessage`](super::message::MexcMessage). Text
+/// frames (eg/ subscription responses, pongs) are JSON and bypass this decoder entirely.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://github.com/mexcdevelop/websocket-proto>
+/// #### Decoded PublicDeals
+/// ```json
+/// {
+///     "channel": "spot@public.deals.v3.api.pb@BTCUSDT",
+///     "symbol": "BTCUSDT",
+///     "sendTime": 1736409765051,
+///     "publicDeals": {
+///         "deals": [
+///             {
+///                 "price": "93220.00",
+///                 "quantity": "0.04438243",
+///                 "tradeType": 2,
+///                 "time": 1736409765046
+///             }
+///         ],
+///         "eventType": "spot@public.deals.v3.api.pb"
+///     }
+/// }
+/// ```
+///
+/// ## Notes:
+/// - Only the `PushDataV3ApiWrapper` bodies of supported channels are decoded, any other fields
+///   are skipped.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MexcProtobuf;
+
+impl PayloadDecoder for MexcProtobuf {
+    fn decode(payload: &[u8]) -> Result<Cow<'_, str>, SocketError> {
+        decode_message(payload, PUSH_DATA_WRAPPER)
+            .map(|wrapper| Cow::Owned(Value::Object(wrapper).to_string()))
+            .map_err(|error| SocketError::DeserialiseBinary {
+                error: serde::de::Error::custom(error),
+                payload: payload.to_vec(),
+            })
+    }
+}
+
+/// Protobuf schema field decoded into the proto3 JSON mapping.
+struct ProtoField {
+    number: u64,
+    name: &'static str,
+    kind: ProtoKind,
+}
+
+/// Protobuf scalar or message type of a [`ProtoField`].
+enum ProtoKind {
+    String,
+    Int,
+    Message(&'static [ProtoField]),
+    RepeatedMessage(&'static [ProtoField]),
+}
+
+/// `PushDataV3ApiWrapper` schema.
+///
+/// See docs: <https://github.com/mexcdevelop/websocket-proto/blob/main/PushDataV3ApiWrapper.proto>
+const PUSH_DATA_WRAPPER: &[ProtoField] = &[
+    ProtoField {
+        number: 1,
+        name: "channel",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 3,
+        name: "symbol",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 4,
+        name: "symbolId",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 5,
+        name: "createTime",
+        kind: ProtoKind::Int,
+    },
+    ProtoField {
+        number: 6,
+        name: "sendTime",
+        kind: ProtoKind::Int,
+    },
+    ProtoField {
+        number: 301,
+        name: "publicDeals",
+        kind: ProtoKind::Message(PUBLIC_DEALS),
+    },
+    ProtoField {
+        number: 305,
+        name: "publicBookTicker",
+        kind: ProtoKind::Message(PUBLIC_BOOK_TICKER),
+    },
+];
+
+/// `PublicDealsV3Api` schema.
+///
+/// See docs: <https://github.com/mexcdevelop/websocket-proto/blob/main/PublicDealsV3Api.proto>
+const PUBLIC_DEALS: &[ProtoField] = &[
+    ProtoField {
+        number: 1,
+        name: "deals",
+        kind: ProtoKind::RepeatedMessage(PUBLIC_DEAL),
+    },
+    ProtoField {
+        number: 2,
+        name: "eventType",
+        kind: ProtoKind::String,
+    },
+];
+
+/// `PublicDealsV3ApiItem` schema.
+const PUBLIC_DEAL: &[ProtoField] = &[
+    ProtoField {
+        number: 1,
+        name: "price",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 2,
+        name: "quantity",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 3,
+        name: "tradeType",
+        kind: ProtoKind::Int,
+    },
+    ProtoField {
+        number: 4,
+        name: "time",
+        kind: ProtoKind::Int,
+    },
+];
+
+/// `PublicBookTickerV3Api` schema.
+///
+/// See docs: <https://github.com/mexcdevelop/websocket-proto/blob/main/PublicBookTickerV3Api.proto>
+const PUBLIC_BOOK_TICKER: &[ProtoField] = &[
+    ProtoField {
+        number: 1,
+        name: "bidPrice",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 2,
+        name: "bidQuantity",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 3,
+        name: "askPrice",
+        kind: ProtoKind::String,
+    },
+    ProtoField {
+        number: 4,
+        name: "askQuantity",
+        kind: ProtoKind::String,
+    },
+];
+
+/// Protobuf wire value of a single field.
+///
+/// See docs: <https://protobuf.dev/programming-guides/encoding/>
+enum WireValue<'a> {
+    Varint(u64),
+    LengthDelimited(&'a [u8]),
+    Fixed,
+}
+
+/// Decode the protobuf encoded `payload` into a proto3 JSON mapping object of the provided
+/// `schema`, skipping any fields not present in the `schema`.
+fn decode_message(mut payload: &[u8], schema: &[ProtoField]) -> Result<Map<String, Value>, String> {
+    let mut message = Map::new();
+
+    while !payload.is_empty() {
+        let key = decode_varint(&mut payload)?;
+        let value = match key & 0x7 {
+            0 => WireValue::Varint(decode_varint(&mut payload)?),
+            1 => take(&mut payload, 8).map(|_| WireValue::Fixed)?,
+            2 => {
+                let length = decode_varint(&mut payload)?;
+                let length = usize::try_from(length).map_err(|_| "length overflow".to_string())?;
+                WireValue::LengthDelimited(take(&mut payload, length)?)
+            }
+            5 => take(&mut payload, 4).map(|_| WireValue::Fixed)?,
+            wire_type => return Err(format!("unsupported protobuf wire type: {wire_type}")),
+        };
+
+        let Some(field) = schema.iter().find(|field| field.number == key >> 3) else {
+            continue;
+        };
+
+        match (&field.kind, value) {
+            (ProtoKind::String, WireValue::LengthDelimited(bytes)) => {
+                let string = std::str::from_utf8(bytes)
+                    .map_err(|error| format!("{} is not UTF-8: {error}", field.name))?;
+                message.insert(field.name.to_string(), Value::from(string));
+            }
+            (ProtoKind::Int, WireValue::Varint(int)) => {
+                // int32 & int64 are encoded as two's complement varints
+                message.insert(field.name.to_string(), Value::from(int as i64));
+            }
+            (ProtoKind::Message(schema), WireValue::LengthDelimited(bytes)) => {
+                message.insert(
+                    field.name.to_string(),
+                    Value::Object(decode_message(bytes, schema)?),
+                );
+            }
+            (ProtoKind::RepeatedMessage(schema), WireValue::LengthDelimited(bytes)) => {
+                let item = Value::Object(decode_message(bytes, schema)?);
+                match message
+                    .entry(field.name)
+                    .or_insert_with(|| Value::Array(vec![]))
+                {
+                    Value::Array(items) => items.push(item),
+                    _ => unreachable!("repeated fields are always inserted as arrays"),
+                }
+            }
+            _ => {
+                return Err(format!(
+                    "{} has an unexpected protobuf wire type",
+                    field.name
+                ))
+            }
+        }
+    }
+
+    Ok(message)
+}
+
+/// Decode a base 128 varint, advancing the `payload` past it.
+fn decode_varint(payload: &mut &[u8]) -> Result<u64, String> {
+    let mut value = 0_u64;
+    for (index, byte) in payload.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            *payload = &payload[index + 1..];
+            return Ok(value);
+        }
+    }
+    Err("truncated or overlong protobuf varint".to_string())
+}
+
+/// Take the next `length` bytes of the `payload`, advancing the `payload` past them.
+fn take<'a>(payload: &mut &'a [u8], length: usize) -> Result<&'a [u8], String> {
+    if payload.len() < length {
+        return Err(format!(
+            "truncated protobuf field: expected {length} bytes, found {}",
+            payload.len()
+        ));
+    }
+    let (bytes, remaining) = payload.split_at(length);
+    *payload = remaining;
+    Ok(bytes)
+}
+
+/// Binary [`MexcSpot`](super::MexcSpot) push data fixtures.
+#[cfg(test)]
+pub(crate) mod fixtures {
+    /// `spot@public.deals.v3.api.pb@BTCUSDT` push containing a sell & a buy deal.
+    pub const DEALS: &[u8] = &[
+        0x0a, 0x23, 0x73, 0x70, 0x6f, 0x74, 0x40, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x2e, 0x64,
+        0x65, 0x61, 0x6c, 0x73, 0x2e, 0x76, 0x33, 0x2e, 0x61, 0x70, 0x69, 0x2e, 0x70, 0x62, 0x40,
+        0x42, 0x54, 0x43, 0x55, 0x53, 0x44, 0x54, 0x1a, 0x07, 0x42, 0x54, 0x43, 0x55, 0x53, 0x44,
+        0x54, 0x30, 0xbb, 0xa9, 0xd9, 0xd0, 0xc4, 0x32, 0xea, 0x12, 0x5a, 0x0a, 0x1f, 0x0a, 0x08,
+        0x39, 0x33, 0x32, 0x32, 0x30, 0x2e, 0x30, 0x30, 0x12, 0x0a, 0x30, 0x2e, 0x30, 0x34, 0x34,
+        0x33, 0x38, 0x32, 0x34, 0x33, 0x18, 0x02, 0x20, 0xb6, 0xa9, 0xd9, 0xd0, 0xc4, 0x32, 0x0a,
+        0x1a, 0x0a, 0x08, 0x39, 0x33, 0x32, 0x32, 0x30, 0x2e, 0x30, 0x31, 0x12, 0x05, 0x30, 0x2e,
+        0x30, 0x30, 0x31, 0x18, 0x01, 0x20, 0xb7, 0xa9, 0xd9, 0xd0, 0xc4, 0x32, 0x12, 0x1b, 0x73,
+        0x70, 0x6f, 0x74, 0x40, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x2e, 0x64, 0x65, 0x61, 0x6c,
+        0x73, 0x2e, 0x76, 0x33, 0x2e, 0x61, 0x70, 0x69, 0x2e, 0x70, 0x62,
+    ];
+
+    /// `spot@public.bookTicker.v3.api.pb@BTCUSDT` push.
+    pub const BOOK_TICKER: &[u8] = &[
+        0x0a, 0x28, 0x73, 0x70, 0x6f, 0x74, 0x40, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x2e, 0x62,
+        0x6f, 0x6f, 0x6b, 0x54, 0x69, 0x63, 0x6b, 0x65, 0x72, 0x2e, 0x76, 0x33, 0x2e, 0x61, 0x70,
+        0x69, 0x2e, 0x70, 0x62, 0x40, 0x42, 0x54, 0x43, 0x55, 0x53, 0x44, 0x54, 0x1a, 0x07, 0x42,
+        0x54, 0x43, 0x55, 0x53, 0x44, 0x54, 0x30, 0x91, 0xb0, 0xe7, 0xd1, 0xc4, 0x32, 0x8a, 0x13,
+        0x27, 0x0a, 0x08, 0x39, 0x33, 0x33, 0x38, 0x37, 0x2e, 0x32, 0x38, 0x12, 0x07, 0x33, 0x2e,
+        0x37, 0x33, 0x34, 0x38, 0x35, 0x1a, 0x08, 0x39, 0x33, 0x33, 0x38, 0x37, 0x2e, 0x32, 0x39,
+        0x22, 0x08, 0x37, 0x2e, 0x36, 0x36, 0x39, 0x38, 0x37, 0x35,
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_mexc_protobuf_decode() {
+        struct TestCase {
+            input: &'static [u8],
+            expected: Result<Value, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: PublicDeals push is decoded into the proto3 JSON mapping
+                input: fixtures::DEALS,
+                expected: Ok(json!({
+                    "channel": "spot@public.deals.v3.api.pb@BTCUSDT",
+                    "symbol": "BTCUSDT",
+                    "sendTime": 1736409765051_i64,
+                    "publicDeals": {
+                        "deals": [
+                            {
+                                "price": "93220.00",
+                                "quantity": "0.04438243",
+                                "tradeType": 2,
+                                "time": 1736409765046_i64
+                            },
+                            {
+                                "price": "93220.01",
+                                "quantity": "0.001",
+                                "tradeType": 1,
+                                "time": 1736409765047_i64
+                            }
+                        ],
+                        "eventType": "spot@public.deals.v3.api.pb"
+                    }
+                })),
+            },
+            TestCase {
+                // TC1: PublicBookTicker push is decoded into the proto3 JSON mapping
+                input: fixtures::BOOK_TICKER,
+                expected: Ok(json!({
+                    "channel": "spot@public.bookTicker.v3.api.pb@BTCUSDT",
+                    "symbol": "BTCUSDT",
+                    "sendTime": 1736412092433_i64,
+                    "publicBookTicker": {
+                        "bidPrice": "93387.28",
+                        "bidQuantity": "3.73485",
+                        "askPrice": "93387.29",
+                        "askQuantity": "7.669875"
+                    }
+                })),
+            },
+            TestCase {
+                // TC2: unknown fields are skipped
+                input: &[0x0a, 0x01, 0x61, 0x10, 0x01, 0x3d, 0x00, 0x00, 0x00, 0x00],
+                expected: Ok(json!({ "channel": "a" })),
+            },
+            TestCase {
+                // TC3: truncated push fails to decode
+                input: &fixtures::DEALS[..60],
+                expected: Err(SocketError::Sink),
+            },
+            TestCase {
+                // TC4: JSON payload fails to decode
+                input: br#"{"id":0,"code":0,"msg":"PONG"}"#,
+                expected: Err(SocketError::Sink),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = MexcProtobuf::decode(test.input)
+                .map(|text| serde_json::from_str::<Value>(&text).unwrap());
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(SocketError::DeserialiseBinary { .. }), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`MexcSpot`](super::MexcSpot) subscription response message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#subscribe-to-a-stream>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "id": 0,
+///     "code": 0,
+///     "msg": "spot@public.deals.v3.api.pb@BTCUSDT"
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "id": 0,
+///     "code": 0,
+///     "msg": "Not Subscribed successfully! [spot@public.deals.v3.api.pb@BTCUSDTX].  Reason： Blocked! "
+/// }
+/// ```
+///
+/// #### Pong
+/// ```json
+/// {
+///     "id": 0,
+///     "code": 0,
+///     "msg": "PONG"
+/// }
+/// ```
+///
+/// ## Notes:
+/// - Failed subscriptions are also responded to with a "code" of 0, so success is determined by
+///   the "msg" echoing the subscribed channel.
+/// - Responses are sent in JSON text frames, whereas market data is pushed in protobuf binary
+///   frames.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MexcSubResponse {
+    pub id: u64,
+    pub code: i64,
+    pub msg: String,
+}
+
+impl Validator for MexcSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        if self.code == 0 && self.msg.starts_with("spot@") {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {} with message: {}",
+                self.code, self.msg,
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_mexc_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<MexcSubResponse, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input ack frame is a subscription response
+                    input: r#"{"id":0,"code":0,"msg":"spot@public.deals.v3.api.pb@BTCUSDT"}"#,
+                    expected: Ok(MexcSubResponse {
+                        id: 0,
+                        code: 0,
+                        msg: "spot@public.deals.v3.api.pb@BTCUSDT".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: input decoded push data is not a subscription response
+                    input: r#"{"channel":"spot@public.deals.v3.api.pb@BTCUSDT","symbol":"BTCUSDT","sendTime":1736409765051}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<MexcSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_mexc_sub_response() {
+        struct TestCase {
+            input: MexcSubResponse,
+            expected: Result<MexcSubResponse, SocketError>,
+        }
+
+        let response = |msg: &str| MexcSubResponse {
+            id: 0,
+            code: 0,
+            msg: msg.to_string(),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: input response echoing the channel is a successful subscription
+                input: response("spot@public.deals.v3.api.pb@BTCUSDT"),
+                expected: Ok(response("spot@public.deals.v3.api.pb@BTCUSDT")),
+            },
+            TestCase {
+                // TC1: input response w/ code 0 is a failed subscription
+                input: response(
+                    "Not Subscribed successfully! [spot@public.deals.v3.api.pb@BTCUSDTX].  Reason： Blocked! ",
+                ),
+                expected: Err(SocketError::Subscribe("not subscribed".to_string())),
+            },
+            TestCase {
+                // TC2: input response w/ non-zero code is a failed subscription
+                input: MexcSubResponse {
+                    id: 0,
+                    code: 1,
+                    msg: "spot@public.deals.v3.api.pb@BTCUSDT".to_string(),
+                },
+                expected: Err(SocketError::Subscribe("non-zero code".to_string())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.validate();
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use super::message::MexcMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::{number::Number, trade::PublicTrade},
+};
+use barter_integration::model::{Exchange, Instrument, Side};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`MexcSpot`](super::MexcSpot) real-time trades WebSocket message.
+pub type MexcTrades = MexcMessage<MexcDeals>;
+
+/// [`MexcSpot`](super::MexcSpot) real-time trades push data.
+///
+/// See [`MexcProtobuf`](super::proto::MexcProtobuf) for the full decoded payload example.
+///
+/// See docs: <https://mexcdevelop.github.io/apidocs/spot_v3_en/#trade-streams>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcDeals {
+    pub deals: Vec<MexcDeal>,
+}
+
+/// [`MexcSpot`](super::MexcSpot) real-time trade contained within [`MexcDeals`].
+///
+/// ## Notes:
+/// - "tradeType" is the taker side of the trade, where 1 is a buy and 2 is a sell.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MexcDeal {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Number,
+    #[serde(
+        alias = "quantity",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub amount: Number,
+    #[serde(alias = "tradeType", deserialize_with = "de_side_from_trade_type")]
+    pub side: Side,
+    #[serde(deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+/// Generate a custom [`MexcSpot`](super::MexcSpot) trade identifier since it is not provided in
+/// the [`MexcDeal`] model.
+fn custom_mexc_trade_id(trade: &MexcDeal) -> String {
+    format!(
+        "{}_{}_{}_{}",
+        trade.time.timestamp_millis(),
+        trade.side,
+        trade.price,
+        trade.amount
+    )
+}
+
+impl From<(ExchangeId, Instrument, MexcTrades)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trades): (ExchangeId, Instrument, MexcTrades)) -> Self {
+        match trades {
+            MexcMessage::Data(payload) => Self(
+                payload
+                    .data
+                    .deals
+                    .into_iter()
+                    .map(|trade| {
+                        Ok(MarketEvent {
+                            exchange_time: trade.time,
+                            received_time: Utc::now(),
+                            exchange: Exchange::from(exchange_id),
+                            instrument: instrument.clone(),
+                            sequence: None,
+                            tag: None,
+                            kind: PublicTrade {
+                                id: custom_mexc_trade_id(&trade),
+                                price: trade.price,
+                                amount: trade.amount,
+                                side: trade.side,
+                                buyer_order_id: None,
+                                seller_order_id: None,
+                                taker_order_kind: None,
+                            },
+                        })
+                    })
+                    .collect(),
+            ),
+            MexcMessage::Response(_) => Self(vec![]),
+        }
+    }
+}
+
+/// Deserialize a [`MexcDeal`] "tradeType" taker indicator (eg/ 1) as the associated [`Side`].
+pub fn de_side_from_trade_type<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <i64 as Deserialize>::deserialize(deserializer)? {
+        1 => Ok(Side::Buy),
+        2 => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Signed(other),
+            &"Mexc tradeType of 1 (buy) or 2 (sell)",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use crate::{
+            decoder::DecodingParser,
+            exchange::mexc::{
+                message::MexcPayload,
+                proto::{fixtures, MexcProtobuf},
+                subscription::MexcSubResponse,
+            },
+        };
+        use barter_integration::{
+            de::datetime_utc_from_epoch_duration,
+            error::SocketError,
+            model::SubscriptionId,
+            protocol::{websocket::WsMessage, StreamParser},
+        };
+        use std::time::Duration;
+
+        #[test]
+        fn test_mexc_message_trades() {
+            struct TestCase {
+                input: WsMessage,
+                expected: Result<MexcTrades, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input binary deals frame is decoded & deserialised
+                    input: WsMessage::Binary(fixtures::DEALS.to_vec()),
+                    expected: Ok(MexcTrades::Data(MexcPayload {
+                        subscription_id: SubscriptionId::from("spot@public.deals.v3.api.pb|BTCUSDT"),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1736409765051,
+                        )),
+                        data: MexcDeals {
+                            deals: vec![
+                                MexcDeal {
+                                    price: from_f64(93220.00),
+                                    amount: from_f64(0.04438243),
+                                    side: Side::Sell,
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1736409765046,
+                                    )),
+                                },
+                                MexcDeal {
+                                    price: from_f64(93220.01),
+                                    amount: from_f64(0.001),
+                                    side: Side::Buy,
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1736409765047,
+                                    )),
+                                },
+                            ],
+                        },
+                    })),
+                },
+                TestCase {
+                    // TC1: input JSON ack text frame is a MexcMessage::Response
+                    input: WsMessage::Text(
+                        r#"{"id":0,"code":0,"msg":"spot@public.deals.v3.api.pb@BTCUSDT"}"#
+                            .to_string(),
+                    ),
+                    expected: Ok(MexcTrades::Response(MexcSubResponse {
+                        id: 0,
+                        code: 0,
+                        msg: "spot@public.deals.v3.api.pb@BTCUSDT".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC2: input decoded deals w/ unknown tradeType fails to deserialise
+                    input: WsMessage::Text(
+                        r#"{"channel":"spot@public.deals.v3.api.pb@BTCUSDT","sendTime":1736409765051,"publicDeals":{"deals":[{"price":"93220.00","quantity":"0.04438243","tradeType":3,"time":1736409765046}]}}"#
+                            .to_string(),
+                    ),
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC3: input truncated binary deals frame fails to decode
+                    input: WsMessage::Binary(fixtures::DEALS[..60].to_vec()),
+                    expected: Err(SocketError::Sink),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual =
+                    DecodingParser::<MexcProtobuf>::parse::<MexcTrades>(Ok(test.input)).unwrap();
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,16 +1,27 @@
-use self::subscription::ExchangeSub;
-use crate::subscription::SubKind;
+pub use self::subscription::ExchangeSub;
+use crate::subscription::{Interval, SubKind, SubKindId};
 use crate::{
+    decoder::PayloadDecoder,
+    error::ParseExchangeIdError,
+    streams::{backfill::BackfillClient, snapshot::SnapshotClient},
     subscriber::{validator::SubscriptionValidator, Subscriber},
-    subscription::Map,
-    MarketStream,
+    subscription::{Map, Subscription},
+    Identifier, MarketStream,
 };
 use barter_integration::{
     error::SocketError, model::Instrument, protocol::websocket::WsMessage, Validator,
 };
-use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde::{
+    de::{value::StrDeserializer, DeserializeOwned},
+    Deserialize, Serialize,
+};
 use std::{
+    collections::{BTreeSet, HashMap, HashSet},
     fmt::{Debug, Display},
+    future::Future,
+    pin::Pin,
+    str::FromStr,
+    sync::Mutex,
     time::Duration,
 };
 use url::Url;
@@ -21,19 +32,40 @@ pub mod binance;
 /// `Bitfinex` [`Connector`] and [`StreamSelector`] implementations.
 pub mod bitfinex;
 
+/// `Bitstamp` [`Connector`] and [`StreamSelector`] implementations.
+pub mod bitstamp;
+
+/// `BybitSpot` & `BybitPerpetualsUsd` [`Connector`] and [`StreamSelector`] implementations.
+pub mod bybit;
+
 /// `Coinbase` [`Connector`] and [`StreamSelector`] implementations.
 pub mod coinbase;
 
+/// `Deribit` [`Connector`] and [`StreamSelector`] implementations.
+pub mod deribit;
+
 /// `GateioSpot`, `GateioFuturesUsd` & `GateioFuturesBtc` [`Connector`] and [`StreamSelector`]
 /// implementations.
 pub mod gateio;
 
+/// `HuobiSpot` [`Connector`] and [`StreamSelector`] implementations.
+pub mod huobi;
+
 /// `Kraken` [`Connector`] and [`StreamSelector`] implementations.
 pub mod kraken;
 
+/// `KucoinSpot` [`Connector`] and [`StreamSelector`] implementations.
+pub mod kucoin;
+
+/// `MexcSpot` [`Connector`] and [`StreamSelector`] implementations.
+pub mod mexc;
+
 /// `Okx` [`Connector`] and [`StreamSelector`] implementations.
 pub mod okx;
 
+/// `UpbitSpot` [`Connector`] and [`StreamSelector`] implementations.
+pub mod upbit;
+
 /// Defines the generic [`ExchangeSub`] containing a market and channel combination used by an
 /// exchange [`Connector`] to build [`WsMessage`] subscription payloads.
 pub mod subscription;
@@ -42,6 +74,11 @@ pub mod subscription;
 /// [`Subscription`](crate::subscription::Subscription) requests.
 pub const DEFAULT_SUBSCRIPTION_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// Communicative type alias representing the [`Future`] result of a [`Connector::fetch_markets`]
+/// call. Yields every market listed by the exchange, formatted identically to the
+/// [`Connector::Market`] used when subscribing (eg/ "BTC-USDT-SWAP").
+pub type MarketsFuture = Pin<Box<dyn Future<Output = Result<HashSet<String>, SocketError>> + Send>>;
+
 /// Defines the [`MarketStream`] kind associated with an exchange
 /// [`Subscription`](crate::subscription::Subscription) [`SubKind`](crate::subscription::SubKind).
 ///
@@ -54,13 +91,32 @@ where
     Kind: SubKind,
 {
     type Stream: MarketStream<Self, Kind>;
+
+    /// [`BackfillClient`] used to fetch historical [`SubKind::Event`]s before streaming live
+    /// events (eg/ via [`StreamBuilder::with_backfill`](crate::streams::builder::StreamBuilder)).
+    ///
+    /// Defaults to `None`, meaning backfill is not supported and is skipped with a warning.
+    fn backfill_client() -> Option<Box<dyn BackfillClient<Self, Kind>>> {
+        None
+    }
+
+    /// [`SnapshotClient`] used to fetch the current [`SubKind::Event`] of each
+    /// [`Subscription`](crate::subscription::Subscription) before streaming live events (eg/ via
+    /// [`StreamBuilder::with_initial_snapshots`](crate::streams::builder::StreamBuilder)).
+    ///
+    /// Defaults to `None`, meaning initial snapshots are not supported and are skipped.
+    fn snapshot_client() -> Option<Box<dyn SnapshotClient<Self, Kind>>> {
+        None
+    }
 }
 
 /// Primary exchange abstraction. Defines how to translate Barter types into exchange specific
 /// types, as well as connecting, subscribing, and interacting with the exchange server.
 ///
 /// ### Notes
-/// This must be implemented for a new exchange integration!
+/// This must be implemented for a new exchange integration! Exchanges implemented outside of
+/// barter-data are identified by an [`ExchangeId::Custom`], and translate their messages via
+/// [`IntoMarketIter`](crate::event::IntoMarketIter) (see the `custom_exchange` example).
 pub trait Connector
 where
     Self: Clone + Default + Debug + for<'de> Deserialize<'de> + Serialize + Sized,
@@ -68,6 +124,25 @@ where
     /// Unique identifier for the exchange server being connected with.
     const ID: ExchangeId;
 
+    /// Maximum number of [`Subscription`]s the exchange server supports on a single connection.
+    /// Used as the upper bound of a [`BatchPolicy`](crate::streams::builder::BatchPolicy).
+    ///
+    /// Defaults to `usize::MAX`, meaning no limit is enforced.
+    const MAX_STREAMS_PER_CONNECTION: usize = usize::MAX;
+
+    /// Maximum number of [`ExchangeSub`]s actioned by a single [`WsMessage`], used by
+    /// [`Self::requests`] & [`Self::unsubscribe_requests`] to chunk larger collections into
+    /// multiple [`WsMessage`]s.
+    ///
+    /// Defaults to `usize::MAX`, meaning no limit is enforced.
+    const MAX_SUBS_PER_REQUEST: usize = usize::MAX;
+
+    /// Defines how the exchange server acknowledges subscription requests, used by the default
+    /// [`Self::expected_responses`].
+    ///
+    /// Defaults to [`AckMode::PerSubscription`].
+    const ACK_MODE: AckMode = AckMode::PerSubscription;
+
     /// Type that defines how to translate a Barter
     /// [`Subscription`](crate::subscription::Subscription) into an exchange specific channel
     /// to be subscribed to.
@@ -102,9 +177,100 @@ where
     /// communicates a successful [`Subscription`](crate::subscription::Subscription) outcome.
     type SubResponse: Validator + Debug + DeserializeOwned;
 
+    /// [`PayloadDecoder`] used to decode binary WebSocket payloads sent by the exchange server
+    /// (eg/ gzip compressed frames) before they are deserialised.
+    type Decoder: PayloadDecoder;
+
     /// Base [`Url`] of the exchange server being connected with.
     fn url() -> Result<Url, SocketError>;
 
+    /// Base [`Url`] of the exchange server being connected with in the provided [`Environment`].
+    ///
+    /// Defaults to [`Self::url`] for [`Environment::Production`], and a
+    /// [`SocketError::Unsupported`] for [`Environment::Sandbox`], meaning the exchange has no
+    /// sandbox server.
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        match environment {
+            Environment::Production => Self::url(),
+            Environment::Sandbox => Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!("{environment} environment"),
+            }),
+        }
+    }
+
+    /// [`Url`] of the exchange server that must be connected with in order to action the provided
+    /// [`Subscription`]s in the provided [`Environment`].
+    ///
+    /// Defaults to [`Self::url_for`]. Override for exchanges that serve some channels on a
+    /// distinct server (eg/ [`Okx`](okx::Okx) candles are served via the "business" server).
+    fn subscription_url<Kind>(
+        environment: Environment,
+        _: &[Subscription<Self, Kind>],
+    ) -> Result<Url, SocketError>
+    where
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        Self::url_for(environment)
+    }
+
+    /// Validate the provided [`Subscription`]s can be actioned by the exchange server, for
+    /// constraints that are not captured by the [`Subscription`]
+    /// [`InstrumentKind`](barter_integration::model::InstrumentKind) (eg/ unsupported candle
+    /// intervals).
+    ///
+    /// Defaults to `Ok(())`, meaning all [`Subscription`]s are assumed to be supported.
+    fn validate_subscriptions<Kind>(_: &[Subscription<Self, Kind>]) -> Result<(), SocketError>
+    where
+        Kind: Debug,
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        Ok(())
+    }
+
+    /// Validate the provided [`Instrument`] can be translated into a [`Self::Market`] that the
+    /// exchange server recognises, without making any requests.
+    ///
+    /// Defaults to ensuring the base & quote [`Symbol`](barter_integration::model::Symbol)s are
+    /// non-empty & alphanumeric, since exchange specific separators (eg/ "BTC-USDT",
+    /// "BTC_USDT") are added by [`Self::Market`].
+    fn validate_market(instrument: &Instrument) -> Result<(), SocketError> {
+        let is_valid = |symbol: &str| {
+            !symbol.is_empty() && symbol.chars().all(|char| char.is_ascii_alphanumeric())
+        };
+
+        if is_valid(instrument.base.as_ref()) && is_valid(instrument.quote.as_ref()) {
+            Ok(())
+        } else {
+            Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "market {}_{} (base & quote must be alphanumeric symbols)",
+                    instrument.base, instrument.quote
+                ),
+            })
+        }
+    }
+
+    /// Fetch every market listed by the exchange over REST, used to verify each
+    /// [`Subscription`] market exists before subscribing (eg/ via
+    /// [`StreamBuilder::validate_markets`](crate::streams::builder::StreamBuilder::validate_markets)).
+    ///
+    /// Defaults to `None`, meaning market existence cannot be verified and is skipped with a
+    /// warning.
+    fn fetch_markets() -> Option<MarketsFuture> {
+        None
+    }
+
+    /// Candle [`Interval`]s published by the exchange server, used to reject
+    /// [`Candles`](crate::subscription::candle::Candles) [`Subscription`]s with an unsupported
+    /// [`Interval`] during [`Subscription`] validation.
+    ///
+    /// Defaults to [`Interval::ALL`].
+    fn supported_intervals() -> &'static [Interval] {
+        &Interval::ALL
+    }
+
     /// Defines [`PingInterval`] of custom application-level
     /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) pings for the exchange
     /// server being connected with.
@@ -114,15 +280,58 @@ where
         None
     }
 
+    /// Maximum lifetime of a connection with the exchange server, after which the exchange
+    /// server forcibly disconnects it (eg/ [`Binance`](binance::Binance) after 24 hours).
+    ///
+    /// If `Some`, each connection is proactively rotated once it reaches this age: a new
+    /// connection is initialised & subscribed, and both are consumed for a deduplicated overlap
+    /// window before the old connection is closed, so there is no gap in the stream. See
+    /// [`Handover`](crate::streams::handover::Handover).
+    ///
+    /// Defaults to `None`, meaning connections are never proactively rotated.
+    fn max_connection_age() -> Option<Duration> {
+        None
+    }
+
     /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
     /// subscription payloads sent to the exchange server.
+    ///
+    /// Every [`ExchangeSub`] of a connection is provided in a single call, so exchanges that
+    /// replace any previous subscriptions upon each request (eg/ [`UpbitSpot`](upbit::UpbitSpot))
+    /// must merge them into a single [`WsMessage`].
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
 
+    /// Defines how to translate a collection of [`ExchangeSub`]s added to an already subscribed
+    /// connection into the [`WsMessage`] subscription payloads sent to the exchange server (eg/
+    /// via [`StreamController::subscribe`](crate::streams::controller::StreamController::subscribe)).
+    ///
+    /// Defaults to [`Self::requests`]. Exchanges that cannot add subscriptions to a live
+    /// connection (eg/ [`UpbitSpot`](upbit::UpbitSpot) replaces any previous subscriptions upon
+    /// each request) return a [`SocketError::Unsupported`].
+    fn live_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Result<Vec<WsMessage>, SocketError> {
+        Ok(Self::requests(exchange_subs))
+    }
+
+    /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
+    /// unsubscribe payloads sent to the exchange server, counterpart to [`Self::requests`].
+    ///
+    /// Defaults to no payloads for exchanges that cannot unsubscribe from individual
+    /// [`ExchangeSub`]s, in which case any further messages are ignored.
+    fn unsubscribe_requests(
+        _exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        vec![]
+    }
+
     /// Number of [`Subscription`](crate::subscription::Subscription) responses expected from the
     /// exchange server in responses to the requests send. Used to validate all
     /// [`Subscription`](crate::subscription::Subscription)s were accepted.
+    ///
+    /// Defaults to the number determined by [`Self::ACK_MODE`].
     fn expected_responses(map: &Map<Instrument>) -> usize {
-        map.0.len()
+        Self::ACK_MODE.expected_responses(map.0.len(), Self::MAX_SUBS_PER_REQUEST)
     }
 
     /// Expected [`Duration`] the [`SubscriptionValidator`] will wait to receive all success
@@ -132,6 +341,32 @@ where
     }
 }
 
+/// Defines how an exchange server acknowledges the [`WsMessage`] subscription requests sent by
+/// [`Connector::requests`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum AckMode {
+    /// One response per [`ExchangeSub`], regardless of how many are actioned by each request
+    /// (eg/ [`Okx`](okx::Okx)).
+    PerSubscription,
+    /// One response per [`WsMessage`] request, each actioning at most
+    /// [`Connector::MAX_SUBS_PER_REQUEST`] [`ExchangeSub`]s (eg/ [`Binance`](binance::Binance)).
+    PerRequest,
+    /// No responses are sent (eg/ [`UpbitSpot`](upbit::UpbitSpot)).
+    None,
+}
+
+impl AckMode {
+    /// Number of responses expected for `num_subs` [`ExchangeSub`]s actioned in requests of at
+    /// most `max_subs_per_request`.
+    pub fn expected_responses(&self, num_subs: usize, max_subs_per_request: usize) -> usize {
+        match self {
+            AckMode::PerSubscription => num_subs,
+            AckMode::PerRequest => num_subs.div_ceil(max_subs_per_request.max(1)),
+            AckMode::None => 0,
+        }
+    }
+}
+
 /// Used when an exchange has servers different
 /// [`InstrumentKind`](barter_integration::model::InstrumentKind) market data on distinct servers,
 /// allowing all the [`Connector`] logic to be identical apart from what this trait provides.
@@ -142,6 +377,41 @@ where
 pub trait ExchangeServer: Default + Debug + Clone + Send {
     const ID: ExchangeId;
     fn websocket_url() -> &'static str;
+
+    /// WebSocket url of the [`Environment::Sandbox`] server, if the exchange provides one.
+    ///
+    /// Defaults to `None`, meaning there is no sandbox server.
+    fn websocket_url_sandbox() -> Option<&'static str> {
+        None
+    }
+}
+
+/// Exchange server environment connected with, see
+/// [`StreamBuilder::with_environment`](crate::streams::builder::StreamBuilder::with_environment).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Environment {
+    /// Live production servers.
+    #[default]
+    Production,
+    /// Sandbox, testnet or demo trading servers, used for integration testing (eg/
+    /// [`BinanceSpotTestnet`](binance::spot::BinanceSpotTestnet), Okx demo trading).
+    Sandbox,
+}
+
+impl Display for Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Environment::Production => "production",
+                Environment::Sandbox => "sandbox",
+            }
+        )
+    }
 }
 
 /// Defines the frequency and construction function for custom
@@ -150,6 +420,8 @@ pub trait ExchangeServer: Default + Debug + Clone + Send {
 #[derive(Debug)]
 pub struct PingInterval {
     pub interval: tokio::time::Interval,
+    /// Constructs a fresh ping [`WsMessage`] upon every tick of the `interval`, so payloads
+    /// may contain per-ping values (eg/ timestamps, ids).
     pub ping: fn() -> WsMessage,
 }
 
@@ -159,18 +431,122 @@ pub struct PingInterval {
 /// An exchange may server different [`InstrumentKind`](barter_integration::model::InstrumentKind)
 /// market data on distinct servers (eg/ Binance, Gateio). Such exchanges have multiple [`Self`]
 /// variants, and often utilise the [`ExchangeServer`] trait.
+///
+/// New exchanges are added as new variants, so downstream `match` statements must include a
+/// wildcard arm.
+///
+/// The derived [`Ord`] follows the declaration order, so built-in exchanges sort by name and
+/// [`ExchangeId::Custom`] exchanges, which are always declared last, sort after every built-in
+/// exchange.
+///
+/// ### Parsing
+/// [`ExchangeId`]s can be parsed from user input (eg/ CLI arguments) via [`FromStr`], which is
+/// case-insensitive, treats '-', '.' & ' ' as '_', and accepts common aliases (see
+/// [`ExchangeId::ALIASES`]). Use [`ExchangeId::iter`] to list the supported exchanges.
+///
+/// ```rust
+/// use barter_data::{
+///     event::{DataKind, MarketEvent},
+///     exchange::ExchangeId,
+///     streams::{builder::dynamic::{SubKindConfig, SubscriptionConfig}, Streams},
+/// };
+/// use barter_integration::model::InstrumentKind;
+///
+/// // Parse user input such as "--exchange binance-futures"
+/// let exchange = "binance-futures".parse::<ExchangeId>().unwrap();
+/// assert_eq!(exchange, ExchangeId::BinanceFuturesUsd);
+/// assert_eq!("OKEX".parse::<ExchangeId>(), Ok(ExchangeId::Okx));
+///
+/// // Unknown input lists the valid values, eg/ for --help output
+/// let error = "binanse".parse::<ExchangeId>().unwrap_err();
+/// assert!(error.to_string().contains("binance_spot"));
+///
+/// // Build a dynamic subscription for the parsed exchange
+/// let _builder = Streams::<MarketEvent<DataKind>>::builder_multi()
+///     .subscribe_configs([SubscriptionConfig {
+///         exchange,
+///         base: "btc".to_string(),
+///         quote: "usdt".to_string(),
+///         instrument_kind: InstrumentKind::FuturePerpetual,
+///         kind: SubKindConfig::PublicTrades,
+///     }])
+///     .unwrap();
+/// ```
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 #[serde(rename = "exchange", rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum ExchangeId {
+    BinanceFuturesCoin,
     BinanceFuturesUsd,
+    BinanceFuturesUsdTestnet,
     BinanceSpot,
+    BinanceSpotTestnet,
+    #[serde(alias = "binanceus", alias = "binance.us")]
+    BinanceUs,
     Bitfinex,
+    Bitstamp,
+    BybitPerpetualsUsd,
+    BybitSpot,
     Coinbase,
+    Deribit,
     GateioFuturesBtc,
     GateioFuturesUsd,
     GateioSpot,
+    HuobiSpot,
     Kraken,
+    KrakenFutures,
+    KucoinSpot,
+    MexcSpot,
     Okx,
+    UpbitSpot,
+    /// Exchange implemented outside of barter-data, identified by its unique name (eg/ a
+    /// private broker feed). See the `custom_exchange` example.
+    ///
+    /// Serialised as its name, but rejected when deserialising an [`ExchangeId`] so that typos
+    /// of built-in exchanges are caught (eg/ in a config file). Deserialise a
+    /// [`CustomExchangeId`] to opt in to custom names (eg/ when replaying recordings of custom
+    /// exchanges). Cannot be parsed via [`FromStr`], and is not listed by [`ExchangeId::iter`] or
+    /// covered by [`ExchangeId::supports`].
+    #[serde(untagged, skip_deserializing)]
+    Custom(CustomExchangeName),
+}
+
+/// Unique name of an [`ExchangeId::Custom`] exchange (eg/ "my_broker").
+///
+/// Aliased so the derived [`Deserialize`] does not infer a borrowed `'static` lifetime for
+/// [`ExchangeId`], since [`ExchangeId::Custom`] exchanges are never deserialised.
+pub type CustomExchangeName = &'static str;
+
+/// Interned [`CustomExchangeName`]s of every [`ExchangeId::Custom`] constructed at runtime.
+static CUSTOM_EXCHANGE_NAMES: Mutex<BTreeSet<CustomExchangeName>> = Mutex::new(BTreeSet::new());
+
+/// [`ExchangeId`] that deserialises any name that is not a built-in [`ExchangeId`] as an
+/// interned [`ExchangeId::Custom`] (see [`ExchangeId::custom`]).
+///
+/// Opt-in alternative to the strict [`ExchangeId`] deserialisation, used to replay recordings of
+/// custom exchanges. Each distinct custom name is leaked once, so only deserialise trusted input.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+#[serde(transparent)]
+pub struct CustomExchangeId(pub ExchangeId);
+
+impl<'de> Deserialize<'de> for CustomExchangeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+        let exchange =
+            ExchangeId::deserialize(StrDeserializer::<serde::de::value::Error>::new(&name))
+                .unwrap_or_else(|_| ExchangeId::custom(&name));
+
+        Ok(Self(exchange))
+    }
+}
+
+impl From<CustomExchangeId> for ExchangeId {
+    fn from(exchange: CustomExchangeId) -> Self {
+        exchange.0
+    }
 }
 
 impl From<ExchangeId> for barter_integration::model::Exchange {
@@ -185,28 +561,300 @@ impl Display for ExchangeId {
     }
 }
 
+impl FromStr for ExchangeId {
+    type Err = ParseExchangeIdError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalised = input.trim().to_lowercase().replace(['-', '.', ' '], "_");
+
+        Self::iter()
+            .find(|exchange| exchange.as_str() == normalised)
+            .or_else(|| {
+                Self::ALIASES
+                    .iter()
+                    .find_map(|(alias, exchange)| (*alias == normalised).then_some(*exchange))
+            })
+            .ok_or_else(|| ParseExchangeIdError {
+                input: input.to_owned(),
+            })
+    }
+}
+
 impl ExchangeId {
+    /// Construct an [`ExchangeId::Custom`] from a name only known at runtime (eg/ read from a
+    /// recording).
+    ///
+    /// The name is leaked to provide the `'static` [`CustomExchangeName`], but interned so that
+    /// each distinct name is only leaked once. Avoid calling with untrusted names, since every
+    /// distinct name grows memory for the lifetime of the process.
+    pub fn custom(name: &str) -> Self {
+        let mut names = CUSTOM_EXCHANGE_NAMES
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        let name = match names.get(name) {
+            Some(interned) => *interned,
+            None => {
+                let interned: CustomExchangeName = Box::leak(name.to_owned().into_boxed_str());
+                names.insert(interned);
+                interned
+            }
+        };
+
+        ExchangeId::Custom(name)
+    }
+
     /// Return the &str representation of this [`ExchangeId`]
     pub fn as_str(&self) -> &'static str {
         match self {
             ExchangeId::BinanceSpot => "binance_spot",
             ExchangeId::BinanceFuturesUsd => "binance_futures_usd",
+            ExchangeId::BinanceFuturesCoin => "binance_futures_coin",
+            ExchangeId::BinanceSpotTestnet => "binance_spot_testnet",
+            ExchangeId::BinanceFuturesUsdTestnet => "binance_futures_usd_testnet",
+            ExchangeId::BinanceUs => "binance_us",
             ExchangeId::Bitfinex => "bitfinex",
+            ExchangeId::Bitstamp => "bitstamp",
+            ExchangeId::BybitSpot => "bybit_spot",
+            ExchangeId::BybitPerpetualsUsd => "bybit_perpetuals_usd",
             ExchangeId::Coinbase => "coinbase",
+            ExchangeId::Deribit => "deribit",
             ExchangeId::GateioSpot => "gateio_spot",
             ExchangeId::GateioFuturesUsd => "gateio_futures_usd",
             ExchangeId::GateioFuturesBtc => "gateio_futures_btc",
+            ExchangeId::HuobiSpot => "huobi_spot",
             ExchangeId::Kraken => "kraken",
+            ExchangeId::KrakenFutures => "kraken_futures",
+            ExchangeId::KucoinSpot => "kucoin_spot",
+            ExchangeId::MexcSpot => "mexc_spot",
             ExchangeId::Okx => "okx",
+            ExchangeId::UpbitSpot => "upbit_spot",
+            ExchangeId::Custom(name) => name,
+        }
+    }
+
+    /// Every [`ExchangeId`] variant, excluding [`ExchangeId::Custom`] exchanges.
+    pub const ALL: [Self; 22] = [
+        ExchangeId::BinanceFuturesCoin,
+        ExchangeId::BinanceFuturesUsd,
+        ExchangeId::BinanceFuturesUsdTestnet,
+        ExchangeId::BinanceSpot,
+        ExchangeId::BinanceSpotTestnet,
+        ExchangeId::BinanceUs,
+        ExchangeId::Bitfinex,
+        ExchangeId::Bitstamp,
+        ExchangeId::BybitPerpetualsUsd,
+        ExchangeId::BybitSpot,
+        ExchangeId::Coinbase,
+        ExchangeId::Deribit,
+        ExchangeId::GateioFuturesBtc,
+        ExchangeId::GateioFuturesUsd,
+        ExchangeId::GateioSpot,
+        ExchangeId::HuobiSpot,
+        ExchangeId::Kraken,
+        ExchangeId::KrakenFutures,
+        ExchangeId::KucoinSpot,
+        ExchangeId::MexcSpot,
+        ExchangeId::Okx,
+        ExchangeId::UpbitSpot,
+    ];
+
+    /// Return every [`ExchangeId`] variant (eg/ for enumerating venues in a UI), excluding
+    /// [`ExchangeId::Custom`] exchanges.
+    pub fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    /// Iterate over every [`ExchangeId`] variant (eg/ for listing venues in `--help` output),
+    /// excluding [`ExchangeId::Custom`] exchanges.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Common aliases accepted when parsing an [`ExchangeId`] via [`FromStr`], in addition to
+    /// the [`ExchangeId::as_str`] representation. Aliases are normalised (ie/ lowercase, with
+    /// '-', '.' & ' ' replaced by '_').
+    pub const ALIASES: [(&'static str, Self); 21] = [
+        ("binance", ExchangeId::BinanceSpot),
+        ("binance_futures", ExchangeId::BinanceFuturesUsd),
+        ("binance_usdm", ExchangeId::BinanceFuturesUsd),
+        ("binance_coinm", ExchangeId::BinanceFuturesCoin),
+        ("binanceus", ExchangeId::BinanceUs),
+        ("bybit", ExchangeId::BybitSpot),
+        ("bybit_perpetuals", ExchangeId::BybitPerpetualsUsd),
+        ("bybit_futures", ExchangeId::BybitPerpetualsUsd),
+        ("coinbase_pro", ExchangeId::Coinbase),
+        ("gdax", ExchangeId::Coinbase),
+        ("gate", ExchangeId::GateioSpot),
+        ("gateio", ExchangeId::GateioSpot),
+        ("gate_io", ExchangeId::GateioSpot),
+        ("gateio_futures", ExchangeId::GateioFuturesUsd),
+        ("huobi", ExchangeId::HuobiSpot),
+        ("htx", ExchangeId::HuobiSpot),
+        ("kucoin", ExchangeId::KucoinSpot),
+        ("mexc", ExchangeId::MexcSpot),
+        ("okex", ExchangeId::Okx),
+        ("upbit", ExchangeId::UpbitSpot),
+        ("kraken_spot", ExchangeId::Kraken),
+    ];
+
+    /// Determines whether the [`Connector`] associated with this [`ExchangeId`] has a
+    /// [`StreamSelector`] implementation for the [`SubKind`](crate::subscription::SubKind)
+    /// identified by the provided [`SubKindId`].
+    ///
+    /// Verified against the [`StreamSelector`] implementations of every exchange by tests, so
+    /// the support matrix cannot drift. Always `false` for [`ExchangeId::Custom`] exchanges,
+    /// since their [`StreamSelector`] implementations are unknown.
+    pub fn supports(&self, kind: SubKindId) -> bool {
+        if let ExchangeId::Custom(_) = self {
+            return false;
+        }
+
+        match kind {
+            SubKindId::PublicTrades | SubKindId::CandlesFromTrades => true,
+            SubKindId::PublicTradesAggregated => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceSpotTestnet
+                    | ExchangeId::BinanceUs
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::BinanceFuturesUsdTestnet
+                    | ExchangeId::BinanceFuturesCoin
+            ),
+            SubKindId::PublicTradesAll => matches!(self, ExchangeId::Okx),
+            SubKindId::OrderBooksL1 => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceSpotTestnet
+                    | ExchangeId::BinanceUs
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::BinanceFuturesUsdTestnet
+                    | ExchangeId::BinanceFuturesCoin
+                    | ExchangeId::BybitSpot
+                    | ExchangeId::BybitPerpetualsUsd
+                    | ExchangeId::Coinbase
+                    | ExchangeId::GateioSpot
+                    | ExchangeId::GateioFuturesUsd
+                    | ExchangeId::GateioFuturesBtc
+                    | ExchangeId::Kraken
+                    | ExchangeId::KucoinSpot
+                    | ExchangeId::MexcSpot
+            ),
+            SubKindId::OrderBooksL2 => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::Bitfinex
+                    | ExchangeId::Bitstamp
+                    | ExchangeId::Coinbase
+                    | ExchangeId::Okx
+            ),
+            SubKindId::OrderBooksL3 => false,
+            SubKindId::OrderBookSnapshots => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceSpotTestnet
+                    | ExchangeId::BinanceUs
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::BinanceFuturesUsdTestnet
+                    | ExchangeId::BinanceFuturesCoin
+                    | ExchangeId::Okx
+            ),
+            SubKindId::Candles => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceSpotTestnet
+                    | ExchangeId::BinanceUs
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::BinanceFuturesUsdTestnet
+                    | ExchangeId::BinanceFuturesCoin
+                    | ExchangeId::Bitfinex
+                    | ExchangeId::Deribit
+                    | ExchangeId::GateioSpot
+                    | ExchangeId::GateioFuturesUsd
+                    | ExchangeId::GateioFuturesBtc
+                    | ExchangeId::Kraken
+                    | ExchangeId::Okx
+            ),
+            SubKindId::CandleUpdates => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceSpotTestnet
+                    | ExchangeId::BinanceUs
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::BinanceFuturesUsdTestnet
+                    | ExchangeId::BinanceFuturesCoin
+                    | ExchangeId::Bitfinex
+                    | ExchangeId::Deribit
+                    | ExchangeId::GateioSpot
+                    | ExchangeId::GateioFuturesUsd
+                    | ExchangeId::GateioFuturesBtc
+                    | ExchangeId::Kraken
+                    | ExchangeId::Okx
+            ),
+            SubKindId::SourcedCandles => matches!(
+                self,
+                ExchangeId::GateioFuturesUsd | ExchangeId::GateioFuturesBtc
+            ),
+            SubKindId::Liquidations => {
+                matches!(
+                    self,
+                    ExchangeId::BinanceFuturesUsd
+                        | ExchangeId::BinanceFuturesCoin
+                        | ExchangeId::Deribit
+                        | ExchangeId::KrakenFutures
+                        | ExchangeId::Okx
+                )
+            }
+            SubKindId::LiquidationsAll => matches!(self, ExchangeId::BinanceFuturesUsd),
+            SubKindId::Tickers => matches!(
+                self,
+                ExchangeId::BinanceSpot
+                    | ExchangeId::BinanceSpotTestnet
+                    | ExchangeId::BinanceUs
+                    | ExchangeId::BinanceFuturesUsd
+                    | ExchangeId::BinanceFuturesUsdTestnet
+                    | ExchangeId::BinanceFuturesCoin
+                    | ExchangeId::Okx
+                    | ExchangeId::UpbitSpot
+            ),
+            SubKindId::IndexTickers => matches!(self, ExchangeId::Okx),
+            SubKindId::MarkPrices => {
+                matches!(self, ExchangeId::BinanceFuturesUsd | ExchangeId::Okx)
+            }
+            SubKindId::OptionSummaries => matches!(self, ExchangeId::Okx),
         }
     }
 
+    /// Return every [`SubKindId`] supported by the [`Connector`] associated with this
+    /// [`ExchangeId`]. See [`Self::supports`].
+    pub fn sub_kinds(&self) -> Vec<SubKindId> {
+        SubKindId::all()
+            .iter()
+            .copied()
+            .filter(|kind| self.supports(*kind))
+            .collect()
+    }
+
+    /// Machine-readable support matrix of every [`ExchangeId`] and the [`SubKindId`]s it
+    /// supports.
+    pub fn support_matrix() -> HashMap<ExchangeId, Vec<SubKindId>> {
+        Self::all()
+            .iter()
+            .map(|exchange| (*exchange, exchange.sub_kinds()))
+            .collect()
+    }
+
     /// Determines whether the [`Connector`] associated with this [`ExchangeId`] supports the
     /// ingestion of [`InstrumentKind::Spot`](barter_integration::model::InstrumentKind) market data.
     #[allow(clippy::match_like_matches_macro)]
     pub fn supports_spot(&self) -> bool {
         match self {
             ExchangeId::BinanceFuturesUsd => false,
+            ExchangeId::BinanceFuturesUsdTestnet => false,
+            ExchangeId::BinanceFuturesCoin => false,
+            ExchangeId::BybitPerpetualsUsd => false,
+            ExchangeId::KrakenFutures => false,
             _ => true,
         }
     }
@@ -218,8 +866,768 @@ impl ExchangeId {
     pub fn supports_futures(&self) -> bool {
         match self {
             ExchangeId::BinanceFuturesUsd => true,
+            ExchangeId::BinanceFuturesUsdTestnet => true,
+            ExchangeId::BinanceFuturesCoin => true,
+            ExchangeId::BybitPerpetualsUsd => true,
+            ExchangeId::Deribit => true,
+            ExchangeId::KrakenFutures => true,
             ExchangeId::Okx => true,
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        book::{OrderBookSnapshots, OrderBooksL1, OrderBooksL2, OrderBooksL3},
+        candle::{CandleUpdates, Candles, CandlesFromTrades, SourcedCandles},
+        liquidation::{Liquidations, LiquidationsAll},
+        mark_price::MarkPrices,
+        option::OptionSummaries,
+        ticker::{IndexTickers, Tickers},
+        trade::{PublicTrades, PublicTradesAggregated, PublicTradesAll},
+    };
+    use std::marker::PhantomData;
+
+    /// Probe resolving to [`Implemented::implemented`] if `Exchange` implements
+    /// [`StreamSelector<Kind>`], else falling back to [`NotImplemented::implemented`] via
+    /// auto-ref method resolution.
+    struct Probe<Exchange, Kind>(PhantomData<(Exchange, Kind)>);
+
+    trait Implemented {
+        fn implemented(&self) -> bool {
+            true
+        }
+    }
+
+    impl<Exchange, Kind> Implemented for Probe<Exchange, Kind>
+    where
+        Exchange: StreamSelector<Kind>,
+        Kind: SubKind,
+    {
+    }
+
+    trait NotImplemented {
+        fn implemented(&self) -> bool {
+            false
+        }
+    }
+
+    impl<Exchange, Kind> NotImplemented for &Probe<Exchange, Kind> {}
+
+    /// Generate the actual support matrix of the provided exchange types & [`SubKind`]s from
+    /// their [`StreamSelector`] implementations.
+    macro_rules! stream_selector_matrix {
+        ([$($exchange:ty),*], $kinds:tt) => {
+            vec![$(stream_selector_matrix!(@exchange $exchange, $kinds)),*]
+        };
+        (@exchange $exchange:ty, [$(($kind:ty, $kind_id:expr)),*]) => {
+            (
+                <$exchange as Connector>::ID,
+                vec![$(($kind_id, (&Probe::<$exchange, $kind>(PhantomData)).implemented())),*],
+            )
+        };
+    }
+
+    #[test]
+    fn test_connector_url_for() {
+        use crate::exchange::{
+            binance::{
+                futures::{BinanceFuturesUsd, BinanceFuturesUsdTestnet},
+                spot::{BinanceSpot, BinanceUs},
+            },
+            coinbase::Coinbase,
+            okx::Okx,
+        };
+
+        struct TestCase {
+            input: Result<Url, SocketError>,
+            expected: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot Production
+                input: BinanceSpot::url_for(Environment::Production),
+                expected: Some("wss://stream.binance.com:9443/ws"),
+            },
+            TestCase {
+                // TC1: BinanceSpot Sandbox uses the spot testnet
+                input: BinanceSpot::url_for(Environment::Sandbox),
+                expected: Some("wss://testnet.binance.vision/ws"),
+            },
+            TestCase {
+                // TC2: BinanceFuturesUsd Production
+                input: BinanceFuturesUsd::url_for(Environment::Production),
+                expected: Some("wss://fstream.binance.com/ws"),
+            },
+            TestCase {
+                // TC3: BinanceFuturesUsd Sandbox uses the futures testnet
+                input: BinanceFuturesUsd::url_for(Environment::Sandbox),
+                expected: Some("wss://stream.binancefuture.com/ws"),
+            },
+            TestCase {
+                // TC4: BinanceFuturesUsdTestnet Sandbox is itself
+                input: BinanceFuturesUsdTestnet::url_for(Environment::Sandbox),
+                expected: Some("wss://stream.binancefuture.com/ws"),
+            },
+            TestCase {
+                // TC5: BinanceUs has no sandbox
+                input: BinanceUs::url_for(Environment::Sandbox),
+                expected: None,
+            },
+            TestCase {
+                // TC6: Okx Production
+                input: Okx::url_for(Environment::Production),
+                expected: Some("wss://wsaws.okx.com:8443/ws/v5/public"),
+            },
+            TestCase {
+                // TC7: Okx Sandbox uses the demo trading server
+                input: Okx::url_for(Environment::Sandbox),
+                expected: Some("wss://wspap.okx.com:8443/ws/v5/public?brokerId=9999"),
+            },
+            TestCase {
+                // TC8: Coinbase Production
+                input: Coinbase::url_for(Environment::Production),
+                expected: Some("wss://ws-feed.exchange.coinbase.com/"),
+            },
+            TestCase {
+                // TC9: Coinbase public feed has no sandbox
+                input: Coinbase::url_for(Environment::Sandbox),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            match (test.input, test.expected) {
+                (Ok(actual), Some(expected)) => {
+                    assert_eq!(actual.as_str(), expected, "TC{} failed", index)
+                }
+                (Err(SocketError::Unsupported { item, .. }), None) => {
+                    assert_eq!(item, "sandbox environment", "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ack_mode_expected_responses() {
+        struct TestCase {
+            mode: AckMode,
+            num_subs: usize,
+            max_subs_per_request: usize,
+            expected: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: PerSubscription expects a response per ExchangeSub
+                mode: AckMode::PerSubscription,
+                num_subs: 250,
+                max_subs_per_request: 200,
+                expected: 250,
+            },
+            TestCase {
+                // TC1: PerRequest expects a response per chunk of ExchangeSubs
+                mode: AckMode::PerRequest,
+                num_subs: 250,
+                max_subs_per_request: 200,
+                expected: 2,
+            },
+            TestCase {
+                // TC2: PerRequest w/ no limit expects a single response
+                mode: AckMode::PerRequest,
+                num_subs: 250,
+                max_subs_per_request: usize::MAX,
+                expected: 1,
+            },
+            TestCase {
+                // TC3: PerRequest w/ no ExchangeSubs expects no responses
+                mode: AckMode::PerRequest,
+                num_subs: 0,
+                max_subs_per_request: 200,
+                expected: 0,
+            },
+            TestCase {
+                // TC4: None expects no responses
+                mode: AckMode::None,
+                num_subs: 250,
+                max_subs_per_request: 200,
+                expected: 0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test
+                .mode
+                .expected_responses(test.num_subs, test.max_subs_per_request);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_exchange_id_supports_matches_stream_selectors() {
+        use crate::exchange::{
+            binance::{
+                futures::{BinanceFuturesCoin, BinanceFuturesUsd, BinanceFuturesUsdTestnet},
+                spot::{BinanceSpot, BinanceSpotTestnet, BinanceUs},
+            },
+            bitfinex::Bitfinex,
+            bitstamp::Bitstamp,
+            bybit::{futures::BybitPerpetualsUsd, spot::BybitSpot},
+            coinbase::Coinbase,
+            deribit::Deribit,
+            gateio::{
+                futures::{GateioFuturesBtc, GateioFuturesUsd},
+                spot::GateioSpot,
+            },
+            huobi::HuobiSpot,
+            kraken::{futures::KrakenFutures, Kraken},
+            kucoin::KucoinSpot,
+            mexc::MexcSpot,
+            okx::Okx,
+            upbit::UpbitSpot,
+        };
+
+        let actual = stream_selector_matrix!(
+            [
+                BinanceFuturesCoin,
+                BinanceFuturesUsd,
+                BinanceFuturesUsdTestnet,
+                BinanceSpot,
+                BinanceSpotTestnet,
+                BinanceUs,
+                Bitfinex,
+                Bitstamp,
+                BybitPerpetualsUsd,
+                BybitSpot,
+                Coinbase,
+                Deribit,
+                GateioFuturesBtc,
+                GateioFuturesUsd,
+                GateioSpot,
+                HuobiSpot,
+                Kraken,
+                KrakenFutures,
+                KucoinSpot,
+                MexcSpot,
+                Okx,
+                UpbitSpot
+            ],
+            [
+                (PublicTrades, SubKindId::PublicTrades),
+                (PublicTradesAggregated, SubKindId::PublicTradesAggregated),
+                (PublicTradesAll, SubKindId::PublicTradesAll),
+                (OrderBooksL1, SubKindId::OrderBooksL1),
+                (OrderBooksL2, SubKindId::OrderBooksL2),
+                (OrderBooksL3, SubKindId::OrderBooksL3),
+                (OrderBookSnapshots, SubKindId::OrderBookSnapshots),
+                (Candles, SubKindId::Candles),
+                (CandleUpdates, SubKindId::CandleUpdates),
+                (SourcedCandles, SubKindId::SourcedCandles),
+                (CandlesFromTrades, SubKindId::CandlesFromTrades),
+                (Liquidations, SubKindId::Liquidations),
+                (LiquidationsAll, SubKindId::LiquidationsAll),
+                (Tickers, SubKindId::Tickers),
+                (IndexTickers, SubKindId::IndexTickers),
+                (MarkPrices, SubKindId::MarkPrices),
+                (OptionSummaries, SubKindId::OptionSummaries)
+            ]
+        );
+
+        // Every ExchangeId & SubKindId is probed
+        assert_eq!(
+            actual
+                .iter()
+                .map(|(exchange, _)| *exchange)
+                .collect::<Vec<_>>(),
+            ExchangeId::all()
+        );
+
+        for (exchange, kinds) in actual {
+            assert_eq!(
+                kinds.iter().map(|(kind, _)| *kind).collect::<Vec<_>>(),
+                SubKindId::all()
+            );
+
+            for (kind, implemented) in kinds {
+                assert_eq!(
+                    exchange.supports(kind),
+                    implemented,
+                    "{exchange} supports({kind}) does not match StreamSelector<{kind}> impl"
+                );
+            }
+        }
+    }
+
+    /// [`VolumeDenomination`](crate::subscription::candle::VolumeDenomination)s of the
+    /// [`Candle`](crate::subscription::candle::Candle) `volume` yielded by each [`ExchangeId`]
+    /// [`Candles`] stream.
+    ///
+    /// Exhaustive so that every new exchange integration must declare its denomination.
+    fn candle_volume_denominations(
+        exchange: ExchangeId,
+    ) -> &'static [crate::subscription::candle::VolumeDenomination] {
+        use crate::subscription::candle::VolumeDenomination::*;
+        match exchange {
+            ExchangeId::BinanceFuturesCoin => &[Contracts],
+            ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceFuturesUsdTestnet
+            | ExchangeId::BinanceSpot
+            | ExchangeId::BinanceSpotTestnet
+            | ExchangeId::BinanceUs
+            | ExchangeId::Bitfinex
+            | ExchangeId::Deribit
+            | ExchangeId::GateioSpot
+            | ExchangeId::Kraken
+            | ExchangeId::Okx => &[Base],
+            // Base volume is optional, so falls back to the number of contracts
+            ExchangeId::GateioFuturesBtc | ExchangeId::GateioFuturesUsd => &[Base, Contracts],
+            ExchangeId::Bitstamp
+            | ExchangeId::BybitPerpetualsUsd
+            | ExchangeId::BybitSpot
+            | ExchangeId::Coinbase
+            | ExchangeId::HuobiSpot
+            | ExchangeId::KrakenFutures
+            | ExchangeId::KucoinSpot
+            | ExchangeId::MexcSpot
+            | ExchangeId::UpbitSpot
+            | ExchangeId::Custom(_) => &[],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_candle_volume_denomination_per_exchange() {
+        use crate::{
+            exchange::{
+                binance::{
+                    candle::BinanceCandle,
+                    futures::{BinanceFuturesCoin, BinanceFuturesUsd, BinanceFuturesUsdTestnet},
+                    spot::{BinanceSpot, BinanceSpotTestnet, BinanceUs},
+                },
+                bitfinex::{candle::BitfinexCandleTransformer, Bitfinex},
+                deribit::{candle::DeribitCandles, Deribit},
+                gateio::{
+                    futures::{candle::GateioFuturesCandles, GateioFuturesBtc, GateioFuturesUsd},
+                    spot::{candle::GateioSpotCandle, GateioSpot},
+                },
+                kraken::{candle::KrakenCandles, Kraken},
+                okx::{candle::OkxCandles, Okx},
+            },
+            subscription::{candle::VolumeDenomination, Interval, Map, Subscription},
+            test_util::{instrument_map, transform_frames},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+            Identifier,
+        };
+        use barter_integration::model::{Instrument, InstrumentKind, SubscriptionId};
+        use std::collections::{BTreeSet, HashMap};
+
+        /// Collect the distinct [`VolumeDenomination`]s of the [`Candle`]s transformed from the
+        /// provided frames.
+        ///
+        /// Transforms via [`CandleUpdates`] so that in-progress frames are also yielded, since
+        /// [`Candles`] streams share the same conversions.
+        async fn denominations<Exchange, Transformer>(
+            instrument_map: Map<Instrument>,
+            frames: &[&str],
+        ) -> BTreeSet<VolumeDenomination>
+        where
+            Exchange: Connector,
+            Transformer: ExchangeTransformer<Exchange, CandleUpdates>,
+        {
+            transform_frames::<Exchange, CandleUpdates, Transformer, _>(instrument_map, frames)
+                .await
+                .into_iter()
+                .map(|event| event.unwrap().kind.volume_denomination)
+                .collect()
+        }
+
+        fn map<Exchange>(exchange: Exchange, instrument: Instrument) -> Map<Instrument>
+        where
+            Exchange: Connector,
+            Subscription<Exchange, Candles>:
+                Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        {
+            instrument_map(&[Subscription::from((
+                exchange,
+                instrument,
+                Candles(Interval::Minute1),
+            ))])
+        }
+
+        let spot = |base, quote| Instrument::from((base, quote, InstrumentKind::Spot));
+        let perpetual =
+            |base, quote| Instrument::from((base, quote, InstrumentKind::FuturePerpetual));
+
+        let binance_spot = r#"{"e":"kline","E":1672516000000,"s":"BNBBTC","k":{"t":1672515960000,"T":1672516019999,"s":"BNBBTC","i":"1m","o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":false,"q":"1.0000","V":"500","Q":"0.500"}}"#;
+        let binance_futures_usd = r#"{"e":"kline","E":1672515782136,"s":"BTCUSDT","k":{"t":1672515780000,"T":1672515839999,"s":"BTCUSDT","i":"1m","o":"16541.60","c":"16541.90","h":"16542.00","l":"16541.60","v":"5.344","n":39,"x":true,"q":"88400.69200","V":"3.932","Q":"65043.09090"}}"#;
+        let binance_futures_coin = r#"{"e":"kline","E":1700000000100,"s":"BTCUSD_PERP","k":{"t":1699999980000,"T":1700000039999,"s":"BTCUSD_PERP","i":"1m","o":"37000.0","c":"37012.5","h":"37020.1","l":"36998.2","v":"5880","n":28,"x":false,"q":"15.88904931","V":"2958","Q":"7.99538479"}}"#;
+        let gateio_futures = |name| {
+            [
+                format!(
+                    r#"{{"time":1542162490,"channel":"futures.candlesticks","event":"update","error":null,"result":[{{"t":1545129300,"v":100,"c":"95.4","h":"96.9","l":"89.5","o":"94.3","n":"1m_{name}","a":"1.5","w":true}}]}}"#
+                ),
+                format!(
+                    r#"{{"time":1542162490,"channel":"futures.candlesticks","event":"update","error":null,"result":[{{"t":1545129360,"v":100,"c":"95.4","h":"96.9","l":"89.5","o":"94.3","n":"1m_{name}"}}]}}"#
+                ),
+            ]
+        };
+        let [gateio_futures_usd_base, gateio_futures_usd_contracts] = gateio_futures("BTC_USDT");
+        let [gateio_futures_btc_base, gateio_futures_btc_contracts] = gateio_futures("BTC_USD");
+
+        let actual = vec![
+            (
+                ExchangeId::BinanceFuturesCoin,
+                denominations::<
+                    BinanceFuturesCoin,
+                    StatelessTransformer<BinanceFuturesCoin, CandleUpdates, BinanceCandle>,
+                >(
+                    map(BinanceFuturesCoin::default(), perpetual("btc", "usd")),
+                    &[binance_futures_coin],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::BinanceFuturesUsd,
+                denominations::<
+                    BinanceFuturesUsd,
+                    StatelessTransformer<BinanceFuturesUsd, CandleUpdates, BinanceCandle>,
+                >(
+                    map(BinanceFuturesUsd::default(), perpetual("btc", "usdt")),
+                    &[binance_futures_usd],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::BinanceFuturesUsdTestnet,
+                denominations::<
+                    BinanceFuturesUsdTestnet,
+                    StatelessTransformer<BinanceFuturesUsdTestnet, CandleUpdates, BinanceCandle>,
+                >(
+                    map(BinanceFuturesUsdTestnet::default(), perpetual("btc", "usdt")),
+                    &[binance_futures_usd],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::BinanceSpot,
+                denominations::<BinanceSpot, StatelessTransformer<BinanceSpot, CandleUpdates, BinanceCandle>>(
+                    map(BinanceSpot::default(), spot("bnb", "btc")),
+                    &[binance_spot],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::BinanceSpotTestnet,
+                denominations::<
+                    BinanceSpotTestnet,
+                    StatelessTransformer<BinanceSpotTestnet, CandleUpdates, BinanceCandle>,
+                >(
+                    map(BinanceSpotTestnet::default(), spot("bnb", "btc")),
+                    &[binance_spot],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::BinanceUs,
+                denominations::<BinanceUs, StatelessTransformer<BinanceUs, CandleUpdates, BinanceCandle>>(
+                    map(BinanceUs::default(), spot("bnb", "btc")),
+                    &[binance_spot],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::Bitfinex,
+                // Bitfinex candles are identified by the CHANNEL_ID of the validated subscription
+                denominations::<Bitfinex, BitfinexCandleTransformer>(
+                    Map(HashMap::from([(
+                        SubscriptionId::from("343351|trade:1m:tBTCUSD"),
+                        spot("btc", "usd"),
+                    )])),
+                    &[r#"[343351,[1574698200000,7399.9,7379.7,7399.9,7371.8,41.63633658]]"#],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::Deribit,
+                denominations::<Deribit, StatelessTransformer<Deribit, CandleUpdates, DeribitCandles>>(
+                    map(Deribit, perpetual("btc", "usd")),
+                    &[
+                        r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"chart.trades.BTC-PERPETUAL.1","data":{"volume":0.05219351,"tick":1573645080000,"open":8869.79,"low":8788.25,"high":8870.31,"cost":460,"close":8791.25}}}"#,
+                    ],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::GateioFuturesBtc,
+                denominations::<
+                    GateioFuturesBtc,
+                    StatelessTransformer<GateioFuturesBtc, CandleUpdates, GateioFuturesCandles>,
+                >(
+                    map(GateioFuturesBtc::default(), perpetual("btc", "usd")),
+                    &[&gateio_futures_btc_base, &gateio_futures_btc_contracts],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::GateioFuturesUsd,
+                denominations::<
+                    GateioFuturesUsd,
+                    StatelessTransformer<GateioFuturesUsd, CandleUpdates, GateioFuturesCandles>,
+                >(
+                    map(GateioFuturesUsd::default(), perpetual("btc", "usdt")),
+                    &[&gateio_futures_usd_base, &gateio_futures_usd_contracts],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::GateioSpot,
+                denominations::<GateioSpot, StatelessTransformer<GateioSpot, CandleUpdates, GateioSpotCandle>>(
+                    map(GateioSpot::default(), spot("btc", "usdt")),
+                    &[
+                        r#"{"time":1606292600,"channel":"spot.candlesticks","event":"update","result":{"t":"1606292580","v":"2362.32035","c":"19128.1","h":"19128.1","l":"19128.1","o":"19128.1","n":"1m_BTC_USDT"}}"#,
+                    ],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::Kraken,
+                denominations::<Kraken, StatelessTransformer<Kraken, CandleUpdates, KrakenCandles>>(
+                    map(Kraken, spot("xbt", "usd")),
+                    &[
+                        r#"[42,["1542057314.748456","1542057360.000000","3586.70000","3586.70000","3586.60000","3586.60000","3586.68894","0.03373000",2],"ohlc-1","XBT/USD"]"#,
+                    ],
+                )
+                .await,
+            ),
+            (
+                ExchangeId::Okx,
+                denominations::<Okx, StatelessTransformer<Okx, CandleUpdates, OkxCandles>>(
+                    map(Okx, spot("btc", "usdt")),
+                    &[
+                        r#"{"arg":{"channel":"candle1m","instId":"BTC-USDT"},"data":[["1597026383085","8533.02","8553.74","8527.17","8548.26","45247","529.5858061","529.5858061","0"]]}"#,
+                    ],
+                )
+                .await,
+            ),
+        ];
+
+        // Every ExchangeId supporting Candles is probed
+        assert_eq!(
+            actual
+                .iter()
+                .map(|(exchange, _)| *exchange)
+                .collect::<Vec<_>>(),
+            ExchangeId::iter()
+                .filter(|exchange| exchange.supports(SubKindId::Candles))
+                .collect::<Vec<_>>()
+        );
+
+        for exchange in ExchangeId::iter() {
+            assert_eq!(
+                candle_volume_denominations(exchange).is_empty(),
+                !exchange.supports(SubKindId::Candles),
+                "{exchange} Candles volume denomination is not declared"
+            );
+        }
+
+        for (exchange, denominations) in actual {
+            assert_eq!(
+                denominations,
+                BTreeSet::from_iter(candle_volume_denominations(exchange).iter().copied()),
+                "{exchange} Candles volume denomination does not match declaration"
+            );
+        }
+    }
+
+    #[test]
+    fn test_exchange_id_support_matrix() {
+        let matrix = ExchangeId::support_matrix();
+
+        assert_eq!(matrix.len(), ExchangeId::all().len());
+        assert_eq!(
+            matrix[&ExchangeId::HuobiSpot],
+            vec![SubKindId::PublicTrades, SubKindId::CandlesFromTrades]
+        );
+        assert_eq!(
+            matrix[&ExchangeId::Kraken],
+            vec![
+                SubKindId::PublicTrades,
+                SubKindId::OrderBooksL1,
+                SubKindId::Candles,
+                SubKindId::CandleUpdates,
+                SubKindId::CandlesFromTrades
+            ]
+        );
+        assert!(matrix.values().all(|kinds| {
+            kinds.contains(&SubKindId::PublicTrades)
+                && kinds.contains(&SubKindId::CandlesFromTrades)
+        }));
+    }
+
+    #[test]
+    fn test_exchange_id_round_trip() {
+        // ALL contains every variant exactly once
+        assert_eq!(
+            ExchangeId::iter().collect::<HashSet<_>>().len(),
+            ExchangeId::ALL.len()
+        );
+
+        for exchange in ExchangeId::iter() {
+            // FromStr, Display & as_str are consistent
+            assert_eq!(exchange.as_str().parse::<ExchangeId>(), Ok(exchange));
+            assert_eq!(exchange.to_string().parse::<ExchangeId>(), Ok(exchange));
+
+            // as_str is kept in sync with the serde renames
+            let serialised = serde_json::to_string(&exchange).unwrap();
+            assert_eq!(serialised, format!("\"{}\"", exchange.as_str()));
+            assert_eq!(
+                serde_json::from_str::<ExchangeId>(&serialised).unwrap(),
+                exchange
+            );
+        }
+
+        // Aliases never shadow the as_str representation of another ExchangeId
+        for (alias, exchange) in ExchangeId::ALIASES {
+            assert!(
+                ExchangeId::iter().all(|other| other.as_str() != alias),
+                "alias {alias} clashes with an ExchangeId"
+            );
+            assert_eq!(alias.parse::<ExchangeId>(), Ok(exchange));
+        }
+    }
+
+    #[test]
+    fn test_exchange_id_custom() {
+        let exchange = ExchangeId::Custom("my_broker");
+
+        // Identified by its name, including the MarketEvent Exchange
+        assert_eq!(exchange.as_str(), "my_broker");
+        assert_eq!(exchange.to_string(), "my_broker");
+        assert_eq!(
+            barter_integration::model::Exchange::from(exchange),
+            barter_integration::model::Exchange::from("my_broker")
+        );
+
+        // Serialised as its name, but rejected by the strict ExchangeId deserialisation
+        let serialised = serde_json::to_string(&exchange).unwrap();
+        assert_eq!(serialised, r#""my_broker""#);
+        assert!(serde_json::from_str::<ExchangeId>(&serialised).is_err());
+
+        // Opt-in CustomExchangeId deserialises it as an interned ExchangeId::Custom
+        let deserialised = serde_json::from_str::<CustomExchangeId>(&serialised).unwrap();
+        assert_eq!(ExchangeId::from(deserialised), exchange);
+        assert!(std::ptr::eq(
+            serde_json::from_str::<CustomExchangeId>(&serialised)
+                .unwrap()
+                .0
+                .as_str(),
+            deserialised.0.as_str()
+        ));
+        assert_eq!(serde_json::to_string(&deserialised).unwrap(), serialised);
+
+        // Built-in names & aliases are never deserialised as an ExchangeId::Custom
+        assert_eq!(
+            serde_json::from_str::<CustomExchangeId>(r#""binance_spot""#).unwrap(),
+            CustomExchangeId(ExchangeId::BinanceSpot)
+        );
+        assert_eq!(
+            serde_json::from_str::<CustomExchangeId>(r#""binanceus""#).unwrap(),
+            CustomExchangeId(ExchangeId::BinanceUs)
+        );
+
+        // Cannot be parsed, so typos of built-in ExchangeIds are still rejected
+        assert!("my_broker".parse::<ExchangeId>().is_err());
+
+        // Excluded from the built-in ExchangeIds & support matrix
+        assert!(ExchangeId::iter().all(|other| other != exchange));
+        assert!(exchange.sub_kinds().is_empty());
+
+        // Sorted after every built-in ExchangeId, which remain sorted by name
+        assert!(ExchangeId::iter().all(|other| other < ExchangeId::Custom("a")));
+        assert!(ExchangeId::iter()
+            .zip(ExchangeId::iter().skip(1))
+            .all(|(prev, next)| prev < next && prev.as_str() < next.as_str()));
+    }
+
+    #[test]
+    fn test_exchange_id_from_str() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<ExchangeId, ParseExchangeIdError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: as_str representation
+                input: "binance_futures_usd",
+                expected: Ok(ExchangeId::BinanceFuturesUsd),
+            },
+            TestCase {
+                // TC1: case-insensitive with surrounding whitespace
+                input: "  Binance_Spot ",
+                expected: Ok(ExchangeId::BinanceSpot),
+            },
+            TestCase {
+                // TC2: dashes are treated as underscores
+                input: "kraken-futures",
+                expected: Ok(ExchangeId::KrakenFutures),
+            },
+            TestCase {
+                // TC3: alias w/ dashes
+                input: "binance-futures",
+                expected: Ok(ExchangeId::BinanceFuturesUsd),
+            },
+            TestCase {
+                // TC4: legacy exchange name alias
+                input: "OKEX",
+                expected: Ok(ExchangeId::Okx),
+            },
+            TestCase {
+                // TC5: serde aliases are also accepted
+                input: "binance.us",
+                expected: Ok(ExchangeId::BinanceUs),
+            },
+            TestCase {
+                // TC6: serde aliases are also accepted
+                input: "binanceus",
+                expected: Ok(ExchangeId::BinanceUs),
+            },
+            TestCase {
+                // TC7: unknown exchange
+                input: "binanse",
+                expected: Err(ParseExchangeIdError {
+                    input: "binanse".to_string(),
+                }),
+            },
+            TestCase {
+                // TC8: empty input
+                input: "",
+                expected: Err(ParseExchangeIdError {
+                    input: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.parse::<ExchangeId>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_parse_exchange_id_error_lists_valid_values() {
+        let error = "ftx".parse::<ExchangeId>().unwrap_err();
+        let display = error.to_string();
+
+        assert!(display.starts_with("ParseExchangeIdError: unknown exchange \"ftx\""));
+        for exchange in ExchangeId::iter() {
+            assert!(display.contains(exchange.as_str()), "missing {exchange}");
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use super::{okx_checksum, OkxLevel};
+use crate::{
+    error::DataError,
+    exchange::{
+        okx::{channel::OkxChannel, market::OkxMarket, Okx},
+        ExchangeSub,
+    },
+    subscription::{
+        book::{Level, OrderBook, OrderBookSide, OrderBooksL2, OrderedPrice},
+        number, Subscription,
+    },
+    transformer::book::{InstrumentOrderBook, OrderBookUpdater},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::{cmp::Reverse, collections::BTreeMap};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-order-book-channel>
+/// #### Snapshot
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books",
+///     "instId": "BTC-USDT"
+///   },
+///   "action": "snapshot",
+///   "data": [
+///     {
+///       "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+///       "bids": [["8476.97", "256", "0", "12"], ["8475.55", "101", "0", "1"]],
+///       "ts": "1597026383085",
+///       "checksum": 2123921068,
+///       "prevSeqId": -1,
+///       "seqId": 123456
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### Subscription Event
+/// ```json
+/// {
+///   "event": "subscribe",
+///   "arg": {
+///     "channel": "books",
+///     "instId": "BTC-USDT"
+///   }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxOrderBookL2 {
+    Data(OkxOrderBookL2Message),
+    Event(OkxEvent),
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update message.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2Message {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "crate::exchange::okx::trade::de_okx_message_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub action: OkxBookAction,
+    pub data: Vec<OkxOrderBookL2Data>,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 message action.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OkxBookAction {
+    Snapshot,
+    Update,
+}
+
+/// [`Okx`](super::super::Okx) OrderBook Level2 snapshot or update data.
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL2Data {
+    pub bids: Vec<OkxLevel>,
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    pub checksum: i32,
+    #[serde(rename = "seqId", default)]
+    pub seq_id: Option<u64>,
+    #[serde(rename = "prevSeqId", default)]
+    pub prev_seq_id: Option<i64>,
+}
+
+/// [`Okx`](super::super::Okx) non market data message (eg/ "subscribe", "unsubscribe", "error").
+///
+/// See [`OkxOrderBookL2`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxEvent {
+    pub event: String,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            OkxOrderBookL2::Data(message) => Some(message.subscription_id.clone()),
+            OkxOrderBookL2::Event(_) => None,
+        }
+    }
+}
+
+/// [`Okx`](super::super::Okx) [`OrderBookUpdater`].
+///
+/// Okx: How To Maintain A Local OrderBook
+///
+/// 1. Subscribe to the "books" channel, which first sends a 400 level snapshot.
+/// 2. Apply each subsequent update to the local OrderBook:
+///    - If the amount is 0, remove the price level.
+///    - If the price level exists, replace the amount.
+///    - If the price level does not exist, insert it.
+/// 3. Calculate the CRC32 checksum over the best 25 levels of the local OrderBook, and compare it
+///    to the "checksum" provided by the exchange.
+/// 4. If the checksums do not match, re-subscribe to receive a fresh snapshot.
+///
+/// ## Notes:
+/// - Updates received while awaiting a fresh snapshot are skipped.
+/// - Each side is a [`BTreeMap`] keyed by price, so updates are applied in place and the best
+///   levels used for the checksum are read in order without re-sorting.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-checksum>
+#[derive(Clone, Debug)]
+pub struct OkxBookUpdater {
+    pub exchange_sub: ExchangeSub<OkxChannel, OkxMarket>,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    bids: BTreeMap<Reverse<OrderedPrice>, OkxLevel>,
+    asks: BTreeMap<OrderedPrice, OkxLevel>,
+    pub awaiting_snapshot: bool,
+}
+
+impl OkxBookUpdater {
+    /// Construct a new Okx [`OrderBookUpdater`] that awaits an initial snapshot, using the
+    /// provided `ws_sink_tx` to re-subscribe the [`ExchangeSub`] on checksum failure.
+    pub fn new(
+        exchange_sub: ExchangeSub<OkxChannel, OkxMarket>,
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    ) -> Self {
+        Self {
+            exchange_sub,
+            ws_sink_tx,
+            bids: BTreeMap::new(),
+            asks: BTreeMap::new(),
+            awaiting_snapshot: true,
+        }
+    }
+
+    /// Apply a snapshot or update to the local [`OkxLevel`]s and validate the resulting checksum.
+    pub fn apply(
+        &mut self,
+        action: OkxBookAction,
+        data: OkxOrderBookL2Data,
+    ) -> Result<(), DataError> {
+        match action {
+            OkxBookAction::Snapshot => {
+                self.bids.clear();
+                self.asks.clear();
+                self.awaiting_snapshot = false;
+            }
+            OkxBookAction::Update => {}
+        }
+
+        // Best levels first: bids descending & asks ascending
+        for level in data.bids {
+            upsert(&mut self.bids, Reverse(OrderedPrice(level.price)), level);
+        }
+        for level in data.asks {
+            upsert(&mut self.asks, OrderedPrice(level.price), level);
+        }
+
+        let actual = okx_checksum(self.bids.values(), self.asks.values());
+        if actual == data.checksum {
+            Ok(())
+        } else {
+            Err(DataError::InvalidChecksum {
+                expected: data.checksum,
+                actual,
+            })
+        }
+    }
+
+    /// Re-subscribe to the [`ExchangeSub`] in order to receive a fresh snapshot, skipping any
+    /// updates received in the meantime.
+    pub fn resubscribe(&mut self) {
+        self.awaiting_snapshot = true;
+
+        for op in ["unsubscribe", "subscribe"] {
+            let payload = WsMessage::Text(
+                json!({
+                    "op": op,
+                    "args": [&self.exchange_sub],
+                })
+                .to_string(),
+            );
+
+            if self.ws_sink_tx.send(payload).is_err() {
+                warn!(
+                    exchange_sub = ?self.exchange_sub,
+                    why = "WebSocket sink dropped",
+                    "failed to re-subscribe to Okx OrderBook after checksum failure",
+                );
+            }
+        }
+    }
+}
+
+/// Upsert the provided [`OkxLevel`] into the local side of the book at the provided price `key`,
+/// removing the level if it has a zero amount.
+fn upsert<Key: Ord>(side: &mut BTreeMap<Key, OkxLevel>, key: Key, level: OkxLevel) {
+    if level.amount > number::ZERO {
+        side.insert(key, level);
+    } else {
+        side.remove(&key);
+    }
+}
+
+#[async_trait]
+impl OrderBookUpdater for OkxBookUpdater {
+    type OrderBook = OrderBook;
+    type Update = OkxOrderBookL2;
+
+    async fn init<Exchange, Kind>(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument: Instrument,
+    ) -> Result<InstrumentOrderBook<Self>, DataError>
+    where
+        Exchange: Send,
+        Kind: Send,
+    {
+        // Okx sends the initial OrderBook snapshot over the WebSocket after subscribing
+        let subscription = Subscription::from((Okx, instrument.clone(), OrderBooksL2));
+
+        Ok(InstrumentOrderBook {
+            instrument,
+            updater: Self::new(ExchangeSub::new(&subscription), ws_sink_tx),
+            book: OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, Vec::<Level>::new()),
+                asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                first_update_id: None,
+                last_update_id: None,
+            },
+        })
+    }
+
+    fn update(
+        &mut self,
+        book: &mut Self::OrderBook,
+        update: Self::Update,
+    ) -> Result<Option<Self::OrderBook>, DataError> {
+        let message = match update {
+            OkxOrderBookL2::Data(message) => message,
+            OkxOrderBookL2::Event(_) => return Ok(None),
+        };
+
+        let mut updated = false;
+        for data in message.data {
+            // Skip updates until a fresh snapshot is received
+            if self.awaiting_snapshot && message.action == OkxBookAction::Update {
+                continue;
+            }
+
+            let time = data.time;
+            let seq_id = data.seq_id;
+            // Snapshots have a prevSeqId of -1, since they do not follow on from a delta
+            let first_update_id = data
+                .prev_seq_id
+                .and_then(|prev_seq_id| u64::try_from(prev_seq_id).ok())
+                .map(|prev_seq_id| prev_seq_id + 1);
+
+            if let Err(error) = self.apply(message.action, data) {
+                self.resubscribe();
+                return Err(error);
+            }
+
+            book.last_update_time = time;
+            book.first_update_id = first_update_id;
+            book.last_update_id = seq_id;
+            updated = true;
+        }
+
+        if !updated {
+            return Ok(None);
+        }
+
+        // Rebuild the OrderBook from the checksum validated levels
+        book.bids = OrderBookSide::new(Side::Buy, self.bids.values().map(Level::from));
+        book.asks = OrderBookSide::new(Side::Sell, self.asks.values().map(Level::from));
+
+        Ok(Some(book.snapshot()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::okx::book::tests::level;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_order_book_l2() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxOrderBookL2, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid snapshot
+                    input: r#"
+                    {
+                        "arg": {"channel": "books", "instId": "BTC-USDT"},
+                        "action": "snapshot",
+                        "data": [{
+                            "asks": [["8476.98", "415", "0", "13"]],
+                            "bids": [["8476.97", "256", "0", "12"]],
+                            "ts": "1597026383085",
+                            "checksum": -855196043,
+                            "prevSeqId": -1,
+                            "seqId": 123456
+                        }]
+                    }
+                    "#,
+                    expected: Ok(OkxOrderBookL2::Data(OkxOrderBookL2Message {
+                        subscription_id: SubscriptionId::from("books|BTC-USDT"),
+                        action: OkxBookAction::Snapshot,
+                        data: vec![OkxOrderBookL2Data {
+                            bids: vec![level("8476.97", "256")],
+                            asks: vec![level("8476.98", "415")],
+                            time: barter_integration::de::datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_millis(1597026383085),
+                            ),
+                            checksum: -855196043,
+                            seq_id: Some(123456),
+                            prev_seq_id: Some(-1),
+                        }],
+                    })),
+                },
+                TestCase {
+                    // TC1: subscription event
+                    input: r#"{"event": "subscribe", "arg": {"channel": "books", "instId": "BTC-USDT"}}"#,
+                    expected: Ok(OkxOrderBookL2::Event(OkxEvent {
+                        event: "subscribe".to_string(),
+                    })),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOrderBookL2>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_book_updater_apply_keeps_levels_sorted() {
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let subscription = Subscription::from((
+            Okx,
+            Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::InstrumentKind::Spot,
+            )),
+            OrderBooksL2,
+        ));
+        let mut updater = OkxBookUpdater::new(ExchangeSub::new(&subscription), ws_sink_tx);
+
+        let data = |bids: Vec<OkxLevel>, asks: Vec<OkxLevel>, checksum: i32| OkxOrderBookL2Data {
+            bids,
+            asks,
+            time: Utc::now(),
+            checksum,
+            seq_id: None,
+            prev_seq_id: None,
+        };
+
+        // Snapshot w/ unsorted levels
+        let snapshot_bids = vec![level("8475.55", "101"), level("8476.97", "256")];
+        let snapshot_asks = vec![level("8477", "7"), level("8476.98", "415")];
+        let expected = okx_checksum(
+            &[level("8476.97", "256"), level("8475.55", "101")],
+            &[level("8476.98", "415"), level("8477", "7")],
+        );
+        assert!(updater
+            .apply(
+                OkxBookAction::Snapshot,
+                data(snapshot_bids, snapshot_asks, expected)
+            )
+            .is_ok());
+
+        // Update inserting between, before & after existing levels, replacing, removing, and
+        // removing a price that does not exist
+        let update_bids = vec![
+            level("8476", "3"),
+            level("8477.5", "1"),
+            level("8475.55", "0"),
+            level("8470", "0"),
+        ];
+        let update_asks = vec![
+            level("8476.99", "2"),
+            level("8477", "9"),
+            level("8480", "4"),
+        ];
+        let expected_bids = vec![
+            level("8477.5", "1"),
+            level("8476.97", "256"),
+            level("8476", "3"),
+        ];
+        let expected_asks = vec![
+            level("8476.98", "415"),
+            level("8476.99", "2"),
+            level("8477", "9"),
+            level("8480", "4"),
+        ];
+        let expected = okx_checksum(&expected_bids, &expected_asks);
+        assert!(updater
+            .apply(
+                OkxBookAction::Update,
+                data(update_bids, update_asks, expected)
+            )
+            .is_ok());
+
+        assert_eq!(
+            updater.bids.values().cloned().collect::<Vec<_>>(),
+            expected_bids
+        );
+        assert_eq!(
+            updater.asks.values().cloned().collect::<Vec<_>>(),
+            expected_asks
+        );
+    }
+
+    #[test]
+    fn test_okx_book_updater_sequence() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<Option<OrderBook>, DataError>,
+            expected_resubscribe: bool,
+        }
+
+        let (ws_sink_tx, mut ws_sink_rx) = mpsc::unbounded_channel();
+        let mut book = futures::executor::block_on(OkxBookUpdater::init::<Okx, OrderBooksL2>(
+            ws_sink_tx,
+            Instrument::from((
+                "btc",
+                "usdt",
+                barter_integration::model::InstrumentKind::Spot,
+            )),
+        ))
+        .unwrap();
+
+        let book_l2 =
+            |bids: Vec<Level>, asks: Vec<Level>, update_ids: (Option<u64>, u64)| OrderBook {
+                last_update_time: Utc::now(),
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+                first_update_id: update_ids.0,
+                last_update_id: Some(update_ids.1),
+            };
+
+        let tests = vec![
+            TestCase {
+                // TC0: update before snapshot is skipped
+                input: r#"{"arg": {"channel": "books", "instId": "BTC-USDT"}, "action": "update", "data": [{"asks": [], "bids": [["8476.5", "10", "0", "1"]], "ts": "1597026383085", "checksum": 0}]}"#,
+                expected: Ok(None),
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC1: valid snapshot
+                input: r#"{"arg": {"channel": "books", "instId": "BTC-USDT"}, "action": "snapshot", "data": [{"asks": [["8477", "7", "0", "2"], ["8476.98", "415", "0", "13"]], "bids": [["8476.97", "256", "0", "12"], ["8475.55", "101", "0", "1"]], "ts": "1597026383085", "checksum": 2123921068, "prevSeqId": -1, "seqId": 10}]}"#,
+                expected: Ok(Some(book_l2(
+                    vec![
+                        Level::new(from_f64(8476.97), from_f64(256.0)),
+                        Level::new(from_f64(8475.55), from_f64(101.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(8476.98), from_f64(415.0)),
+                        Level::new(from_f64(8477.0), from_f64(7.0)),
+                    ],
+                    (None, 10),
+                ))),
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC2: valid update removing, inserting & replacing levels
+                input: r#"{"arg": {"channel": "books", "instId": "BTC-USDT"}, "action": "update", "data": [{"asks": [["8477", "9", "0", "3"]], "bids": [["8476.97", "0", "0", "0"], ["8476.5", "10", "0", "1"]], "ts": "1597026383086", "checksum": -1960475949, "prevSeqId": 10, "seqId": 12}]}"#,
+                expected: Ok(Some(book_l2(
+                    vec![
+                        Level::new(from_f64(8476.5), from_f64(10.0)),
+                        Level::new(from_f64(8475.55), from_f64(101.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(8476.98), from_f64(415.0)),
+                        Level::new(from_f64(8477.0), from_f64(9.0)),
+                    ],
+                    (Some(11), 12),
+                ))),
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC3: corrupted update w/ checksum mismatch triggers re-subscribe
+                input: r#"{"arg": {"channel": "books", "instId": "BTC-USDT"}, "action": "update", "data": [{"asks": [["8478", "1", "0", "1"]], "bids": [], "ts": "1597026383087", "checksum": 123}]}"#,
+                expected: Err(DataError::InvalidChecksum {
+                    expected: 123,
+                    actual: 0,
+                }),
+                expected_resubscribe: true,
+            },
+            TestCase {
+                // TC4: update while awaiting fresh snapshot is skipped
+                input: r#"{"arg": {"channel": "books", "instId": "BTC-USDT"}, "action": "update", "data": [{"asks": [["8477", "9", "0", "3"]], "bids": [], "ts": "1597026383088", "checksum": 0}]}"#,
+                expected: Ok(None),
+                expected_resubscribe: false,
+            },
+            TestCase {
+                // TC5: fresh snapshot resyncs the OrderBook
+                input: r#"{"arg": {"channel": "books", "instId": "BTC-USDT"}, "action": "snapshot", "data": [{"asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]], "bids": [["8476.97", "256", "0", "12"], ["8475.55", "101", "0", "1"]], "ts": "1597026383089", "checksum": 2123921068, "prevSeqId": -1, "seqId": 20}]}"#,
+                expected: Ok(Some(book_l2(
+                    vec![
+                        Level::new(from_f64(8476.97), from_f64(256.0)),
+                        Level::new(from_f64(8475.55), from_f64(101.0)),
+                    ],
+                    vec![
+                        Level::new(from_f64(8476.98), from_f64(415.0)),
+                        Level::new(from_f64(8477.0), from_f64(7.0)),
+                    ],
+                    (None, 20),
+                ))),
+                expected_resubscribe: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let update = serde_json::from_str::<OkxOrderBookL2>(test.input).unwrap();
+            let actual = book.updater.update(&mut book.book, update);
+
+            match (actual, test.expected) {
+                (Ok(Some(actual)), Ok(Some(expected))) => {
+                    assert_eq!(actual.bids, expected.bids, "TC{} failed", index);
+                    assert_eq!(actual.asks, expected.asks, "TC{} failed", index);
+                    assert_eq!(
+                        (actual.first_update_id, actual.last_update_id),
+                        (expected.first_update_id, expected.last_update_id),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Ok(None), Ok(None)) => {
+                    // Test passed
+                }
+                (
+                    Err(DataError::InvalidChecksum { expected, .. }),
+                    Err(DataError::InvalidChecksum {
+                        expected: expected_checksum,
+                        ..
+                    }),
+                ) => {
+                    assert_eq!(expected, expected_checksum, "TC{} failed", index);
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+
+            let resubscribed =
+                std::iter::from_fn(|| ws_sink_rx.try_recv().ok()).collect::<Vec<_>>();
+            if test.expected_resubscribe {
+                assert_eq!(
+                    resubscribed,
+                    vec![
+                        WsMessage::Text(
+                            json!({"op": "unsubscribe", "args": [{"channel": "books", "instId": "BTC-USDT"}]}).to_string()
+                        ),
+                        WsMessage::Text(
+                            json!({"op": "subscribe", "args": [{"channel": "books", "instId": "BTC-USDT"}]}).to_string()
+                        ),
+                    ],
+                    "TC{} failed",
+                    index
+                );
+            } else {
+                assert!(resubscribed.is_empty(), "TC{} failed", index);
+            }
+        }
+    }
+}
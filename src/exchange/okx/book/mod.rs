@@ -0,0 +1,259 @@
+use crate::subscription::{book::Level, number::Number};
+use barter_integration::de::extract_next;
+use serde::Serialize;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// Top-N OrderBook snapshot types.
+pub mod snapshot;
+
+/// Number of best levels on each side of the book used to calculate an [`Okx`](super::Okx)
+/// OrderBook checksum.
+pub const OKX_CHECKSUM_DEPTH: usize = 25;
+
+/// [`Okx`](super::Okx) OrderBook level.
+///
+/// The raw price & amount strings are retained since the [`Okx`](super::Okx) OrderBook checksum
+/// is calculated over the exact strings the exchange sent (eg/ "8476.10" != "8476.1").
+///
+/// #### Raw Payload Examples
+/// Format: \[price, amount, deprecated, number_of_orders\]
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-order-book-channel>
+/// ```json
+/// ["8476.98", "415", "0", "13"]
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxLevel {
+    pub price: Number,
+    pub amount: Number,
+    pub raw_price: String,
+    pub raw_amount: String,
+}
+
+impl From<&OkxLevel> for Level {
+    fn from(level: &OkxLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxLevel struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxLevel Sequence Format:
+                // [price, amount, deprecated, number_of_orders]
+                // <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-order-book-channel>
+
+                // Extract String price & amount, retaining the raw Strings for checksums
+                let raw_price = extract_next::<SeqAccessor, String>(&mut seq, "price")?;
+                let raw_amount = extract_next::<SeqAccessor, String>(&mut seq, "amount")?;
+                let price = raw_price.parse().map_err(serde::de::Error::custom)?;
+                let amount = raw_amount.parse().map_err(serde::de::Error::custom)?;
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxLevel {
+                    price,
+                    amount,
+                    raw_price,
+                    raw_amount,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxLevel
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+/// Calculate the [`Okx`](super::Okx) OrderBook checksum using the best
+/// [`OKX_CHECKSUM_DEPTH`] levels of the sorted bids & asks.
+///
+/// The checksum is the signed CRC32 of the levels interleaved as
+/// "bid1_price:bid1_amount:ask1_price:ask1_amount:bid2_price:...". If one side has fewer levels,
+/// the remaining levels of the other side are appended in order.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-checksum>
+pub fn okx_checksum<'a, Bids, Asks>(bids: Bids, asks: Asks) -> i32
+where
+    Bids: IntoIterator<Item = &'a OkxLevel>,
+    Asks: IntoIterator<Item = &'a OkxLevel>,
+{
+    let mut bids = bids.into_iter().take(OKX_CHECKSUM_DEPTH);
+    let mut asks = asks.into_iter().take(OKX_CHECKSUM_DEPTH);
+    let mut fields = Vec::with_capacity(OKX_CHECKSUM_DEPTH * 4);
+
+    loop {
+        let (bid, ask) = (bids.next(), asks.next());
+        if bid.is_none() && ask.is_none() {
+            break;
+        }
+
+        for level in [bid, ask].into_iter().flatten() {
+            fields.push(level.raw_price.as_str());
+            fields.push(level.raw_amount.as_str());
+        }
+    }
+
+    crc32(fields.join(":").as_bytes()) as i32
+}
+
+/// Lookup table for the CRC32 (IEEE 802.3) polynomial, generated at compile time.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+/// Calculate the CRC32 (IEEE 802.3) of the provided bytes.
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn level(price: &str, amount: &str) -> OkxLevel {
+        OkxLevel {
+            price: price.parse().unwrap(),
+            amount: amount.parse().unwrap(),
+            raw_price: price.to_string(),
+            raw_amount: amount.to_string(),
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_level() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxLevel, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OkxLevel retains raw strings
+                    input: r#"["8476.10", "415", "0", "13"]"#,
+                    expected: Ok(level("8476.10", "415")),
+                },
+                TestCase {
+                    // TC1: invalid OkxLevel w/ non-numeric price
+                    input: r#"["not a number", "415", "0", "13"]"#,
+                    expected: Err(serde::de::Error::custom("")),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxLevel>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_crc32() {
+        // Standard CRC32 check value
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn test_okx_checksum() {
+        struct TestCase {
+            bids: Vec<OkxLevel>,
+            asks: Vec<OkxLevel>,
+            expected: i32,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: equal depth bids & asks are interleaved
+                bids: vec![level("3366.1", "7"), level("3366", "6")],
+                asks: vec![level("3366.8", "9"), level("3368", "8")],
+                expected: -1881014294,
+            },
+            TestCase {
+                // TC1: remaining bids are appended when asks are shallower
+                bids: vec![level("8476.97", "256"), level("8475.55", "101")],
+                asks: vec![level("8476.98", "415")],
+                expected: 214565906,
+            },
+            TestCase {
+                // TC2: only the best OKX_CHECKSUM_DEPTH levels are used
+                bids: (0..30)
+                    .map(|i| level(&format!("{}", 8476 - i), "1"))
+                    .collect(),
+                asks: (0..30)
+                    .map(|i| level(&format!("{}", 8477 + i), "1"))
+                    .collect(),
+                expected: okx_checksum(
+                    &(0..25)
+                        .map(|i| level(&format!("{}", 8476 - i), "1"))
+                        .collect::<Vec<_>>(),
+                    &(0..25)
+                        .map(|i| level(&format!("{}", 8477 + i), "1"))
+                        .collect::<Vec<_>>(),
+                ),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = okx_checksum(&test.bids, &test.asks);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
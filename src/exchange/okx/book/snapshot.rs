@@ -0,0 +1,147 @@
+use super::OkxLevel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{okx::trade::OkxMessage, ExchangeId},
+    subscription::book::{Level, OrderBookSnapshot},
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::super::Okx) real-time top 5 levels OrderBook snapshot
+/// WebSocket message.
+pub type OkxOrderBookSnapshots = OkxMessage<OkxOrderBookSnapshot>;
+
+/// [`Okx`](super::super::Okx) real-time top 5 levels OrderBook snapshot.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-order-book-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "books5",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+///       "bids": [["8476.97", "256", "0", "12"], ["8475.55", "101", "0", "1"]],
+///       "instId": "BTC-USDT",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookSnapshot {
+    pub bids: Vec<OkxLevel>,
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxOrderBookSnapshots)> for MarketIter<OrderBookSnapshot> {
+    fn from(
+        (exchange_id, instrument, snapshots): (ExchangeId, Instrument, OkxOrderBookSnapshots),
+    ) -> Self {
+        snapshots
+            .data
+            .into_iter()
+            .map(|snapshot| {
+                Ok(MarketEvent {
+                    exchange_time: snapshot.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: OrderBookSnapshot {
+                        last_update_time: snapshot.time,
+                        bids: snapshot.bids.iter().map(Level::from).collect(),
+                        asks: snapshot.asks.iter().map(Level::from).collect(),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::okx::book::tests::level;
+    use barter_integration::{error::SocketError, model::SubscriptionId};
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_order_book_snapshots() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxOrderBookSnapshots, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OkxOrderBookSnapshots
+                    input: r#"
+                    {
+                        "arg": {"channel": "books5", "instId": "BTC-USDT"},
+                        "data": [
+                            {
+                                "asks": [["8476.98", "415", "0", "13"], ["8477", "7", "0", "2"]],
+                                "bids": [["8476.97", "256", "0", "12"]],
+                                "instId": "BTC-USDT",
+                                "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxOrderBookSnapshots {
+                        subscription_id: SubscriptionId::from("books5|BTC-USDT"),
+                        data: vec![OkxOrderBookSnapshot {
+                            bids: vec![level("8476.97", "256")],
+                            asks: vec![level("8476.98", "415"), level("8477", "7")],
+                            time: barter_integration::de::datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_millis(1597026383085),
+                            ),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid OkxOrderBookSnapshots w/ missing ts
+                    input: r#"
+                    {
+                        "arg": {"channel": "books5", "instId": "BTC-USDT"},
+                        "data": [{"asks": [], "bids": [], "instId": "BTC-USDT"}]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOrderBookSnapshots>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,639 @@
+use super::{
+    channel::{OkxCandleBar, OkxChannel},
+    market::OkxMarket,
+    trade::OkxMessage,
+    Okx,
+};
+use crate::{
+    de::{datetime_utc_from_epoch_ms, epoch_out_of_range},
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    streams::backfill::{candle_backfill_key, latest_closed_candles, BackfillClient, BackfillKey},
+    subscription::{
+        candle::{Candle, CandleSource, Candles, ClosedCandles, VolumeDenomination},
+        number::Number,
+        Interval, Subscription,
+    },
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    de::extract_next,
+    error::SocketError,
+    model::{Exchange, Instrument, InstrumentKind},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) HTTP candles url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#rest-api-market-data-get-candlesticks>
+pub const HTTP_CANDLES_URL_OKX: &str = "https://www.okx.com/api/v5/market/candles";
+
+/// Maximum number of candles [`Okx`](super::Okx) returns from a single HTTP request.
+pub const HTTP_CANDLES_MAX_LIMIT_OKX: usize = 300;
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time candles WebSocket message.
+pub type OkxCandles = OkxMessage<OkxCandle>;
+
+/// [`Okx`](super::Okx) real-time candle.
+///
+/// ### Raw Payload Examples
+/// Format: \[ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm\]
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "candle1m",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     [
+///       "1597026383085",
+///       "8533.02",
+///       "8553.74",
+///       "8527.17",
+///       "8548.26",
+///       "45247",
+///       "529.5858061",
+///       "529.5858061",
+///       "0"
+///     ]
+///   ]
+/// }
+/// ```
+///
+/// ## Notes:
+/// - For [`InstrumentKind::Spot`] the "vol" field is the base currency volume, whereas for
+///   [`InstrumentKind::FuturePerpetual`] it is the number of contracts, and "volCcy" is the base
+///   currency volume.
+/// - [`Okx`](super::Okx) does not provide the number of trades in a candle.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxCandle {
+    pub start: DateTime<Utc>,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
+    pub volume_currency: Number,
+    pub volume_currency_quote: Number,
+    pub confirmed: bool,
+}
+
+impl From<(ExchangeId, Instrument, OkxCandles)> for MarketIter<Candle> {
+    fn from((exchange_id, instrument, candles): (ExchangeId, Instrument, OkxCandles)) -> Self {
+        // Determine the candle channel (eg/ "candle1m") from the SubscriptionId (eg/ "candle1m|BTC-USDT")
+        let channel = candles
+            .subscription_id
+            .as_ref()
+            .split('|')
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+
+        candles
+            .data
+            .into_iter()
+            .map(|candle| {
+                let close_time = okx_candle_close_time(&channel, candle.start)?;
+
+                // Normalise volume to be denominated in the base currency
+                let volume = match instrument.kind {
+                    InstrumentKind::Spot => candle.volume,
+                    InstrumentKind::FuturePerpetual => candle.volume_currency,
+                };
+
+                // Okx does not provide a candle event time, so use the close time of historic
+                // candles, and the received time of in-progress candles that close in the future
+                let received_time = Utc::now();
+
+                Ok(MarketEvent {
+                    exchange_time: close_time.min(received_time),
+                    received_time,
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: Candle {
+                        start_time: candle.start,
+                        close_time,
+                        open: candle.open,
+                        high: candle.high,
+                        low: candle.low,
+                        close: candle.close,
+                        volume,
+                        volume_denomination: VolumeDenomination::Base,
+                        base_volume: Some(volume),
+                        quote_volume: Some(candle.volume_currency_quote),
+                        taker_buy_volume: None,
+                        taker_buy_quote_volume: None,
+                        trade_count: 0,
+                        closed: candle.confirmed,
+                        source: CandleSource::LastTrade,
+                        synthetic: false,
+                        derived: false,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Determine the close time of an [`Okx`](super::Okx) candle using the candle channel
+/// (eg/ "candle1m", "candle1H", "candle1Dutc") and the candle start time.
+///
+/// Consistent with [`Binance`](crate::exchange::binance::Binance), the close time is the last
+/// millisecond of the candle interval (ie/ start + interval - 1ms).
+fn okx_candle_close_time(channel: &str, start: DateTime<Utc>) -> Result<DateTime<Utc>, DataError> {
+    Interval::ALL
+        .into_iter()
+        .find(|interval| {
+            OkxCandleBar::try_from(*interval).is_ok_and(|bar| bar.channel.as_ref() == channel)
+        })
+        .map(|interval| interval.close_time(start))
+        .ok_or_else(|| {
+            DataError::Socket(SocketError::Unsupported {
+                entity: ExchangeId::Okx.as_str(),
+                item: format!("candle channel: {channel}"),
+            })
+        })
+}
+
+impl<'de> serde::Deserialize<'de> for OkxCandle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct SeqVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for SeqVisitor {
+            type Value = OkxCandle;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("OkxCandle struct from the Okx WebSocket API")
+            }
+
+            fn visit_seq<SeqAccessor>(
+                self,
+                mut seq: SeqAccessor,
+            ) -> Result<Self::Value, SeqAccessor::Error>
+            where
+                SeqAccessor: serde::de::SeqAccess<'de>,
+            {
+                // OkxCandle Sequence Format:
+                // [ts, o, h, l, c, vol, volCcy, volCcyQuote, confirm]
+                // <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+
+                // Extract String epoch ms start time & parse to DateTime<Utc>
+                let start = extract_next::<SeqAccessor, String>(&mut seq, "ts")?
+                    .parse()
+                    .map_err(serde::de::Error::custom)
+                    .and_then(|ms| {
+                        datetime_utc_from_epoch_ms(ms).ok_or_else(|| epoch_out_of_range(ms))
+                    })?;
+
+                // Extract String OHLCV values & parse to Number
+                let mut next_number = |field: &'static str| -> Result<Number, SeqAccessor::Error> {
+                    extract_next::<SeqAccessor, String>(&mut seq, field)?
+                        .parse()
+                        .map_err(serde::de::Error::custom)
+                };
+                let open = next_number("o")?;
+                let high = next_number("h")?;
+                let low = next_number("l")?;
+                let close = next_number("c")?;
+                let volume = next_number("vol")?;
+                let volume_currency = next_number("volCcy")?;
+                let volume_currency_quote = next_number("volCcyQuote")?;
+
+                // Extract String confirm flag & map to bool (ie/ "0" => false, "1" => true)
+                let confirmed = extract_next::<SeqAccessor, String>(&mut seq, "confirm")? == "1";
+
+                // Ignore any additional elements or SerDe will fail
+                //  '--> Exchange may add fields without warning
+                while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+
+                Ok(OkxCandle {
+                    start,
+                    open,
+                    high,
+                    low,
+                    close,
+                    volume,
+                    volume_currency,
+                    volume_currency_quote,
+                    confirmed,
+                })
+            }
+        }
+
+        // Use Visitor implementation to deserialise the OkxCandle
+        deserializer.deserialize_seq(SeqVisitor)
+    }
+}
+
+impl From<(ExchangeId, Instrument, ClosedCandles<OkxCandles>)> for MarketIter<Candle> {
+    fn from(
+        (exchange_id, instrument, ClosedCandles(candles)): (
+            ExchangeId,
+            Instrument,
+            ClosedCandles<OkxCandles>,
+        ),
+    ) -> Self {
+        Self::from((exchange_id, instrument, candles)).retain_closed()
+    }
+}
+
+/// [`Okx`](super::Okx) HTTP candles response, ordered newest first.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#rest-api-market-data-get-candlesticks>
+/// ```json
+/// {
+///     "code": "0",
+///     "msg": "",
+///     "data": [
+///         ["1597026383085", "3.721", "3.743", "3.677", "3.708", "8422410", "22698348.04828491", "12698348.04828491", "0"],
+///         ["1597026383085", "3.731", "3.799", "3.494", "3.72", "24912403", "67632347.24399722", "37632347.24399722", "1"]
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxCandlesResponse {
+    pub code: String,
+    pub msg: String,
+    pub data: Vec<OkxCandle>,
+}
+
+/// [`Okx`](super::Okx) HTTP candles [`BackfillClient`] used to backfill [`Candles`] streams.
+///
+/// The `candles_url` is injectable so an alternative server (eg/ a mock server) can be used.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct OkxCandlesClient {
+    pub candles_url: String,
+}
+
+impl Default for OkxCandlesClient {
+    fn default() -> Self {
+        Self::new(HTTP_CANDLES_URL_OKX)
+    }
+}
+
+impl OkxCandlesClient {
+    /// Construct a new [`Self`] using the provided candles endpoint
+    /// (eg/ "https://www.okx.com/api/v5/market/candles").
+    pub fn new<S: Into<String>>(candles_url: S) -> Self {
+        Self {
+            candles_url: candles_url.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl BackfillClient<Okx, Candles> for OkxCandlesClient {
+    async fn fetch(
+        &self,
+        subscription: &Subscription<Okx, Candles>,
+        limit: usize,
+    ) -> Result<Vec<MarketEvent<Candle>>, DataError> {
+        let exchange_sub = ExchangeSub::<OkxChannel, OkxMarket>::new(subscription);
+        let bar = OkxCandleBar::try_from(subscription.kind.0).map_err(SocketError::from)?;
+
+        // Request an additional candle since the latest is usually still in-progress
+        let url = format!(
+            "{}?instId={}&bar={}&limit={}",
+            self.candles_url,
+            exchange_sub.market.as_ref(),
+            bar.bar,
+            limit.saturating_add(1).min(HTTP_CANDLES_MAX_LIMIT_OKX)
+        );
+
+        let response = reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<OkxCandlesResponse>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        if response.code != "0" {
+            return Err(DataError::Socket(SocketError::Exchange(response.msg)));
+        }
+
+        // Re-use the WebSocket OkxCandles normalisation, which requires the SubscriptionId
+        let candles = OkxCandles {
+            subscription_id: exchange_sub.id(),
+            data: response.data,
+        };
+
+        let candles =
+            MarketIter::<Candle>::from((ExchangeId::Okx, subscription.instrument.clone(), candles))
+                .0
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(latest_closed_candles(candles, limit))
+    }
+
+    fn key(&self, event: &MarketEvent<Candle>) -> BackfillKey {
+        candle_backfill_key(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, model::SubscriptionId};
+    use chrono::Duration;
+
+    mod de {
+        use super::*;
+        use serde::de::Error;
+
+        #[test]
+        fn test_okx_message_candles() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxCandles, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid in progress Spot candle
+                    input: r#"
+                    {
+                        "arg": {"channel": "candle1m", "instId": "BTC-USDT"},
+                        "data": [[
+                            "1597026383085", "8533.02", "8553.74", "8527.17", "8548.26",
+                            "45247", "529.5858061", "529.5858061", "0"
+                        ]]
+                    }
+                    "#,
+                    expected: Ok(OkxCandles {
+                        subscription_id: SubscriptionId::from("candle1m|BTC-USDT"),
+                        data: vec![OkxCandle {
+                            start: datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_millis(1597026383085),
+                            ),
+                            open: from_f64(8533.02),
+                            high: from_f64(8553.74),
+                            low: from_f64(8527.17),
+                            close: from_f64(8548.26),
+                            volume: from_f64(45247.0),
+                            volume_currency: from_f64(529.5858061),
+                            volume_currency_quote: from_f64(529.5858061),
+                            confirmed: false,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid confirmed FuturePerpetual candle
+                    input: r#"
+                    {
+                        "arg": {"channel": "candle1H", "instId": "BTC-USDT-SWAP"},
+                        "data": [[
+                            "1672513200000", "16530.1", "16545.9", "16521.3", "16541.2",
+                            "102934", "1029.34", "17024313.2", "1"
+                        ]]
+                    }
+                    "#,
+                    expected: Ok(OkxCandles {
+                        subscription_id: SubscriptionId::from("candle1H|BTC-USDT-SWAP"),
+                        data: vec![OkxCandle {
+                            start: datetime_utc_from_epoch_duration(
+                                std::time::Duration::from_millis(1672513200000),
+                            ),
+                            open: from_f64(16530.1),
+                            high: from_f64(16545.9),
+                            low: from_f64(16521.3),
+                            close: from_f64(16541.2),
+                            volume: from_f64(102934.0),
+                            volume_currency: from_f64(1029.34),
+                            volume_currency_quote: from_f64(17024313.2),
+                            confirmed: true,
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid candle w/ non-numeric open price
+                    input: r#"
+                    {
+                        "arg": {"channel": "candle1m", "instId": "BTC-USDT"},
+                        "data": [[
+                            "1597026383085", "gibberish", "8553.74", "8527.17", "8548.26",
+                            "45247", "529.5858061", "529.5858061", "0"
+                        ]]
+                    }
+                    "#,
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxCandles>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_candle_close_time() {
+        struct TestCase {
+            channel: &'static str,
+            start: DateTime<Utc>,
+            expected: Option<DateTime<Utc>>,
+        }
+
+        let start = datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+            1672531200000, // 2023-01-01T00:00:00Z
+        ));
+
+        let tests = vec![
+            TestCase {
+                // TC0: candle1m
+                channel: "candle1m",
+                start,
+                expected: Some(start + Duration::minutes(1) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC1: candle4H
+                channel: "candle4H",
+                start,
+                expected: Some(start + Duration::hours(4) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC2: candle1Dutc
+                channel: "candle1Dutc",
+                start,
+                expected: Some(start + Duration::days(1) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC3: candle1Mutc is calendar month aware (January has 31 days)
+                channel: "candle1Mutc",
+                start,
+                expected: Some(start + Duration::days(31) - Duration::milliseconds(1)),
+            },
+            TestCase {
+                // TC4: unsupported channel
+                channel: "trades",
+                start,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = okx_candle_close_time(test.channel, test.start).ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_candles_to_market_iter() {
+        let candles = OkxCandles {
+            subscription_id: SubscriptionId::from("candle1m|BTC-USDT-SWAP"),
+            data: vec![
+                OkxCandle {
+                    start: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+                        1672531200000,
+                    )),
+                    open: from_f64(1.0),
+                    high: from_f64(2.0),
+                    low: from_f64(0.5),
+                    close: from_f64(1.5),
+                    volume: from_f64(100.0),
+                    volume_currency: from_f64(1.0),
+                    volume_currency_quote: from_f64(1.5),
+                    confirmed: true,
+                },
+                OkxCandle {
+                    start: datetime_utc_from_epoch_duration(std::time::Duration::from_millis(
+                        1672531260000,
+                    )),
+                    open: from_f64(1.5),
+                    high: from_f64(1.5),
+                    low: from_f64(1.5),
+                    close: from_f64(1.5),
+                    volume: from_f64(0.0),
+                    volume_currency: from_f64(0.0),
+                    volume_currency_quote: from_f64(0.0),
+                    confirmed: false,
+                },
+            ],
+        };
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+        let actual = MarketIter::<Candle>::from((ExchangeId::Okx, instrument, candles)).0;
+
+        // Both candles are yielded, w/ base currency volume & confirmed mapped to closed
+        assert_eq!(actual.len(), 2);
+        let candle = actual[0].as_ref().unwrap();
+        assert_eq!(
+            candle.kind.close_time,
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(1672531259999))
+        );
+        assert_eq!(
+            candle.kind.start_time,
+            datetime_utc_from_epoch_duration(std::time::Duration::from_millis(1672531200000))
+        );
+        assert_eq!(candle.exchange_time, candle.kind.close_time);
+        assert_eq!(candle.kind.volume, from_f64(1.0));
+        assert!(candle.kind.closed);
+        assert!(!actual[1].as_ref().unwrap().kind.closed);
+    }
+
+    #[tokio::test]
+    async fn test_okx_candles_client_fetch_from_mock_server() {
+        struct TestCase {
+            body: &'static str,
+            expected: Result<Vec<(u64, Number)>, DataError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: newest first response w/ in-progress candle is ordered oldest first &
+                // only the latest closed candles are retained
+                body: r#"{
+                    "code": "0",
+                    "msg": "",
+                    "data": [
+                        ["1672531320000", "3.0", "3.0", "3.0", "3.0", "3", "3", "3", "0"],
+                        ["1672531260000", "2.0", "2.0", "2.0", "2.0", "2", "2", "2", "1"],
+                        ["1672531200000", "1.0", "1.0", "1.0", "1.0", "1", "1", "1", "1"],
+                        ["1672531140000", "0.5", "0.5", "0.5", "0.5", "1", "1", "1", "1"]
+                    ]
+                }"#,
+                expected: Ok(vec![
+                    (1672531259999, from_f64(1.0)),
+                    (1672531319999, from_f64(2.0)),
+                ]),
+            },
+            TestCase {
+                // TC1: error response
+                body: r#"{"code": "51000", "msg": "Parameter bar error", "data": []}"#,
+                expected: Err(DataError::Socket(SocketError::Exchange(
+                    "Parameter bar error".to_string(),
+                ))),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (url, request) = crate::streams::backfill::tests::mock_http_server(test.body).await;
+            let client = OkxCandlesClient::new(format!("{url}/api/v5/market/candles"));
+            let subscription = Subscription::from((
+                Okx,
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            ));
+
+            let actual = client.fetch(&subscription, 2).await.map(|candles| {
+                candles
+                    .into_iter()
+                    .map(|candle| {
+                        (
+                            candle.kind.close_time.timestamp_millis() as u64,
+                            candle.kind.close,
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            });
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+
+            let request = request.await.unwrap();
+            assert!(
+                request.starts_with("GET /api/v5/market/candles?instId=BTC-USDT&bar=1m&limit=3 "),
+                "TC{index} failed because of unexpected request: {request}"
+            );
+        }
+    }
+}
@@ -1,6 +1,16 @@
 use super::Okx;
 use crate::{
-    subscription::{trade::PublicTrades, Subscription},
+    error::UnsupportedIntervalError,
+    subscription::{
+        book::{Depth, OrderBookSnapshots, OrderBooksL2},
+        candle::{CandleUpdates, Candles},
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        option::OptionSummaries,
+        ticker::{IndexTickers, Tickers},
+        trade::{PublicTrades, PublicTradesAll},
+        Interval, Subscription,
+    },
     Identifier,
 };
 use serde::Serialize;
@@ -17,6 +27,146 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time trades channel publishing every fill of an aggregated taker order,
+    /// whereas the [`Self::TRADES`] channel only publishes the first fill. Served via the
+    /// "business" server.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-all-trades-channel>
+    pub const TRADES_ALL: Self = Self("trades-all");
+
+    /// [`Okx`] real-time OrderBook Level2 channel, sending an initial 400 level snapshot followed
+    /// by incremental updates with a checksum over the top 25 levels.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-order-book-channel>
+    pub const ORDER_BOOK_L2: Self = Self("books");
+
+    /// [`Okx`] OrderBook snapshots channel used for unsupported [`Depth`]s. Subscriptions to
+    /// this channel are rejected during validation.
+    pub const ORDER_BOOK_SNAPSHOTS_UNSUPPORTED: Self = Self("books-unsupported");
+
+    /// [`Okx`] candles channel used for unsupported [`Interval`]s. Subscriptions to this channel
+    /// are rejected during validation.
+    pub const CANDLES_UNSUPPORTED: Self = Self("candle-unsupported");
+
+    /// [`Okx`] real-time tickers (24h rolling statistics) channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-tickers-channel>
+    pub const TICKERS: Self = Self("tickers");
+
+    /// [`Okx`] real-time index tickers channel, keyed by index (eg/ "BTC-USDT").
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-index-tickers-channel>
+    pub const INDEX_TICKERS: Self = Self("index-tickers");
+
+    /// [`Okx`] real-time option summary (greeks) channel.
+    ///
+    /// Note: this channel is keyed by instrument family (eg/ "BTC-USD") rather than by a single
+    /// instrument, so a single subscription yields a summary for every option contract of the
+    /// family.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-option-summary-channel>
+    pub const OPTION_SUMMARIES: Self = Self("opt-summary");
+
+    /// [`Okx`] real-time mark price channel.
+    ///
+    /// Note: a [`MarkPrices`] subscription also subscribes to the [`Self::FUNDING_RATES`]
+    /// channel, since [`Okx`] publishes funding separately.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-mark-price-channel>
+    pub const MARK_PRICES: Self = Self("mark-price");
+
+    /// [`Okx`] real-time funding rate channel.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-funding-rate-channel>
+    pub const FUNDING_RATES: Self = Self("funding-rate");
+
+    /// [`Okx`] real-time liquidation orders channel.
+    ///
+    /// Note: this channel is keyed by instrument type rather than by a single instrument, so a
+    /// single subscription yields liquidations for every SWAP instrument family.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-liquidation-orders-channel>
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
+
+    /// [`Okx`] real-time OrderBook snapshots channel for the provided [`Depth`], sending a top 5
+    /// levels snapshot every 100ms.
+    ///
+    /// Note: Okx only supports [`Depth::Top5`], so other depths will be rejected.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-order-book-channel>
+    pub fn order_book_snapshots(depth: Depth) -> Self {
+        match depth {
+            Depth::Top5 => Self("books5"),
+            Depth::Top10 | Depth::Top20 | Depth::Top50 => Self::ORDER_BOOK_SNAPSHOTS_UNSUPPORTED,
+        }
+    }
+
+    /// [`Okx`] real-time candles channel for the provided [`Interval`].
+    ///
+    /// [`Interval`]s without an [`OkxCandleBar`] map to [`Self::CANDLES_UNSUPPORTED`].
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+    pub fn candles(interval: Interval) -> Self {
+        OkxCandleBar::try_from(interval).map_or(Self::CANDLES_UNSUPPORTED, |bar| bar.channel)
+    }
+
+    /// Determine if the [`OkxChannel`] is served via the [`Okx`] "business" server rather than
+    /// the "public" server (eg/ candles, all trades).
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-production-trading-services>
+    pub fn is_business(&self) -> bool {
+        self.0.starts_with("candle") || *self == Self::TRADES_ALL
+    }
+}
+
+/// [`Okx`] candle bar (eg/ "1H") of a Barter [`Interval`].
+///
+/// Note: Okx does not support 8 hour candles, so [`Interval::Hour8`] is unsupported. Bars of 6
+/// hours and above use the UTC aligned variant (eg/ "1Dutc").
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-candlesticks-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct OkxCandleBar {
+    /// Candle bar, as used by the REST API (eg/ "1H").
+    pub bar: &'static str,
+    /// Candles channel of the bar (eg/ "candle1H").
+    pub channel: OkxChannel,
+}
+
+impl TryFrom<Interval> for OkxCandleBar {
+    type Error = UnsupportedIntervalError;
+
+    fn try_from(interval: Interval) -> Result<Self, Self::Error> {
+        let (bar, channel) = match interval {
+            Interval::Minute1 => ("1m", "candle1m"),
+            Interval::Minute3 => ("3m", "candle3m"),
+            Interval::Minute5 => ("5m", "candle5m"),
+            Interval::Minute15 => ("15m", "candle15m"),
+            Interval::Minute30 => ("30m", "candle30m"),
+            Interval::Hour1 => ("1H", "candle1H"),
+            Interval::Hour2 => ("2H", "candle2H"),
+            Interval::Hour4 => ("4H", "candle4H"),
+            Interval::Hour6 => ("6Hutc", "candle6Hutc"),
+            Interval::Hour12 => ("12Hutc", "candle12Hutc"),
+            Interval::Day1 => ("1Dutc", "candle1Dutc"),
+            Interval::Day3 => ("3Dutc", "candle3Dutc"),
+            Interval::Week1 => ("1Wutc", "candle1Wutc"),
+            Interval::Month1 => ("1Mutc", "candle1Mutc"),
+            Interval::Month3 => ("3Mutc", "candle3Mutc"),
+            Interval::Hour8 => {
+                return Err(UnsupportedIntervalError {
+                    exchange: "okx",
+                    interval,
+                })
+            }
+        };
+
+        Ok(Self {
+            bar,
+            channel: OkxChannel(channel),
+        })
+    }
 }
 
 impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
@@ -25,8 +175,132 @@ impl Identifier<OkxChannel> for Subscription<Okx, PublicTrades> {
     }
 }
 
+impl Identifier<OkxChannel> for Subscription<Okx, PublicTradesAll> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TRADES_ALL
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBooksL2> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L2
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OrderBookSnapshots> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::order_book_snapshots(self.kind.depth)
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Tickers> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::TICKERS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, IndexTickers> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::INDEX_TICKERS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, OptionSummaries> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::OPTION_SUMMARIES
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATIONS
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, Candles> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::candles(self.kind.0)
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, CandleUpdates> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::candles(self.kind.0)
+    }
+}
+
+impl Identifier<OkxChannel> for Subscription<Okx, MarkPrices> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::MARK_PRICES
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::Connector;
+
+    #[test]
+    fn test_okx_candle_bar_try_from_interval() {
+        // Expected candles channel of every Interval, in Interval::ALL order
+        let tests = [
+            (Interval::Minute1, Some("candle1m")),
+            (Interval::Minute3, Some("candle3m")),
+            (Interval::Minute5, Some("candle5m")),
+            (Interval::Minute15, Some("candle15m")),
+            (Interval::Minute30, Some("candle30m")),
+            (Interval::Hour1, Some("candle1H")),
+            (Interval::Hour2, Some("candle2H")),
+            (Interval::Hour4, Some("candle4H")),
+            (Interval::Hour6, Some("candle6Hutc")),
+            (Interval::Hour8, None),
+            (Interval::Hour12, Some("candle12Hutc")),
+            (Interval::Day1, Some("candle1Dutc")),
+            (Interval::Day3, Some("candle3Dutc")),
+            (Interval::Week1, Some("candle1Wutc")),
+            (Interval::Month1, Some("candle1Mutc")),
+            (Interval::Month3, Some("candle3Mutc")),
+        ];
+        assert_eq!(tests.map(|(interval, _)| interval), Interval::ALL);
+
+        for (interval, expected) in tests {
+            let actual = OkxCandleBar::try_from(interval);
+            match expected {
+                Some(channel) => {
+                    let bar = actual.unwrap();
+                    assert_eq!(bar.channel, OkxChannel(channel), "{interval} failed");
+                    assert_eq!(format!("candle{}", bar.bar), channel, "{interval} failed");
+                    assert_eq!(OkxChannel::candles(interval), bar.channel);
+                    assert!(bar.channel.is_business(), "{interval} failed");
+                }
+                None => {
+                    assert_eq!(
+                        actual,
+                        Err(UnsupportedIntervalError {
+                            exchange: "okx",
+                            interval
+                        }),
+                        "{interval} failed"
+                    );
+                    assert_eq!(
+                        OkxChannel::candles(interval),
+                        OkxChannel::CANDLES_UNSUPPORTED
+                    );
+                }
+            }
+
+            // Okx supported_intervals are exactly those with an OkxCandleBar
+            assert_eq!(
+                Okx::supported_intervals().contains(&interval),
+                expected.is_some(),
+                "{interval} failed"
+            );
+        }
+    }
+}
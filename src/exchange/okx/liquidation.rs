@@ -0,0 +1,351 @@
+use super::channel::OkxChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::liquidation::{Liquidation, LiquidationStatus},
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) real-time liquidation orders WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// ## Notes:
+/// - The "arg" is keyed by instrument type rather than by a single instrument, so the
+///   [`SubscriptionId`] is derived from the "instId" of the liquidated instrument instead.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    pub data: Vec<OkxLiquidation>,
+}
+
+/// [`Okx`](super::Okx) liquidation orders for a single instrument.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    #[serde(
+        rename = "instId",
+        deserialize_with = "de_okx_liquidation_inst_id_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "instFamily")]
+    pub instrument_family: String,
+    pub details: Vec<OkxLiquidationDetail>,
+}
+
+/// [`Okx`](super::Okx) liquidation order fill.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+///
+/// ## Notes:
+/// - For SWAP instruments the "sz" quantity is denominated in contracts.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationDetail {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxLiquidations {
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data
+            .first()
+            .map(|liquidation| liquidation.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, OkxLiquidations)> for MarketIter<Liquidation> {
+    fn from(
+        (exchange_id, instrument, liquidations): (ExchangeId, Instrument, OkxLiquidations),
+    ) -> Self {
+        // Only the Instrument associated with the first liquidation was identified, so skip any
+        // liquidations for other instrument families
+        let subscription_id = liquidations.id();
+
+        liquidations
+            .data
+            .into_iter()
+            .filter(|liquidation| Some(&liquidation.subscription_id) == subscription_id.as_ref())
+            .flat_map(|liquidation| liquidation.details)
+            .map(|detail| {
+                Ok(MarketEvent {
+                    exchange_time: detail.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: Liquidation {
+                        side: detail.side,
+                        price: detail.price,
+                        // Okx provides the bankruptcy price rather than the average fill price
+                        average_price: None,
+                        filled_quantity: detail.quantity,
+                        total_quantity: detail.quantity,
+                        status: LiquidationStatus::Filled,
+                        time: detail.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize an [`OkxLiquidation`] "instId" (eg/ "BTC-USDT-SWAP") as the associated
+/// [`SubscriptionId`] (eg/ "liquidation-orders|BTC-USDT-SWAP").
+pub fn de_okx_liquidation_inst_id_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|inst_id| ExchangeSub::from((OkxChannel::LIQUIDATIONS, inst_id)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::Okx,
+        subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+        subscription::{liquidation::Liquidations, Subscription},
+    };
+    use barter_integration::{de::datetime_utc_from_epoch_duration, model::InstrumentKind};
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+
+        #[test]
+        fn test_okx_liquidations() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxLiquidations, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input OkxLiquidations w/ multiple details is deserialised
+                    input: r#"
+                    {
+                        "arg": {"channel": "liquidation-orders", "instType": "SWAP"},
+                        "data": [
+                            {
+                                "details": [
+                                    {
+                                        "bkLoss": "0",
+                                        "bkPx": "0.007831",
+                                        "ccy": "",
+                                        "posSide": "short",
+                                        "side": "buy",
+                                        "sz": "13",
+                                        "ts": "1692266434010"
+                                    },
+                                    {
+                                        "bkLoss": "0",
+                                        "bkPx": "0.007830",
+                                        "ccy": "",
+                                        "posSide": "long",
+                                        "side": "sell",
+                                        "sz": "2",
+                                        "ts": "1692266434011"
+                                    }
+                                ],
+                                "instFamily": "IOST-USDT",
+                                "instId": "IOST-USDT-SWAP",
+                                "instType": "SWAP",
+                                "uly": "IOST-USDT"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxLiquidations {
+                        data: vec![OkxLiquidation {
+                            subscription_id: SubscriptionId::from(
+                                "liquidation-orders|IOST-USDT-SWAP",
+                            ),
+                            instrument_family: "IOST-USDT".to_string(),
+                            details: vec![
+                                OkxLiquidationDetail {
+                                    side: Side::Buy,
+                                    price: 0.007831,
+                                    quantity: 13.0,
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1692266434010,
+                                    )),
+                                },
+                                OkxLiquidationDetail {
+                                    side: Side::Sell,
+                                    price: 0.00783,
+                                    quantity: 2.0,
+                                    time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                        1692266434011,
+                                    )),
+                                },
+                            ],
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: input OkxLiquidations w/ invalid price fails to deserialise
+                    input: r#"
+                    {
+                        "arg": {"channel": "liquidation-orders", "instType": "SWAP"},
+                        "data": [
+                            {
+                                "details": [
+                                    {
+                                        "bkPx": "not a number",
+                                        "side": "buy",
+                                        "sz": "13",
+                                        "ts": "1692266434010"
+                                    }
+                                ],
+                                "instFamily": "IOST-USDT",
+                                "instId": "IOST-USDT-SWAP"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxLiquidations>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_liquidations_routing() {
+        struct TestCase {
+            input: String,
+            expected_instrument: Instrument,
+            expected_events: usize,
+        }
+
+        let subscriptions = vec![
+            Subscription::from((
+                Okx,
+                ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                Liquidations,
+            )),
+            Subscription::from((
+                Okx,
+                ("eth", "usdt", InstrumentKind::FuturePerpetual),
+                Liquidations,
+            )),
+        ];
+        let instrument_map = WebSocketSubMapper::map(&subscriptions).instrument_map;
+
+        let message = |inst_family: &str, details: usize| {
+            let detail = r#"{"bkPx": "1.0", "side": "sell", "sz": "1", "ts": "1692266434010"}"#;
+            format!(
+                r#"{{"arg": {{"channel": "liquidation-orders", "instType": "SWAP"}}, "data": [{{"details": [{}], "instFamily": "{inst_family}", "instId": "{inst_family}-SWAP"}}]}}"#,
+                vec![detail; details].join(","),
+            )
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: BTC-USDT liquidation is routed to the BTC-USDT perpetual Instrument
+                input: message("BTC-USDT", 1),
+                expected_instrument: Instrument::from((
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                )),
+                expected_events: 1,
+            },
+            TestCase {
+                // TC1: ETH-USDT liquidation w/ multiple details is routed to the ETH-USDT
+                // perpetual Instrument, yielding one MarketEvent per detail
+                input: message("ETH-USDT", 3),
+                expected_instrument: Instrument::from((
+                    "eth",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                )),
+                expected_events: 3,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let liquidations = serde_json::from_str::<OkxLiquidations>(&test.input).unwrap();
+            let instrument = instrument_map
+                .find(&liquidations.id().unwrap())
+                .unwrap_or_else(|_| panic!("TC{index} failed to identify Instrument"));
+            assert_eq!(instrument, test.expected_instrument, "TC{} failed", index);
+
+            let events =
+                MarketIter::<Liquidation>::from((ExchangeId::Okx, instrument, liquidations)).0;
+            assert_eq!(events.len(), test.expected_events, "TC{} failed", index);
+            assert!(
+                events
+                    .into_iter()
+                    .all(|event| event
+                        .is_ok_and(|event| event.instrument == test.expected_instrument))
+            );
+        }
+
+        // Liquidation for an un-subscribed instrument family cannot be identified
+        let unknown = serde_json::from_str::<OkxLiquidations>(&message("SOL-USDT", 1)).unwrap();
+        assert!(instrument_map.find(&unknown.id().unwrap()).is_err());
+    }
+}
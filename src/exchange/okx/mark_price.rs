@@ -0,0 +1,431 @@
+use super::{channel::OkxChannel, Okx};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, Connector},
+    subscription::{
+        mark_price::{MarkPrice, MarkPrices},
+        Map,
+    },
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Exchange, Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+
+/// [`Okx`] real-time mark price or funding rate WebSocket message.
+///
+/// [`Okx`] publishes the mark price and the funding rate on two separate channels, so a
+/// [`MarkPrices`] subscription subscribes to both. Messages from either channel are routed via
+/// the "mark-price" [`SubscriptionId`] (eg/ "mark-price|BTC-USDT-SWAP").
+///
+/// ### Raw Payload Examples
+/// #### Mark Price
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-mark-price-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "mark-price",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "markPx": "42310.6",
+///       "ts": "1630049139746"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// #### Funding Rate
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-funding-rate-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "funding-rate",
+///     "instId": "BTC-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "fundingRate": "0.0001875391284828",
+///       "fundingTime": "1700726400000",
+///       "nextFundingRate": "",
+///       "nextFundingTime": "1700755200000",
+///       "ts": "1700724675402"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxMarkPrices {
+    #[serde(
+        rename = "arg",
+        deserialize_with = "de_okx_mark_price_arg_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: Vec<OkxMarkPriceData>,
+}
+
+impl Identifier<Option<SubscriptionId>> for OkxMarkPrices {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// [`Okx`] mark price or funding rate data.
+///
+/// See [`OkxMarkPrices`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxMarkPriceData {
+    MarkPrice(OkxMarkPrice),
+    FundingRate(OkxFundingRate),
+}
+
+/// [`Okx`] real-time mark price.
+///
+/// See [`OkxMarkPrices`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxMarkPrice {
+    #[serde(rename = "markPx", deserialize_with = "barter_integration::de::de_str")]
+    pub mark_price: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+/// [`Okx`] real-time funding rate, where "fundingTime" is the settlement time of the current
+/// funding period (ie/ the next funding time).
+///
+/// See [`OkxMarkPrices`] for full raw payload examples.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxFundingRate {
+    #[serde(
+        rename = "fundingRate",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub funding_rate: f64,
+    #[serde(
+        rename = "fundingTime",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub next_funding_time: DateTime<Utc>,
+}
+
+/// Deserialize an [`OkxMarkPrices`] "arg" field as the associated "mark-price"
+/// [`SubscriptionId`], regardless of whether the message was sent via the "mark-price" or
+/// "funding-rate" channel.
+///
+/// eg/ "mark-price|BTC-USDT-SWAP"
+pub fn de_okx_mark_price_arg_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Arg<'a> {
+        #[serde(borrow)]
+        inst_id: &'a str,
+    }
+
+    Deserialize::deserialize(deserializer)
+        .map(|arg: Arg<'_>| ExchangeSub::from((OkxChannel::MARK_PRICES, arg.inst_id)).id())
+}
+
+/// [`Okx`] [`MarkPrices`] [`ExchangeTransformer`] that merges the separate "mark-price" and
+/// "funding-rate" channels into normalised [`MarkPrice`] events.
+///
+/// Funding rate messages only update the latest known funding rate for the associated
+/// [`Instrument`] and yield no events. Each mark price message yields a [`MarkPrice`] containing
+/// the latest known funding rate, which is `None` until the first funding rate message is
+/// received (typically within a minute of subscribing).
+#[derive(Clone, PartialEq, Debug)]
+pub struct OkxMarkPriceTransformer {
+    instrument_map: Map<Instrument>,
+    funding_rates: HashMap<SubscriptionId, OkxFundingRate>,
+}
+
+#[async_trait]
+impl ExchangeTransformer<Okx, MarkPrices> for OkxMarkPriceTransformer {
+    async fn new(
+        _: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            instrument_map,
+            funding_rates: HashMap::new(),
+        })
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.instrument_map.remove(subscription_ids);
+        subscription_ids.iter().for_each(|id| {
+            self.funding_rates.remove(id);
+        });
+    }
+
+    fn subscribe(&mut self, instrument_map: Map<Instrument>) -> Result<(), DataError> {
+        self.instrument_map.extend(instrument_map.0);
+        Ok(())
+    }
+}
+
+impl Transformer for OkxMarkPriceTransformer {
+    type Error = DataError;
+    type Input = OkxMarkPrices;
+    type Output = MarketEvent<MarkPrice>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&input.subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::Socket(unidentifiable))],
+        };
+
+        input
+            .data
+            .into_iter()
+            .filter_map(|data| match data {
+                OkxMarkPriceData::FundingRate(funding_rate) => {
+                    self.funding_rates
+                        .insert(input.subscription_id.clone(), funding_rate);
+                    None
+                }
+                OkxMarkPriceData::MarkPrice(mark_price) => {
+                    let funding_rate = self.funding_rates.get(&input.subscription_id);
+
+                    Some(Ok(MarketEvent {
+                        exchange_time: mark_price.time,
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(Okx::ID),
+                        instrument: instrument.clone(),
+                        sequence: None,
+                        tag: None,
+                        kind: MarkPrice {
+                            mark_price: mark_price.mark_price,
+                            index_price: None,
+                            funding_rate: funding_rate.map(|funding| funding.funding_rate),
+                            next_funding_time: funding_rate
+                                .map(|funding| funding.next_funding_time),
+                        },
+                    }))
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, error::SocketError, model::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_mark_prices() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxMarkPrices, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OkxMarkPrices from the mark-price channel
+                    input: r#"
+                    {
+                        "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                        "data": [
+                            {
+                                "instType": "SWAP",
+                                "instId": "BTC-USDT-SWAP",
+                                "markPx": "42310.6",
+                                "ts": "1630049139746"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxMarkPrices {
+                        subscription_id: SubscriptionId::from("mark-price|BTC-USDT-SWAP"),
+                        data: vec![OkxMarkPriceData::MarkPrice(OkxMarkPrice {
+                            mark_price: 42310.6,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1630049139746,
+                            )),
+                        })],
+                    }),
+                },
+                TestCase {
+                    // TC1: valid OkxMarkPrices from the funding-rate channel
+                    input: r#"
+                    {
+                        "arg": {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+                        "data": [
+                            {
+                                "instType": "SWAP",
+                                "instId": "BTC-USDT-SWAP",
+                                "fundingRate": "0.0001875391284828",
+                                "fundingTime": "1700726400000",
+                                "nextFundingRate": "",
+                                "nextFundingTime": "1700755200000",
+                                "ts": "1700724675402"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxMarkPrices {
+                        subscription_id: SubscriptionId::from("mark-price|BTC-USDT-SWAP"),
+                        data: vec![OkxMarkPriceData::FundingRate(OkxFundingRate {
+                            funding_rate: 0.0001875391284828,
+                            next_funding_time: datetime_utc_from_epoch_duration(
+                                Duration::from_millis(1700726400000),
+                            ),
+                        })],
+                    }),
+                },
+                TestCase {
+                    // TC2: invalid OkxMarkPrices w/ unrecognised data
+                    input: r#"
+                    {
+                        "arg": {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                        "data": [{"instType": "SWAP", "instId": "BTC-USDT-SWAP"}]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxMarkPrices>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_okx_mark_price_transformer_merges_funding_rate() {
+        let subscription_id = SubscriptionId::from("mark-price|BTC-USDT-SWAP");
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+        let instrument_map = Map(HashMap::from([(subscription_id.clone(), instrument)]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        let mut transformer =
+            <OkxMarkPriceTransformer as ExchangeTransformer<Okx, MarkPrices>>::new(
+                ws_sink_tx,
+                instrument_map,
+            )
+            .await
+            .unwrap();
+
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1630049139746));
+        let mark_price = |price: f64| OkxMarkPrices {
+            subscription_id: subscription_id.clone(),
+            data: vec![OkxMarkPriceData::MarkPrice(OkxMarkPrice {
+                mark_price: price,
+                time,
+            })],
+        };
+
+        struct TestCase {
+            input: OkxMarkPrices,
+            expected: Vec<MarkPrice>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: MarkPrice before any funding rate is received has no funding
+                input: mark_price(42310.6),
+                expected: vec![MarkPrice {
+                    mark_price: 42310.6,
+                    index_price: None,
+                    funding_rate: None,
+                    next_funding_time: None,
+                }],
+            },
+            TestCase {
+                // TC1: FundingRate updates state but yields no events
+                input: OkxMarkPrices {
+                    subscription_id: subscription_id.clone(),
+                    data: vec![OkxMarkPriceData::FundingRate(OkxFundingRate {
+                        funding_rate: 0.0001,
+                        next_funding_time: time,
+                    })],
+                },
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: MarkPrice after a funding rate is received includes the funding
+                input: mark_price(42311.0),
+                expected: vec![MarkPrice {
+                    mark_price: 42311.0,
+                    index_price: None,
+                    funding_rate: Some(0.0001),
+                    next_funding_time: Some(time),
+                }],
+            },
+            TestCase {
+                // TC3: Unidentifiable SubscriptionId yields an error
+                input: OkxMarkPrices {
+                    subscription_id: SubscriptionId::from("mark-price|ETH-USDT-SWAP"),
+                    data: vec![],
+                },
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let unidentifiable = test.input.subscription_id != subscription_id;
+            let actual = transformer.transform(test.input);
+
+            if unidentifiable {
+                assert!(
+                    matches!(actual.as_slice(), [Err(DataError::Socket(_))]),
+                    "TC{} failed",
+                    index
+                );
+                continue;
+            }
+
+            let actual = actual
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use super::Okx;
 use crate::{subscription::Subscription, Identifier};
-use barter_integration::model::InstrumentKind;
+use barter_integration::{error::SocketError, model::InstrumentKind};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// Type that defines how to translate a Barter [`Subscription`] into a
 /// [`Okx`](super::Okx) market that can be subscribed to.
@@ -28,3 +29,133 @@ impl AsRef<str> for OkxMarket {
         &self.0
     }
 }
+
+/// [`Okx`](super::Okx) HTTP instruments url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_INSTRUMENTS_URL_OKX: &str = "https://www.okx.com/api/v5/public/instruments";
+
+/// [`Okx`](super::Okx) HTTP instruments response, listing every market of an instrument type.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///     "code": "0",
+///     "msg": "",
+///     "data": [
+///         {
+///             "instType": "SWAP",
+///             "instId": "BTC-USDT-SWAP",
+///             "state": "live"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub code: String,
+    pub msg: String,
+    pub data: Vec<OkxInstrument>,
+}
+
+/// [`Okx`](super::Okx) market listed in the [`OkxInstruments`] response.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxInstrument {
+    #[serde(rename = "instId")]
+    pub inst_id: String,
+    pub state: String,
+}
+
+impl OkxInstruments {
+    /// Fetch the [`OkxInstruments`] of the provided instrument type (eg/ "SPOT", "SWAP") from
+    /// the provided url.
+    pub async fn fetch(url: &str, inst_type: &str) -> Result<Self, SocketError> {
+        let response = reqwest::get(format!("{url}?instType={inst_type}"))
+            .await
+            .map_err(SocketError::Http)?
+            .json::<Self>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        if response.code != "0" {
+            return Err(SocketError::Exchange(response.msg));
+        }
+
+        Ok(response)
+    }
+
+    /// Fetch every live spot & perpetual market, formatted as an [`OkxMarket`]
+    /// (eg/ "BTC-USDT", "BTC-USDT-SWAP").
+    pub async fn fetch_markets(url: &str) -> Result<HashSet<String>, SocketError> {
+        let (spot, swap) =
+            futures::future::try_join(Self::fetch(url, "SPOT"), Self::fetch(url, "SWAP")).await?;
+
+        Ok(spot.markets().chain(swap.markets()).collect())
+    }
+
+    /// Every live market, formatted as an [`OkxMarket`].
+    pub fn markets(self) -> impl Iterator<Item = String> {
+        self.data
+            .into_iter()
+            .filter(|instrument| instrument.state == "live")
+            .map(|instrument| instrument.inst_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::backfill::tests::mock_http_server;
+
+    #[tokio::test]
+    async fn test_okx_instruments_fetch_from_mock_server() {
+        struct TestCase {
+            body: &'static str,
+            expected: Result<Vec<&'static str>, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: only live instruments are returned
+                body: r#"{"code":"0","msg":"","data":[
+                    {"instType":"SWAP","instId":"BTC-USDT-SWAP","state":"live"},
+                    {"instType":"SWAP","instId":"ETH-USDT-SWAP","state":"live"},
+                    {"instType":"SWAP","instId":"LUNA-USDT-SWAP","state":"suspend"}
+                ]}"#,
+                expected: Ok(vec!["BTC-USDT-SWAP", "ETH-USDT-SWAP"]),
+            },
+            TestCase {
+                // TC1: error response code
+                body: r#"{"code":"51000","msg":"Parameter instType error","data":[]}"#,
+                expected: Err(SocketError::Exchange(String::new())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (url, request) = mock_http_server(test.body).await;
+            let actual = OkxInstruments::fetch(&url, "SWAP").await;
+
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    let mut actual = actual.markets().collect::<Vec<_>>();
+                    actual.sort();
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(SocketError::Exchange(_)), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+
+            assert!(
+                request.await.unwrap().starts_with("GET /?instType=SWAP "),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
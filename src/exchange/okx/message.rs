@@ -0,0 +1,158 @@
+use super::subscription::OkxSubResponse;
+use crate::{error::DataError, transformer::error::ExchangeMessage};
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) error codes that require the connection to be re-initialised.
+///
+/// - 60014: Requests too frequent.
+/// - 64008: The connection will soon be closed for a service upgrade.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+pub const OKX_FATAL_ERROR_CODES: [&str; 2] = ["60014", "64008"];
+
+/// [`Okx`](super::Okx) message variants that can be received over
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-websocket-public>
+///
+/// #### Invalid Request Error
+/// ```json
+/// {
+///     "event": "error",
+///     "code": "60012",
+///     "msg": "Invalid request: {\"op\": \"subscribe\", \"argss\":[{ \"channel\" : \"trades\", \"instId\" : \"BTC-USDT\"}]}",
+///     "connId": "a4d3ae55"
+/// }
+/// ```
+///
+/// #### Requests Too Frequent Error
+/// ```json
+/// {
+///     "event": "error",
+///     "code": "60014",
+///     "msg": "Requests too frequent.",
+///     "connId": "a4d3ae55"
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum OkxWsMessage<T> {
+    Data(T),
+    Error(OkxError),
+}
+
+impl<T> ExchangeMessage for OkxWsMessage<T> {
+    type Data = T;
+
+    fn into_data(self) -> Result<T, DataError> {
+        match self {
+            OkxWsMessage::Data(data) => Ok(data),
+            OkxWsMessage::Error(error) => Err(DataError::from(error)),
+        }
+    }
+}
+
+/// [`Okx`](super::Okx) error message sent by the exchange server, deserialized via the
+/// [`OkxSubResponse::Error`] variant.
+///
+/// See [`OkxWsMessage`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(try_from = "OkxSubResponse")]
+pub struct OkxError {
+    pub code: String,
+    pub message: String,
+}
+
+impl TryFrom<OkxSubResponse> for OkxError {
+    type Error = String;
+
+    fn try_from(response: OkxSubResponse) -> Result<Self, Self::Error> {
+        match response {
+            OkxSubResponse::Error { code, message } => Ok(Self { code, message }),
+            response => Err(format!("expected Okx error event, received: {response:?}")),
+        }
+    }
+}
+
+impl OkxError {
+    /// Determine if the error requires the connection to be re-initialised (eg/ requests too
+    /// frequent), rather than only affecting an individual subscription.
+    pub fn is_fatal(&self) -> bool {
+        OKX_FATAL_ERROR_CODES.contains(&self.code.as_str())
+    }
+}
+
+impl From<OkxError> for DataError {
+    fn from(error: OkxError) -> Self {
+        let fatal = error.is_fatal();
+        DataError::Exchange {
+            code: error.code,
+            message: error.message,
+            fatal,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use crate::exchange::okx::trade::OkxTrades;
+
+        #[test]
+        fn test_okx_ws_message() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxWsMessage<OkxTrades>, serde_json::Error>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input is invalid request OkxError
+                    input: r#"{"event":"error","code":"60012","msg":"Invalid request","connId":"a4d3ae55"}"#,
+                    expected: Ok(OkxWsMessage::Error(OkxError {
+                        code: "60012".to_string(),
+                        message: "Invalid request".to_string(),
+                    })),
+                },
+                TestCase {
+                    // TC1: input is subscription success, which is not an error
+                    input: r#"{"event":"subscribe","arg":{"channel":"trades","instId":"BTC-USDT"}}"#,
+                    expected: Err(serde::de::Error::custom("")),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxWsMessage<OkxTrades>>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_error_is_fatal() {
+        let error = |code: &str| OkxError {
+            code: code.to_string(),
+            message: String::new(),
+        };
+
+        assert!(!error("60012").is_fatal());
+        assert!(!error("60018").is_fatal());
+        assert!(error("60014").is_fatal());
+        assert!(error("64008").is_fatal());
+    }
+}
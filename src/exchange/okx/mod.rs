@@ -1,38 +1,124 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::{l2::OkxBookUpdater, snapshot::OkxOrderBookSnapshots},
+    candle::{OkxCandles, OkxCandlesClient},
+    channel::OkxChannel,
+    liquidation::OkxLiquidations,
+    mark_price::OkxMarkPriceTransformer,
+    market::{OkxInstruments, OkxMarket, HTTP_INSTRUMENTS_URL_OKX},
+    message::OkxWsMessage,
+    option::OkxOptionSummaries,
+    subscription::OkxSubResponse,
+    ticker::{OkxIndexTickers, OkxTickers},
+    trade::OkxTrades,
+    validator::OkxSubValidator,
 };
 use crate::{
-    exchange::{Connector, ExchangeId, ExchangeSub, StreamSelector},
-    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
-    ExchangeWsStream,
+    decoder::PlainText,
+    exchange::{Connector, Environment, ExchangeId, ExchangeSub, MarketsFuture, StreamSelector},
+    streams::backfill::BackfillClient,
+    subscriber::WebSocketSubscriber,
+    subscription::{
+        book::{OrderBookSnapshots, OrderBooksL2},
+        candle::{CandleUpdates, Candles, ClosedCandles},
+        liquidation::Liquidations,
+        mark_price::MarkPrices,
+        option::OptionSummaries,
+        ticker::{IndexTickers, Tickers},
+        trade::{PublicTrades, PublicTradesAll},
+        Interval, Map, Subscription,
+    },
+    transformer::{
+        book::MultiBookTransformer, error::ErrorTransformer, stateless::StatelessTransformer,
+    },
+    ExchangeWsStream, Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, InstrumentKind},
+    protocol::websocket::WsMessage,
 };
-use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
 use barter_macro::{DeExchange, SerExchange};
 use serde_json::json;
+use std::fmt::Debug;
 use url::Url;
 
+/// OrderBook types for [`Okx`].
+pub mod book;
+
+/// Candle types for [`Okx`].
+pub mod candle;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Liquidation types for [`Okx`].
+pub mod liquidation;
+
+/// Mark price & funding rate types, and the [`MarkPrices`] transformer for [`Okx`].
+pub mod mark_price;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// WebSocket message variants, including error messages, for [`Okx`].
+pub mod message;
+
+/// Option summary (greeks) types for [`Okx`].
+pub mod option;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
 
+/// Ticker (24h rolling statistics) types for [`Okx`].
+pub mod ticker;
+
 /// Public trade types for [`Okx`].
 pub mod trade;
 
+/// Custom [`SubscriptionValidator`](crate::subscriber::validator::SubscriptionValidator)
+/// implementation for [`Okx`].
+pub mod validator;
+
 /// [`Okx`] server base url.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#overview-api-resources-and-support>
 pub const BASE_URL_OKX: &str = "wss://wsaws.okx.com:8443/ws/v5/public";
 
+/// [`Okx`] business server base url, required for candle channels.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-production-trading-services>
+pub const BASE_URL_OKX_BUSINESS: &str = "wss://wsaws.okx.com:8443/ws/v5/business";
+
+/// [`Okx`] demo trading server base url, used for [`Environment::Sandbox`].
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-demo-trading-services>
+pub const BASE_URL_OKX_SANDBOX: &str = "wss://wspap.okx.com:8443/ws/v5/public?brokerId=9999";
+
+/// [`Okx`] demo trading business server base url, used for [`Environment::Sandbox`] candle
+/// channels.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-demo-trading-services>
+pub const BASE_URL_OKX_BUSINESS_SANDBOX: &str =
+    "wss://wspap.okx.com:8443/ws/v5/business?brokerId=9999";
+
+/// Maximum number of subscription args [`Okx`] accepts in a single subscribe request, also used
+/// as the maximum number of [`Subscription`](crate::subscription::Subscription)s per connection.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+pub const MAX_STREAMS_PER_CONNECTION_OKX: usize = 100;
+
+/// Convenient type alias for an [`Okx`] [`ExchangeWsStream`] that surfaces
+/// [`OkxWsMessage::Error`]s as [`DataError::Exchange`](crate::error::DataError::Exchange)s.
+pub type OkxWsStream<Transformer> = ExchangeWsStream<
+    ErrorTransformer<
+        Transformer,
+        OkxWsMessage<<Transformer as barter_integration::Transformer>::Input>,
+    >,
+>;
+
 /// [`Okx`] exchange.
 ///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api>
@@ -43,27 +129,618 @@ pub struct Okx;
 
 impl Connector for Okx {
     const ID: ExchangeId = ExchangeId::Okx;
+    const MAX_STREAMS_PER_CONNECTION: usize = MAX_STREAMS_PER_CONNECTION_OKX;
+    const MAX_SUBS_PER_REQUEST: usize = MAX_STREAMS_PER_CONNECTION_OKX;
     type Channel = OkxChannel;
     type Market = OkxMarket;
     type Subscriber = WebSocketSubscriber;
-    type SubValidator = WebSocketSubValidator;
+    type SubValidator = OkxSubValidator;
     type SubResponse = OkxSubResponse;
+    type Decoder = PlainText;
 
     fn url() -> Result<Url, SocketError> {
         Url::parse(BASE_URL_OKX).map_err(SocketError::UrlParse)
     }
 
+    fn url_for(environment: Environment) -> Result<Url, SocketError> {
+        match environment {
+            Environment::Production => Self::url(),
+            Environment::Sandbox => Url::parse(BASE_URL_OKX_SANDBOX).map_err(SocketError::UrlParse),
+        }
+    }
+
+    fn supported_intervals() -> &'static [Interval] {
+        &[
+            Interval::Minute1,
+            Interval::Minute3,
+            Interval::Minute5,
+            Interval::Minute15,
+            Interval::Minute30,
+            Interval::Hour1,
+            Interval::Hour2,
+            Interval::Hour4,
+            Interval::Hour6,
+            Interval::Hour12,
+            Interval::Day1,
+            Interval::Day3,
+            Interval::Week1,
+            Interval::Month1,
+            Interval::Month3,
+        ]
+    }
+
+    fn subscription_url<Kind>(
+        environment: Environment,
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<Url, SocketError>
+    where
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        match subscriptions.first() {
+            Some(subscription) if subscription.id().is_business() => {
+                let url = match environment {
+                    Environment::Production => BASE_URL_OKX_BUSINESS,
+                    Environment::Sandbox => BASE_URL_OKX_BUSINESS_SANDBOX,
+                };
+                Url::parse(url).map_err(SocketError::UrlParse)
+            }
+            _ => Self::url_for(environment),
+        }
+    }
+
+    fn validate_subscriptions<Kind>(
+        subscriptions: &[Subscription<Self, Kind>],
+    ) -> Result<(), SocketError>
+    where
+        Kind: Debug,
+        Subscription<Self, Kind>: Identifier<Self::Channel>,
+    {
+        // Okx only publishes top 5 levels OrderBook snapshots
+        if let Some(unsupported) = subscriptions
+            .iter()
+            .find(|subscription| subscription.id() == OkxChannel::ORDER_BOOK_SNAPSHOTS_UNSUPPORTED)
+        {
+            return Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!("{:?} (supported depths: top5)", unsupported.kind),
+            });
+        }
+
+        // Okx index & option family instruments are identified by their base & quote only
+        if let Some(unsupported) = subscriptions.iter().find(|subscription| {
+            let channel = subscription.id();
+            (channel == OkxChannel::INDEX_TICKERS || channel == OkxChannel::OPTION_SUMMARIES)
+                && subscription.instrument.kind != InstrumentKind::Spot
+        }) {
+            return Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} for {} instruments (supported: {})",
+                    unsupported.kind,
+                    unsupported.instrument.kind,
+                    InstrumentKind::Spot
+                ),
+            });
+        }
+
+        // Okx only publishes liquidation orders & mark prices for SWAP (ie/ perpetual) instruments
+        match subscriptions.iter().find(|subscription| {
+            let channel = subscription.id();
+            (channel == OkxChannel::LIQUIDATIONS || channel == OkxChannel::MARK_PRICES)
+                && subscription.instrument.kind != InstrumentKind::FuturePerpetual
+        }) {
+            Some(unsupported) => Err(SocketError::Unsupported {
+                entity: Self::ID.as_str(),
+                item: format!(
+                    "{:?} for {} instruments (supported: {})",
+                    unsupported.kind,
+                    unsupported.instrument.kind,
+                    InstrumentKind::FuturePerpetual
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn fetch_markets() -> Option<MarketsFuture> {
+        Some(Box::pin(OkxInstruments::fetch_markets(
+            HTTP_INSTRUMENTS_URL_OKX,
+        )))
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        vec![WsMessage::Text(
-            json!({
-                "op": "subscribe",
-                "args": &exchange_subs,
-            })
-            .to_string(),
-        )]
+        okx_requests("subscribe", exchange_subs)
+    }
+
+    fn unsubscribe_requests(
+        exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Vec<WsMessage> {
+        okx_requests("unsubscribe", exchange_subs)
     }
+
+    fn expected_responses(map: &Map<Instrument>) -> usize {
+        OkxSubValidator::expected_acks(map).len()
+    }
+}
+
+/// Construct the [`Okx`] [`WsMessage`] payload that actions the provided `op` (eg/ "subscribe",
+/// "unsubscribe") for a collection of [`ExchangeSub`]s.
+fn okx_requests(
+    op: &str,
+    exchange_subs: Vec<ExchangeSub<OkxChannel, OkxMarket>>,
+) -> Vec<WsMessage> {
+    // Liquidation orders are keyed by instrument type, so only action the channel once
+    let mut liquidations_subscribed = false;
+    let exchange_subs = exchange_subs
+        .into_iter()
+        .filter(|exchange_sub| {
+            exchange_sub.channel != OkxChannel::LIQUIDATIONS
+                || !std::mem::replace(&mut liquidations_subscribed, true)
+        })
+        .flat_map(|exchange_sub| {
+            // Mark prices also require the funding rate channel for the same market
+            let funding_rates = (exchange_sub.channel == OkxChannel::MARK_PRICES).then(|| {
+                ExchangeSub::from((OkxChannel::FUNDING_RATES, exchange_sub.market.clone()))
+            });
+            std::iter::once(exchange_sub).chain(funding_rates)
+        })
+        .collect::<Vec<_>>();
+
+    exchange_subs
+        .chunks(Okx::MAX_SUBS_PER_REQUEST)
+        .map(|args| {
+            WsMessage::Text(
+                json!({
+                    "op": op,
+                    "args": args,
+                })
+                .to_string(),
+            )
+        })
+        .collect()
 }
 
 impl StreamSelector<PublicTrades> for Okx {
-    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, OkxTrades>>;
+    type Stream = OkxWsStream<StatelessTransformer<Self, PublicTrades, OkxTrades>>;
+}
+
+impl StreamSelector<PublicTradesAll> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, PublicTradesAll, OkxTrades>>;
+}
+
+impl StreamSelector<OrderBooksL2> for Okx {
+    type Stream = OkxWsStream<MultiBookTransformer<Self, OrderBooksL2, OkxBookUpdater>>;
+}
+
+impl StreamSelector<OrderBookSnapshots> for Okx {
+    type Stream =
+        OkxWsStream<StatelessTransformer<Self, OrderBookSnapshots, OkxOrderBookSnapshots>>;
+}
+
+impl StreamSelector<Tickers> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, Tickers, OkxTickers>>;
+}
+
+impl StreamSelector<IndexTickers> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, IndexTickers, OkxIndexTickers>>;
+}
+
+impl StreamSelector<OptionSummaries> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, OptionSummaries, OkxOptionSummaries>>;
+}
+
+impl StreamSelector<MarkPrices> for Okx {
+    type Stream = OkxWsStream<OkxMarkPriceTransformer>;
+}
+
+impl StreamSelector<Liquidations> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, Liquidations, OkxLiquidations>>;
+}
+
+impl StreamSelector<Candles> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, Candles, ClosedCandles<OkxCandles>>>;
+
+    fn backfill_client() -> Option<Box<dyn BackfillClient<Self, Candles>>> {
+        Some(Box::new(OkxCandlesClient::default()))
+    }
+}
+
+impl StreamSelector<CandleUpdates> for Okx {
+    type Stream = OkxWsStream<StatelessTransformer<Self, CandleUpdates, OkxCandles>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{book::Depth, Interval};
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_okx_validate_subscriptions() {
+        struct TestCase {
+            input: Subscription<Okx, Liquidations>,
+            expected: Result<(), SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Liquidations for perpetual instrument is supported
+                input: Subscription::from((
+                    Okx,
+                    ("btc", "usdt", InstrumentKind::FuturePerpetual),
+                    Liquidations,
+                )),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Liquidations for spot instrument is unsupported
+                input: Subscription::from((
+                    Okx,
+                    ("btc", "usdt", InstrumentKind::Spot),
+                    Liquidations,
+                )),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Okx::validate_subscriptions(&[test.input]);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_validate_subscriptions_order_book_snapshots() {
+        struct TestCase {
+            input: Depth,
+            expected: Result<(), SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Top5 snapshots are supported
+                input: Depth::Top5,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: Top10 snapshots are unsupported
+                input: Depth::Top10,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+            TestCase {
+                // TC2: Top50 snapshots are unsupported
+                input: Depth::Top50,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription = Subscription::from((
+                Okx,
+                ("btc", "usdt", InstrumentKind::Spot),
+                OrderBookSnapshots::new(test.input),
+            ));
+            let actual = Okx::validate_subscriptions(&[subscription]);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_requests_liquidations() {
+        let actual = Okx::requests(vec![
+            ExchangeSub::from((
+                OkxChannel::LIQUIDATIONS,
+                OkxMarket("BTC-USDT-SWAP".to_string()),
+            )),
+            ExchangeSub::from((
+                OkxChannel::LIQUIDATIONS,
+                OkxMarket("ETH-USDT-SWAP".to_string()),
+            )),
+            ExchangeSub::from((OkxChannel::TRADES, OkxMarket("BTC-USDT".to_string()))),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": [
+                        {"channel": "liquidation-orders", "instType": "SWAP"},
+                        {"channel": "trades", "instId": "BTC-USDT"},
+                    ],
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_okx_requests_mark_prices() {
+        let actual = Okx::requests(vec![ExchangeSub::from((
+            OkxChannel::MARK_PRICES,
+            OkxMarket("BTC-USDT-SWAP".to_string()),
+        ))]);
+
+        assert_eq!(
+            actual,
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": [
+                        {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                        {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+                    ],
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_okx_requests_index_tickers_and_option_summaries() {
+        let actual = Okx::requests(vec![
+            ExchangeSub::from((OkxChannel::INDEX_TICKERS, OkxMarket("BTC-USDT".to_string()))),
+            ExchangeSub::from((
+                OkxChannel::OPTION_SUMMARIES,
+                OkxMarket("BTC-USD".to_string()),
+            )),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![WsMessage::Text(
+                json!({
+                    "op": "subscribe",
+                    "args": [
+                        {"channel": "index-tickers", "instId": "BTC-USDT"},
+                        {"channel": "opt-summary", "instFamily": "BTC-USD"},
+                    ],
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_okx_validate_subscriptions_index_tickers_and_option_summaries() {
+        struct TestCase {
+            input: InstrumentKind,
+            expected: Result<(), SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: index & option family instruments identified by Spot base & quote
+                input: InstrumentKind::Spot,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: perpetual instruments are unsupported
+                input: InstrumentKind::FuturePerpetual,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let index_tickers = Okx::validate_subscriptions(&[Subscription::from((
+                Okx,
+                ("btc", "usdt", test.input),
+                IndexTickers,
+            ))]);
+            let option_summaries = Okx::validate_subscriptions(&[Subscription::from((
+                Okx,
+                ("btc", "usd", test.input),
+                OptionSummaries,
+            ))]);
+
+            for actual in [index_tickers, option_summaries] {
+                match (actual, &test.expected) {
+                    (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_unsubscribe_requests() {
+        let actual = Okx::unsubscribe_requests(vec![
+            ExchangeSub::from((OkxChannel::TRADES, OkxMarket("BTC-USDT".to_string()))),
+            ExchangeSub::from((
+                OkxChannel::MARK_PRICES,
+                OkxMarket("BTC-USDT-SWAP".to_string()),
+            )),
+        ]);
+
+        assert_eq!(
+            actual,
+            vec![WsMessage::Text(
+                json!({
+                    "op": "unsubscribe",
+                    "args": [
+                        {"channel": "trades", "instId": "BTC-USDT"},
+                        {"channel": "mark-price", "instId": "BTC-USDT-SWAP"},
+                        {"channel": "funding-rate", "instId": "BTC-USDT-SWAP"},
+                    ],
+                })
+                .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_okx_validate_subscriptions_mark_prices() {
+        struct TestCase {
+            input: InstrumentKind,
+            expected: Result<(), SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: MarkPrices for perpetual instrument is supported
+                input: InstrumentKind::FuturePerpetual,
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: MarkPrices for spot instrument is unsupported
+                input: InstrumentKind::Spot,
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let subscription = Subscription::from((Okx, ("btc", "usdt", test.input), MarkPrices));
+            let actual = Okx::validate_subscriptions(&[subscription]);
+            match (actual, test.expected) {
+                (Ok(_), Ok(_)) | (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_expected_responses() {
+        struct TestCase {
+            input: Vec<&'static str>,
+            expected: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: one response per non-liquidation subscription
+                input: vec!["trades|BTC-USDT", "trades|ETH-USDT"],
+                expected: 2,
+            },
+            TestCase {
+                // TC1: liquidation subscriptions share a single response
+                input: vec![
+                    "liquidation-orders|BTC-USDT-SWAP",
+                    "liquidation-orders|ETH-USDT-SWAP",
+                ],
+                expected: 1,
+            },
+            TestCase {
+                // TC2: mark price subscriptions also expect a funding rate response
+                input: vec!["mark-price|BTC-USDT-SWAP", "trades|BTC-USDT-SWAP"],
+                expected: 3,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let map = test
+                .input
+                .into_iter()
+                .map(|id| {
+                    (
+                        barter_integration::model::SubscriptionId::from(id),
+                        Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    )
+                })
+                .collect::<Map<Instrument>>();
+
+            assert_eq!(
+                Okx::expected_responses(&map),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_subscription_url() {
+        // Trades are served via the public server
+        let trades = vec![Subscription::from((
+            Okx,
+            ("btc", "usdt", InstrumentKind::Spot),
+            PublicTrades,
+        ))];
+        assert_eq!(
+            Okx::subscription_url(Environment::Production, &trades)
+                .unwrap()
+                .as_str(),
+            BASE_URL_OKX
+        );
+
+        // Candles are served via the business server
+        let candles = vec![Subscription::from((
+            Okx,
+            ("btc", "usdt", InstrumentKind::Spot),
+            Candles(Interval::Minute1),
+        ))];
+        assert_eq!(
+            Okx::subscription_url(Environment::Production, &candles)
+                .unwrap()
+                .as_str(),
+            BASE_URL_OKX_BUSINESS
+        );
+
+        // All trades are served via the business server
+        let trades_all = vec![Subscription::from((
+            Okx,
+            ("btc", "usdt", InstrumentKind::Spot),
+            PublicTradesAll,
+        ))];
+        assert_eq!(
+            Okx::subscription_url(Environment::Production, &trades_all)
+                .unwrap()
+                .as_str(),
+            BASE_URL_OKX_BUSINESS
+        );
+
+        // Sandbox trades & candles are served via the demo trading servers
+        assert_eq!(
+            Okx::subscription_url(Environment::Sandbox, &trades)
+                .unwrap()
+                .as_str(),
+            BASE_URL_OKX_SANDBOX
+        );
+        assert_eq!(
+            Okx::subscription_url(Environment::Sandbox, &candles)
+                .unwrap()
+                .as_str(),
+            BASE_URL_OKX_BUSINESS_SANDBOX
+        );
+    }
 }
@@ -0,0 +1,224 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::option::OptionSummary,
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time option summary WebSocket message.
+pub type OkxOptionSummaries = OkxMessage<OkxOptionSummary>;
+
+/// [`Okx`](super::Okx) real-time option summary WebSocket message, containing the greeks of
+/// every option contract of an instrument family.
+///
+/// Note: the Black-Scholes greeks ("deltaBS", "gammaBS", "thetaBS", "vegaBS") are used since
+/// they are denominated in the quote currency, rather than the "delta", "gamma", "theta" & "vega"
+/// fields which are denominated in the number of underlying coins.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-option-summary-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "opt-summary",
+///     "instFamily": "BTC-USD"
+///   },
+///   "data": [
+///     {
+///       "instType": "OPTION",
+///       "instId": "BTC-USD-241013-70000-P",
+///       "uly": "BTC-USD",
+///       "delta": "-1.1180902625",
+///       "gamma": "2.2361957091",
+///       "vega": "0.0000000001",
+///       "theta": "0.0000032334",
+///       "lever": "8465.7148881161",
+///       "markVol": "0.3675452456",
+///       "bidVol": "0",
+///       "askVol": "1.1669317472",
+///       "realVol": "",
+///       "deltaBS": "-0.9999672034",
+///       "gammaBS": "0.0000000002",
+///       "thetaBS": "28.2649858387",
+///       "vegaBS": "0.0000114332",
+///       "ts": "1728703155650",
+///       "fwdPx": "62604.6993093463",
+///       "volLv": "0.2044831288"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOptionSummary {
+    #[serde(rename = "instId")]
+    pub contract: String,
+    #[serde(
+        rename = "deltaBS",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub delta: f64,
+    #[serde(
+        rename = "gammaBS",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub gamma: f64,
+    #[serde(
+        rename = "thetaBS",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub theta: f64,
+    #[serde(rename = "vegaBS", deserialize_with = "barter_integration::de::de_str")]
+    pub vega: f64,
+    #[serde(
+        rename = "markVol",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub mark_volatility: f64,
+    #[serde(rename = "bidVol", deserialize_with = "barter_integration::de::de_str")]
+    pub bid_volatility: f64,
+    #[serde(rename = "askVol", deserialize_with = "barter_integration::de::de_str")]
+    pub ask_volatility: f64,
+    #[serde(rename = "fwdPx", deserialize_with = "barter_integration::de::de_str")]
+    pub forward_price: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxOptionSummaries)> for MarketIter<OptionSummary> {
+    fn from(
+        (exchange_id, instrument, summaries): (ExchangeId, Instrument, OkxOptionSummaries),
+    ) -> Self {
+        summaries
+            .data
+            .into_iter()
+            .map(|summary| {
+                Ok(MarketEvent {
+                    exchange_time: summary.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: OptionSummary {
+                        contract: summary.contract,
+                        delta: summary.delta,
+                        gamma: summary.gamma,
+                        theta: summary.theta,
+                        vega: summary.vega,
+                        mark_volatility: summary.mark_volatility,
+                        bid_volatility: summary.bid_volatility,
+                        ask_volatility: summary.ask_volatility,
+                        forward_price: summary.forward_price,
+                        time: summary.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_option_summaries() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxOptionSummaries, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OkxOptionSummaries keyed by instrument family
+                    input: r#"
+                    {
+                        "arg": {"channel": "opt-summary", "instFamily": "BTC-USD"},
+                        "data": [
+                            {
+                                "instType": "OPTION", "instId": "BTC-USD-241013-70000-P",
+                                "uly": "BTC-USD", "delta": "-1.1180902625",
+                                "gamma": "2.2361957091", "vega": "0.0000000001",
+                                "theta": "0.0000032334", "lever": "8465.7148881161",
+                                "markVol": "0.3675452456", "bidVol": "0",
+                                "askVol": "1.1669317472", "realVol": "",
+                                "deltaBS": "-0.9999672034", "gammaBS": "0.0000000002",
+                                "thetaBS": "28.2649858387", "vegaBS": "0.0000114332",
+                                "ts": "1728703155650", "fwdPx": "62604.6993093463",
+                                "volLv": "0.2044831288"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxOptionSummaries {
+                        subscription_id: SubscriptionId::from("opt-summary|BTC-USD"),
+                        data: vec![OkxOptionSummary {
+                            contract: "BTC-USD-241013-70000-P".to_string(),
+                            delta: -0.9999672034,
+                            gamma: 0.0000000002,
+                            theta: 28.2649858387,
+                            vega: 0.0000114332,
+                            mark_volatility: 0.3675452456,
+                            bid_volatility: 0.0,
+                            ask_volatility: 1.1669317472,
+                            forward_price: 62604.6993093463,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1728703155650,
+                            )),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid OkxOptionSummaries w/ missing Black-Scholes greeks
+                    input: r#"
+                    {
+                        "arg": {"channel": "opt-summary", "instFamily": "BTC-USD"},
+                        "data": [
+                            {
+                                "instId": "BTC-USD-241013-70000-P", "delta": "-1.1180902625",
+                                "gamma": "2.2361957091", "vega": "0.0000000001",
+                                "theta": "0.0000032334", "markVol": "0.3675452456",
+                                "bidVol": "0", "askVol": "1.1669317472",
+                                "fwdPx": "62604.6993093463", "ts": "1728703155650"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxOptionSummaries>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{channel::OkxChannel, market::OkxMarket};
-use crate::exchange::subscription::ExchangeSub;
-use barter_integration::{error::SocketError, Validator};
+use crate::{exchange::subscription::ExchangeSub, Identifier};
+use barter_integration::{error::SocketError, model::SubscriptionId, Validator};
 use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
 // Implement custom Serialize to assist aesthetics of <Okx as Connector>::requests() function.
@@ -11,11 +11,58 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     {
         let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
         state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
+
+        // Liquidation orders are keyed by instrument type, and option summaries by instrument
+        // family, rather than a single instrument
+        if self.channel == OkxChannel::LIQUIDATIONS {
+            state.serialize_field("instType", "SWAP")?;
+        } else if self.channel == OkxChannel::OPTION_SUMMARIES {
+            state.serialize_field("instFamily", self.market.as_ref())?;
+        } else {
+            state.serialize_field("instId", self.market.as_ref())?;
+        }
+
         state.end()
     }
 }
 
+/// [`Okx`](super::Okx) subscription argument, echoed back by the exchange in subscription
+/// responses & market data messages.
+///
+/// Exactly one of `inst_id`, `inst_family` or `inst_type` is expected, depending on the
+/// [`OkxChannel`] (eg/ [`OkxChannel::OPTION_SUMMARIES`] are keyed by instrument family).
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"channel": "trades", "instId": "BTC-USDT"}
+/// {"channel": "opt-summary", "instFamily": "BTC-USD"}
+/// {"channel": "liquidation-orders", "instType": "SWAP"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxSubArg {
+    pub channel: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inst_family: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<String>,
+}
+
+impl Identifier<SubscriptionId> for OkxSubArg {
+    fn id(&self) -> SubscriptionId {
+        let key = self
+            .inst_id
+            .as_deref()
+            .or(self.inst_family.as_deref())
+            .or(self.inst_type.as_deref())
+            .unwrap_or_default();
+
+        ExchangeSub::from((self.channel.as_str(), key)).id()
+    }
+}
+
 /// [`Okx`](super::Okx) WebSocket subscription response.
 ///
 /// ### Raw Payload Examples
@@ -23,10 +70,11 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 /// ```json
 /// {
 ///   "event": "subscribe",
-///   "args": {
+///   "arg": {
 ///     "channel": "trades",
 ///     "instId": "BTC-USD-191227"
-///   }
+///   },
+///   "connId": "a4d3ae55"
 /// }
 /// ```
 ///
@@ -44,7 +92,10 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
 #[serde(tag = "event", rename_all = "lowercase")]
 pub enum OkxSubResponse {
     #[serde(rename = "subscribe")]
-    Subscribed,
+    Subscribed {
+        #[serde(alias = "args")]
+        arg: OkxSubArg,
+    },
     Error {
         code: String,
         #[serde(rename = "msg")]
@@ -58,7 +109,7 @@ impl Validator for OkxSubResponse {
         Self: Sized,
     {
         match self {
-            Self::Subscribed => Ok(self),
+            Self::Subscribed { .. } => Ok(self),
             Self::Error { code, message } => Err(SocketError::Subscribe(format!(
                 "received failure subscription response code: {code} with message: {message}",
             ))),
@@ -89,7 +140,14 @@ mod tests {
                     "args": {"channel": "trades", "instId": "BTC-USD-191227"}
                 }
                 "#,
-                    expected: Ok(OkxSubResponse::Subscribed),
+                    expected: Ok(OkxSubResponse::Subscribed {
+                        arg: OkxSubArg {
+                            channel: "trades".to_string(),
+                            inst_id: Some("BTC-USD-191227".to_string()),
+                            inst_family: None,
+                            inst_type: None,
+                        },
+                    }),
                 },
                 TestCase {
                     // TC1: input response is failed subscription
@@ -105,6 +163,24 @@ mod tests {
                         message: "Invalid request: {\"op\": \"subscribe\", \"args\":[{ \"channel\" : \"trades\", \"instId\" : \"BTC-USD-191227\"}]}".to_string()
                     }),
                 },
+                TestCase {
+                    // TC2: input response is option summary subscription success
+                    input: r#"
+                {
+                    "event": "subscribe",
+                    "arg": {"channel": "opt-summary", "instFamily": "BTC-USD"},
+                    "connId": "a4d3ae55"
+                }
+                "#,
+                    expected: Ok(OkxSubResponse::Subscribed {
+                        arg: OkxSubArg {
+                            channel: "opt-summary".to_string(),
+                            inst_id: None,
+                            inst_family: Some("BTC-USD".to_string()),
+                            inst_type: None,
+                        },
+                    }),
+                },
             ];
 
             for (index, test) in cases.into_iter().enumerate() {
@@ -135,7 +211,14 @@ mod tests {
         let cases = vec![
             TestCase {
                 // TC0: input response is subscription success
-                input_response: OkxSubResponse::Subscribed,
+                input_response: OkxSubResponse::Subscribed {
+                    arg: OkxSubArg {
+                        channel: "trades".to_string(),
+                        inst_id: Some("BTC-USDT".to_string()),
+                        inst_family: None,
+                        inst_type: None,
+                    },
+                },
                 is_valid: true,
             },
             TestCase {
@@ -153,4 +236,35 @@ mod tests {
             assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
         }
     }
+
+    #[test]
+    fn test_okx_sub_arg_id() {
+        struct TestCase {
+            input: &'static str,
+            expected: SubscriptionId,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: arg keyed by instrument id
+                input: r#"{"channel": "index-tickers", "instId": "BTC-USDT"}"#,
+                expected: SubscriptionId::from("index-tickers|BTC-USDT"),
+            },
+            TestCase {
+                // TC1: arg keyed by instrument family
+                input: r#"{"channel": "opt-summary", "instFamily": "BTC-USD"}"#,
+                expected: SubscriptionId::from("opt-summary|BTC-USD"),
+            },
+            TestCase {
+                // TC2: arg keyed by instrument type
+                input: r#"{"channel": "liquidation-orders", "instType": "SWAP"}"#,
+                expected: SubscriptionId::from("liquidation-orders|SWAP"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<OkxSubArg>(test.input).unwrap().id();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
 }
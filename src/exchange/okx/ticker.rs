@@ -0,0 +1,410 @@
+use super::trade::OkxMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeId,
+    subscription::ticker::{IndexTicker, Ticker},
+};
+use barter_integration::model::{Exchange, Instrument, InstrumentKind};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time tickers WebSocket message.
+pub type OkxTickers = OkxMessage<OkxTicker>;
+
+/// Terse type alias for an [`Okx`](super::Okx) real-time index tickers WebSocket message.
+pub type OkxIndexTickers = OkxMessage<OkxIndexTicker>;
+
+/// [`Okx`](super::Okx) real-time ticker (24h rolling statistics) WebSocket message.
+///
+/// Note: for [`InstrumentKind::Spot`] "vol24h" is denominated in the base currency and
+/// "volCcy24h" in the quote currency, whereas for [`InstrumentKind::FuturePerpetual`]
+/// "vol24h" is the number of contracts and "volCcy24h" is denominated in the base currency.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instType": "SPOT",
+///       "instId": "BTC-USDT",
+///       "last": "9999.99",
+///       "lastSz": "0.1",
+///       "askPx": "9999.99",
+///       "askSz": "11",
+///       "bidPx": "8888.88",
+///       "bidSz": "5",
+///       "open24h": "9000",
+///       "high24h": "10000",
+///       "low24h": "8888.88",
+///       "volCcy24h": "2222",
+///       "vol24h": "2222",
+///       "sodUtc0": "2222",
+///       "sodUtc8": "2222",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxTicker {
+    #[serde(rename = "last", deserialize_with = "barter_integration::de::de_str")]
+    pub last_price: f64,
+    #[serde(
+        rename = "open24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub open: f64,
+    #[serde(
+        rename = "high24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: f64,
+    #[serde(rename = "low24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(rename = "vol24h", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(
+        rename = "volCcy24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub volume_currency: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxTickers)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, tickers): (ExchangeId, Instrument, OkxTickers)) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                // Normalise volume to be denominated in the base currency
+                let (volume_24h, quote_volume_24h) = match instrument.kind {
+                    InstrumentKind::Spot => (ticker.volume, Some(ticker.volume_currency)),
+                    InstrumentKind::FuturePerpetual => (ticker.volume_currency, None),
+                };
+
+                Ok(MarketEvent {
+                    exchange_time: ticker.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: Ticker {
+                        last_price: ticker.last_price,
+                        open_24h: ticker.open,
+                        high_24h: ticker.high,
+                        low_24h: ticker.low,
+                        volume_24h,
+                        quote_volume_24h,
+                        price_change_percent_24h: Ticker::price_change_percent(
+                            ticker.open,
+                            ticker.last_price,
+                        ),
+                        time: ticker.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// [`Okx`](super::Okx) real-time index ticker WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-index-tickers-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "index-tickers",
+///     "instId": "BTC-USDT"
+///   },
+///   "data": [
+///     {
+///       "instId": "BTC-USDT",
+///       "idxPx": "0.1",
+///       "high24h": "0.5",
+///       "low24h": "0.1",
+///       "open24h": "0.1",
+///       "sodUtc0": "0.1",
+///       "sodUtc8": "0.1",
+///       "ts": "1597026383085"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxIndexTicker {
+    #[serde(rename = "idxPx", deserialize_with = "barter_integration::de::de_str")]
+    pub index_price: f64,
+    #[serde(
+        rename = "open24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub open: f64,
+    #[serde(
+        rename = "high24h",
+        deserialize_with = "barter_integration::de::de_str"
+    )]
+    pub high: f64,
+    #[serde(rename = "low24h", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl From<(ExchangeId, Instrument, OkxIndexTickers)> for MarketIter<IndexTicker> {
+    fn from((exchange_id, instrument, tickers): (ExchangeId, Instrument, OkxIndexTickers)) -> Self {
+        tickers
+            .data
+            .into_iter()
+            .map(|ticker| {
+                Ok(MarketEvent {
+                    exchange_time: ticker.time,
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(exchange_id),
+                    instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
+                    kind: IndexTicker {
+                        index_price: ticker.index_price,
+                        open_24h: ticker.open,
+                        high_24h: ticker.high,
+                        low_24h: ticker.low,
+                        time: ticker.time,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, error::SocketError, model::SubscriptionId,
+    };
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_tickers() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxTickers, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid Spot OkxTickers
+                    input: r#"
+                    {
+                        "arg": {"channel": "tickers", "instId": "BTC-USDT"},
+                        "data": [
+                            {
+                                "instType": "SPOT", "instId": "BTC-USDT", "last": "9999.99",
+                                "lastSz": "0.1", "askPx": "9999.99", "askSz": "11",
+                                "bidPx": "8888.88", "bidSz": "5", "open24h": "9000",
+                                "high24h": "10000", "low24h": "8888.88", "volCcy24h": "2222",
+                                "vol24h": "2222", "sodUtc0": "2222", "sodUtc8": "2222",
+                                "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxTickers {
+                        subscription_id: SubscriptionId::from("tickers|BTC-USDT"),
+                        data: vec![OkxTicker {
+                            last_price: 9999.99,
+                            open: 9000.0,
+                            high: 10000.0,
+                            low: 8888.88,
+                            volume: 2222.0,
+                            volume_currency: 2222.0,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid OkxTickers w/ missing open24h
+                    input: r#"
+                    {
+                        "arg": {"channel": "tickers", "instId": "BTC-USDT"},
+                        "data": [
+                            {
+                                "last": "9999.99", "high24h": "10000", "low24h": "8888.88",
+                                "volCcy24h": "2222", "vol24h": "2222", "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxTickers>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_okx_index_tickers() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<OkxIndexTickers, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid OkxIndexTickers
+                    input: r#"
+                    {
+                        "arg": {"channel": "index-tickers", "instId": "BTC-USDT"},
+                        "data": [
+                            {
+                                "instId": "BTC-USDT", "idxPx": "43350", "high24h": "43649.7",
+                                "low24h": "43261.9", "open24h": "43640.8", "sodUtc0": "43444.1",
+                                "sodUtc8": "43328.7", "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(OkxIndexTickers {
+                        subscription_id: SubscriptionId::from("index-tickers|BTC-USDT"),
+                        data: vec![OkxIndexTicker {
+                            index_price: 43350.0,
+                            open: 43640.8,
+                            high: 43649.7,
+                            low: 43261.9,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1597026383085,
+                            )),
+                        }],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid OkxIndexTickers w/ missing idxPx
+                    input: r#"
+                    {
+                        "arg": {"channel": "index-tickers", "instId": "BTC-USDT"},
+                        "data": [
+                            {
+                                "instId": "BTC-USDT", "high24h": "43649.7", "low24h": "43261.9",
+                                "open24h": "43640.8", "ts": "1597026383085"
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<OkxIndexTickers>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_okx_tickers_volume_normalisation() {
+        struct TestCase {
+            kind: InstrumentKind,
+            expected_volume: f64,
+            expected_quote_volume: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Spot vol24h is denominated in the base currency
+                kind: InstrumentKind::Spot,
+                expected_volume: 10.0,
+                expected_quote_volume: Some(200000.0),
+            },
+            TestCase {
+                // TC1: FuturePerpetual volCcy24h is denominated in the base currency
+                kind: InstrumentKind::FuturePerpetual,
+                expected_volume: 200000.0,
+                expected_quote_volume: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let tickers = OkxTickers {
+                subscription_id: SubscriptionId::from("tickers|BTC-USDT"),
+                data: vec![OkxTicker {
+                    last_price: 110.0,
+                    open: 100.0,
+                    high: 120.0,
+                    low: 90.0,
+                    volume: 10.0,
+                    volume_currency: 200000.0,
+                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1597026383085)),
+                }],
+            };
+
+            let instrument = Instrument::from(("btc", "usdt", test.kind));
+            let MarketIter(events) =
+                MarketIter::<Ticker>::from((ExchangeId::Okx, instrument, tickers));
+            let ticker = events.into_iter().next().unwrap().unwrap().kind;
+
+            assert_eq!(
+                ticker.volume_24h, test.expected_volume,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                ticker.quote_volume_24h, test.expected_quote_volume,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(ticker.price_change_percent_24h, 10.0, "TC{} failed", index);
+        }
+    }
+}
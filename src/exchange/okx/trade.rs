@@ -1,7 +1,8 @@
+use super::subscription::OkxSubArg;
 use crate::{
     event::{MarketEvent, MarketIter},
-    exchange::{ExchangeId, ExchangeSub},
-    subscription::trade::PublicTrade,
+    exchange::ExchangeId,
+    subscription::{number::Number, trade::PublicTrade},
     Identifier,
 };
 use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
@@ -61,13 +62,14 @@ pub struct OkxTrade {
     #[serde(rename = "tradeId")]
     pub id: String,
     #[serde(rename = "px", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Number,
     #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Number,
+    #[serde(deserialize_with = "crate::subscription::trade::de_side_from_taker_side")]
     pub side: Side,
     #[serde(
         rename = "ts",
-        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+        deserialize_with = "crate::de::de_str_u64_epoch_ms_as_datetime_utc"
     )]
     pub time: DateTime<Utc>,
 }
@@ -83,11 +85,16 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
                     received_time: Utc::now(),
                     exchange: Exchange::from(exchange_id),
                     instrument: instrument.clone(),
+                    sequence: None,
+                    tag: None,
                     kind: PublicTrade {
                         id: trade.id,
                         price: trade.price,
                         amount: trade.amount,
                         side: trade.side,
+                        buyer_order_id: None,
+                        seller_order_id: None,
+                        taker_order_kind: None,
                     },
                 })
             })
@@ -96,78 +103,246 @@ impl From<(ExchangeId, Instrument, OkxTrades)> for MarketIter<PublicTrade> {
 }
 
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
-fn de_okx_message_arg_as_subscription_id<'de, D>(
+pub fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
 ) -> Result<SubscriptionId, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    #[derive(Deserialize)]
-    #[serde(rename_all = "camelCase")]
-    struct Arg<'a> {
-        channel: &'a str,
-        inst_id: &'a str,
-    }
-
-    Deserialize::deserialize(deserializer)
-        .map(|arg: Arg<'_>| ExchangeSub::from((arg.channel, arg.inst_id)).id())
+    OkxSubArg::deserialize(deserializer).map(|arg| arg.id())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod de {
         use super::*;
+        use crate::{
+            exchange::okx::Okx,
+            test_util::{parse_frame, Fixtures},
+        };
         use barter_integration::de::datetime_utc_from_epoch_duration;
         use barter_integration::error::SocketError;
+        use serde::de::Error;
         use std::time::Duration;
 
         #[test]
         fn test_okx_message_trades() {
-            let input = r#"
-            {
-                "arg": {
-                    "channel": "trades",
-                    "instId": "BTC-USDT"
+            struct TestCase {
+                fixture: &'static str,
+                expected: Result<OkxTrades, SocketError>,
+            }
+
+            let fixtures = Fixtures::load("okx", "trades");
+
+            let tests = vec![
+                TestCase {
+                    // TC0: trades valid
+                    fixture: "trades",
+                    expected: Ok(OkxTrades {
+                        subscription_id: SubscriptionId::from("trades|BTC-USDT"),
+                        data: vec![OkxTrade {
+                            id: "130639474".to_string(),
+                            price: from_f64(42219.9),
+                            amount: from_f64(0.12060306),
+                            side: Side::Buy,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1630048897897,
+                            )),
+                        }],
+                    }),
                 },
-                "data": [
-                    {
-                        "instId": "BTC-USDT",
-                        "tradeId": "130639474",
-                        "px": "42219.9",
-                        "sz": "0.12060306",
-                        "side": "buy",
-                        "ts": "1630048897897"
+                TestCase {
+                    // TC1: truncated trades frame
+                    fixture: "malformed_truncated",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+                TestCase {
+                    // TC2: trades malformed w/ numeric price & unknown side
+                    fixture: "malformed_wrong_types",
+                    expected: Err(SocketError::Deserialise {
+                        error: serde_json::Error::custom(""),
+                        payload: "".to_owned(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = parse_frame::<Okx, OkxTrades>(fixtures.get(test.fixture));
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (
+                        Err(SocketError::Deserialise { .. }),
+                        Err(SocketError::Deserialise { .. }),
+                    ) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
                     }
-                ]
-            }
-            "#;
-
-            let actual = serde_json::from_str::<OkxTrades>(input);
-            let expected: Result<OkxTrades, SocketError> = Ok(OkxTrades {
-                subscription_id: SubscriptionId::from("trades|BTC-USDT"),
-                data: vec![OkxTrade {
-                    id: "130639474".to_string(),
-                    price: 42219.9,
-                    amount: 0.12060306,
-                    side: Side::Buy,
-                    time: datetime_utc_from_epoch_duration(Duration::from_millis(1630048897897)),
-                }],
-            });
-
-            match (actual, expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC failed")
-                }
-                (Err(_), Err(_)) => {
-                    // Test passed
-                }
-                (actual, expected) => {
-                    // Test failed
-                    panic!("TC failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
                 }
             }
         }
+
+        #[test]
+        fn test_okx_message_trades_all_multiple_trades() {
+            let fixtures = Fixtures::load("okx", "trades");
+
+            let actual =
+                parse_frame::<Okx, OkxTrades>(fixtures.get("trades_all_multiple_trades")).unwrap();
+
+            assert_eq!(
+                actual.subscription_id,
+                SubscriptionId::from("trades-all|BTC-USDT")
+            );
+            assert_eq!(
+                actual
+                    .data
+                    .iter()
+                    .map(|trade| (trade.id.as_str(), trade.price, trade.amount, trade.side))
+                    .collect::<Vec<_>>(),
+                vec![
+                    (
+                        "130639474",
+                        from_f64(42219.9),
+                        from_f64(0.12060306),
+                        Side::Buy
+                    ),
+                    ("130639475", from_f64(42220.1), from_f64(0.5), Side::Buy),
+                    ("130639476", from_f64(42218.0), from_f64(1.25), Side::Sell),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_trades_market_iter_multiple_trades() {
+        use barter_integration::{de::datetime_utc_from_epoch_duration, model::InstrumentKind};
+        use std::time::Duration;
+
+        let time = |ms: u64| datetime_utc_from_epoch_duration(Duration::from_millis(ms));
+        let trade = |id: &str, price: f64, side: Side, ms: u64| OkxTrade {
+            id: id.to_string(),
+            price: from_f64(price),
+            amount: from_f64(1.0),
+            side,
+            time: time(ms),
+        };
+
+        let input = OkxTrades {
+            subscription_id: SubscriptionId::from("trades-all|BTC-USDT"),
+            data: vec![
+                trade("3", 100.0, Side::Buy, 1630048897897),
+                trade("4", 101.0, Side::Sell, 1630048897897),
+                trade("5", 99.0, Side::Buy, 1630048897901),
+            ],
+        };
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+
+        let actual = MarketIter::<PublicTrade>::from((ExchangeId::Okx, instrument.clone(), input))
+            .0
+            .into_iter()
+            .map(|event| {
+                let event = event.unwrap();
+                assert_eq!(event.instrument, instrument);
+                (
+                    event.kind.id,
+                    event.kind.price,
+                    event.kind.side,
+                    event.exchange_time,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // One MarketEvent per trade, preserving the order of the data array
+        let expected = vec![
+            (
+                "3".to_string(),
+                from_f64(100.0),
+                Side::Buy,
+                time(1630048897897),
+            ),
+            (
+                "4".to_string(),
+                from_f64(101.0),
+                Side::Sell,
+                time(1630048897897),
+            ),
+            (
+                "5".to_string(),
+                from_f64(99.0),
+                Side::Buy,
+                time(1630048897901),
+            ),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_okx_trades_fixtures_transform() {
+        use crate::{
+            error::DataError,
+            exchange::okx::Okx,
+            subscription::{trade::PublicTrades, Subscription},
+            test_util::{assert_deserialize_roundtrip, instrument_map, transform_frames, Fixtures},
+            transformer::stateless::StatelessTransformer,
+        };
+        use barter_integration::{error::SocketError, model::InstrumentKind};
+
+        let fixtures = Fixtures::load("okx", "trades");
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let instrument_map =
+            instrument_map(&[Subscription::from((Okx, instrument.clone(), PublicTrades))]);
+
+        let actual = transform_frames::<
+            Okx,
+            PublicTrades,
+            StatelessTransformer<Okx, PublicTrades, OkxTrades>,
+            _,
+        >(
+            instrument_map,
+            [
+                "trades",
+                "malformed_truncated",
+                "malformed_wrong_types",
+                "unknown_symbol",
+            ]
+            .map(|name| fixtures.get(name)),
+        )
+        .await;
+        assert_eq!(actual.len(), 4);
+
+        // Valid frame yields a normalised trade that survives a serde roundtrip
+        let trade = actual[0].as_ref().unwrap();
+        assert_eq!(trade.instrument, instrument);
+        assert_eq!(trade.kind.id, "130639474");
+        assert_deserialize_roundtrip(trade);
+
+        // Malformed frames yield deserialisation errors
+        for index in [1, 2] {
+            assert!(
+                matches!(
+                    &actual[index],
+                    Err(DataError::Socket(SocketError::Deserialise { .. }))
+                ),
+                "frame {index} failed: {:?}",
+                actual[index]
+            );
+        }
+
+        // Trades of an unsubscribed instrument are unidentifiable
+        assert!(matches!(
+            &actual[3],
+            Err(DataError::Socket(SocketError::Unidentifiable(subscription_id)))
+                if subscription_id == &SubscriptionId::from("trades|ETH-USDT")
+        ));
     }
 }
@@ -0,0 +1,268 @@
+use super::{channel::OkxChannel, subscription::OkxSubResponse};
+use crate::{
+    exchange::{Connector, ExchangeSub},
+    subscriber::validator::{
+        validate_acks, AckTracker, SubscriptionOutcome, SubscriptionValidator,
+    },
+    subscription::{Map, SubKind},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WebSocket,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::Duration,
+};
+use tracing::debug;
+
+/// [`Okx`](super::Okx) specific [`SubscriptionValidator`].
+///
+/// ### Notes
+/// - Verifies the "arg" echoed back in each [`OkxSubResponse::Subscribed`] matches an actioned
+///   subscription arg, rather than only counting success responses.
+/// - [`OkxSubResponse::Error`]s do not echo the failed arg, so they are attributed to the
+///   actioned arg whose market (and ideally channel) appears in the error message. Errors that
+///   cannot be attributed reject every arg still unacknowledged at the end of validation.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxSubValidator;
+
+impl OkxSubValidator {
+    /// Determine the [`SubscriptionId`] of every [`OkxSubArg`](super::subscription::OkxSubArg)
+    /// [`Okx`](super::Okx) is expected to acknowledge for the provided instrument map.
+    ///
+    /// Mirrors the args actioned by [`Okx::requests`](super::Okx): liquidation orders share a
+    /// single instrument type arg, and mark prices also subscribe to the funding rate channel.
+    pub fn expected_acks(map: &Map<Instrument>) -> BTreeSet<SubscriptionId> {
+        Self::ack_subscriptions(map).into_keys().collect()
+    }
+
+    /// Map the [`SubscriptionId`] of every expected [`OkxSubArg`](super::subscription::OkxSubArg)
+    /// acknowledgement to the instrument map [`SubscriptionId`]s it acknowledges.
+    pub fn ack_subscriptions(
+        map: &Map<Instrument>,
+    ) -> BTreeMap<SubscriptionId, Vec<SubscriptionId>> {
+        map.0
+            .keys()
+            .flat_map(|subscription_id| {
+                let (channel, market) = subscription_id
+                    .as_ref()
+                    .split_once('|')
+                    .unwrap_or((subscription_id.as_ref(), ""));
+
+                let acks = if channel == OkxChannel::LIQUIDATIONS.as_ref() {
+                    vec![ExchangeSub::from((channel, "SWAP")).id()]
+                } else if channel == OkxChannel::MARK_PRICES.as_ref() {
+                    vec![
+                        subscription_id.clone(),
+                        ExchangeSub::from((OkxChannel::FUNDING_RATES, market)).id(),
+                    ]
+                } else {
+                    vec![subscription_id.clone()]
+                };
+
+                acks.into_iter().map(|ack| (ack, subscription_id.clone()))
+            })
+            .fold(BTreeMap::new(), |mut acks, (ack, subscription_id)| {
+                acks.entry(ack)
+                    .or_insert_with(Vec::new)
+                    .push(subscription_id);
+                acks
+            })
+    }
+
+    /// Attribute an [`OkxSubResponse::Error`] message (eg/ "Wrong URL or channel:trades,instId:BTC-XYZ
+    /// doesn't exist.") to one of the pending ack [`SubscriptionId`]s.
+    ///
+    /// The message is attributed to a pending ack whose market appears in the message, preferring
+    /// acks whose channel also appears. Returns `None` if no pending ack market appears.
+    pub fn attribute_error<'a, Iter>(message: &str, pending: Iter) -> Option<SubscriptionId>
+    where
+        Iter: IntoIterator<Item = &'a SubscriptionId>,
+    {
+        let tokens = message
+            .split(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .filter(|token| !token.is_empty())
+            .collect::<BTreeSet<_>>();
+
+        let candidates = pending
+            .into_iter()
+            .filter_map(|ack| {
+                let (channel, market) = ack.as_ref().split_once('|')?;
+                tokens
+                    .contains(market)
+                    .then(|| (tokens.contains(channel), ack))
+            })
+            .collect::<Vec<_>>();
+
+        candidates
+            .iter()
+            .find(|(channel_matched, _)| *channel_matched)
+            .or_else(|| candidates.first())
+            .map(|(_, ack)| (*ack).clone())
+    }
+}
+
+#[async_trait]
+impl SubscriptionValidator for OkxSubValidator {
+    async fn validate<Exchange, Kind>(
+        instrument_map: Map<Instrument>,
+        websocket: &mut WebSocket,
+        timeout: Duration,
+    ) -> Result<(Map<Instrument>, SubscriptionOutcome), SocketError>
+    where
+        Exchange: Connector + Send,
+        Kind: SubKind + Send,
+    {
+        let tracker = AckTracker::new(Self::ack_subscriptions(&instrument_map));
+
+        let outcome = validate_acks::<Exchange, OkxSubResponse, _, _>(
+            tracker,
+            websocket,
+            timeout,
+            |response, tracker| match response {
+                // Subscription success
+                OkxSubResponse::Subscribed { arg } => {
+                    if !tracker.confirm(&arg.id()) {
+                        debug!(
+                            exchange = %Exchange::ID,
+                            payload = ?arg,
+                            "received Ok subscription response for unexpected arg",
+                        );
+                    }
+                }
+
+                // Subscription failure
+                OkxSubResponse::Error { code, message } => {
+                    let reason = format!("{code}: {message}");
+                    match Self::attribute_error(&message, tracker.pending()) {
+                        Some(ack) => {
+                            tracker.reject(&ack, &reason);
+                        }
+                        None => tracker.reject_unattributed(&reason),
+                    }
+                }
+            },
+        )
+        .await?;
+
+        Ok((outcome.retain_confirmed(instrument_map), outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_okx_sub_validator_expected_acks() {
+        struct TestCase {
+            input: Vec<&'static str>,
+            expected: Vec<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: one ack per instrument keyed subscription
+                input: vec!["trades|BTC-USDT", "index-tickers|BTC-USDT"],
+                expected: vec!["index-tickers|BTC-USDT", "trades|BTC-USDT"],
+            },
+            TestCase {
+                // TC1: option summaries are acknowledged by instrument family
+                input: vec!["opt-summary|BTC-USD", "opt-summary|ETH-USD"],
+                expected: vec!["opt-summary|BTC-USD", "opt-summary|ETH-USD"],
+            },
+            TestCase {
+                // TC2: liquidation subscriptions share a single instrument type ack
+                input: vec![
+                    "liquidation-orders|BTC-USDT-SWAP",
+                    "liquidation-orders|ETH-USDT-SWAP",
+                ],
+                expected: vec!["liquidation-orders|SWAP"],
+            },
+            TestCase {
+                // TC3: mark price subscriptions also expect a funding rate ack
+                input: vec!["mark-price|BTC-USDT-SWAP"],
+                expected: vec!["funding-rate|BTC-USDT-SWAP", "mark-price|BTC-USDT-SWAP"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let map = test
+                .input
+                .into_iter()
+                .map(|id| {
+                    (
+                        SubscriptionId::from(id),
+                        Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual)),
+                    )
+                })
+                .collect::<Map<Instrument>>();
+
+            let actual = OkxSubValidator::expected_acks(&map);
+            let expected = test
+                .expected
+                .into_iter()
+                .map(SubscriptionId::from)
+                .collect::<BTreeSet<_>>();
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_sub_validator_attribute_error() {
+        struct TestCase {
+            message: &'static str,
+            expected: Option<&'static str>,
+        }
+
+        let pending = [
+            "books|BTC-USDT",
+            "trades|BTC-USDT",
+            "trades|ETH-USDT",
+            "trades|BTC-USDT-SWAP",
+        ]
+        .into_iter()
+        .map(SubscriptionId::from)
+        .collect::<Vec<_>>();
+
+        let tests = vec![
+            TestCase {
+                // TC0: message contains channel & market of a pending ack
+                message: "Wrong URL or channel:trades,instId:BTC-USDT doesn't exist.",
+                expected: Some("trades|BTC-USDT"),
+            },
+            TestCase {
+                // TC1: message contains only the market of a pending ack
+                message: "instId:ETH-USDT doesn't exist.",
+                expected: Some("trades|ETH-USDT"),
+            },
+            TestCase {
+                // TC2: market token must match exactly, not as a prefix
+                message: "channel:trades,instId:BTC-USDT-SWAP doesn't exist.",
+                expected: Some("trades|BTC-USDT-SWAP"),
+            },
+            TestCase {
+                // TC3: message does not identify any pending ack
+                message: "Invalid request: {\"op\": \"subscribe\"}",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxSubValidator::attribute_error(test.message, &pending);
+            assert_eq!(
+                actual,
+                test.expected.map(SubscriptionId::from),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
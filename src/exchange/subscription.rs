@@ -47,11 +47,13 @@ where
     Market: AsRef<str>,
 {
     fn id(&self) -> SubscriptionId {
-        SubscriptionId::from(format!(
-            "{}|{}",
-            self.channel.as_ref(),
-            self.market.as_ref()
-        ))
+        // Allocate the exact capacity upfront since this is called for every received message
+        let (channel, market) = (self.channel.as_ref(), self.market.as_ref());
+        let mut id = String::with_capacity(channel.len() + 1 + market.len());
+        id.push_str(channel);
+        id.push('|');
+        id.push_str(market);
+        SubscriptionId(id)
     }
 }
 
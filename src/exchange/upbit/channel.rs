@@ -0,0 +1,43 @@
+use super::UpbitSpot;
+use crate::{
+    subscription::{ticker::Tickers, trade::PublicTrades, Subscription},
+    Identifier,
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`UpbitSpot`](super::UpbitSpot) channel to be subscribed to.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct UpbitChannel(pub &'static str);
+
+impl UpbitChannel {
+    /// [`UpbitSpot`](super::UpbitSpot) real-time trades channel name.
+    ///
+    /// See docs: <https://global-docs.upbit.com/reference/websocket-trade>
+    pub const TRADES: Self = Self("trade");
+
+    /// [`UpbitSpot`](super::UpbitSpot) real-time ticker channel name.
+    ///
+    /// See docs: <https://global-docs.upbit.com/reference/websocket-ticker>
+    pub const TICKERS: Self = Self("ticker");
+}
+
+impl Identifier<UpbitChannel> for Subscription<UpbitSpot, PublicTrades> {
+    fn id(&self) -> UpbitChannel {
+        UpbitChannel::TRADES
+    }
+}
+
+impl Identifier<UpbitChannel> for Subscription<UpbitSpot, Tickers> {
+    fn id(&self) -> UpbitChannel {
+        UpbitChannel::TICKERS
+    }
+}
+
+impl AsRef<str> for UpbitChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,81 @@
+use super::UpbitSpot;
+use crate::{subscription::Subscription, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`UpbitSpot`](super::UpbitSpot) market that can be subscribed to.
+///
+/// Upbit market codes are formatted "{QUOTE}-{BASE}" (eg/ "KRW-BTC"), the reverse of most
+/// exchanges.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitMarket(pub String);
+
+impl<Kind> Identifier<UpbitMarket> for Subscription<UpbitSpot, Kind> {
+    fn id(&self) -> UpbitMarket {
+        UpbitMarket(format!("{}-{}", self.instrument.quote, self.instrument.base).to_uppercase())
+    }
+}
+
+impl AsRef<str> for UpbitMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::trade::PublicTrades;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_upbit_market() {
+        struct TestCase {
+            input: Subscription<UpbitSpot, PublicTrades>,
+            expected: UpbitMarket,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: KRW market is formatted quote-base
+                input: Subscription::from((
+                    UpbitSpot,
+                    "btc",
+                    "krw",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )),
+                expected: UpbitMarket("KRW-BTC".to_string()),
+            },
+            TestCase {
+                // TC1: BTC market is formatted quote-base
+                input: Subscription::from((
+                    UpbitSpot,
+                    "eth",
+                    "btc",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )),
+                expected: UpbitMarket("BTC-ETH".to_string()),
+            },
+            TestCase {
+                // TC2: USDT market w/ alphanumeric base is formatted quote-base
+                input: Subscription::from((
+                    UpbitSpot,
+                    "1inch",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )),
+                expected: UpbitMarket("USDT-1INCH".to_string()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual: UpbitMarket = test.input.id();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use self::{
+    channel::UpbitChannel, market::UpbitMarket, subscription::UpbitSubResponse,
+    ticker::UpbitTicker, trade::UpbitTrade,
+};
+use crate::{
+    decoder::PlainText,
+    exchange::{AckMode, Connector, ExchangeId, ExchangeSub, PingInterval, StreamSelector},
+    subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+    subscription::{ticker::Tickers, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+    ExchangeWsStream,
+};
+use barter_integration::{error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{DeExchange, SerExchange};
+use serde_json::json;
+use std::time::Duration;
+use url::Url;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// [`Subscription`](crate::subscription::Subscription) error response type and response
+/// [`Validator`](barter_integration::Validator) for [`UpbitSpot`].
+pub mod subscription;
+
+/// Ticker types for [`UpbitSpot`].
+pub mod ticker;
+
+/// Public trade types for [`UpbitSpot`].
+pub mod trade;
+
+/// [`UpbitSpot`] WebSocket server base url.
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+pub const BASE_URL_UPBIT_SPOT: &str = "wss://api.upbit.com/websocket/v1";
+
+/// [`UpbitSpot`] subscription "ticket" identifying the requesting client.
+pub const TICKET_UPBIT_SPOT: &str = "barter-data";
+
+/// [`UpbitSpot`] server idles out connections after 120 seconds without a message, so a
+/// [`PingInterval`] is required to keep quiet markets connected.
+pub const PING_INTERVAL_UPBIT_SPOT: Duration = Duration::from_secs(60);
+
+/// [`UpbitSpot`] exchange.
+///
+/// ### Notes
+/// - Every [`Subscription`](crate::subscription::Subscription) of a connection must be sent in a
+///   single request, since each request replaces any previous subscriptions (see
+///   [`Connector::requests`]).
+/// - Upbit does not acknowledge successful subscriptions, so subscriptions are assumed to be
+///   successful once the request is sent.
+/// - Market data is sent in binary WebSocket frames containing uncompressed UTF-8 JSON, hence the
+///   [`PlainText`] [`Connector::Decoder`].
+///
+/// See docs: <https://global-docs.upbit.com/reference/websocket-guide>
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+)]
+pub struct UpbitSpot;
+
+impl Connector for UpbitSpot {
+    const ID: ExchangeId = ExchangeId::UpbitSpot;
+    const ACK_MODE: AckMode = AckMode::None;
+    type Channel = UpbitChannel;
+    type Market = UpbitMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = UpbitSubResponse;
+    type Decoder = PlainText;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_UPBIT_SPOT).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_UPBIT_SPOT),
+            ping: || WsMessage::Ping(vec![]),
+        })
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Group the markets of each channel, preserving the order they were provided in
+        let mut channels = Vec::<(UpbitChannel, Vec<String>)>::new();
+        for ExchangeSub { channel, market } in exchange_subs {
+            match channels
+                .iter_mut()
+                .find(|(existing, _)| *existing == channel)
+            {
+                Some((_, codes)) => codes.push(market.0),
+                None => channels.push((channel, vec![market.0])),
+            }
+        }
+
+        let payload = std::iter::once(json!({ "ticket": TICKET_UPBIT_SPOT }))
+            .chain(
+                channels
+                    .into_iter()
+                    .map(|(channel, codes)| json!({ "type": channel.as_ref(), "codes": codes })),
+            )
+            .collect::<Vec<_>>();
+
+        vec![WsMessage::Text(json!(payload).to_string())]
+    }
+
+    fn live_requests(
+        _: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+    ) -> Result<Vec<WsMessage>, SocketError> {
+        // Each request replaces every previous subscription of the connection
+        Err(SocketError::Unsupported {
+            entity: Self::ID.as_str(),
+            item: String::from("subscribing on a live connection"),
+        })
+    }
+}
+
+impl StreamSelector<PublicTrades> for UpbitSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, PublicTrades, UpbitTrade>>;
+}
+
+impl StreamSelector<Tickers> for UpbitSpot {
+    type Stream = ExchangeWsStream<StatelessTransformer<Self, Tickers, UpbitTicker>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upbit_requests() {
+        struct TestCase {
+            input: Vec<ExchangeSub<UpbitChannel, UpbitMarket>>,
+            expected: Vec<WsMessage>,
+        }
+
+        let sub = |channel, market: &str| ExchangeSub::from((channel, UpbitMarket(market.into())));
+
+        let tests = vec![
+            TestCase {
+                // TC0: every market of a channel is merged into a single request
+                input: vec![
+                    sub(UpbitChannel::TRADES, "KRW-BTC"),
+                    sub(UpbitChannel::TRADES, "KRW-ETH"),
+                ],
+                expected: vec![WsMessage::Text(
+                    r#"[{"ticket":"barter-data"},{"codes":["KRW-BTC","KRW-ETH"],"type":"trade"}]"#
+                        .to_string(),
+                )],
+            },
+            TestCase {
+                // TC1: every channel is merged into a single request
+                input: vec![
+                    sub(UpbitChannel::TICKERS, "KRW-BTC"),
+                    sub(UpbitChannel::TRADES, "KRW-ETH"),
+                    sub(UpbitChannel::TICKERS, "BTC-ETH"),
+                ],
+                expected: vec![WsMessage::Text(
+                    r#"[{"ticket":"barter-data"},{"codes":["KRW-BTC","BTC-ETH"],"type":"ticker"},{"codes":["KRW-ETH"],"type":"trade"}]"#
+                        .to_string(),
+                )],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = UpbitSpot::requests(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
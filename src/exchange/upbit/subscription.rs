@@ -0,0 +1,109 @@
+use barter_integration::{error::SocketError, Validator};
+use serde::{Deserialize, Serialize};
+
+/// [`UpbitSpot`](super::UpbitSpot) subscription error response message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://global-docs.upbit.com/reference/websocket-error>
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "error": {
+///         "name": "INVALID_PARAM",
+///         "message": "Invalid parameter."
+///     }
+/// }
+/// ```
+///
+/// ## Notes:
+/// - Upbit does not acknowledge successful subscriptions, so no [`UpbitSubResponse`] is
+///   expected (see [`UpbitSpot::expected_responses`](super::UpbitSpot)).
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitSubResponse {
+    pub error: UpbitError,
+}
+
+/// [`UpbitSpot`](super::UpbitSpot) error detail contained within an [`UpbitSubResponse`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct UpbitError {
+    pub name: String,
+    pub message: String,
+}
+
+impl Validator for UpbitSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        Err(SocketError::Subscribe(format!(
+            "received failure subscription response name: {} with message: {}",
+            self.error.name, self.error.message,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_upbit_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<UpbitSubResponse, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input response is failed subscription
+                    input: r#"{"error":{"name":"INVALID_PARAM","message":"Invalid parameter."}}"#,
+                    expected: Ok(UpbitSubResponse {
+                        error: UpbitError {
+                            name: "INVALID_PARAM".to_string(),
+                            message: "Invalid parameter.".to_string(),
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: input trade is not a subscription response
+                    input: r#"{"type":"trade","code":"KRW-BTC","trade_price":31287000.0}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<UpbitSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_upbit_sub_response() {
+        let response = UpbitSubResponse {
+            error: UpbitError {
+                name: "INVALID_PARAM".to_string(),
+                message: "Invalid parameter.".to_string(),
+            },
+        };
+
+        assert!(response.validate().is_err());
+    }
+}
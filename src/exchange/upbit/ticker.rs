@@ -0,0 +1,231 @@
+use super::channel::UpbitChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::ticker::Ticker,
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`UpbitSpot`](super::UpbitSpot) real-time ticker message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://global-docs.upbit.com/reference/websocket-ticker>
+/// ```json
+/// {
+///   "type": "ticker",
+///   "code": "KRW-BTC",
+///   "opening_price": 31883000,
+///   "high_price": 32310000,
+///   "low_price": 31855000,
+///   "trade_price": 32287000,
+///   "prev_closing_price": 31883000.0,
+///   "acc_trade_price": 78039261076.51241,
+///   "change": "RISE",
+///   "change_price": 404000.0,
+///   "signed_change_price": 404000.0,
+///   "change_rate": 0.0126713295,
+///   "signed_change_rate": 0.0126713295,
+///   "ask_bid": "ASK",
+///   "trade_volume": 0.03103806,
+///   "acc_trade_volume": 2429.58834336,
+///   "trade_date": "20230221",
+///   "trade_time": "074102",
+///   "trade_timestamp": 1676965262139,
+///   "acc_ask_volume": 1146.25573608,
+///   "acc_bid_volume": 1283.33260728,
+///   "market_state": "ACTIVE",
+///   "is_trading_suspended": false,
+///   "market_warning": "NONE",
+///   "timestamp": 1676965262177,
+///   "acc_trade_price_24h": 228827082483.70729,
+///   "acc_trade_volume_24h": 7158.8028356,
+///   "stream_type": "REALTIME"
+/// }
+/// ```
+///
+/// ## Notes:
+/// - "opening_price", "high_price" & "low_price" are those of the current trading day (starting
+///   00:00 KST), rather than of a rolling 24h window.
+/// - "signed_change_rate" is relative to the previous trading day close.
+/// - "acc_trade_volume_24h" is denominated in the base currency, and "acc_trade_price_24h" in the
+///   quote currency.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitTicker {
+    #[serde(rename = "code", deserialize_with = "de_ticker_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "trade_price")]
+    pub last_price: f64,
+    #[serde(rename = "opening_price")]
+    pub open: f64,
+    #[serde(rename = "high_price")]
+    pub high: f64,
+    #[serde(rename = "low_price")]
+    pub low: f64,
+    #[serde(rename = "acc_trade_volume_24h")]
+    pub volume: f64,
+    #[serde(rename = "acc_trade_price_24h")]
+    pub quote_volume: f64,
+    #[serde(rename = "signed_change_rate")]
+    pub change_rate: f64,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for UpbitTicker {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, UpbitTicker)> for MarketIter<Ticker> {
+    fn from((exchange_id, instrument, ticker): (ExchangeId, Instrument, UpbitTicker)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: ticker.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: Ticker {
+                last_price: ticker.last_price,
+                open_24h: ticker.open,
+                high_24h: ticker.high,
+                low_24h: ticker.low,
+                volume_24h: ticker.volume,
+                quote_volume_24h: Some(ticker.quote_volume),
+                price_change_percent_24h: ticker.change_rate * 100.0,
+                time: ticker.time,
+            },
+        })])
+    }
+}
+
+/// Deserialize an [`UpbitTicker`] "code" (eg/ "KRW-BTC") as the associated [`SubscriptionId`]
+/// (eg/ "ticker|KRW-BTC").
+pub fn de_ticker_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((UpbitChannel::TICKERS, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration, error::SocketError, model::InstrumentKind,
+    };
+    use std::time::Duration;
+
+    fn ticker() -> UpbitTicker {
+        UpbitTicker {
+            subscription_id: SubscriptionId::from("ticker|KRW-BTC"),
+            last_price: 32287000.0,
+            open: 31883000.0,
+            high: 32310000.0,
+            low: 31855000.0,
+            volume: 7158.8028356,
+            quote_volume: 228827082483.70729,
+            change_rate: 0.0126713295,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(1676965262177)),
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_upbit_ticker() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<UpbitTicker, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input is a valid ticker
+                    input: r#"
+                    {
+                        "type":"ticker","code":"KRW-BTC","opening_price":31883000,
+                        "high_price":32310000,"low_price":31855000,"trade_price":32287000,
+                        "prev_closing_price":31883000.0,"acc_trade_price":78039261076.51241,
+                        "change":"RISE","change_price":404000.0,"signed_change_price":404000.0,
+                        "change_rate":0.0126713295,"signed_change_rate":0.0126713295,
+                        "ask_bid":"ASK","trade_volume":0.03103806,"acc_trade_volume":2429.58834336,
+                        "trade_date":"20230221","trade_time":"074102",
+                        "trade_timestamp":1676965262139,"acc_ask_volume":1146.25573608,
+                        "acc_bid_volume":1283.33260728,"market_state":"ACTIVE",
+                        "is_trading_suspended":false,"market_warning":"NONE",
+                        "timestamp":1676965262177,"acc_trade_price_24h":228827082483.70729,
+                        "acc_trade_volume_24h":7158.8028356,"stream_type":"REALTIME"
+                    }
+                    "#,
+                    expected: Ok(ticker()),
+                },
+                TestCase {
+                    // TC1: input is a trade, rather than a ticker
+                    input: r#"
+                    {
+                        "type":"trade","code":"KRW-BTC","timestamp":1676965262177,
+                        "trade_timestamp":1676965262139,"trade_price":31287000.0,
+                        "trade_volume":0.03993413,"ask_bid":"BID",
+                        "sequential_id":1676965262139000,"stream_type":"REALTIME"
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<UpbitTicker>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_upbit_ticker_into_market_event() {
+        let instrument = Instrument::from(("btc", "krw", InstrumentKind::Spot));
+
+        let MarketIter(events) =
+            MarketIter::<Ticker>::from((ExchangeId::UpbitSpot, instrument.clone(), ticker()));
+        let event = events.into_iter().next().unwrap().unwrap();
+
+        assert_eq!(event.instrument, instrument);
+        assert_eq!(event.exchange_time, ticker().time);
+        assert_eq!(
+            event.kind,
+            Ticker {
+                last_price: 32287000.0,
+                open_24h: 31883000.0,
+                high_24h: 32310000.0,
+                low_24h: 31855000.0,
+                volume_24h: 7158.8028356,
+                quote_volume_24h: Some(228827082483.70729),
+                price_change_percent_24h: 0.0126713295 * 100.0,
+                time: ticker().time,
+            }
+        );
+    }
+}
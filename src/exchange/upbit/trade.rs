@@ -0,0 +1,192 @@
+use super::channel::UpbitChannel;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeId, ExchangeSub},
+    subscription::{number::Number, trade::PublicTrade},
+    Identifier,
+};
+use barter_integration::model::{Exchange, Instrument, Side, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// [`UpbitSpot`](super::UpbitSpot) real-time trade message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://global-docs.upbit.com/reference/websocket-trade>
+/// ```json
+/// {
+///   "type": "trade",
+///   "code": "KRW-BTC",
+///   "timestamp": 1676965262177,
+///   "trade_date": "2023-02-21",
+///   "trade_time": "07:41:02",
+///   "trade_timestamp": 1676965262139,
+///   "trade_price": 31287000.0,
+///   "trade_volume": 0.03993413,
+///   "ask_bid": "BID",
+///   "prev_closing_price": 31009000.0,
+///   "change": "RISE",
+///   "change_price": 278000.0,
+///   "sequential_id": 1676965262139000,
+///   "stream_type": "REALTIME"
+/// }
+/// ```
+///
+/// ## Notes:
+/// - "ask_bid" is the taker side of the trade, where "ASK" is a sell and "BID" is a buy.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct UpbitTrade {
+    #[serde(rename = "code", deserialize_with = "de_trade_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    #[serde(rename = "sequential_id")]
+    pub id: u64,
+    #[serde(
+        rename = "trade_timestamp",
+        deserialize_with = "crate::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "trade_price")]
+    pub price: Number,
+    #[serde(rename = "trade_volume")]
+    pub amount: Number,
+    #[serde(
+        rename = "ask_bid",
+        deserialize_with = "crate::subscription::trade::de_side_from_taker_side"
+    )]
+    pub side: Side,
+}
+
+impl Identifier<Option<SubscriptionId>> for UpbitTrade {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+impl From<(ExchangeId, Instrument, UpbitTrade)> for MarketIter<PublicTrade> {
+    fn from((exchange_id, instrument, trade): (ExchangeId, Instrument, UpbitTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            exchange_time: trade.time,
+            received_time: Utc::now(),
+            exchange: Exchange::from(exchange_id),
+            instrument,
+            sequence: None,
+            tag: None,
+            kind: PublicTrade {
+                id: trade.id.to_string(),
+                price: trade.price,
+                amount: trade.amount,
+                side: trade.side,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        })])
+    }
+}
+
+/// Deserialize an [`UpbitTrade`] "code" (eg/ "KRW-BTC") as the associated [`SubscriptionId`]
+/// (eg/ "trade|KRW-BTC").
+pub fn de_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|market| ExchangeSub::from((UpbitChannel::TRADES, market)).id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::from_f64;
+
+    mod de {
+        use super::*;
+        use barter_integration::{de::datetime_utc_from_epoch_duration, error::SocketError};
+        use std::time::Duration;
+
+        #[test]
+        fn test_upbit_trade() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<UpbitTrade, SocketError>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: input is a valid buy trade
+                    input: r#"
+                    {
+                        "type":"trade","code":"KRW-BTC","timestamp":1676965262177,
+                        "trade_date":"2023-02-21","trade_time":"07:41:02",
+                        "trade_timestamp":1676965262139,"trade_price":31287000.0,
+                        "trade_volume":0.03993413,"ask_bid":"BID",
+                        "prev_closing_price":31009000.0,"change":"RISE","change_price":278000.0,
+                        "sequential_id":1676965262139000,"stream_type":"REALTIME"
+                    }
+                    "#,
+                    expected: Ok(UpbitTrade {
+                        subscription_id: SubscriptionId::from("trade|KRW-BTC"),
+                        id: 1676965262139000,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1676965262139,
+                        )),
+                        price: from_f64(31287000.0),
+                        amount: from_f64(0.03993413),
+                        side: Side::Buy,
+                    }),
+                },
+                TestCase {
+                    // TC1: input is a valid sell trade
+                    input: r#"
+                    {
+                        "type":"trade","code":"KRW-ETH","timestamp":1676965262177,
+                        "trade_timestamp":1676965262139,"trade_price":2250000.0,
+                        "trade_volume":1.5,"ask_bid":"ASK",
+                        "sequential_id":1676965262139001,"stream_type":"REALTIME"
+                    }
+                    "#,
+                    expected: Ok(UpbitTrade {
+                        subscription_id: SubscriptionId::from("trade|KRW-ETH"),
+                        id: 1676965262139001,
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1676965262139,
+                        )),
+                        price: from_f64(2250000.0),
+                        amount: from_f64(1.5),
+                        side: Side::Sell,
+                    }),
+                },
+                TestCase {
+                    // TC2: input is an invalid trade w/ unknown "ask_bid"
+                    input: r#"
+                    {
+                        "type":"trade","code":"KRW-BTC","trade_timestamp":1676965262139,
+                        "trade_price":31287000.0,"trade_volume":0.03993413,"ask_bid":"BOTH",
+                        "sequential_id":1676965262139000
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "",
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<UpbitTrade>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                    }
+                }
+            }
+        }
+    }
+}
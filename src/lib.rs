@@ -72,7 +72,7 @@
 //!     // Join all exchange PublicTrades streams into a single tokio_stream::StreamMap
 //!     // Notes:
 //!     //  - Use `streams.select(ExchangeId)` to interact with the individual exchange streams!
-//!     //  - Use `streams.join()` to join all exchange streams into a single mpsc::UnboundedReceiver!
+//!     //  - Use `streams.join()` to join all exchange streams into a single EventReceiver!
 //!     let mut joined_stream = streams.join_map().await;
 //!
 //!     while let Some((exchange, trade)) = joined_stream.next().await {
@@ -82,22 +82,48 @@
 //! ```
 
 use crate::{
+    decoder::{DecodingParser, PlainText},
     error::DataError,
     event::MarketEvent,
-    exchange::{Connector, ExchangeId, PingInterval},
-    subscriber::Subscriber,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, PingInterval},
+    streams::{
+        health::{Heartbeat, HeartbeatStream},
+        metrics::{MetricsHook, StreamMetrics},
+        raw::RawCapture,
+    },
+    subscriber::{
+        connection::ConnectionOptions,
+        validator::{SubscriptionAcks, SubscriptionOutcome},
+        Subscriber,
+    },
     subscription::{SubKind, Subscription},
     transformer::ExchangeTransformer,
 };
 use async_trait::async_trait;
 use barter_integration::{
-    protocol::websocket::{WebSocketParser, WsMessage, WsSink, WsStream},
+    error::SocketError,
+    model::SubscriptionId,
+    protocol::websocket::{WsMessage, WsSink, WsStream},
     ExchangeStream,
 };
 use futures::{SinkExt, Stream, StreamExt};
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
 use tokio::sync::mpsc;
 use tracing::{debug, error};
 
+/// [`PayloadDecoder`](decoder::PayloadDecoder) implementations (eg/ gzip) and the
+/// Epoch timestamp conversions & `serde` deserializers shared by exchange message types (eg/
+/// [`de_u64_epoch_ms_as_datetime_utc`](de::de_u64_epoch_ms_as_datetime_utc)).
+pub mod de;
+
+/// [`DecodingParser`](decoder::DecodingParser) used to decode binary WebSocket payloads before
+/// they are deserialised.
+pub mod decoder;
+
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
 
@@ -107,6 +133,17 @@ pub mod event;
 /// [`Connector`] implementations for each exchange.
 pub mod exchange;
 
+/// [`MarketEventSink`](sinks::MarketEventSink) implementations that write normalised
+/// [`MarketEvent`](event::MarketEvent)s to rotating newline-delimited JSON & CSV files.
+///
+/// Requires the "sinks" feature.
+///
+/// There is no Parquet sink, since the arrow & parquet crates would add a heavy dependency tree
+/// to the feature. The stable CSV schema can be converted to Parquet downstream, or a Parquet
+/// sink can be added by implementing [`MarketEventSink`](sinks::MarketEventSink).
+#[cfg(feature = "sinks")]
+pub mod sinks;
+
 /// High-level API types used for building [`MarketStream`]s from collections
 /// of Barter [`Subscription`]s.
 pub mod streams;
@@ -122,6 +159,14 @@ pub mod subscriber;
 /// Barter output type the exchange will be transformed into.
 pub mod subscription;
 
+/// Golden test harness shared by every exchange: fixture loading from
+/// `tests/fixtures/<exchange>/<kind>/*.json`, and helpers that drive raw frames through an
+/// [`ExchangeTransformer`].
+///
+/// Available to downstream integrators via the "test-util" feature.
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
 /// Generic [`ExchangeTransformer`] implementations used by [`MarketStream`]s to translate exchange
 /// specific types to normalised Barter types.
 ///
@@ -135,8 +180,30 @@ pub mod subscription;
 pub mod transformer;
 
 /// Convenient type alias for an [`ExchangeStream`] utilising a tungstenite
-/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
-pub type ExchangeWsStream<Transformer> = ExchangeStream<WebSocketParser, WsStream, Transformer>;
+/// [`WebSocket`](barter_integration::protocol::websocket::WebSocket), with a [`HeartbeatStream`]
+/// tracking the last message received.
+///
+/// Binary payloads are decoded with the `Decoder`, which must be the
+/// [`Connector::Decoder`] of the exchange (defaults to [`PlainText`]).
+pub type ExchangeWsStream<Transformer, Decoder = PlainText> =
+    ExchangeStream<DecodingParser<Decoder>, WsConnection, Transformer>;
+
+/// [`HeartbeatStream`] wrapped [`WsStream`] of an [`ExchangeWsStream`], alongside the
+/// [`mpsc::UnboundedSender`] used to send [`WsMessage`]s (eg/ unsubscribe requests, close frames)
+/// to the exchange server via the associated [`WsSink`].
+#[derive(Debug)]
+pub struct WsConnection {
+    pub stream: HeartbeatStream<WsStream>,
+    pub ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+}
+
+impl Stream for WsConnection {
+    type Item = <HeartbeatStream<WsStream> as Stream>::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.stream).poll_next(cx)
+    }
+}
 
 /// Defines a generic identification type for the implementor.
 pub trait Identifier<T> {
@@ -152,25 +219,108 @@ where
     Exchange: Connector,
     Kind: SubKind,
 {
-    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    /// Connect according to the [`ConnectionOptions`] & subscribe to the provided
+    /// [`Subscription`]s, waiting for the exchange to acknowledge them according to the
+    /// [`SubscriptionAcks`] configuration.
+    ///
+    /// Returns the [`SubscriptionOutcome`] of every actioned [`Subscription`] alongside
+    /// [`Self`], or a [`DataError::SubscriptionRejected`] if the outcome does not satisfy the
+    /// configured [`AckPolicy`](subscriber::validator::AckPolicy).
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        acks: SubscriptionAcks,
+        options: &ConnectionOptions,
+    ) -> Result<(Self, SubscriptionOutcome), DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// [`Heartbeat`] updated each time a message is received from the exchange server, including
+    /// protocol-level pings & pongs that are never yielded as [`MarketEvent`]s.
+    ///
+    /// Defaults to `None`, meaning liveness is inferred from the items yielded by [`Self`].
+    fn heartbeat(&self) -> Option<Heartbeat> {
+        None
+    }
+
+    /// Install [`StreamMetrics`] that are invoked for every normalised event of this
+    /// [`MarketStream`].
+    ///
+    /// Defaults to a no-op for [`MarketStream`]s that do not support metrics.
+    fn set_metrics(&mut self, _: Arc<dyn StreamMetrics>) {}
+
+    /// Install a [`RawCapture`] that records the raw text of every message received from the
+    /// exchange server.
+    ///
+    /// Defaults to a no-op for [`MarketStream`]s that do not support raw payload capture.
+    fn set_raw_capture(&mut self, _: RawCapture) {}
+
+    /// Unsubscribe from the provided exchange [`SubscriptionId`]s by sending the `requests`
+    /// (see [`Connector::unsubscribe_requests`]) to the exchange server, and removing them from
+    /// the transformer so they are no longer identified.
+    ///
+    /// Defaults to an unsupported error for [`MarketStream`]s that cannot unsubscribe.
+    fn unsubscribe(
+        &mut self,
+        _subscription_ids: &[SubscriptionId],
+        _requests: Vec<WsMessage>,
+    ) -> Result<(), DataError> {
+        Err(DataError::Socket(SocketError::Unsupported {
+            entity: Exchange::ID.as_str(),
+            item: String::from("unsubscribe"),
+        }))
+    }
+
+    /// Subscribe to the provided [`Subscription`]s on this live connection by sending the
+    /// exchange specific payloads (see [`Connector::live_requests`]) to the exchange server, and
+    /// adding them to the transformer so their messages are identified.
+    ///
+    /// Defaults to an unsupported error for [`MarketStream`]s that cannot subscribe once
+    /// initialised.
+    fn subscribe(
+        &mut self,
+        _subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Err(DataError::Socket(SocketError::Unsupported {
+            entity: Exchange::ID.as_str(),
+            item: String::from("subscribe"),
+        }))
+    }
+
+    /// Gracefully close the connection with the exchange server by sending a close frame.
+    ///
+    /// Defaults to a no-op for [`MarketStream`]s without a closable connection.
+    fn close(&mut self) {}
 }
 
 #[async_trait]
-impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind> for ExchangeWsStream<Transformer>
+impl<Exchange, Kind, Transformer> MarketStream<Exchange, Kind>
+    for ExchangeWsStream<Transformer, Exchange::Decoder>
 where
     Exchange: Connector + Send + Sync,
     Kind: SubKind + Send + Sync,
     Transformer: ExchangeTransformer<Exchange, Kind> + Send,
     Kind::Event: Send,
 {
-    async fn init(subscriptions: &[Subscription<Exchange, Kind>]) -> Result<Self, DataError>
+    async fn init(
+        subscriptions: &[Subscription<Exchange, Kind>],
+        acks: SubscriptionAcks,
+        options: &ConnectionOptions,
+    ) -> Result<(Self, SubscriptionOutcome), DataError>
     where
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
         // Connect & subscribe
-        let (websocket, map) = Exchange::Subscriber::subscribe(subscriptions).await?;
+        let (websocket, map, outcome) =
+            Exchange::Subscriber::subscribe(subscriptions, acks.timeout::<Exchange>(), options)
+                .await?;
+
+        // Determine if the SubscriptionOutcome is acceptable
+        if !outcome.is_acceptable(acks.policy) {
+            return Err(DataError::SubscriptionRejected(outcome));
+        }
 
         // Split WebSocket into WsStream & WsSink components
         let (ws_sink, ws_stream) = websocket.split();
@@ -193,9 +343,80 @@ where
         }
 
         // Construct Transformer associated with this Exchange and SubKind
-        let transformer = Transformer::new(ws_sink_tx, map).await?;
+        let transformer = Transformer::new(ws_sink_tx.clone(), map).await?;
+
+        Ok((
+            ExchangeWsStream::new(
+                WsConnection {
+                    stream: HeartbeatStream::new(ws_stream),
+                    ws_sink_tx,
+                },
+                transformer,
+            ),
+            outcome,
+        ))
+    }
+
+    fn heartbeat(&self) -> Option<Heartbeat> {
+        Some(self.stream.stream.heartbeat.clone())
+    }
+
+    fn set_metrics(&mut self, metrics: Arc<dyn StreamMetrics>) {
+        self.transformer.set_metrics(MetricsHook::new(
+            Exchange::ID,
+            metrics,
+            self.stream.stream.payload.clone(),
+        ));
+    }
+
+    fn set_raw_capture(&mut self, capture: RawCapture) {
+        self.stream.stream.raw = capture;
+    }
+
+    fn unsubscribe(
+        &mut self,
+        subscription_ids: &[SubscriptionId],
+        requests: Vec<WsMessage>,
+    ) -> Result<(), DataError> {
+        self.transformer.unsubscribe(subscription_ids);
+        requests.into_iter().try_for_each(|request| {
+            self.stream
+                .ws_sink_tx
+                .send(request)
+                .map_err(|_| DataError::Socket(SocketError::Sink))
+        })
+    }
+
+    fn subscribe(&mut self, subscriptions: &[Subscription<Exchange, Kind>]) -> Result<(), DataError>
+    where
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let exchange_subs = subscriptions
+            .iter()
+            .map(ExchangeSub::<Exchange::Channel, Exchange::Market>::new)
+            .collect::<Vec<_>>();
+        let instrument_map = exchange_subs
+            .iter()
+            .zip(subscriptions)
+            .map(|(exchange_sub, subscription)| {
+                (exchange_sub.id(), subscription.instrument.clone())
+            })
+            .collect();
+
+        // Determine the payloads before identifying any messages, since the exchange may not
+        // support subscribing on a live connection
+        let requests = Exchange::live_requests(exchange_subs)?;
+        self.transformer.subscribe(instrument_map)?;
+        requests.into_iter().try_for_each(|request| {
+            self.stream
+                .ws_sink_tx
+                .send(request)
+                .map_err(|_| DataError::Socket(SocketError::Sink))
+        })
+    }
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer))
+    fn close(&mut self) {
+        let _ = self.stream.ws_sink_tx.send(WsMessage::Close(None));
     }
 }
 
@@ -0,0 +1,409 @@
+use super::{MarketEventSink, RotatingFile, Rotation};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    subscription::{
+        candle::{Candle, CandleSource, VolumeDenomination},
+        number::Number,
+        trade::{PublicTrade, TakerOrderKind},
+    },
+};
+use async_trait::async_trait;
+use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side, Symbol};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// File extension of CSV sink files.
+const CSV_EXTENSION: &str = "csv";
+
+/// Stable CSV column schema of a normalised [`MarketEvent<Self>`](MarketEvent) kind.
+///
+/// Every row starts with the common [`MarketEvent`] columns ("exchange_time", "received_time",
+/// "exchange", "base", "quote", "instrument_kind", "sequence"), followed by the kind specific
+/// columns.
+pub trait CsvSchema: Sized {
+    /// Column names, in the order the [`Self::Row`] fields are serialised.
+    const HEADER: &'static [&'static str];
+
+    /// Flat row representation of a [`MarketEvent<Self>`](MarketEvent).
+    type Row: Serialize + DeserializeOwned;
+
+    fn to_row(event: &MarketEvent<Self>) -> Self::Row;
+
+    fn from_row(row: Self::Row) -> MarketEvent<Self>;
+}
+
+/// [`CsvSchema`] row of a [`MarketEvent<PublicTrade>`](MarketEvent).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CsvTradeRow {
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    pub exchange: Exchange,
+    pub base: Symbol,
+    pub quote: Symbol,
+    pub instrument_kind: InstrumentKind,
+    pub sequence: Option<u64>,
+    pub id: String,
+    pub price: Number,
+    pub amount: Number,
+    pub side: Side,
+    pub buyer_order_id: Option<String>,
+    pub seller_order_id: Option<String>,
+    pub taker_order_kind: Option<TakerOrderKind>,
+}
+
+impl CsvSchema for PublicTrade {
+    const HEADER: &'static [&'static str] = &[
+        "exchange_time",
+        "received_time",
+        "exchange",
+        "base",
+        "quote",
+        "instrument_kind",
+        "sequence",
+        "id",
+        "price",
+        "amount",
+        "side",
+        "buyer_order_id",
+        "seller_order_id",
+        "taker_order_kind",
+    ];
+
+    type Row = CsvTradeRow;
+
+    fn to_row(event: &MarketEvent<Self>) -> Self::Row {
+        CsvTradeRow {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange.clone(),
+            base: event.instrument.base.clone(),
+            quote: event.instrument.quote.clone(),
+            instrument_kind: event.instrument.kind,
+            sequence: event.sequence,
+            id: event.kind.id.clone(),
+            price: event.kind.price,
+            amount: event.kind.amount,
+            side: event.kind.side,
+            buyer_order_id: event.kind.buyer_order_id.clone(),
+            seller_order_id: event.kind.seller_order_id.clone(),
+            taker_order_kind: event.kind.taker_order_kind,
+        }
+    }
+
+    fn from_row(row: Self::Row) -> MarketEvent<Self> {
+        MarketEvent {
+            exchange_time: row.exchange_time,
+            received_time: row.received_time,
+            exchange: row.exchange,
+            instrument: Instrument::from((row.base, row.quote, row.instrument_kind)),
+            sequence: row.sequence,
+            tag: None,
+            kind: PublicTrade {
+                id: row.id,
+                price: row.price,
+                amount: row.amount,
+                side: row.side,
+                buyer_order_id: row.buyer_order_id,
+                seller_order_id: row.seller_order_id,
+                taker_order_kind: row.taker_order_kind,
+            },
+        }
+    }
+}
+
+/// [`CsvSchema`] row of a [`MarketEvent<Candle>`](MarketEvent).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct CsvCandleRow {
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    pub exchange: Exchange,
+    pub base: Symbol,
+    pub quote: Symbol,
+    pub instrument_kind: InstrumentKind,
+    pub sequence: Option<u64>,
+    pub start_time: DateTime<Utc>,
+    pub close_time: DateTime<Utc>,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
+    pub quote_volume: Option<Number>,
+    pub taker_buy_volume: Option<Number>,
+    pub taker_buy_quote_volume: Option<Number>,
+    pub trade_count: u64,
+    pub closed: bool,
+    pub source: CandleSource,
+    #[serde(default)]
+    pub synthetic: bool,
+    #[serde(default)]
+    pub derived: bool,
+    #[serde(default)]
+    pub volume_denomination: VolumeDenomination,
+    #[serde(default)]
+    pub base_volume: Option<Number>,
+}
+
+impl CsvSchema for Candle {
+    const HEADER: &'static [&'static str] = &[
+        "exchange_time",
+        "received_time",
+        "exchange",
+        "base",
+        "quote",
+        "instrument_kind",
+        "sequence",
+        "start_time",
+        "close_time",
+        "open",
+        "high",
+        "low",
+        "close",
+        "volume",
+        "quote_volume",
+        "taker_buy_volume",
+        "taker_buy_quote_volume",
+        "trade_count",
+        "closed",
+        "source",
+        "synthetic",
+        "derived",
+        "volume_denomination",
+        "base_volume",
+    ];
+
+    type Row = CsvCandleRow;
+
+    fn to_row(event: &MarketEvent<Self>) -> Self::Row {
+        CsvCandleRow {
+            exchange_time: event.exchange_time,
+            received_time: event.received_time,
+            exchange: event.exchange.clone(),
+            base: event.instrument.base.clone(),
+            quote: event.instrument.quote.clone(),
+            instrument_kind: event.instrument.kind,
+            sequence: event.sequence,
+            start_time: event.kind.start_time,
+            close_time: event.kind.close_time,
+            open: event.kind.open,
+            high: event.kind.high,
+            low: event.kind.low,
+            close: event.kind.close,
+            volume: event.kind.volume,
+            quote_volume: event.kind.quote_volume,
+            taker_buy_volume: event.kind.taker_buy_volume,
+            taker_buy_quote_volume: event.kind.taker_buy_quote_volume,
+            trade_count: event.kind.trade_count,
+            closed: event.kind.closed,
+            source: event.kind.source,
+            synthetic: event.kind.synthetic,
+            derived: event.kind.derived,
+            volume_denomination: event.kind.volume_denomination,
+            base_volume: event.kind.base_volume,
+        }
+    }
+
+    fn from_row(row: Self::Row) -> MarketEvent<Self> {
+        MarketEvent {
+            exchange_time: row.exchange_time,
+            received_time: row.received_time,
+            exchange: row.exchange,
+            instrument: Instrument::from((row.base, row.quote, row.instrument_kind)),
+            sequence: row.sequence,
+            tag: None,
+            kind: Candle {
+                start_time: row.start_time,
+                close_time: row.close_time,
+                open: row.open,
+                high: row.high,
+                low: row.low,
+                close: row.close,
+                volume: row.volume,
+                volume_denomination: row.volume_denomination,
+                base_volume: row.base_volume,
+                quote_volume: row.quote_volume,
+                taker_buy_volume: row.taker_buy_volume,
+                taker_buy_quote_volume: row.taker_buy_quote_volume,
+                trade_count: row.trade_count,
+                closed: row.closed,
+                source: row.source,
+                synthetic: row.synthetic,
+                derived: row.derived,
+            },
+        }
+    }
+}
+
+/// [`MarketEventSink`] writing every [`MarketEvent<T>`](MarketEvent) as a CSV row, using the
+/// [`CsvSchema`] of `T`, to a sequence of rotating files (eg/ "candles.0.csv",
+/// "candles.1.csv").
+///
+/// Every file starts with the [`CsvSchema::HEADER`] row.
+#[derive(Debug)]
+pub struct CsvSink {
+    file: RotatingFile,
+}
+
+impl CsvSink {
+    /// Construct a new [`Self`] writing files named with the provided stem (eg/ "candles") to
+    /// the provided directory, using the default [`Rotation`] policy.
+    pub fn new<P, S>(directory: P, stem: S) -> Self
+    where
+        P: Into<PathBuf>,
+        S: Into<String>,
+    {
+        Self {
+            file: RotatingFile::new(
+                directory.into(),
+                stem.into(),
+                CSV_EXTENSION,
+                Rotation::default(),
+            ),
+        }
+    }
+
+    /// Configure the [`Rotation`] policy of the sink files.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.file.set_rotation(rotation);
+        self
+    }
+
+    /// Every file created by the sink so far, ordered oldest first.
+    pub fn files(&self) -> &[PathBuf] {
+        self.file.files()
+    }
+}
+
+#[async_trait]
+impl<T> MarketEventSink<T> for CsvSink
+where
+    T: CsvSchema + Sync,
+{
+    async fn write(&mut self, event: &MarketEvent<T>) -> Result<(), DataError> {
+        let row = csv_line(|writer| writer.serialize(T::to_row(event)))?;
+        let header = csv_line(|writer| writer.write_record(T::HEADER))?;
+        self.file.write(&row, &header).await
+    }
+
+    async fn flush(&mut self) -> Result<(), DataError> {
+        self.file.flush().await
+    }
+}
+
+/// Serialise a single CSV line using the provided write operation.
+fn csv_line<F>(write: F) -> Result<Vec<u8>, DataError>
+where
+    F: FnOnce(&mut ::csv::Writer<Vec<u8>>) -> Result<(), ::csv::Error>,
+{
+    let mut writer = ::csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+
+    write(&mut writer).map_err(std::io::Error::from)?;
+
+    writer
+        .into_inner()
+        .map_err(|error| DataError::from(error.into_error()))
+}
+
+/// Read every [`MarketEvent<T>`](MarketEvent) from a file written by a [`CsvSink`].
+///
+/// Columns are matched to the [`CsvSchema::Row`] fields by header name.
+pub fn read_csv<T, P>(path: P) -> Result<Vec<MarketEvent<T>>, DataError>
+where
+    T: CsvSchema,
+    P: AsRef<Path>,
+{
+    ::csv::Reader::from_path(path)
+        .map_err(std::io::Error::from)?
+        .deserialize::<T::Row>()
+        .map(|row| {
+            row.map(T::from_row)
+                .map_err(|error| DataError::from(std::io::Error::from(error)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        sinks::tests::{candles, read_files, temp_directory},
+        subscription::number::from_f64,
+    };
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_csv_sink_candles_round_trip() {
+        let directory = temp_directory("csv_candles");
+        let expected = candles(1000);
+
+        let mut sink = CsvSink::new(&directory, "candles")
+            .rotation(Rotation::default().max_file_size(64 * 1024));
+        for event in &expected {
+            sink.write(event).await.unwrap();
+        }
+        MarketEventSink::<Candle>::flush(&mut sink).await.unwrap();
+
+        // Rotated files each start with the schema header
+        assert!(sink.files().len() > 1);
+        for path in sink.files() {
+            let header = std::fs::read_to_string(path)
+                .unwrap()
+                .lines()
+                .next()
+                .unwrap()
+                .to_string();
+            assert_eq!(header, Candle::HEADER.join(","));
+        }
+
+        let actual = read_files(sink.files(), |path| read_csv::<Candle, _>(path));
+        assert_eq!(actual, expected);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_drain_trades_into_csv_sink() {
+        let directory = temp_directory("csv_trades");
+        let expected = (0..10)
+            .map(|index| MarketEvent {
+                exchange_time: datetime_utc_from_epoch_duration(Duration::from_millis(index)),
+                received_time: datetime_utc_from_epoch_duration(Duration::from_millis(index)),
+                exchange: Exchange::from("kraken"),
+                instrument: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+                sequence: None,
+                tag: None,
+                kind: PublicTrade {
+                    id: format!("trade,{index}"),
+                    price: from_f64(0.1 * index as f64),
+                    amount: from_f64(0.2),
+                    side: Side::Buy,
+                    buyer_order_id: Some(index.to_string()),
+                    seller_order_id: None,
+                    taker_order_kind: Some(TakerOrderKind::Limit),
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        for event in expected.iter().cloned() {
+            tx.send(event).unwrap();
+        }
+        drop(tx);
+
+        let mut sink = CsvSink::new(&directory, "trades");
+        super::super::drain(rx.into(), &mut sink, Duration::from_secs(1))
+            .await
+            .unwrap();
+
+        assert_eq!(sink.files().len(), 1);
+        let actual = read_csv::<PublicTrade, _>(&sink.files()[0]).unwrap();
+        assert_eq!(actual, expected);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
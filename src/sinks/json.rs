@@ -0,0 +1,106 @@
+use super::{MarketEventSink, RotatingFile, Rotation};
+use crate::{error::DataError, event::MarketEvent};
+use async_trait::async_trait;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// File extension of newline-delimited JSON sink files.
+const JSON_LINES_EXTENSION: &str = "jsonl";
+
+/// [`MarketEventSink`] writing every [`MarketEvent<T>`](MarketEvent) as a line of JSON to a
+/// sequence of rotating files (eg/ "trades.0.jsonl", "trades.1.jsonl").
+///
+/// Files are compatible with [`ReplayStream`](crate::streams::recorder::ReplayStream).
+#[derive(Debug)]
+pub struct JsonLinesSink {
+    file: RotatingFile,
+}
+
+impl JsonLinesSink {
+    /// Construct a new [`Self`] writing files named with the provided stem (eg/ "trades") to
+    /// the provided directory, using the default [`Rotation`] policy.
+    pub fn new<P, S>(directory: P, stem: S) -> Self
+    where
+        P: Into<PathBuf>,
+        S: Into<String>,
+    {
+        Self {
+            file: RotatingFile::new(
+                directory.into(),
+                stem.into(),
+                JSON_LINES_EXTENSION,
+                Rotation::default(),
+            ),
+        }
+    }
+
+    /// Configure the [`Rotation`] policy of the sink files.
+    pub fn rotation(mut self, rotation: Rotation) -> Self {
+        self.file.set_rotation(rotation);
+        self
+    }
+
+    /// Every file created by the sink so far, ordered oldest first.
+    pub fn files(&self) -> &[PathBuf] {
+        self.file.files()
+    }
+}
+
+#[async_trait]
+impl<T> MarketEventSink<T> for JsonLinesSink
+where
+    T: Serialize + Sync,
+{
+    async fn write(&mut self, event: &MarketEvent<T>) -> Result<(), DataError> {
+        let mut line = serde_json::to_vec(event).map_err(std::io::Error::from)?;
+        line.push(b'\n');
+        self.file.write(&line, &[]).await
+    }
+
+    async fn flush(&mut self) -> Result<(), DataError> {
+        self.file.flush().await
+    }
+}
+
+/// Read every [`MarketEvent<T>`](MarketEvent) from a file written by a [`JsonLinesSink`].
+pub fn read_json_lines<T, P>(path: P) -> Result<Vec<MarketEvent<T>>, DataError>
+where
+    T: serde::de::DeserializeOwned,
+    P: AsRef<Path>,
+{
+    std::fs::read_to_string(path)?
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            serde_json::from_str(line).map_err(|error| DataError::from(std::io::Error::from(error)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        sinks::tests::{candles, read_files, temp_directory},
+        subscription::candle::Candle,
+    };
+
+    #[tokio::test]
+    async fn test_json_lines_sink_candles_round_trip() {
+        let directory = temp_directory("json_candles");
+        let expected = candles(1000);
+
+        let mut sink = JsonLinesSink::new(&directory, "candles")
+            .rotation(Rotation::default().max_file_size(64 * 1024));
+        for event in &expected {
+            sink.write(event).await.unwrap();
+        }
+        MarketEventSink::<Candle>::flush(&mut sink).await.unwrap();
+
+        assert!(sink.files().len() > 1);
+        let actual = read_files(sink.files(), |path| read_json_lines::<Candle, _>(path));
+        assert_eq!(actual, expected);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
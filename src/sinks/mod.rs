@@ -0,0 +1,351 @@
+use crate::{error::DataError, event::MarketEvent, streams::channel::EventReceiver};
+use async_trait::async_trait;
+use std::{path::PathBuf, time::Duration};
+use tokio::{
+    fs::File,
+    io::{AsyncWriteExt, BufWriter},
+    time::Instant,
+};
+use tracing::error;
+
+/// [`CsvSink`](csv::CsvSink) writing [`MarketEvent`]s as CSV rows with a stable column schema
+/// per [`SubKind`](crate::subscription::SubKind).
+pub mod csv;
+
+/// [`JsonLinesSink`](json::JsonLinesSink) writing [`MarketEvent`]s as newline-delimited JSON.
+pub mod json;
+
+/// Default maximum size in bytes of a single sink file before rotating to the next file.
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Destination that normalised [`MarketEvent<T>`](MarketEvent)s are written to (eg/ a sequence of
+/// rotating files).
+#[async_trait]
+pub trait MarketEventSink<T>
+where
+    T: Sync,
+{
+    /// Write the [`MarketEvent`] to the sink. Written events may be buffered until
+    /// [`Self::flush`] is called.
+    async fn write(&mut self, event: &MarketEvent<T>) -> Result<(), DataError>;
+
+    /// Flush every buffered [`MarketEvent`] to the underlying destination.
+    async fn flush(&mut self) -> Result<(), DataError>;
+}
+
+/// Drain the provided exchange [`EventReceiver`] (eg/ from
+/// [`Streams::select`](crate::streams::Streams::select)) into the [`MarketEventSink`], flushing
+/// every `flush_interval`.
+///
+/// Returns once the receiver is closed and the sink has been flushed. Events that fail to be
+/// written are logged and skipped, whereas a failed flush is returned as an error.
+pub async fn drain<T, Sink>(
+    mut exchange_rx: EventReceiver<MarketEvent<T>>,
+    sink: &mut Sink,
+    flush_interval: Duration,
+) -> Result<(), DataError>
+where
+    T: Sync,
+    Sink: MarketEventSink<T> + Send,
+{
+    let mut flush = tokio::time::interval_at(Instant::now() + flush_interval, flush_interval);
+
+    loop {
+        tokio::select! {
+            _ = flush.tick() => sink.flush().await?,
+            event = exchange_rx.recv() => match event {
+                Some(event) => {
+                    if let Err(error) = sink.write(&event).await {
+                        error!(
+                            exchange = %event.exchange,
+                            instrument = %event.instrument,
+                            %error,
+                            "failed to write MarketEvent to sink"
+                        );
+                    }
+                }
+                None => break sink.flush().await,
+            }
+        }
+    }
+}
+
+/// Size & time based file rotation policy of a file backed [`MarketEventSink`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Rotation {
+    /// Maximum size in bytes of a single file before rotating to the next file.
+    pub max_file_size: u64,
+    /// Maximum duration a single file is written to before rotating to the next file.
+    pub max_file_age: Option<Duration>,
+}
+
+impl Default for Rotation {
+    fn default() -> Self {
+        Self {
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            max_file_age: None,
+        }
+    }
+}
+
+impl Rotation {
+    /// Configure the maximum size in bytes of a single file before rotating to the next file.
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = bytes;
+        self
+    }
+
+    /// Configure the maximum duration a single file is written to before rotating to the next
+    /// file.
+    pub fn max_file_age(mut self, age: Duration) -> Self {
+        self.max_file_age = Some(age);
+        self
+    }
+}
+
+/// Sequence of rotating files (eg/ "trades.0.csv", "trades.1.csv") written to by a file backed
+/// [`MarketEventSink`].
+#[derive(Debug)]
+pub(crate) struct RotatingFile {
+    directory: PathBuf,
+    stem: String,
+    extension: &'static str,
+    rotation: Rotation,
+    files: Vec<PathBuf>,
+    current: Option<CurrentFile>,
+}
+
+/// Currently open file of a [`RotatingFile`].
+#[derive(Debug)]
+struct CurrentFile {
+    size: u64,
+    opened: Instant,
+    file: BufWriter<File>,
+}
+
+impl RotatingFile {
+    pub(crate) fn new(
+        directory: PathBuf,
+        stem: String,
+        extension: &'static str,
+        rotation: Rotation,
+    ) -> Self {
+        Self {
+            directory,
+            stem,
+            extension,
+            rotation,
+            files: Vec::new(),
+            current: None,
+        }
+    }
+
+    pub(crate) fn set_rotation(&mut self, rotation: Rotation) {
+        self.rotation = rotation;
+    }
+
+    /// Every file created so far, ordered oldest first.
+    pub(crate) fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Write the bytes to the current file, rotating to the next file beforehand if the current
+    /// file would exceed the [`Rotation`] policy. The provided `header` is written at the start
+    /// of every new file.
+    pub(crate) async fn write(&mut self, bytes: &[u8], header: &[u8]) -> Result<(), DataError> {
+        let len = bytes.len() as u64;
+
+        let rotate = match &self.current {
+            Some(current) => {
+                let exceeds_size =
+                    current.size > 0 && current.size + len > self.rotation.max_file_size;
+                let exceeds_age = self
+                    .rotation
+                    .max_file_age
+                    .is_some_and(|age| current.opened.elapsed() >= age);
+                exceeds_size || exceeds_age
+            }
+            None => true,
+        };
+
+        if rotate {
+            if let Some(mut current) = self.current.take() {
+                current.file.flush().await?;
+            }
+            self.open(header).await?;
+        }
+
+        let current = self
+            .current
+            .as_mut()
+            .expect("RotatingFile current file is opened before writing");
+        current.file.write_all(bytes).await?;
+        current.size += len;
+        Ok(())
+    }
+
+    /// Create the next file in the sequence, writing the provided `header`.
+    async fn open(&mut self, header: &[u8]) -> Result<(), DataError> {
+        tokio::fs::create_dir_all(&self.directory).await?;
+
+        let path = self.directory.join(format!(
+            "{}.{}.{}",
+            self.stem,
+            self.files.len(),
+            self.extension
+        ));
+        let mut file = BufWriter::new(File::create(&path).await?);
+        file.write_all(header).await?;
+
+        self.files.push(path);
+        self.current = Some(CurrentFile {
+            size: header.len() as u64,
+            opened: Instant::now(),
+            file,
+        });
+        Ok(())
+    }
+
+    pub(crate) async fn flush(&mut self) -> Result<(), DataError> {
+        match self.current.as_mut() {
+            Some(current) => current.file.flush().await.map_err(DataError::from),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        candle::{Candle, CandleSource, VolumeDenomination},
+        number::from_f64,
+    };
+    use barter_integration::{
+        de::datetime_utc_from_epoch_duration,
+        model::{Exchange, Instrument, InstrumentKind},
+    };
+
+    pub(super) fn temp_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("barter_data_sinks_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    /// Synthetic [`Candle`] events with awkward `f64` values, used to verify lossless sink
+    /// round trips.
+    pub(super) fn candles(count: u64) -> Vec<MarketEvent<Candle>> {
+        (0..count)
+            .map(|index| {
+                // Awkward f64s that do not have an exact decimal representation
+                let price = 30_000.0 + (index as f64) / 3.0 + 0.1;
+                let start_time = datetime_utc_from_epoch_duration(Duration::from_secs(
+                    1_680_000_000 + index * 60,
+                ));
+                let close_time = start_time + chrono::Duration::milliseconds(59_999);
+
+                MarketEvent {
+                    exchange_time: close_time,
+                    received_time: close_time + chrono::Duration::nanoseconds(123_456_789),
+                    exchange: Exchange::from("binance_spot"),
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    sequence: (index % 2 == 0).then_some(index),
+                    tag: None,
+                    kind: Candle {
+                        start_time,
+                        close_time,
+                        open: from_f64(price),
+                        high: from_f64(price * 1.000_1),
+                        low: from_f64(price / 1.000_3),
+                        close: from_f64(price + f64::EPSILON * price),
+                        volume: from_f64(1.0 / (index as f64 + 7.0)),
+                        volume_denomination: if index % 4 == 0 {
+                            VolumeDenomination::Contracts
+                        } else {
+                            VolumeDenomination::Base
+                        },
+                        base_volume: (index % 4 != 0).then(|| from_f64(1.0 / (index as f64 + 7.0))),
+                        quote_volume: (index % 3 != 0).then(|| from_f64(price * 0.1 / 7.0)),
+                        taker_buy_volume: Some(from_f64(f64::MIN_POSITIVE * (index + 1) as f64)),
+                        taker_buy_quote_volume: None,
+                        trade_count: index * 11,
+                        closed: index % 5 == 0,
+                        source: CandleSource::default(),
+                        synthetic: index % 7 == 0,
+                        derived: false,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    pub(super) fn read_files<F>(files: &[PathBuf], read: F) -> Vec<MarketEvent<Candle>>
+    where
+        F: Fn(&PathBuf) -> Result<Vec<MarketEvent<Candle>>, DataError>,
+    {
+        files.iter().flat_map(|path| read(path).unwrap()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_rotating_file_rotates_by_size() {
+        let directory = temp_directory("rotation");
+        let mut file = RotatingFile::new(
+            directory.clone(),
+            "events".to_string(),
+            "txt",
+            Rotation::default().max_file_size(10),
+        );
+
+        for line in ["aaaa\n", "bbbb\n", "cccc\n", "dddddddddddddddd\n", "eeee\n"] {
+            file.write(line.as_bytes(), b"h\n").await.unwrap();
+        }
+        file.flush().await.unwrap();
+
+        let actual = file
+            .files()
+            .iter()
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                "h\naaaa\n",
+                "h\nbbbb\n",
+                "h\ncccc\n",
+                "h\ndddddddddddddddd\n",
+                "h\neeee\n",
+            ]
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rotating_file_rotates_by_age() {
+        let directory = temp_directory("rotation_age");
+        let mut file = RotatingFile::new(
+            directory.clone(),
+            "events".to_string(),
+            "txt",
+            Rotation::default().max_file_age(Duration::from_secs(60)),
+        );
+
+        file.write(b"a\n", b"").await.unwrap();
+        file.write(b"b\n", b"").await.unwrap();
+        tokio::time::advance(Duration::from_secs(60)).await;
+        file.write(b"c\n", b"").await.unwrap();
+        file.flush().await.unwrap();
+
+        let actual = file
+            .files()
+            .iter()
+            .map(|path| std::fs::read_to_string(path).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, vec!["a\nb\n", "c\n"]);
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
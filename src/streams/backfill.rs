@@ -0,0 +1,377 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{ExchangeId, StreamSelector},
+    streams::channel::{EventReceiver, EventSender},
+    subscription::{candle::Candle, SubKind, Subscription},
+    Identifier,
+};
+use async_trait::async_trait;
+use barter_integration::model::Instrument;
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use tracing::{info, warn};
+
+/// Unique key of a backfilled [`MarketEvent`], used to skip live events that were already
+/// backfilled (eg/ a [`Candle`] [`Instrument`], start time & close time).
+pub type BackfillKey = (Instrument, DateTime<Utc>, DateTime<Utc>);
+
+/// REST client that fetches the most recent historical [`MarketEvent`]s of a [`Subscription`],
+/// used to backfill a [`MarketStream`](crate::MarketStream) before streaming live events.
+///
+/// Exchanges provide an implementation via [`StreamSelector::backfill_client`].
+#[async_trait]
+pub trait BackfillClient<Exchange, Kind>: Send + Sync
+where
+    Kind: SubKind,
+{
+    /// Fetch at most `limit` of the most recent final (eg/ closed candle) historical
+    /// [`MarketEvent`]s for the provided [`Subscription`], ordered oldest first.
+    async fn fetch(
+        &self,
+        subscription: &Subscription<Exchange, Kind>,
+        limit: usize,
+    ) -> Result<Vec<MarketEvent<Kind::Event>>, DataError>;
+
+    /// [`BackfillKey`] of the provided [`MarketEvent`], used to de-duplicate live events that
+    /// overlap with the backfilled events.
+    fn key(&self, event: &MarketEvent<Kind::Event>) -> BackfillKey;
+}
+
+/// [`BackfillKey`] of a [`Candle`] [`MarketEvent`], identified by the candle start & close time.
+///
+/// Both times are required to distinguish candles of different [`Interval`]s for the same
+/// [`Instrument`] that close at the same time (eg/ the 1m & 5m candles closing at 00:04:59.999).
+///
+/// [`Interval`]: crate::subscription::Interval
+pub fn candle_backfill_key(event: &MarketEvent<Candle>) -> BackfillKey {
+    (
+        event.instrument.clone(),
+        event.kind.start_time,
+        event.kind.close_time,
+    )
+}
+
+/// Retain at most `limit` of the most recent closed [`Candle`]s, ordered oldest first.
+///
+/// Exchange REST APIs usually include the in-progress candle, which is instead provided by the
+/// live stream.
+pub fn latest_closed_candles(
+    mut candles: Vec<MarketEvent<Candle>>,
+    limit: usize,
+) -> Vec<MarketEvent<Candle>> {
+    candles.retain(|candle| candle.kind.closed);
+    candles.sort_by_key(|candle| candle.kind.close_time);
+    let skip = candles.len().saturating_sub(limit);
+    candles.into_iter().skip(skip).collect()
+}
+
+/// Wrap the provided `exchange_tx` so the most recent `limit` historical events of each
+/// [`Subscription`] are sent before any live events.
+///
+/// Returns the [`EventSender`] the [`MarketStream`](crate::MarketStream) consumer loop should
+/// send live events to. If the exchange has no [`BackfillClient`] for the `Kind`,
+/// backfill is skipped with a warning and the `exchange_tx` is returned unchanged.
+pub fn spawn_backfill<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    limit: usize,
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+) -> EventSender<MarketEvent<Kind::Event>>
+where
+    Exchange: StreamSelector<Kind> + Send + Sync + 'static,
+    Kind: SubKind + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let Some(client) = Exchange::backfill_client() else {
+        warn!(
+            exchange = %Exchange::ID,
+            action = "skipping backfill",
+            "exchange has no REST backfill client for the Subscription SubKind",
+        );
+        return exchange_tx;
+    };
+
+    let (live_tx, live_rx) = exchange_tx.upstream();
+    tokio::spawn(backfill(
+        Exchange::ID,
+        client,
+        subscriptions,
+        limit,
+        live_rx,
+        exchange_tx,
+    ));
+
+    live_tx
+}
+
+/// Send the most recent `limit` historical events of each [`Subscription`] via the
+/// `exchange_tx`, followed by the live events received via `live_rx`.
+///
+/// Backfill is only fetched once the first live event is received, so the backfilled history
+/// always overlaps with the live stream (ie/ no gap). Live events that were already backfilled
+/// are skipped (ie/ no overlap).
+pub async fn backfill<Exchange, Kind>(
+    exchange: ExchangeId,
+    client: Box<dyn BackfillClient<Exchange, Kind>>,
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    limit: usize,
+    mut live_rx: EventReceiver<MarketEvent<Kind::Event>>,
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+) where
+    Exchange: Send + Sync,
+    Kind: SubKind + Send + Sync,
+    Kind::Event: Send,
+{
+    // Wait for the live stream to start before fetching history, guaranteeing no gap
+    let Some(first) = live_rx.recv().await else {
+        return;
+    };
+
+    // Fetch & send backfilled events for each Subscription
+    let mut backfilled = HashSet::new();
+    for subscription in &subscriptions {
+        match client.fetch(subscription, limit).await {
+            Ok(events) => {
+                info!(
+                    %exchange,
+                    instrument = %subscription.instrument,
+                    num_events = events.len(),
+                    "backfilled historical MarketEvents",
+                );
+                for event in events {
+                    backfilled.insert(client.key(&event));
+                    let _ = exchange_tx.send(event).await;
+                }
+            }
+            Err(error) => {
+                warn!(
+                    %exchange,
+                    instrument = %subscription.instrument,
+                    %error,
+                    action = "skipping backfill",
+                    "failed to fetch historical MarketEvents",
+                );
+            }
+        }
+    }
+
+    // Forward live events, skipping any that were already backfilled
+    let mut event = Some(first);
+    while let Some(live) = event {
+        if !backfilled.contains(&client.key(&live)) && exchange_tx.send(live).await.is_err() {
+            break;
+        }
+        event = live_rx.recv().await;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::exchange::binance::{candle::BinanceCandlesClient, spot::BinanceSpot};
+    use crate::subscription::{
+        candle::{CandleSource, Candles, VolumeDenomination},
+        number::{self, Number},
+        Interval,
+    };
+    use barter_integration::model::{Exchange, InstrumentKind};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        sync::mpsc,
+    };
+
+    /// Serve the provided JSON `body` to a single HTTP request, returning the mock server base
+    /// url and a handle yielding the raw request received.
+    pub(crate) async fn mock_http_server(
+        body: &'static str,
+    ) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+
+            String::from_utf8(request).unwrap()
+        });
+
+        (url, handle)
+    }
+
+    fn candle(close_time_ms: u64, close: f64, closed: bool) -> MarketEvent<Candle> {
+        let close_time = barter_integration::de::datetime_utc_from_epoch_duration(
+            std::time::Duration::from_millis(close_time_ms),
+        );
+        let close = number::from_f64(close);
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: close_time,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind: Candle {
+                start_time: Interval::Minute1.floor(close_time),
+                close_time,
+                open: close,
+                high: close,
+                low: close,
+                close,
+                volume: Number::from(1),
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: None,
+                quote_volume: None,
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 1,
+                closed,
+                source: CandleSource::LastTrade,
+                synthetic: false,
+                derived: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_latest_closed_candles() {
+        struct TestCase {
+            input: Vec<MarketEvent<Candle>>,
+            limit: usize,
+            expected: Vec<i64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: in-progress candle is removed & output is ordered oldest first
+                input: vec![
+                    candle(179_999, 3.0, false),
+                    candle(119_999, 2.0, true),
+                    candle(59_999, 1.0, true),
+                ],
+                limit: 5,
+                expected: vec![59_999, 119_999],
+            },
+            TestCase {
+                // TC1: only the most recent limit candles are retained
+                input: vec![
+                    candle(59_999, 1.0, true),
+                    candle(119_999, 2.0, true),
+                    candle(179_999, 3.0, true),
+                ],
+                limit: 2,
+                expected: vec![119_999, 179_999],
+            },
+            TestCase {
+                // TC2: zero limit retains nothing
+                input: vec![candle(59_999, 1.0, true)],
+                limit: 0,
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = latest_closed_candles(test.input, test.limit)
+                .into_iter()
+                .map(|candle| candle.kind.close_time.timestamp_millis())
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_candle_backfill_key_distinguishes_intervals() {
+        // 1m & 5m candles closing at the same time have distinct keys
+        let minute = candle(299_999, 1.0, true);
+        let mut five_minute = candle(299_999, 1.0, true);
+        five_minute.kind.start_time = Interval::Minute5.floor(five_minute.kind.close_time);
+
+        assert_eq!(candle_backfill_key(&minute), candle_backfill_key(&minute));
+        assert_ne!(
+            candle_backfill_key(&minute),
+            candle_backfill_key(&five_minute)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backfill_binance_candles_from_mock_server() {
+        // Mock Binance klines response: two closed candles & the in-progress candle
+        let (url, request) = mock_http_server(
+            r#"[
+                [1672531260000, "1.0", "1.0", "1.0", "1.0", "10.0", 1672531319999, "10.0", 5, "5.0", "5.0", "0"],
+                [1672531320000, "2.0", "2.0", "2.0", "2.0", "20.0", 1672531379999, "40.0", 6, "10.0", "20.0", "0"],
+                [1672531380000, "3.0", "3.0", "3.0", "3.0", "30.0", 4102444799999, "90.0", 7, "15.0", "45.0", "0"]
+            ]"#,
+        )
+        .await;
+
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            Candles(Interval::Minute1),
+        ));
+
+        let (live_tx, live_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        // Live stream starts with an update for the already closed 1_672_531_379_999 candle, followed by
+        // the in-progress candle
+        live_tx.send(candle(1_672_531_379_999, 2.0, true)).unwrap();
+        live_tx.send(candle(1_672_531_439_999, 3.5, false)).unwrap();
+        drop(live_tx);
+
+        backfill(
+            ExchangeId::BinanceSpot,
+            Box::new(BinanceCandlesClient::new(format!("{url}/api/v3/klines"))),
+            vec![subscription],
+            2,
+            live_rx.into(),
+            exchange_tx.into(),
+        )
+        .await;
+
+        // Backfilled candles are sent first, with no overlap or gap with the live candles
+        let actual = std::iter::from_fn(|| exchange_rx.try_recv().ok())
+            .map(|candle| {
+                (
+                    candle.kind.close_time.timestamp_millis(),
+                    candle.kind.closed,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![
+                (1_672_531_319_999, true),
+                (1_672_531_379_999, true),
+                (1_672_531_439_999, false)
+            ]
+        );
+
+        let request = request.await.unwrap();
+        assert!(
+            request.starts_with("GET /api/v3/klines?symbol=BTCUSDT&interval=1m&limit=3 "),
+            "unexpected request: {request}"
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use super::channel::{EventReceiver, EventSender};
+use crate::{
+    event::MarketEvent,
+    subscription::{
+        book::{Level, OrderBook, OrderBookSnapshot, SortedOrderBook},
+        number::Number,
+    },
+};
+use barter_integration::model::Instrument;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard},
+};
+
+/// Shared handle to the [`SortedOrderBook`] maintained from the [`OrderBook`] [`MarketEvent`]s
+/// of a single [`Instrument`], see [`Streams::select_book`](super::Streams::select_book).
+///
+/// ### Notes
+/// - The book is reset from every [`OrderBook`] event, so after a re-connection it is cleared
+///   and repopulated from the re-fetched snapshot rather than merged with stale levels.
+/// - Between a disconnect and the re-sync, the handle keeps serving the last known book. Check
+///   [`SortedOrderBook::last_update_time`] to detect a stale book.
+#[derive(Clone, Debug, Default)]
+pub struct BookHandle(Arc<RwLock<SortedOrderBook>>);
+
+impl BookHandle {
+    /// Acquire a read guard of the maintained [`SortedOrderBook`].
+    pub fn read(&self) -> RwLockReadGuard<'_, SortedOrderBook> {
+        self.0
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Best (highest) bid [`Level`], if any.
+    pub fn best_bid(&self) -> Option<Level> {
+        self.read().best_bid()
+    }
+
+    /// Best (lowest) ask [`Level`], if any.
+    pub fn best_ask(&self) -> Option<Level> {
+        self.read().best_ask()
+    }
+
+    /// Mid price of the maintained [`SortedOrderBook`], if any.
+    pub fn mid_price(&self) -> Option<Number> {
+        self.read().mid_price()
+    }
+
+    /// [`OrderBookSnapshot`] of the best `n` [`Level`]s on each side of the book.
+    pub fn depth(&self, n: usize) -> OrderBookSnapshot {
+        self.read().depth(n)
+    }
+
+    /// Clone of the entire maintained [`SortedOrderBook`].
+    pub fn snapshot(&self) -> SortedOrderBook {
+        self.read().clone()
+    }
+
+    /// Clear the maintained [`SortedOrderBook`] and repopulate it from the provided
+    /// [`OrderBook`].
+    fn reset(&self, book: &OrderBook) {
+        self.0
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .reset(book);
+    }
+}
+
+/// [`BookHandle`]s of every [`Instrument`] of a single exchange, populated by the task spawned
+/// via [`BookRegistry::spawn`].
+#[derive(Clone, Debug, Default)]
+pub struct BookRegistry(Arc<RwLock<HashMap<Instrument, BookHandle>>>);
+
+impl BookRegistry {
+    /// Get the [`BookHandle`] of the provided [`Instrument`], inserting an empty book if no
+    /// [`OrderBook`] event has been received for it yet.
+    pub fn handle(&self, instrument: &Instrument) -> BookHandle {
+        if let Some(handle) = self
+            .0
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(instrument)
+        {
+            return handle.clone();
+        }
+
+        self.0
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry(instrument.clone())
+            .or_default()
+            .clone()
+    }
+
+    /// Spawn a task that maintains the [`BookHandle`]s of this registry from the [`OrderBook`]
+    /// [`MarketEvent`]s received via `exchange_rx`, forwarding every event to the `book_tx`.
+    pub fn spawn(
+        &self,
+        mut exchange_rx: EventReceiver<MarketEvent<OrderBook>>,
+        book_tx: EventSender<MarketEvent<OrderBook>>,
+    ) {
+        let registry = self.clone();
+        tokio::spawn(async move {
+            while let Some(event) = exchange_rx.recv().await {
+                registry.handle(&event.instrument).reset(&event.kind);
+                let _ = book_tx.send(event).await;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{book::OrderBookSide, number};
+    use barter_integration::model::{Exchange, InstrumentKind, Side};
+    use chrono::{DateTime, Utc};
+    use tokio::sync::mpsc;
+
+    fn book(bids: Vec<Level>, asks: Vec<Level>) -> MarketEvent<OrderBook> {
+        MarketEvent {
+            exchange_time: DateTime::<Utc>::MIN_UTC,
+            received_time: DateTime::<Utc>::MIN_UTC,
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind: OrderBook {
+                last_update_time: DateTime::<Utc>::MIN_UTC,
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+                first_update_id: None,
+                last_update_id: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_book_registry_maintains_handle_across_resync() {
+        let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+        let (book_tx, mut book_rx) = mpsc::unbounded_channel();
+        let registry = BookRegistry::default();
+        registry.spawn(exchange_rx.into(), book_tx.into());
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let handle = registry.handle(&instrument);
+        assert_eq!(handle.best_bid(), None);
+
+        // Maintained book is updated & the event is forwarded
+        exchange_tx
+            .send(book(
+                vec![Level::new(100, 1), Level::new(99, 2)],
+                vec![Level::new(101, 1)],
+            ))
+            .unwrap();
+        assert!(book_rx.recv().await.is_some());
+        assert_eq!(handle.best_bid(), Some(Level::new(100, 1)));
+        assert_eq!(handle.best_ask(), Some(Level::new(101, 1)));
+        assert_eq!(handle.mid_price(), Some(number::from_f64(100.5)));
+
+        // Re-sync after a re-connection replaces every stale level
+        exchange_tx
+            .send(book(vec![Level::new(98, 3)], vec![Level::new(102, 4)]))
+            .unwrap();
+        assert!(book_rx.recv().await.is_some());
+        assert_eq!(
+            handle.depth(5),
+            OrderBookSnapshot {
+                last_update_time: DateTime::<Utc>::MIN_UTC,
+                bids: vec![Level::new(98, 3)],
+                asks: vec![Level::new(102, 4)],
+            }
+        );
+    }
+}
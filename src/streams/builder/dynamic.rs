@@ -0,0 +1,953 @@
+use super::{multi::MultiStreamBuilder, StreamBuilder};
+use crate::{
+    error::DataError,
+    event::{DataKind, MarketEvent},
+    exchange::{
+        binance::{
+            futures::{BinanceFuturesCoin, BinanceFuturesUsd, BinanceFuturesUsdTestnet},
+            spot::{BinanceSpot, BinanceSpotTestnet, BinanceUs},
+        },
+        bitfinex::Bitfinex,
+        bitstamp::Bitstamp,
+        bybit::{futures::BybitPerpetualsUsd, spot::BybitSpot},
+        coinbase::Coinbase,
+        deribit::Deribit,
+        gateio::{
+            futures::{GateioFuturesBtc, GateioFuturesUsd},
+            spot::GateioSpot,
+        },
+        huobi::HuobiSpot,
+        kraken::{futures::KrakenFutures, Kraken},
+        kucoin::KucoinSpot,
+        mexc::MexcSpot,
+        okx::Okx,
+        upbit::UpbitSpot,
+        ExchangeId, StreamSelector,
+    },
+    subscription::{
+        book::{Depth, OrderBookSnapshots, OrderBooksL1, OrderBooksL2},
+        candle::{CandleSource, CandleUpdates, Candles, CandlesFromTrades, SourcedCandles},
+        liquidation::{Liquidations, LiquidationsAll},
+        mark_price::MarkPrices,
+        option::OptionSummaries,
+        ticker::{IndexTickers, Tickers},
+        trade::{PublicTrades, PublicTradesAggregated, PublicTradesAll},
+        Interval, SubKind, SubKindId, Subscription,
+    },
+    Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, InstrumentKind},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter},
+};
+
+/// Dynamic, serde friendly representation of a [`Subscription`], allowing a heterogeneous
+/// collection of exchange & [`SubKind`] combinations to be loaded from a config file.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {
+///     "exchange": "binance_spot",
+///     "base": "btc",
+///     "quote": "usdt",
+///     "instrument_kind": "spot",
+///     "kind": { "type": "candles", "interval": "1m" }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SubscriptionConfig {
+    pub exchange: ExchangeId,
+    pub base: String,
+    pub quote: String,
+    pub instrument_kind: InstrumentKind,
+    pub kind: SubKindConfig,
+}
+
+/// Dynamic, serde friendly representation of each [`SubKind`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SubKindConfig {
+    PublicTrades,
+    PublicTradesAggregated,
+    PublicTradesAll,
+    OrderBooksL1,
+    OrderBooksL2,
+    OrderBookSnapshots {
+        depth: Depth,
+    },
+    Candles {
+        interval: Interval,
+    },
+    CandleUpdates {
+        interval: Interval,
+    },
+    SourcedCandles {
+        interval: Interval,
+        source: CandleSource,
+    },
+    CandlesFromTrades {
+        interval: Interval,
+    },
+    Liquidations,
+    LiquidationsAll,
+    Tickers,
+    IndexTickers,
+    MarkPrices,
+    OptionSummaries,
+}
+
+impl SubKindConfig {
+    /// Return the [`SubKindId`] of this [`SubKindConfig`], ignoring any parameters.
+    pub fn id(&self) -> SubKindId {
+        match self {
+            SubKindConfig::PublicTrades => SubKindId::PublicTrades,
+            SubKindConfig::PublicTradesAggregated => SubKindId::PublicTradesAggregated,
+            SubKindConfig::PublicTradesAll => SubKindId::PublicTradesAll,
+            SubKindConfig::OrderBooksL1 => SubKindId::OrderBooksL1,
+            SubKindConfig::OrderBooksL2 => SubKindId::OrderBooksL2,
+            SubKindConfig::OrderBookSnapshots { .. } => SubKindId::OrderBookSnapshots,
+            SubKindConfig::Candles { .. } => SubKindId::Candles,
+            SubKindConfig::CandleUpdates { .. } => SubKindId::CandleUpdates,
+            SubKindConfig::SourcedCandles { .. } => SubKindId::SourcedCandles,
+            SubKindConfig::CandlesFromTrades { .. } => SubKindId::CandlesFromTrades,
+            SubKindConfig::Liquidations => SubKindId::Liquidations,
+            SubKindConfig::LiquidationsAll => SubKindId::LiquidationsAll,
+            SubKindConfig::Tickers => SubKindId::Tickers,
+            SubKindConfig::IndexTickers => SubKindId::IndexTickers,
+            SubKindConfig::MarkPrices => SubKindId::MarkPrices,
+            SubKindConfig::OptionSummaries => SubKindId::OptionSummaries,
+        }
+    }
+}
+
+impl Display for SubKindConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            SubKindConfig::PublicTrades => PublicTrades.fmt(f),
+            SubKindConfig::PublicTradesAggregated => PublicTradesAggregated.fmt(f),
+            SubKindConfig::PublicTradesAll => PublicTradesAll.fmt(f),
+            SubKindConfig::OrderBooksL1 => OrderBooksL1.fmt(f),
+            SubKindConfig::OrderBooksL2 => OrderBooksL2.fmt(f),
+            SubKindConfig::OrderBookSnapshots { depth } => OrderBookSnapshots::new(depth).fmt(f),
+            SubKindConfig::Candles { interval } => Candles(interval).fmt(f),
+            SubKindConfig::CandleUpdates { interval } => CandleUpdates(interval).fmt(f),
+            SubKindConfig::SourcedCandles { interval, source } => {
+                SourcedCandles(interval, source).fmt(f)
+            }
+            SubKindConfig::CandlesFromTrades { interval } => CandlesFromTrades(interval).fmt(f),
+            SubKindConfig::Liquidations => Liquidations.fmt(f),
+            SubKindConfig::LiquidationsAll => LiquidationsAll.fmt(f),
+            SubKindConfig::Tickers => Tickers.fmt(f),
+            SubKindConfig::IndexTickers => IndexTickers.fmt(f),
+            SubKindConfig::MarkPrices => MarkPrices.fmt(f),
+            SubKindConfig::OptionSummaries => OptionSummaries.fmt(f),
+        }
+    }
+}
+
+impl MultiStreamBuilder<MarketEvent<DataKind>> {
+    /// Add a [`StreamBuilder`] for each exchange & [`SubKindConfig`] combination present in the
+    /// provided [`SubscriptionConfig`]s, each of which is actioned on a distinct connection.
+    ///
+    /// Fails with a [`SocketError::Unsupported`] identifying the first exchange & [`SubKind`]
+    /// combination that has no [`StreamSelector`] implementation.
+    pub fn subscribe_configs<Iter>(mut self, configs: Iter) -> Result<Self, DataError>
+    where
+        Iter: IntoIterator<Item = SubscriptionConfig>,
+    {
+        // Group Instruments by exchange & SubKindConfig
+        let mut groups = BTreeMap::<(ExchangeId, SubKindConfig), Vec<Instrument>>::new();
+        for config in configs {
+            groups
+                .entry((config.exchange, config.kind))
+                .or_default()
+                .push(Instrument::from((
+                    config.base,
+                    config.quote,
+                    config.instrument_kind,
+                )));
+        }
+
+        for ((exchange, kind), instruments) in groups {
+            self = match (exchange, kind) {
+                // PublicTrades
+                (ExchangeId::BinanceSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BinanceSpot, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BinanceFuturesCoin, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BinanceFuturesUsd, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsdTestnet, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BinanceFuturesUsdTestnet, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BinanceSpotTestnet, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BinanceUs, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::Bitfinex, SubKindConfig::PublicTrades) => {
+                    self.add_config::<Bitfinex, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::Bitstamp, SubKindConfig::PublicTrades) => {
+                    self.add_config::<Bitstamp, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BybitSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BybitSpot, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::BybitPerpetualsUsd, SubKindConfig::PublicTrades) => {
+                    self.add_config::<BybitPerpetualsUsd, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::Coinbase, SubKindConfig::PublicTrades) => {
+                    self.add_config::<Coinbase, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::Deribit, SubKindConfig::PublicTrades) => {
+                    self.add_config::<Deribit, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::GateioSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<GateioSpot, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::GateioFuturesUsd, SubKindConfig::PublicTrades) => {
+                    self.add_config::<GateioFuturesUsd, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::GateioFuturesBtc, SubKindConfig::PublicTrades) => {
+                    self.add_config::<GateioFuturesBtc, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::HuobiSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<HuobiSpot, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::Kraken, SubKindConfig::PublicTrades) => {
+                    self.add_config::<Kraken, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::KrakenFutures, SubKindConfig::PublicTrades) => {
+                    self.add_config::<KrakenFutures, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::KucoinSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<KucoinSpot, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::MexcSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<MexcSpot, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::PublicTrades) => {
+                    self.add_config::<Okx, _>(PublicTrades, instruments)
+                }
+                (ExchangeId::UpbitSpot, SubKindConfig::PublicTrades) => {
+                    self.add_config::<UpbitSpot, _>(PublicTrades, instruments)
+                }
+
+                // PublicTradesAggregated
+                (ExchangeId::BinanceSpot, SubKindConfig::PublicTradesAggregated) => {
+                    self.add_config::<BinanceSpot, _>(PublicTradesAggregated, instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::PublicTradesAggregated) => {
+                    self.add_config::<BinanceFuturesCoin, _>(PublicTradesAggregated, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::PublicTradesAggregated) => {
+                    self.add_config::<BinanceFuturesUsd, _>(PublicTradesAggregated, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsdTestnet, SubKindConfig::PublicTradesAggregated) => {
+                    self.add_config::<BinanceFuturesUsdTestnet, _>(
+                        PublicTradesAggregated,
+                        instruments,
+                    )
+                }
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::PublicTradesAggregated) => {
+                    self.add_config::<BinanceSpotTestnet, _>(PublicTradesAggregated, instruments)
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::PublicTradesAggregated) => {
+                    self.add_config::<BinanceUs, _>(PublicTradesAggregated, instruments)
+                }
+
+                // PublicTradesAll
+                (ExchangeId::Okx, SubKindConfig::PublicTradesAll) => {
+                    self.add_config::<Okx, _>(PublicTradesAll, instruments)
+                }
+
+                // OrderBooksL1
+                (ExchangeId::BinanceSpot, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BinanceSpot, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BinanceFuturesCoin, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BinanceFuturesUsd, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsdTestnet, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BinanceFuturesUsdTestnet, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BinanceSpotTestnet, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BinanceUs, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BybitSpot, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BybitSpot, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::BybitPerpetualsUsd, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<BybitPerpetualsUsd, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::Coinbase, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<Coinbase, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::GateioSpot, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<GateioSpot, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::GateioFuturesUsd, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<GateioFuturesUsd, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::GateioFuturesBtc, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<GateioFuturesBtc, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::Kraken, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<Kraken, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::KucoinSpot, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<KucoinSpot, _>(OrderBooksL1, instruments)
+                }
+                (ExchangeId::MexcSpot, SubKindConfig::OrderBooksL1) => {
+                    self.add_config::<MexcSpot, _>(OrderBooksL1, instruments)
+                }
+
+                // OrderBooksL2
+                (ExchangeId::BinanceSpot, SubKindConfig::OrderBooksL2) => {
+                    self.add_config::<BinanceSpot, _>(OrderBooksL2, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::OrderBooksL2) => {
+                    self.add_config::<BinanceFuturesUsd, _>(OrderBooksL2, instruments)
+                }
+                (ExchangeId::Bitfinex, SubKindConfig::OrderBooksL2) => {
+                    self.add_config::<Bitfinex, _>(OrderBooksL2, instruments)
+                }
+                (ExchangeId::Bitstamp, SubKindConfig::OrderBooksL2) => {
+                    self.add_config::<Bitstamp, _>(OrderBooksL2, instruments)
+                }
+                (ExchangeId::Coinbase, SubKindConfig::OrderBooksL2) => {
+                    self.add_config::<Coinbase, _>(OrderBooksL2, instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::OrderBooksL2) => {
+                    self.add_config::<Okx, _>(OrderBooksL2, instruments)
+                }
+
+                // OrderBookSnapshots
+                (ExchangeId::BinanceSpot, SubKindConfig::OrderBookSnapshots { depth }) => {
+                    self.add_config::<BinanceSpot, _>(OrderBookSnapshots::new(depth), instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::OrderBookSnapshots { depth }) => {
+                    self.add_config::<BinanceFuturesCoin, _>(
+                        OrderBookSnapshots::new(depth),
+                        instruments,
+                    )
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::OrderBookSnapshots { depth }) => {
+                    self.add_config::<BinanceFuturesUsd, _>(
+                        OrderBookSnapshots::new(depth),
+                        instruments,
+                    )
+                }
+                (
+                    ExchangeId::BinanceFuturesUsdTestnet,
+                    SubKindConfig::OrderBookSnapshots { depth },
+                ) => self.add_config::<BinanceFuturesUsdTestnet, _>(
+                    OrderBookSnapshots::new(depth),
+                    instruments,
+                ),
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::OrderBookSnapshots { depth }) => {
+                    self.add_config::<BinanceSpotTestnet, _>(
+                        OrderBookSnapshots::new(depth),
+                        instruments,
+                    )
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::OrderBookSnapshots { depth }) => {
+                    self.add_config::<BinanceUs, _>(OrderBookSnapshots::new(depth), instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::OrderBookSnapshots { depth }) => {
+                    self.add_config::<Okx, _>(OrderBookSnapshots::new(depth), instruments)
+                }
+
+                // Candles
+                (ExchangeId::BinanceSpot, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<BinanceSpot, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<BinanceFuturesCoin, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<BinanceFuturesUsd, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::BinanceFuturesUsdTestnet, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<BinanceFuturesUsdTestnet, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<BinanceSpotTestnet, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<BinanceUs, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::Bitfinex, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<Bitfinex, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::Deribit, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<Deribit, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::GateioSpot, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<GateioSpot, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::GateioFuturesUsd, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<GateioFuturesUsd, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::GateioFuturesBtc, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<GateioFuturesBtc, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::Kraken, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<Kraken, _>(Candles(interval), instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::Candles { interval }) => {
+                    self.add_config::<Okx, _>(Candles(interval), instruments)
+                }
+
+                // CandleUpdates
+                (ExchangeId::BinanceSpot, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<BinanceSpot, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<BinanceFuturesCoin, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<BinanceFuturesUsd, _>(CandleUpdates(interval), instruments)
+                }
+                (
+                    ExchangeId::BinanceFuturesUsdTestnet,
+                    SubKindConfig::CandleUpdates { interval },
+                ) => self.add_config::<BinanceFuturesUsdTestnet, _>(
+                    CandleUpdates(interval),
+                    instruments,
+                ),
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<BinanceSpotTestnet, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<BinanceUs, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::Bitfinex, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<Bitfinex, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::Deribit, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<Deribit, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::GateioSpot, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<GateioSpot, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::GateioFuturesUsd, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<GateioFuturesUsd, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::GateioFuturesBtc, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<GateioFuturesBtc, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::Kraken, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<Kraken, _>(CandleUpdates(interval), instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::CandleUpdates { interval }) => {
+                    self.add_config::<Okx, _>(CandleUpdates(interval), instruments)
+                }
+
+                // SourcedCandles
+                (
+                    ExchangeId::GateioFuturesUsd,
+                    SubKindConfig::SourcedCandles { interval, source },
+                ) => self.add_config::<GateioFuturesUsd, _>(
+                    SourcedCandles(interval, source),
+                    instruments,
+                ),
+                (
+                    ExchangeId::GateioFuturesBtc,
+                    SubKindConfig::SourcedCandles { interval, source },
+                ) => self.add_config::<GateioFuturesBtc, _>(
+                    SourcedCandles(interval, source),
+                    instruments,
+                ),
+
+                // CandlesFromTrades
+                (ExchangeId::BinanceSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BinanceSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BinanceFuturesCoin, _>(
+                        CandlesFromTrades(interval),
+                        instruments,
+                    )
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BinanceFuturesUsd, _>(
+                        CandlesFromTrades(interval),
+                        instruments,
+                    )
+                }
+                (
+                    ExchangeId::BinanceFuturesUsdTestnet,
+                    SubKindConfig::CandlesFromTrades { interval },
+                ) => self.add_config::<BinanceFuturesUsdTestnet, _>(
+                    CandlesFromTrades(interval),
+                    instruments,
+                ),
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BinanceSpotTestnet, _>(
+                        CandlesFromTrades(interval),
+                        instruments,
+                    )
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BinanceUs, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::Bitfinex, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<Bitfinex, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::Bitstamp, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<Bitstamp, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::BybitSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BybitSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::BybitPerpetualsUsd, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<BybitPerpetualsUsd, _>(
+                        CandlesFromTrades(interval),
+                        instruments,
+                    )
+                }
+                (ExchangeId::Coinbase, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<Coinbase, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::Deribit, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<Deribit, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::GateioSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<GateioSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::GateioFuturesUsd, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<GateioFuturesUsd, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::GateioFuturesBtc, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<GateioFuturesBtc, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::HuobiSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<HuobiSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::Kraken, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<Kraken, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::KrakenFutures, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<KrakenFutures, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::KucoinSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<KucoinSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::MexcSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<MexcSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<Okx, _>(CandlesFromTrades(interval), instruments)
+                }
+                (ExchangeId::UpbitSpot, SubKindConfig::CandlesFromTrades { interval }) => {
+                    self.add_config::<UpbitSpot, _>(CandlesFromTrades(interval), instruments)
+                }
+
+                // Liquidations
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::Liquidations) => {
+                    self.add_config::<BinanceFuturesCoin, _>(Liquidations, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::Liquidations) => {
+                    self.add_config::<BinanceFuturesUsd, _>(Liquidations, instruments)
+                }
+                (ExchangeId::Deribit, SubKindConfig::Liquidations) => {
+                    self.add_config::<Deribit, _>(Liquidations, instruments)
+                }
+                (ExchangeId::KrakenFutures, SubKindConfig::Liquidations) => {
+                    self.add_config::<KrakenFutures, _>(Liquidations, instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::Liquidations) => {
+                    self.add_config::<Okx, _>(Liquidations, instruments)
+                }
+
+                // LiquidationsAll
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::LiquidationsAll) => {
+                    self.add_config::<BinanceFuturesUsd, _>(LiquidationsAll, instruments)
+                }
+
+                // Tickers
+                (ExchangeId::BinanceSpot, SubKindConfig::Tickers) => {
+                    self.add_config::<BinanceSpot, _>(Tickers, instruments)
+                }
+                (ExchangeId::BinanceFuturesCoin, SubKindConfig::Tickers) => {
+                    self.add_config::<BinanceFuturesCoin, _>(Tickers, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::Tickers) => {
+                    self.add_config::<BinanceFuturesUsd, _>(Tickers, instruments)
+                }
+                (ExchangeId::BinanceFuturesUsdTestnet, SubKindConfig::Tickers) => {
+                    self.add_config::<BinanceFuturesUsdTestnet, _>(Tickers, instruments)
+                }
+                (ExchangeId::BinanceSpotTestnet, SubKindConfig::Tickers) => {
+                    self.add_config::<BinanceSpotTestnet, _>(Tickers, instruments)
+                }
+                (ExchangeId::BinanceUs, SubKindConfig::Tickers) => {
+                    self.add_config::<BinanceUs, _>(Tickers, instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::Tickers) => {
+                    self.add_config::<Okx, _>(Tickers, instruments)
+                }
+                (ExchangeId::UpbitSpot, SubKindConfig::Tickers) => {
+                    self.add_config::<UpbitSpot, _>(Tickers, instruments)
+                }
+
+                // IndexTickers
+                (ExchangeId::Okx, SubKindConfig::IndexTickers) => {
+                    self.add_config::<Okx, _>(IndexTickers, instruments)
+                }
+
+                // MarkPrices
+                (ExchangeId::BinanceFuturesUsd, SubKindConfig::MarkPrices) => {
+                    self.add_config::<BinanceFuturesUsd, _>(MarkPrices, instruments)
+                }
+                (ExchangeId::Okx, SubKindConfig::MarkPrices) => {
+                    self.add_config::<Okx, _>(MarkPrices, instruments)
+                }
+
+                // OptionSummaries
+                (ExchangeId::Okx, SubKindConfig::OptionSummaries) => {
+                    self.add_config::<Okx, _>(OptionSummaries, instruments)
+                }
+
+                (exchange, kind) => {
+                    return Err(DataError::Socket(SocketError::Unsupported {
+                        entity: exchange.as_str(),
+                        item: format!("{kind:?}"),
+                    }))
+                }
+            };
+        }
+
+        Ok(self)
+    }
+
+    /// Add a [`StreamBuilder<Kind>`](StreamBuilder) subscribing to the provided [`Instrument`]s
+    /// on the `Exchange`.
+    fn add_config<Exchange, Kind>(self, kind: Kind, instruments: Vec<Instrument>) -> Self
+    where
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: SubKind + Copy + Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        MarketEvent<DataKind>: From<MarketEvent<Kind::Event>>,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.add(
+            StreamBuilder::<Kind>::new().subscribe(
+                instruments
+                    .into_iter()
+                    .map(|instrument| Subscription::new(Exchange::default(), instrument, kind)),
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::Streams;
+
+    #[test]
+    fn test_de_subscription_config() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<SubscriptionConfig, serde_json::Error>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: PublicTrades w/ unit SubKindConfig
+                input: r#"{"exchange":"okx","base":"btc","quote":"usdt","instrument_kind":"future_perpetual","kind":{"type":"public_trades"}}"#,
+                expected: Ok(SubscriptionConfig {
+                    exchange: ExchangeId::Okx,
+                    base: "btc".to_string(),
+                    quote: "usdt".to_string(),
+                    instrument_kind: InstrumentKind::FuturePerpetual,
+                    kind: SubKindConfig::PublicTrades,
+                }),
+            },
+            TestCase {
+                // TC1: OrderBookSnapshots w/ SubKindConfig parameters
+                input: r#"{"exchange":"binance_spot","base":"eth","quote":"usdt","instrument_kind":"spot","kind":{"type":"order_book_snapshots","depth":"top10"}}"#,
+                expected: Ok(SubscriptionConfig {
+                    exchange: ExchangeId::BinanceSpot,
+                    base: "eth".to_string(),
+                    quote: "usdt".to_string(),
+                    instrument_kind: InstrumentKind::Spot,
+                    kind: SubKindConfig::OrderBookSnapshots {
+                        depth: Depth::Top10,
+                    },
+                }),
+            },
+            TestCase {
+                // TC2: unknown SubKindConfig type
+                input: r#"{"exchange":"okx","base":"btc","quote":"usdt","instrument_kind":"spot","kind":{"type":"order_books_l4"}}"#,
+                expected: Err(serde::de::Error::custom("")),
+            },
+            TestCase {
+                // TC3: misspelled exchange is rejected rather than treated as a custom exchange
+                input: r#"{"exchange":"binanse_spot","base":"btc","quote":"usdt","instrument_kind":"spot","kind":{"type":"public_trades"}}"#,
+                expected: Err(serde::de::Error::custom("")),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<SubscriptionConfig>(test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_configs_round_trip() {
+        let input = r#"[
+            {"exchange":"binance_spot","base":"btc","quote":"usdt","instrument_kind":"spot","kind":{"type":"public_trades"}},
+            {"exchange":"binance_spot","base":"eth","quote":"usdt","instrument_kind":"spot","kind":{"type":"public_trades"}},
+            {"exchange":"coinbase","base":"btc","quote":"usd","instrument_kind":"spot","kind":{"type":"public_trades"}},
+            {"exchange":"okx","base":"btc","quote":"usdt","instrument_kind":"spot","kind":{"type":"candles","interval":"1m"}}
+        ]"#;
+
+        // Round trip the config through serde
+        let configs = serde_json::from_str::<Vec<SubscriptionConfig>>(input).unwrap();
+        let round_trip = serde_json::from_str::<Vec<SubscriptionConfig>>(
+            &serde_json::to_string(&configs).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(round_trip, configs);
+
+        // One StreamBuilder per exchange & SubKindConfig combination
+        let builder = Streams::<MarketEvent<DataKind>>::builder_multi()
+            .subscribe_configs(round_trip)
+            .unwrap();
+        assert_eq!(builder.futures.len(), 3);
+
+        // Initialising spawns the consumer loops in the background, yielding a receiver per exchange
+        let mut streams = builder.init().await.unwrap();
+        for exchange in [
+            ExchangeId::BinanceSpot,
+            ExchangeId::Coinbase,
+            ExchangeId::Okx,
+        ] {
+            assert!(
+                streams.select(exchange).is_ok(),
+                "missing {exchange} stream"
+            );
+        }
+        assert!(streams.streams.is_empty());
+    }
+
+    #[test]
+    fn test_sub_kind_config_display() {
+        struct TestCase {
+            input: SubKindConfig,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: unit SubKindConfig
+                input: SubKindConfig::PublicTrades,
+                expected: "public_trades",
+            },
+            TestCase {
+                // TC1: OrderBooksL2
+                input: SubKindConfig::OrderBooksL2,
+                expected: "order_books_l2",
+            },
+            TestCase {
+                // TC2: Liquidations
+                input: SubKindConfig::Liquidations,
+                expected: "liquidations",
+            },
+            TestCase {
+                // TC3: OrderBookSnapshots w/ Depth parameter
+                input: SubKindConfig::OrderBookSnapshots { depth: Depth::Top5 },
+                expected: "order_book_snapshots_top5",
+            },
+            TestCase {
+                // TC4: Candles w/ Interval parameter
+                input: SubKindConfig::Candles {
+                    interval: Interval::Minute1,
+                },
+                expected: "candles_1m",
+            },
+            TestCase {
+                // TC5: CandleUpdates w/ Interval parameter
+                input: SubKindConfig::CandleUpdates {
+                    interval: Interval::Hour4,
+                },
+                expected: "candle_updates_4h",
+            },
+            TestCase {
+                // TC6: SourcedCandles w/ Interval & CandleSource parameters
+                input: SubKindConfig::SourcedCandles {
+                    interval: Interval::Minute5,
+                    source: CandleSource::MarkPrice,
+                },
+                expected: "sourced_candles_5m_mark_price",
+            },
+            TestCase {
+                // TC7: CandlesFromTrades w/ Interval parameter
+                input: SubKindConfig::CandlesFromTrades {
+                    interval: Interval::Minute1,
+                },
+                expected: "candles_from_trades_1m",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.to_string();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        // Display of every SubKindConfig starts with its serde "type" name
+        let configs = SubKindId::all()
+            .iter()
+            .filter_map(|id| match id {
+                SubKindId::PublicTrades => Some(SubKindConfig::PublicTrades),
+                SubKindId::PublicTradesAggregated => Some(SubKindConfig::PublicTradesAggregated),
+                SubKindId::PublicTradesAll => Some(SubKindConfig::PublicTradesAll),
+                SubKindId::OrderBooksL1 => Some(SubKindConfig::OrderBooksL1),
+                SubKindId::OrderBooksL2 => Some(SubKindConfig::OrderBooksL2),
+                SubKindId::OrderBooksL3 => None,
+                SubKindId::OrderBookSnapshots => Some(SubKindConfig::OrderBookSnapshots {
+                    depth: Depth::Top20,
+                }),
+                SubKindId::Candles => Some(SubKindConfig::Candles {
+                    interval: Interval::Day1,
+                }),
+                SubKindId::CandleUpdates => Some(SubKindConfig::CandleUpdates {
+                    interval: Interval::Day1,
+                }),
+                SubKindId::SourcedCandles => Some(SubKindConfig::SourcedCandles {
+                    interval: Interval::Day1,
+                    source: CandleSource::IndexPrice,
+                }),
+                SubKindId::CandlesFromTrades => Some(SubKindConfig::CandlesFromTrades {
+                    interval: Interval::Day1,
+                }),
+                SubKindId::Liquidations => Some(SubKindConfig::Liquidations),
+                SubKindId::LiquidationsAll => Some(SubKindConfig::LiquidationsAll),
+                SubKindId::Tickers => Some(SubKindConfig::Tickers),
+                SubKindId::IndexTickers => Some(SubKindConfig::IndexTickers),
+                SubKindId::MarkPrices => Some(SubKindConfig::MarkPrices),
+                SubKindId::OptionSummaries => Some(SubKindConfig::OptionSummaries),
+            })
+            .collect::<Vec<_>>();
+
+        for config in configs {
+            let serialised = serde_json::to_value(config).unwrap();
+            let kind = serialised["type"].as_str().unwrap();
+            let display = config.to_string();
+            assert!(
+                display == kind || display.starts_with(&format!("{kind}_")),
+                "{display} does not start with serde name {kind}"
+            );
+            assert_eq!(kind, config.id().as_str());
+        }
+    }
+
+    #[test]
+    fn test_subscribe_configs_unsupported() {
+        let configs = vec![SubscriptionConfig {
+            exchange: ExchangeId::Coinbase,
+            base: "btc".to_string(),
+            quote: "usd".to_string(),
+            instrument_kind: InstrumentKind::Spot,
+            kind: SubKindConfig::Candles {
+                interval: Interval::Minute1,
+            },
+        }];
+
+        let actual = Streams::<MarketEvent<DataKind>>::builder_multi().subscribe_configs(configs);
+
+        match actual {
+            Err(DataError::Socket(SocketError::Unsupported { entity, .. })) => {
+                assert_eq!(entity, "coinbase")
+            }
+            other => panic!("expected SocketError::Unsupported, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_subscribe_configs_matches_exchange_id_supports() {
+        let kinds = [
+            SubKindConfig::PublicTrades,
+            SubKindConfig::PublicTradesAggregated,
+            SubKindConfig::PublicTradesAll,
+            SubKindConfig::OrderBooksL1,
+            SubKindConfig::OrderBooksL2,
+            SubKindConfig::OrderBookSnapshots {
+                depth: Depth::Top10,
+            },
+            SubKindConfig::Candles {
+                interval: Interval::Minute1,
+            },
+            SubKindConfig::CandleUpdates {
+                interval: Interval::Minute1,
+            },
+            SubKindConfig::SourcedCandles {
+                interval: Interval::Minute1,
+                source: CandleSource::MarkPrice,
+            },
+            SubKindConfig::CandlesFromTrades {
+                interval: Interval::Minute1,
+            },
+            SubKindConfig::Liquidations,
+            SubKindConfig::LiquidationsAll,
+            SubKindConfig::Tickers,
+            SubKindConfig::IndexTickers,
+            SubKindConfig::MarkPrices,
+            SubKindConfig::OptionSummaries,
+        ];
+
+        for exchange in ExchangeId::all().iter().copied() {
+            for kind in kinds {
+                let config = SubscriptionConfig {
+                    exchange,
+                    base: "btc".to_string(),
+                    quote: "usdt".to_string(),
+                    instrument_kind: InstrumentKind::Spot,
+                    kind,
+                };
+
+                let actual = Streams::<MarketEvent<DataKind>>::builder_multi()
+                    .subscribe_configs(vec![config])
+                    .is_ok();
+
+                assert_eq!(
+                    actual,
+                    exchange.supports(kind.id()),
+                    "{exchange} {} subscribe_configs does not match ExchangeId::supports",
+                    kind.id()
+                );
+            }
+        }
+    }
+}
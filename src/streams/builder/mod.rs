@@ -1,23 +1,143 @@
-use super::{consumer::consume, Streams};
+use super::{
+    backfill::spawn_backfill,
+    channel::{ChannelCapacity, EventReceiver, EventSender, OverflowPolicy},
+    conflate::spawn_conflation,
+    consumer::{
+        consume, InvalidEventPolicy, ReconnectPolicy, StreamCommand, TimestampPolicy,
+        STREAM_COMMAND_CAPACITY,
+    },
+    filter::EventFilter,
+    handler::{EventHandler, HandlerSender},
+    health::{ConnectionEvent, ErrorChannel, HealthMonitor},
+    metrics::StreamMetrics,
+    raw::RawChannel,
+    resume::spawn_resume,
+    snapshot::spawn_initial_snapshots,
+    stats::StatsRegistry,
+    tag::spawn_tagging,
+    watermark::{Watermark, WatermarkEmitter, WatermarkPolicy},
+    Streams,
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::{ExchangeId, StreamSelector},
-    subscription::{SubKind, Subscription},
+    exchange::{Environment, ExchangeId, StreamSelector},
+    subscriber::{
+        connection::ConnectionOptions,
+        validator::{AckPolicy, SubscriptionAcks},
+    },
+    subscription::{
+        book::OrderBooksL1, candle::Candles, InvalidSubscription, SubKind, Subscription,
+    },
     Identifier,
 };
 use barter_integration::{error::SocketError, Validator};
-use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
-use tokio::sync::mpsc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    future::Future,
+    hash::Hash,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+use tokio::sync::{broadcast, mpsc};
+use tracing::warn;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
 /// initialising a common [`Streams<Output>`](Streams) from multiple
 /// [`StreamBuilder<SubKind>`](StreamBuilder)s.
 pub mod multi;
 
+/// Defines the serde friendly [`SubscriptionConfig`](dynamic::SubscriptionConfig) used to
+/// dynamically load heterogeneous exchange & [`SubKind`] combinations into a
+/// [`MultiStreamBuilder`](multi::MultiStreamBuilder).
+pub mod dynamic;
+
 /// Communicative type alias representing the [`Future`] result of a [`Subscription`] [`validate`]
-/// call generated whilst executing [`StreamBuilder::subscribe`].
-pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
+/// call generated whilst executing [`StreamBuilder::subscribe`]. Yields any
+/// [`InvalidSubscription`]s that were skipped due to the [`SubscriptionPolicy`].
+pub type SubscribeFuture =
+    Pin<Box<dyn Future<Output = Result<Vec<InvalidSubscription>, DataError>>>>;
+
+/// Communicative type alias representing a deferred [`StreamBuilder::subscribe`] call, which
+/// produces a [`SubscribeFuture`] once the [`SubscriptionPolicy`] is known at initialisation.
+pub type SubscribeFn = Box<dyn FnOnce(SubscriptionPolicy) -> SubscribeFuture>;
+
+/// Communicative type alias representing the valid [`Subscription`]s and skipped
+/// [`InvalidSubscription`]s output by [`validate_with_policy`].
+pub type ValidatedSubscriptions<Exchange, Kind> =
+    (Vec<Subscription<Exchange, Kind>>, Vec<InvalidSubscription>);
+
+/// Determines how a [`StreamBuilder`] handles invalid [`Subscription`]s during initialisation.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum SubscriptionPolicy {
+    /// Fail initialisation with a [`DataError::InvalidSubscription`] identifying the first
+    /// invalid [`Subscription`].
+    #[default]
+    Strict,
+    /// Drop & log any invalid [`Subscription`]s, and initialise streams for the remaining valid
+    /// [`Subscription`]s.
+    SkipInvalid,
+}
+
+/// Default [`Duration`] a [`BatchPolicy`] waits between initialising each connection of a batched
+/// [`StreamBuilder::subscribe_batched`] call.
+pub const DEFAULT_SUBSCRIBE_RATE: Duration = Duration::from_millis(250);
+
+/// Determines how a [`StreamBuilder::subscribe_batched`] call splits a collection of
+/// [`Subscription`]s across multiple connections to the same exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct BatchPolicy {
+    /// Maximum number of [`Subscription`]s actioned on a single connection. Bounded by the
+    /// exchange [`Connector::MAX_STREAMS_PER_CONNECTION`](crate::exchange::Connector).
+    pub max_streams_per_connection: usize,
+    /// [`Duration`] to wait between initialising each connection, used to respect exchange
+    /// subscription request rate limits.
+    pub subscribe_rate: Duration,
+}
+
+impl BatchPolicy {
+    /// Construct a [`BatchPolicy`] using the exchange [`Connector`](crate::exchange::Connector)
+    /// default `MAX_STREAMS_PER_CONNECTION` and the [`DEFAULT_SUBSCRIBE_RATE`].
+    pub fn connector<Exchange>() -> Self
+    where
+        Exchange: crate::exchange::Connector,
+    {
+        Self {
+            max_streams_per_connection: Exchange::MAX_STREAMS_PER_CONNECTION,
+            subscribe_rate: DEFAULT_SUBSCRIBE_RATE,
+        }
+    }
+
+    /// [`BatchPolicy`] that actions every [`Subscription`] on a single connection, as used by
+    /// [`StreamBuilder::subscribe`].
+    pub fn single_connection() -> Self {
+        Self {
+            max_streams_per_connection: usize::MAX,
+            subscribe_rate: Duration::ZERO,
+        }
+    }
+
+    /// Number of [`Subscription`]s to action on each connection for the provided exchange
+    /// [`Connector`](crate::exchange::Connector), never exceeding the exchange limit.
+    pub fn batch_size<Exchange>(&self) -> usize
+    where
+        Exchange: crate::exchange::Connector,
+    {
+        self.max_streams_per_connection
+            .min(Exchange::MAX_STREAMS_PER_CONNECTION)
+            .max(1)
+    }
+
+    /// [`Duration`] to wait before initialising the connection of the provided batch index.
+    pub fn delay(&self, batch: usize) -> Duration {
+        self.subscribe_rate
+            .saturating_mul(u32::try_from(batch).unwrap_or(u32::MAX))
+    }
+}
 
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubKind::Event>`](Streams) instance
 /// for a specific [`SubKind`].
@@ -26,8 +146,30 @@ pub struct StreamBuilder<Kind>
 where
     Kind: SubKind,
 {
-    pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Kind::Event>>>,
-    pub futures: Vec<SubscribeFuture>,
+    pub channels: HashMap<ExchangeId, EventChannel<MarketEvent<Kind::Event>>>,
+    pub capacity: ChannelCapacity,
+    pub futures: Vec<SubscribeFn>,
+    pub policy: ReconnectPolicy,
+    pub invalid_policy: InvalidEventPolicy,
+    pub timestamp_policy: Option<TimestampPolicy>,
+    pub filter: EventFilter<Kind::Event>,
+    pub acks: SubscriptionAcks,
+    pub connection_options: ConnectionOptions,
+    pub stale_after: Option<Duration>,
+    pub backfill: Option<usize>,
+    pub initial_snapshots: bool,
+    pub conflation: Option<Duration>,
+    pub gap_fill: Option<Duration>,
+    pub validate_markets: bool,
+    pub metrics: Option<Arc<dyn StreamMetrics>>,
+    pub raw_payloads: Option<RawChannel<MarketEvent<Kind::Event>>>,
+    pub watermark_policy: Option<WatermarkPolicy>,
+    pub connection_events: ExchangeChannel<ConnectionEvent>,
+    pub errors: ErrorChannel,
+    pub watermarks: ExchangeChannel<Watermark>,
+    pub commands: HashMap<ExchangeId, broadcast::Sender<StreamCommand>>,
+    pub stats: StatsRegistry,
+    pub handler: Arc<OnceLock<HandlerSender<MarketEvent<Kind::Event>>>>,
 }
 
 impl<Kind> Debug for StreamBuilder<Kind>
@@ -37,7 +179,24 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StreamBuilder<SubKind>")
             .field("channels", &self.channels)
+            .field("capacity", &self.capacity)
             .field("num_futures", &self.futures.len())
+            .field("policy", &self.policy)
+            .field("invalid_policy", &self.invalid_policy)
+            .field("timestamp_policy", &self.timestamp_policy)
+            .field("filter", &self.filter)
+            .field("acks", &self.acks)
+            .field("connection_options", &self.connection_options)
+            .field("stale_after", &self.stale_after)
+            .field("backfill", &self.backfill)
+            .field("initial_snapshots", &self.initial_snapshots)
+            .field("conflation", &self.conflation)
+            .field("gap_fill", &self.gap_fill)
+            .field("validate_markets", &self.validate_markets)
+            .field("metrics", &self.metrics)
+            .field("raw_payloads", &self.raw_payloads.is_some())
+            .field("watermark_policy", &self.watermark_policy)
+            .field("handler", &self.handler.get().is_some())
             .finish()
     }
 }
@@ -50,16 +209,237 @@ where
     pub fn new() -> Self {
         Self {
             channels: HashMap::new(),
+            capacity: ChannelCapacity::default(),
             futures: Vec::new(),
+            policy: ReconnectPolicy::default(),
+            invalid_policy: InvalidEventPolicy::default(),
+            timestamp_policy: None,
+            filter: EventFilter::default(),
+            acks: SubscriptionAcks::default(),
+            connection_options: ConnectionOptions::default(),
+            stale_after: None,
+            backfill: None,
+            initial_snapshots: false,
+            conflation: None,
+            gap_fill: None,
+            validate_markets: false,
+            metrics: None,
+            raw_payloads: None,
+            watermark_policy: None,
+            connection_events: ExchangeChannel::default(),
+            errors: ErrorChannel::default(),
+            watermarks: ExchangeChannel::default(),
+            commands: HashMap::new(),
+            stats: StatsRegistry::default(),
+            handler: Arc::default(),
         }
     }
 
+    /// Configure the [`ReconnectPolicy`] used to re-connect the
+    /// [`MarketStream`](crate::MarketStream)s of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// Defaults to infinite re-connection attempts with a jittered exponential backoff.
+    pub fn reconnect_policy(mut self, policy: ReconnectPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Configure the [`InvalidEventPolicy`] used to handle events that violate the invariants of
+    /// their [`SubKind`] (eg/ [`Candle`](crate::subscription::candle::Candle)s with high < low)
+    /// for the [`MarketStream`](crate::MarketStream)s of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// Defaults to [`InvalidEventPolicy::Reject`], surfacing a [`DataError`] via
+    /// [`Streams::errors`] rather than emitting the invalid event.
+    pub fn invalid_event_policy(mut self, policy: InvalidEventPolicy) -> Self {
+        self.invalid_policy = policy;
+        self
+    }
+
+    /// Configure the [`TimestampPolicy`] used to sanity check the `exchange_time` of every
+    /// [`MarketEvent`] against its `received_time` for the
+    /// [`MarketStream`](crate::MarketStream)s of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls. Out of range timestamps are either
+    /// clamped to the `received_time`, or surfaced as a [`DataError::TimestampOutOfRange`] via
+    /// [`Streams::errors`].
+    ///
+    /// Defaults to `None`, meaning timestamps are not checked. See [`TimestampPolicy::default`]
+    /// for a ±5 minute tolerance that clamps out of range timestamps.
+    pub fn timestamp_policy(mut self, policy: TimestampPolicy) -> Self {
+        self.timestamp_policy = Some(policy);
+        self
+    }
+
+    /// Install a predicate that every [`MarketEvent`] of the [`MarketStream`](crate::MarketStream)s
+    /// of any subsequent [`subscribe()`](StreamBuilder::subscribe()) calls must satisfy to be sent
+    /// downstream (eg/ only trades above a notional threshold). Events are filtered by the
+    /// consumer loop before they are sent to the exchange output channel, and errors are never
+    /// filtered.
+    ///
+    /// Dropped events are counted via [`Streams::stats`]. Combined with any filter of
+    /// [`subscribe_filtered()`](StreamBuilder::subscribe_filtered()), so events must satisfy
+    /// both. Defaults to `None`, meaning every event is sent.
+    pub fn with_filter<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&MarketEvent<Kind::Event>) -> bool + Send + Sync + 'static,
+    {
+        self.filter = EventFilter::new(filter);
+        self
+    }
+
+    /// Configure the [`AckPolicy`] determining if the [`MarketStream`](crate::MarketStream)s of
+    /// any subsequent [`subscribe()`](StreamBuilder::subscribe()) calls initialise when only
+    /// some of their [`Subscription`]s are acknowledged by the exchange. The
+    /// [`SubscriptionOutcome`](crate::subscriber::validator::SubscriptionOutcome) of each
+    /// connection is sent as a [`ConnectionEvent::SubscriptionOutcome`] via
+    /// [`Streams::connection_events`].
+    ///
+    /// Defaults to [`AckPolicy::RequireAll`].
+    pub fn ack_policy(mut self, policy: AckPolicy) -> Self {
+        self.acks.policy = policy;
+        self
+    }
+
+    /// Configure the maximum duration to wait for the exchange to acknowledge the
+    /// [`Subscription`]s of any subsequent [`subscribe()`](StreamBuilder::subscribe()) calls.
+    /// Unacknowledged [`Subscription`]s are reported as timed out.
+    ///
+    /// Defaults to the exchange
+    /// [`Connector::subscription_timeout`](crate::exchange::Connector::subscription_timeout).
+    pub fn subscription_timeout(mut self, timeout: Duration) -> Self {
+        self.acks.timeout = Some(timeout);
+        self
+    }
+
+    /// Configure the [`ConnectionOptions`] (eg/ proxy, connect timeout, custom headers, TLS
+    /// connector) used to establish the WebSocket connections of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls, including every re-connection.
+    ///
+    /// Defaults to [`ConnectionOptions::default`], meaning a direct connection using the default
+    /// TLS configuration.
+    pub fn with_connection_options(mut self, options: ConnectionOptions) -> Self {
+        self.connection_options = options;
+        self
+    }
+
+    /// Configure the [`Environment`] of the exchange servers connected with by any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls (eg/ [`Environment::Sandbox`] for
+    /// integration tests against exchange testnets), overriding the
+    /// [`ConnectionOptions::environment`].
+    ///
+    /// Initialising fails for exchanges without a server in the configured [`Environment`],
+    /// rather than silently connecting to another.
+    ///
+    /// Defaults to [`Environment::Production`].
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.connection_options.environment = environment;
+        self
+    }
+
+    /// Configure the staleness threshold used to monitor the liveness of the
+    /// [`MarketStream`](crate::MarketStream)s of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls. A
+    /// [`ConnectionEvent::Stale`] is sent via [`Streams::connection_events`] if no message
+    /// (including pings & pongs) is received from the exchange server within the threshold.
+    ///
+    /// Defaults to `None`, meaning staleness is not monitored.
+    pub fn stale_after(mut self, threshold: Duration) -> Self {
+        self.stale_after = Some(threshold);
+        self
+    }
+
+    /// Configure if the [`Subscription`] markets of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls are verified to exist on the exchange
+    /// during [`init()`](StreamBuilder::init()), using the markets fetched via
+    /// [`Connector::fetch_markets`](crate::exchange::Connector::fetch_markets). Non-existent
+    /// markets are handled according to the [`SubscriptionPolicy`], rather than waiting for data
+    /// that will never arrive.
+    ///
+    /// Defaults to `false`. Exchanges that cannot fetch their markets skip verification with a
+    /// warning.
+    pub fn validate_markets(mut self, enabled: bool) -> Self {
+        self.validate_markets = enabled;
+        self
+    }
+
+    /// Install [`StreamMetrics`] that are invoked for every normalised event & error of the
+    /// [`MarketStream`](crate::MarketStream)s of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls, reporting the per
+    /// [`SubscriptionId`](barter_integration::model::SubscriptionId) latency & payload size.
+    ///
+    /// Defaults to `None`, meaning no metrics are collected. See
+    /// [`TracingMetrics`](super::metrics::TracingMetrics) for a default implementation.
+    pub fn with_metrics(mut self, metrics: Arc<dyn StreamMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Configure if every [`MarketEvent`] of the [`MarketStream`](crate::MarketStream)s of any
+    /// subsequent [`subscribe()`](StreamBuilder::subscribe()) calls is also sent alongside the
+    /// raw exchange [`RawPayload`](super::raw::RawPayload) it was normalised from, via
+    /// [`Streams::raw_payloads`]. Useful for debugging parsers & auditing.
+    ///
+    /// The channel is bounded by [`DEFAULT_RAW_PAYLOAD_CAPACITY`](super::raw::DEFAULT_RAW_PAYLOAD_CAPACITY),
+    /// with pairs dropped whilst it is full. Defaults to `false`, meaning no raw payloads are
+    /// captured.
+    pub fn with_raw_payloads(mut self, enabled: bool) -> Self {
+        self.raw_payloads = match enabled {
+            true => Some(self.raw_payloads.take().unwrap_or_default()),
+            false => None,
+        };
+        self
+    }
+
+    /// Conflate the [`MarketEvent`]s of each [`Subscription`] of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls, so at most one event is sent per
+    /// `window`. Events within a window are replaced by the most recent, which is sent at the
+    /// window boundary. The first event after a quiet period is sent immediately.
+    ///
+    /// Useful for high frequency streams (eg/ Binance OrderBooksL1) where only the latest state
+    /// is of interest. Defaults to `None`, meaning every event is sent.
+    pub fn with_conflation(mut self, window: Duration) -> Self {
+        self.conflation = Some(window);
+        self
+    }
+
+    /// Emit the event-time [`Watermark`]s of each connection of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls via [`Streams::watermarks`], according
+    /// to the [`WatermarkPolicy`]. Events received with an `exchange_time` before an already
+    /// emitted [`Watermark`] are counted as late via [`Streams::stats`].
+    ///
+    /// Watermarks are tracked as events are consumed, so events held back by
+    /// [`with_conflation`](Self::with_conflation) may be sent after a later [`Watermark`].
+    /// Defaults to `None`, meaning no watermarks are emitted. See [`WatermarkPolicy::default`]
+    /// for a watermark each second.
+    pub fn with_watermarks(mut self, policy: WatermarkPolicy) -> Self {
+        self.watermark_policy = Some(policy);
+        self
+    }
+
+    /// Bound the output channel of each exchange of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls to `capacity` events, handling events
+    /// sent whilst it is full according to the [`OverflowPolicy`].
+    ///
+    /// Events dropped by the [`OverflowPolicy`] are counted per exchange, and exposed via
+    /// [`Streams::stats`]. Defaults to unbounded channels, meaning a slow consumer buffers
+    /// events without limit.
+    pub fn with_bounded_output(mut self, capacity: usize, policy: OverflowPolicy) -> Self {
+        self.capacity = ChannelCapacity::Bounded { capacity, policy };
+        self
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
+    /// Each call may subscribe to a different exchange, provided it implements
+    /// [`StreamSelector<Kind>`], and the events of every exchange can be joined via
+    /// [`Streams::join`] whilst remaining distinguishable by their
+    /// [`MarketEvent::exchange`](MarketEvent).
+    ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
-    pub fn subscribe<SubIter, Sub, Exchange>(mut self, subscriptions: SubIter) -> Self
+    pub fn subscribe<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
         Sub: Into<Subscription<Exchange, Kind>>,
@@ -68,26 +448,257 @@ where
         Kind::Event: Send,
         Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Construct Vec<Subscriptions> from input SubIter
-        let mut subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        self.subscribe_batched(subscriptions, BatchPolicy::single_connection())
+    }
 
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be split across
+    /// as many [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connections as
+    /// required by the [`BatchPolicy`]. Each connection is initialised `subscribe_rate` after
+    /// the previous one.
+    ///
+    /// Events from every connection are merged into the same exchange receiver, accessible via
+    /// [`Streams::select`].
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_batched<SubIter, Sub, Exchange>(
+        self,
+        subscriptions: SubIter,
+        batch_policy: BatchPolicy,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        self.subscribe_with(
+            subscriptions.into_iter().map(Sub::into).collect(),
+            batch_policy,
+            EventFilter::default(),
+            |_, exchange_tx| exchange_tx,
+        )
+    }
+
+    /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
+    /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection,
+    /// only sending the [`MarketEvent`]s that satisfy the provided `filter` predicate downstream.
+    ///
+    /// The `filter` applies to these [`Subscription`]s only, in addition to any filter installed
+    /// via [`with_filter()`](StreamBuilder::with_filter()).
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_filtered<SubIter, Sub, Exchange, F>(
+        self,
+        subscriptions: SubIter,
+        filter: F,
+    ) -> Self
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<Exchange, Kind>>,
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        F: Fn(&MarketEvent<Kind::Event>) -> bool + Send + Sync + 'static,
+    {
+        self.subscribe_with(
+            subscriptions.into_iter().map(Sub::into).collect(),
+            BatchPolicy::single_connection(),
+            EventFilter::new(filter),
+            |_, exchange_tx| exchange_tx,
+        )
+    }
+
+    /// Add a collection of [`Subscription`]s batched according to the [`BatchPolicy`], filtered
+    /// by the provided [`EventFilter`] alongside any [`with_filter()`](Self::with_filter())
+    /// filter, and wrapping the `exchange_tx` of each batch via the provided `wrap` function
+    /// before any other configured wrappers (eg/ backfill), so it sits nearest the exchange
+    /// output channel.
+    fn subscribe_with<Exchange, Wrap>(
+        mut self,
+        subscriptions: Vec<Subscription<Exchange, Kind>>,
+        batch_policy: BatchPolicy,
+        filter: EventFilter<Kind::Event>,
+        wrap: Wrap,
+    ) -> Self
+    where
+        Exchange: StreamSelector<Kind> + Ord + Send + Sync + 'static,
+        Kind: Ord + Send + Sync + 'static,
+        Kind::Event: Send,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        Wrap: Fn(
+                &[Subscription<Exchange, Kind>],
+                EventSender<MarketEvent<Kind::Event>>,
+            ) -> EventSender<MarketEvent<Kind::Event>>
+            + Clone
+            + Send
+            + 'static,
+    {
         // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
-        // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
-        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+        // '--> Add EventChannel Entry if this Exchange <--> SubKind combination is new
+        let capacity = self.capacity;
+        let exchange_tx = self
+            .channels
+            .entry(Exchange::ID)
+            .or_insert_with(|| EventChannel::new(capacity))
+            .tx
+            .clone();
 
-        // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
-        self.futures.push(Box::pin(async move {
-            // Validate Subscriptions
-            validate(&subscriptions)?;
+        // Acquire StreamCommand Sender shared by every connection of this Exchange
+        let commands_tx = self
+            .commands
+            .entry(Exchange::ID)
+            .or_insert_with(|| broadcast::channel(STREAM_COMMAND_CAPACITY).0)
+            .clone();
+
+        // Re-connection policy & liveness monitoring for the MarketStream consumer loop
+        let policy = self.policy;
+        let invalid_policy = self.invalid_policy;
+        let timestamp_policy = self.timestamp_policy;
+        let filter = self.filter.clone().and(filter);
+        let acks = self.acks;
+        let connection_options = self.connection_options.clone();
+        let backfill = self.backfill;
+        let initial_snapshots = self.initial_snapshots;
+        let conflation = self.conflation;
+        let gap_fill = self.gap_fill;
+        let validate_markets = self.validate_markets;
+        let metrics = self.metrics.clone();
+        let raw_tx = self.raw_payloads.as_ref().map(|channel| channel.tx.clone());
+        let stats = self.stats.clone();
+        let watermarks = self.watermark_policy.map(|policy| WatermarkEmitter {
+            policy,
+            tx: self.watermarks.tx.clone(),
+        });
+        let health = HealthMonitor {
+            stale_after: self.stale_after,
+            events_tx: self.connection_events.tx.clone(),
+            errors_tx: self.errors.tx.clone(),
+        };
+        let handler = Arc::clone(&self.handler);
+
+        // Add Future that once awaited will yield the Result<Vec<InvalidSubscription>, DataError>
+        // of subscribing
+        self.futures.push(Box::new(move |sub_policy| {
+            Box::pin(async move {
+                // Validate Subscriptions according to the SubscriptionPolicy
+                let (subscriptions, mut invalid) = validate_with_policy(subscriptions, sub_policy)?;
+
+                // Verify each Subscription market exists on the exchange, if configured
+                let (mut subscriptions, invalid_markets) = match validate_markets {
+                    true if !subscriptions.is_empty() => {
+                        validate_markets_with_policy(subscriptions, sub_policy).await?
+                    }
+                    _ => (subscriptions, vec![]),
+                };
+                invalid.extend(invalid_markets);
+
+                // Skip spawning a consumer loop if every Subscription was invalid
+                if subscriptions.is_empty() {
+                    return Ok(invalid);
+                }
+
+                // Fail if the exchange has no server in the configured Environment
+                Exchange::url_for(connection_options.environment)?;
+
+                // Remove duplicate Subscriptions
+                let num_subscriptions = subscriptions.len();
+                subscriptions.sort();
+                subscriptions.dedup();
+                if subscriptions.len() < num_subscriptions {
+                    warn!(
+                        exchange = %Exchange::ID,
+                        num_duplicates = num_subscriptions - subscriptions.len(),
+                        action = "dropping duplicates",
+                        "duplicate Subscriptions provided",
+                    );
+                }
+
+                // Spawn a MarketStream consumer loop for each batch of Subscriptions<Exchange, Kind>
+                let batches =
+                    batch_subscriptions(subscriptions, batch_policy.batch_size::<Exchange>());
+                for (index, batch) in batches.into_iter().enumerate() {
+                    // Dispatch directly to the EventHandler, if initialised with one
+                    let exchange_tx = match handler.get() {
+                        Some(handler) => EventSender::Handler(handler.clone()),
+                        None => exchange_tx.clone(),
+                    };
+                    let health = health.clone();
+                    let metrics = metrics.clone();
+                    let filter = filter.clone();
+                    let options = connection_options.clone();
+                    let raw_tx = raw_tx.clone();
+                    let commands = commands_tx.subscribe();
+                    let stats = stats.register(&batch);
+                    let watermarks = watermarks.clone();
+                    let wrap = wrap.clone();
+                    let delay = batch_policy.delay(index);
+                    tokio::spawn(async move {
+                        tokio::time::sleep(delay).await;
+
+                        // Apply the subscribe specific wrapper nearest the output channel
+                        let exchange_tx = wrap(&batch, exchange_tx);
+
+                        // Attribute events to tagged Subscriptions, including backfilled events
+                        let exchange_tx = spawn_tagging(&batch, exchange_tx);
 
-            // Remove duplicate Subscriptions
-            subscriptions.sort();
-            subscriptions.dedup();
+                        // Send backfilled historical events before the live events
+                        let exchange_tx = match backfill {
+                            Some(limit) => spawn_backfill(batch.clone(), limit, exchange_tx),
+                            None => exchange_tx,
+                        };
 
-            // Spawn a MarketStream consumer loop with these Subscriptions<Exchange, Kind>
-            tokio::spawn(consume(subscriptions, exchange_tx));
+                        // Send a REST snapshot of each Subscription before the live events
+                        let exchange_tx = match initial_snapshots {
+                            true => spawn_initial_snapshots(batch.clone(), exchange_tx),
+                            false => exchange_tx,
+                        };
 
-            Ok(())
+                        // Conflate live events to at most one per window, if configured
+                        let exchange_tx = match conflation {
+                            Some(window) => spawn_conflation(window, exchange_tx),
+                            None => exchange_tx,
+                        };
+
+                        // Synthesize events missing from the live stream, if configured
+                        let exchange_tx = match gap_fill {
+                            Some(grace) => Kind::spawn_gap_fill(
+                                batch
+                                    .iter()
+                                    .map(|sub| (sub.instrument.clone(), sub.kind.clone()))
+                                    .collect(),
+                                grace,
+                                exchange_tx,
+                            ),
+                            None => exchange_tx,
+                        };
+
+                        consume(
+                            batch,
+                            exchange_tx,
+                            policy,
+                            invalid_policy,
+                            timestamp_policy,
+                            filter,
+                            acks,
+                            options,
+                            health,
+                            metrics,
+                            stats,
+                            watermarks,
+                            raw_tx,
+                            commands,
+                        )
+                        .await
+                    });
+                }
+
+                Ok(invalid)
+            })
         }));
 
         self
@@ -99,18 +710,172 @@ where
     ///
     /// Each consumer loop distributes consumed [`MarketEvent<SubKind::Event>s`](MarketEvent) to
     /// the [`Streams`] `HashMap` returned by this method.
+    ///
+    /// Fails with a [`DataError::InvalidSubscription`] identifying the offending [`Subscription`]
+    /// if any [`Subscription`] is invalid. See [`Self::init_with_policy`] to skip invalid
+    /// [`Subscription`]s instead.
     pub async fn init(self) -> Result<Streams<MarketEvent<Kind::Event>>, DataError> {
+        self.init_with_policy(SubscriptionPolicy::Strict)
+            .await
+            .map(|(streams, _)| streams)
+    }
+
+    /// Spawn a [`MarketEvent<SubKind::Event>`](MarketEvent) consumer loop for each collection of
+    /// [`Subscription`]s added to [`StreamBuilder`], handling invalid [`Subscription`]s according
+    /// to the provided [`SubscriptionPolicy`].
+    ///
+    /// Returns the [`Streams`] alongside any [`InvalidSubscription`]s that were skipped.
+    pub async fn init_with_policy(
+        self,
+        policy: SubscriptionPolicy,
+    ) -> Result<(Streams<MarketEvent<Kind::Event>>, Vec<InvalidSubscription>), DataError> {
         // Await Stream initialisation futures and ensure success
-        futures::future::try_join_all(self.futures).await?;
+        let skipped = futures::future::try_join_all(
+            self.futures.into_iter().map(|subscribe| subscribe(policy)),
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
 
-        // Construct Streams using each ExchangeChannel receiver
-        Ok(Streams {
-            streams: self
-                .channels
+        // Construct Streams using each EventChannel receiver
+        let streams = Streams::new(
+            self.channels
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
-        })
+            Some(self.connection_events.rx),
+            Some(self.errors.rx),
+            self.raw_payloads.map(|channel| channel.rx),
+            self.watermark_policy.map(|_| self.watermarks.rx),
+            self.commands,
+            self.capacity,
+            self.stats,
+        );
+
+        Ok((streams, skipped))
+    }
+
+    /// Spawn a [`MarketEvent<SubKind::Event>`](MarketEvent) consumer loop for each collection of
+    /// [`Subscription`]s added to [`StreamBuilder`], calling the provided [`EventHandler`] inline
+    /// on each connection task with every consumed [`MarketEvent<SubKind::Event>`](MarketEvent)
+    /// & [`DataError`], rather than sending them via the exchange output channels.
+    ///
+    /// This avoids the channel hop (and allocation) per event for latency sensitive consumers,
+    /// with the trade-off that a slow handler backs up the
+    /// [`MarketStream`](crate::MarketStream) WebSocket of every connection. Opt-in stream
+    /// adapters (eg/ [`with_conflation()`](Self::with_conflation())) still forward events via an
+    /// intermediate channel.
+    ///
+    /// The returned [`Streams`] yields no [`MarketEvent`]s, but still provides the
+    /// [`ConnectionEvent`]s, stats & [`StreamController`](super::controller::StreamController)
+    /// of every connection.
+    ///
+    /// Fails with a [`DataError::InvalidSubscription`] identifying the offending [`Subscription`]
+    /// if any [`Subscription`] is invalid.
+    pub async fn init_with_handler<Handler>(
+        self,
+        handler: Handler,
+    ) -> Result<Streams<MarketEvent<Kind::Event>>, DataError>
+    where
+        Handler: EventHandler<MarketEvent<Kind::Event>> + 'static,
+    {
+        let _ = self.handler.set(HandlerSender::new(handler));
+        self.init().await
+    }
+}
+
+impl StreamBuilder<Candles> {
+    /// Backfill the last `n_candles` closed [`Candle`](crate::subscription::candle::Candle)s of
+    /// each [`Subscription`] of any subsequent [`subscribe()`](StreamBuilder::subscribe()) calls,
+    /// fetched from the exchange REST API and sent before the live
+    /// [`MarketEvent<Candle>`](MarketEvent)s.
+    ///
+    /// Live candles already covered by the backfill are skipped, so there is no gap or overlap.
+    /// Exchanges without a [`BackfillClient`](super::backfill::BackfillClient) skip backfill
+    /// with a warning.
+    pub fn with_backfill(mut self, n_candles: usize) -> Self {
+        self.backfill = Some(n_candles);
+        self
+    }
+
+    /// Synthesize the [`Candle`](crate::subscription::candle::Candle)s the exchange does not emit
+    /// for an [`Interval`](crate::subscription::Interval) boundary (eg/ illiquid pairs with zero
+    /// trades) within the `grace` period after the boundary closes, for any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls.
+    ///
+    /// Synthesized candles carry the previous close as open, high, low & close, zero volume, and
+    /// are marked via [`Candle::synthetic`](crate::subscription::candle::Candle::synthetic). See
+    /// [`CandleGapFill`](super::gap::CandleGapFill) for the gap filling behaviour.
+    pub fn with_gap_fill(mut self, grace: Duration) -> Self {
+        self.gap_fill = Some(grace);
+        self
+    }
+
+    /// Add a collection of [`Subscription`]s, each with an optional `resume_from` time (eg/ the
+    /// `close_time` of the last [`Candle`](crate::subscription::candle::Candle) ingested before a
+    /// restart), that will be actioned on a distinct
+    /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
+    ///
+    /// Candles with a `close_time` at or before `resume_from` are suppressed, so only candles
+    /// closing strictly after it are sent. If the exchange has a
+    /// [`BackfillClient`](super::backfill::BackfillClient), the candles that closed between
+    /// `resume_from` and the first live candle are fetched and sent first, in order. See
+    /// [`spawn_resume`] for details.
+    ///
+    /// Note that [`Subscription`]s are not actioned until the
+    /// [`init()`](StreamBuilder::init()) method is invoked.
+    pub fn subscribe_resuming<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
+    where
+        SubIter: IntoIterator<Item = (Sub, Option<DateTime<Utc>>)>,
+        Sub: Into<Subscription<Exchange, Candles>>,
+        Exchange: StreamSelector<Candles> + Ord + Hash + Send + Sync + 'static,
+        Subscription<Exchange, Candles>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let (subscriptions, resume_from): (Vec<_>, HashMap<_, _>) = subscriptions
+            .into_iter()
+            .map(|(sub, resume_from)| {
+                let subscription = sub.into();
+                (subscription.clone(), (subscription, resume_from))
+            })
+            .unzip();
+        let resume_from = Arc::new(resume_from);
+
+        self.subscribe_with(
+            subscriptions,
+            BatchPolicy::single_connection(),
+            EventFilter::default(),
+            move |batch, exchange_tx| {
+                spawn_resume(
+                    batch
+                        .iter()
+                        .filter_map(|subscription| {
+                            resume_from
+                                .get(subscription)
+                                .copied()
+                                .flatten()
+                                .map(|resume_from| (subscription.clone(), resume_from))
+                        })
+                        .collect(),
+                    exchange_tx,
+                )
+            },
+        )
+    }
+}
+
+impl StreamBuilder<OrderBooksL1> {
+    /// Send a snapshot of the current top of book of each [`Subscription`] of any subsequent
+    /// [`subscribe()`](StreamBuilder::subscribe()) calls, fetched from the exchange REST API,
+    /// as the first [`MarketEvent<OrderBookL1>`](MarketEvent) of the [`Subscription`].
+    ///
+    /// Snapshots are marked via [`TimeSource::Snapshot`](crate::event::TimeSource::Snapshot)
+    /// and always sent before any live events. Exchanges without a
+    /// [`SnapshotClient`](super::snapshot::SnapshotClient) skip initial snapshots.
+    pub fn with_initial_snapshots(mut self, enabled: bool) -> Self {
+        self.initial_snapshots = enabled;
+        self
     }
 }
 
@@ -136,14 +901,54 @@ impl<T> Default for ExchangeChannel<T> {
     }
 }
 
+/// Convenient type that holds the [`EventSender`] and [`EventReceiver`] for an exchange
+/// [`MarketEvent<T>`](MarketEvent) output channel of the configured [`ChannelCapacity`].
+#[derive(Debug)]
+pub struct EventChannel<T> {
+    tx: EventSender<T>,
+    rx: EventReceiver<T>,
+}
+
+impl<T> EventChannel<T> {
+    /// Construct a new [`Self`] with the provided [`ChannelCapacity`].
+    pub fn new(capacity: ChannelCapacity) -> Self {
+        let (tx, rx) = capacity.channel();
+        Self { tx, rx }
+    }
+}
+
+impl<T> Default for EventChannel<T> {
+    fn default() -> Self {
+        Self::new(ChannelCapacity::default())
+    }
+}
+
+/// Split the provided collection of [`Subscription`]s into batches of at most `batch_size`
+/// [`Subscription`]s, each of which is actioned on a distinct connection.
+pub fn batch_subscriptions<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    batch_size: usize,
+) -> Vec<Vec<Subscription<Exchange, Kind>>> {
+    let mut batches = Vec::with_capacity(subscriptions.len() / batch_size.max(1) + 1);
+    let mut subscriptions = subscriptions.into_iter().peekable();
+    while subscriptions.peek().is_some() {
+        batches.push(subscriptions.by_ref().take(batch_size.max(1)).collect());
+    }
+    batches
+}
+
 /// Validate the provided collection of [`Subscription`]s, ensuring that the associated exchange
-/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind).
+/// supports every [`Subscription`] [`InstrumentKind`](barter_integration::model::InstrumentKind),
+/// as well as any exchange specific constraints (eg/ supported candle intervals).
+///
+/// Fails with a [`DataError::InvalidSubscription`] identifying the first invalid [`Subscription`].
 pub fn validate<Exchange, Kind>(
     subscriptions: &[Subscription<Exchange, Kind>],
 ) -> Result<(), DataError>
 where
     Exchange: StreamSelector<Kind>,
     Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel>,
 {
     // Ensure at least one Subscription has been provided
     if subscriptions.is_empty() {
@@ -152,21 +957,373 @@ where
         )));
     }
 
-    // Validate the Exchange supports each Subscription InstrumentKind
+    // Validate the Exchange supports each Subscription
     subscriptions
         .iter()
-        .map(|subscription| subscription.validate())
-        .collect::<Result<Vec<_>, SocketError>>()?;
+        .try_for_each(validate_subscription)
+        .map_err(DataError::InvalidSubscription)
+}
+
+/// Validate the provided collection of [`Subscription`]s according to the
+/// [`SubscriptionPolicy`], returning the valid [`Subscription`]s alongside any
+/// [`InvalidSubscription`]s that were skipped.
+///
+/// With [`SubscriptionPolicy::Strict`] the first invalid [`Subscription`] fails validation.
+pub fn validate_with_policy<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    policy: SubscriptionPolicy,
+) -> Result<ValidatedSubscriptions<Exchange, Kind>, DataError>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel>,
+{
+    match policy {
+        SubscriptionPolicy::Strict => {
+            validate(&subscriptions)?;
+            Ok((subscriptions, vec![]))
+        }
+        SubscriptionPolicy::SkipInvalid => {
+            // Ensure at least one Subscription has been provided
+            if subscriptions.is_empty() {
+                return Err(DataError::Socket(SocketError::Subscribe(
+                    "StreamBuilder contains no Subscription to action".to_owned(),
+                )));
+            }
 
-    Ok(())
+            let mut valid = Vec::with_capacity(subscriptions.len());
+            let mut invalid = Vec::new();
+            for subscription in subscriptions {
+                match validate_subscription(&subscription) {
+                    Ok(()) => valid.push(subscription),
+                    Err(skipped) => {
+                        warn!(
+                            subscription = %skipped,
+                            action = "skipping Subscription",
+                            "invalid Subscription",
+                        );
+                        invalid.push(skipped);
+                    }
+                }
+            }
+
+            Ok((valid, invalid))
+        }
+    }
+}
+
+/// Validate a single [`Subscription`], mapping any failure into an [`InvalidSubscription`]
+/// identifying the offending exchange, instrument and [`SubKind`].
+fn validate_subscription<Exchange, Kind>(
+    subscription: &Subscription<Exchange, Kind>,
+) -> Result<(), InvalidSubscription>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel>,
+{
+    subscription
+        .validate()
+        .and_then(|subscription| Exchange::validate_market(&subscription.instrument))
+        .and_then(|_| Exchange::validate_subscriptions(std::slice::from_ref(subscription)))
+        .map_err(|error| InvalidSubscription::new(subscription, error))
+}
+
+/// Verify the market of each [`Subscription`] exists on the exchange, using the markets fetched
+/// via [`Connector::fetch_markets`](crate::exchange::Connector::fetch_markets), and handle any
+/// non-existent markets according to the [`SubscriptionPolicy`].
+///
+/// If the exchange cannot fetch its markets, verification is skipped with a warning.
+pub async fn validate_markets_with_policy<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    policy: SubscriptionPolicy,
+) -> Result<ValidatedSubscriptions<Exchange, Kind>, DataError>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Market>,
+{
+    let Some(fetch_markets) = Exchange::fetch_markets() else {
+        warn!(
+            exchange = %Exchange::ID,
+            action = "skipping market validation",
+            "exchange cannot fetch the markets it lists",
+        );
+        return Ok((subscriptions, vec![]));
+    };
+
+    let markets = fetch_markets.await?;
+    filter_markets(subscriptions, &markets, policy)
+}
+
+/// Split the provided [`Subscription`]s into those with a market contained in the exchange
+/// `markets`, and [`InvalidSubscription`]s for those without.
+///
+/// With [`SubscriptionPolicy::Strict`] the first non-existent market fails validation.
+pub fn filter_markets<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    markets: &HashSet<String>,
+    policy: SubscriptionPolicy,
+) -> Result<ValidatedSubscriptions<Exchange, Kind>, DataError>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Market>,
+{
+    let mut valid = Vec::with_capacity(subscriptions.len());
+    let mut invalid = Vec::new();
+    for subscription in subscriptions {
+        let market = Identifier::<Exchange::Market>::id(&subscription);
+        if markets.contains(market.as_ref()) {
+            valid.push(subscription);
+            continue;
+        }
+
+        let skipped = InvalidSubscription::new(
+            &subscription,
+            SocketError::Unsupported {
+                entity: Exchange::ID.as_str(),
+                item: format!("market {} (not listed by exchange)", market.as_ref()),
+            },
+        );
+
+        match policy {
+            SubscriptionPolicy::Strict => return Err(DataError::InvalidSubscription(skipped)),
+            SubscriptionPolicy::SkipInvalid => {
+                warn!(
+                    subscription = %skipped,
+                    action = "skipping Subscription",
+                    "invalid Subscription",
+                );
+                invalid.push(skipped);
+            }
+        }
+    }
+
+    Ok((valid, invalid))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::exchange::coinbase::Coinbase;
-    use crate::subscription::trade::PublicTrades;
-    use barter_integration::model::InstrumentKind;
+    use crate::exchange::{
+        binance::spot::BinanceSpot, coinbase::Coinbase, kraken::Kraken, okx::Okx,
+    };
+    use crate::{
+        decoder::PlainText,
+        event::{IntoMarketIter, MarketIter},
+        exchange::{Connector, ExchangeSub},
+        streams::consumer::TimestampAction,
+        subscriber::{validator::WebSocketSubValidator, WebSocketSubscriber},
+        subscription::{
+            candle::{Candle, CandleSource, Candles, VolumeDenomination},
+            number::{self, Number},
+            trade::{PublicTrade, PublicTrades},
+            Interval,
+        },
+        transformer::stateless::StatelessTransformer,
+        ExchangeWsStream,
+    };
+    use barter_integration::{
+        model::{Exchange, Instrument, InstrumentKind, Side, SubscriptionId},
+        protocol::websocket::WsMessage,
+    };
+    use barter_macro::{DeExchange, SerExchange};
+    use futures::{SinkExt, StreamExt};
+    use serde_json::json;
+    use std::cell::RefCell;
+    use tokio::net::TcpListener;
+    use url::Url;
+
+    thread_local! {
+        /// Url of the [`FakeExchange`] server of the current test, whose connections are spawned
+        /// onto the same current thread runtime.
+        static FAKE_EXCHANGE_URL: RefCell<Option<Url>> = const { RefCell::new(None) };
+    }
+
+    /// Exchange served by [`run_fake_exchange`], used to drive [`StreamBuilder::init`] end to
+    /// end.
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeExchange, SerExchange,
+    )]
+    struct FakeExchange;
+
+    impl Connector for FakeExchange {
+        const ID: ExchangeId = ExchangeId::Custom("fake_exchange");
+        type Channel = &'static str;
+        type Market = String;
+        type Subscriber = WebSocketSubscriber;
+        type SubValidator = WebSocketSubValidator;
+        type SubResponse = FakeSubResponse;
+        type Decoder = PlainText;
+
+        fn url() -> Result<Url, SocketError> {
+            Ok(FAKE_EXCHANGE_URL.with(|url| url.borrow().clone().expect("fake exchange running")))
+        }
+
+        fn requests(
+            exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>,
+        ) -> Vec<WsMessage> {
+            let args = exchange_subs
+                .iter()
+                .map(|exchange_sub| exchange_sub.id().0)
+                .collect::<Vec<_>>();
+
+            vec![WsMessage::Text(
+                json!({ "op": "subscribe", "args": args }).to_string(),
+            )]
+        }
+    }
+
+    impl StreamSelector<Candles> for FakeExchange {
+        type Stream = ExchangeWsStream<StatelessTransformer<Self, Candles, FakeMessage<Candle>>>;
+    }
+
+    impl StreamSelector<PublicTrades> for FakeExchange {
+        type Stream =
+            ExchangeWsStream<StatelessTransformer<Self, PublicTrades, FakeMessage<PublicTrade>>>;
+    }
+
+    impl<Kind> Identifier<&'static str> for Subscription<FakeExchange, Kind> {
+        fn id(&self) -> &'static str {
+            "fake"
+        }
+    }
+
+    impl<Kind> Identifier<String> for Subscription<FakeExchange, Kind> {
+        fn id(&self) -> String {
+            format!("{}{}", self.instrument.base, self.instrument.quote).to_uppercase()
+        }
+    }
+
+    /// [`FakeExchange`] subscription response, eg/ `{"event":"subscribed","arg":"fake|BTCUSD"}`.
+    #[derive(Debug, Deserialize)]
+    struct FakeSubResponse {
+        event: String,
+    }
+
+    impl Validator for FakeSubResponse {
+        fn validate(self) -> Result<Self, SocketError> {
+            match self.event.as_str() {
+                "subscribed" => Ok(self),
+                event => Err(SocketError::Subscribe(event.to_string())),
+            }
+        }
+    }
+
+    /// [`FakeExchange`] message of the provided event kind, see [`fake_message`].
+    #[derive(Debug, Deserialize)]
+    struct FakeMessage<T> {
+        arg: SubscriptionId,
+        time: DateTime<Utc>,
+        sequence: Option<u64>,
+        kind: T,
+    }
+
+    impl<T> Identifier<Option<SubscriptionId>> for FakeMessage<T> {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(self.arg.clone())
+        }
+    }
+
+    impl<T> IntoMarketIter<T> for FakeMessage<T> {
+        fn into_market_iter(self, exchange: ExchangeId, instrument: Instrument) -> MarketIter<T> {
+            MarketIter(vec![Ok(MarketEvent {
+                exchange_time: self.time,
+                received_time: Utc::now(),
+                exchange: Exchange::from(exchange),
+                instrument,
+                sequence: self.sequence,
+                tag: None,
+                kind: self.kind,
+            })])
+        }
+    }
+
+    /// [`FakeExchange`] message of the "btc_usd" [`Subscription`] with the provided exchange
+    /// time, sequence & event kind.
+    fn fake_message(
+        time: DateTime<Utc>,
+        sequence: Option<u64>,
+        kind: impl Serialize,
+    ) -> serde_json::Value {
+        json!({ "arg": "fake|BTCUSD", "time": time, "sequence": sequence, "kind": kind })
+    }
+
+    /// Bind a [`FakeExchange`] server that serves a single connection: acknowledge each
+    /// subscribed market, send each of the provided messages, then hold the connection open.
+    async fn run_fake_exchange(messages: Vec<serde_json::Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+        FAKE_EXCHANGE_URL.with(|fake_url| *fake_url.borrow_mut() = Some(url));
+
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(socket).await.unwrap();
+            let mut messages = messages.into_iter();
+
+            while let Some(Ok(message)) = websocket.next().await {
+                let WsMessage::Text(request) = message else {
+                    continue;
+                };
+
+                let request = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+                for arg in request["args"].as_array().cloned().unwrap_or_default() {
+                    let response = json!({ "event": "subscribed", "arg": arg });
+                    websocket
+                        .send(WsMessage::Text(response.to_string()))
+                        .await
+                        .unwrap();
+                }
+                for message in messages.by_ref() {
+                    websocket
+                        .send(WsMessage::Text(message.to_string()))
+                        .await
+                        .unwrap();
+                }
+            }
+        });
+    }
+
+    /// Closed 1m [`Candle`] with the provided high & low, opening & closing at 100.
+    fn candle(high: f64, low: f64) -> Candle {
+        let start_time = DateTime::<Utc>::from_utc(
+            chrono::NaiveDateTime::from_timestamp_opt(1_680_000_000, 0).unwrap(),
+            Utc,
+        );
+        Candle {
+            start_time,
+            close_time: Interval::Minute1.close_time(start_time),
+            open: number::from_f64(100.0),
+            high: number::from_f64(high),
+            low: number::from_f64(low),
+            close: number::from_f64(100.0),
+            volume: Number::from(1),
+            volume_denomination: VolumeDenomination::Base,
+            base_volume: None,
+            quote_volume: None,
+            taker_buy_volume: None,
+            taker_buy_quote_volume: None,
+            trade_count: 1,
+            closed: true,
+            source: CandleSource::LastTrade,
+            synthetic: false,
+            derived: false,
+        }
+    }
+
+    /// Buy [`PublicTrade`] with the provided id.
+    fn trade(id: u64) -> PublicTrade {
+        PublicTrade {
+            id: id.to_string(),
+            price: number::from_f64(100.0),
+            amount: number::from_f64(1.0),
+            side: Side::Buy,
+            buyer_order_id: None,
+            seller_order_id: None,
+            taker_order_kind: None,
+        }
+    }
 
     #[test]
     fn test_validate() {
@@ -228,4 +1385,502 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_batch_subscriptions() {
+        struct TestCase {
+            num_subscriptions: usize,
+            batch_size: usize,
+            expected: Vec<usize>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: fewer Subscriptions than the batch size yields a single batch
+                num_subscriptions: 3,
+                batch_size: 100,
+                expected: vec![3],
+            },
+            TestCase {
+                // TC1: Subscriptions are split into full batches with a remainder batch
+                num_subscriptions: 250,
+                batch_size: 100,
+                expected: vec![100, 100, 50],
+            },
+            TestCase {
+                // TC2: zero batch size is treated as a batch size of one
+                num_subscriptions: 2,
+                batch_size: 0,
+                expected: vec![1, 1],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let subscriptions = (0..test.num_subscriptions)
+                .map(|base| {
+                    Subscription::from((
+                        Coinbase,
+                        base.to_string().as_str(),
+                        "usd",
+                        InstrumentKind::Spot,
+                        PublicTrades,
+                    ))
+                })
+                .collect::<Vec<_>>();
+
+            let actual = batch_subscriptions(subscriptions, test.batch_size)
+                .into_iter()
+                .map(|batch| batch.len())
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_batch_policy() {
+        // Exchange default MAX_STREAMS_PER_CONNECTION is used by BatchPolicy::connector
+        let binance = BatchPolicy::connector::<BinanceSpot>();
+        assert_eq!(binance.batch_size::<BinanceSpot>(), 1024);
+        assert_eq!(BatchPolicy::connector::<Okx>().batch_size::<Okx>(), 100);
+
+        // Configured max_streams_per_connection is bounded by the exchange limit
+        let policy = BatchPolicy {
+            max_streams_per_connection: 5000,
+            subscribe_rate: Duration::from_millis(200),
+        };
+        assert_eq!(policy.batch_size::<BinanceSpot>(), 1024);
+        assert_eq!(policy.batch_size::<Coinbase>(), 5000);
+
+        // Each batch connection is delayed by the subscribe_rate after the previous batch
+        assert_eq!(policy.delay(0), Duration::ZERO);
+        assert_eq!(policy.delay(3), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn test_validate_with_policy_coinbase() {
+        struct TestCase {
+            policy: SubscriptionPolicy,
+            expected: Result<(usize, Vec<InvalidSubscription>), DataError>,
+        }
+
+        let futures_sub = Subscription::from((
+            Coinbase,
+            "btc",
+            "usd",
+            InstrumentKind::FuturePerpetual,
+            PublicTrades,
+        ));
+        let invalid = InvalidSubscription::new(
+            &futures_sub,
+            SocketError::Unsupported {
+                entity: "coinbase",
+                item: InstrumentKind::FuturePerpetual.to_string(),
+            },
+        );
+
+        let cases = vec![
+            TestCase {
+                // TC0: Strict policy fails with InvalidSubscription naming the offending sub
+                policy: SubscriptionPolicy::Strict,
+                expected: Err(DataError::InvalidSubscription(invalid.clone())),
+            },
+            TestCase {
+                // TC1: SkipInvalid policy drops the invalid sub & keeps the valid sub
+                policy: SubscriptionPolicy::SkipInvalid,
+                expected: Ok((1, vec![invalid.clone()])),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = vec![
+                Subscription::from((Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades)),
+                futures_sub.clone(),
+            ];
+
+            let actual = validate_with_policy(input, test.policy);
+
+            match (actual, test.expected) {
+                (Ok((valid, skipped)), Ok((expected_valid, expected_skipped))) => {
+                    assert_eq!(valid.len(), expected_valid, "TC{} failed", index);
+                    assert_eq!(skipped, expected_skipped, "TC{} failed", index);
+                }
+                (
+                    Err(DataError::InvalidSubscription(actual)),
+                    Err(DataError::InvalidSubscription(expected)),
+                ) => {
+                    assert_eq!(actual, expected, "TC{} failed", index);
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_with_policy_kraken() {
+        struct TestCase {
+            input: Vec<Subscription<Kraken, Candles>>,
+            policy: SubscriptionPolicy,
+            expected: Result<(Vec<Interval>, Vec<InvalidSubscription>), DataError>,
+        }
+
+        let candles = |interval| {
+            Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                Candles(interval),
+            ))
+        };
+
+        let invalid = |interval: Interval| InvalidSubscription {
+            exchange: ExchangeId::Kraken,
+            instrument: Instrument::from(("xbt", "usd", InstrumentKind::Spot)),
+            kind: format!("{:?}", Candles(interval)),
+            reason: String::new(),
+        };
+
+        let cases = vec![
+            TestCase {
+                // TC0: Strict policy fails w/ unsupported 3m Kraken candles
+                input: vec![candles(Interval::Minute1), candles(Interval::Minute3)],
+                policy: SubscriptionPolicy::Strict,
+                expected: Err(DataError::InvalidSubscription(invalid(Interval::Minute3))),
+            },
+            TestCase {
+                // TC1: SkipInvalid policy drops unsupported 3m & 1M Kraken candles
+                input: vec![
+                    candles(Interval::Minute3),
+                    candles(Interval::Minute1),
+                    candles(Interval::Month1),
+                    candles(Interval::Hour4),
+                ],
+                policy: SubscriptionPolicy::SkipInvalid,
+                expected: Ok((
+                    vec![Interval::Minute1, Interval::Hour4],
+                    vec![invalid(Interval::Minute3), invalid(Interval::Month1)],
+                )),
+            },
+            TestCase {
+                // TC2: SkipInvalid policy w/ every Subscription invalid yields no valid subs
+                input: vec![candles(Interval::Minute3)],
+                policy: SubscriptionPolicy::SkipInvalid,
+                expected: Ok((vec![], vec![invalid(Interval::Minute3)])),
+            },
+            TestCase {
+                // TC3: SkipInvalid policy w/ empty input still fails
+                input: vec![],
+                policy: SubscriptionPolicy::SkipInvalid,
+                expected: Err(DataError::Socket(SocketError::Subscribe("".to_string()))),
+            },
+        ];
+
+        // Compare InvalidSubscriptions ignoring the exchange specific reason message
+        let without_reason = |subs: Vec<InvalidSubscription>| {
+            subs.into_iter()
+                .map(|sub| InvalidSubscription {
+                    reason: String::new(),
+                    ..sub
+                })
+                .collect::<Vec<_>>()
+        };
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = validate_with_policy(test.input, test.policy);
+
+            match (actual, test.expected) {
+                (Ok((valid, skipped)), Ok((expected_valid, expected_skipped))) => {
+                    let valid = valid.into_iter().map(|sub| sub.kind.0).collect::<Vec<_>>();
+                    assert_eq!(valid, expected_valid, "TC{} failed", index);
+                    assert_eq!(
+                        without_reason(skipped),
+                        expected_skipped,
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (
+                    Err(DataError::InvalidSubscription(actual)),
+                    Err(DataError::InvalidSubscription(expected)),
+                ) => {
+                    assert_eq!(
+                        without_reason(vec![actual]),
+                        vec![expected],
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (Err(DataError::Socket(_)), Err(DataError::Socket(_))) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_market() {
+        struct TestCase {
+            input: Subscription<Okx, PublicTrades>,
+            expected: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: valid alphanumeric symbols
+                input: Subscription::from((
+                    Okx,
+                    "1inch",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )),
+                expected: true,
+            },
+            TestCase {
+                // TC1: invalid base symbol w/ exchange specific separator
+                input: Subscription::from((
+                    Okx,
+                    "btc-usdt",
+                    "swap",
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                )),
+                expected: false,
+            },
+            TestCase {
+                // TC2: invalid empty quote symbol
+                input: Subscription::from((Okx, "btc", "", InstrumentKind::Spot, PublicTrades)),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = validate_subscription(&test.input);
+            assert_eq!(actual.is_ok(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_filter_markets() {
+        struct TestCase {
+            policy: SubscriptionPolicy,
+            expected: Result<(Vec<&'static str>, Vec<&'static str>), &'static str>,
+        }
+
+        let markets = HashSet::from(["BTC-USDT".to_string(), "BTC-USDT-SWAP".to_string()]);
+        let subscription =
+            |base, quote, kind| Subscription::from((Okx, base, quote, kind, PublicTrades));
+
+        let cases = vec![
+            TestCase {
+                // TC0: Strict policy fails w/ InvalidSubscription naming the first unlisted market
+                policy: SubscriptionPolicy::Strict,
+                expected: Err("eth"),
+            },
+            TestCase {
+                // TC1: SkipInvalid policy drops unlisted markets & keeps listed markets
+                policy: SubscriptionPolicy::SkipInvalid,
+                expected: Ok((vec!["btc", "btc"], vec!["eth", "doge"])),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = vec![
+                subscription("btc", "usdt", InstrumentKind::Spot),
+                subscription("eth", "usdt", InstrumentKind::FuturePerpetual),
+                subscription("btc", "usdt", InstrumentKind::FuturePerpetual),
+                subscription("doge", "usdt", InstrumentKind::Spot),
+            ];
+
+            let actual = filter_markets(input, &markets, test.policy);
+
+            match (actual, test.expected) {
+                (Ok((valid, skipped)), Ok((expected_valid, expected_skipped))) => {
+                    let valid = valid
+                        .iter()
+                        .map(|sub| sub.instrument.base.to_string())
+                        .collect::<Vec<_>>();
+                    let skipped = skipped
+                        .iter()
+                        .map(|sub| sub.instrument.base.to_string())
+                        .collect::<Vec<_>>();
+                    assert_eq!(valid, expected_valid, "TC{} failed", index);
+                    assert_eq!(skipped, expected_skipped, "TC{} failed", index);
+                }
+                (Err(DataError::InvalidSubscription(actual)), Err(expected)) => {
+                    assert_eq!(
+                        actual.instrument.base.as_ref(),
+                        expected,
+                        "TC{} failed",
+                        index
+                    );
+                    assert!(
+                        actual.reason.contains("ETH-USDT-SWAP"),
+                        "TC{} failed",
+                        index
+                    );
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_init_sandbox_without_sandbox_server() {
+        let actual = Streams::<PublicTrades>::builder()
+            .with_environment(Environment::Sandbox)
+            .subscribe([(Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades)])
+            .init()
+            .await;
+
+        assert!(
+            matches!(
+                actual,
+                Err(DataError::Socket(SocketError::Unsupported { entity, .. })) if entity == "coinbase"
+            ),
+            "expected Coinbase Sandbox init to fail without connecting"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_init_surfaces_invalid_candle_via_errors() {
+        run_fake_exchange(vec![
+            fake_message(Utc::now(), None, candle(90.0, 110.0)),
+            fake_message(Utc::now(), None, candle(110.0, 90.0)),
+        ])
+        .await;
+
+        let mut streams = Streams::<Candles>::builder()
+            .subscribe([(
+                FakeExchange,
+                "btc",
+                "usd",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            )])
+            .init()
+            .await
+            .unwrap();
+        let mut errors = streams.errors().unwrap();
+        let mut candles = streams.select(FakeExchange::ID).unwrap();
+
+        // Invalid Candle is rejected by the default InvalidEventPolicy, & surfaced via errors
+        let error = errors.recv().await.unwrap();
+        assert_eq!(error.exchange, FakeExchange::ID);
+        assert!(
+            matches!(&error.error, DataError::InvalidCandle { candle: invalid, .. } if **invalid == candle(90.0, 110.0)),
+            "expected InvalidCandle, got: {:?}",
+            error.error
+        );
+
+        // Valid Candle is sent via the exchange channel
+        let event = candles.recv().await.unwrap();
+        assert_eq!(event.kind, candle(110.0, 90.0));
+        assert!(errors.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_init_surfaces_rejected_timestamp_via_errors() {
+        let stale = Utc::now() - chrono::Duration::hours(1);
+        run_fake_exchange(vec![
+            fake_message(stale, None, candle(110.0, 90.0)),
+            fake_message(Utc::now(), None, candle(110.0, 90.0)),
+        ])
+        .await;
+
+        let mut streams = Streams::<Candles>::builder()
+            .timestamp_policy(TimestampPolicy {
+                tolerance: Duration::from_secs(60),
+                action: TimestampAction::Reject,
+            })
+            .subscribe([(
+                FakeExchange,
+                "btc",
+                "usd",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            )])
+            .init()
+            .await
+            .unwrap();
+        let mut errors = streams.errors().unwrap();
+        let mut candles = streams.select(FakeExchange::ID).unwrap();
+
+        // Stale exchange_time is rejected by the TimestampPolicy, & surfaced via errors
+        let error = errors.recv().await.unwrap();
+        assert_eq!(error.exchange, FakeExchange::ID);
+        assert!(
+            matches!(
+                error.error,
+                DataError::TimestampOutOfRange { exchange_time, tolerance, .. }
+                    if exchange_time == stale && tolerance == Duration::from_secs(60)
+            ),
+            "expected TimestampOutOfRange, got: {:?}",
+            error.error
+        );
+
+        // Event within the tolerance is sent via the exchange channel
+        let event = candles.recv().await.unwrap();
+        assert_ne!(event.exchange_time, stale);
+        assert!(errors.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_init_surfaces_sequence_gap_via_errors() {
+        run_fake_exchange(
+            [1, 2, 5]
+                .into_iter()
+                .map(|id| fake_message(Utc::now(), Some(id), trade(id)))
+                .collect(),
+        )
+        .await;
+
+        let mut streams = Streams::<PublicTrades>::builder()
+            .subscribe([(
+                FakeExchange,
+                "btc",
+                "usd",
+                InstrumentKind::Spot,
+                PublicTrades,
+            )])
+            .init()
+            .await
+            .unwrap();
+        let mut errors = streams.errors().unwrap();
+        let mut trades = streams.select(FakeExchange::ID).unwrap();
+
+        // Every trade is sent via the exchange channel
+        for expected in [1, 2, 5] {
+            let event = trades.recv().await.unwrap();
+            assert_eq!(event.sequence, Some(expected));
+            assert_eq!(event.kind, trade(expected));
+        }
+
+        // Missed trades 3 & 4 are surfaced via errors
+        let error = errors.recv().await.unwrap();
+        assert_eq!(error.exchange, FakeExchange::ID);
+        assert!(
+            matches!(
+                error.error,
+                DataError::SequenceGap {
+                    expected: 3,
+                    actual: 5
+                }
+            ),
+            "expected SequenceGap, got: {:?}",
+            error.error
+        );
+        assert!(errors.try_recv().is_err());
+    }
 }
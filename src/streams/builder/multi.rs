@@ -1,6 +1,20 @@
-use super::{ExchangeChannel, StreamBuilder, Streams};
-use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId, subscription::SubKind};
+use super::{EventChannel, ExchangeChannel, StreamBuilder, Streams};
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::ExchangeId,
+    streams::{
+        channel::{ChannelCapacity, OverflowPolicy},
+        consumer::{StreamCommand, STREAM_COMMAND_CAPACITY},
+        health::{ConnectionEvent, ErrorChannel},
+        raw::RawChannel,
+        stats::StatsRegistry,
+        watermark::Watermark,
+    },
+    subscription::SubKind,
+};
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
+use tokio::sync::broadcast;
 
 /// Communicative type alias representing the [`Future`] result of a [`StreamBuilder::init`] call
 /// generated whilst executing [`MultiStreamBuilder::add`].
@@ -10,8 +24,15 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 /// multiple [`StreamBuilder<SubKind>`](StreamBuilder)s.
 #[derive(Default)]
 pub struct MultiStreamBuilder<Output> {
-    pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
+    pub channels: HashMap<ExchangeId, EventChannel<Output>>,
+    pub capacity: ChannelCapacity,
     pub futures: Vec<BuilderInitFuture>,
+    pub connection_events: ExchangeChannel<ConnectionEvent>,
+    pub errors: ErrorChannel,
+    pub raw_payloads: Option<RawChannel<Output>>,
+    pub watermarks: Option<ExchangeChannel<Watermark>>,
+    pub commands: HashMap<ExchangeId, broadcast::Sender<StreamCommand>>,
+    pub stats: StatsRegistry,
 }
 
 impl<Output> Debug for MultiStreamBuilder<Output>
@@ -21,7 +42,10 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("MultiStreamBuilder<Output>")
             .field("channels", &self.channels)
+            .field("capacity", &self.capacity)
             .field("num_futures", &self.futures.len())
+            .field("raw_payloads", &self.raw_payloads.is_some())
+            .field("watermarks", &self.watermarks.is_some())
             .finish()
     }
 }
@@ -31,14 +55,35 @@ impl<Output> MultiStreamBuilder<Output> {
     pub fn new() -> Self {
         Self {
             channels: HashMap::new(),
+            capacity: ChannelCapacity::default(),
             futures: Vec::new(),
+            connection_events: ExchangeChannel::default(),
+            errors: ErrorChannel::default(),
+            raw_payloads: None,
+            watermarks: None,
+            commands: HashMap::new(),
+            stats: StatsRegistry::default(),
         }
     }
 
+    /// Bound the common output channel of each exchange of any subsequently added
+    /// [`StreamBuilder`]s to `capacity` events, handling events sent whilst it is full according
+    /// to the [`OverflowPolicy`]. See [`StreamBuilder::with_bounded_output`].
+    pub fn with_bounded_output(mut self, capacity: usize, policy: OverflowPolicy) -> Self {
+        self.capacity = ChannelCapacity::Bounded { capacity, policy };
+        self
+    }
+
     /// Add a [`StreamBuilder<SubKind>`](StreamBuilder) to the [`MultiStreamBuilder`]. Creates a
     /// [`Future`] that calls [`StreamBuilder::init`] and maps the [`SubKind::Event`](SubKind)
     /// into a common `Output`.
     ///
+    /// If the [`StreamBuilder`] has raw payloads enabled (see
+    /// [`StreamBuilder::with_raw_payloads`]), its mapped `(Output, RawPayload)` pairs are
+    /// forwarded to the common [`Streams::raw_payloads`] channel. Likewise, if it has watermarks
+    /// enabled (see [`StreamBuilder::with_watermarks`]), its [`Watermark`]s are forwarded to the
+    /// common [`Streams::watermarks`] channel.
+    ///
     /// Note that the created [`Future`] is not awaited until the [`MultiStreamBuilder::init`]
     /// method is invoked.
     #[allow(clippy::should_implement_trait)]
@@ -50,21 +95,113 @@ impl<Output> MultiStreamBuilder<Output> {
     {
         // Allocate HashMap to hold the exchange_tx<Output> for each StreamBuilder exchange present
         let mut exchange_txs = HashMap::with_capacity(builder.channels.len());
+        let mut commands_rxs = HashMap::with_capacity(builder.channels.len());
 
         // Iterate over each StreamBuilder exchange present
         for exchange in builder.channels.keys().copied() {
-            // Insert ExchangeChannel<Output> Entry to Self for each exchange
-            let exchange_tx = self.channels.entry(exchange).or_default().tx.clone();
+            // Insert EventChannel<Output> Entry to Self for each exchange
+            let capacity = self.capacity;
+            let exchange_tx = self
+                .channels
+                .entry(exchange)
+                .or_insert_with(|| EventChannel::new(capacity))
+                .tx
+                .clone();
 
             // Insert new exchange_tx<Output> into HashMap for each exchange
             exchange_txs.insert(exchange, exchange_tx);
+
+            // Subscribe to the common StreamCommands of each exchange
+            let commands_rx = self
+                .commands
+                .entry(exchange)
+                .or_insert_with(|| broadcast::channel(STREAM_COMMAND_CAPACITY).0)
+                .subscribe();
+            commands_rxs.insert(exchange, commands_rx);
         }
 
+        // Include the SubscriptionStats of this StreamBuilder in the common StatsRegistry
+        self.stats.link(builder.stats.clone());
+
+        // Forward the ConnectionEvents of this StreamBuilder to the common channel
+        let connection_events_tx = self.connection_events.tx.clone();
+
+        // Forward the ConnectionErrors of this StreamBuilder to the common channel
+        let errors_tx = self.errors.tx.clone();
+
+        // Forward the RawPayloads of this StreamBuilder to the common channel, if enabled
+        let raw_tx = builder.raw_payloads.as_ref().map(|_| {
+            self.raw_payloads
+                .get_or_insert_with(RawChannel::default)
+                .tx
+                .clone()
+        });
+
+        // Forward the Watermarks of this StreamBuilder to the common channel, if enabled
+        let watermarks_tx = builder.watermark_policy.map(|_| {
+            self.watermarks
+                .get_or_insert_with(ExchangeChannel::default)
+                .tx
+                .clone()
+        });
+
         // Init Streams<Kind::Event> & send mapped Outputs to the associated exchange_tx
         self.futures.push(Box::pin(async move {
-            builder
-                .init()
-                .await?
+            let mut streams = builder.init().await?;
+
+            if let Some(mut connection_events_rx) = streams.connection_events() {
+                tokio::spawn(async move {
+                    while let Some(event) = connection_events_rx.recv().await {
+                        let _ = connection_events_tx.send(event);
+                    }
+                });
+            }
+
+            if let Some(mut errors_rx) = streams.errors() {
+                tokio::spawn(async move {
+                    while let Some(error) = errors_rx.recv().await {
+                        let _ = errors_tx.try_send(error);
+                    }
+                });
+            }
+
+            if let (Some(mut raw_rx), Some(raw_tx)) = (streams.raw_payloads(), raw_tx) {
+                tokio::spawn(async move {
+                    while let Some((event, raw)) = raw_rx.recv().await {
+                        let _ = raw_tx.try_send((Output::from(event), raw));
+                    }
+                });
+            }
+
+            if let (Some(mut watermarks_rx), Some(watermarks_tx)) =
+                (streams.watermarks(), watermarks_tx)
+            {
+                tokio::spawn(async move {
+                    while let Some(watermark) = watermarks_rx.recv().await {
+                        let _ = watermarks_tx.send(watermark);
+                    }
+                });
+            }
+
+            // Forward the common StreamCommands to the connections of this StreamBuilder
+            for (exchange, commands_tx) in streams.commands.drain() {
+                let Some(mut commands_rx) = commands_rxs.remove(&exchange) else {
+                    continue;
+                };
+                tokio::spawn(async move {
+                    loop {
+                        match commands_rx.recv().await {
+                            Ok(command) => {
+                                let _ = commands_tx.send(command);
+                            }
+                            Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                            Err(broadcast::error::RecvError::Closed) => break,
+                        }
+                    }
+                });
+            }
+
+            streams
                 .streams
                 .into_iter()
                 .for_each(|(exchange, mut exchange_rx)| {
@@ -77,7 +214,7 @@ impl<Output> MultiStreamBuilder<Output> {
                     // Task to receive MarketEvent<SubKind::Event> and send Outputs via exchange_tx
                     tokio::spawn(async move {
                         while let Some(event) = exchange_rx.recv().await {
-                            let _ = exchange_tx.send(Output::from(event));
+                            let _ = exchange_tx.send(Output::from(event)).await;
                         }
                     });
                 });
@@ -95,13 +232,19 @@ impl<Output> MultiStreamBuilder<Output> {
         // Await Stream initialisation futures and ensure success
         futures::future::try_join_all(self.futures).await?;
 
-        // Construct Streams<Output> using each ExchangeChannel receiver
-        Ok(Streams {
-            streams: self
-                .channels
+        // Construct Streams<Output> using each EventChannel receiver
+        Ok(Streams::new(
+            self.channels
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
-        })
+            Some(self.connection_events.rx),
+            Some(self.errors.rx),
+            self.raw_payloads.map(|channel| channel.rx),
+            self.watermarks.map(|channel| channel.rx),
+            self.commands,
+            self.capacity,
+            self.stats,
+        ))
     }
 }
@@ -0,0 +1,501 @@
+use super::handler::HandlerSender;
+use crate::error::DataError;
+use futures::{task::AtomicWaker, Stream};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+};
+use tokio::sync::{
+    mpsc,
+    mpsc::error::{SendError, TryRecvError},
+    Notify,
+};
+
+/// Determines how a [`ChannelCapacity::Bounded`] channel handles an event sent whilst it is full.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub enum OverflowPolicy {
+    /// Wait for the receiver to make space, applying backpressure all the way to the
+    /// [`MarketStream`](crate::MarketStream) WebSocket read loop.
+    Block,
+    /// Drop the oldest buffered event to make space for the new event, incrementing the dropped
+    /// events counter.
+    DropOldest,
+    /// Drop the new event, incrementing the dropped events counter.
+    DropNewest,
+}
+
+/// Capacity of the [`EventSender`] & [`EventReceiver`] channel used to output the events of
+/// each exchange (eg/ via [`Streams::select`](super::Streams::select)).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum ChannelCapacity {
+    /// Unbounded channel, where a slow consumer causes events to buffer without limit.
+    #[default]
+    Unbounded,
+    /// Channel buffering at most `capacity` events, with any further events handled according to
+    /// the [`OverflowPolicy`].
+    Bounded {
+        capacity: usize,
+        policy: OverflowPolicy,
+    },
+}
+
+impl ChannelCapacity {
+    /// Construct a new [`EventSender`] & [`EventReceiver`] channel with this [`ChannelCapacity`].
+    pub fn channel<T>(&self) -> (EventSender<T>, EventReceiver<T>) {
+        match *self {
+            Self::Unbounded => {
+                let (tx, rx) = mpsc::unbounded_channel();
+                (EventSender::Unbounded(tx), EventReceiver::Unbounded(rx))
+            }
+            Self::Bounded { capacity, policy } => {
+                let shared = Arc::new(Shared {
+                    state: Mutex::new(State {
+                        buffer: VecDeque::with_capacity(capacity.max(1)),
+                        senders: 1,
+                        closed: false,
+                    }),
+                    capacity: capacity.max(1),
+                    policy,
+                    dropped: Arc::new(AtomicU64::new(0)),
+                    space: Notify::new(),
+                    receiver: AtomicWaker::new(),
+                });
+
+                (
+                    EventSender::Bounded(BoundedSender {
+                        shared: Arc::clone(&shared),
+                    }),
+                    EventReceiver::Bounded(BoundedReceiver { shared }),
+                )
+            }
+        }
+    }
+}
+
+/// Sending half of an exchange output channel, constructed via [`ChannelCapacity::channel`], or
+/// an [`EventHandler`](super::handler::EventHandler) called inline in place of a channel.
+#[derive(Debug)]
+pub enum EventSender<T> {
+    Unbounded(mpsc::UnboundedSender<T>),
+    Bounded(BoundedSender<T>),
+    Handler(HandlerSender<T>),
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unbounded(tx) => Self::Unbounded(tx.clone()),
+            Self::Bounded(tx) => Self::Bounded(tx.clone()),
+            Self::Handler(tx) => Self::Handler(tx.clone()),
+        }
+    }
+}
+
+impl<T> From<mpsc::UnboundedSender<T>> for EventSender<T> {
+    fn from(tx: mpsc::UnboundedSender<T>) -> Self {
+        Self::Unbounded(tx)
+    }
+}
+
+impl<T> EventSender<T> {
+    /// Send an event, waiting for space if the channel is bounded with [`OverflowPolicy::Block`].
+    ///
+    /// Fails with the event if the [`EventReceiver`] has been dropped.
+    pub async fn send(&self, event: T) -> Result<(), SendError<T>> {
+        match self {
+            Self::Unbounded(tx) => tx.send(event),
+            Self::Bounded(tx) => tx.send(event).await,
+            Self::Handler(tx) => tx.send(event),
+        }
+    }
+
+    /// Send a [`DataError`] to an [`EventHandler`](super::handler::EventHandler).
+    ///
+    /// Fails with the [`DataError`] if this [`EventSender`] is a channel, since an
+    /// [`EventReceiver`] only yields events, so the caller can deliver it separately (eg/ via
+    /// [`Streams::errors`](super::Streams::errors)).
+    pub fn send_error(&self, error: DataError) -> Result<(), DataError> {
+        match self {
+            Self::Handler(tx) => {
+                tx.send_error(error);
+                Ok(())
+            }
+            Self::Unbounded(_) | Self::Bounded(_) => Err(error),
+        }
+    }
+
+    /// Construct a new channel that feeds this [`EventSender`] (eg/ via a forwarding task), with
+    /// an equivalent capacity.
+    ///
+    /// The new channel is unbounded if this [`EventSender`] is unbounded or a
+    /// [`HandlerSender`], otherwise it is bounded with the same capacity and
+    /// [`OverflowPolicy::Block`], so any backpressure propagates upstream rather than buffering
+    /// without limit.
+    ///
+    /// The new [`EventSender`] of a [`HandlerSender`] still sends any [`DataError`] to the
+    /// [`EventHandler`](super::handler::EventHandler) inline.
+    pub fn upstream(&self) -> (EventSender<T>, EventReceiver<T>) {
+        match self {
+            Self::Unbounded(_) => ChannelCapacity::Unbounded.channel(),
+            Self::Handler(tx) => {
+                let (upstream_tx, upstream_rx) = mpsc::unbounded_channel();
+                (
+                    EventSender::Handler(tx.with_upstream(upstream_tx)),
+                    EventReceiver::Unbounded(upstream_rx),
+                )
+            }
+            Self::Bounded(tx) => ChannelCapacity::Bounded {
+                capacity: tx.shared.capacity,
+                policy: OverflowPolicy::Block,
+            }
+            .channel(),
+        }
+    }
+}
+
+/// Receiving half of an exchange output channel, constructed via [`ChannelCapacity::channel`].
+///
+/// Yields `None` once every [`EventSender`] has been dropped and all buffered events received.
+#[derive(Debug)]
+pub enum EventReceiver<T> {
+    Unbounded(mpsc::UnboundedReceiver<T>),
+    Bounded(BoundedReceiver<T>),
+}
+
+impl<T> From<mpsc::UnboundedReceiver<T>> for EventReceiver<T> {
+    fn from(rx: mpsc::UnboundedReceiver<T>) -> Self {
+        Self::Unbounded(rx)
+    }
+}
+
+impl<T> EventReceiver<T> {
+    /// Receive the next event, or `None` if the channel is closed.
+    pub async fn recv(&mut self) -> Option<T> {
+        futures::future::poll_fn(|cx| self.poll_recv(cx)).await
+    }
+
+    /// Receive the next event if one is immediately available, without waiting.
+    pub fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        match self {
+            Self::Unbounded(rx) => rx.try_recv(),
+            Self::Bounded(rx) => rx.try_recv(),
+        }
+    }
+
+    /// Blocking receive of the next event, or `None` if the channel is closed.
+    ///
+    /// Panics if called within an asynchronous execution context (eg/ use inside
+    /// [`tokio::task::spawn_blocking`]).
+    pub fn blocking_recv(&mut self) -> Option<T> {
+        match self {
+            Self::Unbounded(rx) => rx.blocking_recv(),
+            Self::Bounded(_) => futures::executor::block_on(self.recv()),
+        }
+    }
+
+    /// Poll to receive the next event, or `None` if the channel is closed.
+    pub fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        match self {
+            Self::Unbounded(rx) => rx.poll_recv(cx),
+            Self::Bounded(rx) => rx.poll_recv(cx),
+        }
+    }
+
+    /// Number of events dropped by the [`OverflowPolicy`] of a bounded channel. Always zero for
+    /// unbounded channels & [`OverflowPolicy::Block`].
+    pub fn dropped(&self) -> u64 {
+        self.dropped_counter()
+            .map(|dropped| dropped.load(Ordering::Relaxed))
+            .unwrap_or_default()
+    }
+
+    /// Shared dropped events counter of a bounded channel, which remains readable after this
+    /// [`EventReceiver`] has been moved (eg/ via [`Streams::select`](super::Streams::select)).
+    pub fn dropped_counter(&self) -> Option<Arc<AtomicU64>> {
+        match self {
+            Self::Unbounded(_) => None,
+            Self::Bounded(rx) => Some(Arc::clone(&rx.shared.dropped)),
+        }
+    }
+}
+
+impl<T> Stream for EventReceiver<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_recv(cx)
+    }
+}
+
+/// State shared between the [`BoundedSender`]s & [`BoundedReceiver`] of a bounded channel.
+#[derive(Debug)]
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    capacity: usize,
+    policy: OverflowPolicy,
+    dropped: Arc<AtomicU64>,
+    /// Notified whenever the [`BoundedReceiver`] makes space, or is dropped.
+    space: Notify,
+    /// Woken whenever an event is buffered, or the last [`BoundedSender`] is dropped.
+    receiver: AtomicWaker,
+}
+
+#[derive(Debug)]
+struct State<T> {
+    buffer: VecDeque<T>,
+    senders: usize,
+    closed: bool,
+}
+
+impl<T> Shared<T> {
+    fn state(&self) -> std::sync::MutexGuard<'_, State<T>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// [`EventSender`] of a [`ChannelCapacity::Bounded`] channel.
+#[derive(Debug)]
+pub struct BoundedSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedSender<T> {
+    async fn send(&self, mut event: T) -> Result<(), SendError<T>> {
+        loop {
+            // Register interest in space before checking the buffer, so no notification is missed
+            let space = self.shared.space.notified();
+            tokio::pin!(space);
+            space.as_mut().enable();
+
+            match self.try_send(event)? {
+                None => return Ok(()),
+                Some(blocked) => {
+                    event = blocked;
+                    space.await;
+                }
+            }
+        }
+    }
+
+    /// Attempt to buffer the event according to the [`OverflowPolicy`], returning the event if
+    /// the channel is full and the [`OverflowPolicy::Block`] sender must wait for space.
+    fn try_send(&self, event: T) -> Result<Option<T>, SendError<T>> {
+        let mut state = self.shared.state();
+        if state.closed {
+            return Err(SendError(event));
+        }
+
+        if state.buffer.len() >= self.shared.capacity {
+            match self.shared.policy {
+                OverflowPolicy::Block => return Ok(Some(event)),
+                OverflowPolicy::DropOldest => {
+                    state.buffer.pop_front();
+                    self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                OverflowPolicy::DropNewest => {
+                    self.shared.dropped.fetch_add(1, Ordering::Relaxed);
+                    return Ok(None);
+                }
+            }
+        }
+
+        state.buffer.push_back(event);
+        drop(state);
+        self.shared.receiver.wake();
+        Ok(None)
+    }
+}
+
+impl<T> Clone for BoundedSender<T> {
+    fn clone(&self) -> Self {
+        self.shared.state().senders += 1;
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T> Drop for BoundedSender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            self.shared.receiver.wake();
+        }
+    }
+}
+
+/// [`EventReceiver`] of a [`ChannelCapacity::Bounded`] channel.
+#[derive(Debug)]
+pub struct BoundedReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> BoundedReceiver<T> {
+    fn poll_recv(&mut self, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        // Register the waker before checking the buffer, so no wake up is missed
+        self.shared.receiver.register(cx.waker());
+
+        match self.try_recv() {
+            Ok(event) => Poll::Ready(Some(event)),
+            Err(TryRecvError::Disconnected) => Poll::Ready(None),
+            Err(TryRecvError::Empty) => Poll::Pending,
+        }
+    }
+
+    fn try_recv(&mut self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.state();
+        match state.buffer.pop_front() {
+            Some(event) => {
+                drop(state);
+                self.shared.space.notify_one();
+                Ok(event)
+            }
+            None if state.senders == 0 => Err(TryRecvError::Disconnected),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+}
+
+impl<T> Drop for BoundedReceiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.state();
+        state.closed = true;
+        state.buffer.clear();
+        drop(state);
+        self.shared.space.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::error::SocketError;
+    use std::time::Duration;
+
+    /// Consume events from the provided [`EventReceiver`] with a deliberately slow consumer,
+    /// waiting `delay` before receiving each event.
+    async fn slow_consume(rx: &mut EventReceiver<u64>, delay: Duration) -> Vec<u64> {
+        let mut received = Vec::new();
+        loop {
+            tokio::time::sleep(delay).await;
+            match rx.recv().await {
+                Some(event) => received.push(event),
+                None => break received,
+            }
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_bounded_channel_overflow_policies() {
+        struct TestCase {
+            policy: OverflowPolicy,
+            expected_received: Vec<u64>,
+            expected_dropped: u64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Block applies backpressure, so every event is received
+                policy: OverflowPolicy::Block,
+                expected_received: (0..10).collect(),
+                expected_dropped: 0,
+            },
+            TestCase {
+                // TC1: DropOldest keeps the most recent events buffered whilst the consumer sleeps
+                policy: OverflowPolicy::DropOldest,
+                expected_received: vec![7, 8, 9],
+                expected_dropped: 7,
+            },
+            TestCase {
+                // TC2: DropNewest keeps the first events buffered & counts the rest
+                policy: OverflowPolicy::DropNewest,
+                expected_received: vec![0, 1, 2],
+                expected_dropped: 7,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (tx, mut rx) = ChannelCapacity::Bounded {
+                capacity: 3,
+                policy: test.policy,
+            }
+            .channel();
+
+            // Fast producer sends every event before the slow consumer wakes up
+            let producer = tokio::spawn(async move {
+                for event in 0..10 {
+                    tx.send(event).await.unwrap();
+                }
+            });
+
+            let received = slow_consume(&mut rx, Duration::from_millis(100)).await;
+            producer.await.unwrap();
+
+            assert_eq!(received, test.expected_received, "TC{} failed", index);
+            assert_eq!(rx.dropped(), test.expected_dropped, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_bounded_channel_block_applies_backpressure() {
+        let (tx, mut rx) = ChannelCapacity::Bounded {
+            capacity: 2,
+            policy: OverflowPolicy::Block,
+        }
+        .channel();
+
+        let sent = Arc::new(AtomicU64::new(0));
+        let producer = tokio::spawn({
+            let sent = Arc::clone(&sent);
+            async move {
+                for event in 0..5u64 {
+                    tx.send(event).await?;
+                    sent.fetch_add(1, Ordering::Relaxed);
+                }
+                Ok(())
+            }
+        });
+
+        // Producer is blocked once the channel is full
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(sent.load(Ordering::Relaxed), 2);
+
+        // Receiving an event unblocks the producer for a single event
+        assert_eq!(rx.recv().await, Some(0));
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert_eq!(sent.load(Ordering::Relaxed), 3);
+
+        // Dropping the receiver fails the blocked send
+        drop(rx);
+        assert_eq!(producer.await.unwrap(), Err(SendError(3)));
+    }
+
+    #[tokio::test]
+    async fn test_unbounded_channel() {
+        let (tx, mut rx) = ChannelCapacity::Unbounded.channel();
+        for event in 0..3u64 {
+            tx.send(event).await.unwrap();
+        }
+
+        // DataErrors are returned to the caller, since the receiver only yields events
+        assert!(matches!(
+            tx.send_error(DataError::Socket(SocketError::Sink)),
+            Err(DataError::Socket(SocketError::Sink))
+        ));
+        drop(tx);
+
+        assert_eq!(slow_consume(&mut rx, Duration::ZERO).await, vec![0, 1, 2]);
+        assert_eq!(rx.dropped(), 0);
+        assert!(rx.dropped_counter().is_none());
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{
+    event::MarketEvent,
+    streams::channel::{EventReceiver, EventSender},
+};
+use barter_integration::model::Instrument;
+use std::{collections::HashMap, time::Duration};
+use tokio::time::Instant;
+
+/// Conflation window of a single [`Subscription`](crate::subscription::Subscription), holding the
+/// most recent [`MarketEvent`] received since the window opened.
+#[derive(Clone, Debug)]
+struct Window<T> {
+    deadline: Instant,
+    latest: Option<MarketEvent<T>>,
+}
+
+/// Wrap the provided `exchange_tx` so the [`MarketEvent`]s of each
+/// [`Subscription`](crate::subscription::Subscription) are conflated to at most one per `window`.
+///
+/// Returns the [`EventSender`] the [`MarketStream`](crate::MarketStream) consumer loop should
+/// send events to. See [`conflate`] for the conflation behaviour.
+pub fn spawn_conflation<T>(
+    window: Duration,
+    exchange_tx: EventSender<MarketEvent<T>>,
+) -> EventSender<MarketEvent<T>>
+where
+    T: Send + 'static,
+{
+    let (conflate_tx, conflate_rx) = exchange_tx.upstream();
+    tokio::spawn(conflate(window, conflate_rx, exchange_tx));
+    conflate_tx
+}
+
+/// Forward the [`MarketEvent`]s received via `event_rx` to the `exchange_tx`, keeping only the
+/// most recent event of each [`Instrument`] within every `window`.
+///
+/// The first event after a quiet period is forwarded immediately and opens a window. Any events
+/// received before the window closes replace each other, and only the latest is forwarded (with
+/// its original `exchange_time`) at the window boundary, opening the next window. A window that
+/// closes without receiving any events ends the busy period, so slow streams pass through
+/// untouched.
+pub async fn conflate<T>(
+    window: Duration,
+    mut event_rx: EventReceiver<MarketEvent<T>>,
+    exchange_tx: EventSender<MarketEvent<T>>,
+) {
+    let mut windows = HashMap::<Instrument, Window<T>>::new();
+
+    loop {
+        let next_deadline = windows.values().map(|window| window.deadline).min();
+
+        tokio::select! {
+            event = event_rx.recv() => {
+                let Some(event) = event else {
+                    break;
+                };
+
+                match windows.get_mut(&event.instrument) {
+                    Some(open) => open.latest = Some(event),
+                    None => {
+                        windows.insert(
+                            event.instrument.clone(),
+                            Window { deadline: Instant::now() + window, latest: None },
+                        );
+                        if exchange_tx.send(event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+            _ = sleep_until(next_deadline) => {
+                let now = Instant::now();
+                let mut closed = Vec::new();
+
+                let expired = windows.iter_mut().filter(|(_, open)| open.deadline <= now);
+                for (instrument, open) in expired {
+                    match open.latest.take() {
+                        Some(event) => {
+                            open.deadline = now + window;
+                            if exchange_tx.send(event).await.is_err() {
+                                return;
+                            }
+                        }
+                        None => closed.push(instrument.clone()),
+                    }
+                }
+
+                for instrument in closed {
+                    windows.remove(&instrument);
+                }
+            }
+        }
+    }
+
+    // Flush the latest events of any open windows once the consumer loop has ended
+    let mut remaining = windows
+        .into_values()
+        .filter_map(|open| open.latest.map(|event| (open.deadline, event)))
+        .collect::<Vec<_>>();
+    remaining.sort_by_key(|(deadline, _)| *deadline);
+    for (_, event) in remaining {
+        let _ = exchange_tx.send(event).await;
+    }
+}
+
+/// Sleep until the provided deadline, or forever if there is no deadline.
+pub(crate) async fn sleep_until(deadline: Option<Instant>) {
+    match deadline {
+        Some(deadline) => tokio::time::sleep_until(deadline).await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{number::Number, trade::PublicTrade};
+    use barter_integration::model::{Exchange, InstrumentKind, Side};
+    use chrono::{DateTime, Utc};
+    use tokio::sync::mpsc;
+
+    fn trade(base: &str, id: u64) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: DateTime::<Utc>::MIN_UTC + chrono::Duration::milliseconds(id as i64),
+            received_time: DateTime::<Utc>::MIN_UTC,
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind: PublicTrade {
+                id: id.to_string(),
+                price: Number::from(1),
+                amount: Number::from(1),
+                side: Side::Buy,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        }
+    }
+
+    /// Drain every event currently forwarded to the receiver, returning the trade ids.
+    async fn drain(
+        exchange_rx: &mut mpsc::UnboundedReceiver<MarketEvent<PublicTrade>>,
+    ) -> Vec<u64> {
+        // Yield so the conflation task can process everything sent so far
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+
+        let mut ids = Vec::new();
+        while let Ok(event) = exchange_rx.try_recv() {
+            ids.push(event.kind.id.parse().unwrap());
+        }
+        ids
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_conflate_fast_stream() {
+        let window = Duration::from_millis(100);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let event_tx = spawn_conflation(window, exchange_tx.into());
+
+        struct TestCase {
+            advance: Duration,
+            input: Vec<MarketEvent<PublicTrade>>,
+            expected: Vec<u64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first event after a quiet period is forwarded immediately
+                advance: Duration::ZERO,
+                input: vec![trade("btc", 1)],
+                expected: vec![1],
+            },
+            TestCase {
+                // TC1: events within the window are held back
+                advance: Duration::from_millis(10),
+                input: vec![trade("btc", 2), trade("btc", 3), trade("btc", 4)],
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: first event of another Instrument is forwarded immediately
+                advance: Duration::from_millis(10),
+                input: vec![trade("eth", 5)],
+                expected: vec![5],
+            },
+            TestCase {
+                // TC3: latest btc event is forwarded at the window boundary
+                advance: Duration::from_millis(80),
+                input: vec![],
+                expected: vec![4],
+            },
+            TestCase {
+                // TC4: eth window closes without further events
+                advance: Duration::from_millis(20),
+                input: vec![],
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: btc events within the next window are conflated to the latest
+                advance: Duration::from_millis(50),
+                input: vec![trade("btc", 6), trade("btc", 7)],
+                expected: vec![],
+            },
+            TestCase {
+                // TC6: latest btc event is forwarded at the next window boundary
+                advance: Duration::from_millis(30),
+                input: vec![],
+                expected: vec![7],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            tokio::time::advance(test.advance).await;
+            for event in test.input {
+                event_tx.send(event).await.unwrap();
+            }
+            assert_eq!(
+                drain(&mut exchange_rx).await,
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_conflate_slow_stream_passes_through() {
+        let window = Duration::from_millis(100);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let event_tx = spawn_conflation(window, exchange_tx.into());
+
+        // Events slower than the window are each forwarded immediately & unmodified
+        for id in 1..=5 {
+            let event = trade("btc", id);
+            let exchange_time = event.exchange_time;
+            event_tx.send(event).await.unwrap();
+
+            tokio::task::yield_now().await;
+            let actual = exchange_rx.try_recv().unwrap();
+            assert_eq!(actual.kind.id, id.to_string());
+            assert_eq!(actual.exchange_time, exchange_time);
+
+            tokio::time::sleep(Duration::from_millis(250)).await;
+        }
+
+        assert!(exchange_rx.try_recv().is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_conflate_flushes_on_close() {
+        let window = Duration::from_millis(100);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let event_tx = spawn_conflation(window, exchange_tx.into());
+
+        event_tx.send(trade("btc", 1)).await.unwrap();
+        event_tx.send(trade("btc", 2)).await.unwrap();
+        event_tx.send(trade("btc", 3)).await.unwrap();
+        drop(event_tx);
+
+        assert_eq!(drain(&mut exchange_rx).await, vec![1, 3]);
+        assert!(exchange_rx.recv().await.is_none());
+    }
+}
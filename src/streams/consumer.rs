@@ -1,32 +1,302 @@
+use super::{
+    channel::EventSender,
+    controller::{SubscribeCommand, UnsubscribeCommand},
+    filter::EventFilter,
+    handover::{
+        next_previous, Handover, HandoverSource, HANDOVER_OVERLAP, ROTATION_RETRY_INTERVAL,
+    },
+    health::{ConnectionEvent, HealthMonitor, LivenessMonitor},
+    metrics::StreamMetrics,
+    raw::{RawCapture, RawPayload},
+    stats::ConnectionStats,
+    watermark::{WatermarkEmitter, WatermarkTracker},
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
-    exchange::StreamSelector,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, StreamSelector},
+    subscriber::{
+        connection::ConnectionOptions,
+        validator::{SubscriptionAcks, SubscriptionOutcome},
+    },
     subscription::{SubKind, Subscription},
     Identifier, MarketStream,
 };
-use futures::StreamExt;
-use std::time::Duration;
-use tokio::sync::mpsc;
-use tracing::{error, info, warn};
+use barter_integration::{error::SocketError, model::SubscriptionId};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    any::Any, collections::HashMap, future::Future, ops::ControlFlow, panic::AssertUnwindSafe,
+    sync::Arc, time::Duration,
+};
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::Instant,
+};
+use tracing::{debug, error, info, warn};
 
 /// Initial duration that the [`consume`] function should wait after disconnecting before attempting
 /// to re-initialise a [`MarketStream`]. This duration will increase exponentially as a result
 /// of repeated disconnections with re-initialisation failures.
 pub const STARTING_RECONNECT_BACKOFF_MS: u64 = 125;
 
+/// Default maximum duration that the [`consume`] function will wait between attempts to
+/// re-initialise a [`MarketStream`].
+pub const MAX_RECONNECT_BACKOFF_MS: u64 = 60_000;
+
+/// Re-connection policy used by the [`consume`] function when a [`MarketStream`] disconnects.
+///
+/// Defaults to infinite re-connection attempts with a jittered exponential backoff starting at
+/// [`STARTING_RECONNECT_BACKOFF_MS`] and capped at [`MAX_RECONNECT_BACKOFF_MS`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct ReconnectPolicy {
+    /// Backoff before the first re-connection attempt, doubled after each consecutive failure.
+    pub initial_backoff_ms: u64,
+    /// Maximum backoff between re-connection attempts.
+    pub max_backoff_ms: u64,
+    /// Maximum number of consecutive failed re-connection attempts before the [`consume`]
+    /// function exits. `None` means re-connection is attempted forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: STARTING_RECONNECT_BACKOFF_MS,
+            max_backoff_ms: MAX_RECONNECT_BACKOFF_MS,
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Exponential backoff (without jitter) to wait before the provided re-connection `attempt`,
+    /// capped at the `max_backoff_ms`.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let backoff_ms = self
+            .initial_backoff_ms
+            .saturating_mul(2_u64.saturating_pow(attempt))
+            .min(self.max_backoff_ms);
+
+        Duration::from_millis(backoff_ms)
+    }
+
+    /// Jittered [`Self::backoff`] to wait before the provided re-connection `attempt`, uniformly
+    /// distributed between 50% and 100% of the un-jittered backoff. Jitter prevents many
+    /// [`MarketStream`]s that disconnected simultaneously from re-connecting in lockstep.
+    pub fn backoff_jittered(&self, attempt: u32) -> Duration {
+        let backoff = self.backoff(attempt);
+        let jitter_ratio = f64::from(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .subsec_nanos(),
+        ) / 1_000_000_000.0;
+
+        backoff.mul_f64(0.5 + 0.5 * jitter_ratio)
+    }
+
+    /// Determine if the [`consume`] function should give up after the provided number of
+    /// consecutive failed re-connection `attempt`s.
+    pub fn is_exhausted(&self, attempt: u32) -> bool {
+        self.max_attempts
+            .is_some_and(|max_attempts| attempt >= max_attempts)
+    }
+}
+
+/// Determines how the [`consume`] function handles normalised events that violate the
+/// invariants of their [`SubKind`] (see [`SubKind::validate`]), such as malformed
+/// [`Candle`](crate::subscription::candle::Candle)s.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum InvalidEventPolicy {
+    /// Surface the [`DataError`] (eg/ [`DataError::InvalidCandle`]) via
+    /// [`Streams::errors`](super::Streams::errors) rather than emitting the invalid event.
+    #[default]
+    Reject,
+    /// Log the [`DataError`] as a warning, but still emit the invalid event.
+    Warn,
+}
+
+/// Default tolerance of the [`TimestampPolicy`] between the `exchange_time` & `received_time`
+/// of a [`MarketEvent`].
+pub const DEFAULT_TIMESTAMP_TOLERANCE: Duration = Duration::from_secs(5 * 60);
+
+/// Action taken by the [`TimestampPolicy`] when the `exchange_time` of a [`MarketEvent`] is
+/// outside the tolerance of its `received_time`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum TimestampAction {
+    /// Log a warning & clamp the `exchange_time` to the `received_time`.
+    #[default]
+    Clamp,
+    /// Surface a [`DataError::TimestampOutOfRange`] via
+    /// [`Streams::errors`](super::Streams::errors) rather than emitting the event.
+    Reject,
+}
+
+/// Clock-skew aware sanity check of the `exchange_time` of each [`MarketEvent`] used by the
+/// [`consume`] function, protecting latency monitoring & time-ordered storage from garbage
+/// exchange timestamps (eg/ seconds sent in place of milliseconds).
+///
+/// Defaults to a ±[`DEFAULT_TIMESTAMP_TOLERANCE`] window, clamping out of range timestamps.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct TimestampPolicy {
+    /// Maximum duration the `exchange_time` may be before or after the `received_time`.
+    pub tolerance: Duration,
+    /// Action taken when the `exchange_time` is outside the `tolerance`.
+    pub action: TimestampAction,
+}
+
+impl Default for TimestampPolicy {
+    fn default() -> Self {
+        Self {
+            tolerance: DEFAULT_TIMESTAMP_TOLERANCE,
+            action: TimestampAction::default(),
+        }
+    }
+}
+
+impl TimestampPolicy {
+    /// Check the `exchange_time` of the [`MarketEvent`] is within the `tolerance` of its
+    /// `received_time`, handling violations according to the [`TimestampAction`].
+    pub fn check<T>(&self, mut event: MarketEvent<T>) -> Result<MarketEvent<T>, DataError> {
+        let skew = (event.exchange_time - event.received_time)
+            .num_milliseconds()
+            .unsigned_abs();
+        if u128::from(skew) <= self.tolerance.as_millis() {
+            return Ok(event);
+        }
+
+        match self.action {
+            TimestampAction::Clamp => {
+                warn!(
+                    exchange = %event.exchange,
+                    instrument = %event.instrument,
+                    exchange_time = %event.exchange_time,
+                    received_time = %event.received_time,
+                    tolerance = ?self.tolerance,
+                    action = "clamping exchange_time to received_time",
+                    "consumed MarketEvent with exchange_time out of range",
+                );
+                event.exchange_time = event.received_time;
+                Ok(event)
+            }
+            TimestampAction::Reject => Err(DataError::TimestampOutOfRange {
+                exchange_time: event.exchange_time,
+                received_time: event.received_time,
+                tolerance: self.tolerance,
+            }),
+        }
+    }
+}
+
+/// Maximum duration the [`consume`] function waits for the exchange server to acknowledge the
+/// close frame of a gracefully closed [`MarketStream`].
+pub const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Capacity of the [`StreamCommand`] broadcast channel shared by every [`consume`] loop of an
+/// exchange.
+pub const STREAM_COMMAND_CAPACITY: usize = 64;
+
+/// Duration after unsubscribing during which [`SocketError::Unidentifiable`] errors of the
+/// unsubscribed [`SubscriptionId`]s (ie/ in-flight messages) are only logged at debug level by
+/// the [`consume`] loop.
+pub const UNSUBSCRIBE_GRACE_PERIOD: Duration = Duration::from_secs(10);
+
+/// Lifecycle command sent from [`Streams`](super::Streams) to the [`consume`] loop of every
+/// connection of an exchange.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum StreamCommand {
+    /// Subscribe to the [`SubscribeCommand`] [`Subscription`] on the live connection, if claimed
+    /// by a connection of the same [`SubKind`].
+    Subscribe(SubscribeCommand),
+    /// Unsubscribe from the [`UnsubscribeCommand`] exchange [`SubscriptionId`]s, if actioned by
+    /// the connection. Connections left without any [`Subscription`]s are gracefully closed.
+    Unsubscribe(UnsubscribeCommand),
+    /// Gracefully close the connection and stop the [`consume`] loop.
+    Shutdown,
+}
+
 /// Central [`MarketEvent<T>`](MarketEvent) consumer loop.
 ///
 /// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
-/// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
-/// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
+/// events are distributed downstream via the `exchange_tx` [`EventSender`]. If the
+/// [`MarketStream`] disconnects (eg/ stream end, close frame, WebSocket error), it is
+/// re-initialised with the same [`Subscription`]s according to the [`ReconnectPolicy`], and
+/// events continue to be sent via the same `exchange_tx`.
+///
+/// Connection lifecycle changes, and periods without any message from the exchange server
+/// exceeding the [`HealthMonitor`] `stale_after` threshold, are distributed as
+/// [`ConnectionEvent`]s via the [`HealthMonitor`].
+///
+/// Every (re-)connection is established according to the provided [`ConnectionOptions`].
+///
+/// Any provided [`StreamMetrics`] are installed on every (re-)initialised [`MarketStream`].
+///
+/// Every transformed event, [`DataError`] & re-connection is recorded in the provided
+/// [`ConnectionStats`].
+///
+/// If a [`WatermarkEmitter`] is provided, the maximum `exchange_time` of the emitted events is
+/// periodically sent as a monotonically advancing [`Watermark`](super::watermark::Watermark),
+/// also advancing whilst the connection is idle but still receiving messages (eg/ pings). Events
+/// with an `exchange_time` before an already emitted watermark are still emitted, but recorded as
+/// late in the [`ConnectionStats`].
+///
+/// If the `exchange_tx` is bounded with [`OverflowPolicy::Block`](super::channel::OverflowPolicy),
+/// a slow receiver pauses this loop, applying backpressure to the [`MarketStream`] WebSocket.
+/// If the `exchange_tx` is an [`EventSender::Handler`], events are handled inline on this loop,
+/// alongside every consumed [`DataError`] other than in-flight messages of unsubscribed
+/// [`Subscription`]s. Otherwise, those [`DataError`]s are sent as
+/// [`ConnectionError`](super::health::ConnectionError)s via the [`HealthMonitor`].
+///
+/// If a bounded `raw_tx` is provided, a [`RawCapture`] is installed on every (re-)initialised
+/// [`MarketStream`], and each emitted event is also sent alongside the [`RawPayload`] it was
+/// normalised from. Pairs are dropped whenever the `raw_tx` channel is full.
+///
+/// The [`SubscriptionOutcome`](crate::subscriber::validator::SubscriptionOutcome) of every
+/// (re-)initialisation is sent as a [`ConnectionEvent::SubscriptionOutcome`]. If partial
+/// success is accepted by the [`SubscriptionAcks`] policy, rejected & timed out
+/// [`Subscription`]s are dropped so they are not re-actioned upon re-connection.
+///
+/// Events that fail [`SubKind::validate`] are handled according to the [`InvalidEventPolicy`],
+/// and events with an out of range `exchange_time` according to any provided
+/// [`TimestampPolicy`]. Valid events not admitted by the [`EventFilter`] are dropped before they
+/// are sent via the `exchange_tx`, and recorded as filtered in the [`ConnectionStats`].
+///
+/// If the exchange has a [`Connector::max_connection_age`], each connection is proactively
+/// rotated before reaching it via a deduplicated [`Handover`] to a new connection, with a
+/// [`ConnectionEvent::Rotated`] sent once the new connection is subscribed. The current
+/// connection continues to be consumed whilst the new connection is initialised, and a failed
+/// rotation is retried after [`ROTATION_RETRY_INTERVAL`] whilst the current connection remains
+/// in use.
+///
+/// [`StreamCommand`]s received via the `commands` [`broadcast::Receiver`] subscribe to or
+/// unsubscribe from individual [`Subscription`]s on the live [`MarketStream`], or gracefully close
+/// it and return `Ok(())`. In-flight messages of unsubscribed [`Subscription`]s are logged at
+/// debug level for the [`UNSUBSCRIBE_GRACE_PERIOD`], or ignored indefinitely if the exchange
+/// cannot unsubscribe.
+///
+/// Returns a [`DataError`] if the [`MarketStream`] fails to initialise on the first attempt, or
+/// if the [`ReconnectPolicy`] is exhausted.
+#[allow(clippy::too_many_arguments)]
 pub async fn consume<Exchange, Kind>(
-    subscriptions: Vec<Subscription<Exchange, Kind>>,
-    exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
-) -> DataError
+    mut subscriptions: Vec<Subscription<Exchange, Kind>>,
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+    policy: ReconnectPolicy,
+    invalid_policy: InvalidEventPolicy,
+    timestamp_policy: Option<TimestampPolicy>,
+    filter: EventFilter<Kind::Event>,
+    acks: SubscriptionAcks,
+    options: ConnectionOptions,
+    health: HealthMonitor,
+    metrics: Option<Arc<dyn StreamMetrics>>,
+    mut stats: ConnectionStats,
+    watermarks: Option<WatermarkEmitter>,
+    raw_tx: Option<mpsc::Sender<(MarketEvent<Kind::Event>, RawPayload)>>,
+    commands: broadcast::Receiver<StreamCommand>,
+) -> Result<(), DataError>
 where
-    Exchange: StreamSelector<Kind>,
-    Kind: SubKind,
+    Exchange: StreamSelector<Kind> + Send + Sync + 'static,
+    Kind: SubKind + Send + Sync + 'static,
     Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
 {
     // Determine ExchangeId associated with these Subscriptions
@@ -35,46 +305,459 @@ where
     info!(
         %exchange,
         ?subscriptions,
-        policy = "retry connection with exponential backoff",
+        ?policy,
+        stale_after = ?health.stale_after,
         "MarketStream consumer loop running",
     );
 
     // Consumer loop retry parameters
+    let mut connected_once = false;
     let mut attempt: u32 = 0;
-    let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
+
+    // Lifecycle commands, and the SubscriptionIds unsubscribed from whose in-flight messages are
+    // ignored until the optional grace period deadline
+    let mut commands = Some(commands);
+    let mut unsubscribed = HashMap::<SubscriptionId, Option<Instant>>::new();
+
+    // Event-time watermark of this connection, retained across re-connections
+    let mut watermark = watermarks.map(|emitter| WatermarkTracker::new(exchange, emitter));
 
     loop {
-        // Increment retry parameters at start of every iteration
-        attempt += 1;
-        backoff_ms *= 2;
         info!(%exchange, attempt, "attempting to initialise MarketStream");
 
         // Attempt to initialise MarketStream: if it fails on first attempt return DataError
-        let mut stream = match Exchange::Stream::init(&subscriptions).await {
-            Ok(stream) => {
-                info!(%exchange, attempt, "successfully initialised MarketStream");
-                attempt = 0;
-                backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
+        let mut stream = match Exchange::Stream::init(&subscriptions, acks, &options).await {
+            Ok((stream, outcome)) => {
+                // Drop rejected & timed out Subscriptions accepted by the AckPolicy
+                if !outcome.is_complete() {
+                    let removed = remove_subscriptions(&mut subscriptions, &outcome.unconfirmed());
+                    stats.deregister(&removed, &subscriptions);
+                    warn!(
+                        %exchange,
+                        attempt,
+                        %outcome,
+                        subscriptions = ?removed,
+                        action = "dropping unconfirmed Subscriptions",
+                        "MarketStream initialised with partial subscription outcome",
+                    );
+                }
+
+                if connected_once {
+                    // Notify consumers that any stateful data (eg/ OrderBooks) should be invalidated
+                    warn!(
+                        %exchange,
+                        attempt,
+                        action = "stateful data (eg/ OrderBooks) should be re-initialised",
+                        "successfully re-connected MarketStream",
+                    );
+                    health.send(ConnectionEvent::Resubscribed { exchange });
+                    stats.record_reconnect();
+                    attempt = 0;
+                } else {
+                    info!(%exchange, attempt, "successfully initialised MarketStream");
+                    health.send(ConnectionEvent::Connected { exchange });
+                    connected_once = true;
+                }
+
+                health.send(ConnectionEvent::SubscriptionOutcome { exchange, outcome });
                 stream
             }
             Err(error) => {
                 error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
 
-                // Exit function function if Stream::init failed the first attempt, else retry
-                if attempt == 1 {
-                    return error;
-                } else {
-                    continue;
+                if let DataError::SubscriptionRejected(outcome) = &error {
+                    health.send(ConnectionEvent::SubscriptionOutcome {
+                        exchange,
+                        outcome: outcome.clone(),
+                    });
+                }
+
+                // Exit function if Stream::init failed the first attempt, or the policy is exhausted
+                attempt += 1;
+                if !connected_once || policy.is_exhausted(attempt) {
+                    return Err(error);
+                }
+
+                match wait_for_backoff(
+                    policy.backoff_jittered(attempt),
+                    &mut commands,
+                    &mut subscriptions,
+                    &mut stats,
+                )
+                .await
+                {
+                    ControlFlow::Continue(()) => continue,
+                    ControlFlow::Break(()) => return Ok(()),
                 }
             }
         };
 
+        // Install the opt-in StreamMetrics on this connection
+        if let Some(metrics) = &metrics {
+            stream.set_metrics(Arc::clone(metrics));
+        }
+
+        // Install the opt-in RawCapture on this connection
+        let mut raw_capture = match &raw_tx {
+            Some(_) => RawCapture::enabled(),
+            None => RawCapture::default(),
+        };
+        if raw_capture.is_enabled() {
+            stream.set_raw_capture(raw_capture.clone());
+        }
+
+        // Monitor liveness of this connection, falling back to the consumed items if the
+        // MarketStream does not provide a Heartbeat
+        let mut liveness = LivenessMonitor::new(
+            exchange,
+            health.stale_after,
+            stream.heartbeat().unwrap_or_default(),
+        );
+        let mut reason = String::from("MarketStream ended");
+
+        // Proactively rotate this connection before it reaches the exchange max connection age
+        let mut rotate_at = Exchange::max_connection_age().map(|age| Instant::now() + age);
+        let mut rotation: Option<Rotation<Exchange::Stream>> = None;
+        let mut handover: Option<Handover<Exchange::Stream>> = None;
+
         // Consume Result<MarketEvent<T>, DataError> from MarketStream
-        while let Some(event_result) = stream.next().await {
+        loop {
+            let deadline = liveness.deadline();
+            let handover_until = handover.as_ref().map(|handover| handover.until);
+            let watermark_at = watermark.as_ref().map(WatermarkTracker::deadline);
+            let (source, event_result) = tokio::select! {
+                event_result = next_unwind(stream.next(), exchange) => {
+                    (HandoverSource::Current, event_result)
+                }
+                event_result = next_unwind(next_previous(&mut handover), exchange) => match event_result {
+                    // Previous connection of the Handover ended early, so complete the Handover
+                    None => {
+                        complete_handover::<Exchange, Kind>(&mut handover);
+                        continue;
+                    }
+                    Some(Err(error)) if error.is_terminal() => {
+                        complete_handover::<Exchange, Kind>(&mut handover);
+                        continue;
+                    }
+                    event_result => (HandoverSource::Previous, event_result),
+                },
+                _ = tokio::time::sleep_until(handover_until.unwrap_or_else(Instant::now)),
+                    if handover_until.is_some() =>
+                {
+                    complete_handover::<Exchange, Kind>(&mut handover);
+                    continue;
+                }
+                _ = tokio::time::sleep_until(rotate_at.unwrap_or_else(Instant::now)),
+                    if rotate_at.is_some() && rotation.is_none() && handover.is_none() =>
+                {
+                    info!(%exchange, "proactively rotating MarketStream connection");
+
+                    // Initialise the next connection whilst the current connection is consumed
+                    rotation = Some(init_rotation::<Exchange, Kind>(
+                        subscriptions.clone(),
+                        acks,
+                        options.clone(),
+                    ));
+                    continue;
+                }
+                next = next_rotation(&mut rotation) => {
+                    rotation = None;
+                    let mut next = match next {
+                        Ok((next, outcome)) => {
+                            if !outcome.is_complete() {
+                                let removed = remove_subscriptions(
+                                    &mut subscriptions,
+                                    &outcome.unconfirmed(),
+                                );
+                                stats.deregister(&removed, &subscriptions);
+                                warn!(
+                                    %exchange,
+                                    %outcome,
+                                    subscriptions = ?removed,
+                                    action = "dropping unconfirmed Subscriptions",
+                                    "rotated MarketStream with partial subscription outcome",
+                                );
+                            }
+                            health.send(ConnectionEvent::SubscriptionOutcome {
+                                exchange,
+                                outcome,
+                            });
+                            next
+                        }
+                        Err(error) => {
+                            warn!(
+                                %exchange,
+                                ?error,
+                                retry_after = ?ROTATION_RETRY_INTERVAL,
+                                action = "continuing with current connection",
+                                "failed to proactively rotate MarketStream connection",
+                            );
+                            rotate_at = Some(Instant::now() + ROTATION_RETRY_INTERVAL);
+                            continue;
+                        }
+                    };
+
+                    if let Some(metrics) = &metrics {
+                        next.set_metrics(Arc::clone(metrics));
+                    }
+                    let next_raw = match &raw_tx {
+                        Some(_) => RawCapture::enabled(),
+                        None => RawCapture::default(),
+                    };
+                    if next_raw.is_enabled() {
+                        next.set_raw_capture(next_raw.clone());
+                    }
+
+                    // Consume both connections for the overlap, before closing the previous one
+                    liveness = LivenessMonitor::new(
+                        exchange,
+                        health.stale_after,
+                        next.heartbeat().unwrap_or_default(),
+                    );
+                    let previous = std::mem::replace(&mut stream, next);
+                    let previous_raw = std::mem::replace(&mut raw_capture, next_raw);
+                    handover = Some(Handover::new(previous, previous_raw, HANDOVER_OVERLAP));
+                    rotate_at = Exchange::max_connection_age().map(|age| Instant::now() + age);
+
+                    info!(
+                        %exchange,
+                        overlap = ?HANDOVER_OVERLAP,
+                        "handing over to rotated MarketStream",
+                    );
+                    health.send(ConnectionEvent::Rotated { exchange });
+                    continue;
+                }
+                command = next_command(&mut commands) => {
+                    match command {
+                        StreamCommand::Subscribe(command) => {
+                            let Some(subscription) = command.claim::<Exchange, Kind>() else {
+                                continue;
+                            };
+
+                            let result = subscribe_live(
+                                &mut stream,
+                                &mut subscriptions,
+                                &mut unsubscribed,
+                                &mut stats,
+                                subscription.clone(),
+                            );
+                            match &result {
+                                Ok(()) => {
+                                    info!(
+                                        %exchange,
+                                        ?subscription,
+                                        "subscribed to Subscription on live MarketStream",
+                                    );
+                                    restart_rotation(&mut rotation, exchange);
+                                }
+                                Err(error) => warn!(
+                                    %exchange,
+                                    %error,
+                                    ?subscription,
+                                    "failed to subscribe to Subscription on live MarketStream",
+                                ),
+                            }
+                            command.respond(result);
+                            continue;
+                        }
+                        StreamCommand::Unsubscribe(command) => {
+                            let removed = remove_subscriptions(
+                                &mut subscriptions,
+                                command.subscription_ids(),
+                            );
+                            if removed.is_empty() {
+                                continue;
+                            }
+                            stats.deregister(&removed, &subscriptions);
+
+                            // Send the exchange specific unsubscribe payloads
+                            let exchange_subs = removed
+                                .iter()
+                                .map(ExchangeSub::<Exchange::Channel, Exchange::Market>::new)
+                                .collect::<Vec<_>>();
+                            let removed_ids =
+                                exchange_subs.iter().map(Identifier::id).collect::<Vec<_>>();
+                            let requests = Exchange::unsubscribe_requests(exchange_subs);
+                            let grace_until = match requests.is_empty() {
+                                true => None,
+                                false => Some(Instant::now() + UNSUBSCRIBE_GRACE_PERIOD),
+                            };
+                            if let Some(handover) = &mut handover {
+                                let _ = handover
+                                    .previous
+                                    .unsubscribe(&removed_ids, requests.clone());
+                            }
+                            let grace_until = match stream.unsubscribe(&removed_ids, requests) {
+                                Ok(()) => {
+                                    info!(
+                                        %exchange,
+                                        subscriptions = ?removed,
+                                        "unsubscribed from Subscriptions",
+                                    );
+                                    grace_until
+                                }
+                                Err(error) => {
+                                    warn!(
+                                        %exchange,
+                                        %error,
+                                        subscriptions = ?removed,
+                                        action = "ignoring any further messages",
+                                        "failed to unsubscribe from Subscriptions",
+                                    );
+                                    None
+                                }
+                            };
+                            prune_unsubscribed(&mut unsubscribed, Instant::now());
+                            unsubscribed.extend(removed_ids.into_iter().map(|id| (id, grace_until)));
+                            restart_rotation(&mut rotation, exchange);
+                            command.ack();
+
+                            if !subscriptions.is_empty() {
+                                continue;
+                            }
+                            info!(%exchange, "no Subscriptions remaining, closing MarketStream");
+                        }
+                        StreamCommand::Shutdown => {
+                            info!(%exchange, "shutting down MarketStream");
+                        }
+                    }
+
+                    // Send a close frame & wait for the exchange server to acknowledge it
+                    if let Some(mut handover) = handover.take() {
+                        handover.previous.close();
+                    }
+                    stream.close();
+                    let _ = tokio::time::timeout(CLOSE_TIMEOUT, async {
+                        while stream.next().await.is_some() {}
+                    })
+                    .await;
+
+                    health.send(ConnectionEvent::Disconnected {
+                        exchange,
+                        reason: String::from("MarketStream closed"),
+                    });
+                    return Ok(());
+                },
+                _ = tokio::time::sleep_until(deadline.unwrap_or_else(Instant::now)),
+                    if deadline.is_some() =>
+                {
+                    if let Some(stale) = liveness.check() {
+                        warn!(
+                            %exchange,
+                            stale_after = ?health.stale_after,
+                            "no message received from exchange within staleness threshold",
+                        );
+                        health.send(stale);
+                    }
+                    continue;
+                }
+                _ = tokio::time::sleep_until(watermark_at.unwrap_or_else(Instant::now)),
+                    if watermark_at.is_some() =>
+                {
+                    if let Some(watermark) = &mut watermark {
+                        watermark.tick(liveness.heartbeat.last());
+                    }
+                    continue;
+                }
+            };
+
+            let Some(event_result) = event_result else {
+                break;
+            };
+
+            // Deduplicate the overlapping events of both connections during a Handover
+            if let (Some(handover), Ok(event)) = (&mut handover, &event_result) {
+                let subscription_id = event_subscription_id(&subscriptions, event);
+                if !handover.dedup.admit(
+                    source,
+                    subscription_id.as_ref(),
+                    Kind::handover_key(event),
+                ) {
+                    continue;
+                }
+            }
+            if source == HandoverSource::Current {
+                liveness.heartbeat.beat();
+            }
+            stats.record(&event_result);
+
+            // Validate the invariants of the event according to the InvalidEventPolicy
+            let event_result = event_result.and_then(|market_event| {
+                match (Kind::validate(&market_event.kind), invalid_policy) {
+                    (Ok(()), _) => Ok(market_event),
+                    (Err(error), InvalidEventPolicy::Reject) => Err(error),
+                    (Err(error), InvalidEventPolicy::Warn) => {
+                        warn!(
+                            %exchange,
+                            %error,
+                            action = "emitting invalid event",
+                            "consumed invalid MarketEvent from MarketStream",
+                        );
+                        Ok(market_event)
+                    }
+                }
+            });
+
+            // Sanity check the exchange_time of the event according to the TimestampPolicy
+            let event_result = match &timestamp_policy {
+                Some(timestamp_policy) => {
+                    event_result.and_then(|event| timestamp_policy.check(event))
+                }
+                None => event_result,
+            };
+
+            // Notify consumers of a MarketStream panic before the disconnection
+            if let Err(DataError::StreamPanicked { exchange, detail }) = &event_result {
+                health.send(ConnectionEvent::Panicked {
+                    exchange: *exchange,
+                    detail: detail.clone(),
+                });
+            }
+
+            // Notify consumers of any error message sent by the exchange server
+            if let Err(DataError::Exchange {
+                code,
+                message,
+                fatal,
+            }) = &event_result
+            {
+                health.send(ConnectionEvent::ExchangeError {
+                    exchange,
+                    code: code.clone(),
+                    message: message.clone(),
+                    fatal: *fatal,
+                });
+            }
+
+            // Forget unsubscribed SubscriptionIds whose grace period has elapsed
+            if let Err(DataError::Socket(SocketError::Unidentifiable(_))) = &event_result {
+                prune_unsubscribed(&mut unsubscribed, Instant::now());
+            }
+
             match event_result {
                 // If Ok: send MarketEvent<T> to exchange receiver
                 Ok(market_event) => {
-                    let _ = exchange_tx.send(market_event).map_err(|err| {
+                    // Drop events not admitted by the opt-in EventFilter
+                    let Some(market_event) = filter.apply(market_event, &stats) else {
+                        continue;
+                    };
+
+                    if let Some(watermark) = &mut watermark {
+                        if watermark.observe(market_event.exchange_time, Instant::now()) {
+                            stats.record_late(&market_event);
+                        }
+                    }
+
+                    if let Some(raw_tx) = &raw_tx {
+                        match (source, &handover) {
+                            (HandoverSource::Previous, Some(handover)) => {
+                                handover.previous_raw.forward(raw_tx, &market_event)
+                            }
+                            _ => raw_capture.forward(raw_tx, &market_event),
+                        }
+                    }
+
+                    let _ = exchange_tx.send(market_event).await.map_err(|err| {
                         error!(
                             payload = ?err.0,
                             why = "receiver dropped",
@@ -82,6 +765,18 @@ where
                         );
                     });
                 }
+                // If in-flight message of an unsubscribed SubscriptionId: log at debug & ignore
+                Err(DataError::Socket(SocketError::Unidentifiable(subscription_id)))
+                    if unsubscribed.contains_key(&subscription_id) =>
+                {
+                    debug!(
+                        %exchange,
+                        %subscription_id,
+                        action = "ignoring in-flight message",
+                        "consumed message of unsubscribed SubscriptionId",
+                    );
+                    continue;
+                }
                 // If terminal DataError: break
                 Err(error) if error.is_terminal() => {
                     error!(
@@ -90,6 +785,10 @@ where
                         action = "re-initialising Stream",
                         "consumed DataError from MarketStream",
                     );
+                    reason = error.to_string();
+                    if let Err(error) = exchange_tx.send_error(error) {
+                        health.send_error(exchange, error);
+                    }
                     break;
                 }
 
@@ -101,18 +800,705 @@ where
                         action = "skipping message",
                         "consumed DataError from MarketStream",
                     );
+                    if let Err(error) = exchange_tx.send_error(error) {
+                        health.send_error(exchange, error);
+                    }
                     continue;
                 }
             }
         }
 
-        // If MarketStream ends unexpectedly, attempt re-connection after backoff_ms
+        // Notify consumers of the disconnection
+        health.send(ConnectionEvent::Disconnected { exchange, reason });
+
+        // If MarketStream ends unexpectedly, attempt re-connection after backoff
+        let backoff = policy.backoff_jittered(attempt);
         warn!(
             %exchange,
-            backoff_ms,
+            backoff_ms = backoff.as_millis() as u64,
             action = "attempt re-connection after backoff",
             "exchange MarketStream unexpectedly ended"
         );
-        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+        if wait_for_backoff(backoff, &mut commands, &mut subscriptions, &mut stats)
+            .await
+            .is_break()
+        {
+            return Ok(());
+        }
+    }
+}
+
+/// In-flight [`MarketStream::init`] of the next connection of a proactive rotation, owning the
+/// [`Subscription`]s & [`ConnectionOptions`] it is initialised with.
+type Rotation<Stream> = BoxFuture<'static, Result<(Stream, SubscriptionOutcome), DataError>>;
+
+/// Start initialising the next connection of a proactive rotation with the current
+/// [`Subscription`]s.
+fn init_rotation<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    acks: SubscriptionAcks,
+    options: ConnectionOptions,
+) -> Rotation<Exchange::Stream>
+where
+    Exchange: StreamSelector<Kind> + Send + Sync + 'static,
+    Kind: SubKind + Send + Sync + 'static,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    Box::pin(async move { Exchange::Stream::init(&subscriptions, acks, &options).await })
+}
+
+/// Wait for the in-flight [`Rotation`], pending forever if no rotation is in progress.
+async fn next_rotation<Stream>(
+    rotation: &mut Option<Rotation<Stream>>,
+) -> Result<(Stream, SubscriptionOutcome), DataError> {
+    match rotation {
+        Some(rotation) => rotation.await,
+        None => futures::future::pending().await,
+    }
+}
+
+/// Abandon any in-flight [`Rotation`] initialised with outdated [`Subscription`]s, so that it is
+/// immediately restarted with the current [`Subscription`]s.
+fn restart_rotation<Stream>(rotation: &mut Option<Rotation<Stream>>, exchange: ExchangeId) {
+    if rotation.take().is_some() {
+        debug!(
+            %exchange,
+            "restarting proactive rotation after Subscriptions changed"
+        );
+    }
+}
+
+/// Complete any in progress [`Handover`], gracefully closing the previous connection.
+fn complete_handover<Exchange, Kind>(handover: &mut Option<Handover<Exchange::Stream>>)
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+{
+    if let Some(mut handover) = handover.take() {
+        info!(exchange = %Exchange::ID, "handover complete, closing previous MarketStream");
+        handover.previous.close();
+    }
+}
+
+/// Await the next item of a [`MarketStream`] connection, catching any panic whilst processing
+/// the message (eg/ an [`ExchangeTransformer`](crate::transformer::ExchangeTransformer)
+/// panicking on a pathological payload) as a terminal [`DataError::StreamPanicked`], so the
+/// connection is re-initialised rather than silently ending the consumer loop.
+async fn next_unwind<Next, Event>(
+    next: Next,
+    exchange: ExchangeId,
+) -> Option<Result<Event, DataError>>
+where
+    Next: Future<Output = Option<Result<Event, DataError>>>,
+{
+    match AssertUnwindSafe(next).catch_unwind().await {
+        Ok(event_result) => event_result,
+        Err(panic) => Some(Err(DataError::StreamPanicked {
+            exchange,
+            detail: panic_detail(panic.as_ref()),
+        })),
+    }
+}
+
+/// Extract the message of a caught panic payload (eg/ "called `Option::unwrap()` on a `None`
+/// value").
+fn panic_detail(panic: &(dyn Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|detail| detail.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| String::from("unknown panic payload"))
+}
+
+/// Receive the next [`StreamCommand`], pending forever once every [`broadcast::Sender`] has been
+/// dropped (eg/ the [`Streams`](super::Streams) were dropped after selecting the receivers).
+async fn next_command(commands: &mut Option<broadcast::Receiver<StreamCommand>>) -> StreamCommand {
+    loop {
+        let Some(commands_rx) = commands else {
+            return std::future::pending().await;
+        };
+
+        match commands_rx.recv().await {
+            Ok(command) => return command,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                warn!(
+                    skipped,
+                    "MarketStream consumer loop lagged behind StreamCommands"
+                );
+            }
+            Err(broadcast::error::RecvError::Closed) => *commands = None,
+        }
+    }
+}
+
+/// Wait for the provided backoff before re-initialising a [`MarketStream`], whilst handling any
+/// [`StreamCommand`]s. Returns [`ControlFlow::Break`] if the [`consume`] loop should stop.
+async fn wait_for_backoff<Exchange, Kind>(
+    backoff: Duration,
+    commands: &mut Option<broadcast::Receiver<StreamCommand>>,
+    subscriptions: &mut Vec<Subscription<Exchange, Kind>>,
+    stats: &mut ConnectionStats,
+) -> ControlFlow<()>
+where
+    Exchange: Connector + Clone + 'static,
+    Kind: Clone + 'static,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let sleep = tokio::time::sleep(backoff);
+    tokio::pin!(sleep);
+
+    loop {
+        tokio::select! {
+            _ = &mut sleep => return ControlFlow::Continue(()),
+            command = next_command(commands) => match command {
+                StreamCommand::Subscribe(command) => {
+                    // Claimed Subscriptions are actioned upon re-connection
+                    if let Some(subscription) = command.claim::<Exchange, Kind>() {
+                        let subscription_id =
+                            ExchangeSub::<Exchange::Channel, Exchange::Market>::new(&subscription)
+                                .id();
+                        remove_subscriptions(subscriptions, &[subscription_id]);
+                        stats.register(&subscription);
+                        subscriptions.push(subscription);
+                        command.respond(Ok(()));
+                    }
+                }
+                StreamCommand::Unsubscribe(command) => {
+                    let removed = remove_subscriptions(subscriptions, command.subscription_ids());
+                    if removed.is_empty() {
+                        continue;
+                    }
+                    stats.deregister(&removed, subscriptions);
+                    command.ack();
+
+                    if subscriptions.is_empty() {
+                        return ControlFlow::Break(());
+                    }
+                }
+                StreamCommand::Shutdown => return ControlFlow::Break(()),
+            },
+        }
+    }
+}
+
+/// Remove the unsubscribed [`SubscriptionId`]s whose grace period has elapsed by `now`, so
+/// any further messages of them are no longer ignored. [`SubscriptionId`]s without a grace
+/// period deadline are retained.
+pub fn prune_unsubscribed(
+    unsubscribed: &mut HashMap<SubscriptionId, Option<Instant>>,
+    now: Instant,
+) {
+    unsubscribed.retain(|_, grace_until| grace_until.map_or(true, |grace_until| now < grace_until));
+}
+
+/// Subscribe to the provided [`Subscription`] on the live [`MarketStream`], adding it to the
+/// `subscriptions` actioned upon re-connection & registering it with the [`ConnectionStats`].
+///
+/// [`Subscription`]s already actioned by the connection are ignored.
+pub fn subscribe_live<Exchange, Kind>(
+    stream: &mut Exchange::Stream,
+    subscriptions: &mut Vec<Subscription<Exchange, Kind>>,
+    unsubscribed: &mut HashMap<SubscriptionId, Option<Instant>>,
+    stats: &mut ConnectionStats,
+    subscription: Subscription<Exchange, Kind>,
+) -> Result<(), SocketError>
+where
+    Exchange: StreamSelector<Kind>,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let subscription_id =
+        ExchangeSub::<Exchange::Channel, Exchange::Market>::new(&subscription).id();
+    let is_actioned = subscriptions.iter().any(|existing| {
+        ExchangeSub::<Exchange::Channel, Exchange::Market>::new(existing).id() == subscription_id
+    });
+    if is_actioned {
+        return Ok(());
+    }
+
+    stream
+        .subscribe(std::slice::from_ref(&subscription))
+        .map_err(|error| match error {
+            DataError::Socket(error) => error,
+            error => SocketError::Subscribe(error.to_string()),
+        })?;
+
+    unsubscribed.remove(&subscription_id);
+    stats.register(&subscription);
+    subscriptions.push(subscription);
+    Ok(())
+}
+
+/// Remove the [`Subscription`]s associated with the provided exchange [`SubscriptionId`]s,
+/// returning the removed [`Subscription`]s.
+pub fn remove_subscriptions<Exchange, Kind>(
+    subscriptions: &mut Vec<Subscription<Exchange, Kind>>,
+    subscription_ids: &[SubscriptionId],
+) -> Vec<Subscription<Exchange, Kind>>
+where
+    Exchange: Connector,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let is_removed = |subscription: &Subscription<Exchange, Kind>| {
+        subscription_ids
+            .contains(&ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id())
+    };
+
+    let (removed, kept) = std::mem::take(subscriptions)
+        .into_iter()
+        .partition(is_removed);
+    *subscriptions = kept;
+    removed
+}
+
+/// [`SubscriptionId`] of the [`Subscription`] the provided [`MarketEvent`] belongs to, being the
+/// first of its [`Instrument`](barter_integration::model::Instrument) whose [`SubKind`]
+/// configuration [`matches`](SubKind::matches) the event (eg/ `Candles(1h)` for a 1h candle).
+fn event_subscription_id<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    event: &MarketEvent<Kind::Event>,
+) -> Option<SubscriptionId>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    subscriptions
+        .iter()
+        .find(|subscription| {
+            subscription.instrument == event.instrument && subscription.kind.matches(&event.kind)
+        })
+        .map(|subscription| {
+            ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::MarketIter,
+        exchange::{
+            binance::{spot::BinanceSpot, trade::BinanceTrade},
+            ExchangeId,
+        },
+        subscription::trade::{PublicTrade, PublicTrades},
+    };
+    use barter_integration::model::{Instrument, InstrumentKind};
+    use chrono::Utc;
+
+    #[test]
+    fn test_reconnect_policy_backoff() {
+        struct TestCase {
+            policy: ReconnectPolicy,
+            attempt: u32,
+            expected: Duration,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first attempt w/ default policy
+                policy: ReconnectPolicy::default(),
+                attempt: 0,
+                expected: Duration::from_millis(125),
+            },
+            TestCase {
+                // TC1: third attempt w/ default policy doubles backoff each attempt
+                policy: ReconnectPolicy::default(),
+                attempt: 3,
+                expected: Duration::from_millis(1000),
+            },
+            TestCase {
+                // TC2: backoff is capped at max_backoff_ms
+                policy: ReconnectPolicy::default(),
+                attempt: 20,
+                expected: Duration::from_millis(MAX_RECONNECT_BACKOFF_MS),
+            },
+            TestCase {
+                // TC3: very large attempt does not overflow
+                policy: ReconnectPolicy::default(),
+                attempt: u32::MAX,
+                expected: Duration::from_millis(MAX_RECONNECT_BACKOFF_MS),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.policy.backoff(test.attempt);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+
+            let jittered = test.policy.backoff_jittered(test.attempt);
+            assert!(
+                jittered >= test.expected / 2 && jittered <= test.expected,
+                "TC{index} failed because jittered backoff {jittered:?} is out of range"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reconnect_policy_is_exhausted() {
+        struct TestCase {
+            policy: ReconnectPolicy,
+            attempt: u32,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: infinite retries are never exhausted
+                policy: ReconnectPolicy::default(),
+                attempt: u32::MAX,
+                expected: false,
+            },
+            TestCase {
+                // TC1: not exhausted before max_attempts
+                policy: ReconnectPolicy {
+                    max_attempts: Some(3),
+                    ..ReconnectPolicy::default()
+                },
+                attempt: 2,
+                expected: false,
+            },
+            TestCase {
+                // TC2: exhausted at max_attempts
+                policy: ReconnectPolicy {
+                    max_attempts: Some(3),
+                    ..ReconnectPolicy::default()
+                },
+                attempt: 3,
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.policy.is_exhausted(test.attempt);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_timestamp_policy_check() {
+        #[derive(Debug)]
+        enum Expected {
+            Unchanged,
+            Clamped,
+            OutOfRange,
+        }
+
+        struct TestCase {
+            policy: TimestampPolicy,
+            trade_time_ms: i64,
+            expected: Expected,
+        }
+
+        let now_ms = Utc::now().timestamp_millis();
+        let reject = TimestampPolicy {
+            action: TimestampAction::Reject,
+            ..TimestampPolicy::default()
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: exchange_time within default tolerance is unchanged
+                policy: TimestampPolicy::default(),
+                trade_time_ms: now_ms - 1_000,
+                expected: Expected::Unchanged,
+            },
+            TestCase {
+                // TC1: garbage exchange_time in the future is clamped to received_time
+                policy: TimestampPolicy::default(),
+                trade_time_ms: 4_102_444_800_000,
+                expected: Expected::Clamped,
+            },
+            TestCase {
+                // TC2: stale exchange_time is clamped to received_time
+                policy: TimestampPolicy::default(),
+                trade_time_ms: now_ms - 10 * 60 * 1_000,
+                expected: Expected::Clamped,
+            },
+            TestCase {
+                // TC3: garbage exchange_time in the future is rejected
+                policy: reject,
+                trade_time_ms: 4_102_444_800_000,
+                expected: Expected::OutOfRange,
+            },
+            TestCase {
+                // TC4: stale exchange_time is rejected
+                policy: reject,
+                trade_time_ms: now_ms - 10 * 60 * 1_000,
+                expected: Expected::OutOfRange,
+            },
+            TestCase {
+                // TC5: stale exchange_time within custom tolerance is unchanged
+                policy: TimestampPolicy {
+                    tolerance: Duration::from_secs(60 * 60),
+                    ..reject
+                },
+                trade_time_ms: now_ms - 10 * 60 * 1_000,
+                expected: Expected::Unchanged,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let payload = format!(
+                r#"{{"e":"trade","E":{0},"s":"BTCUSDT","t":1,"p":"16000.0","q":"0.5","T":{0},"m":true}}"#,
+                test.trade_time_ms
+            );
+            let trade = serde_json::from_str::<BinanceTrade>(&payload).unwrap();
+            let event = MarketIter::<PublicTrade>::from((
+                ExchangeId::BinanceSpot,
+                Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                trade,
+            ))
+            .0
+            .remove(0)
+            .unwrap();
+            let exchange_time = event.exchange_time;
+
+            match (test.policy.check(event), test.expected) {
+                (Ok(actual), Expected::Unchanged) => {
+                    assert_eq!(actual.exchange_time, exchange_time, "TC{} failed", index)
+                }
+                (Ok(actual), Expected::Clamped) => {
+                    assert_eq!(
+                        actual.exchange_time, actual.received_time,
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (
+                    Err(DataError::TimestampOutOfRange {
+                        exchange_time: actual,
+                        ..
+                    }),
+                    Expected::OutOfRange,
+                ) => {
+                    assert_eq!(actual, exchange_time, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_remove_subscriptions() {
+        let trades = |base| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+        };
+
+        let mut subscriptions = vec![trades("btc"), trades("eth"), trades("sol")];
+        let removed = remove_subscriptions(
+            &mut subscriptions,
+            &[
+                SubscriptionId::from("@trade|ETHUSDT"),
+                SubscriptionId::from("@trade|XRPUSDT"),
+            ],
+        );
+
+        assert_eq!(removed, vec![trades("eth")]);
+        assert_eq!(subscriptions, vec![trades("btc"), trades("sol")]);
+    }
+
+    #[test]
+    fn test_prune_unsubscribed() {
+        let btc = SubscriptionId::from("@trade|BTCUSDT");
+        let eth = SubscriptionId::from("@trade|ETHUSDT");
+        let unsubscribed_at = Instant::now();
+        let grace_until = unsubscribed_at + UNSUBSCRIBE_GRACE_PERIOD;
+        let mut unsubscribed =
+            HashMap::from([(btc.clone(), Some(grace_until)), (eth.clone(), None)]);
+
+        // Entries are retained within the grace period
+        prune_unsubscribed(&mut unsubscribed, grace_until - Duration::from_millis(1));
+        assert!(unsubscribed.contains_key(&btc));
+
+        // Entry is gone once the grace period has elapsed, but entries without one are retained
+        prune_unsubscribed(&mut unsubscribed, grace_until);
+        assert_eq!(unsubscribed, HashMap::from([(eth, None)]));
+    }
+
+    #[test]
+    fn test_event_subscription_id() {
+        use crate::subscription::{
+            candle::{Candle, CandleSource, Candles, VolumeDenomination},
+            number::Number,
+            Interval,
+        };
+
+        let candles = |base, interval| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(interval),
+            ))
+        };
+        let candle = |base, interval: Interval| {
+            let start_time = Utc::now();
+            MarketEvent {
+                exchange_time: start_time,
+                received_time: start_time,
+                exchange: barter_integration::model::Exchange::from(ExchangeId::BinanceSpot),
+                instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+                sequence: None,
+                tag: None,
+                kind: Candle {
+                    start_time,
+                    close_time: interval.close_time(start_time),
+                    open: Number::from(1),
+                    high: Number::from(1),
+                    low: Number::from(1),
+                    close: Number::from(1),
+                    volume: Number::from(1),
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: None,
+                    quote_volume: None,
+                    taker_buy_volume: None,
+                    taker_buy_quote_volume: None,
+                    trade_count: 1,
+                    closed: false,
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: false,
+                },
+            }
+        };
+
+        let subscriptions = vec![
+            candles("btc", Interval::Minute1),
+            candles("btc", Interval::Hour1),
+            candles("eth", Interval::Minute1),
+        ];
+
+        struct TestCase {
+            event: MarketEvent<Candle>,
+            expected: Option<SubscriptionId>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m candle is attributed to the 1m Subscription of the Instrument
+                event: candle("btc", Interval::Minute1),
+                expected: Some(SubscriptionId::from("@kline_1m|BTCUSDT")),
+            },
+            TestCase {
+                // TC1: 1h candle of the same Instrument is attributed to the 1h Subscription
+                event: candle("btc", Interval::Hour1),
+                expected: Some(SubscriptionId::from("@kline_1h|BTCUSDT")),
+            },
+            TestCase {
+                // TC2: candle of another Instrument is attributed to its own Subscription
+                event: candle("eth", Interval::Minute1),
+                expected: Some(SubscriptionId::from("@kline_1m|ETHUSDT")),
+            },
+            TestCase {
+                // TC3: candle not matching any Subscription is unattributed
+                event: candle("eth", Interval::Hour1),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = event_subscription_id(&subscriptions, &test.event);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_next_unwind_catches_market_stream_panic() {
+        // Transformer that panics on a pathological payload (eg/ an unexpected null price)
+        let mut stream =
+            futures::stream::iter([r#"{"p":"1.5"}"#, r#"{"p":null}"#]).map(|payload| {
+                let payload = serde_json::from_str::<serde_json::Value>(payload).unwrap();
+                Ok::<_, DataError>(payload["p"].as_str().unwrap().to_owned())
+            });
+
+        // Valid payload is yielded as normal
+        let actual = next_unwind(stream.next(), ExchangeId::BinanceSpot).await;
+        assert!(matches!(actual, Some(Ok(price)) if price == "1.5"));
+
+        // Panic is caught & surfaced as a terminal DataError::StreamPanicked
+        let actual = next_unwind(stream.next(), ExchangeId::BinanceSpot).await;
+        match actual {
+            Some(Err(error @ DataError::StreamPanicked { exchange, .. })) => {
+                assert_eq!(exchange, ExchangeId::BinanceSpot);
+                assert!(error.is_terminal());
+                assert!(error
+                    .to_string()
+                    .contains("`Option::unwrap()` on a `None` value"));
+            }
+            actual => panic!("expected DataError::StreamPanicked, actual: {actual:?}"),
+        }
+
+        // Stream ends as normal
+        assert!(next_unwind(stream.next(), ExchangeId::BinanceSpot)
+            .await
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_next_unwind_binance_trade_transformer_poisoned_payload() {
+        use crate::{
+            test_util::{instrument_map, parse_frame, Fixtures},
+            transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+        };
+        use barter_integration::Transformer;
+
+        let fixtures = Fixtures::load("binance", "trades");
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+        let mut transformer = StatelessTransformer::<BinanceSpot, PublicTrades, BinanceTrade>::new(
+            ws_sink_tx,
+            instrument_map(&[subscription]),
+        )
+        .await
+        .unwrap();
+
+        // Real Binance trade transformer fed a poisoned frame w/ an out of range trade time
+        // between two valid frames
+        let mut stream =
+            futures::stream::iter(["spot_trade", "malformed_time_out_of_range", "spot_trade"])
+                .flat_map(|fixture| {
+                    let output =
+                        match parse_frame::<BinanceSpot, BinanceTrade>(fixtures.get(fixture)) {
+                            Ok(input) => transformer.transform(input),
+                            Err(error) => vec![Err(DataError::Socket(error))],
+                        };
+                    futures::stream::iter(output)
+                });
+
+        // Valid frame is yielded as normal
+        let actual = next_unwind(stream.next(), ExchangeId::BinanceSpot).await;
+        assert!(matches!(actual, Some(Ok(event)) if event.kind.id == "1000000000"));
+
+        // Poisoned frame is a non-terminal deserialisation error rather than a panic
+        let actual = next_unwind(stream.next(), ExchangeId::BinanceSpot).await;
+        match actual {
+            Some(Err(error @ DataError::Socket(SocketError::Deserialise { .. }))) => {
+                assert!(!error.is_terminal());
+            }
+            actual => panic!("expected DataError::Socket(Deserialise), actual: {actual:?}"),
+        }
+
+        // Connection keeps yielding valid frames after the poisoned frame
+        let actual = next_unwind(stream.next(), ExchangeId::BinanceSpot).await;
+        assert!(matches!(actual, Some(Ok(event)) if event.kind.id == "1000000000"));
+        assert!(next_unwind(stream.next(), ExchangeId::BinanceSpot)
+            .await
+            .is_none());
     }
 }
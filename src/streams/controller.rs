@@ -0,0 +1,558 @@
+use super::consumer::StreamCommand;
+use crate::{
+    exchange::{subscription::ExchangeSub, ExchangeId, StreamSelector},
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::{error::SocketError, model::SubscriptionId, Validator};
+use std::{
+    any::Any,
+    collections::HashMap,
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::sync::{broadcast, oneshot};
+
+/// Cloneable handle for managing the [`Subscription`]s of running [`Streams`](super::Streams)
+/// at runtime, obtained via [`Streams::controller`](super::Streams::controller).
+///
+/// [`Subscription`]s are added to & removed from the existing connections of each exchange, so
+/// the other live feeds are unaffected, and events of newly added [`Subscription`]s are sent via
+/// the existing exchange receivers.
+#[derive(Clone, Debug)]
+pub struct StreamController {
+    commands: HashMap<ExchangeId, broadcast::Sender<StreamCommand>>,
+}
+
+impl StreamController {
+    /// Construct a new [`Self`] from the [`StreamCommand`] senders of every exchange.
+    pub fn new(commands: HashMap<ExchangeId, broadcast::Sender<StreamCommand>>) -> Self {
+        Self { commands }
+    }
+
+    /// Subscribe to the provided [`Subscription`] via a running connection of the same exchange
+    /// & [`SubKind`], sending the exchange specific subscription payloads (see
+    /// [`Connector::live_requests`](crate::exchange::Connector::live_requests)) on the existing
+    /// WebSocket.
+    ///
+    /// Returns a [`SocketError::Unsupported`] if the exchange cannot subscribe on a live
+    /// connection (eg/ [`UpbitSpot`](crate::exchange::upbit::UpbitSpot)), or a
+    /// [`SocketError::Subscribe`] if there are no running connections of the exchange & [`SubKind`].
+    pub async fn subscribe<Exchange, Kind>(
+        &self,
+        subscription: Subscription<Exchange, Kind>,
+    ) -> Result<(), SocketError>
+    where
+        Exchange: StreamSelector<Kind> + Send + Sync + 'static,
+        Kind: SubKind + Send + Sync + 'static,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Validate the Subscription before sending it to the running connections
+        (&subscription)
+            .validate()
+            .and_then(|subscription| Exchange::validate_market(&subscription.instrument))
+            .and_then(|_| Exchange::validate_subscriptions(std::slice::from_ref(&subscription)))?;
+
+        let (command, response_rx) = SubscribeCommand::new(subscription);
+        self.send(Exchange::ID, StreamCommand::Subscribe(command))?;
+
+        // Response Sender is dropped without a response if no connection claimed the command
+        response_rx.await.unwrap_or_else(|_| {
+            Err(SocketError::Subscribe(format!(
+                "no running {} {} connections",
+                Exchange::ID,
+                std::any::type_name::<Kind>(),
+            )))
+        })
+    }
+
+    /// Unsubscribe from the provided [`Subscription`], sending the exchange specific unsubscribe
+    /// payloads (see [`Connector::unsubscribe_requests`](crate::exchange::Connector::unsubscribe_requests))
+    /// via the connection that actioned it.
+    ///
+    /// Any in-flight messages of the [`Subscription`] are ignored for the
+    /// [`UNSUBSCRIBE_GRACE_PERIOD`](super::consumer::UNSUBSCRIBE_GRACE_PERIOD), and connections
+    /// left without any [`Subscription`]s are gracefully closed.
+    ///
+    /// Returns a [`SocketError::Subscribe`] if there are no running connections of the exchange,
+    /// or none of them actioned the [`Subscription`].
+    pub async fn unsubscribe<Exchange, Kind>(
+        &self,
+        subscription: Subscription<Exchange, Kind>,
+    ) -> Result<(), SocketError>
+    where
+        Exchange: StreamSelector<Kind>,
+        Kind: SubKind,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let subscription_id =
+            ExchangeSub::<Exchange::Channel, Exchange::Market>::new(&subscription).id();
+
+        let (command, ack_rx) = UnsubscribeCommand::new(vec![subscription_id.clone()]);
+        self.send(Exchange::ID, StreamCommand::Unsubscribe(command))?;
+
+        // Ack Sender is dropped without an ack if no connection actioned the Subscription
+        ack_rx.await.map_err(|_| {
+            SocketError::Subscribe(format!(
+                "no running {} connections subscribed to {subscription_id}",
+                Exchange::ID,
+            ))
+        })
+    }
+
+    /// Send a [`StreamCommand`] to every connection of the provided exchange.
+    fn send(&self, exchange: ExchangeId, command: StreamCommand) -> Result<(), SocketError> {
+        self.commands
+            .get(&exchange)
+            .and_then(|commands_tx| commands_tx.send(command).ok())
+            .map(|_| ())
+            .ok_or_else(|| SocketError::Subscribe(format!("no running {exchange} connections")))
+    }
+}
+
+/// Communicative type alias for the [`oneshot::Sender`] of the result of a [`SubscribeCommand`].
+pub type SubscribeResponseTx = oneshot::Sender<Result<(), SocketError>>;
+
+/// Type erased [`Subscription`] sent to every connection of an exchange via
+/// [`StreamCommand::Subscribe`], actioned by the first connection of the same [`SubKind`] to
+/// [`claim`](Self::claim) it.
+#[derive(Clone)]
+pub struct SubscribeCommand {
+    subscription: Arc<dyn Any + Send + Sync>,
+    claimed: Arc<AtomicBool>,
+    response_tx: Arc<Mutex<Option<SubscribeResponseTx>>>,
+}
+
+impl Debug for SubscribeCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscribeCommand")
+            .field("claimed", &self.claimed.load(Ordering::Acquire))
+            .finish()
+    }
+}
+
+impl PartialEq for SubscribeCommand {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.claimed, &other.claimed)
+    }
+}
+
+impl Eq for SubscribeCommand {}
+
+impl SubscribeCommand {
+    /// Construct a new [`Self`] for the provided [`Subscription`], alongside the
+    /// [`oneshot::Receiver`] of the response sent by the connection that claims it.
+    pub fn new<Exchange, Kind>(
+        subscription: Subscription<Exchange, Kind>,
+    ) -> (Self, oneshot::Receiver<Result<(), SocketError>>)
+    where
+        Exchange: Send + Sync + 'static,
+        Kind: Send + Sync + 'static,
+    {
+        let (response_tx, response_rx) = oneshot::channel();
+        let command = Self {
+            subscription: Arc::new(subscription),
+            claimed: Arc::new(AtomicBool::new(false)),
+            response_tx: Arc::new(Mutex::new(Some(response_tx))),
+        };
+
+        (command, response_rx)
+    }
+
+    /// Claim the [`Subscription`] if it is of the provided exchange & [`SubKind`], and has not
+    /// already been claimed by another connection.
+    pub fn claim<Exchange, Kind>(&self) -> Option<Subscription<Exchange, Kind>>
+    where
+        Exchange: Clone + 'static,
+        Kind: Clone + 'static,
+    {
+        let subscription = self
+            .subscription
+            .downcast_ref::<Subscription<Exchange, Kind>>()?;
+
+        self.claimed
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| subscription.clone())
+    }
+
+    /// Send the result of actioning the claimed [`Subscription`] to the [`StreamController`].
+    pub fn respond(&self, result: Result<(), SocketError>) {
+        let response_tx = self
+            .response_tx
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+
+        if let Some(response_tx) = response_tx {
+            let _ = response_tx.send(result);
+        }
+    }
+}
+
+/// Exchange [`SubscriptionId`]s sent to every connection of an exchange via
+/// [`StreamCommand::Unsubscribe`], acknowledged by the first connection that actions any of them.
+#[derive(Clone)]
+pub struct UnsubscribeCommand {
+    subscription_ids: Vec<SubscriptionId>,
+    ack_tx: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+}
+
+impl Debug for UnsubscribeCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnsubscribeCommand")
+            .field("subscription_ids", &self.subscription_ids)
+            .finish()
+    }
+}
+
+impl PartialEq for UnsubscribeCommand {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.ack_tx, &other.ack_tx)
+    }
+}
+
+impl Eq for UnsubscribeCommand {}
+
+impl UnsubscribeCommand {
+    /// Construct a new [`Self`] for the provided exchange [`SubscriptionId`]s, alongside the
+    /// [`oneshot::Receiver`] of the ack sent by the connection that actions them.
+    pub fn new(subscription_ids: Vec<SubscriptionId>) -> (Self, oneshot::Receiver<()>) {
+        let (ack_tx, ack_rx) = oneshot::channel();
+        let command = Self {
+            subscription_ids,
+            ack_tx: Arc::new(Mutex::new(Some(ack_tx))),
+        };
+
+        (command, ack_rx)
+    }
+
+    /// Exchange [`SubscriptionId`]s to unsubscribe from.
+    pub fn subscription_ids(&self) -> &[SubscriptionId] {
+        &self.subscription_ids
+    }
+
+    /// Acknowledge that the [`SubscriptionId`]s actioned by the connection were unsubscribed from.
+    pub fn ack(&self) {
+        let ack_tx = self
+            .ack_tx
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take();
+
+        if let Some(ack_tx) = ack_tx {
+            let _ = ack_tx.send(());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        distribute_messages_to_exchange,
+        error::DataError,
+        exchange::{binance::spot::BinanceSpot, upbit::UpbitSpot, Connector},
+        streams::{consumer::subscribe_live, health::HeartbeatStream, stats::StatsRegistry},
+        subscriber::connection::{connect, ConnectionOptions},
+        subscription::{candle::Candles, trade::PublicTrades, Interval},
+        test_util::instrument_map,
+        transformer::ExchangeTransformer,
+        ExchangeWsStream, WsConnection,
+    };
+    use barter_integration::{
+        model::{Instrument, InstrumentKind},
+        protocol::websocket::WsMessage,
+    };
+    use futures::{SinkExt, StreamExt};
+    use tokio::{net::TcpListener, sync::mpsc};
+    use tokio_tungstenite::WebSocketStream;
+    use url::Url;
+
+    /// Connect an [`ExchangeWsStream`] that has actioned the provided [`Subscription`]s to a
+    /// mock WebSocket server, returning it alongside the server side of the WebSocket.
+    async fn mock_stream<Exchange, Kind, Transformer>(
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> (
+        ExchangeWsStream<Transformer, Exchange::Decoder>,
+        WebSocketStream<tokio::net::TcpStream>,
+    )
+    where
+        Exchange: Connector,
+        Kind: SubKind,
+        Transformer: ExchangeTransformer<Exchange, Kind>,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("ws://{}", listener.local_addr().unwrap())).unwrap();
+        let options = ConnectionOptions::default();
+        let (websocket, server) = tokio::join!(connect(&url, &options), async {
+            let (stream, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(stream).await.unwrap()
+        });
+
+        let (ws_sink, ws_stream) = websocket.unwrap().split();
+        let (ws_sink_tx, ws_sink_rx) = mpsc::unbounded_channel();
+        tokio::spawn(distribute_messages_to_exchange(
+            Exchange::ID,
+            ws_sink,
+            ws_sink_rx,
+        ));
+        let transformer = Transformer::new(ws_sink_tx.clone(), instrument_map(subscriptions))
+            .await
+            .unwrap();
+
+        let stream = ExchangeWsStream::new(
+            WsConnection {
+                stream: HeartbeatStream::new(ws_stream),
+                ws_sink_tx,
+            },
+            transformer,
+        );
+
+        (stream, server)
+    }
+
+    #[test]
+    fn test_subscribe_command_claim() {
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            Candles(Interval::Minute1),
+        ));
+        let (command, _response_rx) = SubscribeCommand::new(subscription.clone());
+        let received = command.clone();
+
+        // Connections of a different SubKind cannot claim the Subscription
+        assert_eq!(received.claim::<BinanceSpot, PublicTrades>(), None);
+
+        // First connection of the same SubKind claims the Subscription, exactly once
+        assert_eq!(received.claim::<BinanceSpot, Candles>(), Some(subscription));
+        assert_eq!(command.claim::<BinanceSpot, Candles>(), None);
+        assert_eq!(command, received);
+    }
+
+    #[tokio::test]
+    async fn test_stream_controller_subscribe() {
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            Candles(Interval::Minute1),
+        ));
+
+        // No running connections of the exchange
+        let controller = StreamController::new(HashMap::new());
+        assert!(matches!(
+            controller.subscribe(subscription.clone()).await,
+            Err(SocketError::Subscribe(_))
+        ));
+
+        // Invalid Subscriptions are rejected before being sent to the connections
+        let (commands_tx, mut commands_rx) = broadcast::channel(8);
+        let controller =
+            StreamController::new(HashMap::from([(ExchangeId::BinanceSpot, commands_tx)]));
+        let invalid = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::FuturePerpetual,
+            Candles(Interval::Minute1),
+        ));
+        assert!(controller.subscribe(invalid).await.is_err());
+        assert!(commands_rx.try_recv().is_err());
+
+        // Connection of the same SubKind claims & responds
+        tokio::spawn(async move {
+            while let Ok(StreamCommand::Subscribe(command)) = commands_rx.recv().await {
+                if command.claim::<BinanceSpot, Candles>().is_some() {
+                    command.respond(Ok(()));
+                }
+            }
+        });
+        assert!(controller.subscribe(subscription.clone()).await.is_ok());
+
+        // No connection of the same SubKind claims the Subscription
+        let trades = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+        assert!(matches!(
+            controller.subscribe(trades).await,
+            Err(SocketError::Subscribe(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_stream_controller_unsubscribe() {
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+
+        // No running connections of the exchange
+        let controller = StreamController::new(HashMap::new());
+        assert!(matches!(
+            controller.unsubscribe(subscription.clone()).await,
+            Err(SocketError::Subscribe(_))
+        ));
+
+        // Connection that actioned the Subscription acks, whilst the others ignore it
+        let (commands_tx, mut commands_rx) = broadcast::channel(8);
+        let mut other_rx = commands_tx.subscribe();
+        let controller =
+            StreamController::new(HashMap::from([(ExchangeId::BinanceSpot, commands_tx)]));
+        tokio::spawn(async move {
+            while let Ok(StreamCommand::Unsubscribe(command)) = commands_rx.recv().await {
+                if command.subscription_ids() == [SubscriptionId::from("@trade|BTCUSDT")] {
+                    command.ack();
+                }
+            }
+        });
+        tokio::spawn(async move { while other_rx.recv().await.is_ok() {} });
+        assert!(controller.unsubscribe(subscription).await.is_ok());
+
+        // No connection actioned the Subscription
+        let unknown = Subscription::from((
+            BinanceSpot::default(),
+            "eth",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+        assert!(matches!(
+            controller.unsubscribe(unknown).await,
+            Err(SocketError::Subscribe(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_live_sends_payloads_and_routes_new_instrument() {
+        let mut subscriptions = vec![Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            Candles(Interval::Minute1),
+        ))];
+        let added = Subscription::from((
+            BinanceSpot::default(),
+            "bnb",
+            "btc",
+            InstrumentKind::Spot,
+            Candles(Interval::Minute1),
+        ));
+        let kline = r#"{"e":"kline","E":1672516000000,"s":"BNBBTC","k":{"t":1672515960000,"T":1672516019999,"s":"BNBBTC","i":"1m","f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015","v":"1000","n":100,"x":true,"q":"1.0000","V":"500","Q":"0.500","B":"123456"}}"#;
+
+        let (mut stream, mut server) = mock_stream::<_, _, _>(&subscriptions).await;
+        let mut unsubscribed = HashMap::new();
+        let registry = StatsRegistry::default();
+        let mut stats = registry.register(&subscriptions);
+
+        // Messages of the Instrument are unidentifiable before subscribing
+        server
+            .send(WsMessage::Text(kline.to_owned()))
+            .await
+            .unwrap();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(DataError::Socket(SocketError::Unidentifiable(_))))
+        ));
+
+        subscribe_live::<BinanceSpot, Candles>(
+            &mut stream,
+            &mut subscriptions,
+            &mut unsubscribed,
+            &mut stats,
+            added.clone(),
+        )
+        .unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        assert!(registry.snapshot()[&ExchangeId::BinanceSpot]
+            .contains_key(&SubscriptionId::from("@kline_1m|BNBBTC")));
+
+        // Exchange specific subscription payload is sent on the existing connection
+        let payload = match server.next().await {
+            Some(Ok(WsMessage::Text(payload))) => payload,
+            other => panic!("expected subscription payload, found: {other:?}"),
+        };
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&payload).unwrap(),
+            serde_json::json!({ "method": "SUBSCRIBE", "params": ["bnbbtc@kline_1m"], "id": 1 })
+        );
+
+        // Messages of the newly added Instrument are routed to the existing stream
+        server
+            .send(WsMessage::Text(kline.to_owned()))
+            .await
+            .unwrap();
+        let event = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            event.instrument,
+            Instrument::from(("bnb", "btc", InstrumentKind::Spot))
+        );
+
+        // Events of the newly added Subscription are counted
+        stats.record(&Ok(event));
+        assert_eq!(
+            registry.snapshot()[&ExchangeId::BinanceSpot]
+                [&SubscriptionId::from("@kline_1m|BNBBTC")]
+                .events_received,
+            1
+        );
+
+        // Subscriptions already actioned by the connection are ignored
+        subscribe_live::<BinanceSpot, Candles>(
+            &mut stream,
+            &mut subscriptions,
+            &mut unsubscribed,
+            &mut stats,
+            added,
+        )
+        .unwrap();
+        assert_eq!(subscriptions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_live_unsupported_replace_all_exchange() {
+        let mut subscriptions = vec![Subscription::from((
+            UpbitSpot,
+            "btc",
+            "krw",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))];
+        let (mut stream, _server) = mock_stream::<_, _, _>(&subscriptions).await;
+
+        let registry = StatsRegistry::default();
+        let actual = subscribe_live::<UpbitSpot, PublicTrades>(
+            &mut stream,
+            &mut subscriptions,
+            &mut HashMap::new(),
+            &mut registry.register(&subscriptions),
+            Subscription::from((UpbitSpot, "eth", "krw", InstrumentKind::Spot, PublicTrades)),
+        );
+
+        assert!(matches!(
+            actual,
+            Err(SocketError::Unsupported {
+                entity: "upbit_spot",
+                ..
+            })
+        ));
+        assert_eq!(subscriptions.len(), 1);
+        assert_eq!(registry.snapshot()[&ExchangeId::UpbitSpot].len(), 1);
+    }
+}
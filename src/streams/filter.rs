@@ -0,0 +1,215 @@
+use super::stats::ConnectionStats;
+use crate::event::MarketEvent;
+use std::{fmt::Debug, sync::Arc};
+
+/// Predicate deciding if a [`MarketEvent`] is sent downstream, see [`EventFilter`].
+pub type FilterFn<T> = Arc<dyn Fn(&MarketEvent<T>) -> bool + Send + Sync>;
+
+/// Opt-in [`MarketEvent`] filter applied by the [`consume`](super::consumer::consume) loop of a
+/// connection before events are sent to the exchange output channel, so dropped events never
+/// occupy the channel.
+///
+/// Installed via [`StreamBuilder::with_filter`](super::builder::StreamBuilder::with_filter) and
+/// [`StreamBuilder::subscribe_filtered`](super::builder::StreamBuilder::subscribe_filtered).
+///
+/// ### Notes
+/// - [`DataError`](crate::error::DataError)s are never passed to, or dropped by, the filter.
+/// - Without an installed filter each event is a single `Option` check, so the hot path is
+///   unaffected.
+/// - Dropped events are counted per [`Subscription`](crate::subscription::Subscription) via
+///   [`Streams::stats`](super::Streams::stats).
+pub struct EventFilter<T>(Option<FilterFn<T>>);
+
+impl<T> EventFilter<T> {
+    /// Construct a new [`Self`] admitting only the [`MarketEvent`]s the `filter` returns `true`
+    /// for.
+    pub fn new<F>(filter: F) -> Self
+    where
+        F: Fn(&MarketEvent<T>) -> bool + Send + Sync + 'static,
+    {
+        Self(Some(Arc::new(filter)))
+    }
+
+    /// Determine if a filter is installed.
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Combine with the `other` [`EventFilter`], admitting only the [`MarketEvent`]s admitted by
+    /// both.
+    pub fn and(self, other: Self) -> Self
+    where
+        T: 'static,
+    {
+        match (self.0, other.0) {
+            (Some(first), Some(second)) => {
+                Self(Some(Arc::new(move |event| first(event) && second(event))))
+            }
+            (Some(filter), None) | (None, Some(filter)) => Self(Some(filter)),
+            (None, None) => Self(None),
+        }
+    }
+
+    /// Determine if the provided [`MarketEvent`] should be sent downstream.
+    pub fn admit(&self, event: &MarketEvent<T>) -> bool {
+        self.0.as_ref().map_or(true, |filter| filter(event))
+    }
+
+    /// Return the provided [`MarketEvent`] if admitted, otherwise record it as filtered in the
+    /// [`ConnectionStats`] and return `None`.
+    pub fn apply(&self, event: MarketEvent<T>, stats: &ConnectionStats) -> Option<MarketEvent<T>> {
+        if self.admit(&event) {
+            Some(event)
+        } else {
+            stats.record_filtered(&event);
+            None
+        }
+    }
+}
+
+impl<T> Default for EventFilter<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> Clone for EventFilter<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Debug for EventFilter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("EventFilter")
+            .field(&self.is_enabled())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::MarketIter,
+        exchange::{
+            binance::{spot::BinanceSpot, trade::BinanceTrade},
+            ExchangeId,
+        },
+        streams::stats::StatsRegistry,
+        subscription::{
+            number,
+            trade::{PublicTrade, PublicTrades},
+            Subscription,
+        },
+    };
+    use barter_integration::model::{Instrument, InstrumentKind, SubscriptionId};
+    use tokio::sync::mpsc;
+
+    fn binance_trade(id: u64, price: &str, amount: &str) -> MarketEvent<PublicTrade> {
+        let trade = serde_json::from_str::<BinanceTrade>(&format!(
+            r#"{{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":{id},"p":"{price}","q":"{amount}","b":10,"a":11,"T":1649324825173,"m":true,"M":true}}"#
+        ))
+        .unwrap();
+
+        MarketIter::<PublicTrade>::from((
+            ExchangeId::BinanceSpot,
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            trade,
+        ))
+        .0
+        .remove(0)
+        .unwrap()
+    }
+
+    #[test]
+    fn test_event_filter_and() {
+        struct TestCase {
+            input: EventFilter<PublicTrade>,
+            expected: Vec<bool>,
+        }
+
+        let trade_one =
+            || EventFilter::new(|event: &MarketEvent<PublicTrade>| event.kind.id == "1");
+        let large = || {
+            EventFilter::new(|event: &MarketEvent<PublicTrade>| {
+                event.kind.amount >= number::from_f64(1.0)
+            })
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: no filters admit every event
+                input: EventFilter::default().and(EventFilter::default()),
+                expected: vec![true, true, true],
+            },
+            TestCase {
+                // TC1: single filter is applied as is
+                input: EventFilter::default().and(large()),
+                expected: vec![false, true, true],
+            },
+            TestCase {
+                // TC2: both filters must admit the event
+                input: trade_one().and(large()),
+                expected: vec![false, true, false],
+            },
+        ];
+
+        let events = [
+            binance_trade(0, "100.0", "0.5"),
+            binance_trade(1, "100.0", "1.0"),
+            binance_trade(2, "100.0", "2.0"),
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = events
+                .iter()
+                .map(|event| test.input.admit(event))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_filter_notional_binance_trades() {
+        let registry = StatsRegistry::default();
+        let stats = registry.register(&[Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))]);
+
+        // Only forward trades with a notional of at least 10,000
+        let filter = EventFilter::new(|event: &MarketEvent<PublicTrade>| {
+            event.kind.price * event.kind.amount >= number::from_f64(10_000.0)
+        });
+
+        let trades = vec![
+            binance_trade(1, "20000.0", "0.1"),
+            binance_trade(2, "20000.0", "0.5"),
+            binance_trade(3, "20000.0", "0.4999"),
+            binance_trade(4, "20000.0", "2.0"),
+            binance_trade(5, "5000.0", "1.0"),
+        ];
+
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        for trade in trades {
+            if let Some(trade) = filter.apply(trade, &stats) {
+                exchange_tx.send(trade).unwrap();
+            }
+        }
+        drop(exchange_tx);
+
+        let mut actual = Vec::new();
+        while let Some(event) = exchange_rx.recv().await {
+            actual.push(event.kind.id);
+        }
+        assert_eq!(actual, vec!["2".to_string(), "4".to_string()]);
+
+        let stats =
+            registry.snapshot()[&ExchangeId::BinanceSpot][&SubscriptionId::from("@trade|BTCUSDT")];
+        assert_eq!(stats.filtered_events, 3);
+    }
+}
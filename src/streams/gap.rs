@@ -0,0 +1,471 @@
+use super::conflate::sleep_until;
+use crate::{
+    event::MarketEvent,
+    streams::channel::{EventReceiver, EventSender},
+    subscription::{candle::Candle, number, Interval},
+};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use std::{collections::HashMap, time::Duration};
+use tokio::time::Instant;
+
+/// Synthesizes the [`Candle`]s an exchange did not emit for an [`Interval`] boundary (eg/
+/// illiquid pairs with zero trades, or stalled exchange updates), per [`Instrument`] &
+/// [`Interval`] series.
+///
+/// ### Notes
+/// - A boundary is covered once any [`Candle`] (in-progress or closed) starting at the boundary
+///   is received.
+/// - If no [`Candle`] is received for the next boundary within the `grace` period after it
+///   closes, a closed [`Candle`] with open, high, low & close equal to the previous close,
+///   and zero volume & trade count, is synthesized with [`Candle::synthetic`] set.
+/// - If a received [`Candle`] skips boundaries, the skipped boundaries are synthesized
+///   immediately before it is forwarded.
+/// - Series are only tracked once their first [`Candle`] is received, since there is no
+///   previous close to synthesize from.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CandleGapFill {
+    grace: Duration,
+    intervals: HashMap<Instrument, Vec<Interval>>,
+    series: HashMap<(Instrument, Interval), CandleSeries>,
+}
+
+/// Gap filling state of a single [`Instrument`] & [`Interval`] series.
+#[derive(Clone, PartialEq, Debug)]
+struct CandleSeries {
+    exchange: Exchange,
+    /// Most recent [`Candle`] of the series, used as the previous close.
+    previous: Candle,
+    /// Start time of the next [`Interval`] boundary expected to be received.
+    next_start: DateTime<Utc>,
+    /// Instant by which a [`Candle`] starting at `next_start` must be received before it is
+    /// synthesized.
+    deadline: Instant,
+}
+
+impl CandleGapFill {
+    /// Construct a new [`CandleGapFill`] for the provided [`Instrument`] & [`Interval`]
+    /// subscriptions, synthesizing missing [`Candle`]s once the `grace` period has elapsed.
+    pub fn new<Iter>(subscriptions: Iter, grace: Duration) -> Self
+    where
+        Iter: IntoIterator<Item = (Instrument, Interval)>,
+    {
+        let mut intervals = HashMap::<Instrument, Vec<Interval>>::new();
+        for (instrument, interval) in subscriptions {
+            intervals.entry(instrument).or_default().push(interval);
+        }
+
+        Self {
+            grace,
+            intervals,
+            series: HashMap::new(),
+        }
+    }
+
+    /// Earliest deadline of every tracked series, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.series.values().map(|series| series.deadline).min()
+    }
+
+    /// Update the [`CandleGapFill`] with the next received [`Candle`] at the provided `now`,
+    /// returning the [`Candle`]s to forward (ie/ any synthesized skipped boundaries, followed
+    /// by the received [`Candle`]).
+    pub fn update(&mut self, event: MarketEvent<Candle>, now: Instant) -> Vec<MarketEvent<Candle>> {
+        // Determine the subscribed Interval of the Candle, forwarding unknown Candles untouched
+        let Some(interval) = self.intervals.get(&event.instrument).and_then(|intervals| {
+            intervals.iter().copied().find(|interval| {
+                interval.close_time(event.kind.start_time) == event.kind.close_time
+            })
+        }) else {
+            return vec![event];
+        };
+
+        let next_start = next_start(interval, event.kind.start_time);
+        let deadline = now
+            + (interval.close_time(next_start) - event.received_time)
+                .to_std()
+                .unwrap_or_default()
+            + self.grace;
+
+        let key = (event.instrument.clone(), interval);
+        let Some(series) = self.series.get_mut(&key) else {
+            self.series.insert(
+                key,
+                CandleSeries {
+                    exchange: event.exchange.clone(),
+                    previous: event.kind,
+                    next_start,
+                    deadline,
+                },
+            );
+            return vec![event];
+        };
+
+        let mut output = Vec::new();
+        if event.kind.start_time >= series.next_start {
+            // Exchange skipped boundaries before this Candle, so synthesize them immediately
+            while series.next_start < event.kind.start_time {
+                output.push(series.synthesize(&event.instrument, interval));
+            }
+            series.next_start = next_start;
+            series.deadline = deadline;
+        }
+
+        // Late Candles of a previous boundary do not update the previous close
+        if event.kind.start_time >= series.previous.start_time {
+            series.previous = event.kind;
+        }
+
+        output.push(event);
+        output
+    }
+
+    /// Synthesize a [`Candle`] for every boundary whose deadline has elapsed at the provided
+    /// `now`.
+    pub fn expire(&mut self, now: Instant) -> Vec<MarketEvent<Candle>> {
+        let mut output = Vec::new();
+        for ((instrument, interval), series) in self.series.iter_mut() {
+            while series.deadline <= now {
+                let close_time = interval.close_time(series.next_start);
+                output.push(series.synthesize(instrument, *interval));
+
+                // Next boundary is due one (possibly calendar aware) Interval later
+                series.deadline += (interval.close_time(series.next_start) - close_time)
+                    .to_std()
+                    .unwrap_or_default();
+            }
+        }
+
+        output.sort_by_key(|event| event.kind.close_time);
+        output
+    }
+}
+
+impl CandleSeries {
+    /// Synthesize the [`Candle`] of the `next_start` boundary from the previous close, advancing
+    /// the series to the following boundary.
+    fn synthesize(&mut self, instrument: &Instrument, interval: Interval) -> MarketEvent<Candle> {
+        let previous = self.previous;
+        let close_time = interval.close_time(self.next_start);
+
+        let candle = Candle {
+            start_time: self.next_start,
+            close_time,
+            open: previous.close,
+            high: previous.close,
+            low: previous.close,
+            close: previous.close,
+            volume: number::ZERO,
+            volume_denomination: previous.volume_denomination,
+            base_volume: previous.base_volume.map(|_| number::ZERO),
+            quote_volume: previous.quote_volume.map(|_| number::ZERO),
+            taker_buy_volume: previous.taker_buy_volume.map(|_| number::ZERO),
+            taker_buy_quote_volume: previous.taker_buy_quote_volume.map(|_| number::ZERO),
+            trade_count: 0,
+            closed: true,
+            source: previous.source,
+            synthetic: true,
+            derived: false,
+        };
+
+        self.previous = candle;
+        self.next_start = next_start(interval, self.next_start);
+
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: Utc::now(),
+            exchange: self.exchange.clone(),
+            instrument: instrument.clone(),
+            sequence: None,
+            tag: None,
+            kind: candle,
+        }
+    }
+}
+
+/// Start time of the [`Interval`] boundary following the boundary starting at `start`.
+fn next_start(interval: Interval, start: DateTime<Utc>) -> DateTime<Utc> {
+    interval.close_time(start) + chrono::Duration::milliseconds(1)
+}
+
+/// Wrap the provided `exchange_tx` so the [`Candle`]s of the provided [`Instrument`] &
+/// [`Interval`] subscriptions are gap filled by a [`CandleGapFill`].
+///
+/// Returns the [`EventSender`] the [`MarketStream`](crate::MarketStream) consumer loop should
+/// send events to.
+pub fn spawn_gap_fill<Iter>(
+    subscriptions: Iter,
+    grace: Duration,
+    exchange_tx: EventSender<MarketEvent<Candle>>,
+) -> EventSender<MarketEvent<Candle>>
+where
+    Iter: IntoIterator<Item = (Instrument, Interval)>,
+{
+    let (gap_tx, gap_rx) = exchange_tx.upstream();
+    tokio::spawn(gap_fill(
+        CandleGapFill::new(subscriptions, grace),
+        gap_rx,
+        exchange_tx,
+    ));
+    gap_tx
+}
+
+/// Forward the [`Candle`]s received via `event_rx` to the `exchange_tx`, synthesizing any
+/// missing [`Candle`]s using the provided [`CandleGapFill`]. See [`CandleGapFill`] for the gap
+/// filling behaviour.
+pub async fn gap_fill(
+    mut fill: CandleGapFill,
+    mut event_rx: EventReceiver<MarketEvent<Candle>>,
+    exchange_tx: EventSender<MarketEvent<Candle>>,
+) {
+    loop {
+        let output = tokio::select! {
+            event = event_rx.recv() => match event {
+                Some(event) => fill.update(event, Instant::now()),
+                None => break,
+            },
+            _ = sleep_until(fill.next_deadline()) => fill.expire(Instant::now()),
+        };
+
+        for event in output {
+            if exchange_tx.send(event).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        candle::{CandleSource, VolumeDenomination},
+        number::Number,
+    };
+    use barter_integration::model::InstrumentKind;
+    use tokio::sync::mpsc;
+
+    fn instrument() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    /// 2023-01-01T00:00:00Z
+    fn base() -> DateTime<Utc> {
+        barter_integration::de::datetime_utc_from_epoch_duration(Duration::from_secs(1672531200))
+    }
+
+    /// 1m [`Candle`] starting `minute` minutes after the [`base`] time, received at the provided
+    /// `received_secs` seconds after the [`base`] time.
+    fn candle(minute: i64, received_secs: i64, closed: bool) -> MarketEvent<Candle> {
+        let start_time = base() + chrono::Duration::minutes(minute);
+        let close_time = Interval::Minute1.close_time(start_time);
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: base() + chrono::Duration::seconds(received_secs),
+            exchange: Exchange::from("binance_spot"),
+            instrument: instrument(),
+            sequence: None,
+            tag: None,
+            kind: Candle {
+                start_time,
+                close_time,
+                open: Number::from(100 + minute as i32),
+                high: Number::from(110 + minute as i32),
+                low: Number::from(90 + minute as i32),
+                close: Number::from(105 + minute as i32),
+                volume: Number::from(1),
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: None,
+                quote_volume: Some(Number::from(100)),
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 10,
+                closed,
+                source: CandleSource::LastTrade,
+                synthetic: false,
+                derived: false,
+            },
+        }
+    }
+
+    /// Drain every event currently forwarded to the receiver.
+    async fn drain(
+        exchange_rx: &mut mpsc::UnboundedReceiver<MarketEvent<Candle>>,
+    ) -> Vec<MarketEvent<Candle>> {
+        // Yield so the gap fill task can process everything sent so far
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+
+        let mut events = Vec::new();
+        while let Ok(event) = exchange_rx.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+
+    /// Minute offset from the [`base`] time & synthetic flag of each [`Candle`].
+    fn minutes(events: &[MarketEvent<Candle>]) -> Vec<(i64, bool)> {
+        events
+            .iter()
+            .map(|event| {
+                (
+                    (event.kind.start_time - base()).num_minutes(),
+                    event.kind.synthetic,
+                )
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gap_fill_synthesizes_missing_candles() {
+        let grace = Duration::from_secs(5);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let event_tx = spawn_gap_fill(
+            [(instrument(), Interval::Minute1)],
+            grace,
+            exchange_tx.into(),
+        );
+
+        struct TestCase {
+            advance: Duration,
+            input: Vec<MarketEvent<Candle>>,
+            expected: Vec<(i64, bool)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first closed candle is forwarded
+                advance: Duration::ZERO,
+                input: vec![candle(0, 60, true)],
+                expected: vec![(0, false)],
+            },
+            TestCase {
+                // TC1: next boundary has closed, but the grace period has not elapsed
+                advance: Duration::from_secs(62),
+                input: vec![],
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: grace period elapsed without a candle, so the boundary is synthesized
+                advance: Duration::from_secs(3),
+                input: vec![],
+                expected: vec![(1, true)],
+            },
+            TestCase {
+                // TC3: following boundary is synthesized one interval later
+                advance: Duration::from_secs(60),
+                input: vec![],
+                expected: vec![(2, true)],
+            },
+            TestCase {
+                // TC4: in-progress candle covers the next boundary
+                advance: Duration::from_secs(5),
+                input: vec![candle(3, 185, false)],
+                expected: vec![(3, false)],
+            },
+            TestCase {
+                // TC5: boundary covered by an in-progress candle is not synthesized
+                advance: Duration::from_secs(40),
+                input: vec![candle(3, 240, true)],
+                expected: vec![(3, false)],
+            },
+            TestCase {
+                // TC6: candle skipping a boundary is preceded by the synthesized boundary
+                advance: Duration::from_secs(70),
+                input: vec![candle(5, 310, false)],
+                expected: vec![(4, true), (5, false)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            tokio::time::advance(test.advance).await;
+            for event in test.input {
+                event_tx.send(event).await.unwrap();
+            }
+            assert_eq!(
+                minutes(&drain(&mut exchange_rx).await),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gap_fill_synthetic_candle() {
+        let grace = Duration::from_secs(5);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let event_tx = spawn_gap_fill(
+            [(instrument(), Interval::Minute1)],
+            grace,
+            exchange_tx.into(),
+        );
+
+        let previous = candle(0, 60, true);
+        event_tx.send(previous.clone()).await.unwrap();
+        assert_eq!(drain(&mut exchange_rx).await.len(), 1);
+
+        tokio::time::advance(Duration::from_secs(65)).await;
+        let actual = drain(&mut exchange_rx).await;
+        assert_eq!(actual.len(), 1);
+
+        let start_time = base() + chrono::Duration::minutes(1);
+        let close_time =
+            start_time + chrono::Duration::minutes(1) - chrono::Duration::milliseconds(1);
+        assert_eq!(actual[0].exchange, previous.exchange);
+        assert_eq!(actual[0].instrument, previous.instrument);
+        assert_eq!(actual[0].exchange_time, close_time);
+        assert_eq!(
+            actual[0].kind,
+            Candle {
+                start_time,
+                close_time,
+                open: previous.kind.close,
+                high: previous.kind.close,
+                low: previous.kind.close,
+                close: previous.kind.close,
+                volume: number::ZERO,
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: None,
+                quote_volume: Some(number::ZERO),
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 0,
+                closed: true,
+                source: CandleSource::LastTrade,
+                synthetic: true,
+                derived: false,
+            }
+        );
+        assert!(actual[0].kind.validate().is_ok());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_gap_fill_skips_series_without_previous_close() {
+        let grace = Duration::from_secs(5);
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let event_tx = spawn_gap_fill(
+            [
+                (instrument(), Interval::Minute1),
+                (instrument(), Interval::Minute5),
+            ],
+            grace,
+            exchange_tx.into(),
+        );
+
+        // No candle has ever been received, so there is nothing to synthesize from
+        tokio::time::advance(Duration::from_secs(600)).await;
+        assert!(drain(&mut exchange_rx).await.is_empty());
+
+        // 1m series is tracked from its first candle, but the 5m series is still skipped
+        event_tx.send(candle(10, 660, true)).await.unwrap();
+        assert_eq!(minutes(&drain(&mut exchange_rx).await), vec![(10, false)]);
+
+        tokio::time::advance(Duration::from_secs(65)).await;
+        assert_eq!(minutes(&drain(&mut exchange_rx).await), vec![(11, true)]);
+
+        drop(event_tx);
+        assert!(exchange_rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::error::DataError;
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex, MutexGuard},
+};
+use tokio::sync::mpsc::{self, error::SendError};
+
+/// Handler of the consumed events & [`DataError`]s of every connection of a
+/// [`StreamBuilder`](super::builder::StreamBuilder) initialised via
+/// [`init_with_handler`](super::builder::StreamBuilder::init_with_handler).
+///
+/// Called inline on the connection task rather than via an exchange output channel, so a slow
+/// handler backs up the [`MarketStream`](crate::MarketStream) WebSocket.
+///
+/// Implemented for any `FnMut(Result<T, DataError>) + Send + 'static` closure.
+pub trait EventHandler<T>: Send {
+    /// Handle the next consumed event, or [`DataError`] of a connection.
+    fn handle(&mut self, event: Result<T, DataError>);
+}
+
+impl<T, F> EventHandler<T> for F
+where
+    F: FnMut(Result<T, DataError>) + Send + 'static,
+{
+    fn handle(&mut self, event: Result<T, DataError>) {
+        self(event)
+    }
+}
+
+/// [`EventSender`](super::channel::EventSender) calling an [`EventHandler`] shared by every
+/// connection inline.
+///
+/// Events sent via an [`upstream`](super::channel::EventSender::upstream) sender (eg/ by a
+/// backfill or conflation task) are forwarded to the [`EventHandler`] via an intermediate
+/// channel, whereas [`DataError`]s are always handled inline.
+pub struct HandlerSender<T> {
+    handler: Arc<Mutex<dyn EventHandler<T>>>,
+    upstream: Option<mpsc::UnboundedSender<T>>,
+}
+
+impl<T> HandlerSender<T> {
+    /// Construct a new [`Self`] calling the provided [`EventHandler`].
+    pub fn new<Handler>(handler: Handler) -> Self
+    where
+        Handler: EventHandler<T> + 'static,
+    {
+        Self {
+            handler: Arc::new(Mutex::new(handler)),
+            upstream: None,
+        }
+    }
+
+    /// Construct a new [`Self`] sharing the [`EventHandler`] of this [`Self`], but sending events
+    /// via the provided intermediate channel.
+    pub fn with_upstream(&self, upstream: mpsc::UnboundedSender<T>) -> Self {
+        Self {
+            handler: Arc::clone(&self.handler),
+            upstream: Some(upstream),
+        }
+    }
+
+    /// Handle the event inline, or send it via the intermediate channel if this is an upstream
+    /// sender.
+    ///
+    /// Fails with the event if the intermediate channel receiver has been dropped.
+    pub fn send(&self, event: T) -> Result<(), SendError<T>> {
+        match &self.upstream {
+            Some(upstream) => upstream.send(event),
+            None => {
+                self.handler().handle(Ok(event));
+                Ok(())
+            }
+        }
+    }
+
+    /// Handle the [`DataError`] inline.
+    pub fn send_error(&self, error: DataError) {
+        self.handler().handle(Err(error));
+    }
+
+    fn handler(&self) -> MutexGuard<'_, dyn EventHandler<T> + 'static> {
+        self.handler
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<T> Clone for HandlerSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            handler: Arc::clone(&self.handler),
+            upstream: self.upstream.clone(),
+        }
+    }
+}
+
+impl<T> Debug for HandlerSender<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HandlerSender")
+            .field("upstream", &self.upstream.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::channel::EventSender;
+    use barter_integration::error::SocketError;
+
+    #[tokio::test]
+    async fn test_handler_sender() {
+        let (handled_tx, mut handled_rx) = mpsc::unbounded_channel();
+        let exchange_tx =
+            EventSender::Handler(HandlerSender::new(move |event: Result<u64, DataError>| {
+                handled_tx
+                    .send(event.map_err(|error| error.to_string()))
+                    .unwrap();
+            }));
+
+        // Events & errors are handled inline
+        exchange_tx.send(1).await.unwrap();
+        assert!(exchange_tx
+            .send_error(DataError::Socket(SocketError::Sink))
+            .is_ok());
+        assert_eq!(handled_rx.try_recv(), Ok(Ok(1)));
+        assert_eq!(
+            handled_rx.try_recv(),
+            Ok(Err(DataError::Socket(SocketError::Sink).to_string()))
+        );
+
+        // Upstream events are forwarded via the intermediate channel, but errors remain inline
+        let (upstream_tx, mut upstream_rx) = exchange_tx.upstream();
+        upstream_tx.send(2).await.unwrap();
+        assert!(upstream_tx
+            .send_error(DataError::Socket(SocketError::Sink))
+            .is_ok());
+        assert!(matches!(handled_rx.try_recv(), Ok(Err(_))));
+        assert!(handled_rx.try_recv().is_err());
+
+        let forwarded = upstream_rx.recv().await.unwrap();
+        exchange_tx.send(forwarded).await.unwrap();
+        assert_eq!(handled_rx.try_recv(), Ok(Ok(2)));
+
+        // Dropping the intermediate channel receiver fails upstream sends
+        drop(upstream_rx);
+        assert_eq!(upstream_tx.send(3).await, Err(SendError(3)));
+    }
+}
@@ -0,0 +1,372 @@
+use super::raw::RawCapture;
+use barter_integration::model::SubscriptionId;
+use futures::{Stream, StreamExt};
+use std::{collections::HashMap, time::Duration};
+use tokio::time::Instant;
+
+/// Duration both connections are consumed during a [`Handover`], before the previous connection
+/// is closed.
+pub const HANDOVER_OVERLAP: Duration = Duration::from_secs(5);
+
+/// Duration the [`consume`](super::consumer::consume) loop waits before re-attempting a failed
+/// proactive connection rotation, whilst the current connection remains in use.
+pub const ROTATION_RETRY_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Connection of a [`Handover`] that an event was received from.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum HandoverSource {
+    /// Connection being rotated out, closed once the [`Handover`] completes.
+    Previous,
+    /// New connection that remains in use once the [`Handover`] completes.
+    Current,
+}
+
+/// Seamless handover from a `previous` connection to the current connection of a
+/// [`MarketStream`](crate::MarketStream), used to proactively rotate connections before they
+/// reach the [`Connector::max_connection_age`](crate::exchange::Connector::max_connection_age).
+///
+/// Both connections are consumed until the overlap window elapses, with the events of the
+/// overlap deduplicated via the [`HandoverDedup`].
+#[derive(Debug)]
+pub struct Handover<Stream> {
+    pub previous: Stream,
+    pub previous_raw: RawCapture,
+    pub until: Instant,
+    pub dedup: HandoverDedup,
+}
+
+impl<St> Handover<St> {
+    /// Construct a new [`Self`] that consumes the `previous` connection for the `overlap`.
+    pub fn new(previous: St, previous_raw: RawCapture, overlap: Duration) -> Self {
+        Self {
+            previous,
+            previous_raw,
+            until: Instant::now() + overlap,
+            dedup: HandoverDedup::default(),
+        }
+    }
+}
+
+/// Receive the next item of the `previous` connection of a [`Handover`], pending forever if
+/// there is no [`Handover`] in progress.
+pub async fn next_previous<St>(handover: &mut Option<Handover<St>>) -> Option<St::Item>
+where
+    St: Stream + Unpin,
+{
+    match handover {
+        Some(handover) => handover.previous.next().await,
+        None => futures::future::pending().await,
+    }
+}
+
+/// Deduplicates the events of both connections of a [`Handover`] via the
+/// [`SubKind::handover_key`](crate::subscription::SubKind::handover_key) of the last event
+/// admitted for each [`SubscriptionId`] of the connection.
+///
+/// Keys are tracked per [`Subscription`](crate::subscription::Subscription) rather than per
+/// [`Instrument`](barter_integration::model::Instrument), since several subscriptions of the same
+/// [`Instrument`](barter_integration::model::Instrument) may share a connection (eg/ 1m & 1h
+/// [`Candles`](crate::subscription::candle::Candles)), each with an independent key.
+///
+/// An event is admitted if its key is greater than the last admitted key, or equal to it and
+/// received from the same connection (eg/ an in-progress candle update). Events without a key,
+/// or that cannot be attributed to a [`SubscriptionId`], cannot be deduplicated, so are only
+/// admitted from the [`HandoverSource::Previous`] connection until the [`Handover`] completes.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct HandoverDedup {
+    last: HashMap<SubscriptionId, (u64, HandoverSource)>,
+}
+
+impl HandoverDedup {
+    /// Determine if the event of the provided [`SubscriptionId`] & handover `key` received from
+    /// the [`HandoverSource`] connection should be emitted, recording it if so.
+    pub fn admit(
+        &mut self,
+        source: HandoverSource,
+        subscription_id: Option<&SubscriptionId>,
+        key: Option<u64>,
+    ) -> bool {
+        let (Some(subscription_id), Some(key)) = (subscription_id, key) else {
+            return source == HandoverSource::Previous;
+        };
+
+        let admit = match self.last.get(subscription_id) {
+            Some((last_key, last_source)) => {
+                key > *last_key || (key == *last_key && source == *last_source)
+            }
+            None => true,
+        };
+
+        if admit {
+            self.last.insert(subscription_id.clone(), (key, source));
+        }
+
+        admit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    fn subscription_id(id: &str) -> SubscriptionId {
+        SubscriptionId::from(id)
+    }
+
+    #[test]
+    fn test_handover_dedup_admit() {
+        struct TestCase {
+            source: HandoverSource,
+            subscription_id: Option<SubscriptionId>,
+            key: Option<u64>,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first event of an Instrument is admitted
+                source: HandoverSource::Previous,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: Some(10),
+                expected: true,
+            },
+            TestCase {
+                // TC1: already admitted key from the other connection is a duplicate
+                source: HandoverSource::Current,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: Some(10),
+                expected: false,
+            },
+            TestCase {
+                // TC2: equal key from the same connection is admitted (eg/ candle update)
+                source: HandoverSource::Previous,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: Some(10),
+                expected: true,
+            },
+            TestCase {
+                // TC3: greater key from the other connection is admitted
+                source: HandoverSource::Current,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: Some(11),
+                expected: true,
+            },
+            TestCase {
+                // TC4: already admitted key is a duplicate, since the last admitted is Current
+                source: HandoverSource::Previous,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: Some(11),
+                expected: false,
+            },
+            TestCase {
+                // TC5: lesser key is a duplicate
+                source: HandoverSource::Current,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: Some(9),
+                expected: false,
+            },
+            TestCase {
+                // TC6: keys are deduplicated independently per SubscriptionId
+                source: HandoverSource::Current,
+                subscription_id: Some(subscription_id("@trade|ETHUSDT")),
+                key: Some(1),
+                expected: true,
+            },
+            TestCase {
+                // TC7: event without a key is admitted from the Previous connection
+                source: HandoverSource::Previous,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: None,
+                expected: true,
+            },
+            TestCase {
+                // TC8: event without a key is not admitted from the Current connection
+                source: HandoverSource::Current,
+                subscription_id: Some(subscription_id("@trade|BTCUSDT")),
+                key: None,
+                expected: false,
+            },
+            TestCase {
+                // TC9: unattributed event is admitted from the Previous connection
+                source: HandoverSource::Previous,
+                subscription_id: None,
+                key: Some(12),
+                expected: true,
+            },
+            TestCase {
+                // TC10: unattributed event is not admitted from the Current connection
+                source: HandoverSource::Current,
+                subscription_id: None,
+                key: Some(12),
+                expected: false,
+            },
+        ];
+
+        let mut dedup = HandoverDedup::default();
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = dedup.admit(test.source, test.subscription_id.as_ref(), test.key);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_handover_seamless() {
+        // Mocked previous & current connections yielding trade ids of the same Instrument
+        let (previous_tx, previous_rx) = mpsc::unbounded_channel();
+        let (current_tx, current_rx) = mpsc::unbounded_channel();
+        let mut current = UnboundedReceiverStream::new(current_rx);
+        let mut handover = Some(Handover::new(
+            UnboundedReceiverStream::new(previous_rx),
+            RawCapture::default(),
+            HANDOVER_OVERLAP,
+        ));
+
+        // Previous connection yields trades 1..=6 before being rotated out, whilst the current
+        // connection subscribes part way through & overlaps from trade 4 onwards
+        tokio::spawn(async move {
+            for id in 1..=6 {
+                previous_tx.send(id).unwrap();
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            futures::future::pending::<()>().await;
+        });
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(2500)).await;
+            for id in 4..=10 {
+                current_tx.send(id).unwrap();
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        });
+
+        let mut admitted = Vec::new();
+        let mut completed_at = None;
+        loop {
+            let until = handover.as_ref().map(|handover| handover.until);
+            let (source, id) = tokio::select! {
+                id = current.next() => match id {
+                    Some(id) => (HandoverSource::Current, id),
+                    None => break,
+                },
+                id = next_previous(&mut handover) => match id {
+                    Some(id) => (HandoverSource::Previous, id),
+                    None => {
+                        handover = None;
+                        continue;
+                    }
+                },
+                _ = tokio::time::sleep_until(until.unwrap_or_else(Instant::now)),
+                    if until.is_some() =>
+                {
+                    handover = None;
+                    completed_at = Some(Instant::now());
+                    continue;
+                }
+            };
+
+            if let Some(handover) = &mut handover {
+                let subscription_id = subscription_id("@trade|BTCUSDT");
+                if !handover
+                    .dedup
+                    .admit(source, Some(&subscription_id), Some(id))
+                {
+                    continue;
+                }
+            }
+            admitted.push(id);
+        }
+
+        // Every trade is emitted exactly once & in order, without a gap
+        assert_eq!(admitted, (1..=10).collect::<Vec<_>>());
+        assert!(completed_at.is_some());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_handover_interleaved_candle_intervals() {
+        // Mocked previous & current connections yielding the 1m & 1h candle updates of the same
+        // Instrument, keyed by the epoch millisecond start_time of each candle
+        let minute_1 = subscription_id("@kline_1m|BTCUSDT");
+        let hour_1 = subscription_id("@kline_1h|BTCUSDT");
+        let updates = {
+            let (minute_1, hour_1) = (minute_1.clone(), hour_1.clone());
+            move |minute: u64| {
+                [
+                    (minute_1.clone(), minute * 60_000, minute),
+                    (hour_1.clone(), 0, minute),
+                ]
+            }
+        };
+
+        let (previous_tx, previous_rx) = mpsc::unbounded_channel();
+        let (current_tx, current_rx) = mpsc::unbounded_channel();
+        let mut current = UnboundedReceiverStream::new(current_rx);
+        let mut handover = Some(Handover::new(
+            UnboundedReceiverStream::new(previous_rx),
+            RawCapture::default(),
+            Duration::from_secs(10),
+        ));
+
+        // Previous connection yields minutes 1..=6 before ending, whilst the current connection
+        // subscribes part way through & overlaps from minute 4 onwards
+        tokio::spawn({
+            let updates = updates.clone();
+            async move {
+                for minute in 1..=6 {
+                    updates(minute)
+                        .into_iter()
+                        .for_each(|update| previous_tx.send(update).unwrap());
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+            }
+        });
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(3500)).await;
+            for minute in 4..=10 {
+                updates(minute)
+                    .into_iter()
+                    .for_each(|update| current_tx.send(update).unwrap());
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        });
+
+        let mut admitted = Vec::new();
+        loop {
+            let (source, (subscription_id, key, minute)) = tokio::select! {
+                update = current.next() => match update {
+                    Some(update) => (HandoverSource::Current, update),
+                    None => break,
+                },
+                update = next_previous(&mut handover) => match update {
+                    Some(update) => (HandoverSource::Previous, update),
+                    None => {
+                        handover = None;
+                        continue;
+                    }
+                },
+            };
+
+            if let Some(handover) = &mut handover {
+                if !handover
+                    .dedup
+                    .admit(source, Some(&subscription_id), Some(key))
+                {
+                    continue;
+                }
+            }
+            admitted.push((subscription_id, minute));
+        }
+
+        // Every 1m & 1h candle update is emitted exactly once & in order, without the earlier
+        // start_time of the 1h candle being mistaken for a duplicate of the 1m candle
+        let admitted_of = |id: &SubscriptionId| {
+            admitted
+                .iter()
+                .filter(|(subscription_id, _)| subscription_id == id)
+                .map(|(_, minute)| *minute)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(admitted_of(&minute_1), (1..=10).collect::<Vec<_>>());
+        assert_eq!(admitted_of(&hour_1), (1..=10).collect::<Vec<_>>());
+    }
+}
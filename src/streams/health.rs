@@ -0,0 +1,381 @@
+use super::{
+    metrics::{MessageSize, PayloadSize},
+    raw::{RawCapture, RawText},
+};
+use crate::{
+    error::DataError, exchange::ExchangeId, subscriber::validator::SubscriptionOutcome,
+};
+use futures::Stream;
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{sync::mpsc, time::Instant};
+
+/// Connection lifecycle & liveness events generated by the [`consume`](super::consumer::consume)
+/// loop of each [`MarketStream`](crate::MarketStream) connection.
+///
+/// Delivered separately from [`MarketEvent`](crate::event::MarketEvent)s via the
+/// [`Streams::connection_events`](super::Streams::connection_events) receiver.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ConnectionEvent {
+    /// [`MarketStream`](crate::MarketStream) connection initialised for the first time.
+    Connected { exchange: ExchangeId },
+    /// [`MarketStream`](crate::MarketStream) connection ended, re-connection will be attempted
+    /// according to the [`ReconnectPolicy`](super::consumer::ReconnectPolicy).
+    Disconnected {
+        exchange: ExchangeId,
+        reason: String,
+    },
+    /// No message (including pings & pongs) has been received from the exchange server since
+    /// the provided [`Instant`], exceeding the configured staleness threshold.
+    Stale {
+        exchange: ExchangeId,
+        since: Instant,
+    },
+    /// [`MarketStream`](crate::MarketStream) successfully re-connected & re-subscribed after a
+    /// disconnection. Any stateful data (eg/ OrderBooks) should be re-initialised.
+    Resubscribed { exchange: ExchangeId },
+    /// [`MarketStream`](crate::MarketStream) connection proactively rotated before reaching the
+    /// [`Connector::max_connection_age`](crate::exchange::Connector::max_connection_age), handing
+    /// over to a new connection without a gap. Unlike [`ConnectionEvent::Resubscribed`], stateful
+    /// data does not need to be re-initialised.
+    Rotated { exchange: ExchangeId },
+    /// Error message sent by the exchange server mid-stream (eg/ invalid subscription, rate
+    /// limit ban). Fatal errors are followed by a [`ConnectionEvent::Disconnected`].
+    ExchangeError {
+        exchange: ExchangeId,
+        code: String,
+        message: String,
+        fatal: bool,
+    },
+    /// [`MarketStream`](crate::MarketStream) connection panicked whilst processing a message
+    /// (eg/ a pathological payload), surfaced as a terminal
+    /// [`DataError::StreamPanicked`](crate::error::DataError::StreamPanicked). Followed by a
+    /// [`ConnectionEvent::Disconnected`], with re-connection attempted as for any other
+    /// disconnection.
+    Panicked {
+        exchange: ExchangeId,
+        detail: String,
+    },
+    /// Outcome of the [`Subscription`](crate::subscription::Subscription)s actioned each time a
+    /// [`MarketStream`](crate::MarketStream) connection is (re-)initialised, including any
+    /// rejected or timed out subscriptions.
+    SubscriptionOutcome {
+        exchange: ExchangeId,
+        outcome: SubscriptionOutcome,
+    },
+}
+
+/// Default capacity of the [`ConnectionError`] channel of each [`Streams`](super::Streams).
+pub const DEFAULT_CONNECTION_ERROR_CAPACITY: usize = 1024;
+
+/// [`DataError`] consumed from a [`MarketStream`](crate::MarketStream) connection (eg/ an
+/// [`InvalidCandle`](DataError::InvalidCandle), a [`SequenceGap`](DataError::SequenceGap)).
+///
+/// Delivered separately from [`MarketEvent`](crate::event::MarketEvent)s via the
+/// [`Streams::errors`](super::Streams::errors) receiver, unless the [`Streams`](super::Streams)
+/// were initialised with an [`EventHandler`](super::handler::EventHandler), which handles every
+/// [`DataError`] inline instead.
+#[derive(Debug)]
+pub struct ConnectionError {
+    pub exchange: ExchangeId,
+    pub error: DataError,
+}
+
+/// Convenient type that holds the bounded [`mpsc::Sender`] and [`mpsc::Receiver`] for a
+/// [`ConnectionError`] channel.
+#[derive(Debug)]
+pub struct ErrorChannel {
+    pub tx: mpsc::Sender<ConnectionError>,
+    pub rx: mpsc::Receiver<ConnectionError>,
+}
+
+impl ErrorChannel {
+    /// Construct a new [`Self`] with the provided capacity.
+    pub fn new(capacity: usize) -> Self {
+        let (tx, rx) = mpsc::channel(capacity);
+        Self { tx, rx }
+    }
+}
+
+impl Default for ErrorChannel {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONNECTION_ERROR_CAPACITY)
+    }
+}
+
+/// Configuration of the liveness monitoring & [`ConnectionEvent`] distribution used by the
+/// [`consume`](super::consumer::consume) loop of a batch of
+/// [`Subscription`](crate::subscription::Subscription)s.
+#[derive(Clone, Debug)]
+pub struct HealthMonitor {
+    /// Duration without any message from the exchange server after which the connection is
+    /// considered [`ConnectionEvent::Stale`]. `None` disables staleness detection.
+    pub stale_after: Option<Duration>,
+    pub events_tx: mpsc::UnboundedSender<ConnectionEvent>,
+    pub errors_tx: mpsc::Sender<ConnectionError>,
+}
+
+impl HealthMonitor {
+    /// Send a [`ConnectionEvent`] downstream, ignoring a dropped receiver since consuming
+    /// [`ConnectionEvent`]s is optional.
+    pub fn send(&self, event: ConnectionEvent) {
+        let _ = self.events_tx.send(event);
+    }
+
+    /// Send a [`ConnectionError`] downstream without waiting, dropping it if the receiver is full
+    /// or has been dropped, since consuming [`ConnectionError`]s is optional and every
+    /// [`DataError`] is also logged by the consumer loop.
+    pub fn send_error(&self, exchange: ExchangeId, error: DataError) {
+        let _ = self.errors_tx.try_send(ConnectionError { exchange, error });
+    }
+}
+
+/// Shared handle to the [`Instant`] the last message was received from the exchange server.
+#[derive(Clone, Debug)]
+pub struct Heartbeat(Arc<Mutex<Instant>>);
+
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(Instant::now())))
+    }
+}
+
+impl Heartbeat {
+    /// Record that a message was received from the exchange server now.
+    pub fn beat(&self) {
+        *self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Instant::now();
+    }
+
+    /// [`Instant`] the last message was received from the exchange server.
+    pub fn last(&self) -> Instant {
+        *self
+            .0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// [`Stream`] wrapper that updates a [`Heartbeat`] each time the inner [`Stream`] yields an item,
+/// and records the [`PayloadSize`] of the item for any installed
+/// [`StreamMetrics`](super::metrics::StreamMetrics), as well as its [`RawPayload`] if
+/// [`RawCapture`] is enabled.
+///
+/// [`RawPayload`]: super::raw::RawPayload
+///
+/// Wraps the [`WsStream`](barter_integration::protocol::websocket::WsStream) of an
+/// [`ExchangeWsStream`](crate::ExchangeWsStream) so protocol-level pings & pongs, which are never
+/// yielded as [`MarketEvent`](crate::event::MarketEvent)s, still count towards liveness.
+#[derive(Debug)]
+pub struct HeartbeatStream<St> {
+    pub stream: St,
+    pub heartbeat: Heartbeat,
+    pub payload: PayloadSize,
+    pub raw: RawCapture,
+}
+
+impl<St> HeartbeatStream<St> {
+    /// Construct a new [`Self`] with a [`Heartbeat`] starting now.
+    pub fn new(stream: St) -> Self {
+        Self {
+            stream,
+            heartbeat: Heartbeat::default(),
+            payload: PayloadSize::default(),
+            raw: RawCapture::default(),
+        }
+    }
+}
+
+impl<St> Stream for HeartbeatStream<St>
+where
+    St: Stream + Unpin,
+    St::Item: MessageSize + RawText,
+{
+    type Item = St::Item;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = Pin::new(&mut self.stream).poll_next(cx);
+        if let Poll::Ready(Some(message)) = &poll {
+            self.heartbeat.beat();
+            self.payload.record(message.message_size());
+            self.raw.record(message);
+        }
+        poll
+    }
+}
+
+/// Per connection liveness monitor that determines when a [`Heartbeat`] has gone stale.
+#[derive(Debug)]
+pub struct LivenessMonitor {
+    pub exchange: ExchangeId,
+    pub stale_after: Option<Duration>,
+    pub heartbeat: Heartbeat,
+    reported: Option<Instant>,
+}
+
+impl LivenessMonitor {
+    /// Construct a new [`Self`] monitoring the provided [`Heartbeat`].
+    pub fn new(exchange: ExchangeId, stale_after: Option<Duration>, heartbeat: Heartbeat) -> Self {
+        Self {
+            exchange,
+            stale_after,
+            heartbeat,
+            reported: None,
+        }
+    }
+
+    /// [`Instant`] at which the [`Heartbeat`] should next be checked for staleness, or `None` if
+    /// staleness detection is disabled.
+    ///
+    /// Once staleness has been reported, the connection is re-checked every `stale_after`.
+    pub fn deadline(&self) -> Option<Instant> {
+        let stale_after = self.stale_after?;
+        let last = self.heartbeat.last();
+
+        if self.reported == Some(last) {
+            Some(Instant::now() + stale_after)
+        } else {
+            Some(last + stale_after)
+        }
+    }
+
+    /// Check if the [`Heartbeat`] has gone stale, returning a [`ConnectionEvent::Stale`] the
+    /// first time staleness is detected since the last message was received.
+    pub fn check(&mut self) -> Option<ConnectionEvent> {
+        let stale_after = self.stale_after?;
+        let last = self.heartbeat.last();
+
+        if Instant::now().saturating_duration_since(last) < stale_after
+            || self.reported == Some(last)
+        {
+            return None;
+        }
+
+        self.reported = Some(last);
+        Some(ConnectionEvent::Stale {
+            exchange: self.exchange,
+            since: last,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_liveness_monitor_check() {
+        struct TestCase {
+            advance: Duration,
+            beat: bool,
+            expected: bool,
+        }
+
+        let start = Instant::now();
+        let mut monitor = LivenessMonitor::new(
+            ExchangeId::BinanceSpot,
+            Some(Duration::from_secs(10)),
+            Heartbeat::default(),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: not stale before threshold
+                advance: Duration::from_secs(5),
+                beat: false,
+                expected: false,
+            },
+            TestCase {
+                // TC1: stale once threshold elapsed since last message
+                advance: Duration::from_secs(5),
+                beat: false,
+                expected: true,
+            },
+            TestCase {
+                // TC2: staleness only reported once per last message
+                advance: Duration::from_secs(30),
+                beat: false,
+                expected: false,
+            },
+            TestCase {
+                // TC3: message received resets staleness
+                advance: Duration::from_secs(1),
+                beat: true,
+                expected: false,
+            },
+            TestCase {
+                // TC4: stale again once threshold elapsed since latest message
+                advance: Duration::from_secs(10),
+                beat: false,
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            tokio::time::advance(test.advance).await;
+            if test.beat {
+                monitor.heartbeat.beat();
+            }
+            let actual = monitor.check();
+            assert_eq!(actual.is_some(), test.expected, "TC{} failed", index);
+        }
+
+        assert_eq!(monitor.check(), None, "staleness should not be re-reported");
+        tokio::time::advance(Duration::from_secs(1)).await;
+        monitor.heartbeat.beat();
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(
+            monitor.check(),
+            Some(ConnectionEvent::Stale {
+                exchange: ExchangeId::BinanceSpot,
+                since: start + Duration::from_secs(52),
+            })
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_liveness_monitor_deadline() {
+        let start = Instant::now();
+        let stale_after = Duration::from_secs(10);
+
+        // Disabled monitor has no deadline
+        let disabled = LivenessMonitor::new(ExchangeId::Okx, None, Heartbeat::default());
+        assert_eq!(disabled.deadline(), None);
+
+        // Deadline is relative to the last message received
+        let mut monitor =
+            LivenessMonitor::new(ExchangeId::Okx, Some(stale_after), Heartbeat::default());
+        assert_eq!(monitor.deadline(), Some(start + stale_after));
+
+        // Once stale, the connection is re-checked every stale_after
+        tokio::time::advance(stale_after).await;
+        assert!(monitor.check().is_some());
+        assert_eq!(monitor.deadline(), Some(Instant::now() + stale_after));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_heartbeat_stream_beats_on_every_item() {
+        let mut stream = HeartbeatStream::new(futures::stream::iter(vec!["ping", "trade"]));
+        let start = stream.heartbeat.last();
+
+        tokio::time::advance(Duration::from_secs(3)).await;
+        assert_eq!(stream.next().await, Some("ping"));
+        assert_eq!(stream.heartbeat.last(), start + Duration::from_secs(3));
+
+        tokio::time::advance(Duration::from_secs(3)).await;
+        assert_eq!(stream.next().await, Some("trade"));
+        assert_eq!(stream.heartbeat.last(), start + Duration::from_secs(6));
+
+        // Stream end does not count as a received message
+        tokio::time::advance(Duration::from_secs(3)).await;
+        assert_eq!(stream.next().await, None);
+        assert_eq!(stream.heartbeat.last(), start + Duration::from_secs(6));
+    }
+}
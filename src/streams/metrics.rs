@@ -0,0 +1,300 @@
+use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId};
+use barter_integration::{model::SubscriptionId, protocol::websocket::WsMessage};
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tracing::debug;
+
+/// Opt-in hook invoked by the transformer layer of a [`MarketStream`](crate::MarketStream) for
+/// every normalised [`MarketEvent`] and [`DataError`], used to collect per
+/// [`SubscriptionId`] latency & throughput metrics.
+///
+/// Installed via [`StreamBuilder::with_metrics`](super::builder::StreamBuilder::with_metrics).
+/// Implementations are called on the hot path, so should be cheap (eg/ incrementing counters).
+pub trait StreamMetrics: Debug + Send + Sync {
+    /// Called for each normalised [`MarketEvent`], where `latency` is the duration between the
+    /// exchange timestamp and the time the event was received (zero if the exchange clock is
+    /// ahead), and `payload_bytes` is the size of the WebSocket message the event was parsed from.
+    fn on_event(
+        &self,
+        exchange: ExchangeId,
+        subscription_id: &SubscriptionId,
+        latency: Duration,
+        payload_bytes: usize,
+    );
+
+    /// Called for each [`DataError`] yielded by the transformer layer.
+    fn on_error(&self, exchange: ExchangeId, error: &DataError);
+}
+
+/// Default [`StreamMetrics`] implementation that records each callback as a `tracing` debug
+/// event with structured fields.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+pub struct TracingMetrics;
+
+impl StreamMetrics for TracingMetrics {
+    fn on_event(
+        &self,
+        exchange: ExchangeId,
+        subscription_id: &SubscriptionId,
+        latency: Duration,
+        payload_bytes: usize,
+    ) {
+        debug!(
+            %exchange,
+            %subscription_id,
+            latency_us = latency.as_micros() as u64,
+            payload_bytes,
+            "MarketStream event",
+        );
+    }
+
+    fn on_error(&self, exchange: ExchangeId, error: &DataError) {
+        debug!(%exchange, %error, "MarketStream error");
+    }
+}
+
+/// Shared handle to the size in bytes of the last message received from the exchange server.
+#[derive(Clone, Debug, Default)]
+pub struct PayloadSize(Arc<AtomicUsize>);
+
+impl PayloadSize {
+    /// Record the size in bytes of the message just received from the exchange server.
+    pub fn record(&self, bytes: usize) {
+        self.0.store(bytes, Ordering::Relaxed);
+    }
+
+    /// Size in bytes of the last message received from the exchange server.
+    pub fn last(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Determine the size in bytes of a message received from the exchange server.
+pub trait MessageSize {
+    fn message_size(&self) -> usize;
+}
+
+impl<Error> MessageSize for Result<WsMessage, Error> {
+    fn message_size(&self) -> usize {
+        self.as_ref().map(WsMessage::len).unwrap_or_default()
+    }
+}
+
+impl MessageSize for &str {
+    fn message_size(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Optional [`StreamMetrics`] installed on a transformer, alongside the [`ExchangeId`] and
+/// [`PayloadSize`] of the connection it is transforming messages for.
+///
+/// Without an installed [`StreamMetrics`] each report is a single `Option` check, so the hot path
+/// is unaffected.
+#[derive(Clone, Debug, Default)]
+pub struct MetricsHook(Option<InstalledMetrics>);
+
+#[derive(Clone, Debug)]
+struct InstalledMetrics {
+    exchange: ExchangeId,
+    metrics: Arc<dyn StreamMetrics>,
+    payload: PayloadSize,
+}
+
+impl MetricsHook {
+    /// Construct a new [`Self`] reporting to the provided [`StreamMetrics`].
+    pub fn new(
+        exchange: ExchangeId,
+        metrics: Arc<dyn StreamMetrics>,
+        payload: PayloadSize,
+    ) -> Self {
+        Self(Some(InstalledMetrics {
+            exchange,
+            metrics,
+            payload,
+        }))
+    }
+
+    /// Report the outcome of transforming a message associated with the provided
+    /// [`SubscriptionId`] to the installed [`StreamMetrics`], if any.
+    pub fn report<T>(
+        &self,
+        subscription_id: &SubscriptionId,
+        output: &[Result<MarketEvent<T>, DataError>],
+    ) {
+        let Some(installed) = &self.0 else {
+            return;
+        };
+
+        for result in output {
+            match result {
+                Ok(event) => installed.metrics.on_event(
+                    installed.exchange,
+                    subscription_id,
+                    (event.received_time - event.exchange_time)
+                        .to_std()
+                        .unwrap_or_default(),
+                    installed.payload.last(),
+                ),
+                Err(error) => installed.metrics.on_error(installed.exchange, error),
+            }
+        }
+    }
+
+    /// Report a [`DataError`] not associated with a [`SubscriptionId`] to the installed
+    /// [`StreamMetrics`], if any.
+    pub fn report_error(&self, error: &DataError) {
+        if let Some(installed) = &self.0 {
+            installed.metrics.on_error(installed.exchange, error);
+        }
+    }
+}
+
+impl PartialEq for MetricsHook {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(installed), Some(other)) => {
+                installed.exchange == other.exchange
+                    && Arc::ptr_eq(&installed.metrics, &other.metrics)
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for MetricsHook {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+        streams::health::HeartbeatStream,
+        subscription::{trade::PublicTrades, Map},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        model::{Instrument, InstrumentKind},
+        Transformer,
+    };
+    use chrono::Utc;
+    use futures::StreamExt;
+    use std::{collections::HashMap, sync::Mutex};
+    use tokio::sync::mpsc;
+
+    #[derive(Debug, Default)]
+    struct RecordingMetrics {
+        events: Mutex<Vec<(ExchangeId, SubscriptionId, Duration, usize)>>,
+        errors: Mutex<Vec<(ExchangeId, String)>>,
+    }
+
+    impl StreamMetrics for RecordingMetrics {
+        fn on_event(
+            &self,
+            exchange: ExchangeId,
+            subscription_id: &SubscriptionId,
+            latency: Duration,
+            payload_bytes: usize,
+        ) {
+            self.events.lock().unwrap().push((
+                exchange,
+                subscription_id.clone(),
+                latency,
+                payload_bytes,
+            ));
+        }
+
+        fn on_error(&self, exchange: ExchangeId, error: &DataError) {
+            self.errors
+                .lock()
+                .unwrap()
+                .push((exchange, error.to_string()));
+        }
+    }
+
+    async fn transformer(
+        metrics: Arc<RecordingMetrics>,
+        payload: PayloadSize,
+    ) -> StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> {
+        let instrument_map = Map(HashMap::from([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        )]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        let mut transformer = <StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> as ExchangeTransformer<BinanceSpot, PublicTrades>>::new(
+            ws_sink_tx,
+            instrument_map,
+        )
+        .await
+        .unwrap();
+
+        <StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> as ExchangeTransformer<
+            BinanceSpot,
+            PublicTrades,
+        >>::set_metrics(
+            &mut transformer,
+            MetricsHook::new(ExchangeId::BinanceSpot, metrics, payload),
+        );
+
+        transformer
+    }
+
+    #[tokio::test]
+    async fn test_metrics_hook_reports_binance_trade_latency() {
+        let metrics = Arc::new(RecordingMetrics::default());
+
+        // Binance trade executed 250ms ago, received via a HeartbeatStream recording its size
+        let exchange_time = Utc::now().timestamp_millis() - 250;
+        let payload = format!(
+            r#"{{"e":"trade","E":{exchange_time},"s":"BTCUSDT","t":1,"p":"16000.0","q":"0.5","b":10,"a":11,"T":{exchange_time},"m":true,"M":true}}"#
+        );
+        let mut stream = HeartbeatStream::new(futures::stream::iter(vec![Ok::<_, ()>(
+            WsMessage::Text(payload.clone()),
+        )]));
+        stream.next().await.unwrap().unwrap();
+
+        let mut transformer = transformer(Arc::clone(&metrics), stream.payload.clone()).await;
+        let output = transformer.transform(serde_json::from_str::<BinanceTrade>(&payload).unwrap());
+        assert!(output.iter().all(Result::is_ok));
+
+        let events = metrics.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (exchange, subscription_id, latency, payload_bytes) = &events[0];
+        assert_eq!(*exchange, ExchangeId::BinanceSpot);
+        assert_eq!(subscription_id, &SubscriptionId::from("@trade|BTCUSDT"));
+        assert!(
+            *latency >= Duration::from_millis(250) && *latency < Duration::from_secs(5),
+            "unexpected latency: {latency:?}"
+        );
+        assert_eq!(*payload_bytes, payload.len());
+        assert!(metrics.errors.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_metrics_hook_reports_unidentifiable_error() {
+        let metrics = Arc::new(RecordingMetrics::default());
+        let mut transformer = transformer(Arc::clone(&metrics), PayloadSize::default()).await;
+
+        // Trade for a market that was never subscribed to
+        let output = transformer.transform(
+            serde_json::from_str::<BinanceTrade>(
+                r#"{"s":"ETHUSDT","T":1649324825173,"t":1,"p":"1.0","q":"1.0","m":true}"#,
+            )
+            .unwrap(),
+        );
+        assert!(output.iter().all(Result::is_err));
+
+        assert!(metrics.events.lock().unwrap().is_empty());
+        let errors = metrics.errors.lock().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, ExchangeId::BinanceSpot);
+    }
+}
@@ -1,25 +1,193 @@
-use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
-use crate::{exchange::ExchangeId, subscription::SubKind};
-use std::collections::HashMap;
-use tokio::sync::mpsc;
-use tokio_stream::{wrappers::UnboundedReceiverStream, StreamMap};
+use self::{
+    books::{BookHandle, BookRegistry},
+    builder::{multi::MultiStreamBuilder, StreamBuilder},
+    channel::{ChannelCapacity, EventReceiver},
+    consumer::StreamCommand,
+    controller::{StreamController, UnsubscribeCommand},
+    health::{ConnectionError, ConnectionEvent},
+    ordered::{merge_ordered, OrderedEvent},
+    raw::RawPayload,
+    stats::{StatsRegistry, SubscriptionStats},
+    watermark::Watermark,
+};
+use crate::{
+    error::{DataError, StreamError},
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId},
+    subscription::{book::OrderBook, SubKind, Subscription},
+    Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::{broadcast, mpsc};
+use tokio_stream::StreamMap;
+
+/// Historical [`MarketEvent`](crate::event::MarketEvent) backfill via exchange REST APIs, sent
+/// before the live events of a [`MarketStream`](super::MarketStream).
+pub mod backfill;
+
+/// Maintained [`SortedOrderBook`](crate::subscription::book::SortedOrderBook)s of
+/// [`OrderBook`](crate::subscription::book::OrderBook) streams, queryable via the
+/// [`BookHandle`](books::BookHandle)s returned by [`Streams::select_book`].
+pub mod books;
 
 /// Defines the [`StreamBuilder`](builder::StreamBuilder) and
 /// [`MultiStreamBuilder`](builder::multi::MultiStreamBuilder) APIs for ergonomically initialising
 /// [`MarketStream`](super::MarketStream) [`Streams`].
 pub mod builder;
 
+/// Exchange output channels of [`Streams`], optionally bounded with an
+/// [`OverflowPolicy`](channel::OverflowPolicy) applied whilst a slow consumer lags behind.
+pub mod channel;
+
+/// Opt-in per [`Subscription`] conflation of high frequency
+/// [`MarketEvent`](crate::event::MarketEvent)s (eg/ OrderBookL1 updates) to at most one per
+/// configured window.
+pub mod conflate;
+
 /// Central consumer loop functionality used by the [`StreamBuilder`](builder::StreamBuilder) to
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// Runtime [`Subscription`] management of running [`Streams`] via a cloneable
+/// [`StreamController`](controller::StreamController) handle, see [`Streams::controller`].
+pub mod controller;
+
+/// Opt-in [`EventFilter`](filter::EventFilter) predicates dropping unwanted
+/// [`MarketEvent`](crate::event::MarketEvent)s before they are sent to the exchange output
+/// channels.
+pub mod filter;
+
+/// Opt-in gap filling of [`Candles`](crate::subscription::candle::Candles) streams, synthesizing
+/// the [`Candle`](crate::subscription::candle::Candle)s an exchange did not emit (eg/ intervals
+/// without trades).
+pub mod gap;
+
+/// Seamless [`Handover`](handover::Handover) between connections of a
+/// [`MarketStream`](super::MarketStream) that is proactively rotated before reaching the
+/// [`Connector::max_connection_age`](crate::exchange::Connector::max_connection_age).
+pub mod handover;
+
+/// Opt-in direct dispatch of consumed [`MarketEvent`](crate::event::MarketEvent)s to an
+/// [`EventHandler`](handler::EventHandler) called inline on each connection task, see
+/// [`StreamBuilder::init_with_handler`](builder::StreamBuilder::init_with_handler).
+pub mod handler;
+
+/// Connection liveness monitoring and [`ConnectionEvent`](health::ConnectionEvent)s surfaced to
+/// consumers alongside each [`MarketStream`](super::MarketStream).
+pub mod health;
+
+/// Opt-in ordered merge of the [`MarketEvent`](crate::event::MarketEvent)s of multiple exchange
+/// receivers into `exchange_time` order, see [`Streams::join_ordered`].
+pub mod ordered;
+
+/// Record & replay of normalised [`MarketEvent`](crate::event::MarketEvent) streams via
+/// newline-delimited JSON files.
+pub mod recorder;
+
+/// Per [`Subscription`] [`Candles`](crate::subscription::candle::Candles) `resume_from` times
+/// suppressing candles already ingested before a restart, backfilling the gap where possible.
+pub mod resume;
+
+/// Opt-in snapshots of each [`Subscription`] (eg/ the top of book) fetched via exchange REST
+/// APIs, sent before the live events of a [`MarketStream`](super::MarketStream).
+pub mod snapshot;
+
+/// Opt-in per [`SubscriptionId`](barter_integration::model::SubscriptionId) latency & throughput
+/// metrics hooks invoked by the transformer layer of each [`MarketStream`](super::MarketStream).
+pub mod metrics;
+
+/// Opt-in capture of the raw exchange [`RawPayload`](raw::RawPayload) each
+/// [`MarketEvent`](crate::event::MarketEvent) was normalised from, for debugging parsers &
+/// auditing.
+pub mod raw;
+
+/// Lock-free per [`Subscription`] event, error & re-connection counters, available via
+/// [`Streams::stats`].
+pub mod stats;
+
+/// Opt-in [`Subscription`] tags carried through to the `tag` of each
+/// [`MarketEvent`](crate::event::MarketEvent), see
+/// [`Subscription::with_tag`](crate::subscription::Subscription::with_tag).
+pub mod tag;
+
+/// Opt-in event-time [`Watermark`](watermark::Watermark)s of each connection, available via
+/// [`Streams::watermarks`], for closing windows across the [`Subscription`]s of a connection.
+pub mod watermark;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
+///
+/// The underlying connections can be controlled via [`Streams::unsubscribe`] and
+/// [`Streams::shutdown`] until the [`Streams`] are dropped (eg/ via [`Streams::join`]), or via a
+/// [`StreamController`] obtained from [`Streams::controller`] thereafter.
 #[derive(Debug)]
 pub struct Streams<T> {
-    pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+    pub streams: HashMap<ExchangeId, EventReceiver<T>>,
+    pub connection_events: Option<mpsc::UnboundedReceiver<ConnectionEvent>>,
+    pub errors: Option<mpsc::Receiver<ConnectionError>>,
+    pub raw_payloads: Option<mpsc::Receiver<(T, RawPayload)>>,
+    pub watermarks: Option<mpsc::UnboundedReceiver<Watermark>>,
+    pub commands: HashMap<ExchangeId, broadcast::Sender<StreamCommand>>,
+    pub capacity: ChannelCapacity,
+    pub dropped: HashMap<ExchangeId, Arc<AtomicU64>>,
+    pub books: HashMap<ExchangeId, BookRegistry>,
+    pub subscriptions: StatsRegistry,
+}
+
+/// Statistics of an exchange of the [`Streams`], see [`Streams::stats`].
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct StreamStats {
+    /// Number of events dropped by the [`OverflowPolicy`](channel::OverflowPolicy) of a bounded
+    /// output channel.
+    pub dropped: u64,
+    /// [`SubscriptionStats`] of every [`Subscription`] of the exchange.
+    pub subscriptions: HashMap<SubscriptionId, SubscriptionStats>,
 }
 
 impl<T> Streams<T> {
+    /// Construct a new [`Self`] from the provided exchange receivers, tracking the dropped
+    /// events counter of any bounded receivers so it remains available via [`Streams::stats`]
+    /// after the receiver is selected.
+    pub fn new(
+        streams: HashMap<ExchangeId, EventReceiver<T>>,
+        connection_events: Option<mpsc::UnboundedReceiver<ConnectionEvent>>,
+        errors: Option<mpsc::Receiver<ConnectionError>>,
+        raw_payloads: Option<mpsc::Receiver<(T, RawPayload)>>,
+        watermarks: Option<mpsc::UnboundedReceiver<Watermark>>,
+        commands: HashMap<ExchangeId, broadcast::Sender<StreamCommand>>,
+        capacity: ChannelCapacity,
+        subscriptions: StatsRegistry,
+    ) -> Self {
+        let dropped = streams
+            .iter()
+            .map(|(exchange, rx)| (*exchange, rx.dropped_counter().unwrap_or_default()))
+            .collect();
+
+        Self {
+            streams,
+            connection_events,
+            errors,
+            raw_payloads,
+            watermarks,
+            commands,
+            capacity,
+            dropped,
+            books: HashMap::new(),
+            subscriptions,
+        }
+    }
+
     /// Construct a [`StreamBuilder`] for configuring new
     /// [`MarketEvent<SubKind::Event>`](crate::event::MarketEvent) [`Streams`].
     pub fn builder<Kind>() -> StreamBuilder<Kind>
@@ -35,24 +203,196 @@ impl<T> Streams<T> {
         MultiStreamBuilder::<T>::new()
     }
 
-    /// Remove an exchange [`mpsc::UnboundedReceiver`] from the [`Streams`] `HashMap`.
-    pub fn select(&mut self, exchange: ExchangeId) -> Option<mpsc::UnboundedReceiver<T>> {
-        self.streams.remove(&exchange)
+    /// Remove an exchange [`EventReceiver`] from the [`Streams`] `HashMap`.
+    ///
+    /// Returns a [`StreamError::ExchangeNotInitialised`] naming the available exchanges if the
+    /// exchange was not initialised, or its receiver has already been selected.
+    pub fn select(&mut self, exchange: ExchangeId) -> Result<EventReceiver<T>, StreamError> {
+        match self.streams.remove(&exchange) {
+            Some(exchange_rx) => Ok(exchange_rx),
+            None => Err(StreamError::ExchangeNotInitialised {
+                requested: exchange,
+                available: self.available(),
+            }),
+        }
+    }
+
+    /// Remove the [`EventReceiver`]s of the provided exchanges from the [`Streams`] `HashMap`,
+    /// returned in the same order as the provided exchanges.
+    ///
+    /// No receivers are removed if any exchange was not initialised, has already been selected,
+    /// or is provided more than once, in which case a [`StreamError::ExchangesNotInitialised`]
+    /// naming exactly those exchanges is returned.
+    pub fn select_many(
+        &mut self,
+        exchanges: &[ExchangeId],
+    ) -> Result<Vec<EventReceiver<T>>, StreamError> {
+        let mut selected = HashSet::with_capacity(exchanges.len());
+        let missing = exchanges
+            .iter()
+            .filter(|exchange| !self.streams.contains_key(exchange) || !selected.insert(**exchange))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            return Err(StreamError::ExchangesNotInitialised {
+                missing,
+                available: self.available(),
+            });
+        }
+
+        Ok(exchanges
+            .iter()
+            .filter_map(|exchange| self.streams.remove(exchange))
+            .collect())
+    }
+
+    /// Consume the [`Streams`] into every remaining exchange [`EventReceiver`], sorted by
+    /// [`ExchangeId`].
+    pub fn select_all(self) -> Vec<(ExchangeId, EventReceiver<T>)> {
+        let mut streams = self.streams.into_iter().collect::<Vec<_>>();
+        streams.sort_by_key(|(exchange, _)| *exchange);
+        streams
+    }
+
+    /// Sorted [`ExchangeId`]s of every exchange [`EventReceiver`] that is yet to be selected.
+    fn available(&self) -> Vec<ExchangeId> {
+        let mut available = self.streams.keys().copied().collect::<Vec<_>>();
+        available.sort();
+        available
+    }
+
+    /// Snapshot of the [`StreamStats`] of each exchange, including any exchange receivers that
+    /// have already been selected.
+    ///
+    /// Counters are maintained with atomics by each consumer loop, so taking a snapshot never
+    /// blocks the hot path.
+    pub fn stats(&self) -> HashMap<ExchangeId, StreamStats> {
+        let mut stats = self
+            .subscriptions
+            .snapshot()
+            .into_iter()
+            .map(|(exchange, subscriptions)| {
+                let stats = StreamStats {
+                    dropped: 0,
+                    subscriptions,
+                };
+                (exchange, stats)
+            })
+            .collect::<HashMap<_, _>>();
+
+        for (exchange, dropped) in &self.dropped {
+            stats.entry(*exchange).or_default().dropped = dropped.load(Ordering::Relaxed);
+        }
+
+        stats
+    }
+
+    /// Take the [`ConnectionEvent`] [`mpsc::UnboundedReceiver`] shared by every connection of
+    /// the [`Streams`]. Returns `None` if it has already been taken.
+    pub fn connection_events(&mut self) -> Option<mpsc::UnboundedReceiver<ConnectionEvent>> {
+        self.connection_events.take()
     }
 
-    /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified
-    /// [`mpsc::UnboundedReceiver`].
-    pub async fn join(self) -> mpsc::UnboundedReceiver<T>
+    /// Take the bounded [`ConnectionError`] [`mpsc::Receiver`] shared by every connection of the
+    /// [`Streams`], yielding each [`DataError`] consumed from a connection (eg/ an invalid
+    /// event, a sequence gap, an out of range timestamp) that the exchange receivers cannot yield.
+    ///
+    /// [`ConnectionError`]s are dropped whenever the channel is full. Returns `None` if it has
+    /// already been taken. Yields nothing if the [`Streams`] were initialised with an
+    /// [`EventHandler`](handler::EventHandler), which handles every [`DataError`] inline.
+    pub fn errors(&mut self) -> Option<mpsc::Receiver<ConnectionError>> {
+        self.errors.take()
+    }
+
+    /// Take the bounded [`RawPayload`] [`mpsc::Receiver`] shared by every connection of the
+    /// [`Streams`], yielding each event alongside the raw exchange payload it was normalised from.
+    ///
+    /// Returns `None` if raw payloads were not enabled (see
+    /// [`StreamBuilder::with_raw_payloads`]), or if it has already been taken.
+    pub fn raw_payloads(&mut self) -> Option<mpsc::Receiver<(T, RawPayload)>> {
+        self.raw_payloads.take()
+    }
+
+    /// Take the [`Watermark`] [`mpsc::UnboundedReceiver`] shared by every connection of the
+    /// [`Streams`], yielding the monotonically advancing event-time [`Watermark`] of each
+    /// connection.
+    ///
+    /// Returns `None` if watermarks were not enabled (see [`StreamBuilder::with_watermarks`]), or
+    /// if it has already been taken.
+    pub fn watermarks(&mut self) -> Option<mpsc::UnboundedReceiver<Watermark>> {
+        self.watermarks.take()
+    }
+
+    /// Unsubscribe from the provided [`Subscription`]s, sending the exchange specific unsubscribe
+    /// payloads (see [`Connector::unsubscribe_requests`]) via the connections that actioned them.
+    ///
+    /// Any in-flight messages of the [`Subscription`]s are ignored, and connections left without
+    /// any [`Subscription`]s are gracefully closed.
+    pub fn unsubscribe<Exchange, Kind>(
+        &self,
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> Result<(), DataError>
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let subscription_ids = subscriptions
+            .iter()
+            .map(|subscription| {
+                ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id()
+            })
+            .collect();
+
+        let (command, _ack_rx) = UnsubscribeCommand::new(subscription_ids);
+        self.send(Exchange::ID, StreamCommand::Unsubscribe(command))
+    }
+
+    /// Construct a cloneable [`StreamController`] handle for subscribing to & unsubscribing from
+    /// [`Subscription`]s on the existing connections at runtime, whose events are sent via the
+    /// existing exchange receivers.
+    ///
+    /// Unlike the [`Streams`], the [`StreamController`] keeps the underlying connections
+    /// controllable after the receivers are joined (eg/ via [`Streams::join`]).
+    pub fn controller(&self) -> StreamController {
+        StreamController::new(self.commands.clone())
+    }
+
+    /// Gracefully shutdown every connection of the [`Streams`], sending a close frame to each
+    /// exchange server. Each exchange receiver yields `None` once all of its connections have
+    /// closed.
+    pub fn shutdown(&self) {
+        for exchange in self.commands.keys() {
+            let _ = self.send(*exchange, StreamCommand::Shutdown);
+        }
+    }
+
+    /// Send a [`StreamCommand`] to every connection of the provided exchange.
+    fn send(&self, exchange: ExchangeId, command: StreamCommand) -> Result<(), DataError> {
+        self.commands
+            .get(&exchange)
+            .and_then(|commands_tx| commands_tx.send(command).ok())
+            .map(|_| ())
+            .ok_or_else(|| {
+                DataError::Socket(SocketError::Subscribe(format!(
+                    "no running {exchange} connections"
+                )))
+            })
+    }
+
+    /// Join all exchange [`EventReceiver`] streams into a unified [`EventReceiver`], with the
+    /// same [`ChannelCapacity`] as each exchange receiver.
+    pub async fn join(self) -> EventReceiver<T>
     where
         T: Send + 'static,
     {
-        let (joined_tx, joined_rx) = mpsc::unbounded_channel();
+        let (joined_tx, joined_rx) = self.capacity.channel();
 
         for mut exchange_rx in self.streams.into_values() {
             let joined_tx = joined_tx.clone();
             tokio::spawn(async move {
                 while let Some(event) = exchange_rx.recv().await {
-                    let _ = joined_tx.send(event);
+                    let _ = joined_tx.send(event).await;
                 }
             });
         }
@@ -60,13 +400,334 @@ impl<T> Streams<T> {
         joined_rx
     }
 
-    /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified [`StreamMap`].
-    pub async fn join_map(self) -> StreamMap<ExchangeId, UnboundedReceiverStream<T>> {
+    /// Join all exchange [`EventReceiver`] streams into a unified [`StreamMap`].
+    pub async fn join_map(self) -> StreamMap<ExchangeId, EventReceiver<T>> {
         self.streams
             .into_iter()
             .fold(StreamMap::new(), |mut map, (exchange, rx)| {
-                map.insert(exchange, UnboundedReceiverStream::new(rx));
+                map.insert(exchange, rx);
                 map
             })
     }
 }
+
+impl<T> Streams<MarketEvent<T>> {
+    /// Join all exchange [`EventReceiver`] streams into a unified [`EventReceiver`] that
+    /// releases events in `exchange_time` order, buffering each event for up to `max_skew`.
+    ///
+    /// Events arriving after a later event has been released are emitted immediately, marked
+    /// via [`OrderedEvent::late`]. An idle exchange never stalls the merge for longer than
+    /// `max_skew`. See [`OrderedMerge`](ordered::OrderedMerge) for the ordering behaviour.
+    pub async fn join_ordered(self, max_skew: Duration) -> EventReceiver<OrderedEvent<T>>
+    where
+        T: Send + 'static,
+    {
+        let (ordered_tx, ordered_rx) = self.capacity.channel();
+        tokio::spawn(merge_ordered(self.join_map().await, max_skew, ordered_tx));
+        ordered_rx
+    }
+}
+
+impl Streams<MarketEvent<OrderBook>> {
+    /// Select a shared [`BookHandle`] to the book of the provided [`Instrument`], maintained
+    /// from the level 2 [`OrderBook`] events of the exchange.
+    ///
+    /// The first call for an exchange interposes a task between its connections and its
+    /// [`EventReceiver`], so every event is still yielded via [`Streams::select`] after updating
+    /// the book. Returns `None` if the exchange receiver has already been selected.
+    ///
+    /// See [`BookHandle`] for the re-connection behaviour.
+    pub fn select_book(
+        &mut self,
+        exchange: ExchangeId,
+        instrument: &Instrument,
+    ) -> Option<BookHandle> {
+        if let Some(registry) = self.books.get(&exchange) {
+            return Some(registry.handle(instrument));
+        }
+
+        let exchange_rx = self.streams.remove(&exchange)?;
+        let (book_tx, book_rx) = self.capacity.channel();
+        if let Some(dropped) = book_rx.dropped_counter() {
+            self.dropped.insert(exchange, dropped);
+        }
+
+        let registry = BookRegistry::default();
+        registry.spawn(exchange_rx, book_tx);
+        self.streams.insert(exchange, book_rx);
+
+        let handle = registry.handle(instrument);
+        self.books.insert(exchange, registry);
+        Some(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::MarketEvent,
+        exchange::{binance::spot::BinanceSpot, okx::Okx},
+        subscription::{
+            candle::{Candle, CandleSource, VolumeDenomination},
+            number::{self, Number},
+            trade::{PublicTrade, PublicTrades},
+        },
+    };
+    use barter_integration::model::{Exchange, InstrumentKind, SubscriptionId};
+    use chrono::{DateTime, Utc};
+
+    #[test]
+    fn test_streams_unsubscribe() {
+        let (commands_tx, mut commands_rx) = broadcast::channel(8);
+        let streams = Streams::<MarketEvent<PublicTrade>>::new(
+            HashMap::new(),
+            None,
+            None,
+            None,
+            None,
+            HashMap::from([(ExchangeId::BinanceSpot, commands_tx)]),
+            ChannelCapacity::default(),
+            StatsRegistry::default(),
+        );
+
+        // Unsubscribe Command is sent to the connections of the Subscription exchange
+        let subscription = Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ));
+        streams.unsubscribe(&[subscription]).unwrap();
+        match commands_rx.try_recv().unwrap() {
+            StreamCommand::Unsubscribe(command) => assert_eq!(
+                command.subscription_ids(),
+                [SubscriptionId::from("@trade|BTCUSDT")]
+            ),
+            command => panic!("expected StreamCommand::Unsubscribe, actual: {command:?}"),
+        }
+
+        // Exchange without any connections fails
+        let subscription =
+            Subscription::from((Okx, "btc", "usdt", InstrumentKind::Spot, PublicTrades));
+        assert!(streams.unsubscribe(&[subscription]).is_err());
+
+        // Shutdown Command is sent to the connections of every exchange
+        streams.shutdown();
+        assert_eq!(commands_rx.try_recv().unwrap(), StreamCommand::Shutdown);
+    }
+
+    #[tokio::test]
+    async fn test_streams_join_candles_multi_exchange() {
+        fn candle(exchange: ExchangeId, close: f64) -> MarketEvent<Candle> {
+            let close = number::from_f64(close);
+            let time = DateTime::<Utc>::from_utc(
+                chrono::NaiveDateTime::from_timestamp_opt(1_680_000_000, 0).unwrap(),
+                Utc,
+            );
+            MarketEvent {
+                exchange_time: time,
+                received_time: time,
+                exchange: Exchange::from(exchange),
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                sequence: None,
+                tag: None,
+                kind: Candle {
+                    start_time: time - chrono::Duration::minutes(1),
+                    close_time: time,
+                    open: close,
+                    high: close,
+                    low: close,
+                    close,
+                    volume: Number::from(1),
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: None,
+                    quote_volume: None,
+                    taker_buy_volume: None,
+                    taker_buy_quote_volume: None,
+                    trade_count: 1,
+                    closed: true,
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: false,
+                },
+            }
+        }
+
+        // Mocked BinanceSpot & Okx Candles streams
+        let capacity = ChannelCapacity::default();
+        let (binance_tx, binance_rx) = capacity.channel();
+        let (okx_tx, okx_rx) = capacity.channel();
+        let streams = Streams::<MarketEvent<Candle>>::new(
+            HashMap::from([
+                (ExchangeId::BinanceSpot, binance_rx),
+                (ExchangeId::Okx, okx_rx),
+            ]),
+            None,
+            None,
+            None,
+            None,
+            HashMap::new(),
+            capacity,
+            StatsRegistry::default(),
+        );
+
+        binance_tx
+            .send(candle(ExchangeId::BinanceSpot, 100.0))
+            .await
+            .unwrap();
+        okx_tx.send(candle(ExchangeId::Okx, 101.0)).await.unwrap();
+        drop((binance_tx, okx_tx));
+
+        // Joined receiver yields the Candles of both exchanges, distinguished by the exchange
+        let mut joined = streams.join().await;
+        let mut actual = Vec::new();
+        while let Some(event) = joined.recv().await {
+            actual.push((event.exchange, number::to_f64(event.kind.close)));
+        }
+        actual.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        assert_eq!(
+            actual,
+            vec![
+                (Exchange::from(ExchangeId::BinanceSpot), 100.0),
+                (Exchange::from(ExchangeId::Okx), 101.0),
+            ]
+        );
+    }
+
+    fn streams_select() -> Streams<MarketEvent<PublicTrade>> {
+        let capacity = ChannelCapacity::default();
+        Streams::new(
+            [
+                ExchangeId::Okx,
+                ExchangeId::BinanceSpot,
+                ExchangeId::Coinbase,
+            ]
+            .into_iter()
+            .map(|exchange| (exchange, capacity.channel().1))
+            .collect(),
+            None,
+            None,
+            None,
+            None,
+            HashMap::new(),
+            capacity,
+            StatsRegistry::default(),
+        )
+    }
+
+    #[test]
+    fn test_streams_select() {
+        let mut streams = streams_select();
+
+        // Initialised exchange is selected
+        assert!(streams.select(ExchangeId::Okx).is_ok());
+
+        // Already selected exchange names the remaining available exchanges
+        let error = streams.select(ExchangeId::Okx).unwrap_err();
+        assert_eq!(
+            error,
+            StreamError::ExchangeNotInitialised {
+                requested: ExchangeId::Okx,
+                available: vec![ExchangeId::BinanceSpot, ExchangeId::Coinbase],
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "ExchangeNotInitialised: okx stream was not initialised or has already been selected, \
+            available: [binance_spot, coinbase]"
+        );
+
+        // Exchange that was never initialised
+        assert_eq!(
+            streams.select(ExchangeId::Kraken).unwrap_err().to_string(),
+            "ExchangeNotInitialised: kraken stream was not initialised or has already been \
+            selected, available: [binance_spot, coinbase]"
+        );
+    }
+
+    #[test]
+    fn test_streams_select_many() {
+        struct TestCase {
+            input: Vec<ExchangeId>,
+            expected: Result<usize, Vec<ExchangeId>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: missing exchanges are named exactly
+                input: vec![
+                    ExchangeId::Coinbase,
+                    ExchangeId::Kraken,
+                    ExchangeId::MexcSpot,
+                ],
+                expected: Err(vec![ExchangeId::Kraken, ExchangeId::MexcSpot]),
+            },
+            TestCase {
+                // TC1: duplicate exchange is missing the second time it is selected
+                input: vec![ExchangeId::Okx, ExchangeId::Okx],
+                expected: Err(vec![ExchangeId::Okx]),
+            },
+            TestCase {
+                // TC2: receivers are returned in input order, since no receivers were removed
+                // by the failed selections
+                input: vec![ExchangeId::Coinbase, ExchangeId::Okx],
+                expected: Ok(2),
+            },
+            TestCase {
+                // TC3: previously selected exchanges are missing
+                input: vec![ExchangeId::BinanceSpot, ExchangeId::Okx],
+                expected: Err(vec![ExchangeId::Okx]),
+            },
+        ];
+
+        let mut streams = streams_select();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let available = streams.available();
+            let actual = streams.select_many(&test.input);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual.len(), expected, "TC{} failed", index)
+                }
+                (Err(actual), Err(missing)) => {
+                    assert_eq!(
+                        actual,
+                        StreamError::ExchangesNotInitialised { missing, available },
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {:?}\nExpected: {expected:?}\n", actual.map(|rxs| rxs.len()));
+                }
+            }
+        }
+
+        assert_eq!(
+            streams
+                .select_many(&[ExchangeId::Kraken, ExchangeId::Okx])
+                .unwrap_err()
+                .to_string(),
+            "ExchangesNotInitialised: [kraken, okx] streams were not initialised or have already \
+            been selected, available: [binance_spot]"
+        );
+    }
+
+    #[test]
+    fn test_streams_select_all() {
+        let mut streams = streams_select();
+        streams.select(ExchangeId::Coinbase).unwrap();
+
+        let actual = streams
+            .select_all()
+            .into_iter()
+            .map(|(exchange, _)| exchange)
+            .collect::<Vec<_>>();
+
+        assert_eq!(actual, vec![ExchangeId::BinanceSpot, ExchangeId::Okx]);
+    }
+}
@@ -0,0 +1,406 @@
+use super::{
+    channel::{EventReceiver, EventSender},
+    conflate::sleep_until,
+};
+use crate::{event::MarketEvent, exchange::ExchangeId};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, HashSet, VecDeque},
+    time::Duration,
+};
+use tokio::time::Instant;
+use tokio_stream::StreamMap;
+
+/// [`MarketEvent`] released by an [`OrderedMerge`], in `exchange_time` order unless `late`.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderedEvent<T> {
+    pub event: MarketEvent<T>,
+    /// Event arrived after a [`MarketEvent`] with a later `exchange_time` had already been
+    /// released, so it is emitted immediately and out of order.
+    pub late: bool,
+}
+
+/// Merges [`MarketEvent`]s from multiple sources into `exchange_time` order, buffering each
+/// event for up to `max_skew` after it arrives.
+///
+/// ### Notes
+/// - Buffered events are held in a binary heap ordered by `exchange_time`, with ties released
+///   in arrival order.
+/// - Once an event has been buffered for `max_skew`, it is released alongside every buffered
+///   event with an earlier `exchange_time`. An idle source therefore never stalls the merge
+///   for longer than `max_skew`.
+/// - An event with an `exchange_time` earlier than an already released event is emitted
+///   immediately with [`OrderedEvent::late`] set, rather than reordering released output.
+#[derive(Debug)]
+pub struct OrderedMerge<T> {
+    max_skew: Duration,
+    buffer: BinaryHeap<Reverse<Buffered<T>>>,
+    /// Release deadline & sequence of each buffered event, in arrival order.
+    arrivals: VecDeque<(Instant, u64)>,
+    /// Sequences of buffered events released ahead of their own deadline.
+    released_early: HashSet<u64>,
+    /// Most recent `exchange_time` released in order.
+    watermark: Option<DateTime<Utc>>,
+    sequence: u64,
+}
+
+/// [`MarketEvent`] buffered by an [`OrderedMerge`], ordered by `exchange_time` then arrival
+/// sequence.
+#[derive(Debug)]
+struct Buffered<T> {
+    sequence: u64,
+    event: MarketEvent<T>,
+}
+
+impl<T> Buffered<T> {
+    fn key(&self) -> (DateTime<Utc>, u64) {
+        (self.event.exchange_time, self.sequence)
+    }
+}
+
+impl<T> PartialEq for Buffered<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<T> Eq for Buffered<T> {}
+
+impl<T> PartialOrd for Buffered<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Buffered<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl<T> OrderedMerge<T> {
+    /// Construct a new [`OrderedMerge`] that buffers each event for up to `max_skew`.
+    pub fn new(max_skew: Duration) -> Self {
+        Self {
+            max_skew,
+            buffer: BinaryHeap::new(),
+            arrivals: VecDeque::new(),
+            released_early: HashSet::new(),
+            watermark: None,
+            sequence: 0,
+        }
+    }
+
+    /// Release deadline of the longest buffered event, if any.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.arrivals.front().map(|(deadline, _)| *deadline)
+    }
+
+    /// Update the [`OrderedMerge`] with the next [`MarketEvent`] received at the provided `now`,
+    /// returning it immediately as a late [`OrderedEvent`] if it can no longer be released in
+    /// order.
+    pub fn update(&mut self, event: MarketEvent<T>, now: Instant) -> Option<OrderedEvent<T>> {
+        if self
+            .watermark
+            .is_some_and(|watermark| event.exchange_time < watermark)
+        {
+            return Some(OrderedEvent { event, late: true });
+        }
+
+        let sequence = self.sequence;
+        self.sequence += 1;
+        self.arrivals.push_back((now + self.max_skew, sequence));
+        self.buffer.push(Reverse(Buffered { sequence, event }));
+        None
+    }
+
+    /// Release every event buffered for at least `max_skew` at the provided `now`, alongside
+    /// any buffered events with an earlier `exchange_time`, in `exchange_time` order.
+    pub fn expire(&mut self, now: Instant) -> Vec<OrderedEvent<T>> {
+        let mut released = Vec::new();
+
+        while let Some(&(deadline, sequence)) = self.arrivals.front() {
+            if self.released_early.remove(&sequence) {
+                self.arrivals.pop_front();
+                continue;
+            }
+
+            if deadline > now {
+                break;
+            }
+
+            // Release buffered events in order, up to & including the expired event
+            while let Some(Reverse(buffered)) = self.buffer.pop() {
+                let expired = buffered.sequence == sequence;
+                if !expired {
+                    self.released_early.insert(buffered.sequence);
+                }
+                released.push(self.release(buffered));
+                if expired {
+                    break;
+                }
+            }
+            self.arrivals.pop_front();
+        }
+
+        released
+    }
+
+    /// Release every buffered event in `exchange_time` order (eg/ once every source has ended).
+    pub fn flush(&mut self) -> Vec<OrderedEvent<T>> {
+        self.arrivals.clear();
+        self.released_early.clear();
+        let mut released = Vec::with_capacity(self.buffer.len());
+        while let Some(Reverse(buffered)) = self.buffer.pop() {
+            released.push(self.release(buffered));
+        }
+        released
+    }
+
+    fn release(&mut self, buffered: Buffered<T>) -> OrderedEvent<T> {
+        self.watermark = Some(buffered.event.exchange_time);
+        OrderedEvent {
+            event: buffered.event,
+            late: false,
+        }
+    }
+}
+
+/// Merge the [`MarketEvent`]s of every exchange receiver into the `ordered_tx` using an
+/// [`OrderedMerge`]. See [`OrderedMerge`] for the ordering behaviour.
+///
+/// Any buffered events are flushed once every exchange receiver has ended.
+pub async fn merge_ordered<T>(
+    mut streams: StreamMap<ExchangeId, EventReceiver<MarketEvent<T>>>,
+    max_skew: Duration,
+    ordered_tx: EventSender<OrderedEvent<T>>,
+) {
+    let mut merge = OrderedMerge::new(max_skew);
+
+    loop {
+        let output = tokio::select! {
+            event = streams.next() => match event {
+                Some((_, event)) => merge.update(event, Instant::now()).into_iter().collect(),
+                None => break,
+            },
+            _ = sleep_until(merge.next_deadline()) => merge.expire(Instant::now()),
+        };
+
+        for event in output {
+            if ordered_tx.send(event).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    for event in merge.flush() {
+        if ordered_tx.send(event).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        streams::channel::ChannelCapacity,
+        subscription::{number::Number, trade::PublicTrade},
+    };
+    use barter_integration::model::{Exchange, Instrument, InstrumentKind, Side};
+
+    /// 2023-01-01T00:00:00Z
+    fn base() -> DateTime<Utc> {
+        barter_integration::de::datetime_utc_from_epoch_duration(Duration::from_secs(1672531200))
+    }
+
+    /// [`PublicTrade`] of the provided exchange, with an `exchange_time` `millis` after the
+    /// [`base`] time.
+    fn trade(exchange: ExchangeId, millis: i64) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: base() + chrono::Duration::milliseconds(millis),
+            received_time: base(),
+            exchange: Exchange::from(exchange),
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind: PublicTrade {
+                id: millis.to_string(),
+                price: Number::from(1),
+                amount: Number::from(1),
+                side: Side::Buy,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        }
+    }
+
+    /// Millisecond offset from the [`base`] time & late flag of each [`OrderedEvent`].
+    fn millis(events: &[OrderedEvent<PublicTrade>]) -> Vec<(i64, bool)> {
+        events
+            .iter()
+            .map(|ordered| {
+                (
+                    (ordered.event.exchange_time - base()).num_milliseconds(),
+                    ordered.late,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ordered_merge() {
+        struct TestCase {
+            advance: Duration,
+            input: Vec<MarketEvent<PublicTrade>>,
+            expected_update: Vec<(i64, bool)>,
+            expected_expire: Vec<(i64, bool)>,
+        }
+
+        let max_skew = Duration::from_millis(100);
+        let mut merge = OrderedMerge::new(max_skew);
+        let mut now = Instant::now();
+
+        let tests = vec![
+            TestCase {
+                // TC0: events from three sources are buffered until the max_skew elapses
+                advance: Duration::ZERO,
+                input: vec![
+                    trade(ExchangeId::BinanceSpot, 30),
+                    trade(ExchangeId::Okx, 10),
+                    trade(ExchangeId::Kraken, 20),
+                ],
+                expected_update: vec![],
+                expected_expire: vec![],
+            },
+            TestCase {
+                // TC1: buffered events are released in exchange_time order once expired
+                advance: max_skew,
+                input: vec![],
+                expected_update: vec![],
+                expected_expire: vec![(10, false), (20, false), (30, false)],
+            },
+            TestCase {
+                // TC2: event earlier than the released output is emitted immediately as late
+                advance: Duration::from_millis(10),
+                input: vec![trade(ExchangeId::Okx, 25)],
+                expected_update: vec![(25, true)],
+                expected_expire: vec![],
+            },
+            TestCase {
+                // TC3: event equal to the released output is buffered in order
+                advance: Duration::ZERO,
+                input: vec![trade(ExchangeId::Kraken, 30), trade(ExchangeId::Okx, 50)],
+                expected_update: vec![],
+                expected_expire: vec![],
+            },
+            TestCase {
+                // TC4: idle BinanceSpot source does not stall the merge beyond the max_skew,
+                // and later events from the other sources remain buffered
+                advance: Duration::from_millis(50),
+                input: vec![trade(ExchangeId::Kraken, 40)],
+                expected_update: vec![],
+                expected_expire: vec![],
+            },
+            TestCase {
+                // TC5: expired event releases buffered events with an earlier exchange_time
+                advance: Duration::from_millis(50),
+                input: vec![],
+                expected_update: vec![],
+                expected_expire: vec![(30, false), (40, false), (50, false)],
+            },
+            TestCase {
+                // TC6: event released early does not release anything when its deadline passes
+                advance: Duration::from_millis(50),
+                input: vec![trade(ExchangeId::BinanceSpot, 60)],
+                expected_update: vec![],
+                expected_expire: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            now += test.advance;
+
+            let updated = test
+                .input
+                .into_iter()
+                .filter_map(|event| merge.update(event, now))
+                .collect::<Vec<_>>();
+            assert_eq!(millis(&updated), test.expected_update, "TC{} failed", index);
+
+            let expired = merge.expire(now);
+            assert_eq!(millis(&expired), test.expected_expire, "TC{} failed", index);
+        }
+
+        // Remaining buffered events are released in order upon flush
+        assert_eq!(millis(&merge.flush()), vec![(60, false)]);
+        assert_eq!(merge.next_deadline(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_merge_ordered_three_sources() {
+        let max_skew = Duration::from_millis(100);
+        let mut senders = Vec::new();
+        let mut streams = StreamMap::new();
+        for exchange in [ExchangeId::BinanceSpot, ExchangeId::Okx, ExchangeId::Kraken] {
+            let (tx, rx) = ChannelCapacity::Unbounded.channel();
+            senders.push((exchange, tx));
+            streams.insert(exchange, rx);
+        }
+
+        let (ordered_tx, mut ordered_rx) = ChannelCapacity::Unbounded.channel();
+        tokio::spawn(merge_ordered(streams, max_skew, ordered_tx));
+
+        // Each source delivers events interleaved & out of exchange_time order
+        for (exchange, tx) in &senders {
+            let offset = match exchange {
+                ExchangeId::BinanceSpot => 0,
+                ExchangeId::Okx => 1,
+                _ => 2,
+            };
+            for millis in [30 + offset, offset, 20 + offset, 10 + offset] {
+                tx.send(trade(*exchange, millis)).await.unwrap();
+            }
+        }
+
+        // Nothing is released before the max_skew elapses
+        tokio::time::sleep(max_skew / 2).await;
+        assert!(ordered_rx.try_recv().is_err());
+
+        // Every event is released in exchange_time order, despite the Kraken source going idle
+        tokio::time::sleep(max_skew).await;
+        let released = std::iter::from_fn(|| ordered_rx.try_recv().ok()).collect::<Vec<_>>();
+        let expected = [0, 10, 20, 30]
+            .into_iter()
+            .flat_map(|millis| [millis, millis + 1, millis + 2])
+            .map(|millis| (millis, false))
+            .collect::<Vec<_>>();
+        assert_eq!(millis(&released), expected);
+
+        // Late event of the BinanceSpot source is emitted immediately, marked late
+        senders[0]
+            .1
+            .send(trade(ExchangeId::BinanceSpot, 5))
+            .await
+            .unwrap();
+        tokio::task::yield_now().await;
+        assert_eq!(millis(&[ordered_rx.recv().await.unwrap()]), vec![(5, true)]);
+
+        // Buffered events are flushed & the receiver ends once every source has ended
+        senders[1]
+            .1
+            .send(trade(ExchangeId::Okx, 100))
+            .await
+            .unwrap();
+        drop(senders);
+        assert_eq!(
+            millis(&[ordered_rx.recv().await.unwrap()]),
+            vec![(100, false)]
+        );
+        assert!(ordered_rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,302 @@
+use crate::event::MarketEvent;
+use barter_integration::protocol::websocket::WsMessage;
+use std::{
+    fmt::{Display, Formatter},
+    sync::{Arc, Mutex},
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Default capacity of the bounded [`RawPayload`] channel enabled via
+/// [`StreamBuilder::with_raw_payloads`](super::builder::StreamBuilder::with_raw_payloads).
+///
+/// Once full, further [`RawPayload`]s are dropped until the receiver catches up, so memory usage
+/// is bounded regardless of how slowly the receiver is consumed.
+pub const DEFAULT_RAW_PAYLOAD_CAPACITY: usize = 1024;
+
+/// Original text frame received from the exchange server that a [`MarketEvent`] was normalised
+/// from.
+///
+/// Shared via an [`Arc<str>`] so every [`MarketEvent`] normalised from the same frame (eg/ a
+/// batch of trades) references a single allocation.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct RawPayload(pub Arc<str>);
+
+impl RawPayload {
+    /// Raw text frame as a `&str`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for RawPayload {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for RawPayload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for RawPayload {
+    fn from(raw: &str) -> Self {
+        Self(Arc::from(raw))
+    }
+}
+
+/// Determine the raw text of a message received from the exchange server, if any.
+pub trait RawText {
+    fn raw_text(&self) -> Option<&str>;
+}
+
+impl<Error> RawText for Result<WsMessage, Error> {
+    fn raw_text(&self) -> Option<&str> {
+        match self {
+            Ok(WsMessage::Text(text)) => Some(text),
+            Ok(WsMessage::Binary(binary)) => std::str::from_utf8(binary).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl RawText for &str {
+    fn raw_text(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+/// Opt-in shared handle to the [`RawPayload`] of the last message received from the exchange
+/// server, recorded by the [`HeartbeatStream`](super::health::HeartbeatStream) of a connection.
+///
+/// Disabled by default, in which case recording is a single `Option` check, so the hot path is
+/// unaffected. Compressed (eg/ gzip) binary frames are not valid UTF-8 and are never recorded.
+#[derive(Clone, Debug, Default)]
+pub struct RawCapture(Option<Arc<Mutex<Option<RawPayload>>>>);
+
+impl RawCapture {
+    /// Construct a new enabled [`Self`].
+    pub fn enabled() -> Self {
+        Self(Some(Arc::new(Mutex::new(None))))
+    }
+
+    /// Determine if [`Self`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// Record the message just received from the exchange server, if enabled.
+    pub fn record<Message>(&self, message: &Message)
+    where
+        Message: RawText,
+    {
+        if let Some(last) = &self.0 {
+            *last.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) =
+                message.raw_text().map(RawPayload::from);
+        }
+    }
+
+    /// [`RawPayload`] of the last message received from the exchange server, if enabled & the
+    /// message was text.
+    pub fn last(&self) -> Option<RawPayload> {
+        self.0.as_ref().and_then(|last| {
+            last.lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .clone()
+        })
+    }
+
+    /// Send the [`MarketEvent`] alongside the [`RawPayload`] it was normalised from via the
+    /// bounded `raw_tx`, dropping the pair if the channel is full.
+    pub fn forward<T>(
+        &self,
+        raw_tx: &mpsc::Sender<(MarketEvent<T>, RawPayload)>,
+        event: &MarketEvent<T>,
+    ) where
+        T: Clone,
+    {
+        let Some(raw) = self.last() else {
+            return;
+        };
+
+        if let Err(mpsc::error::TrySendError::Full(_)) = raw_tx.try_send((event.clone(), raw)) {
+            debug!(
+                exchange = %event.exchange,
+                instrument = %event.instrument,
+                "RawPayload channel full, dropping RawPayload"
+            );
+        }
+    }
+}
+
+/// Convenient type that holds the bounded [`mpsc::Sender`] and [`mpsc::Receiver`] for a
+/// `(T, RawPayload)` channel.
+#[derive(Debug)]
+pub struct RawChannel<T> {
+    pub tx: mpsc::Sender<(T, RawPayload)>,
+    pub rx: mpsc::Receiver<(T, RawPayload)>,
+}
+
+impl<T> RawChannel<T> {
+    /// Construct a new [`Self`] with the provided capacity.
+    pub fn new(capacity: usize) -> Self {
+        let (tx, rx) = mpsc::channel(capacity);
+        Self { tx, rx }
+    }
+}
+
+impl<T> Default for RawChannel<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_RAW_PAYLOAD_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+        streams::health::HeartbeatStream,
+        subscription::{trade::PublicTrades, Map},
+        transformer::{stateless::StatelessTransformer, ExchangeTransformer},
+    };
+    use barter_integration::{
+        model::{Instrument, InstrumentKind, SubscriptionId},
+        Transformer,
+    };
+    use futures::StreamExt;
+    use std::collections::HashMap;
+
+    fn binance_trade(id: u64) -> String {
+        format!(
+            r#"{{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":{id},"p":"16000.0","q":"0.5","b":10,"a":11,"T":1649324825173,"m":true,"M":true}}"#
+        )
+    }
+
+    async fn transformer() -> StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> {
+        let instrument_map = Map(HashMap::from([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        )]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        <StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> as ExchangeTransformer<
+            BinanceSpot,
+            PublicTrades,
+        >>::new(ws_sink_tx, instrument_map)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_raw_payload_parses_back_into_exchange_message() {
+        let payloads = [binance_trade(1), binance_trade(2)];
+        let mut stream = HeartbeatStream::new(futures::stream::iter(
+            payloads
+                .iter()
+                .cloned()
+                .map(|payload| Ok::<_, ()>(WsMessage::Text(payload))),
+        ));
+        stream.raw = RawCapture::enabled();
+        let capture = stream.raw.clone();
+
+        let mut transformer = transformer().await;
+        let RawChannel { tx, mut rx } = RawChannel::new(8);
+
+        // Normalise each received frame, forwarding every event alongside its RawPayload
+        while let Some(Ok(WsMessage::Text(payload))) = stream.next().await {
+            let trade = serde_json::from_str::<BinanceTrade>(&payload).unwrap();
+            for event in transformer.transform(trade) {
+                capture.forward(&tx, &event.unwrap());
+            }
+        }
+        drop(tx);
+
+        let mut received = Vec::new();
+        while let Some((event, raw)) = rx.recv().await {
+            // RawPayload parses back into the BinanceTrade that produced the MarketEvent
+            let trade = serde_json::from_str::<BinanceTrade>(raw.as_str()).unwrap();
+            assert_eq!(trade.id.to_string(), event.kind.id);
+            assert_eq!(trade.price, event.kind.price);
+            received.push(raw);
+        }
+
+        assert_eq!(
+            received,
+            payloads
+                .iter()
+                .map(|payload| RawPayload::from(payload.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_raw_capture_record() {
+        struct TestCase {
+            capture: RawCapture,
+            message: Result<WsMessage, ()>,
+            expected: Option<RawPayload>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: disabled RawCapture records nothing
+                capture: RawCapture::default(),
+                message: Ok(WsMessage::Text(binance_trade(1))),
+                expected: None,
+            },
+            TestCase {
+                // TC1: enabled RawCapture records text frame
+                capture: RawCapture::enabled(),
+                message: Ok(WsMessage::Text(binance_trade(1))),
+                expected: Some(RawPayload::from(binance_trade(1).as_str())),
+            },
+            TestCase {
+                // TC2: enabled RawCapture records UTF-8 binary frame
+                capture: RawCapture::enabled(),
+                message: Ok(WsMessage::Binary(binance_trade(2).into_bytes())),
+                expected: Some(RawPayload::from(binance_trade(2).as_str())),
+            },
+            TestCase {
+                // TC3: enabled RawCapture does not record non UTF-8 (eg/ gzip) binary frame
+                capture: RawCapture::enabled(),
+                message: Ok(WsMessage::Binary(vec![0x1f, 0x8b, 0xff])),
+                expected: None,
+            },
+            TestCase {
+                // TC4: enabled RawCapture does not record error
+                capture: RawCapture::enabled(),
+                message: Err(()),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            test.capture.record(&test.message);
+            assert_eq!(test.capture.last(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_raw_capture_forward_drops_when_full() {
+        let capture = RawCapture::enabled();
+        capture.record(&binance_trade(1).as_str());
+
+        let mut transformer = transformer().await;
+        let event = transformer
+            .transform(serde_json::from_str::<BinanceTrade>(&binance_trade(1)).unwrap())
+            .remove(0)
+            .unwrap();
+
+        // Bounded channel keeps the first pair & drops the rest
+        let RawChannel { tx, mut rx } = RawChannel::new(1);
+        capture.forward(&tx, &event);
+        capture.forward(&tx, &event);
+        drop(tx);
+
+        assert!(rx.recv().await.is_some());
+        assert!(rx.recv().await.is_none());
+    }
+}
@@ -0,0 +1,496 @@
+use crate::{error::DataError, event::MarketEvent, streams::channel::EventReceiver};
+use barter_integration::model::{Exchange, Instrument};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::PathBuf,
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    sync::mpsc,
+    time::Instant,
+};
+use tracing::{error, warn};
+
+/// Default maximum size in bytes of a single recording file before rotating to the next file.
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// File extension of newline-delimited JSON recording files.
+const RECORDING_EXTENSION: &str = "jsonl";
+
+/// Records the [`MarketEvent<T>`](MarketEvent)s of a [`Streams`](super::Streams) receiver into
+/// newline-delimited JSON files, whilst forwarding every event to the consumer unchanged.
+///
+/// Each exchange & [`Instrument`] is recorded to a separate sequence of files in the configured
+/// directory (eg/ "binance_spot/btc_usdt_spot.0.jsonl"), rotating to the next file once
+/// `max_file_size` bytes is reached. Recordings can be replayed via [`ReplayStream`].
+///
+/// ### Notes
+/// Recordings of different [`SubKind`](crate::subscription::SubKind)s should use separate
+/// directories, since the files are only keyed by exchange & [`Instrument`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct StreamRecorder {
+    pub directory: PathBuf,
+    pub max_file_size: u64,
+}
+
+impl StreamRecorder {
+    /// Construct a new [`Self`] recording to the provided directory, using the
+    /// [`DEFAULT_MAX_FILE_SIZE`].
+    pub fn new<P>(directory: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            directory: directory.into(),
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+        }
+    }
+
+    /// Configure the maximum size in bytes of a single recording file before rotating to the
+    /// next file.
+    pub fn max_file_size(mut self, bytes: u64) -> Self {
+        self.max_file_size = bytes;
+        self
+    }
+
+    /// Tee the provided exchange [`EventReceiver`] (eg/ from [`Streams::select`]) into recording
+    /// files, returning an unbounded [`EventReceiver`] that yields the same events.
+    ///
+    /// Every event is written before it is forwarded, and the returned receiver only yields
+    /// `None` once all recording files have been flushed.
+    ///
+    /// [`Streams::select`]: super::Streams::select
+    pub fn record<T>(
+        &self,
+        mut exchange_rx: EventReceiver<MarketEvent<T>>,
+    ) -> EventReceiver<MarketEvent<T>>
+    where
+        T: Serialize + Send + 'static,
+    {
+        let (recorded_tx, recorded_rx) = mpsc::unbounded_channel();
+        let mut writers = RecordingWriters::new(self.directory.clone(), self.max_file_size);
+
+        tokio::task::spawn_blocking(move || {
+            let mut batch = Vec::new();
+            while let Some(event) = exchange_rx.blocking_recv() {
+                // Write every event that is immediately available before flushing
+                batch.push(event);
+                while let Ok(event) = exchange_rx.try_recv() {
+                    batch.push(event);
+                }
+
+                for event in &batch {
+                    if let Err(error) = writers.write(event) {
+                        error!(
+                            exchange = %event.exchange,
+                            instrument = %event.instrument,
+                            %error,
+                            "failed to record MarketEvent"
+                        );
+                    }
+                }
+                writers.flush();
+
+                for event in batch.drain(..) {
+                    let _ = recorded_tx.send(event);
+                }
+            }
+        });
+
+        recorded_rx.into()
+    }
+
+    /// Recording files of the provided exchange & [`Instrument`], ordered oldest first.
+    pub fn files(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Result<Vec<PathBuf>, DataError> {
+        let directory = self.directory.join(exchange.to_string());
+        let prefix = format!("{}.", file_stem(instrument));
+
+        let mut files = std::fs::read_dir(directory)?
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let index = path
+                    .file_name()?
+                    .to_str()?
+                    .strip_prefix(&prefix)?
+                    .strip_suffix(&format!(".{RECORDING_EXTENSION}"))?
+                    .parse::<usize>()
+                    .ok()?;
+                Some((index, path))
+            })
+            .collect::<Vec<_>>();
+
+        files.sort();
+        Ok(files.into_iter().map(|(_, path)| path).collect())
+    }
+}
+
+/// Recording file stem of an [`Instrument`] (eg/ "btc_usdt_spot").
+fn file_stem(instrument: &Instrument) -> String {
+    format!(
+        "{}_{}_{}",
+        instrument.base, instrument.quote, instrument.kind
+    )
+}
+
+/// Open recording files of each exchange & [`Instrument`] used by a [`StreamRecorder`].
+#[derive(Debug)]
+struct RecordingWriters {
+    directory: PathBuf,
+    max_file_size: u64,
+    writers: HashMap<(Exchange, Instrument), RecordingWriter>,
+}
+
+/// Currently open recording file of an exchange & [`Instrument`].
+#[derive(Debug)]
+struct RecordingWriter {
+    index: usize,
+    size: u64,
+    file: BufWriter<File>,
+}
+
+impl RecordingWriters {
+    fn new(directory: PathBuf, max_file_size: u64) -> Self {
+        Self {
+            directory,
+            max_file_size,
+            writers: HashMap::new(),
+        }
+    }
+
+    /// Write the [`MarketEvent`] as a JSON line, rotating to the next file if the current file
+    /// would exceed the `max_file_size`.
+    fn write<T>(&mut self, event: &MarketEvent<T>) -> Result<(), DataError>
+    where
+        T: Serialize,
+    {
+        let mut line = serde_json::to_vec(event).map_err(std::io::Error::from)?;
+        line.push(b'\n');
+        let len = line.len() as u64;
+
+        let key = (event.exchange.clone(), event.instrument.clone());
+        let writer = match self.writers.remove(&key) {
+            Some(mut writer) if writer.size > 0 && writer.size + len > self.max_file_size => {
+                writer.file.flush()?;
+                self.open(&key, writer.index + 1)?
+            }
+            Some(writer) => writer,
+            None => self.open(&key, 0)?,
+        };
+        let writer = self.writers.entry(key).or_insert(writer);
+
+        writer.file.write_all(&line)?;
+        writer.size += len;
+        Ok(())
+    }
+
+    /// Create the recording file with the provided rotation index.
+    fn open(
+        &self,
+        (exchange, instrument): &(Exchange, Instrument),
+        index: usize,
+    ) -> Result<RecordingWriter, DataError> {
+        let directory = self.directory.join(exchange.to_string());
+        std::fs::create_dir_all(&directory)?;
+
+        let path = directory.join(format!(
+            "{}.{index}.{RECORDING_EXTENSION}",
+            file_stem(instrument)
+        ));
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(path)?;
+
+        Ok(RecordingWriter {
+            index,
+            size: 0,
+            file: BufWriter::new(file),
+        })
+    }
+
+    /// Flush every open recording file.
+    fn flush(&mut self) {
+        for ((exchange, instrument), writer) in self.writers.iter_mut() {
+            if let Err(error) = writer.file.flush() {
+                error!(%exchange, %instrument, %error, "failed to flush recording file");
+            }
+        }
+    }
+}
+
+/// Speed at which a [`ReplayStream`] yields recorded [`MarketEvent`]s.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ReplaySpeed {
+    /// Yield every recorded event as fast as possible.
+    AsFastAsPossible,
+    /// Yield recorded events paced by the original `received_time` deltas, divided by the
+    /// provided multiplier (eg/ 2.0 replays twice as fast as real-time).
+    Paced(f64),
+}
+
+impl ReplaySpeed {
+    /// [`ReplaySpeed`] that replays recorded events at the original real-time pace.
+    pub fn real_time() -> Self {
+        Self::Paced(1.0)
+    }
+}
+
+/// Replays [`MarketEvent<T>`](MarketEvent)s recorded by a [`StreamRecorder`], exposing the same
+/// [`EventReceiver`] interface as [`Streams::select`](super::Streams::select) so
+/// consumer code is unchanged.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ReplayStream;
+
+impl ReplayStream {
+    /// Replay the provided recording files in order (eg/ from [`StreamRecorder::files`]) at the
+    /// provided [`ReplaySpeed`].
+    ///
+    /// The returned [`EventReceiver`] yields `None` once every file has been replayed.
+    /// Lines that fail to deserialise are skipped with a warning.
+    pub fn init<T>(
+        files: Vec<PathBuf>,
+        speed: ReplaySpeed,
+    ) -> Result<EventReceiver<MarketEvent<T>>, DataError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        if let ReplaySpeed::Paced(multiplier) = speed {
+            if !(multiplier.is_finite() && multiplier > 0.0) {
+                return Err(DataError::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("invalid ReplaySpeed multiplier: {multiplier}"),
+                )));
+            }
+        }
+
+        let (replay_tx, replay_rx) = mpsc::unbounded_channel();
+        tokio::spawn(replay(files, speed, replay_tx));
+        Ok(replay_rx.into())
+    }
+}
+
+/// Send the [`MarketEvent`]s of every recording file via the `replay_tx`, paced according to
+/// the [`ReplaySpeed`].
+async fn replay<T>(
+    files: Vec<PathBuf>,
+    speed: ReplaySpeed,
+    replay_tx: mpsc::UnboundedSender<MarketEvent<T>>,
+) where
+    T: DeserializeOwned,
+{
+    // Replay start Instant & received_time of the first event, used to pace without drift
+    let mut origin: Option<(Instant, DateTime<Utc>)> = None;
+
+    for path in files {
+        let mut lines = match tokio::fs::File::open(&path).await {
+            Ok(file) => BufReader::new(file).lines(),
+            Err(error) => {
+                error!(path = %path.display(), %error, "failed to open recording file");
+                continue;
+            }
+        };
+
+        loop {
+            let line = match lines.next_line().await {
+                Ok(Some(line)) if line.trim().is_empty() => continue,
+                Ok(Some(line)) => line,
+                Ok(None) => break,
+                Err(error) => {
+                    error!(path = %path.display(), %error, "failed to read recording file");
+                    break;
+                }
+            };
+
+            let event = match serde_json::from_str::<MarketEvent<T>>(&line) {
+                Ok(event) => event,
+                Err(error) => {
+                    warn!(path = %path.display(), %error, "skipping invalid recorded MarketEvent");
+                    continue;
+                }
+            };
+
+            if let ReplaySpeed::Paced(multiplier) = speed {
+                let (start, first_received) =
+                    *origin.get_or_insert((Instant::now(), event.received_time));
+                let offset = (event.received_time - first_received)
+                    .to_std()
+                    .unwrap_or(Duration::ZERO);
+                tokio::time::sleep_until(start + offset.div_f64(multiplier)).await;
+            }
+
+            if replay_tx.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::{
+        number::{self, Number},
+        trade::PublicTrade,
+    };
+    use barter_integration::model::{InstrumentKind, Side};
+
+    fn trade(base: &str, id: u64, received_time: DateTime<Utc>) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: received_time - chrono::Duration::milliseconds(5),
+            received_time,
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            sequence: Some(id),
+            tag: None,
+            kind: PublicTrade {
+                id: id.to_string(),
+                price: Number::from(20_000 + id as u32),
+                amount: number::from_f64(0.5),
+                side: [Side::Buy, Side::Sell][id as usize % 2],
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        }
+    }
+
+    fn recording_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "barter-data-recorder-{name}-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[tokio::test]
+    async fn test_record_replay_round_trip() {
+        let directory = recording_directory("round-trip");
+        let recorder = StreamRecorder::new(&directory).max_file_size(16 * 1024);
+
+        let start = Utc::now();
+        let events = (0..300)
+            .map(|id| {
+                let base = ["eth", "btc", "btc"][id as usize % 3];
+                trade(base, id, start + chrono::Duration::milliseconds(id as i64))
+            })
+            .collect::<Vec<_>>();
+
+        // Record events, which are forwarded unchanged
+        let (exchange_tx, exchange_rx) = mpsc::unbounded_channel();
+        let mut recorded_rx = recorder.record(exchange_rx.into());
+        for event in events.clone() {
+            exchange_tx.send(event).unwrap();
+        }
+        drop(exchange_tx);
+
+        let mut forwarded = Vec::new();
+        while let Some(event) = recorded_rx.recv().await {
+            forwarded.push(event);
+        }
+        assert_eq!(forwarded, events);
+
+        // Replay each Instrument recording & compare with the recorded events
+        for base in ["btc", "eth"] {
+            let instrument = Instrument::from((base, "usdt", InstrumentKind::Spot));
+            let files = recorder
+                .files(&Exchange::from("binance_spot"), &instrument)
+                .unwrap();
+            assert!(files.len() > 1, "{base} recording should have rotated");
+            for file in &files {
+                assert!(std::fs::metadata(file).unwrap().len() <= 16 * 1024);
+            }
+
+            let mut replay_rx =
+                ReplayStream::init::<PublicTrade>(files, ReplaySpeed::AsFastAsPossible).unwrap();
+            let mut replayed = Vec::new();
+            while let Some(event) = replay_rx.recv().await {
+                replayed.push(event);
+            }
+
+            let expected = events
+                .iter()
+                .filter(|event| event.instrument == instrument)
+                .cloned()
+                .collect::<Vec<_>>();
+            assert_eq!(replayed, expected, "{base} replay failed");
+        }
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_replay_paced_by_speed_multiplier() {
+        let directory = recording_directory("paced");
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("btc_usdt_spot.0.jsonl");
+
+        let start = Utc::now();
+        let offsets_ms = [0, 1000, 1000, 3000];
+        let lines = offsets_ms
+            .iter()
+            .enumerate()
+            .map(|(id, offset)| {
+                let event = trade(
+                    "btc",
+                    id as u64,
+                    start + chrono::Duration::milliseconds(*offset),
+                );
+                serde_json::to_string(&event).unwrap()
+            })
+            .collect::<Vec<_>>();
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        struct TestCase {
+            speed: ReplaySpeed,
+            expected_elapsed_ms: [u64; 4],
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: real-time replay is paced by the received_time deltas
+                speed: ReplaySpeed::real_time(),
+                expected_elapsed_ms: [0, 1000, 1000, 3000],
+            },
+            TestCase {
+                // TC1: speed multiplier shortens the received_time deltas
+                speed: ReplaySpeed::Paced(2.0),
+                expected_elapsed_ms: [0, 500, 500, 1500],
+            },
+            TestCase {
+                // TC2: as fast as possible ignores the received_time deltas
+                speed: ReplaySpeed::AsFastAsPossible,
+                expected_elapsed_ms: [0, 0, 0, 0],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut replay_rx =
+                ReplayStream::init::<PublicTrade>(vec![path.clone()], test.speed).unwrap();
+
+            let replay_start = Instant::now();
+            let mut actual = [0; 4];
+            for elapsed in actual.iter_mut() {
+                replay_rx.recv().await.unwrap();
+                *elapsed = (Instant::now() - replay_start).as_millis() as u64;
+            }
+            assert!(replay_rx.recv().await.is_none(), "TC{} failed", index);
+            assert_eq!(actual, test.expected_elapsed_ms, "TC{} failed", index);
+        }
+
+        // Invalid speed multiplier is rejected
+        assert!(ReplayStream::init::<PublicTrade>(vec![path], ReplaySpeed::Paced(0.0)).is_err());
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
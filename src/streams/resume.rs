@@ -0,0 +1,336 @@
+use super::{
+    backfill::spawn_backfill,
+    channel::{EventReceiver, EventSender},
+};
+use crate::{
+    event::MarketEvent,
+    exchange::StreamSelector,
+    subscription::{
+        candle::{Candle, Candles},
+        Interval, Subscription,
+    },
+    Identifier,
+};
+use barter_integration::model::Instrument;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tracing::debug;
+
+/// Suppresses the [`Candle`]s of each resumed [`Instrument`] & [`Interval`] series that were
+/// already ingested before a restart, ie/ every [`Candle`] with a `close_time` at or before the
+/// `resume_from` time of its series.
+///
+/// ### Notes
+/// - Only [`Candle`]s with a `close_time` strictly greater than `resume_from` are forwarded, so
+///   the [`Candle`] closing exactly at `resume_from` is suppressed.
+/// - In-progress updates of the [`Candle`] spanning `resume_from` are forwarded, since its
+///   `close_time` is after `resume_from`.
+/// - [`Candle`]s of series without a `resume_from` are forwarded untouched.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CandleResume {
+    intervals: HashMap<Instrument, Vec<(Interval, DateTime<Utc>)>>,
+}
+
+impl CandleResume {
+    /// Construct a new [`CandleResume`] for the provided [`Instrument`] & [`Interval`] series,
+    /// each resuming after its `resume_from` time.
+    pub fn new<Iter>(series: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (Instrument, Interval, DateTime<Utc>)>,
+    {
+        let mut intervals = HashMap::<Instrument, Vec<(Interval, DateTime<Utc>)>>::new();
+        for (instrument, interval, resume_from) in series {
+            intervals
+                .entry(instrument)
+                .or_default()
+                .push((interval, resume_from));
+        }
+
+        Self { intervals }
+    }
+
+    /// Determine if the provided [`Candle`] should be forwarded, ie/ it closes strictly after the
+    /// `resume_from` time of its series, or its series is not resumed.
+    pub fn admit(&self, event: &MarketEvent<Candle>) -> bool {
+        let resume_from = self.intervals.get(&event.instrument).and_then(|intervals| {
+            intervals.iter().find_map(|(interval, resume_from)| {
+                (interval.close_time(event.kind.start_time) == event.kind.close_time)
+                    .then_some(*resume_from)
+            })
+        });
+
+        resume_from.map_or(true, |resume_from| event.kind.close_time > resume_from)
+    }
+}
+
+/// Number of [`Interval`] boundaries closing after `resume_from` up to & including `now`, used as
+/// the [`BackfillClient`](super::backfill::BackfillClient) limit required to fill the gap since
+/// a restart.
+///
+/// Calendar aware intervals (eg/ [`Interval::Month1`]) are approximated by their nominal
+/// duration, so an extra boundary is included to avoid under fetching.
+pub fn candles_since(interval: Interval, resume_from: DateTime<Utc>, now: DateTime<Utc>) -> usize {
+    let elapsed_ms = u64::try_from((now - resume_from).num_milliseconds()).unwrap_or_default();
+    let interval_ms = u64::try_from(interval.duration().num_milliseconds())
+        .unwrap_or_default()
+        .max(1);
+
+    usize::try_from(elapsed_ms.div_ceil(interval_ms))
+        .unwrap_or(usize::MAX)
+        .saturating_add(1)
+}
+
+/// Wrap the provided `exchange_tx` so each [`Subscription`] resumes after its `resume_from` time
+/// (eg/ the `close_time` of the last [`Candle`] ingested before a restart).
+///
+/// Every [`Candle`] with a `close_time` at or before `resume_from` is suppressed by a
+/// [`CandleResume`], including backfilled & gap filled [`Candle`]s. If the exchange has a
+/// [`BackfillClient`](super::backfill::BackfillClient), the [`Candle`]s that closed between
+/// `resume_from` and the first live [`Candle`] are fetched and sent first, in order.
+///
+/// Returns the [`EventSender`] the [`MarketStream`](crate::MarketStream) consumer loop should
+/// send events to.
+pub fn spawn_resume<Exchange>(
+    subscriptions: Vec<(Subscription<Exchange, Candles>, DateTime<Utc>)>,
+    exchange_tx: EventSender<MarketEvent<Candle>>,
+) -> EventSender<MarketEvent<Candle>>
+where
+    Exchange: StreamSelector<Candles> + Send + Sync + 'static,
+    Subscription<Exchange, Candles>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    if subscriptions.is_empty() {
+        return exchange_tx;
+    }
+
+    // Suppress Candles that were ingested before the restart
+    let (resume_tx, resume_rx) = exchange_tx.upstream();
+    tokio::spawn(resume(
+        CandleResume::new(subscriptions.iter().map(|(subscription, resume_from)| {
+            (
+                subscription.instrument.clone(),
+                subscription.kind.0,
+                *resume_from,
+            )
+        })),
+        resume_rx,
+        exchange_tx,
+    ));
+
+    // Backfill the Candles that closed during the restart gap
+    let now = Utc::now();
+    let limit = subscriptions
+        .iter()
+        .map(|(subscription, resume_from)| candles_since(subscription.kind.0, *resume_from, now))
+        .max()
+        .unwrap_or_default();
+
+    spawn_backfill(
+        subscriptions
+            .into_iter()
+            .map(|(subscription, _)| subscription)
+            .collect(),
+        limit,
+        resume_tx,
+    )
+}
+
+/// Forward the [`Candle`]s received via `event_rx` to the `exchange_tx`, suppressing those not
+/// admitted by the [`CandleResume`].
+pub async fn resume(
+    resume: CandleResume,
+    mut event_rx: EventReceiver<MarketEvent<Candle>>,
+    exchange_tx: EventSender<MarketEvent<Candle>>,
+) {
+    while let Some(event) = event_rx.recv().await {
+        if !resume.admit(&event) {
+            debug!(
+                instrument = %event.instrument,
+                close_time = %event.kind.close_time,
+                "suppressing Candle closed before resume_from",
+            );
+            continue;
+        }
+
+        if exchange_tx.send(event).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::ExchangeId,
+        subscription::{
+            candle::{CandleSource, VolumeDenomination},
+            number,
+        },
+    };
+    use barter_integration::model::{Exchange, InstrumentKind};
+    use chrono::TimeZone;
+    use tokio::sync::mpsc;
+
+    fn candle(base: &str, interval: Interval, start_ms: i64, closed: bool) -> MarketEvent<Candle> {
+        let start_time = Utc.timestamp_millis_opt(start_ms).unwrap();
+        let close_time = interval.close_time(start_time);
+        MarketEvent {
+            exchange_time: close_time,
+            received_time: close_time,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind: Candle {
+                start_time,
+                close_time,
+                open: number::from_f64(1.0),
+                high: number::from_f64(1.0),
+                low: number::from_f64(1.0),
+                close: number::from_f64(1.0),
+                volume: number::from_f64(1.0),
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: None,
+                quote_volume: None,
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 1,
+                closed,
+                source: CandleSource::LastTrade,
+                synthetic: false,
+                derived: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_candle_resume_admit() {
+        struct TestCase {
+            input: MarketEvent<Candle>,
+            expected: bool,
+        }
+
+        // Last ingested 1m btc candle closed at 00:02:59.999
+        let resume_from = Utc.timestamp_millis_opt(179_999).unwrap();
+        let resume = CandleResume::new([(
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            Interval::Minute1,
+            resume_from,
+        )]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: candle closing before resume_from is suppressed
+                input: candle("btc", Interval::Minute1, 60_000, true),
+                expected: false,
+            },
+            TestCase {
+                // TC1: candle closing exactly at resume_from is suppressed
+                input: candle("btc", Interval::Minute1, 120_000, true),
+                expected: false,
+            },
+            TestCase {
+                // TC2: in-progress candle closing after resume_from is forwarded
+                input: candle("btc", Interval::Minute1, 180_000, false),
+                expected: true,
+            },
+            TestCase {
+                // TC3: closed candle closing after resume_from is forwarded
+                input: candle("btc", Interval::Minute1, 180_000, true),
+                expected: true,
+            },
+            TestCase {
+                // TC4: candle of a different Interval series is forwarded
+                input: candle("btc", Interval::Minute3, 0, true),
+                expected: true,
+            },
+            TestCase {
+                // TC5: candle of a series without resume_from is forwarded
+                input: candle("eth", Interval::Minute1, 60_000, true),
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = resume.admit(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resume_suppresses_overlapping_candles() {
+        // Last ingested 1m btc candle closed at 00:02:59.999
+        let resume = CandleResume::new([(
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+            Interval::Minute1,
+            Utc.timestamp_millis_opt(179_999).unwrap(),
+        )]);
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        // Restarted stream overlaps with several already ingested candles
+        for start_ms in [0, 60_000, 120_000, 180_000, 240_000] {
+            event_tx
+                .send(candle("btc", Interval::Minute1, start_ms, true))
+                .unwrap();
+        }
+        event_tx
+            .send(candle("btc", Interval::Minute1, 300_000, false))
+            .unwrap();
+        drop(event_tx);
+
+        super::resume(resume, event_rx.into(), exchange_tx.into()).await;
+
+        let actual = std::iter::from_fn(|| exchange_rx.try_recv().ok())
+            .map(|event| (event.kind.close_time.timestamp_millis(), event.kind.closed))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![(239_999, true), (299_999, true), (359_999, false)]
+        );
+    }
+
+    #[test]
+    fn test_candles_since() {
+        struct TestCase {
+            interval: Interval,
+            resume_from_ms: i64,
+            now_ms: i64,
+            expected: usize,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: resume_from in the future requires only the in-progress boundary
+                interval: Interval::Minute1,
+                resume_from_ms: 120_000,
+                now_ms: 60_000,
+                expected: 1,
+            },
+            TestCase {
+                // TC1: partial interval since resume_from rounds up
+                interval: Interval::Minute1,
+                resume_from_ms: 179_999,
+                now_ms: 200_000,
+                expected: 2,
+            },
+            TestCase {
+                // TC2: several whole intervals since resume_from
+                interval: Interval::Minute5,
+                resume_from_ms: 0,
+                now_ms: 3_600_000,
+                expected: 13,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = candles_since(
+                test.interval,
+                Utc.timestamp_millis_opt(test.resume_from_ms).unwrap(),
+                Utc.timestamp_millis_opt(test.now_ms).unwrap(),
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{ExchangeId, StreamSelector},
+    streams::channel::{EventReceiver, EventSender},
+    subscription::{SubKind, Subscription},
+    Identifier,
+};
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+/// REST client that fetches the current state of a [`Subscription`] (eg/ the top of book of an
+/// [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) market), sent as the first
+/// [`MarketEvent`] of the [`Subscription`] before any live events.
+///
+/// Exchanges provide an implementation via [`StreamSelector::snapshot_client`].
+#[async_trait]
+pub trait SnapshotClient<Exchange, Kind>: Send + Sync
+where
+    Kind: SubKind,
+{
+    /// Fetch the current snapshot [`MarketEvent`] of the provided [`Subscription`], marked as a
+    /// snapshot (eg/ via [`TimeSource::Snapshot`](crate::event::TimeSource::Snapshot)).
+    async fn fetch(
+        &self,
+        subscription: &Subscription<Exchange, Kind>,
+    ) -> Result<MarketEvent<Kind::Event>, DataError>;
+}
+
+/// Wrap the provided `exchange_tx` so a snapshot of each [`Subscription`] is sent before any
+/// live events.
+///
+/// Returns the [`EventSender`] the [`MarketStream`](crate::MarketStream) consumer loop should
+/// send live events to. If the exchange has no [`SnapshotClient`] for the `Kind`, snapshots are
+/// skipped and the `exchange_tx` is returned unchanged.
+pub fn spawn_initial_snapshots<Exchange, Kind>(
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+) -> EventSender<MarketEvent<Kind::Event>>
+where
+    Exchange: StreamSelector<Kind> + Send + Sync + 'static,
+    Kind: SubKind + Send + Sync + 'static,
+    Kind::Event: Send,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let Some(client) = Exchange::snapshot_client() else {
+        info!(
+            exchange = %Exchange::ID,
+            action = "skipping initial snapshots",
+            "exchange has no REST snapshot client for the Subscription SubKind",
+        );
+        return exchange_tx;
+    };
+
+    let (live_tx, live_rx) = exchange_tx.upstream();
+    tokio::spawn(initial_snapshots(
+        Exchange::ID,
+        client,
+        subscriptions,
+        live_rx,
+        exchange_tx,
+    ));
+
+    live_tx
+}
+
+/// Send the snapshot of each [`Subscription`] via the `exchange_tx`, followed by the live events
+/// received via `live_rx`.
+///
+/// Snapshots are fetched immediately, concurrently with the connection being established, so
+/// quiet markets yield an event without waiting for the first live update. Live events received
+/// in the meantime are buffered, guaranteeing every snapshot is sent first. [`Subscription`]s
+/// with a failed snapshot request are skipped with a warning.
+pub async fn initial_snapshots<Exchange, Kind>(
+    exchange: ExchangeId,
+    client: Box<dyn SnapshotClient<Exchange, Kind>>,
+    subscriptions: Vec<Subscription<Exchange, Kind>>,
+    mut live_rx: EventReceiver<MarketEvent<Kind::Event>>,
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+) where
+    Exchange: Send + Sync,
+    Kind: SubKind + Send + Sync,
+    Kind::Event: Send,
+{
+    for subscription in &subscriptions {
+        match client.fetch(subscription).await {
+            Ok(snapshot) => {
+                if exchange_tx.send(snapshot).await.is_err() {
+                    return;
+                }
+            }
+            Err(error) => {
+                warn!(
+                    %exchange,
+                    instrument = %subscription.instrument,
+                    %error,
+                    action = "skipping initial snapshot",
+                    "failed to fetch initial snapshot MarketEvent",
+                );
+            }
+        }
+    }
+
+    // Forward live events
+    while let Some(live) = live_rx.recv().await {
+        if exchange_tx.send(live).await.is_err() {
+            break;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        event::TimeSource,
+        exchange::binance::spot::BinanceSpot,
+        subscription::{
+            book::{Level, OrderBookL1, OrderBooksL1},
+            number,
+        },
+    };
+    use barter_integration::{
+        error::SocketError,
+        model::{Exchange, Instrument, InstrumentKind},
+    };
+    use chrono::{DateTime, Utc};
+    use tokio::sync::mpsc;
+
+    /// [`SnapshotClient`] yielding a snapshot with a best bid of 1.0 for every [`Subscription`]
+    /// except the "fail/usdt" market.
+    struct MockSnapshotClient;
+
+    #[async_trait]
+    impl SnapshotClient<BinanceSpot, OrderBooksL1> for MockSnapshotClient {
+        async fn fetch(
+            &self,
+            subscription: &Subscription<BinanceSpot, OrderBooksL1>,
+        ) -> Result<MarketEvent<OrderBookL1>, DataError> {
+            if subscription.instrument.base.to_string() == "fail" {
+                return Err(DataError::Socket(SocketError::Subscribe(
+                    "mock snapshot failure".to_string(),
+                )));
+            }
+
+            Ok(book(&subscription.instrument, 1.0, TimeSource::Snapshot))
+        }
+    }
+
+    fn book(
+        instrument: &Instrument,
+        bid: f64,
+        time_source: TimeSource,
+    ) -> MarketEvent<OrderBookL1> {
+        let time = DateTime::<Utc>::MIN_UTC;
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument.clone(),
+            sequence: None,
+            tag: None,
+            kind: OrderBookL1 {
+                last_update_time: time,
+                best_bid: Level::new(number::from_f64(bid), number::from_f64(1.0)),
+                best_ask: Level::new(number::from_f64(bid + 1.0), number::from_f64(1.0)),
+                time_source,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_initial_snapshots_sent_before_live_events() {
+        let btc = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let eth = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        let subscriptions = [("btc", "usdt"), ("fail", "usdt"), ("eth", "usdt")]
+            .map(|(base, quote)| {
+                Subscription::from((
+                    BinanceSpot::default(),
+                    base,
+                    quote,
+                    InstrumentKind::Spot,
+                    OrderBooksL1,
+                ))
+            })
+            .to_vec();
+
+        let (live_tx, live_rx) = mpsc::unbounded_channel();
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+
+        // Live events are received before the snapshots are fetched
+        live_tx.send(book(&eth, 2.0, TimeSource::Exchange)).unwrap();
+        live_tx.send(book(&btc, 3.0, TimeSource::Exchange)).unwrap();
+        drop(live_tx);
+
+        initial_snapshots(
+            ExchangeId::BinanceSpot,
+            Box::new(MockSnapshotClient),
+            subscriptions,
+            live_rx.into(),
+            exchange_tx.into(),
+        )
+        .await;
+
+        // Snapshots are sent first in Subscription order, skipping the failed snapshot
+        let actual = std::iter::from_fn(|| exchange_rx.try_recv().ok())
+            .map(|event| {
+                (
+                    event.instrument.base.to_string(),
+                    event.kind.best_bid.price,
+                    event.kind.time_source,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![
+                (
+                    "btc".to_string(),
+                    number::from_f64(1.0),
+                    TimeSource::Snapshot
+                ),
+                (
+                    "eth".to_string(),
+                    number::from_f64(1.0),
+                    TimeSource::Snapshot
+                ),
+                (
+                    "eth".to_string(),
+                    number::from_f64(2.0),
+                    TimeSource::Exchange
+                ),
+                (
+                    "btc".to_string(),
+                    number::from_f64(3.0),
+                    TimeSource::Exchange
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeId},
+    subscription::Subscription,
+    Identifier,
+};
+use barter_integration::model::{Instrument, SubscriptionId};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicI64, AtomicU32, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// Snapshot of the counters of a single [`Subscription`], see
+/// [`Streams::stats`](super::Streams::stats).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct SubscriptionStats {
+    /// Number of [`MarketEvent`]s received from the exchange.
+    pub events_received: u64,
+    /// Time the most recent [`MarketEvent`] was received, if any.
+    pub last_event_time: Option<DateTime<Utc>>,
+    /// Number of [`DataError`]s yielded by the connection of the [`Subscription`].
+    pub errors: u64,
+    /// Number of times the connection of the [`Subscription`] has re-connected.
+    pub reconnects: u32,
+    /// Number of [`MarketEvent`]s received with an `exchange_time` before an already emitted
+    /// [`Watermark`](super::watermark::Watermark) of the connection, if watermarks are enabled.
+    pub late_events: u64,
+    /// Number of [`MarketEvent`]s received from the exchange, but dropped by the
+    /// [`EventFilter`](super::filter::EventFilter) of the connection.
+    pub filtered_events: u64,
+}
+
+/// Counters shared by every [`Subscription`] of a single connection.
+#[derive(Debug, Default)]
+struct ConnectionCounters {
+    errors: AtomicU64,
+    reconnects: AtomicU32,
+}
+
+/// Counters of a single [`Subscription`].
+#[derive(Debug)]
+struct SubscriptionCounters {
+    events_received: AtomicU64,
+    late_events: AtomicU64,
+    filtered_events: AtomicU64,
+    /// Epoch milliseconds of the most recent event, or [`i64::MIN`] if none has been received.
+    last_event_time: AtomicI64,
+    connection: Arc<ConnectionCounters>,
+}
+
+impl SubscriptionCounters {
+    fn new(connection: Arc<ConnectionCounters>) -> Self {
+        Self {
+            events_received: AtomicU64::new(0),
+            late_events: AtomicU64::new(0),
+            filtered_events: AtomicU64::new(0),
+            last_event_time: AtomicI64::new(i64::MIN),
+            connection,
+        }
+    }
+
+    fn snapshot(&self) -> SubscriptionStats {
+        let last_event_time = match self.last_event_time.load(Ordering::Relaxed) {
+            i64::MIN => None,
+            epoch_ms => Utc.timestamp_millis_opt(epoch_ms).single(),
+        };
+
+        SubscriptionStats {
+            events_received: self.events_received.load(Ordering::Relaxed),
+            last_event_time,
+            errors: self.connection.errors.load(Ordering::Relaxed),
+            reconnects: self.connection.reconnects.load(Ordering::Relaxed),
+            late_events: self.late_events.load(Ordering::Relaxed),
+            filtered_events: self.filtered_events.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Registry of the [`SubscriptionStats`] counters of every connection initialised by a
+/// [`StreamBuilder`](super::builder::StreamBuilder), shared with the [`Streams`](super::Streams)
+/// so counters remain available after [`Streams::select`](super::Streams::select) or
+/// [`Streams::join`](super::Streams::join).
+///
+/// The registry is only locked when [`Subscription`]s are registered or deregistered, or a
+/// snapshot is taken. Each consumer loop updates its [`ConnectionStats`] atomics directly.
+#[derive(Clone, Debug, Default)]
+pub struct StatsRegistry(Arc<Mutex<Registered>>);
+
+#[derive(Debug, Default)]
+struct Registered {
+    subscriptions: HashMap<ExchangeId, HashMap<SubscriptionId, Arc<SubscriptionCounters>>>,
+    linked: Vec<StatsRegistry>,
+}
+
+impl StatsRegistry {
+    /// Register the [`Subscription`]s of a single connection, returning the [`ConnectionStats`]
+    /// its consumer loop updates.
+    pub fn register<Exchange, Kind>(
+        &self,
+        subscriptions: &[Subscription<Exchange, Kind>],
+    ) -> ConnectionStats
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let mut stats = ConnectionStats {
+            registry: self.clone(),
+            exchange: Exchange::ID,
+            connection: Arc::new(ConnectionCounters::default()),
+            instruments: HashMap::new(),
+        };
+
+        for subscription in subscriptions {
+            stats.register(subscription);
+        }
+
+        stats
+    }
+
+    /// Include every [`Subscription`] registered with the `other` [`StatsRegistry`] in the
+    /// snapshots of this registry (eg/ the registry of each
+    /// [`StreamBuilder`](super::builder::StreamBuilder) added to a
+    /// [`MultiStreamBuilder`](super::builder::multi::MultiStreamBuilder)).
+    pub fn link(&self, other: StatsRegistry) {
+        self.lock().linked.push(other);
+    }
+
+    /// Snapshot of the [`SubscriptionStats`] of every registered [`Subscription`].
+    pub fn snapshot(&self) -> HashMap<ExchangeId, HashMap<SubscriptionId, SubscriptionStats>> {
+        let registry = self.lock();
+
+        let mut snapshot = registry
+            .subscriptions
+            .iter()
+            .map(|(exchange, subscriptions)| {
+                let subscriptions = subscriptions
+                    .iter()
+                    .map(|(id, counters)| (id.clone(), counters.snapshot()))
+                    .collect();
+                (*exchange, subscriptions)
+            })
+            .collect::<HashMap<_, HashMap<_, _>>>();
+
+        for linked in &registry.linked {
+            for (exchange, subscriptions) in linked.snapshot() {
+                snapshot.entry(exchange).or_default().extend(subscriptions);
+            }
+        }
+
+        snapshot
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Registered> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Lock-free [`SubscriptionStats`] counters of a single connection, updated by its consumer loop
+/// for every transformed [`MarketEvent`] & [`DataError`].
+///
+/// ### Notes
+/// Events are attributed to a [`Subscription`] via their [`Instrument`], so [`Subscription`]s of
+/// the same connection that share an [`Instrument`] (eg/ multiple candle intervals) share their
+/// event counters.
+#[derive(Debug)]
+pub struct ConnectionStats {
+    registry: StatsRegistry,
+    exchange: ExchangeId,
+    connection: Arc<ConnectionCounters>,
+    instruments: HashMap<Instrument, Arc<SubscriptionCounters>>,
+}
+
+impl ConnectionStats {
+    /// Register a [`Subscription`] added to the connection (eg/ via
+    /// [`StreamController::subscribe`](super::controller::StreamController::subscribe)), so its
+    /// events are counted & it is included in the [`StatsRegistry`] snapshots.
+    pub fn register<Exchange, Kind>(&mut self, subscription: &Subscription<Exchange, Kind>)
+    where
+        Exchange: Connector,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let counters = self
+            .instruments
+            .entry(subscription.instrument.clone())
+            .or_insert_with(|| Arc::new(SubscriptionCounters::new(Arc::clone(&self.connection))));
+
+        self.registry
+            .lock()
+            .subscriptions
+            .entry(self.exchange)
+            .or_default()
+            .insert(subscription.subscription_id(), Arc::clone(counters));
+    }
+
+    /// Deregister the `removed` [`Subscription`]s of the connection (eg/ via
+    /// [`StreamController::unsubscribe`](super::controller::StreamController::unsubscribe)), so
+    /// they are no longer included in the [`StatsRegistry`] snapshots.
+    ///
+    /// The counters of an [`Instrument`] are retained whilst any of the `remaining`
+    /// [`Subscription`]s of the connection share it.
+    pub fn deregister<Exchange, Kind>(
+        &mut self,
+        removed: &[Subscription<Exchange, Kind>],
+        remaining: &[Subscription<Exchange, Kind>],
+    ) where
+        Exchange: Connector,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        let mut registry = self.registry.lock();
+        let registered = registry.subscriptions.entry(self.exchange).or_default();
+
+        for subscription in removed {
+            let Some(counters) = self.instruments.get(&subscription.instrument) else {
+                continue;
+            };
+
+            // Only remove the SubscriptionId if it is registered by this connection
+            let subscription_id = subscription.subscription_id();
+            if registered
+                .get(&subscription_id)
+                .is_some_and(|registered| Arc::ptr_eq(registered, counters))
+            {
+                registered.remove(&subscription_id);
+            }
+
+            if !remaining
+                .iter()
+                .any(|remaining| remaining.instrument == subscription.instrument)
+            {
+                self.instruments.remove(&subscription.instrument);
+            }
+        }
+    }
+
+    /// Record the outcome of transforming a message received from the exchange.
+    pub fn record<T>(&self, result: &Result<MarketEvent<T>, DataError>) {
+        match result {
+            Ok(event) => {
+                if let Some(counters) = self.instruments.get(&event.instrument) {
+                    counters.events_received.fetch_add(1, Ordering::Relaxed);
+                    counters
+                        .last_event_time
+                        .fetch_max(event.received_time.timestamp_millis(), Ordering::Relaxed);
+                }
+            }
+            Err(_) => {
+                self.connection.errors.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Record a [`MarketEvent`] received with an `exchange_time` before an already emitted
+    /// [`Watermark`](super::watermark::Watermark) of the connection.
+    pub fn record_late<T>(&self, event: &MarketEvent<T>) {
+        if let Some(counters) = self.instruments.get(&event.instrument) {
+            counters.late_events.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record a [`MarketEvent`] dropped by the [`EventFilter`](super::filter::EventFilter) of the
+    /// connection.
+    pub fn record_filtered<T>(&self, event: &MarketEvent<T>) {
+        if let Some(counters) = self.instruments.get(&event.instrument) {
+            counters.filtered_events.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record a successful re-connection.
+    pub fn record_reconnect(&self) {
+        self.connection.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::spot::BinanceSpot,
+        subscription::{
+            candle::Candles,
+            number::Number,
+            trade::{PublicTrade, PublicTrades},
+            Interval,
+        },
+    };
+    use barter_integration::{
+        error::SocketError,
+        model::{Exchange, InstrumentKind, Side},
+    };
+
+    fn trade(base: &str, received_ms: i64) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: Utc.timestamp_millis_opt(received_ms).unwrap(),
+            received_time: Utc.timestamp_millis_opt(received_ms).unwrap(),
+            exchange: Exchange::from("binance_spot"),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind: PublicTrade {
+                id: received_ms.to_string(),
+                price: Number::from(1),
+                amount: Number::from(1),
+                side: Side::Buy,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats_registry_counts_transformer_output() {
+        let subscriptions = ["btc", "eth"].map(|base| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                PublicTrades,
+            ))
+        });
+        let registry = StatsRegistry::default();
+        let stats = registry.register(&subscriptions);
+
+        // Registered Subscriptions are present before any events are received
+        assert_eq!(
+            registry.snapshot()[&ExchangeId::BinanceSpot][&SubscriptionId::from("@trade|BTCUSDT")],
+            SubscriptionStats::default()
+        );
+
+        // Transformer output of 100 events, 2 errors & a re-connection
+        for index in 0..100 {
+            let base = if index % 4 == 0 { "eth" } else { "btc" };
+            stats.record(&Ok(trade(base, 1_000 + index)));
+        }
+        for _ in 0..2 {
+            stats.record::<PublicTrade>(&Err(DataError::Socket(SocketError::Unsupported {
+                entity: "",
+                item: String::new(),
+            })));
+        }
+        stats.record_reconnect();
+        stats.record_late(&trade("eth", 1_000));
+
+        struct TestCase {
+            input: SubscriptionId,
+            expected: SubscriptionStats,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: btc Subscription counts its 75 events
+                input: SubscriptionId::from("@trade|BTCUSDT"),
+                expected: SubscriptionStats {
+                    events_received: 75,
+                    last_event_time: Utc.timestamp_millis_opt(1_099).single(),
+                    errors: 2,
+                    reconnects: 1,
+                    late_events: 0,
+                    filtered_events: 0,
+                },
+            },
+            TestCase {
+                // TC1: eth Subscription counts its 25 events & late event
+                input: SubscriptionId::from("@trade|ETHUSDT"),
+                expected: SubscriptionStats {
+                    events_received: 25,
+                    last_event_time: Utc.timestamp_millis_opt(1_096).single(),
+                    errors: 2,
+                    reconnects: 1,
+                    late_events: 1,
+                    filtered_events: 0,
+                },
+            },
+        ];
+
+        let snapshot = registry.snapshot();
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = snapshot[&ExchangeId::BinanceSpot][&test.input];
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_connection_stats_register_and_deregister() {
+        let candles = |base, interval| {
+            Subscription::from((
+                BinanceSpot::default(),
+                base,
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(interval),
+            ))
+        };
+        let btc_1m = candles("btc", Interval::Minute1);
+        let btc_1h = candles("btc", Interval::Hour1);
+        let eth_1m = candles("eth", Interval::Minute1);
+
+        let registry = StatsRegistry::default();
+        let mut stats = registry.register(std::slice::from_ref(&btc_1m));
+        let registered = || {
+            let mut registered = registry.snapshot()[&ExchangeId::BinanceSpot]
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>();
+            registered.sort();
+            registered
+        };
+
+        // Subscriptions added to the live connection are registered & counted
+        stats.register(&btc_1h);
+        stats.register(&eth_1m);
+        assert_eq!(
+            registered(),
+            vec![
+                SubscriptionId::from("@kline_1h|BTCUSDT"),
+                SubscriptionId::from("@kline_1m|BTCUSDT"),
+                SubscriptionId::from("@kline_1m|ETHUSDT"),
+            ]
+        );
+        stats.record(&Ok(trade("eth", 1_000)));
+        assert_eq!(
+            registry.snapshot()[&ExchangeId::BinanceSpot]
+                [&SubscriptionId::from("@kline_1m|ETHUSDT")]
+                .events_received,
+            1
+        );
+
+        // Removed Subscriptions are deregistered & no longer counted
+        stats.deregister(
+            std::slice::from_ref(&eth_1m),
+            &[btc_1m.clone(), btc_1h.clone()],
+        );
+        assert_eq!(
+            registered(),
+            vec![
+                SubscriptionId::from("@kline_1h|BTCUSDT"),
+                SubscriptionId::from("@kline_1m|BTCUSDT"),
+            ]
+        );
+        stats.record(&Ok(trade("eth", 2_000)));
+        assert!(!stats.instruments.contains_key(&eth_1m.instrument));
+
+        // Counters of an Instrument are retained whilst another Subscription shares it
+        stats.record(&Ok(trade("btc", 3_000)));
+        stats.deregister(std::slice::from_ref(&btc_1m), std::slice::from_ref(&btc_1h));
+        assert_eq!(
+            registered(),
+            vec![SubscriptionId::from("@kline_1h|BTCUSDT")]
+        );
+        assert_eq!(
+            registry.snapshot()[&ExchangeId::BinanceSpot]
+                [&SubscriptionId::from("@kline_1h|BTCUSDT")]
+                .events_received,
+            1
+        );
+
+        // Subscriptions registered by another connection are not deregistered
+        let other = registry.register(std::slice::from_ref(&eth_1m));
+        stats.deregister(std::slice::from_ref(&eth_1m), std::slice::from_ref(&btc_1h));
+        assert_eq!(
+            registered(),
+            vec![
+                SubscriptionId::from("@kline_1h|BTCUSDT"),
+                SubscriptionId::from("@kline_1m|ETHUSDT"),
+            ]
+        );
+        drop(other);
+    }
+}
@@ -0,0 +1,317 @@
+use super::channel::{EventReceiver, EventSender};
+use crate::{
+    event::MarketEvent,
+    subscription::{SubKind, Subscription},
+};
+use barter_integration::model::Instrument;
+
+/// Tags of the [`Subscription`]s of a connection, attributing each [`MarketEvent`] to the tagged
+/// [`Subscription`]s it belongs to.
+///
+/// ### Notes
+/// - Events matching several [`Subscription`]s with distinct tags are yielded once per tag.
+/// - Events matching only untagged [`Subscription`]s (or none at all) pass through unchanged.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SubscriptionTags<Kind> {
+    subscriptions: Vec<(Instrument, Kind, Option<String>)>,
+}
+
+impl<Kind> SubscriptionTags<Kind>
+where
+    Kind: SubKind,
+{
+    /// Construct a new [`SubscriptionTags`] from the provided [`Subscription`]s.
+    pub fn new<Exchange>(subscriptions: &[Subscription<Exchange, Kind>]) -> Self {
+        Self {
+            subscriptions: subscriptions
+                .iter()
+                .map(|sub| (sub.instrument.clone(), sub.kind.clone(), sub.tag.clone()))
+                .collect(),
+        }
+    }
+
+    /// Determine if any of the [`Subscription`]s are tagged.
+    pub fn is_tagged(&self) -> bool {
+        self.subscriptions.iter().any(|(_, _, tag)| tag.is_some())
+    }
+
+    /// Tag the provided [`MarketEvent`], returning one event per distinct tag of the
+    /// [`Subscription`]s it matches.
+    pub fn tag(&self, event: MarketEvent<Kind::Event>) -> Vec<MarketEvent<Kind::Event>> {
+        let mut tags = self
+            .subscriptions
+            .iter()
+            .filter(|(instrument, kind, _)| {
+                *instrument == event.instrument && kind.matches(&event.kind)
+            })
+            .map(|(_, _, tag)| tag.clone())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+
+        if tags.iter().all(Option::is_none) {
+            return vec![event];
+        }
+
+        tags.into_iter()
+            .map(|tag| MarketEvent {
+                tag,
+                ..event.clone()
+            })
+            .collect()
+    }
+}
+
+/// Wrap the provided `exchange_tx` so each [`MarketEvent`] carries the tag of the
+/// [`Subscription`] that yielded it. See [`SubscriptionTags`].
+///
+/// Returns the `exchange_tx` unchanged if none of the [`Subscription`]s are tagged, otherwise
+/// the [`EventSender`] the [`MarketStream`](crate::MarketStream) consumer loop should send events
+/// to.
+pub fn spawn_tagging<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+) -> EventSender<MarketEvent<Kind::Event>>
+where
+    Kind: SubKind + Send + 'static,
+    Kind::Event: Send,
+{
+    let tags = SubscriptionTags::new(subscriptions);
+    if !tags.is_tagged() {
+        return exchange_tx;
+    }
+
+    let (tag_tx, tag_rx) = exchange_tx.upstream();
+    tokio::spawn(tag(tags, tag_rx, exchange_tx));
+    tag_tx
+}
+
+/// Forward the [`MarketEvent`]s received via `event_rx` to the `exchange_tx`, tagged according
+/// to the [`SubscriptionTags`].
+pub async fn tag<Kind>(
+    tags: SubscriptionTags<Kind>,
+    mut event_rx: EventReceiver<MarketEvent<Kind::Event>>,
+    exchange_tx: EventSender<MarketEvent<Kind::Event>>,
+) where
+    Kind: SubKind,
+{
+    while let Some(event) = event_rx.recv().await {
+        for event in tags.tag(event) {
+            if exchange_tx.send(event).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, ExchangeId},
+        subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+        subscription::{
+            candle::{Candle, CandleSource, Candles, VolumeDenomination},
+            number,
+            trade::{PublicTrade, PublicTrades},
+            Interval,
+        },
+    };
+    use barter_integration::model::{Exchange, InstrumentKind, Side};
+    use chrono::{TimeZone, Utc};
+    use tokio::sync::mpsc;
+
+    fn event<T>(base: &str, kind: T) -> MarketEvent<T> {
+        let time = Utc.timestamp_millis_opt(0).unwrap();
+        MarketEvent {
+            exchange_time: time,
+            received_time: time,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: Instrument::from((base, "usdt", InstrumentKind::Spot)),
+            sequence: None,
+            tag: None,
+            kind,
+        }
+    }
+
+    fn trade(base: &str) -> MarketEvent<PublicTrade> {
+        event(
+            base,
+            PublicTrade {
+                id: "1".to_string(),
+                price: number::from_f64(1.0),
+                amount: number::from_f64(1.0),
+                side: Side::Buy,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        )
+    }
+
+    fn candle(interval: Interval) -> MarketEvent<Candle> {
+        let start_time = Utc.timestamp_millis_opt(0).unwrap();
+        event(
+            "btc",
+            Candle {
+                start_time,
+                close_time: interval.close_time(start_time),
+                open: number::from_f64(1.0),
+                high: number::from_f64(1.0),
+                low: number::from_f64(1.0),
+                close: number::from_f64(1.0),
+                volume: number::from_f64(1.0),
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: None,
+                quote_volume: None,
+                taker_buy_volume: None,
+                taker_buy_quote_volume: None,
+                trade_count: 1,
+                closed: true,
+                source: CandleSource::LastTrade,
+                synthetic: false,
+                derived: false,
+            },
+        )
+    }
+
+    fn trades(base: &str) -> Subscription<BinanceSpot, PublicTrades> {
+        Subscription::from((
+            BinanceSpot::default(),
+            base,
+            "usdt",
+            InstrumentKind::Spot,
+            PublicTrades,
+        ))
+    }
+
+    fn candles(interval: Interval) -> Subscription<BinanceSpot, Candles> {
+        Subscription::from((
+            BinanceSpot::default(),
+            "btc",
+            "usdt",
+            InstrumentKind::Spot,
+            Candles(interval),
+        ))
+    }
+
+    #[test]
+    fn test_subscription_tags_trades() {
+        struct TestCase {
+            subscriptions: Vec<Subscription<BinanceSpot, PublicTrades>>,
+            input: MarketEvent<PublicTrade>,
+            expected: Vec<Option<&'static str>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: untagged Subscriptions pass events through unchanged
+                subscriptions: vec![trades("btc")],
+                input: trade("btc"),
+                expected: vec![None],
+            },
+            TestCase {
+                // TC1: tagged Subscription tags its events
+                subscriptions: vec![trades("btc").with_tag("a")],
+                input: trade("btc"),
+                expected: vec![Some("a")],
+            },
+            TestCase {
+                // TC2: same Instrument & SubKind with two tags yields two distinct events
+                subscriptions: vec![trades("btc").with_tag("a"), trades("btc").with_tag("b")],
+                input: trade("btc"),
+                expected: vec![Some("a"), Some("b")],
+            },
+            TestCase {
+                // TC3: tagged & untagged Subscriptions yield one event each
+                subscriptions: vec![trades("btc"), trades("btc").with_tag("a")],
+                input: trade("btc"),
+                expected: vec![None, Some("a")],
+            },
+            TestCase {
+                // TC4: events of an untagged Instrument pass through unchanged
+                subscriptions: vec![trades("btc").with_tag("a"), trades("eth")],
+                input: trade("eth"),
+                expected: vec![None],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = SubscriptionTags::new(&test.subscriptions)
+                .tag(test.input)
+                .into_iter()
+                .map(|event| event.tag)
+                .collect::<Vec<_>>();
+            let expected = test
+                .expected
+                .into_iter()
+                .map(|tag| tag.map(String::from))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_subscription_tags_candles_by_interval() {
+        let tags = SubscriptionTags::new(&[
+            candles(Interval::Minute1).with_tag("fast"),
+            candles(Interval::Minute5).with_tag("slow"),
+        ]);
+
+        struct TestCase {
+            input: MarketEvent<Candle>,
+            expected: Vec<Option<String>>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 1m Candle is attributed to the 1m Subscription only
+                input: candle(Interval::Minute1),
+                expected: vec![Some("fast".to_string())],
+            },
+            TestCase {
+                // TC1: 5m Candle is attributed to the 5m Subscription only
+                input: candle(Interval::Minute5),
+                expected: vec![Some("slow".to_string())],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = tags
+                .tag(test.input)
+                .into_iter()
+                .map(|event| event.tag)
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tagged_subscriptions_share_exchange_subscription() {
+        // Deduplicated as by the StreamBuilder, retaining Subscriptions with distinct tags
+        let mut subscriptions = vec![
+            trades("btc").with_tag("a"),
+            trades("btc").with_tag("b"),
+            trades("btc").with_tag("a"),
+        ];
+        subscriptions.sort();
+        subscriptions.dedup();
+        assert_eq!(subscriptions.len(), 2);
+
+        // Both tagged Subscriptions share a single exchange subscription
+        let meta = WebSocketSubMapper::map(&subscriptions);
+        assert_eq!(meta.subscriptions.len(), 1);
+
+        // Each exchange event is yielded once per tag
+        let (exchange_tx, mut exchange_rx) = mpsc::unbounded_channel();
+        let tag_tx = spawn_tagging(&subscriptions, exchange_tx.into());
+        tag_tx.send(trade("btc")).await.unwrap();
+        drop(tag_tx);
+
+        let mut actual = Vec::new();
+        while let Some(event) = exchange_rx.recv().await {
+            actual.push(event.tag);
+        }
+        assert_eq!(actual, vec![Some("a".to_string()), Some("b".to_string())]);
+    }
+}
@@ -0,0 +1,308 @@
+use crate::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tokio::{sync::mpsc, time::Instant};
+
+/// Default interval between the [`Watermark`]s emitted by each connection, see
+/// [`WatermarkPolicy`].
+pub const DEFAULT_WATERMARK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default [`WatermarkPolicy`] `idle_skew` subtracted from the local time a message was last
+/// received when advancing the [`Watermark`] of an idle connection.
+pub const DEFAULT_WATERMARK_IDLE_SKEW: Duration = Duration::from_secs(1);
+
+/// Event-time progress of a single connection of an exchange: every subsequent
+/// [`MarketEvent`](crate::event::MarketEvent) of the connection is expected to have an
+/// `exchange_time` at or after the watermark `time`.
+///
+/// Useful for closing event-time windows (eg/ custom bars) across every [`Subscription`] of a
+/// connection. Events that arrive with an `exchange_time` before an emitted watermark are
+/// counted as [`SubscriptionStats::late_events`](super::stats::SubscriptionStats::late_events).
+///
+/// ### Notes
+/// Each connection emits its own watermarks, so an exchange whose [`Subscription`]s are batched
+/// across multiple connections (see [`BatchPolicy`](super::builder::BatchPolicy)) should be
+/// considered complete up to the minimum watermark of its connections.
+///
+/// [`Subscription`]: crate::subscription::Subscription
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Watermark {
+    pub exchange: ExchangeId,
+    pub time: DateTime<Utc>,
+}
+
+/// Configures the [`Watermark`]s emitted by each connection, see
+/// [`StreamBuilder::with_watermarks`](super::builder::StreamBuilder::with_watermarks).
+///
+/// Defaults to an `interval` of [`DEFAULT_WATERMARK_INTERVAL`] and an `idle_skew` of
+/// [`DEFAULT_WATERMARK_IDLE_SKEW`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct WatermarkPolicy {
+    /// Interval between each [`Watermark`] emitted by a connection. A [`Watermark`] is only
+    /// emitted if it has advanced since the previous one.
+    pub interval: Duration,
+    /// Duration subtracted from the local time a message (eg/ ping) was last received when
+    /// advancing the [`Watermark`] of a connection without any events for an `interval`,
+    /// tolerating clock skew between the exchange server & local clocks.
+    pub idle_skew: Duration,
+}
+
+impl Default for WatermarkPolicy {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_WATERMARK_INTERVAL,
+            idle_skew: DEFAULT_WATERMARK_IDLE_SKEW,
+        }
+    }
+}
+
+/// [`WatermarkPolicy`] & sender used by the [`consume`](super::consumer::consume) loop of each
+/// connection to emit its [`Watermark`]s.
+#[derive(Clone, Debug)]
+pub struct WatermarkEmitter {
+    pub policy: WatermarkPolicy,
+    pub tx: mpsc::UnboundedSender<Watermark>,
+}
+
+/// Tracks the maximum `exchange_time` consumed from a single connection, periodically emitting it
+/// as a monotonically advancing [`Watermark`].
+///
+/// If the connection has not yielded any events for a [`WatermarkPolicy`] `interval`, but has
+/// since received other messages (eg/ pings & heartbeats), the [`Watermark`] advances to the
+/// local time the last message was received less the [`WatermarkPolicy`] `idle_skew`, so a quiet
+/// market does not stall downstream windows forever.
+///
+/// State is retained across re-connections, so [`Watermark`]s never move backwards.
+#[derive(Debug)]
+pub struct WatermarkTracker {
+    exchange: ExchangeId,
+    emitter: WatermarkEmitter,
+    max_exchange_time: Option<DateTime<Utc>>,
+    last_event: Option<Instant>,
+    emitted: Option<DateTime<Utc>>,
+    next_tick: Instant,
+}
+
+impl WatermarkTracker {
+    /// Construct a new [`Self`] for a connection of the provided exchange, first ticking after
+    /// the [`WatermarkPolicy`] `interval`.
+    pub fn new(exchange: ExchangeId, emitter: WatermarkEmitter) -> Self {
+        let next_tick = Instant::now() + emitter.policy.interval;
+        Self {
+            exchange,
+            emitter,
+            max_exchange_time: None,
+            last_event: None,
+            emitted: None,
+            next_tick,
+        }
+    }
+
+    /// [`Instant`] the next [`Self::tick`] is due.
+    pub fn deadline(&self) -> Instant {
+        self.next_tick
+    }
+
+    /// Most recently emitted [`Watermark`] time, if any.
+    pub fn emitted(&self) -> Option<DateTime<Utc>> {
+        self.emitted
+    }
+
+    /// Observe the `exchange_time` of an event consumed at the provided [`Instant`], returning
+    /// `true` if it is late (ie/ before an already emitted [`Watermark`]).
+    pub fn observe(&mut self, exchange_time: DateTime<Utc>, now: Instant) -> bool {
+        self.last_event = Some(now);
+        self.max_exchange_time = self.max_exchange_time.max(Some(exchange_time));
+        self.emitted.is_some_and(|emitted| exchange_time < emitted)
+    }
+
+    /// Emit the advanced [`Watermark`] (if any) via the [`WatermarkEmitter`], given the
+    /// [`Instant`] the connection last received a message, and schedule the next tick.
+    pub fn tick(&mut self, last_message: Instant) {
+        let now = Instant::now();
+        self.next_tick = now + self.emitter.policy.interval;
+
+        if let Some(watermark) = self.advance(last_message, now, Utc::now()) {
+            let _ = self.emitter.tx.send(watermark);
+        }
+    }
+
+    /// Advance the [`Watermark`] given the [`Instant`] the connection last received a message,
+    /// and the current [`Instant`] & [`DateTime<Utc>`]. Returns the [`Watermark`] to emit if it
+    /// has advanced past the previously emitted [`Watermark`].
+    pub fn advance(
+        &mut self,
+        last_message: Instant,
+        now: Instant,
+        now_utc: DateTime<Utc>,
+    ) -> Option<Watermark> {
+        // Idle connections that are still receiving messages advance with the local clock
+        let idle = self.last_event.map_or(true, |last_event| {
+            last_message > last_event
+                && now.saturating_duration_since(last_event) >= self.emitter.policy.interval
+        });
+        let idle_time = idle
+            .then(|| now.saturating_duration_since(last_message) + self.emitter.policy.idle_skew)
+            .and_then(|lag| chrono::Duration::from_std(lag).ok())
+            .and_then(|lag| now_utc.checked_sub_signed(lag));
+
+        let candidate = self.max_exchange_time.max(idle_time)?;
+        if self.emitted.is_some_and(|emitted| candidate <= emitted) {
+            return None;
+        }
+
+        self.emitted = Some(candidate);
+        Some(Watermark {
+            exchange: self.exchange,
+            time: candidate,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::streams::health::Heartbeat;
+    use chrono::TimeZone;
+
+    fn tracker(policy: WatermarkPolicy) -> (WatermarkTracker, mpsc::UnboundedReceiver<Watermark>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let tracker =
+            WatermarkTracker::new(ExchangeId::BinanceSpot, WatermarkEmitter { policy, tx });
+        (tracker, rx)
+    }
+
+    fn time(epoch_ms: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(epoch_ms).unwrap()
+    }
+
+    fn watermark(epoch_ms: i64) -> Watermark {
+        Watermark {
+            exchange: ExchangeId::BinanceSpot,
+            time: time(epoch_ms),
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watermark_tracker_advances_monotonically() {
+        let (mut tracker, _rx) = tracker(WatermarkPolicy::default());
+        let heartbeat = Heartbeat::default();
+        let now_utc = time(1_000_000);
+
+        // Events consumed out of order advance to the maximum exchange_time
+        for exchange_time in [999_500, 999_800, 999_600] {
+            heartbeat.beat();
+            assert!(!tracker.observe(time(exchange_time), Instant::now()));
+        }
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), now_utc),
+            Some(watermark(999_800))
+        );
+
+        // Watermark is not re-emitted until it advances
+        tokio::time::advance(Duration::from_millis(500)).await;
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), now_utc),
+            None
+        );
+
+        // Events before the emitted Watermark are late, and do not move it backwards
+        heartbeat.beat();
+        assert!(tracker.observe(time(999_700), Instant::now()));
+        assert!(!tracker.observe(time(999_800), Instant::now()));
+        assert!(!tracker.observe(time(999_900), Instant::now()));
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), now_utc),
+            Some(watermark(999_900))
+        );
+        assert_eq!(tracker.emitted(), Some(time(999_900)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watermark_tracker_advances_idle_connection_with_heartbeats() {
+        let policy = WatermarkPolicy {
+            interval: Duration::from_secs(1),
+            idle_skew: Duration::from_millis(200),
+        };
+        let (mut tracker, _rx) = tracker(policy);
+        let heartbeat = Heartbeat::default();
+        let start = Instant::now();
+        let start_utc = time(1_000_000);
+        let utc_at = |instant: Instant| {
+            start_utc + chrono::Duration::from_std(instant.duration_since(start)).unwrap()
+        };
+
+        heartbeat.beat();
+        tracker.observe(time(999_900), Instant::now());
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), utc_at(Instant::now())),
+            Some(watermark(999_900))
+        );
+
+        // Quiet market without any messages does not advance, since the connection may be dead
+        tokio::time::advance(Duration::from_secs(5)).await;
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), utc_at(Instant::now())),
+            None
+        );
+
+        // Ping received 5.5s after the last event advances to its receipt time less idle_skew
+        tokio::time::advance(Duration::from_millis(500)).await;
+        heartbeat.beat();
+        tokio::time::advance(Duration::from_millis(300)).await;
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), utc_at(Instant::now())),
+            Some(watermark(1_000_000 + 5_500 - 200))
+        );
+
+        // Late event received within the interval of the ping stops the idle advance
+        heartbeat.beat();
+        assert!(tracker.observe(time(1_005_000), Instant::now()));
+        tokio::time::advance(Duration::from_millis(500)).await;
+        heartbeat.beat();
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), utc_at(Instant::now())),
+            None
+        );
+
+        // Once idle for an interval, subsequent pings advance the Watermark again
+        tokio::time::advance(Duration::from_millis(600)).await;
+        heartbeat.beat();
+        assert_eq!(
+            tracker.advance(heartbeat.last(), Instant::now(), utc_at(Instant::now())),
+            Some(watermark(1_000_000 + 6_900 - 200))
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_watermark_tracker_tick_emits_each_interval() {
+        let (mut tracker, mut rx) = tracker(WatermarkPolicy::default());
+        let heartbeat = Heartbeat::default();
+        let start = Instant::now();
+
+        // Ticks are due each interval, emitting the Watermark only if it has advanced
+        for (index, exchange_time) in [1_000_000, 1_001_000, 1_001_000].into_iter().enumerate() {
+            assert_eq!(
+                tracker.deadline(),
+                start + DEFAULT_WATERMARK_INTERVAL * (index as u32 + 1)
+            );
+
+            tokio::time::sleep_until(tracker.deadline() - Duration::from_millis(100)).await;
+            heartbeat.beat();
+            tracker.observe(time(exchange_time), Instant::now());
+
+            tokio::time::sleep_until(tracker.deadline()).await;
+            tracker.tick(heartbeat.last());
+            match index {
+                2 => assert!(rx.try_recv().is_err(), "TC{} failed", index),
+                _ => assert_eq!(
+                    rx.try_recv().unwrap(),
+                    watermark(exchange_time),
+                    "TC{} failed",
+                    index
+                ),
+            }
+        }
+    }
+}
@@ -5,7 +5,8 @@ use crate::{
 };
 use barter_integration::model::SubscriptionId;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use tracing::warn;
 
 /// Defines how to map a collection of Barter [`Subscription`]s into exchange specific
 /// [`SubscriptionMeta`], containing subscription payloads that are sent to the exchange.
@@ -19,6 +20,12 @@ pub trait SubscriptionMapper {
 
 /// Standard [`SubscriptionMapper`] for
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket)s suitable for most exchanges.
+///
+/// Duplicate [`Subscription`]s (ie/ those that translate into an identical exchange channel &
+/// market) are dropped, so exactly one subscription payload is sent per unique [`Subscription`].
+/// [`Subscription`]s for the same [`Instrument`](barter_integration::model::Instrument) with a
+/// different [`SubKind`] (eg/ `Candles(1m)` & `Candles(5m)`) are distinct, and are kept.
+/// [`Subscription`]s differing only by tag share a single exchange subscription.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct WebSocketSubMapper;
 
@@ -33,12 +40,34 @@ impl SubscriptionMapper for WebSocketSubMapper {
         // Allocate SubscriptionIds HashMap to track identifiers for each actioned Subscription
         let mut instrument_map = Map(HashMap::with_capacity(subscriptions.len()));
 
+        // Track the exchange specific channel & market of each actioned Subscription
+        let mut actioned = HashSet::with_capacity(subscriptions.len());
+
         // Map Barter Subscriptions to exchange specific subscriptions
         let exchange_subs = subscriptions
             .iter()
-            .map(|subscription| {
+            .filter_map(|subscription| {
                 // Translate Barter Subscription to exchange specific subscription
-                let exchange_sub = ExchangeSub::new(subscription);
+                let exchange_sub =
+                    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription);
+
+                // Drop duplicate Subscriptions, tagged duplicates silently share the first
+                let key = (
+                    exchange_sub.channel.as_ref().to_owned(),
+                    exchange_sub.market.as_ref().to_owned(),
+                );
+                if !actioned.insert(key) {
+                    if subscription.tag.is_some() {
+                        return None;
+                    }
+                    warn!(
+                        exchange = %Exchange::ID,
+                        subscription = %subscription.id_string(),
+                        action = "dropping duplicate",
+                        "duplicate Subscription provided",
+                    );
+                    return None;
+                }
 
                 // Determine the SubscriptionId associated with this exchange specific subscription
                 let subscription_id = exchange_sub.id();
@@ -48,7 +77,7 @@ impl SubscriptionMapper for WebSocketSubMapper {
                     .0
                     .insert(subscription_id, subscription.instrument.clone());
 
-                exchange_sub
+                Some(exchange_sub)
             })
             .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
 
@@ -61,3 +90,211 @@ impl SubscriptionMapper for WebSocketSubMapper {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{binance::spot::BinanceSpot, kraken::Kraken},
+        subscription::{candle::Candles, trade::PublicTrades, Interval},
+    };
+    use barter_integration::{
+        model::{Instrument, InstrumentKind},
+        protocol::websocket::WsMessage,
+    };
+
+    #[test]
+    fn test_websocket_sub_mapper_dedupes_subscriptions() {
+        struct TestCase {
+            input: Vec<Subscription<Kraken, PublicTrades>>,
+            expected_ids: Vec<&'static str>,
+        }
+
+        let trades = |base, quote| {
+            Subscription::from((Kraken, base, quote, InstrumentKind::Spot, PublicTrades))
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: identical Subscriptions are deduplicated
+                input: vec![trades("xbt", "usd"), trades("xbt", "usd")],
+                expected_ids: vec!["trade|XBT/USD"],
+            },
+            TestCase {
+                // TC1: unique Subscriptions are kept, duplicates are dropped
+                input: vec![
+                    trades("xbt", "usd"),
+                    trades("eth", "usd"),
+                    trades("xbt", "usd"),
+                    trades("eth", "usd"),
+                ],
+                expected_ids: vec!["trade|XBT/USD", "trade|ETH/USD"],
+            },
+            TestCase {
+                // TC2: Subscriptions differing only by tag share one exchange subscription
+                input: vec![
+                    trades("xbt", "usd").with_tag("momentum"),
+                    trades("xbt", "usd").with_tag("mean_reversion"),
+                ],
+                expected_ids: vec!["trade|XBT/USD"],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = WebSocketSubMapper::map(&test.input);
+
+            assert_eq!(
+                actual.subscriptions.len(),
+                test.expected_ids.len(),
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                actual.instrument_map.0.len(),
+                test.expected_ids.len(),
+                "TC{} failed",
+                index
+            );
+            for id in test.expected_ids {
+                assert!(
+                    actual
+                        .instrument_map
+                        .0
+                        .contains_key(&SubscriptionId::from(id)),
+                    "TC{index} failed because instrument_map is missing {id}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_websocket_sub_mapper_keeps_distinct_kinds() {
+        // Same Instrument with different Candles Intervals are distinct Subscriptions
+        let subscriptions = vec![
+            Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            )),
+            Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute5),
+            )),
+            Subscription::from((
+                Kraken,
+                "xbt",
+                "usd",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            )),
+        ];
+
+        let actual = WebSocketSubMapper::map(&subscriptions);
+
+        let instrument = Instrument::from(("xbt", "usd", InstrumentKind::Spot));
+        assert_eq!(actual.subscriptions.len(), 2);
+        assert_eq!(
+            actual.instrument_map,
+            Map(HashMap::from([
+                (SubscriptionId::from("ohlc-1|XBT/USD"), instrument.clone()),
+                (SubscriptionId::from("ohlc-5|XBT/USD"), instrument),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_websocket_sub_mapper_binance_candle_intervals() {
+        // Same Instrument with different Candles Intervals can coexist on one connection
+        let subscriptions = vec![
+            Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute1),
+            )),
+            Subscription::from((
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                InstrumentKind::Spot,
+                Candles(Interval::Minute5),
+            )),
+        ];
+
+        let actual = WebSocketSubMapper::map(&subscriptions);
+
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        assert_eq!(
+            actual.instrument_map,
+            Map(HashMap::from([
+                (
+                    SubscriptionId::from("@kline_1m|BTCUSDT"),
+                    instrument.clone()
+                ),
+                (SubscriptionId::from("@kline_5m|BTCUSDT"), instrument),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_websocket_sub_mapper_chunks_binance_requests() {
+        use crate::exchange::binance::{
+            validator::BinanceSubValidator, MAX_SUBS_PER_REQUEST_BINANCE,
+        };
+
+        let subscriptions = (0..250)
+            .map(|i| {
+                Subscription::from((
+                    BinanceSpot::default(),
+                    format!("coin{i:03}"),
+                    "usdt".to_string(),
+                    InstrumentKind::Spot,
+                    PublicTrades,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let actual = WebSocketSubMapper::map(&subscriptions);
+        assert_eq!(actual.instrument_map.0.len(), 250);
+        assert_eq!(MAX_SUBS_PER_REQUEST_BINANCE, 200);
+
+        // 250 streams are chunked into requests of 200 & 50 streams
+        assert_eq!(actual.subscriptions.len(), 2);
+        assert_eq!(BinanceSpot::expected_responses(&actual.instrument_map), 2);
+
+        let payload = |message: &WsMessage| match message {
+            WsMessage::Text(text) => serde_json::from_str::<serde_json::Value>(text).unwrap(),
+            other => panic!("expected WsMessage::Text, got {other:?}"),
+        };
+
+        let first = payload(&actual.subscriptions[0]);
+        let params = first["params"].as_array().unwrap();
+        assert_eq!(first["method"], "SUBSCRIBE");
+        assert_eq!(first["id"], 1);
+        assert_eq!(params.len(), 200);
+        assert_eq!(params[0], "coin000usdt@trade");
+        assert_eq!(params[199], "coin199usdt@trade");
+
+        let last = payload(&actual.subscriptions[1]);
+        let params = last["params"].as_array().unwrap();
+        assert_eq!(last["method"], "SUBSCRIBE");
+        assert_eq!(last["id"], 2);
+        assert_eq!(params.len(), 50);
+        assert_eq!(params[0], "coin200usdt@trade");
+        assert_eq!(params[49], "coin249usdt@trade");
+
+        // Each request id acknowledges exactly the Subscriptions actioned by that request
+        let acks = BinanceSubValidator::ack_subscriptions(&actual.instrument_map);
+        assert_eq!(acks.len(), 2);
+        assert_eq!(acks[&1].len(), 200);
+        assert_eq!(acks[&1][199], SubscriptionId::from("@trade|COIN199USDT"));
+        assert_eq!(acks[&2].len(), 50);
+        assert_eq!(acks[&2][0], SubscriptionId::from("@trade|COIN200USDT"));
+    }
+}
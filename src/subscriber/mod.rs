@@ -1,6 +1,7 @@
 use self::{
+    connection::{connect, ConnectionOptions},
     mapper::{SubscriptionMapper, WebSocketSubMapper},
-    validator::SubscriptionValidator,
+    validator::{SubscriptionOutcome, SubscriptionValidator},
 };
 use crate::{
     exchange::Connector,
@@ -8,14 +9,16 @@ use crate::{
     Identifier,
 };
 use async_trait::async_trait;
-use barter_integration::{
-    error::SocketError,
-    model::Instrument,
-    protocol::websocket::{connect, WebSocket},
-};
+use barter_integration::{error::SocketError, model::Instrument, protocol::websocket::WebSocket};
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
-use tracing::{debug, info};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+use url::Url;
+
+/// [`ConnectionOptions`](connection::ConnectionOptions) used to establish every
+/// [`WebSocket`] connection (eg/ proxy, connect timeout, headers, TLS).
+pub mod connection;
 
 /// [`SubscriptionMapper`](mapper::SubscriptionMapper) implementations defining how to map a
 /// collection of Barter [`Subscription`]s into exchange specific [`SubscriptionMeta`].
@@ -26,13 +29,20 @@ pub mod mapper;
 pub mod validator;
 
 /// Defines how to connect to a socket and subscribe to market data streams.
+///
+/// Returns the instrument [`Map`] of the confirmed [`Subscription`]s, alongside the
+/// [`SubscriptionOutcome`] of every actioned [`Subscription`], waiting at most `timeout` for the
+/// exchange to acknowledge them. The connection is established according to the provided
+/// [`ConnectionOptions`].
 #[async_trait]
 pub trait Subscriber {
     type SubMapper: SubscriptionMapper;
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        timeout: Duration,
+        options: &ConnectionOptions,
+    ) -> Result<(WebSocket, Map<Instrument>, SubscriptionOutcome), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -49,7 +59,33 @@ impl Subscriber for WebSocketSubscriber {
 
     async fn subscribe<Exchange, Kind>(
         subscriptions: &[Subscription<Exchange, Kind>],
-    ) -> Result<(WebSocket, Map<Instrument>), SocketError>
+        timeout: Duration,
+        options: &ConnectionOptions,
+    ) -> Result<(WebSocket, Map<Instrument>, SubscriptionOutcome), SocketError>
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubKind + Send + Sync,
+        Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Exchange::validate_subscriptions(subscriptions)?;
+        let url = Exchange::subscription_url(options.environment, subscriptions)?;
+        Self::subscribe_via_url(url, subscriptions, timeout, options).await
+    }
+}
+
+impl WebSocketSubscriber {
+    /// Connect to the provided exchange [`Url`] according to the [`ConnectionOptions`], action
+    /// the [`Subscription`]s, and validate the exchange responses within the provided `timeout`.
+    ///
+    /// Used directly by [`Subscriber`]s for exchanges whose [`Url`] must be resolved
+    /// asynchronously before connecting (eg/ [`Kucoin`](crate::exchange::kucoin) requires a
+    /// connection token).
+    pub async fn subscribe_via_url<Exchange, Kind>(
+        url: Url,
+        subscriptions: &[Subscription<Exchange, Kind>],
+        timeout: Duration,
+        options: &ConnectionOptions,
+    ) -> Result<(WebSocket, Map<Instrument>, SubscriptionOutcome), SocketError>
     where
         Exchange: Connector + Send + Sync,
         Kind: SubKind + Send + Sync,
@@ -57,18 +93,21 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
-        debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
+        let subscription_ids = subscriptions
+            .iter()
+            .map(Subscription::id_string)
+            .collect::<Vec<_>>();
+        debug!(%exchange, %url, subscriptions = ?subscription_ids, "subscribing to WebSocket");
 
         // Connect to exchange
-        let mut websocket = connect(url).await?;
-        debug!(%exchange, ?subscriptions, "connected to WebSocket");
+        let mut websocket = connect(&url, options).await?;
+        debug!(%exchange, subscriptions = ?subscription_ids, "connected to WebSocket");
 
         // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
         let SubscriptionMeta {
             instrument_map,
             subscriptions,
-        } = Self::SubMapper::map::<Exchange, Kind>(subscriptions);
+        } = WebSocketSubMapper::map::<Exchange, Kind>(subscriptions);
 
         // Send Subscriptions over WebSocket
         for subscription in subscriptions {
@@ -77,11 +116,19 @@ impl Subscriber for WebSocketSubscriber {
         }
 
         // Validate Subscription responses
-        let map =
-            Exchange::SubValidator::validate::<Exchange, Kind>(instrument_map, &mut websocket)
-                .await?;
+        let (map, outcome) = Exchange::SubValidator::validate::<Exchange, Kind>(
+            instrument_map,
+            &mut websocket,
+            timeout,
+        )
+        .await?;
+
+        if outcome.is_complete() {
+            info!(%exchange, "subscribed to WebSocket");
+        } else {
+            warn!(%exchange, %outcome, "subscribed to WebSocket with unconfirmed subscriptions");
+        }
 
-        info!(%exchange, "subscribed to WebSocket");
-        Ok((websocket, map))
+        Ok((websocket, map, outcome))
     }
 }
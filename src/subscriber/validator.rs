@@ -1,55 +1,420 @@
 use crate::{
+    decoder::DecodingParser,
     exchange::Connector,
     subscription::{Map, SubKind},
 };
 use async_trait::async_trait;
 use barter_integration::{
     error::SocketError,
-    model::Instrument,
-    protocol::{
-        websocket::{WebSocket, WebSocketParser},
-        StreamParser,
-    },
+    model::{Instrument, SubscriptionId},
+    protocol::{websocket::WebSocket, StreamParser},
     Validator,
 };
 use futures::StreamExt;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Debug, Display, Formatter},
+    time::Duration,
+};
 use tracing::debug;
 
 /// Defines how to validate that actioned market data
 /// [`Subscription`](crate::subscription::Subscription)s were accepted by the exchange.
+///
+/// Responses should be parsed with a [`DecodingParser`] using the
+/// [`Connector::Decoder`](crate::exchange::Connector::Decoder) of the exchange.
+///
+/// Returns the instrument [`Map`] of the confirmed [`Subscription`](crate::subscription::Subscription)s,
+/// alongside the [`SubscriptionOutcome`] of every actioned
+/// [`Subscription`](crate::subscription::Subscription). Rejected & timed out subscriptions are
+/// reported via the [`SubscriptionOutcome`] rather than as a [`SocketError`], so the caller can
+/// decide if partial success is acceptable (see [`AckPolicy`]).
 #[async_trait]
 pub trait SubscriptionValidator {
-    type Parser: StreamParser;
-
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+        timeout: Duration,
+    ) -> Result<(Map<Instrument>, SubscriptionOutcome), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send;
 }
 
+/// Determines if a [`MarketStream`](crate::MarketStream) connection is initialised when only
+/// some of its [`Subscription`](crate::subscription::Subscription)s are acknowledged by the
+/// exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum AckPolicy {
+    /// Fail initialisation unless every [`Subscription`](crate::subscription::Subscription) is
+    /// confirmed.
+    #[default]
+    RequireAll,
+    /// Initialise with the confirmed [`Subscription`](crate::subscription::Subscription)s,
+    /// provided at least one is confirmed. Rejected & timed out subscriptions are dropped.
+    AllowPartial,
+}
+
+/// Configuration of the subscription acknowledgement phase of
+/// [`MarketStream::init`](crate::MarketStream::init).
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionAcks {
+    pub policy: AckPolicy,
+    /// Maximum [`Duration`] to wait for every acknowledgement. Defaults to the exchange
+    /// [`Connector::subscription_timeout`] if `None`.
+    pub timeout: Option<Duration>,
+}
+
+impl SubscriptionAcks {
+    /// Resolve the acknowledgement timeout for the provided exchange [`Connector`].
+    pub fn timeout<Exchange>(&self) -> Duration
+    where
+        Exchange: Connector,
+    {
+        self.timeout.unwrap_or_else(Exchange::subscription_timeout)
+    }
+}
+
+/// Outcome of validating the [`Subscription`](crate::subscription::Subscription)s actioned on a
+/// single connection, keyed by exchange [`SubscriptionId`].
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionOutcome {
+    /// Subscriptions acknowledged by the exchange.
+    pub confirmed: Vec<SubscriptionId>,
+    /// Subscriptions rejected by the exchange, with the exchange error message.
+    pub rejected: Vec<RejectedSubscription>,
+    /// Subscriptions that were neither confirmed nor rejected before the timeout.
+    pub timed_out: Vec<SubscriptionId>,
+}
+
+/// [`SubscriptionId`] rejected by the exchange, with the exchange error message.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct RejectedSubscription {
+    pub subscription_id: SubscriptionId,
+    pub reason: String,
+}
+
+impl SubscriptionOutcome {
+    /// Construct a [`SubscriptionOutcome`] where every [`SubscriptionId`] of the instrument
+    /// [`Map`] is confirmed.
+    pub fn confirmed_all(map: &Map<Instrument>) -> Self {
+        Self::from_ids(map, |_| Ack::Confirmed)
+    }
+
+    /// Construct a [`SubscriptionOutcome`] where every [`SubscriptionId`] of the instrument
+    /// [`Map`] is rejected with the provided reason.
+    pub fn rejected_all(map: &Map<Instrument>, reason: &str) -> Self {
+        Self::from_ids(map, |_| Ack::Rejected(reason.to_owned()))
+    }
+
+    /// Construct a [`SubscriptionOutcome`] where every [`SubscriptionId`] of the instrument
+    /// [`Map`] timed out.
+    pub fn timed_out_all(map: &Map<Instrument>) -> Self {
+        Self::from_ids(map, |_| Ack::Pending)
+    }
+
+    fn from_ids<F>(map: &Map<Instrument>, ack: F) -> Self
+    where
+        F: Fn(&SubscriptionId) -> Ack,
+    {
+        map.0.keys().collect::<BTreeSet<_>>().into_iter().fold(
+            Self::default(),
+            |mut outcome, subscription_id| {
+                outcome.push(subscription_id.clone(), ack(subscription_id));
+                outcome
+            },
+        )
+    }
+
+    fn push(&mut self, subscription_id: SubscriptionId, ack: Ack) {
+        match ack {
+            Ack::Confirmed => self.confirmed.push(subscription_id),
+            Ack::Rejected(reason) => self.rejected.push(RejectedSubscription {
+                subscription_id,
+                reason,
+            }),
+            Ack::Pending => self.timed_out.push(subscription_id),
+        }
+    }
+
+    /// Determines if every actioned [`SubscriptionId`] was confirmed.
+    pub fn is_complete(&self) -> bool {
+        self.rejected.is_empty() && self.timed_out.is_empty()
+    }
+
+    /// Determines if the [`SubscriptionOutcome`] satisfies the provided [`AckPolicy`].
+    pub fn is_acceptable(&self, policy: AckPolicy) -> bool {
+        match policy {
+            AckPolicy::RequireAll => self.is_complete(),
+            AckPolicy::AllowPartial => !self.confirmed.is_empty(),
+        }
+    }
+
+    /// Every [`SubscriptionId`] that was not confirmed (ie/ rejected or timed out).
+    pub fn unconfirmed(&self) -> Vec<SubscriptionId> {
+        self.rejected
+            .iter()
+            .map(|rejected| rejected.subscription_id.clone())
+            .chain(self.timed_out.iter().cloned())
+            .collect()
+    }
+
+    /// Retain only the confirmed [`SubscriptionId`]s of the instrument [`Map`].
+    pub fn retain_confirmed(&self, mut map: Map<Instrument>) -> Map<Instrument> {
+        map.0
+            .retain(|subscription_id, _| self.confirmed.contains(subscription_id));
+        map
+    }
+}
+
+impl Display for SubscriptionOutcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "confirmed: {}", self.confirmed.len())?;
+
+        if !self.rejected.is_empty() {
+            let rejected = self
+                .rejected
+                .iter()
+                .map(|rejected| format!("{} ({})", rejected.subscription_id, rejected.reason))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, ", rejected: [{rejected}]")?;
+        }
+
+        if !self.timed_out.is_empty() {
+            let timed_out = self
+                .timed_out
+                .iter()
+                .map(SubscriptionId::as_ref)
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, ", timed out: [{timed_out}]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Acknowledgement state of an expected exchange subscription response.
+#[derive(Clone, Eq, PartialEq, Debug)]
+enum Ack {
+    Pending,
+    Confirmed,
+    Rejected(String),
+}
+
+/// Tracks the acknowledgement of each exchange subscription response expected after actioning
+/// [`Subscription`](crate::subscription::Subscription)s, for exchanges whose responses can be
+/// correlated with the request they answer.
+///
+/// Each expected response is identified by an exchange specific `AckId` (eg/ the echoed
+/// subscription arg, or a request id), which acknowledges one or more [`SubscriptionId`]s.
+#[derive(Clone, Debug)]
+pub struct AckTracker<AckId> {
+    acks: BTreeMap<AckId, (Ack, Vec<SubscriptionId>)>,
+    unattributed: Vec<String>,
+}
+
+impl<AckId> AckTracker<AckId>
+where
+    AckId: Ord,
+{
+    /// Construct a new [`Self`] expecting a response for each `AckId`, which acknowledges the
+    /// associated [`SubscriptionId`]s.
+    pub fn new<Iter>(acks: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (AckId, Vec<SubscriptionId>)>,
+    {
+        Self {
+            acks: acks
+                .into_iter()
+                .map(|(ack_id, subscription_ids)| (ack_id, (Ack::Pending, subscription_ids)))
+                .collect(),
+            unattributed: Vec::new(),
+        }
+    }
+
+    /// Determines if every expected response has been received.
+    pub fn is_done(&self) -> bool {
+        self.acks.values().all(|(ack, _)| *ack != Ack::Pending)
+    }
+
+    /// Every `AckId` still awaiting a response.
+    pub fn pending(&self) -> impl Iterator<Item = &AckId> {
+        self.acks
+            .iter()
+            .filter(|(_, (ack, _))| *ack == Ack::Pending)
+            .map(|(ack_id, _)| ack_id)
+    }
+
+    /// Number of `AckId`s still awaiting a response.
+    pub fn num_pending(&self) -> usize {
+        self.pending().count()
+    }
+
+    /// Record a success response for the `AckId`, returning false if it was not pending.
+    pub fn confirm(&mut self, ack_id: &AckId) -> bool {
+        self.resolve(ack_id, Ack::Confirmed)
+    }
+
+    /// Record a failure response for the `AckId`, returning false if it was not pending.
+    pub fn reject(&mut self, ack_id: &AckId, reason: &str) -> bool {
+        self.resolve(ack_id, Ack::Rejected(reason.to_owned()))
+    }
+
+    /// Record a failure response that cannot be correlated with a specific `AckId`. Any
+    /// responses still pending once validation ends are reported as rejected with this reason,
+    /// rather than timed out.
+    pub fn reject_unattributed(&mut self, reason: &str) {
+        self.unattributed.push(reason.to_owned());
+    }
+
+    fn resolve(&mut self, ack_id: &AckId, outcome: Ack) -> bool {
+        match self.acks.get_mut(ack_id) {
+            Some((ack, _)) if *ack == Ack::Pending => {
+                *ack = outcome;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consume [`Self`], resolving the [`SubscriptionOutcome`] of every [`SubscriptionId`].
+    ///
+    /// A [`SubscriptionId`] is rejected if any of its responses were rejected, confirmed if all
+    /// of its responses were confirmed, and otherwise timed out.
+    pub fn into_outcome(self) -> SubscriptionOutcome {
+        let mut subscriptions = BTreeMap::<SubscriptionId, Ack>::new();
+        for (ack, subscription_ids) in self.acks.into_values() {
+            for subscription_id in subscription_ids {
+                let state = subscriptions
+                    .entry(subscription_id)
+                    .or_insert(Ack::Confirmed);
+                match (&state, &ack) {
+                    (Ack::Rejected(_), _) => {}
+                    (_, Ack::Rejected(_)) | (Ack::Confirmed, Ack::Pending) => *state = ack.clone(),
+                    _ => {}
+                }
+            }
+        }
+
+        let unattributed = (!self.unattributed.is_empty()).then(|| self.unattributed.join("; "));
+
+        subscriptions.into_iter().fold(
+            SubscriptionOutcome::default(),
+            |mut outcome, (subscription_id, ack)| {
+                let ack = match (ack, &unattributed) {
+                    (Ack::Pending, Some(reason)) => Ack::Rejected(reason.clone()),
+                    (ack, _) => ack,
+                };
+                outcome.push(subscription_id, ack);
+                outcome
+            },
+        )
+    }
+}
+
+/// Receive & parse exchange subscription `Response`s until every response expected by the
+/// [`AckTracker`] is received, or the `timeout` is reached.
+///
+/// Each `Response` is passed to `on_response` to be correlated with the [`AckTracker`].
+/// Payloads that are not a `Response` (eg/ market data of already confirmed subscriptions) are
+/// skipped.
+pub async fn validate_acks<Exchange, Response, AckId, F>(
+    mut tracker: AckTracker<AckId>,
+    websocket: &mut WebSocket,
+    timeout: Duration,
+    mut on_response: F,
+) -> Result<SubscriptionOutcome, SocketError>
+where
+    Exchange: Connector,
+    Response: DeserializeOwned + Debug,
+    AckId: Ord,
+    F: FnMut(Response, &mut AckTracker<AckId>),
+{
+    let deadline = tokio::time::Instant::now() + timeout;
+
+    loop {
+        // Break if every expected response was received
+        if tracker.is_done() {
+            debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
+            break Ok(tracker.into_outcome());
+        }
+
+        tokio::select! {
+            // If timeout reached, pending responses are reported as timed out
+            _ = tokio::time::sleep_until(deadline) => {
+                debug!(
+                    exchange = %Exchange::ID,
+                    ?timeout,
+                    pending = %tracker.num_pending(),
+                    "subscription validation timeout reached"
+                );
+                break Ok(tracker.into_outcome())
+            },
+            // Parse incoming messages and determine subscription outcomes
+            message = websocket.next() => {
+                let response = match message {
+                    Some(response) => response,
+                    None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
+                };
+
+                match DecodingParser::<Exchange::Decoder>::parse::<Response>(response) {
+                    Some(Ok(response)) => {
+                        debug!(exchange = %Exchange::ID, payload = ?response, "received subscription response");
+                        on_response(response, &mut tracker);
+                    }
+                    Some(Err(SocketError::Deserialise { error, payload })) => {
+                        // Active subscription payloads, so skip to next SubResponse
+                        debug!(
+                            exchange = %Exchange::ID,
+                            ?error,
+                            pending = %tracker.num_pending(),
+                            %payload,
+                            "failed to deserialise non SubResponse payload"
+                        );
+                        continue
+                    }
+                    Some(Err(SocketError::Terminated(close_frame))) => {
+                        break Err(SocketError::Subscribe(
+                            format!("received WebSocket CloseFrame: {close_frame}")
+                        ))
+                    }
+                    _ => {
+                        // Pings, Pongs, Frames, etc.
+                        continue
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Standard [`SubscriptionValidator`] for [`WebSocket`]s suitable for most exchanges.
+///
+/// ### Notes
+/// Subscription responses are counted rather than correlated with the
+/// [`Subscription`](crate::subscription::Subscription) they acknowledge, so a failure response
+/// rejects, and a timeout times out, every actioned
+/// [`Subscription`](crate::subscription::Subscription).
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct WebSocketSubValidator;
 
 #[async_trait]
 impl SubscriptionValidator for WebSocketSubValidator {
-    type Parser = WebSocketParser;
-
     async fn validate<Exchange, Kind>(
         instrument_map: Map<Instrument>,
         websocket: &mut WebSocket,
-    ) -> Result<Map<Instrument>, SocketError>
+        timeout: Duration,
+    ) -> Result<(Map<Instrument>, SubscriptionOutcome), SocketError>
     where
         Exchange: Connector + Send,
         Kind: SubKind + Send,
     {
         // Establish exchange specific subscription validation parameters
-        let timeout = Exchange::subscription_timeout();
         let expected_responses = Exchange::expected_responses(&instrument_map);
+        let deadline = tokio::time::Instant::now() + timeout;
 
         // Parameter to keep track of successful Subscription outcomes
         let mut success_responses = 0usize;
@@ -58,15 +423,16 @@ impl SubscriptionValidator for WebSocketSubValidator {
             // Break if all Subscriptions were a success
             if success_responses == expected_responses {
                 debug!(exchange = %Exchange::ID, "validated exchange WebSocket subscriptions");
-                break Ok(instrument_map);
+                let outcome = SubscriptionOutcome::confirmed_all(&instrument_map);
+                break Ok((instrument_map, outcome));
             }
 
             tokio::select! {
-                // If timeout reached, return SubscribeError
-                _ = tokio::time::sleep(timeout) => {
-                    break Err(SocketError::Subscribe(
-                        format!("subscription validation timeout reached: {:?}", timeout)
-                    ))
+                // If timeout reached, every Subscription timed out
+                _ = tokio::time::sleep_until(deadline) => {
+                    debug!(exchange = %Exchange::ID, ?timeout, "subscription validation timeout reached");
+                    let outcome = SubscriptionOutcome::timed_out_all(&instrument_map);
+                    break Ok((Map(Default::default()), outcome))
                 },
                 // Parse incoming messages and determine subscription outcomes
                 message = websocket.next() => {
@@ -75,7 +441,7 @@ impl SubscriptionValidator for WebSocketSubValidator {
                         None => break Err(SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string()))
                     };
 
-                    match Self::Parser::parse::<Exchange::SubResponse>(response) {
+                    match DecodingParser::<Exchange::Decoder>::parse::<Exchange::SubResponse>(response) {
                         Some(Ok(response)) => match response.validate() {
                             // Subscription success
                             Ok(response) => {
@@ -90,7 +456,14 @@ impl SubscriptionValidator for WebSocketSubValidator {
                             }
 
                             // Subscription failure
-                            Err(err) => break Err(err)
+                            Err(error) => {
+                                let reason = match error {
+                                    SocketError::Subscribe(reason) => reason,
+                                    error => error.to_string(),
+                                };
+                                let outcome = SubscriptionOutcome::rejected_all(&instrument_map, &reason);
+                                break Ok((Map(Default::default()), outcome))
+                            }
                         }
                         Some(Err(SocketError::Deserialise { error, payload })) if success_responses >= 1 => {
                             // Already active subscription payloads, so skip to next SubResponse
@@ -119,3 +492,200 @@ impl SubscriptionValidator for WebSocketSubValidator {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::InstrumentKind;
+
+    fn ids(ids: &[&str]) -> Vec<SubscriptionId> {
+        ids.iter().copied().map(SubscriptionId::from).collect()
+    }
+
+    fn rejected(id: &str, reason: &str) -> RejectedSubscription {
+        RejectedSubscription {
+            subscription_id: SubscriptionId::from(id),
+            reason: reason.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ack_tracker_into_outcome() {
+        struct TestCase {
+            acks: Vec<(&'static str, Vec<&'static str>)>,
+            confirm: Vec<&'static str>,
+            reject: Vec<(&'static str, &'static str)>,
+            unattributed: Vec<&'static str>,
+            expected: SubscriptionOutcome,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: every ack confirmed
+                acks: vec![("a", vec!["a"]), ("b", vec!["b"])],
+                confirm: vec!["a", "b"],
+                reject: vec![],
+                unattributed: vec![],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["a", "b"]),
+                    rejected: vec![],
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC1: confirmed, rejected & timed out acks
+                acks: vec![("a", vec!["a"]), ("b", vec!["b"]), ("c", vec!["c"])],
+                confirm: vec!["a"],
+                reject: vec![("b", "invalid instId")],
+                unattributed: vec![],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["a"]),
+                    rejected: vec![rejected("b", "invalid instId")],
+                    timed_out: ids(&["c"]),
+                },
+            },
+            TestCase {
+                // TC2: subscription acknowledged by multiple acks requires all to be confirmed
+                acks: vec![
+                    ("mark|x", vec!["mark|x"]),
+                    ("funding|x", vec!["mark|x"]),
+                    ("mark|y", vec!["mark|y"]),
+                    ("funding|y", vec!["mark|y"]),
+                ],
+                confirm: vec!["mark|x", "funding|x", "mark|y"],
+                reject: vec![],
+                unattributed: vec![],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["mark|x"]),
+                    rejected: vec![],
+                    timed_out: ids(&["mark|y"]),
+                },
+            },
+            TestCase {
+                // TC3: single ack acknowledging multiple subscriptions is rejected
+                acks: vec![("1", vec!["trade|a", "trade|b"])],
+                confirm: vec![],
+                reject: vec![("1", "invalid request")],
+                unattributed: vec![],
+                expected: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: vec![
+                        rejected("trade|a", "invalid request"),
+                        rejected("trade|b", "invalid request"),
+                    ],
+                    timed_out: vec![],
+                },
+            },
+            TestCase {
+                // TC4: pending acks are rejected with unattributed failure reasons
+                acks: vec![("a", vec!["a"]), ("b", vec!["b"])],
+                confirm: vec!["a", "unknown"],
+                reject: vec![],
+                unattributed: vec!["error 1", "error 2"],
+                expected: SubscriptionOutcome {
+                    confirmed: ids(&["a"]),
+                    rejected: vec![rejected("b", "error 1; error 2")],
+                    timed_out: vec![],
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut tracker = AckTracker::new(
+                test.acks
+                    .into_iter()
+                    .map(|(ack, subscription_ids)| (ack, ids(&subscription_ids))),
+            );
+            for ack in test.confirm {
+                tracker.confirm(&ack);
+            }
+            for (ack, reason) in test.reject {
+                assert!(tracker.reject(&ack, reason), "TC{} failed", index);
+            }
+            for reason in test.unattributed {
+                tracker.reject_unattributed(reason);
+            }
+
+            assert_eq!(tracker.into_outcome(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_subscription_outcome_is_acceptable() {
+        struct TestCase {
+            input: SubscriptionOutcome,
+            require_all: bool,
+            allow_partial: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: complete outcome
+                input: SubscriptionOutcome {
+                    confirmed: ids(&["a", "b"]),
+                    rejected: vec![],
+                    timed_out: vec![],
+                },
+                require_all: true,
+                allow_partial: true,
+            },
+            TestCase {
+                // TC1: partial outcome w/ rejected subscription
+                input: SubscriptionOutcome {
+                    confirmed: ids(&["a"]),
+                    rejected: vec![rejected("b", "invalid instId")],
+                    timed_out: vec![],
+                },
+                require_all: false,
+                allow_partial: true,
+            },
+            TestCase {
+                // TC2: nothing confirmed
+                input: SubscriptionOutcome {
+                    confirmed: vec![],
+                    rejected: vec![],
+                    timed_out: ids(&["a"]),
+                },
+                require_all: false,
+                allow_partial: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.input.is_acceptable(AckPolicy::RequireAll),
+                test.require_all,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                test.input.is_acceptable(AckPolicy::AllowPartial),
+                test.allow_partial,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_subscription_outcome_retain_confirmed() {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let map = ["a", "b", "c"]
+            .into_iter()
+            .map(|id| (SubscriptionId::from(id), instrument.clone()))
+            .collect::<Map<Instrument>>();
+
+        let outcome = SubscriptionOutcome {
+            confirmed: ids(&["a", "c"]),
+            rejected: vec![rejected("b", "invalid")],
+            timed_out: vec![],
+        };
+
+        let actual = outcome.retain_confirmed(map);
+        let mut actual = actual.0.into_keys().collect::<Vec<_>>();
+        actual.sort();
+        assert_eq!(actual, ids(&["a", "c"]));
+        assert_eq!(outcome.unconfirmed(), ids(&["b"]));
+        assert_eq!(outcome.to_string(), "confirmed: 2, rejected: [b (invalid)]");
+    }
+}
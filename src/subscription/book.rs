@@ -1,13 +1,19 @@
-use super::SubKind;
+use super::{
+    number::{self, Number},
+    SubKind, SubKindId,
+};
 use crate::{
-    event::{MarketEvent, MarketIter},
+    event::{MarketEvent, MarketIter, TimeSource},
     exchange::ExchangeId,
 };
 use barter_integration::model::{Exchange, Instrument, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BTreeMap,
+};
 use tracing::debug;
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 1 [`OrderBook`]
@@ -18,6 +24,12 @@ use tracing::debug;
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL1;
 
+impl std::fmt::Display for OrderBooksL1 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::OrderBooksL1)
+    }
+}
+
 impl SubKind for OrderBooksL1 {
     type Event = OrderBookL1;
 }
@@ -28,13 +40,16 @@ pub struct OrderBookL1 {
     pub last_update_time: DateTime<Utc>,
     pub best_bid: Level,
     pub best_ask: Level,
+    /// Source of the `last_update_time` & associated [`MarketEvent`] `exchange_time`.
+    #[serde(default)]
+    pub time_source: TimeSource,
 }
 
 impl OrderBookL1 {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> f64 {
+    pub fn mid_price(&self) -> Number {
         mid_price(self.best_bid.price, self.best_ask.price)
     }
 
@@ -42,7 +57,7 @@ impl OrderBookL1 {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> f64 {
+    pub fn volume_weighed_mid_price(&self) -> Number {
         volume_weighted_mid_price(self.best_bid, self.best_ask)
     }
 }
@@ -54,10 +69,82 @@ impl OrderBookL1 {
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL2;
 
+impl std::fmt::Display for OrderBooksL2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::OrderBooksL2)
+    }
+}
+
 impl SubKind for OrderBooksL2 {
     type Event = OrderBook;
 }
 
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields periodic top-N
+/// [`OrderBookSnapshot`] [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Unlike [`OrderBooksL2`], no local [`OrderBook`] is maintained - each event is a complete
+/// snapshot of the best [`Depth`] levels sent by the exchange.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OrderBookSnapshots {
+    pub depth: Depth,
+}
+
+impl std::fmt::Display for OrderBookSnapshots {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", SubKindId::OrderBookSnapshots, self.depth)
+    }
+}
+
+impl SubKind for OrderBookSnapshots {
+    type Event = OrderBookSnapshot;
+}
+
+impl OrderBookSnapshots {
+    /// Construct a new [`Self`] with the provided [`Depth`].
+    pub fn new(depth: Depth) -> Self {
+        Self { depth }
+    }
+}
+
+/// Number of best levels on each side of the book included in an [`OrderBookSnapshot`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Depth {
+    Top5,
+    Top10,
+    Top20,
+    Top50,
+}
+
+impl Depth {
+    /// All [`Depth`] variants, ordered from shallowest to deepest.
+    pub const ALL: [Depth; 4] = [Depth::Top5, Depth::Top10, Depth::Top20, Depth::Top50];
+
+    /// Number of levels on each side of the book.
+    pub fn levels(&self) -> usize {
+        match self {
+            Depth::Top5 => 5,
+            Depth::Top10 => 10,
+            Depth::Top20 => 20,
+            Depth::Top50 => 50,
+        }
+    }
+}
+
+impl std::fmt::Display for Depth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "top{}", self.levels())
+    }
+}
+
+/// Normalised Barter top-N [`OrderBookSnapshot`], with bids & asks sorted best level first.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OrderBookSnapshot {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 3 [`OrderBook`]
 /// [`MarketEvent<T>`](crate::event::MarketEvent) events.
 ///
@@ -66,16 +153,37 @@ impl SubKind for OrderBooksL2 {
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct OrderBooksL3;
 
+impl std::fmt::Display for OrderBooksL3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::OrderBooksL3)
+    }
+}
+
 impl SubKind for OrderBooksL3 {
     type Event = OrderBook;
 }
 
 /// Normalised Barter [`OrderBook`] snapshot.
+///
+/// ### Update Id Range
+/// `first_update_id` & `last_update_id` are the exchange update id range of the delta that
+/// produced this [`OrderBook`], allowing the continuity of persisted deltas to be verified on
+/// replay via a [`ContinuityChecker`](crate::transformer::sequence::ContinuityChecker). Both are
+/// `None` for exchanges without the concept.
+///
+/// Continuous deltas satisfy `first_update_id <= previous last_update_id + 1`:
+/// - Binance Spot: `U` & `u`.
+/// - Binance Futures: `pu + 1` & `u`, since futures update ids are not contiguous between events.
+/// - Okx: `prevSeqId + 1` & `seqId`, with `first_update_id` of `None` for snapshots.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct OrderBook {
     pub last_update_time: DateTime<Utc>,
     pub bids: OrderBookSide,
     pub asks: OrderBookSide,
+    #[serde(default)]
+    pub first_update_id: Option<u64>,
+    #[serde(default)]
+    pub last_update_id: Option<u64>,
 }
 
 impl OrderBook {
@@ -90,7 +198,7 @@ impl OrderBook {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> Option<f64> {
+    pub fn mid_price(&self) -> Option<Number> {
         match (self.bids.levels.first(), self.asks.levels.first()) {
             (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
             (Some(best_bid), None) => Some(best_bid.price),
@@ -103,7 +211,7 @@ impl OrderBook {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> Option<f64> {
+    pub fn volume_weighed_mid_price(&self) -> Option<Number> {
         match (self.bids.levels.first(), self.asks.levels.first()) {
             (Some(best_bid), Some(best_ask)) => {
                 Some(volume_weighted_mid_price(*best_bid, *best_ask))
@@ -169,7 +277,7 @@ impl OrderBookSide {
             .find(|(_index, level)| level.eq_price(new_level.price))
         {
             // Scenario 1a: Level exists & new value is 0 => remove Level
-            Some((index, _)) if new_level.amount == 0.0 => {
+            Some((index, _)) if new_level.amount == number::ZERO => {
                 self.levels.remove(index);
             }
 
@@ -179,7 +287,7 @@ impl OrderBookSide {
             }
 
             // Scenario 2a: Level does not exist & new value > 0 => insert new Level
-            None if new_level.amount > 0.0 => self.levels.push(new_level),
+            None if new_level.amount > number::ZERO => self.levels.push(new_level),
 
             // Scenario 2b: Level does not exist & new value is 0 => log error & continue
             _ => {
@@ -205,15 +313,15 @@ impl OrderBookSide {
 }
 
 /// Normalised Barter OrderBook [`Level`].
-#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
 pub struct Level {
-    pub price: f64,
-    pub amount: f64,
+    pub price: Number,
+    pub amount: Number,
 }
 
 impl<T> From<(T, T)> for Level
 where
-    T: Into<f64>,
+    T: Into<Number>,
 {
     fn from((price, amount): (T, T)) -> Self {
         Self::new(price, amount)
@@ -221,18 +329,23 @@ where
 }
 
 impl Ord for Level {
+    /// Total ordering by price then amount, so sorting levels containing a non-finite [`Number`]
+    /// sent by an exchange (eg/ "NaN") cannot panic.
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other)
-            .unwrap_or_else(|| panic!("{:?}.partial_cmp({:?}) impossible", self, other))
+        number::total_cmp(&self.price, &other.price)
+            .then_with(|| number::total_cmp(&self.amount, &other.amount))
     }
 }
 
 impl PartialOrd for Level {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.price.partial_cmp(&other.price)? {
-            Ordering::Equal => self.amount.partial_cmp(&other.amount),
-            non_equal => Some(non_equal),
-        }
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Level {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -241,7 +354,7 @@ impl Eq for Level {}
 impl Level {
     pub fn new<T>(price: T, amount: T) -> Self
     where
-        T: Into<f64>,
+        T: Into<Number>,
     {
         Self {
             price: price.into(),
@@ -249,28 +362,181 @@ impl Level {
         }
     }
 
-    pub fn eq_price(&self, price: f64) -> bool {
-        let diff = (price - self.price).abs();
-        f64::EPSILON > diff
+    pub fn eq_price(&self, price: Number) -> bool {
+        number::approx_eq(price, self.price)
+    }
+}
+
+/// Incremental update to a [`SortedOrderBook`], where each [`Level`] replaces the existing
+/// [`Level`] at the same price, and a zero amount removes it.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct OrderBookDelta {
+    pub last_update_time: DateTime<Utc>,
+    pub bids: Vec<Level>,
+    pub asks: Vec<Level>,
+}
+
+/// Maintained level 2 order book with bids & asks kept sorted by price, supporting synchronous
+/// queries of the best [`Level`]s & depth.
+///
+/// Unlike the [`OrderBook`] event, each side is a [`BTreeMap`] keyed by price, so applying an
+/// [`OrderBookDelta`] and querying the best [`Level`]s never requires re-sorting.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SortedOrderBook {
+    pub last_update_time: DateTime<Utc>,
+    bids: BTreeMap<Reverse<OrderedPrice>, Number>,
+    asks: BTreeMap<OrderedPrice, Number>,
+}
+
+/// Totally ordered [`Level`] price used to key each side of a [`SortedOrderBook`], or of an
+/// exchange specific local order book (eg/ [`OkxBookUpdater`](crate::exchange::okx::book::l2::OkxBookUpdater)).
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct OrderedPrice(pub(crate) Number);
+
+impl PartialEq for OrderedPrice {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedPrice {}
+
+impl PartialOrd for OrderedPrice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderedPrice {
+    fn cmp(&self, other: &Self) -> Ordering {
+        number::total_cmp(&self.0, &other.0)
+    }
+}
+
+impl From<&OrderBook> for SortedOrderBook {
+    fn from(snapshot: &OrderBook) -> Self {
+        let mut book = Self::default();
+        book.reset(snapshot);
+        book
     }
 }
 
-// Todo: Add tests
+impl SortedOrderBook {
+    /// Clear this [`SortedOrderBook`] and repopulate it from the provided [`OrderBook`]
+    /// snapshot (eg/ after a re-connection re-syncs the book).
+    pub fn reset(&mut self, snapshot: &OrderBook) {
+        self.last_update_time = snapshot.last_update_time;
+        self.bids = snapshot
+            .bids
+            .levels
+            .iter()
+            .filter(|level| level.amount > number::ZERO)
+            .map(|level| (Reverse(OrderedPrice(level.price)), level.amount))
+            .collect();
+        self.asks = snapshot
+            .asks
+            .levels
+            .iter()
+            .filter(|level| level.amount > number::ZERO)
+            .map(|level| (OrderedPrice(level.price), level.amount))
+            .collect();
+    }
+
+    /// Apply the provided [`OrderBookDelta`], upserting each [`Level`] and removing any
+    /// [`Level`] with a zero amount.
+    pub fn apply(&mut self, delta: &OrderBookDelta) {
+        self.last_update_time = delta.last_update_time;
+        for level in &delta.bids {
+            upsert(
+                &mut self.bids,
+                Reverse(OrderedPrice(level.price)),
+                level.amount,
+            );
+        }
+        for level in &delta.asks {
+            upsert(&mut self.asks, OrderedPrice(level.price), level.amount);
+        }
+    }
+
+    /// Best (highest) bid [`Level`], if any.
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids
+            .iter()
+            .next()
+            .map(|(Reverse(OrderedPrice(price)), amount)| Level::new(*price, *amount))
+    }
+
+    /// Best (lowest) ask [`Level`], if any.
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(OrderedPrice(price), amount)| Level::new(*price, *amount))
+    }
+
+    /// Calculate the mid price by taking the average of the best bid and ask prices.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    pub fn mid_price(&self) -> Option<Number> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
+            (Some(best_bid), None) => Some(best_bid.price),
+            (None, Some(best_ask)) => Some(best_ask.price),
+            (None, None) => None,
+        }
+    }
+
+    /// [`OrderBookSnapshot`] of the best `n` [`Level`]s on each side of the book.
+    pub fn depth(&self, n: usize) -> OrderBookSnapshot {
+        OrderBookSnapshot {
+            last_update_time: self.last_update_time,
+            bids: self
+                .bids
+                .iter()
+                .take(n)
+                .map(|(Reverse(OrderedPrice(price)), amount)| Level::new(*price, *amount))
+                .collect(),
+            asks: self
+                .asks
+                .iter()
+                .take(n)
+                .map(|(OrderedPrice(price), amount)| Level::new(*price, *amount))
+                .collect(),
+        }
+    }
+}
+
+/// Upsert the `amount` at the provided price `key` of a [`SortedOrderBook`] side, removing the
+/// [`Level`] if the `amount` is zero.
+fn upsert<Key: Ord>(side: &mut BTreeMap<Key, Number>, key: Key, amount: Number) {
+    if amount > number::ZERO {
+        side.insert(key, amount);
+    } else {
+        side.remove(&key);
+    }
+}
 
 /// Calculate the mid price by taking the average of the best bid and ask prices.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn mid_price(best_bid_price: f64, best_ask_price: f64) -> f64 {
-    (best_bid_price + best_ask_price) / 2.0
+pub fn mid_price(best_bid_price: Number, best_ask_price: Number) -> Number {
+    (best_bid_price + best_ask_price) / Number::from(2)
 }
 
 /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
 /// with their associated amount.
 ///
+/// Falls back to the [`mid_price`] if both amounts are zero, since there is no volume to weigh
+/// the prices with.
+///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> f64 {
-    ((best_bid.price * best_ask.amount) + (best_ask.price * best_bid.amount))
-        / (best_bid.amount + best_ask.amount)
+pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> Number {
+    let total_amount = best_bid.amount + best_ask.amount;
+    if total_amount == number::ZERO {
+        return mid_price(best_bid.price, best_ask.price);
+    }
+
+    ((best_bid.price * best_ask.amount) + (best_ask.price * best_bid.amount)) / total_amount
 }
 
 impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
@@ -280,6 +546,8 @@ impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
+            sequence: None,
+            tag: None,
             kind: book,
         })])
     }
@@ -288,6 +556,7 @@ impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::subscription::number::from_f64;
 
     mod order_book_l1 {
         use super::*;
@@ -296,7 +565,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Number,
             }
 
             let tests = vec![
@@ -306,8 +575,9 @@ mod tests {
                         last_update_time: Default::default(),
                         best_bid: Level::new(100, 999999),
                         best_ask: Level::new(200, 1),
+                        time_source: TimeSource::Exchange,
                     },
-                    expected: 150.0,
+                    expected: from_f64(150.0),
                 },
                 TestCase {
                     // TC1
@@ -315,8 +585,9 @@ mod tests {
                         last_update_time: Default::default(),
                         best_bid: Level::new(50, 1),
                         best_ask: Level::new(250, 999999),
+                        time_source: TimeSource::Exchange,
                     },
-                    expected: 150.0,
+                    expected: from_f64(150.0),
                 },
                 TestCase {
                     // TC2
@@ -324,8 +595,9 @@ mod tests {
                         last_update_time: Default::default(),
                         best_bid: Level::new(10, 999999),
                         best_ask: Level::new(250, 999999),
+                        time_source: TimeSource::Exchange,
                     },
-                    expected: 130.0,
+                    expected: from_f64(130.0),
                 },
             ];
 
@@ -338,7 +610,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Number,
             }
 
             let tests = vec![
@@ -348,8 +620,9 @@ mod tests {
                         last_update_time: Default::default(),
                         best_bid: Level::new(100, 100),
                         best_ask: Level::new(200, 100),
+                        time_source: TimeSource::Exchange,
                     },
-                    expected: 150.0,
+                    expected: from_f64(150.0),
                 },
                 TestCase {
                     // TC1: volume affects mid-price
@@ -357,8 +630,9 @@ mod tests {
                         last_update_time: Default::default(),
                         best_bid: Level::new(100, 600),
                         best_ask: Level::new(200, 1000),
+                        time_source: TimeSource::Exchange,
                     },
-                    expected: 137.5,
+                    expected: from_f64(137.5),
                 },
                 TestCase {
                     // TC2: volume the same and price the same
@@ -366,8 +640,19 @@ mod tests {
                         last_update_time: Default::default(),
                         best_bid: Level::new(1000, 999999),
                         best_ask: Level::new(1000, 999999),
+                        time_source: TimeSource::Exchange,
                     },
-                    expected: 1000.0,
+                    expected: from_f64(1000.0),
+                },
+                TestCase {
+                    // TC3: zero volume on both sides falls back to non-weighted mid price
+                    input: OrderBookL1 {
+                        last_update_time: Default::default(),
+                        best_bid: Level::new(100, 0),
+                        best_ask: Level::new(200, 0),
+                        time_source: TimeSource::Exchange,
+                    },
+                    expected: from_f64(150.0),
                 },
             ];
 
@@ -388,7 +673,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Number>,
             }
 
             let tests = vec![
@@ -404,6 +689,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
                     expected: None,
                 },
@@ -413,14 +700,16 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![Level::new(100, 100), Level::new(50, 100)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(100.0),
+                    expected: Some(from_f64(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
@@ -432,10 +721,12 @@ mod tests {
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
+                            levels: vec![Level::new(50, 100), Level::new(100, 100)],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(50.0),
+                    expected: Some(from_f64(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -443,14 +734,16 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![Level::new(100, 100), Level::new(50, 100)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
+                            levels: vec![Level::new(200, 100), Level::new(300, 100)],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(150.0),
+                    expected: Some(from_f64(150.0)),
                 },
             ];
 
@@ -463,7 +756,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Number>,
             }
 
             let tests = vec![
@@ -479,6 +772,8 @@ mod tests {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
                     expected: None,
                 },
@@ -488,14 +783,16 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![Level::new(100, 100), Level::new(50, 100)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
                             levels: vec![],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(100.0),
+                    expected: Some(from_f64(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
@@ -507,10 +804,12 @@ mod tests {
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
+                            levels: vec![Level::new(50, 100), Level::new(100, 100)],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(50.0),
+                    expected: Some(from_f64(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -518,14 +817,16 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![Level::new(100, 100), Level::new(50, 100)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
+                            levels: vec![Level::new(200, 100), Level::new(300, 100)],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(150.0),
+                    expected: Some(from_f64(150.0)),
                 },
                 TestCase {
                     // TC4: valid volume weighted mid-price
@@ -533,14 +834,16 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 3000.0), Level::new(50.0, 100.0)],
+                            levels: vec![Level::new(100, 3000), Level::new(50, 100)],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 1000.0), Level::new(300.0, 100.0)],
+                            levels: vec![Level::new(200, 1000), Level::new(300, 100)],
                         },
+                        first_update_id: None,
+                        last_update_id: None,
                     },
-                    expected: Some(175.0),
+                    expected: Some(from_f64(175.0)),
                 },
             ];
 
@@ -552,6 +855,44 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_de_order_book_update_id_range() {
+            struct TestCase {
+                input: &'static str,
+                expected: (Option<u64>, Option<u64>),
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: OrderBook persisted before update id ranges existed
+                    input: r#"{"last_update_time":"2023-01-01T00:00:00Z","bids":{"side":"buy","levels":[{"price":100.0,"amount":1.0}]},"asks":{"side":"sell","levels":[{"price":101.0,"amount":1.0}]}}"#,
+                    expected: (None, None),
+                },
+                TestCase {
+                    // TC1: OrderBook with an update id range
+                    input: r#"{"last_update_time":"2023-01-01T00:00:00Z","bids":{"side":"buy","levels":[]},"asks":{"side":"sell","levels":[]},"first_update_id":101,"last_update_id":110}"#,
+                    expected: (Some(101), Some(110)),
+                },
+                TestCase {
+                    // TC2: OrderBook snapshot with only a last update id
+                    input: r#"{"last_update_time":"2023-01-01T00:00:00Z","bids":{"side":"buy","levels":[]},"asks":{"side":"sell","levels":[]},"first_update_id":null,"last_update_id":100}"#,
+                    expected: (None, Some(100)),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let book = serde_json::from_str::<OrderBook>(test.input).unwrap();
+                let actual = (book.first_update_id, book.last_update_id);
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+
+                // Round trip is lossless
+                let round_trip =
+                    serde_json::from_str::<OrderBook>(&serde_json::to_string(&book).unwrap())
+                        .unwrap();
+                assert_eq!(round_trip, book, "TC{} failed", index);
+            }
+        }
     }
 
     mod order_book_side {
@@ -883,6 +1224,41 @@ mod tests {
             }
         }
 
+        #[cfg(not(feature = "decimal"))]
+        #[test]
+        fn test_ord_consistent_with_partial_ord_and_eq() {
+            let levels = [
+                Level::new(f64::NAN, 1.0),
+                Level::new(-0.0, 1.0),
+                Level::new(0.0, 1.0),
+                Level::new(1.0, f64::NAN),
+                Level::new(1.0, -0.0),
+                Level::new(1.0, 0.0),
+                Level::new(f64::NEG_INFINITY, 1.0),
+                Level::new(f64::INFINITY, 1.0),
+            ];
+
+            for one in levels {
+                for two in levels {
+                    assert_eq!(
+                        one.partial_cmp(&two),
+                        Some(one.cmp(&two)),
+                        "{one:?} vs {two:?}"
+                    );
+                    assert_eq!(
+                        one == two,
+                        one.cmp(&two) == Ordering::Equal,
+                        "{one:?} vs {two:?}"
+                    );
+                }
+            }
+
+            // NaN is ordered & equal to itself, whilst -0.0 is ordered before 0.0
+            assert_eq!(levels[0], levels[0]);
+            assert!(levels[1] < levels[2]);
+            assert!(levels[4] < levels[5]);
+        }
+
         #[test]
         fn test_eq_price() {
             struct TestCase {
@@ -918,4 +1294,104 @@ mod tests {
             }
         }
     }
+
+    mod sorted_order_book {
+        use super::*;
+
+        fn snapshot(bids: Vec<Level>, asks: Vec<Level>) -> OrderBook {
+            OrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, bids),
+                asks: OrderBookSide::new(Side::Sell, asks),
+                first_update_id: None,
+                last_update_id: None,
+            }
+        }
+
+        #[test]
+        fn test_sorted_order_book_apply() {
+            let mut book = SortedOrderBook::from(&snapshot(
+                vec![Level::new(99, 1), Level::new(100, 2), Level::new(98, 3)],
+                vec![Level::new(102, 1), Level::new(101, 2), Level::new(103, 3)],
+            ));
+
+            struct TestCase {
+                input: OrderBookDelta,
+                expected: OrderBookSnapshot,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: empty delta leaves the snapshot sorted best first
+                    input: OrderBookDelta::default(),
+                    expected: OrderBookSnapshot {
+                        last_update_time: Default::default(),
+                        bids: vec![Level::new(100, 2), Level::new(99, 1)],
+                        asks: vec![Level::new(101, 2), Level::new(102, 1)],
+                    },
+                },
+                TestCase {
+                    // TC1: delta inserts new best bid & updates existing ask amount
+                    input: OrderBookDelta {
+                        last_update_time: Default::default(),
+                        bids: vec![Level::new(from_f64(100.5), from_f64(5.0))],
+                        asks: vec![Level::new(101, 7)],
+                    },
+                    expected: OrderBookSnapshot {
+                        last_update_time: Default::default(),
+                        bids: vec![
+                            Level::new(from_f64(100.5), from_f64(5.0)),
+                            Level::new(100, 2),
+                        ],
+                        asks: vec![Level::new(101, 7), Level::new(102, 1)],
+                    },
+                },
+                TestCase {
+                    // TC2: zero amounts remove the best levels
+                    input: OrderBookDelta {
+                        last_update_time: Default::default(),
+                        bids: vec![Level::new(from_f64(100.5), from_f64(0.0))],
+                        asks: vec![Level::new(101, 0), Level::new(200, 0)],
+                    },
+                    expected: OrderBookSnapshot {
+                        last_update_time: Default::default(),
+                        bids: vec![Level::new(100, 2), Level::new(99, 1)],
+                        asks: vec![Level::new(102, 1), Level::new(103, 3)],
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                book.apply(&test.input);
+                assert_eq!(book.depth(2), test.expected, "TC{} failed", index);
+            }
+
+            assert_eq!(book.best_bid(), Some(Level::new(100, 2)));
+            assert_eq!(book.best_ask(), Some(Level::new(102, 1)));
+            assert_eq!(book.mid_price(), Some(from_f64(101.0)));
+        }
+
+        #[test]
+        fn test_sorted_order_book_reset_clears_stale_levels() {
+            let mut book = SortedOrderBook::from(&snapshot(
+                vec![Level::new(100, 1), Level::new(99, 1)],
+                vec![Level::new(101, 1)],
+            ));
+
+            // Re-connection re-syncs the book from a fresh snapshot
+            book.reset(&snapshot(vec![Level::new(95, 4)], vec![]));
+
+            assert_eq!(book.best_bid(), Some(Level::new(95, 4)));
+            assert_eq!(book.best_ask(), None);
+            assert_eq!(book.mid_price(), Some(from_f64(95.0)));
+            assert_eq!(
+                book.depth(10),
+                OrderBookSnapshot {
+                    last_update_time: Default::default(),
+                    bids: vec![Level::new(95, 4)],
+                    asks: vec![],
+                }
+            );
+        }
+    }
 }
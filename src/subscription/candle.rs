@@ -1,26 +1,989 @@
-use super::SubKind;
-use crate::subscription::Interval;
+use super::{SubKind, SubKindId};
+use crate::{
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::Connector,
+    streams::{channel::EventSender, gap::spawn_gap_fill},
+    subscription::{
+        number::{self, Number},
+        Interval,
+    },
+    Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{Exchange, Instrument, SubscriptionId},
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
-/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events once each candle has closed.
+///
+/// Exchanges often send in-progress candle updates every few seconds - these are filtered out
+/// before being emitted, so exactly one [`Candle`] is yielded per completed [`Interval`].
+/// Subscribe to [`CandleUpdates`] to also receive the in-progress updates.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Candles(pub Interval);
 
+impl std::fmt::Display for Candles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", SubKindId::Candles, self.0)
+    }
+}
+
 impl SubKind for Candles {
     type Event = Candle;
+
+    fn handover_key(event: &MarketEvent<Self::Event>) -> Option<u64> {
+        candle_handover_key(event)
+    }
+
+    fn matches(&self, candle: &Self::Event) -> bool {
+        self.0.close_time(candle.start_time) == candle.close_time
+    }
+
+    fn validate(candle: &Self::Event) -> Result<(), DataError> {
+        candle.validate()
+    }
+
+    fn validate_exchange<Exchange>(&self) -> Result<(), SocketError>
+    where
+        Exchange: Connector,
+    {
+        validate_interval::<Exchange>(self.0)
+    }
+
+    fn spawn_gap_fill(
+        subscriptions: Vec<(Instrument, Self)>,
+        grace: std::time::Duration,
+        exchange_tx: EventSender<MarketEvent<Self::Event>>,
+    ) -> EventSender<MarketEvent<Self::Event>> {
+        spawn_gap_fill(
+            subscriptions
+                .into_iter()
+                .map(|(instrument, Candles(interval))| (instrument, interval)),
+            grace,
+            exchange_tx,
+        )
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields every [`Candle`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) update received from the exchange, including
+/// in-progress updates.
+///
+/// Opt-in alternative to [`Candles`]. Use [`Candle::closed`] to determine if the candle is final.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CandleUpdates(pub Interval);
+
+impl std::fmt::Display for CandleUpdates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", SubKindId::CandleUpdates, self.0)
+    }
+}
+
+impl SubKind for CandleUpdates {
+    type Event = Candle;
+
+    fn handover_key(event: &MarketEvent<Self::Event>) -> Option<u64> {
+        candle_handover_key(event)
+    }
+
+    fn matches(&self, candle: &Self::Event) -> bool {
+        self.0.close_time(candle.start_time) == candle.close_time
+    }
+
+    fn validate(candle: &Self::Event) -> Result<(), DataError> {
+        candle.validate()
+    }
+
+    fn validate_exchange<Exchange>(&self) -> Result<(), SocketError>
+    where
+        Exchange: Connector,
+    {
+        validate_interval::<Exchange>(self.0)
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Candle`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events built from the provided [`CandleSource`]
+/// price (eg/ mark price candles for liquidation modelling).
+///
+/// Like [`CandleUpdates`], every candle update received from the exchange is yielded, including
+/// in-progress updates. [`CandleUpdates`] is equivalent to a [`CandleSource::LastTrade`]
+/// [`SourcedCandles`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SourcedCandles(pub Interval, pub CandleSource);
+
+impl std::fmt::Display for SourcedCandles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}_{}", SubKindId::SourcedCandles, self.0, self.1)
+    }
+}
+
+impl SubKind for SourcedCandles {
+    type Event = Candle;
+
+    fn handover_key(event: &MarketEvent<Self::Event>) -> Option<u64> {
+        candle_handover_key(event)
+    }
+
+    fn matches(&self, candle: &Self::Event) -> bool {
+        self.0.close_time(candle.start_time) == candle.close_time && candle.source == self.1
+    }
+
+    fn validate(candle: &Self::Event) -> Result<(), DataError> {
+        candle.validate()
+    }
+
+    fn validate_exchange<Exchange>(&self) -> Result<(), SocketError>
+    where
+        Exchange: Connector,
+    {
+        validate_interval::<Exchange>(self.0)
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields closed [`Candle`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events built locally from the
+/// [`PublicTrades`](super::trade::PublicTrades) of any exchange, including those without a
+/// candle channel (eg/ [`Coinbase`](crate::exchange::coinbase::Coinbase)).
+///
+/// Trades are aggregated into [`Interval`] buckets by a
+/// [`TradeCandleAggregator`](crate::transformer::candle::TradeCandleAggregator), and every
+/// yielded [`Candle`] has [`Candle::derived`] set. See the aggregator for how buckets are closed.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct CandlesFromTrades(pub Interval);
+
+impl std::fmt::Display for CandlesFromTrades {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", SubKindId::CandlesFromTrades, self.0)
+    }
+}
+
+impl SubKind for CandlesFromTrades {
+    type Event = Candle;
+
+    fn handover_key(event: &MarketEvent<Self::Event>) -> Option<u64> {
+        candle_handover_key(event)
+    }
+
+    fn matches(&self, candle: &Self::Event) -> bool {
+        self.0.close_time(candle.start_time) == candle.close_time
+    }
+
+    fn validate(candle: &Self::Event) -> Result<(), DataError> {
+        candle.validate()
+    }
+}
+
+/// Price source a [`Candle`] is built from.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CandleSource {
+    /// Last traded price.
+    #[default]
+    LastTrade,
+    /// Exchange mark price, used by derivative exchanges to determine liquidations.
+    MarkPrice,
+    /// Exchange index price, aggregated from the spot price of multiple exchanges.
+    IndexPrice,
+}
+
+impl std::fmt::Display for CandleSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                CandleSource::LastTrade => "last_trade",
+                CandleSource::MarkPrice => "mark_price",
+                CandleSource::IndexPrice => "index_price",
+            }
+        )
+    }
+}
+
+/// Unit the `volume` of a [`Candle`] is denominated in.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum VolumeDenomination {
+    /// Base currency of the [`Instrument`] (eg/ BTC of BTC-USDT).
+    #[default]
+    Base,
+    /// Quote currency of the [`Instrument`] (eg/ USDT of BTC-USDT).
+    Quote,
+    /// Number of derivative contracts, whose size is exchange & [`Instrument`] specific.
+    Contracts,
+}
+
+impl std::fmt::Display for VolumeDenomination {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                VolumeDenomination::Base => "base",
+                VolumeDenomination::Quote => "quote",
+                VolumeDenomination::Contracts => "contracts",
+            }
+        )
+    }
+}
+
+/// [`SubKind::handover_key`] of a [`Candle`], being the epoch millisecond `start_time` of the
+/// candle [`Interval`].
+fn candle_handover_key(event: &MarketEvent<Candle>) -> Option<u64> {
+    u64::try_from(event.kind.start_time.timestamp_millis()).ok()
+}
+
+/// Validate the candle [`Interval`] is one of the
+/// [`Connector::supported_intervals`] of the exchange.
+fn validate_interval<Exchange>(interval: Interval) -> Result<(), SocketError>
+where
+    Exchange: Connector,
+{
+    let supported = Exchange::supported_intervals();
+    if supported.contains(&interval) {
+        return Ok(());
+    }
+
+    Err(SocketError::Unsupported {
+        entity: Exchange::ID.as_str(),
+        item: format!(
+            "candle interval {interval} (supported candle intervals: {})",
+            supported
+                .iter()
+                .map(Interval::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    })
 }
 
 /// Normalised Barter OHLCV [`Candle`] model.
+///
+/// The `start_time` & `close_time` are the UTC boundaries of the candle [`Interval`], consistent
+/// across exchanges regardless of whether the exchange keys candles by open or close time. The
+/// `close_time` is the last millisecond of the [`Interval`] (ie/ `start_time` + interval - 1ms).
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Candle {
+    /// Open time of the candle [`Interval`]. Defaults to the unix epoch when deserialising
+    /// candles serialised prior to its addition.
+    #[serde(default)]
+    pub start_time: DateTime<Utc>,
     pub close_time: DateTime<Utc>,
-    pub open: f64,
-    pub high: f64,
-    pub low: f64,
-    pub close: f64,
-    pub volume: f64,
+    pub open: Number,
+    pub high: Number,
+    pub low: Number,
+    pub close: Number,
+    pub volume: Number,
+    /// Unit the `volume` is denominated in, since exchanges disagree on it (eg/ contracts for
+    /// [`GateioFuturesUsd`](crate::exchange::gateio::futures::GateioFuturesUsd) candles). Defaults
+    /// to [`VolumeDenomination::Base`] when deserialising candles serialised prior to its
+    /// addition.
+    #[serde(default)]
+    pub volume_denomination: VolumeDenomination,
+    /// Volume denominated in the base currency, if provided by the exchange.
+    #[serde(default)]
+    pub base_volume: Option<Number>,
+    /// Volume denominated in the quote currency, if provided by the exchange.
+    #[serde(default)]
+    pub quote_volume: Option<Number>,
+    /// Volume of taker buy trades denominated in the base currency, if provided by the exchange.
+    #[serde(default)]
+    pub taker_buy_volume: Option<Number>,
+    /// Volume of taker buy trades denominated in the quote currency, if provided by the exchange.
+    #[serde(default)]
+    pub taker_buy_quote_volume: Option<Number>,
     pub trade_count: u64,
+    /// True if this is the final update of the candle (ie/ the [`Interval`] has elapsed).
+    #[serde(default)]
+    pub closed: bool,
+    /// Price source the candle is built from.
+    #[serde(default)]
+    pub source: CandleSource,
+    /// True if the candle was synthesized by a
+    /// [`CandleGapFill`](crate::streams::gap::CandleGapFill) for an [`Interval`] the exchange did
+    /// not emit a candle for (eg/ zero trades), rather than received from the exchange.
+    #[serde(default)]
+    pub synthetic: bool,
+    /// True if the candle was built locally from [`PublicTrade`](super::trade::PublicTrade)s by
+    /// a [`TradeCandleAggregator`](crate::transformer::candle::TradeCandleAggregator) (see
+    /// [`CandlesFromTrades`]), rather than received from the exchange.
+    #[serde(default)]
+    pub derived: bool,
+}
+
+impl Candle {
+    /// Validate the OHLCV invariants of the [`Candle`], returning a [`DataError::InvalidCandle`]
+    /// describing the first violation (eg/ malformed candles sent during exchange maintenance).
+    ///
+    /// Invariants:
+    /// - `open`, `high`, `low`, `close` & `volume` are finite
+    /// - `high` >= `low`
+    /// - `low` <= `open` <= `high`, and `low` <= `close` <= `high`
+    /// - `volume` >= 0
+    /// - `close_time` > `start_time`
+    /// - `trade_count` is 0 if `volume` is 0
+    pub fn validate(&self) -> Result<(), DataError> {
+        let within_range = |price: Number| self.low <= price && price <= self.high;
+
+        let ohlcv = [self.open, self.high, self.low, self.close, self.volume];
+
+        let reason = if ohlcv.iter().any(|value| !number::is_finite(*value)) {
+            format!("non-finite OHLCV values {ohlcv:?}")
+        } else if self.high < self.low {
+            format!("high {} < low {}", self.high, self.low)
+        } else if !within_range(self.open) {
+            format!("open {} outside [{}, {}]", self.open, self.low, self.high)
+        } else if !within_range(self.close) {
+            format!("close {} outside [{}, {}]", self.close, self.low, self.high)
+        } else if self.volume < number::ZERO {
+            format!("volume {} < 0", self.volume)
+        } else if self.close_time <= self.start_time {
+            format!(
+                "close_time {} <= start_time {}",
+                self.close_time, self.start_time
+            )
+        } else if self.volume == number::ZERO && self.trade_count > 0 {
+            format!("trade_count {} with zero volume", self.trade_count)
+        } else {
+            return Ok(());
+        };
+
+        Err(DataError::InvalidCandle {
+            reason,
+            candle: Box::new(*self),
+        })
+    }
+}
+
+/// Exchange candle input wrapper used by [`Candles`] streams to filter out any in-progress
+/// candles before they are emitted.
+///
+/// Deserialises transparently as the wrapped exchange specific `Input` (eg/
+/// [`BinanceCandle`](crate::exchange::binance::candle::BinanceCandle)).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct ClosedCandles<Input>(pub Input);
+
+impl<Input> Identifier<Option<SubscriptionId>> for ClosedCandles<Input>
+where
+    Input: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        self.0.id()
+    }
+}
+
+impl MarketIter<Candle> {
+    /// Filter out any in-progress [`Candle`]s, retaining only closed [`Candle`]s and errors.
+    ///
+    /// Used by exchange [`ClosedCandles`] conversions to yield [`Candles`] streams.
+    pub fn retain_closed(self) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .filter(|candle| match candle {
+                    Ok(candle) => candle.kind.closed,
+                    Err(_) => true,
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Aggregates closed source [`Interval`] [`Candle`]s (eg/ 1m) into closed target [`Interval`]
+/// [`Candle`]s (eg/ 15m), per exchange [`Instrument`].
+///
+/// ### Notes
+/// - In-progress source [`Candle`]s (ie/ `closed == false`) are ignored.
+/// - A target [`Candle`] is emitted as soon as the final source [`Candle`] of the target bucket
+///   is received.
+/// - If a source [`Candle`] for a later target bucket is received before the current target bucket
+///   is complete (eg/ missing source candles), the partial aggregate is emitted with the correct
+///   target close time.
+/// - Duplicate or late source [`Candle`]s (eg/ replayed after a reconnection) are ignored, so
+///   aggregation is idempotent.
+#[derive(Clone, PartialEq, Debug)]
+pub struct CandleAggregator {
+    source: Interval,
+    target: Interval,
+    buckets: HashMap<(Exchange, Instrument), CandleBucket>,
+}
+
+/// Aggregation state for a single exchange [`Instrument`] target [`Interval`] bucket.
+#[derive(Clone, PartialEq, Debug)]
+struct CandleBucket {
+    /// Start time of the target bucket currently being aggregated.
+    start_time: DateTime<Utc>,
+    /// Close time of the target bucket currently being aggregated.
+    close_time: DateTime<Utc>,
+    /// Close time of the last target bucket emitted, used to ignore late source candles.
+    last_emitted: Option<DateTime<Utc>>,
+    /// Close times of the source candles aggregated into the current target bucket.
+    sources: BTreeSet<DateTime<Utc>>,
+    received_time: DateTime<Utc>,
+    candle: Option<Candle>,
+}
+
+impl CandleAggregator {
+    /// Construct a new [`CandleAggregator`] that aggregates `source` [`Interval`] candles into
+    /// `target` [`Interval`] candles.
+    ///
+    /// Returns an error if the `target` [`Interval`] buckets cannot be exactly composed of
+    /// `source` [`Interval`] buckets (eg/ 3m -> 5m, or 1w -> 1M).
+    pub fn new(source: Interval, target: Interval) -> Result<Self, DataError> {
+        let is_composable = match (source, target) {
+            (source, target) if source >= target => false,
+            // Calendar months can only be composed of days (or smaller), or of months
+            (Interval::Month1, Interval::Month3) => true,
+            (Interval::Day3 | Interval::Week1, Interval::Month1 | Interval::Month3) => false,
+            (_, Interval::Month1 | Interval::Month3) => true,
+            (source, target) => {
+                target.duration().num_milliseconds() % source.duration().num_milliseconds() == 0
+            }
+        };
+
+        if !is_composable {
+            return Err(DataError::Socket(SocketError::Unsupported {
+                entity: "CandleAggregator",
+                item: format!("aggregating {source} candles into {target} candles"),
+            }));
+        }
+
+        Ok(Self {
+            source,
+            target,
+            buckets: HashMap::new(),
+        })
+    }
+
+    /// Source [`Interval`] of the [`Candle`]s being aggregated.
+    pub fn source(&self) -> Interval {
+        self.source
+    }
+
+    /// Target [`Interval`] of the aggregated [`Candle`]s.
+    pub fn target(&self) -> Interval {
+        self.target
+    }
+
+    /// Update the [`CandleAggregator`] with the next source [`Candle`], returning any target
+    /// [`Candle`]s that have been completed as a result.
+    pub fn update(&mut self, event: MarketEvent<Candle>) -> Vec<MarketEvent<Candle>> {
+        if !event.kind.closed {
+            return vec![];
+        }
+
+        let start_time = self.target.floor(event.kind.close_time);
+        let close_time = self.target.close_time(start_time);
+        let key = (event.exchange.clone(), event.instrument.clone());
+        let bucket = self
+            .buckets
+            .entry(key)
+            .or_insert_with(|| CandleBucket::new(start_time, close_time));
+
+        // Ignore late source candles for a target bucket that has already been emitted
+        if bucket
+            .last_emitted
+            .is_some_and(|last_emitted| close_time <= last_emitted)
+        {
+            return vec![];
+        }
+
+        let mut output = Vec::with_capacity(2);
+
+        match close_time.cmp(&bucket.close_time) {
+            // Ignore late source candles for a previous target bucket that was never emitted
+            Ordering::Less => return output,
+            Ordering::Equal => {}
+            // Source candle belongs to a later target bucket, so emit the partial aggregate
+            Ordering::Greater => {
+                if let Some(partial) = bucket.emit(&event.exchange, &event.instrument) {
+                    output.push(partial);
+                }
+                bucket.start_time = start_time;
+                bucket.close_time = close_time;
+            }
+        }
+
+        bucket.merge(&event);
+
+        // Emit the target candle if the final source candle of the bucket has been received
+        if event.kind.close_time == close_time {
+            if let Some(complete) = bucket.emit(&event.exchange, &event.instrument) {
+                output.push(complete);
+            }
+        }
+
+        output
+    }
+
+    /// Flush all partially aggregated target [`Candle`]s (eg/ on shutdown).
+    pub fn flush(&mut self) -> Vec<MarketEvent<Candle>> {
+        self.buckets
+            .iter_mut()
+            .filter_map(|((exchange, instrument), bucket)| bucket.emit(exchange, instrument))
+            .collect()
+    }
+}
+
+impl CandleBucket {
+    fn new(start_time: DateTime<Utc>, close_time: DateTime<Utc>) -> Self {
+        Self {
+            start_time,
+            close_time,
+            last_emitted: None,
+            sources: BTreeSet::new(),
+            received_time: DateTime::<Utc>::MIN_UTC,
+            candle: None,
+        }
+    }
+
+    /// Merge a closed source [`Candle`] into the current target bucket, ignoring duplicates.
+    fn merge(&mut self, event: &MarketEvent<Candle>) {
+        let source = &event.kind;
+        if !self.sources.insert(source.close_time) {
+            return;
+        }
+
+        let is_first = self.sources.first() == Some(&source.close_time);
+        let is_last = self.sources.last() == Some(&source.close_time);
+        self.received_time = self.received_time.max(event.received_time);
+
+        let candle = match &mut self.candle {
+            None => {
+                self.candle = Some(Candle {
+                    start_time: self.start_time,
+                    close_time: self.close_time,
+                    closed: true,
+                    ..*source
+                });
+                return;
+            }
+            Some(candle) => candle,
+        };
+
+        if is_first {
+            candle.open = source.open;
+        }
+        if is_last {
+            candle.close = source.close;
+        }
+        candle.high = candle.high.max(source.high);
+        candle.low = candle.low.min(source.low);
+        candle.volume += source.volume;
+        candle.base_volume = sum_optional(candle.base_volume, source.base_volume);
+        candle.quote_volume = sum_optional(candle.quote_volume, source.quote_volume);
+        candle.taker_buy_volume = sum_optional(candle.taker_buy_volume, source.taker_buy_volume);
+        candle.taker_buy_quote_volume =
+            sum_optional(candle.taker_buy_quote_volume, source.taker_buy_quote_volume);
+        candle.trade_count += source.trade_count;
+    }
+
+    /// Emit the current target bucket aggregate, if any source [`Candle`]s have been merged.
+    fn emit(
+        &mut self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Option<MarketEvent<Candle>> {
+        let candle = self.candle.take()?;
+        self.sources.clear();
+        self.last_emitted = Some(self.close_time);
+
+        Some(MarketEvent {
+            exchange_time: self.close_time,
+            received_time: self.received_time,
+            exchange: exchange.clone(),
+            instrument: instrument.clone(),
+            sequence: None,
+            tag: None,
+            kind: Candle {
+                start_time: self.start_time,
+                close_time: self.close_time,
+                ..candle
+            },
+        })
+    }
+}
+
+/// Sum two optional values, yielding `None` if either value is unknown.
+fn sum_optional(current: Option<Number>, next: Option<Number>) -> Option<Number> {
+    current.zip(next).map(|(current, next)| current + next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange::{binance::candle::BinanceCandle, ExchangeId};
+    use crate::subscription::number::from_f64;
+    use barter_integration::model::InstrumentKind;
+
+    #[test]
+    fn test_closed_candles() {
+        struct TestCase {
+            input: &'static str,
+            expected_candles: Vec<bool>,
+            expected_closed_candles: Vec<bool>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: in progress kline is yielded by CandleUpdates, but filtered by Candles
+                input: r#"
+                {
+                    "e": "kline", "E": 1672515782136, "s": "BTCUSDT",
+                    "k": {
+                        "t": 1672515780000, "T": 1672515839999, "s": "BTCUSDT", "i": "1m",
+                        "o": "16541.60", "c": "16541.90", "h": "16542.00", "l": "16541.60",
+                        "v": "5.344", "q": "88400.69200", "V": "3.932", "Q": "65043.09090",
+                        "n": 39, "x": false
+                    }
+                }
+                "#,
+                expected_candles: vec![false],
+                expected_closed_candles: vec![],
+            },
+            TestCase {
+                // TC1: final kline is yielded by both CandleUpdates & Candles
+                input: r#"
+                {
+                    "e": "kline", "E": 1672515840001, "s": "BTCUSDT",
+                    "k": {
+                        "t": 1672515780000, "T": 1672515839999, "s": "BTCUSDT", "i": "1m",
+                        "o": "16541.60", "c": "16541.90", "h": "16542.00", "l": "16541.60",
+                        "v": "7.123", "q": "117826.03500", "V": "4.101", "Q": "67838.29300",
+                        "n": 52, "x": true
+                    }
+                }
+                "#,
+                expected_candles: vec![true],
+                expected_closed_candles: vec![true],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let instrument = Instrument::from(("btc", "usdt", InstrumentKind::FuturePerpetual));
+
+            let candle = serde_json::from_str::<BinanceCandle>(test.input).unwrap();
+            let actual = MarketIter::<Candle>::from((
+                ExchangeId::BinanceFuturesUsd,
+                instrument.clone(),
+                candle,
+            ))
+            .0
+            .into_iter()
+            .map(|candle| candle.unwrap().kind.closed)
+            .collect::<Vec<_>>();
+            assert_eq!(
+                actual, test.expected_candles,
+                "TC{} CandleUpdates failed",
+                index
+            );
+
+            let closed = serde_json::from_str::<ClosedCandles<BinanceCandle>>(test.input).unwrap();
+            let actual =
+                MarketIter::<Candle>::from((ExchangeId::BinanceFuturesUsd, instrument, closed))
+                    .0
+                    .into_iter()
+                    .map(|candle| candle.unwrap().kind.closed)
+                    .collect::<Vec<_>>();
+            assert_eq!(
+                actual, test.expected_closed_candles,
+                "TC{} Candles failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_candle_serde_round_trip() {
+        /// Candle model used by older consumers prior to the addition of the optional fields.
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct LegacyCandle {
+            close_time: DateTime<Utc>,
+            open: Number,
+            high: Number,
+            low: Number,
+            close: Number,
+            volume: Number,
+            trade_count: u64,
+        }
+
+        let candle = Candle {
+            start_time: DateTime::<Utc>::MIN_UTC,
+            close_time: DateTime::<Utc>::MIN_UTC,
+            open: from_f64(1.0),
+            high: from_f64(2.0),
+            low: from_f64(0.5),
+            close: from_f64(1.5),
+            volume: from_f64(10.0),
+            volume_denomination: VolumeDenomination::Contracts,
+            base_volume: Some(from_f64(0.25)),
+            quote_volume: Some(from_f64(15.0)),
+            taker_buy_volume: Some(from_f64(4.0)),
+            taker_buy_quote_volume: None,
+            trade_count: 7,
+            closed: true,
+            source: CandleSource::MarkPrice,
+            synthetic: false,
+            derived: false,
+        };
+
+        // Extended Candle round trips
+        let serialised = serde_json::to_string(&candle).unwrap();
+        let actual = serde_json::from_str::<Candle>(&serialised).unwrap();
+        assert_eq!(actual, candle);
+
+        // Older consumers ignore the new fields
+        let actual = serde_json::from_str::<LegacyCandle>(&serialised).unwrap();
+        assert_eq!(
+            actual,
+            LegacyCandle {
+                close_time: candle.close_time,
+                open: from_f64(1.0),
+                high: from_f64(2.0),
+                low: from_f64(0.5),
+                close: from_f64(1.5),
+                volume: from_f64(10.0),
+                trade_count: 7,
+            }
+        );
+
+        // Candles serialised by older producers deserialise w/ the new fields as None
+        let legacy = r#"{
+            "close_time": "2023-01-01T00:00:59.999Z",
+            "open": 1.0, "high": 2.0, "low": 0.5, "close": 1.5, "volume": 10.0, "trade_count": 7
+        }"#;
+        let actual = serde_json::from_str::<Candle>(legacy).unwrap();
+        assert_eq!(actual.start_time, DateTime::<Utc>::default());
+        assert_eq!(actual.volume_denomination, VolumeDenomination::Base);
+        assert_eq!(actual.base_volume, None);
+        assert_eq!(actual.quote_volume, None);
+        assert_eq!(actual.taker_buy_volume, None);
+        assert_eq!(actual.taker_buy_quote_volume, None);
+        assert!(!actual.closed);
+        assert_eq!(actual.source, CandleSource::LastTrade);
+    }
+
+    mod aggregator {
+        use super::*;
+        use chrono::{Duration, TimeZone};
+
+        fn base() -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap()
+        }
+
+        /// Construct a closed 1m source candle event for the provided minute offset from base.
+        fn minute(offset: i64, open: f64, high: f64, low: f64, close: f64) -> MarketEvent<Candle> {
+            let close_time = base() + Duration::minutes(offset + 1) - Duration::milliseconds(1);
+            MarketEvent {
+                exchange_time: close_time,
+                received_time: close_time,
+                exchange: Exchange::from(ExchangeId::BinanceSpot),
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                sequence: None,
+                tag: None,
+                kind: Candle {
+                    start_time: base() + Duration::minutes(offset),
+                    close_time,
+                    open: from_f64(open),
+                    high: from_f64(high),
+                    low: from_f64(low),
+                    close: from_f64(close),
+                    volume: from_f64(1.0),
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: None,
+                    quote_volume: Some(from_f64(10.0)),
+                    taker_buy_volume: None,
+                    taker_buy_quote_volume: None,
+                    trade_count: 2,
+                    closed: true,
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: false,
+                },
+            }
+        }
+
+        /// Expected aggregated 5m candle for the provided 5m bucket index, received at the same
+        /// time as the latest source candle minute offset.
+        fn five_minute(
+            bucket: i64,
+            ohlc: (f64, f64, f64, f64),
+            num_sources: u32,
+            latest_source: i64,
+        ) -> MarketEvent<Candle> {
+            let close_time =
+                base() + Duration::minutes(5 * (bucket + 1)) - Duration::milliseconds(1);
+            let (open, high, low, close) = ohlc;
+            MarketEvent {
+                exchange_time: close_time,
+                received_time: minute(latest_source, 0.0, 0.0, 0.0, 0.0).received_time,
+                exchange: Exchange::from(ExchangeId::BinanceSpot),
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                sequence: None,
+                tag: None,
+                kind: Candle {
+                    start_time: base() + Duration::minutes(5 * bucket),
+                    close_time,
+                    open: from_f64(open),
+                    high: from_f64(high),
+                    low: from_f64(low),
+                    close: from_f64(close),
+                    volume: from_f64(f64::from(num_sources)),
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: None,
+                    quote_volume: Some(from_f64(10.0 * f64::from(num_sources))),
+                    taker_buy_volume: None,
+                    taker_buy_quote_volume: None,
+                    trade_count: 2 * u64::from(num_sources),
+                    closed: true,
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: false,
+                },
+            }
+        }
+
+        #[test]
+        fn test_candle_aggregator_new() {
+            struct TestCase {
+                source: Interval,
+                target: Interval,
+                expected_ok: bool,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: 1m -> 5m is valid
+                    source: Interval::Minute1,
+                    target: Interval::Minute5,
+                    expected_ok: true,
+                },
+                TestCase {
+                    // TC1: 1d -> 1M is valid
+                    source: Interval::Day1,
+                    target: Interval::Month1,
+                    expected_ok: true,
+                },
+                TestCase {
+                    // TC2: 3m -> 5m is invalid since 5m is not a multiple of 3m
+                    source: Interval::Minute3,
+                    target: Interval::Minute5,
+                    expected_ok: false,
+                },
+                TestCase {
+                    // TC3: 1w -> 1M is invalid since weeks do not align to months
+                    source: Interval::Week1,
+                    target: Interval::Month1,
+                    expected_ok: false,
+                },
+                TestCase {
+                    // TC4: 15m -> 5m is invalid since target is shorter than source
+                    source: Interval::Minute15,
+                    target: Interval::Minute5,
+                    expected_ok: false,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = CandleAggregator::new(test.source, test.target).is_ok();
+                assert_eq!(actual, test.expected_ok, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_candle_aggregator_update() {
+            struct TestCase {
+                input: Vec<MarketEvent<Candle>>,
+                expected: Vec<MarketEvent<Candle>>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: complete bucket emitted once the final source candle is received
+                    input: vec![
+                        minute(0, 10.0, 12.0, 9.0, 11.0),
+                        minute(1, 11.0, 15.0, 10.0, 14.0),
+                        minute(2, 14.0, 14.0, 8.0, 9.0),
+                        minute(3, 9.0, 10.0, 9.0, 10.0),
+                        minute(4, 10.0, 11.0, 10.0, 10.5),
+                    ],
+                    expected: vec![five_minute(0, (10.0, 15.0, 8.0, 10.5), 5, 4)],
+                },
+                TestCase {
+                    // TC1: in progress source candles are ignored
+                    input: vec![{
+                        let mut in_progress = minute(4, 10.0, 11.0, 10.0, 10.5);
+                        in_progress.kind.closed = false;
+                        in_progress
+                    }],
+                    expected: vec![],
+                },
+                TestCase {
+                    // TC2: gap in source candles emits partial aggregate w/ correct close time
+                    input: vec![
+                        minute(0, 10.0, 12.0, 9.0, 11.0),
+                        minute(1, 11.0, 15.0, 10.0, 14.0),
+                        minute(6, 20.0, 21.0, 19.0, 20.0),
+                    ],
+                    expected: vec![five_minute(0, (10.0, 15.0, 9.0, 14.0), 2, 1)],
+                },
+                TestCase {
+                    // TC3: out of order & duplicate source candles are aggregated idempotently,
+                    // and late source candles for an emitted bucket are ignored
+                    input: vec![
+                        minute(1, 11.0, 15.0, 10.0, 14.0),
+                        minute(0, 10.0, 12.0, 9.0, 11.0),
+                        minute(1, 11.0, 15.0, 10.0, 14.0),
+                        minute(3, 9.0, 10.0, 9.0, 10.0),
+                        minute(2, 14.0, 14.0, 8.0, 9.0),
+                        minute(4, 10.0, 11.0, 10.0, 10.5),
+                        minute(4, 10.0, 11.0, 10.0, 10.5),
+                        minute(2, 14.0, 14.0, 8.0, 9.0),
+                    ],
+                    expected: vec![five_minute(0, (10.0, 15.0, 8.0, 10.5), 5, 4)],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let mut aggregator =
+                    CandleAggregator::new(Interval::Minute1, Interval::Minute5).unwrap();
+
+                let actual = test
+                    .input
+                    .into_iter()
+                    .flat_map(|event| aggregator.update(event))
+                    .collect::<Vec<_>>();
+
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_candle_aggregator_flush() {
+            let mut aggregator =
+                CandleAggregator::new(Interval::Minute1, Interval::Minute5).unwrap();
+            assert!(aggregator.update(minute(5, 1.0, 3.0, 0.5, 2.0)).is_empty());
+            assert!(aggregator.update(minute(6, 2.0, 4.0, 1.5, 3.0)).is_empty());
+
+            assert_eq!(
+                aggregator.flush(),
+                vec![five_minute(1, (1.0, 4.0, 0.5, 3.0), 2, 6)]
+            );
+            assert!(aggregator.flush().is_empty());
+        }
+    }
 }
@@ -1,5 +1,6 @@
-use super::SubKind;
-use barter_integration::model::Side;
+use super::{SubKind, SubKindId};
+use crate::exchange::Connector;
+use barter_integration::{error::SocketError, model::Side};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -8,15 +9,177 @@ use serde::{Deserialize, Serialize};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Liquidations;
 
+impl std::fmt::Display for Liquidations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::Liquidations)
+    }
+}
+
 impl SubKind for Liquidations {
     type Event = Liquidation;
 }
 
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields the [`Liquidation`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events of every market listed by the exchange
+/// via a single market-wide channel (eg/ the BinanceFuturesUsd "!forceOrder@arr" stream).
+///
+/// The [`Instrument`](barter_integration::model::Instrument) of each event is resolved from the
+/// market embedded in each exchange message, so the [`Subscription`](super::Subscription)
+/// instrument is only used to identify the subscription.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct LiquidationsAll;
+
+impl std::fmt::Display for LiquidationsAll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::LiquidationsAll)
+    }
+}
+
+impl SubKind for LiquidationsAll {
+    type Event = Liquidation;
+
+    fn validate_exchange<Exchange>(&self) -> Result<(), SocketError>
+    where
+        Exchange: Connector,
+    {
+        match Exchange::ID.supports(SubKindId::LiquidationsAll) {
+            true => Ok(()),
+            false => Err(SocketError::Unsupported {
+                entity: Exchange::ID.as_str(),
+                item: format!("{self:?}"),
+            }),
+        }
+    }
+}
+
 /// Normalised Barter [`Liquidation`] model.
+///
+/// The `price` is the price of the liquidation order, whereas the `average_price` is the average
+/// price it was filled at. Partially filled liquidations have a `filled_quantity` less than the
+/// `total_quantity`.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Liquidation {
     pub side: Side,
     pub price: f64,
-    pub quantity: f64,
+    /// Average fill price of the liquidation order, if provided by the exchange.
+    #[serde(default)]
+    pub average_price: Option<f64>,
+    /// Filled quantity of the liquidation order. Defaults to 0 when deserialising liquidations
+    /// serialised prior to its addition.
+    #[serde(default)]
+    pub filled_quantity: f64,
+    /// Total quantity of the liquidation order.
+    #[serde(alias = "quantity")]
+    pub total_quantity: f64,
+    #[serde(default)]
+    pub status: LiquidationStatus,
     pub time: DateTime<Utc>,
 }
+
+/// Fill status of a [`Liquidation`] order.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LiquidationStatus {
+    /// The total quantity of the liquidation order was filled.
+    Filled,
+    /// Only part of the total quantity of the liquidation order was filled.
+    PartiallyFilled,
+    /// The exchange did not provide the fill status of the liquidation order.
+    #[default]
+    Unknown,
+}
+
+impl LiquidationStatus {
+    /// Determine the [`LiquidationStatus`] from the filled & total quantities of a liquidation
+    /// order, for exchanges that do not provide an explicit fill status.
+    pub fn from_quantities(filled_quantity: f64, total_quantity: f64) -> Self {
+        if total_quantity > 0.0 && filled_quantity >= total_quantity {
+            Self::Filled
+        } else if filled_quantity > 0.0 {
+            Self::PartiallyFilled
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_liquidation() {
+        struct TestCase {
+            input: &'static str,
+            expected: (Option<f64>, f64, f64, LiquidationStatus),
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Liquidation serialised prior to the fill fields
+                input: r#"{"side":"sell","price":18917.15,"quantity":0.009,"time":"2022-10-11T21:32:54.217Z"}"#,
+                expected: (None, 0.0, 0.009, LiquidationStatus::Unknown),
+            },
+            TestCase {
+                // TC1: partially filled Liquidation
+                input: r#"{"side":"sell","price":18917.15,"average_price":18990.0,"filled_quantity":0.004,"total_quantity":0.009,"status":"partially_filled","time":"2022-10-11T21:32:54.217Z"}"#,
+                expected: (
+                    Some(18990.0),
+                    0.004,
+                    0.009,
+                    LiquidationStatus::PartiallyFilled,
+                ),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let liquidation = serde_json::from_str::<Liquidation>(test.input).unwrap();
+            let actual = (
+                liquidation.average_price,
+                liquidation.filled_quantity,
+                liquidation.total_quantity,
+                liquidation.status,
+            );
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+
+            // Round trip is lossless
+            let round_trip =
+                serde_json::from_str::<Liquidation>(&serde_json::to_string(&liquidation).unwrap())
+                    .unwrap();
+            assert_eq!(round_trip, liquidation, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_liquidation_status_from_quantities() {
+        struct TestCase {
+            input: (f64, f64),
+            expected: LiquidationStatus,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: total quantity filled
+                input: (0.009, 0.009),
+                expected: LiquidationStatus::Filled,
+            },
+            TestCase {
+                // TC1: part of the total quantity filled
+                input: (0.004, 0.009),
+                expected: LiquidationStatus::PartiallyFilled,
+            },
+            TestCase {
+                // TC2: nothing filled
+                input: (0.0, 0.009),
+                expected: LiquidationStatus::Unknown,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = LiquidationStatus::from_quantities(test.input.0, test.input.1);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
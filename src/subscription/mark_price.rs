@@ -0,0 +1,33 @@
+use super::{SubKind, SubKindId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`MarkPrice`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Only supported for [`InstrumentKind::FuturePerpetual`](barter_integration::model::InstrumentKind)
+/// instruments.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct MarkPrices;
+
+impl std::fmt::Display for MarkPrices {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::MarkPrices)
+    }
+}
+
+impl SubKind for MarkPrices {
+    type Event = MarkPrice;
+}
+
+/// Normalised Barter [`MarkPrice`] model, including the latest known funding rate.
+///
+/// Fields are `None` if the exchange does not provide them, or if they have not yet been
+/// received (eg/ [`Okx`](crate::exchange::okx::Okx) publishes funding on a separate channel).
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarkPrice {
+    pub mark_price: f64,
+    pub index_price: Option<f64>,
+    pub funding_rate: Option<f64>,
+    pub next_funding_time: Option<DateTime<Utc>>,
+}
@@ -1,14 +1,22 @@
-use crate::exchange::StreamSelector;
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{subscription::ExchangeSub, Connector, ExchangeId, StreamSelector},
+    streams::channel::EventSender,
+    Identifier,
+};
 use barter_integration::{
     error::SocketError,
     model::{Instrument, InstrumentKind, SubscriptionId, Symbol},
     protocol::websocket::WsMessage,
     Validator,
 };
+use chrono::{DateTime, Datelike, Duration, Months, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
     fmt::{Debug, Display, Formatter},
+    str::FromStr,
 };
 
 /// OrderBook [`SubKind`]s and the associated Barter output data models.
@@ -20,15 +28,170 @@ pub mod candle;
 /// Liquidation [`SubKind`] and the associated Barter output data model.
 pub mod liquidation;
 
+/// Mark price & funding rate [`SubKind`] and the associated Barter output data model.
+pub mod mark_price;
+
+/// [`Number`](number::Number) type of the normalised Barter output data models, switched to a
+/// lossless decimal by the `decimal` feature.
+pub mod number;
+
+/// Option summary (greeks & implied volatility) [`SubKind`] and the associated Barter output data
+/// model.
+pub mod option;
+
+/// Ticker (24h rolling statistics) [`SubKind`] and the associated Barter output data model.
+pub mod ticker;
+
 /// Public trade [`SubKind`] and the associated Barter output data model.
 pub mod trade;
 
 /// Defines the type of a [`Subscription`], and the output [`Self::Event`] that it yields.
+///
+/// The [`Display`] representation of a [`SubKind`] matches the serde name of its
+/// [`SubKindConfig`](crate::streams::builder::dynamic::SubKindConfig), followed by any
+/// parameters (eg/ "public_trades", "candles_1m", "order_book_snapshots_top5").
 pub trait SubKind
 where
-    Self: Debug + Clone,
+    Self: Debug + Display + Clone,
 {
-    type Event: Debug;
+    type Event: Debug + Clone;
+
+    /// Validate the invariants of a normalised [`Self::Event`] (eg/ [`Candle`](candle::Candle)
+    /// OHLC ranges), returning a [`DataError`] describing any violation.
+    ///
+    /// Invalid events are handled according to the
+    /// [`InvalidEventPolicy`](crate::streams::consumer::InvalidEventPolicy) of the stream.
+    /// Defaults to every event being valid.
+    fn validate(_: &Self::Event) -> Result<(), DataError> {
+        Ok(())
+    }
+
+    /// Validate the exchange [`Connector`] supports this [`SubKind`] configuration (eg/ the
+    /// [`Interval`] of [`Candles`](candle::Candles)), used by the [`Subscription`] [`Validator`].
+    ///
+    /// Defaults to every configuration being supported.
+    fn validate_exchange<Exchange>(&self) -> Result<(), SocketError>
+    where
+        Exchange: Connector,
+    {
+        Ok(())
+    }
+
+    /// Key of a [`MarketEvent<Self::Event>`](MarketEvent) that increases monotonically within
+    /// the stream of its [`Subscription`] (eg/ trade id), used to deduplicate the overlap of two
+    /// connections during a proactive connection rotation. See
+    /// [`Handover`](crate::streams::handover::Handover).
+    ///
+    /// Consecutive events with an equal key from the same connection are not duplicates (eg/
+    /// in-progress updates of the same [`Candle`](candle::Candle)). Defaults to the
+    /// [`MarketEvent`] `sequence`.
+    fn handover_key(event: &MarketEvent<Self::Event>) -> Option<u64> {
+        event.sequence
+    }
+
+    /// Determine if the provided [`Self::Event`] was yielded by this [`SubKind`] configuration,
+    /// used to attribute events of the same [`Instrument`] to the tagged [`Subscription`]s they
+    /// belong to (eg/ a 1m [`Candle`](candle::Candle) to `Candles(1m)` rather than `Candles(5m)`).
+    ///
+    /// Defaults to every event matching.
+    fn matches(&self, _: &Self::Event) -> bool {
+        true
+    }
+
+    /// Wrap the provided `exchange_tx` so the [`Self::Event`]s of the provided [`Instrument`] &
+    /// [`SubKind`] subscriptions are gap filled, synthesizing any events the exchange did not
+    /// emit within the `grace` period (eg/ [`Candle`](candle::Candle)s of intervals without
+    /// trades). See [`StreamBuilder::with_gap_fill`](crate::streams::builder::StreamBuilder).
+    ///
+    /// Defaults to returning the `exchange_tx` unchanged, meaning gap filling is not supported.
+    fn spawn_gap_fill(
+        _: Vec<(Instrument, Self)>,
+        _: std::time::Duration,
+        exchange_tx: EventSender<MarketEvent<Self::Event>>,
+    ) -> EventSender<MarketEvent<Self::Event>> {
+        exchange_tx
+    }
+}
+
+/// Unique identifier of each Barter [`SubKind`], used to query the [`SubKind`]s supported by an
+/// exchange at runtime via [`ExchangeId::supports`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubKindId {
+    PublicTrades,
+    PublicTradesAggregated,
+    PublicTradesAll,
+    OrderBooksL1,
+    OrderBooksL2,
+    OrderBooksL3,
+    OrderBookSnapshots,
+    Candles,
+    CandleUpdates,
+    SourcedCandles,
+    CandlesFromTrades,
+    Liquidations,
+    LiquidationsAll,
+    Tickers,
+    IndexTickers,
+    MarkPrices,
+    OptionSummaries,
+}
+
+impl SubKindId {
+    /// Every [`SubKindId`] variant.
+    pub const ALL: [Self; 17] = [
+        Self::PublicTrades,
+        Self::PublicTradesAggregated,
+        Self::PublicTradesAll,
+        Self::OrderBooksL1,
+        Self::OrderBooksL2,
+        Self::OrderBooksL3,
+        Self::OrderBookSnapshots,
+        Self::Candles,
+        Self::CandleUpdates,
+        Self::SourcedCandles,
+        Self::CandlesFromTrades,
+        Self::Liquidations,
+        Self::LiquidationsAll,
+        Self::Tickers,
+        Self::IndexTickers,
+        Self::MarkPrices,
+        Self::OptionSummaries,
+    ];
+
+    /// Return every [`SubKindId`] variant.
+    pub fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    /// Return the &str representation of this [`SubKindId`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubKindId::PublicTrades => "public_trades",
+            SubKindId::PublicTradesAggregated => "public_trades_aggregated",
+            SubKindId::PublicTradesAll => "public_trades_all",
+            SubKindId::OrderBooksL1 => "order_books_l1",
+            SubKindId::OrderBooksL2 => "order_books_l2",
+            SubKindId::OrderBooksL3 => "order_books_l3",
+            SubKindId::OrderBookSnapshots => "order_book_snapshots",
+            SubKindId::Candles => "candles",
+            SubKindId::CandleUpdates => "candle_updates",
+            SubKindId::SourcedCandles => "sourced_candles",
+            SubKindId::CandlesFromTrades => "candles_from_trades",
+            SubKindId::Liquidations => "liquidations",
+            SubKindId::LiquidationsAll => "liquidations_all",
+            SubKindId::Tickers => "tickers",
+            SubKindId::IndexTickers => "index_tickers",
+            SubKindId::MarkPrices => "mark_prices",
+            SubKindId::OptionSummaries => "option_summaries",
+        }
+    }
+}
+
+impl Display for SubKindId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// Barter [`Subscription`] used to subscribe to a [`SubKind`] for a particular exchange
@@ -40,6 +203,10 @@ pub struct Subscription<Exchange, Kind> {
     pub instrument: Instrument,
     #[serde(alias = "type")]
     pub kind: Kind,
+    /// Opaque user tag attached to every [`MarketEvent`] this [`Subscription`] yields. See
+    /// [`Self::with_tag`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
 }
 
 /// Barter time interval used for specifying the interval of a [`SubKind::Candle`].
@@ -106,13 +273,140 @@ impl Display for Interval {
     }
 }
 
+impl Interval {
+    /// All [`Interval`] variants, ordered from shortest to longest.
+    pub const ALL: [Interval; 16] = [
+        Interval::Minute1,
+        Interval::Minute3,
+        Interval::Minute5,
+        Interval::Minute15,
+        Interval::Minute30,
+        Interval::Hour1,
+        Interval::Hour2,
+        Interval::Hour4,
+        Interval::Hour6,
+        Interval::Hour8,
+        Interval::Hour12,
+        Interval::Day1,
+        Interval::Day3,
+        Interval::Week1,
+        Interval::Month1,
+        Interval::Month3,
+    ];
+
+    /// [`Duration`] of the [`Interval`].
+    ///
+    /// Note: month variants are approximated as 30 days per month. Use [`Self::floor`] and
+    /// [`Self::next_close`] for calendar aware interval boundaries.
+    pub fn duration(&self) -> Duration {
+        match self {
+            Interval::Minute1 => Duration::minutes(1),
+            Interval::Minute3 => Duration::minutes(3),
+            Interval::Minute5 => Duration::minutes(5),
+            Interval::Minute15 => Duration::minutes(15),
+            Interval::Minute30 => Duration::minutes(30),
+            Interval::Hour1 => Duration::hours(1),
+            Interval::Hour2 => Duration::hours(2),
+            Interval::Hour4 => Duration::hours(4),
+            Interval::Hour6 => Duration::hours(6),
+            Interval::Hour8 => Duration::hours(8),
+            Interval::Hour12 => Duration::hours(12),
+            Interval::Day1 => Duration::days(1),
+            Interval::Day3 => Duration::days(3),
+            Interval::Week1 => Duration::weeks(1),
+            Interval::Month1 => Duration::days(30),
+            Interval::Month3 => Duration::days(90),
+        }
+    }
+
+    /// Snap the provided timestamp to the start of the [`Interval`] bucket it falls within.
+    ///
+    /// Consistent with [`Binance`](crate::exchange::binance::Binance) kline alignment:
+    /// - Minute, hour & day variants are aligned to the unix epoch (1970-01-01T00:00:00Z).
+    /// - [`Interval::Week1`] buckets start on Monday 00:00 UTC.
+    /// - [`Interval::Month1`] buckets start on the first day of each month 00:00 UTC.
+    /// - [`Interval::Month3`] buckets start on the first day of each quarter
+    ///   (ie/ January, April, July & October) 00:00 UTC.
+    pub fn floor(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Interval::Week1 => {
+                // Unix epoch is a Thursday, so offset by 4 days to align buckets to Monday
+                let monday_offset_ms = Duration::days(4).num_milliseconds();
+                let interval_ms = self.duration().num_milliseconds();
+                let timestamp_ms = timestamp.timestamp_millis();
+                let start_ms =
+                    timestamp_ms - (timestamp_ms - monday_offset_ms).rem_euclid(interval_ms);
+                Utc.timestamp_millis_opt(start_ms)
+                    .single()
+                    .expect("interval start is always a valid timestamp")
+            }
+            Interval::Month1 | Interval::Month3 => {
+                let months_per_bucket = if *self == Interval::Month1 { 1 } else { 3 };
+                let month0 = timestamp.month0() - (timestamp.month0() % months_per_bucket);
+                Utc.with_ymd_and_hms(timestamp.year(), month0 + 1, 1, 0, 0, 0)
+                    .single()
+                    .expect("first day of the month at midnight UTC is always valid")
+            }
+            _ => {
+                let interval_ms = self.duration().num_milliseconds();
+                let timestamp_ms = timestamp.timestamp_millis();
+                let start_ms = timestamp_ms - timestamp_ms.rem_euclid(interval_ms);
+                Utc.timestamp_millis_opt(start_ms)
+                    .single()
+                    .expect("interval start is always a valid timestamp")
+            }
+        }
+    }
+
+    /// Determine the close time of the [`Interval`] bucket the provided timestamp falls within.
+    ///
+    /// Consistent with the [`Candle::close_time`](candle::Candle) convention, the close time is the
+    /// last millisecond of the bucket (ie/ start of the next bucket - 1ms).
+    pub fn next_close(&self, timestamp: DateTime<Utc>) -> DateTime<Utc> {
+        self.close_time(self.floor(timestamp))
+    }
+
+    /// Determine the close time of an [`Interval`] bucket (eg/ a candle) that started at the
+    /// provided open time, without snapping the open time to the [`Interval`] alignment.
+    ///
+    /// The close time is the last millisecond of the bucket (ie/ open time + interval - 1ms), with
+    /// month variants being calendar aware.
+    pub fn close_time(&self, open_time: DateTime<Utc>) -> DateTime<Utc> {
+        let next_open = match self {
+            Interval::Month1 => open_time + Months::new(1),
+            Interval::Month3 => open_time + Months::new(3),
+            _ => open_time + self.duration(),
+        };
+
+        next_open - Duration::milliseconds(1)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = SocketError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Interval::ALL
+            .into_iter()
+            .find(|interval| interval.to_string() == input)
+            .ok_or_else(|| SocketError::Unsupported {
+                entity: "Interval",
+                item: input.to_owned(),
+            })
+    }
+}
+
 impl<Exchange, Kind> Display for Subscription<Exchange, Kind>
 where
     Exchange: Display,
     Kind: Display,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}_{}{}", self.exchange, self.kind, self.instrument)
+        write!(f, "{}_{}{}", self.exchange, self.kind, self.instrument)?;
+        match &self.tag {
+            Some(tag) => write!(f, "#{tag}"),
+            None => Ok(()),
+        }
     }
 }
 
@@ -147,6 +441,77 @@ impl<Exchange, Kind> Subscription<Exchange, Kind> {
             exchange,
             instrument: instrument.into(),
             kind,
+            tag: None,
+        }
+    }
+
+    /// Attach an opaque user tag (eg/ a strategy name) to this [`Subscription`], which is
+    /// carried through to the `tag` of every [`MarketEvent`] it yields.
+    ///
+    /// [`Subscription`]s to the same exchange, [`Instrument`] & [`SubKind`] with different tags
+    /// are distinct: the exchange subscription is shared, but each event is yielded once per
+    /// tag. Tags are retained across re-connections.
+    pub fn with_tag<Tag>(self, tag: Tag) -> Self
+    where
+        Tag: Into<String>,
+    {
+        Self {
+            tag: Some(tag.into()),
+            ..self
+        }
+    }
+}
+
+impl<Exchange, Kind> Subscription<Exchange, Kind>
+where
+    Exchange: Connector,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    /// Determine the [`SubscriptionId`] that links this [`Subscription`] to the exchange messages
+    /// it generates, without connecting to the exchange.
+    ///
+    /// This is the same [`SubscriptionId`] used as the key of the instrument [`Map`] built by the
+    /// [`WebSocketSubMapper`](crate::subscriber::mapper::WebSocketSubMapper), and derived by the
+    /// [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) from every incoming
+    /// payload.
+    ///
+    /// ### Stability
+    /// The [`SubscriptionId`] is a pure function of the exchange, [`Instrument`] and [`SubKind`],
+    /// formatted as "{channel}|{market}" (eg/ "@trade|BTCUSDT"). It is deterministic across
+    /// processes & connections, and its format will only change in a breaking release, so it is
+    /// safe to persist or use to pre-build routing tables.
+    pub fn subscription_id(&self) -> SubscriptionId {
+        ExchangeSub::<Exchange::Channel, Exchange::Market>::new(self).id()
+    }
+}
+
+impl<Exchange, Kind> Subscription<Exchange, Kind>
+where
+    Exchange: Connector,
+    Kind: Display,
+{
+    /// Human-readable key of this [`Subscription`], formatted as "{exchange}_{kind}_{base}_{quote}"
+    /// (eg/ "binance_spot_candles_1m_btc_usdt"). Used to identify [`Subscription`]s in logs.
+    ///
+    /// Tagged [`Subscription`]s are suffixed with "#{tag}" (eg/
+    /// "binance_spot_candles_1m_btc_usdt#momentum").
+    ///
+    /// ### Stability
+    /// The format only changes in a breaking release, so log parsers can rely on it. Note the
+    /// [`InstrumentKind`] is not included, see [`Self::subscription_id`] for an exchange unique
+    /// identifier.
+    pub fn id_string(&self) -> String {
+        let id = format!(
+            "{}_{}_{}_{}",
+            Exchange::ID,
+            self.kind,
+            self.instrument.base,
+            self.instrument.quote
+        );
+
+        match &self.tag {
+            Some(tag) => format!("{id}#{tag}"),
+            None => id,
         }
     }
 }
@@ -165,16 +530,66 @@ where
 
         // Validate the Exchange supports the Subscription InstrumentKind
         match self.instrument.kind {
-            InstrumentKind::Spot if exchange.supports_spot() => Ok(self),
-            InstrumentKind::FuturePerpetual if exchange.supports_futures() => Ok(self),
-            other => Err(SocketError::Unsupported {
-                entity: exchange.as_str(),
-                item: other.to_string(),
-            }),
+            InstrumentKind::Spot if exchange.supports_spot() => {}
+            InstrumentKind::FuturePerpetual if exchange.supports_futures() => {}
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: exchange.as_str(),
+                    item: other.to_string(),
+                })
+            }
+        }
+
+        // Validate the Exchange supports the SubKind configuration (eg/ candle Interval)
+        self.kind.validate_exchange::<Exchange>()?;
+
+        Ok(self)
+    }
+}
+
+/// Record of a Barter [`Subscription`] that failed validation, identifying the offending
+/// exchange, [`Instrument`] and [`SubKind`], along with the reason it was rejected.
+///
+/// Type erased so [`InvalidSubscription`]s for any exchange & [`SubKind`] can be collected
+/// together.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct InvalidSubscription {
+    pub exchange: ExchangeId,
+    pub instrument: Instrument,
+    pub kind: String,
+    pub reason: String,
+}
+
+impl InvalidSubscription {
+    /// Construct a new [`Self`] from the offending [`Subscription`] and the [`SocketError`]
+    /// describing why it is invalid.
+    pub fn new<Exchange, Kind>(
+        subscription: &Subscription<Exchange, Kind>,
+        error: SocketError,
+    ) -> Self
+    where
+        Exchange: StreamSelector<Kind>,
+        Kind: SubKind,
+    {
+        Self {
+            exchange: Exchange::ID,
+            instrument: subscription.instrument.clone(),
+            kind: format!("{:?}", subscription.kind),
+            reason: error.to_string(),
         }
     }
 }
 
+impl Display for InvalidSubscription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "exchange: {}, instrument: {}, kind: {}, reason: {}",
+            self.exchange, self.instrument, self.kind, self.reason
+        )
+    }
+}
+
 /// Metadata generated from a collection of Barter [`Subscription`]s, including the exchange
 /// specific subscription payloads that are sent to the exchange.
 #[derive(Clone, Eq, PartialEq, Debug)]
@@ -202,15 +617,31 @@ impl<T> FromIterator<(SubscriptionId, T)> for Map<T> {
     }
 }
 
+impl<T> Extend<(SubscriptionId, T)> for Map<T> {
+    fn extend<Iter>(&mut self, iter: Iter)
+    where
+        Iter: IntoIterator<Item = (SubscriptionId, T)>,
+    {
+        self.0.extend(iter)
+    }
+}
+
 impl<T> Map<T> {
     /// Find the `T` associated with the provided [`SubscriptionId`].
+    ///
+    /// Prefer [`Self::find_ref`] on hot paths where an owned `T` is not required.
     pub fn find(&self, id: &SubscriptionId) -> Result<T, SocketError>
     where
         T: Clone,
     {
+        self.find_ref(id).cloned()
+    }
+
+    /// Find a reference to the `T` associated with the provided [`SubscriptionId`], without
+    /// cloning it.
+    pub fn find_ref(&self, id: &SubscriptionId) -> Result<&T, SocketError> {
         self.0
             .get(id)
-            .cloned()
             .ok_or_else(|| SocketError::Unidentifiable(id.clone()))
     }
 
@@ -220,6 +651,13 @@ impl<T> Map<T> {
             .get_mut(id)
             .ok_or_else(|| SocketError::Unidentifiable(id.clone()))
     }
+
+    /// Remove the `T`s associated with the provided [`SubscriptionId`]s, eg/ after unsubscribing.
+    pub fn remove(&mut self, ids: &[SubscriptionId]) {
+        ids.iter().for_each(|id| {
+            self.0.remove(id);
+        });
+    }
 }
 
 #[cfg(test)]
@@ -302,6 +740,127 @@ mod tests {
                 serde_json::from_str::<Subscription<GateioFuturesUsd, PublicTrades>>(input)
                     .unwrap();
             }
+
+            #[test]
+            fn test_subscription_tag() {
+                struct TestCase {
+                    input: &'static str,
+                    expected: Option<String>,
+                }
+
+                let tests = vec![
+                    TestCase {
+                        // TC0: Subscription without a tag is untagged
+                        input: r#"{"exchange":"okx","base":"btc","quote":"usdt","instrument_type":"spot","kind":"public_trades"}"#,
+                        expected: None,
+                    },
+                    TestCase {
+                        // TC1: Subscription with a tag
+                        input: r#"{"exchange":"okx","base":"btc","quote":"usdt","instrument_type":"spot","kind":"public_trades","tag":"momentum"}"#,
+                        expected: Some("momentum".to_string()),
+                    },
+                ];
+
+                for (index, test) in tests.into_iter().enumerate() {
+                    let actual =
+                        serde_json::from_str::<Subscription<Okx, PublicTrades>>(test.input)
+                            .unwrap();
+                    assert_eq!(actual.tag, test.expected, "TC{} failed", index);
+
+                    // Untagged Subscriptions serialise without a tag
+                    let round_trip = serde_json::to_value(&actual).unwrap();
+                    assert_eq!(
+                        round_trip.get("tag").and_then(|tag| tag.as_str()),
+                        test.expected.as_deref(),
+                        "TC{} failed",
+                        index
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn test_subscription_id_string() {
+            use crate::exchange::binance::{futures::BinanceFuturesUsd, spot::BinanceSpot};
+            use crate::subscription::{
+                book::{Depth, OrderBookSnapshots, OrderBooksL2},
+                candle::{CandleSource, Candles, SourcedCandles},
+                liquidation::Liquidations,
+            };
+
+            // Format is relied upon by log parsers, so must only change in a breaking release
+            assert_eq!(
+                Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    Candles(Interval::Minute1)
+                ))
+                .id_string(),
+                "binance_spot_candles_1m_btc_usdt"
+            );
+            assert_eq!(
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "eth",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    OrderBooksL2
+                ))
+                .id_string(),
+                "binance_futures_usd_order_books_l2_eth_usdt"
+            );
+            assert_eq!(
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    Liquidations
+                ))
+                .id_string(),
+                "binance_futures_usd_liquidations_btc_usdt"
+            );
+            assert_eq!(
+                Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    SourcedCandles(Interval::Hour1, CandleSource::MarkPrice)
+                ))
+                .id_string(),
+                "binance_futures_usd_sourced_candles_1h_mark_price_btc_usdt"
+            );
+            assert_eq!(
+                Subscription::from((
+                    Okx,
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    OrderBookSnapshots::new(Depth::Top5)
+                ))
+                .id_string(),
+                "okx_order_book_snapshots_top5_btc_usdt"
+            );
+            assert_eq!(
+                Subscription::from((Coinbase, "btc", "usd", InstrumentKind::Spot, PublicTrades))
+                    .id_string(),
+                "coinbase_public_trades_btc_usd"
+            );
+            assert_eq!(
+                Subscription::from((
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    Candles(Interval::Minute1)
+                ))
+                .with_tag("momentum")
+                .id_string(),
+                "binance_spot_candles_1m_btc_usdt#momentum"
+            );
         }
 
         #[test]
@@ -424,6 +983,451 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_binance_futures_usd_candles() {
+        use crate::exchange::binance::futures::BinanceFuturesUsd;
+        use crate::subscription::candle::Candles;
+
+        struct TestCase {
+            input: Subscription<BinanceFuturesUsd, Candles>,
+            expected: Result<Subscription<BinanceFuturesUsd, Candles>, SocketError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Valid BinanceFuturesUsd FuturePerpetual Candles subscription
+                input: Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    Candles(Interval::Minute1),
+                )),
+                expected: Ok(Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::FuturePerpetual,
+                    Candles(Interval::Minute1),
+                ))),
+            },
+            TestCase {
+                // TC1: Invalid BinanceFuturesUsd Spot Candles subscription
+                input: Subscription::from((
+                    BinanceFuturesUsd::default(),
+                    "btc",
+                    "usdt",
+                    InstrumentKind::Spot,
+                    Candles(Interval::Minute1),
+                )),
+                expected: Err(SocketError::Unsupported {
+                    entity: "",
+                    item: "".to_string(),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.validate();
+            match (actual, &test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(_), Err(_)) => {
+                    // Test passed
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_candle_intervals() {
+        use crate::exchange::{
+            binance::spot::BinanceSpot, bitfinex::Bitfinex, deribit::Deribit,
+            gateio::spot::GateioSpot, kraken::Kraken, okx::Okx,
+        };
+        use crate::subscription::candle::Candles;
+
+        fn validate<Exchange>(exchange: Exchange, kind: InstrumentKind, interval: Interval) -> bool
+        where
+            Exchange: StreamSelector<Candles>,
+        {
+            Subscription::from((exchange, "btc", "usdt", kind, Candles(interval)))
+                .validate()
+                .is_ok()
+        }
+
+        struct TestCase {
+            input: bool,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot supports 1M candles
+                input: validate(
+                    BinanceSpot::default(),
+                    InstrumentKind::Spot,
+                    Interval::Month1,
+                ),
+                expected: true,
+            },
+            TestCase {
+                // TC1: BinanceSpot does not support 3M candles
+                input: validate(
+                    BinanceSpot::default(),
+                    InstrumentKind::Spot,
+                    Interval::Month3,
+                ),
+                expected: false,
+            },
+            TestCase {
+                // TC2: Okx supports 3M candles
+                input: validate(Okx, InstrumentKind::Spot, Interval::Month3),
+                expected: true,
+            },
+            TestCase {
+                // TC3: Okx does not support 8h candles
+                input: validate(Okx, InstrumentKind::Spot, Interval::Hour8),
+                expected: false,
+            },
+            TestCase {
+                // TC4: GateioSpot supports 8h candles
+                input: validate(GateioSpot::default(), InstrumentKind::Spot, Interval::Hour8),
+                expected: true,
+            },
+            TestCase {
+                // TC5: GateioSpot does not support 3m candles
+                input: validate(
+                    GateioSpot::default(),
+                    InstrumentKind::Spot,
+                    Interval::Minute3,
+                ),
+                expected: false,
+            },
+            TestCase {
+                // TC6: Kraken supports 4h candles
+                input: validate(Kraken, InstrumentKind::Spot, Interval::Hour4),
+                expected: true,
+            },
+            TestCase {
+                // TC7: Kraken does not support 3m candles
+                input: validate(Kraken, InstrumentKind::Spot, Interval::Minute3),
+                expected: false,
+            },
+            TestCase {
+                // TC8: Deribit supports 12h candles
+                input: validate(Deribit, InstrumentKind::FuturePerpetual, Interval::Hour12),
+                expected: true,
+            },
+            TestCase {
+                // TC9: Deribit does not support 1w candles
+                input: validate(Deribit, InstrumentKind::FuturePerpetual, Interval::Week1),
+                expected: false,
+            },
+            TestCase {
+                // TC10: Bitfinex supports 1M candles
+                input: validate(Bitfinex, InstrumentKind::Spot, Interval::Month1),
+                expected: true,
+            },
+            TestCase {
+                // TC11: Bitfinex does not support 4h candles
+                input: validate(Bitfinex, InstrumentKind::Spot, Interval::Hour4),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input, test.expected, "TC{} failed", index);
+        }
+
+        // Unsupported Interval error names the rejected Interval
+        let error = Subscription::from((
+            Kraken,
+            "xbt",
+            "usd",
+            InstrumentKind::Spot,
+            Candles(Interval::Minute3),
+        ))
+        .validate()
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            SocketError::Unsupported {
+                entity: "kraken",
+                item: "candle interval 3m (supported candle intervals: 1m, 5m, 15m, 30m, 1h, 4h, 1d, 1w)"
+                    .to_string(),
+            }
+            .to_string()
+        );
+    }
+
+    mod subscription_id {
+        use super::*;
+        use crate::{
+            exchange::{
+                binance::{
+                    candle::BinanceCandle, channel::BinanceChannel, spot::BinanceSpot,
+                    trade::BinanceTrade,
+                },
+                okx::{trade::OkxTrades, Okx},
+            },
+            subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+            subscription::{candle::Candles, trade::PublicTrades},
+        };
+
+        const BASES: [&str; 4] = ["btc", "eth", "sol", "1inch"];
+        const QUOTES: [&str; 3] = ["usdt", "btc", "usdc"];
+
+        /// Assert the [`SubscriptionId`] computed up-front for every [`Subscription`] is equal to
+        /// both the instrument [`Map`] key, and the id derived from the associated payload.
+        fn assert_subscription_ids<Exchange, Kind, Input>(
+            subscriptions: Vec<Subscription<Exchange, Kind>>,
+            payload: impl Fn(&Subscription<Exchange, Kind>) -> String,
+        ) where
+            Exchange: Connector + Debug,
+            Kind: SubKind,
+            Subscription<Exchange, Kind>:
+                Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+            Input: for<'de> Deserialize<'de> + Identifier<Option<SubscriptionId>>,
+        {
+            let instrument_map = WebSocketSubMapper::map(&subscriptions).instrument_map;
+
+            for (index, subscription) in subscriptions.iter().enumerate() {
+                let expected = subscription.subscription_id();
+
+                let actual = instrument_map.find_ref(&expected);
+                assert_eq!(
+                    actual.ok(),
+                    Some(&subscription.instrument),
+                    "TC{} failed",
+                    index
+                );
+
+                let input = serde_json::from_str::<Input>(&payload(subscription)).unwrap();
+                assert_eq!(input.id(), Some(expected), "TC{} failed", index);
+            }
+        }
+
+        #[test]
+        fn test_subscription_id_matches_binance_trade_payload_id() {
+            let subscriptions = BASES
+                .into_iter()
+                .flat_map(|base| QUOTES.into_iter().map(move |quote| (base, quote)))
+                .map(|(base, quote)| {
+                    Subscription::from((
+                        BinanceSpot::default(),
+                        base,
+                        quote,
+                        InstrumentKind::Spot,
+                        PublicTrades,
+                    ))
+                })
+                .collect();
+
+            assert_subscription_ids::<_, _, BinanceTrade>(subscriptions, |subscription| {
+                let symbol = format!(
+                    "{}{}",
+                    subscription.instrument.base, subscription.instrument.quote
+                )
+                .to_uppercase();
+                format!(
+                    r#"{{"e":"trade","E":1,"s":"{symbol}","t":1,"p":"1.0","q":"1.0","b":1,"a":1,"T":1,"m":false,"M":true}}"#
+                )
+            });
+        }
+
+        #[test]
+        fn test_subscription_id_matches_binance_candle_payload_id() {
+            let subscriptions = BASES
+                .into_iter()
+                .flat_map(|base| {
+                    Interval::ALL
+                        .into_iter()
+                        .map(move |interval| (base, interval))
+                })
+                .map(|(base, interval)| {
+                    Subscription::from((
+                        BinanceSpot::default(),
+                        base,
+                        "usdt",
+                        InstrumentKind::Spot,
+                        Candles(interval),
+                    ))
+                })
+                .collect();
+
+            assert_subscription_ids::<_, _, BinanceCandle>(subscriptions, |subscription| {
+                let symbol = format!(
+                    "{}{}",
+                    subscription.instrument.base, subscription.instrument.quote
+                )
+                .to_uppercase();
+                let channel = BinanceChannel::candles(subscription.kind.0);
+                let interval = channel.0.trim_start_matches("@kline_");
+                format!(
+                    r#"{{"e":"kline","E":1,"s":"{symbol}","k":{{"t":0,"T":1,"s":"{symbol}","i":"{interval}","o":"1.0","c":"1.0","h":"1.0","l":"1.0","v":"1.0","q":"1.0","V":"1.0","Q":"1.0","n":1,"x":true}}}}"#
+                )
+            });
+        }
+
+        #[test]
+        fn test_subscription_id_matches_okx_trade_payload_id() {
+            let subscriptions = BASES
+                .into_iter()
+                .flat_map(|base| QUOTES.into_iter().map(move |quote| (base, quote)))
+                .flat_map(|(base, quote)| {
+                    [InstrumentKind::Spot, InstrumentKind::FuturePerpetual]
+                        .into_iter()
+                        .map(move |kind| Subscription::from((Okx, base, quote, kind, PublicTrades)))
+                })
+                .collect();
+
+            assert_subscription_ids::<_, _, OkxTrades>(subscriptions, |subscription| {
+                let instrument = &subscription.instrument;
+                let inst_id = match instrument.kind {
+                    InstrumentKind::Spot => format!("{}-{}", instrument.base, instrument.quote),
+                    InstrumentKind::FuturePerpetual => {
+                        format!("{}-{}-SWAP", instrument.base, instrument.quote)
+                    }
+                }
+                .to_uppercase();
+                format!(
+                    r#"{{"arg":{{"channel":"trades","instId":"{inst_id}"}},"data":[{{"instId":"{inst_id}","tradeId":"1","px":"1.0","sz":"1.0","side":"buy","ts":"1"}}]}}"#
+                )
+            });
+        }
+    }
+
+    mod interval {
+        use super::*;
+        use chrono::{DateTime, TimeZone, Utc};
+
+        fn datetime(ymd_hms: (i32, u32, u32, u32, u32, u32)) -> DateTime<Utc> {
+            let (year, month, day, hour, min, sec) = ymd_hms;
+            Utc.with_ymd_and_hms(year, month, day, hour, min, sec)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_interval_from_str() {
+            for interval in Interval::ALL {
+                let actual = interval.to_string().parse::<Interval>().unwrap();
+                assert_eq!(actual, interval);
+            }
+
+            assert_eq!("15m".parse::<Interval>().unwrap(), Interval::Minute15);
+            assert_eq!("1M".parse::<Interval>().unwrap(), Interval::Month1);
+            assert!("2m".parse::<Interval>().is_err());
+            assert!("gibberish".parse::<Interval>().is_err());
+        }
+
+        #[test]
+        fn test_interval_floor_and_next_close() {
+            struct TestCase {
+                interval: Interval,
+                input: DateTime<Utc>,
+                expected_floor: DateTime<Utc>,
+                expected_next_close: DateTime<Utc>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Minute15 mid-bucket
+                    interval: Interval::Minute15,
+                    input: datetime((2023, 3, 15, 10, 37, 12)),
+                    expected_floor: datetime((2023, 3, 15, 10, 30, 0)),
+                    expected_next_close: datetime((2023, 3, 15, 10, 44, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC1: Hour4 exactly on bucket start
+                    interval: Interval::Hour4,
+                    input: datetime((2023, 3, 15, 8, 0, 0)),
+                    expected_floor: datetime((2023, 3, 15, 8, 0, 0)),
+                    expected_next_close: datetime((2023, 3, 15, 11, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC2: Day1
+                    interval: Interval::Day1,
+                    input: datetime((2023, 3, 15, 23, 59, 59)),
+                    expected_floor: datetime((2023, 3, 15, 0, 0, 0)),
+                    expected_next_close: datetime((2023, 3, 15, 23, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC3: Week1 aligns to Monday 00:00 UTC (2023-03-15 is a Wednesday)
+                    interval: Interval::Week1,
+                    input: datetime((2023, 3, 15, 10, 0, 0)),
+                    expected_floor: datetime((2023, 3, 13, 0, 0, 0)),
+                    expected_next_close: datetime((2023, 3, 19, 23, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC4: Week1 on a Sunday stays in the week starting the previous Monday
+                    interval: Interval::Week1,
+                    input: datetime((2023, 1, 1, 12, 0, 0)),
+                    expected_floor: datetime((2022, 12, 26, 0, 0, 0)),
+                    expected_next_close: datetime((2023, 1, 1, 23, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC5: Month1 in a leap year February
+                    interval: Interval::Month1,
+                    input: datetime((2024, 2, 20, 5, 0, 0)),
+                    expected_floor: datetime((2024, 2, 1, 0, 0, 0)),
+                    expected_next_close: datetime((2024, 2, 29, 23, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC6: Month1 crossing the year boundary
+                    interval: Interval::Month1,
+                    input: datetime((2022, 12, 31, 23, 0, 0)),
+                    expected_floor: datetime((2022, 12, 1, 0, 0, 0)),
+                    expected_next_close: datetime((2022, 12, 31, 23, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+                TestCase {
+                    // TC7: Month3 aligns to the start of the quarter
+                    interval: Interval::Month3,
+                    input: datetime((2023, 5, 15, 0, 0, 0)),
+                    expected_floor: datetime((2023, 4, 1, 0, 0, 0)),
+                    expected_next_close: datetime((2023, 6, 30, 23, 59, 59))
+                        + Duration::milliseconds(999),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual_floor = test.interval.floor(test.input);
+                assert_eq!(
+                    actual_floor, test.expected_floor,
+                    "TC{} floor failed",
+                    index
+                );
+
+                let actual_next_close = test.interval.next_close(test.input);
+                assert_eq!(
+                    actual_next_close, test.expected_next_close,
+                    "TC{} next_close failed",
+                    index
+                );
+            }
+        }
+
+        #[test]
+        fn test_interval_duration() {
+            assert_eq!(Interval::Minute3.duration(), Duration::minutes(3));
+            assert_eq!(Interval::Hour12.duration(), Duration::hours(12));
+            assert_eq!(Interval::Week1.duration(), Duration::days(7));
+            assert_eq!(Interval::Month1.duration(), Duration::days(30));
+        }
+    }
+
     mod instrument_map {
         use super::*;
 
@@ -457,6 +1461,15 @@ mod tests {
 
             for (index, test) in cases.into_iter().enumerate() {
                 let actual = ids.find(&test.input);
+
+                // find_ref yields a reference to the same Instrument without cloning
+                assert_eq!(
+                    ids.find_ref(&test.input).ok(),
+                    actual.as_ref().ok(),
+                    "TC{} failed",
+                    index
+                );
+
                 match (actual, test.expected) {
                     (Ok(actual), Ok(expected)) => {
                         assert_eq!(actual, expected, "TC{} failed", index)
@@ -0,0 +1,185 @@
+//! [`Number`] is used for the price, amount & volume fields of the normalised Barter
+//! [`Candle`], [`PublicTrade`] & [`Level`] models. It defaults to `f64`, whereas enabling the
+//! `decimal` feature switches it to `rust_decimal::Decimal`, so the string encoded prices &
+//! amounts sent by exchanges (eg/ "0.00000001234") are parsed directly into a lossless
+//! [`Number`], without the representation error of an intermediate `f64`.
+//!
+//! # Non-Additive Feature
+//! The `decimal` feature changes the type of public fields, so it is not additive. Cargo unifies
+//! features across the whole dependency graph, so if any crate in a build enables `decimal`,
+//! every other crate depending on `barter-data` sees `rust_decimal::Decimal` fields, and code
+//! written against `f64` fields stops compiling. Libraries should therefore use [`from_f64`],
+//! [`to_f64`] & the other helpers in this module rather than assuming either type, and leave
+//! enabling `decimal` to the final binary crate.
+//!
+//! [`Candle`]: super::candle::Candle
+//! [`PublicTrade`]: super::trade::PublicTrade
+//! [`Level`]: super::book::Level
+
+use std::cmp::Ordering;
+
+/// `f64` numeric type of the normalised Barter models.
+#[cfg(not(feature = "decimal"))]
+pub type Number = f64;
+
+/// Lossless [`rust_decimal::Decimal`] numeric type of the normalised Barter models.
+#[cfg(feature = "decimal")]
+pub type Number = rust_decimal::Decimal;
+
+/// Zero [`Number`].
+#[cfg(not(feature = "decimal"))]
+pub const ZERO: Number = 0.0;
+
+/// Zero [`Number`].
+#[cfg(feature = "decimal")]
+pub const ZERO: Number = rust_decimal::Decimal::ZERO;
+
+/// Convert an `f64` into a [`Number`], used for exchanges & derived values that are only
+/// available as an `f64` (eg/ JSON number fields, index prices).
+///
+/// With the `decimal` feature enabled, the shortest decimal representation of the `f64` is used,
+/// and non-finite values convert to zero.
+pub fn from_f64(value: f64) -> Number {
+    #[cfg(not(feature = "decimal"))]
+    {
+        value
+    }
+
+    #[cfg(feature = "decimal")]
+    {
+        use std::str::FromStr;
+        value
+            .is_finite()
+            .then(|| {
+                rust_decimal::Decimal::from_str(&value.to_string())
+                    .or_else(|_| rust_decimal::Decimal::from_scientific(&format!("{value:e}")))
+                    .ok()
+            })
+            .flatten()
+            .unwrap_or(ZERO)
+    }
+}
+
+/// Convert a [`Number`] into an `f64` (eg/ for statistics & technical analysis indicators).
+pub fn to_f64(value: Number) -> f64 {
+    #[cfg(not(feature = "decimal"))]
+    {
+        value
+    }
+
+    #[cfg(feature = "decimal")]
+    {
+        use rust_decimal::prelude::ToPrimitive;
+        value.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+/// Determine if the [`Number`] is finite (ie/ neither infinite nor NaN). Always true with the
+/// `decimal` feature enabled.
+pub fn is_finite(value: Number) -> bool {
+    #[cfg(not(feature = "decimal"))]
+    {
+        value.is_finite()
+    }
+
+    #[cfg(feature = "decimal")]
+    {
+        let _ = value;
+        true
+    }
+}
+
+/// Determine if two [`Number`]s are equal, within `f64::EPSILON` if the `decimal` feature is
+/// disabled (eg/ to match the price of an OrderBook [`Level`](super::book::Level)).
+pub fn approx_eq(a: Number, b: Number) -> bool {
+    #[cfg(not(feature = "decimal"))]
+    {
+        f64::EPSILON > (a - b).abs()
+    }
+
+    #[cfg(feature = "decimal")]
+    {
+        a == b
+    }
+}
+
+/// Total [`Ordering`] of two [`Number`]s (eg/ to key a sorted OrderBook by price).
+pub fn total_cmp(a: &Number, b: &Number) -> Ordering {
+    #[cfg(not(feature = "decimal"))]
+    {
+        a.total_cmp(b)
+    }
+
+    #[cfg(feature = "decimal")]
+    {
+        a.cmp(b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_str_number_is_lossless() {
+        #[derive(serde::Deserialize)]
+        struct Payload {
+            #[serde(deserialize_with = "barter_integration::de::de_str")]
+            price: Number,
+        }
+
+        let actual = serde_json::from_str::<Payload>(r#"{"price":"0.00000001234"}"#)
+            .unwrap()
+            .price;
+
+        #[cfg(feature = "decimal")]
+        {
+            // Decimal survives exactly, including the scale of the string encoding
+            assert_eq!(actual, rust_decimal::Decimal::new(1234, 11));
+            assert_eq!(actual.to_string(), "0.00000001234");
+        }
+
+        #[cfg(not(feature = "decimal"))]
+        {
+            // f64 matches the behaviour of parsing the string as an f64
+            assert_eq!(actual, "0.00000001234".parse::<f64>().unwrap());
+            assert_eq!(actual, 0.00000001234);
+        }
+    }
+
+    #[test]
+    fn test_from_f64() {
+        struct TestCase {
+            input: f64,
+            expected: Number,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: shortest representation is retained
+                input: 0.1,
+                expected: "0.1".parse().unwrap(),
+            },
+            TestCase {
+                // TC1: small alt pair price
+                input: 0.00000001234,
+                expected: "0.00000001234".parse().unwrap(),
+            },
+            TestCase {
+                // TC2: large price
+                input: 42219.9,
+                expected: "42219.9".parse().unwrap(),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(from_f64(test.input), test.expected, "TC{} failed", index);
+            assert_eq!(
+                to_f64(from_f64(test.input)),
+                test.input,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use super::{SubKind, SubKindId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`OptionSummary`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events for every option contract of an
+/// instrument family.
+///
+/// Option families are identified by their base & quote (eg/ "btc", "usd") with an
+/// [`InstrumentKind::Spot`](barter_integration::model::InstrumentKind).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OptionSummaries;
+
+impl std::fmt::Display for OptionSummaries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::OptionSummaries)
+    }
+}
+
+impl SubKind for OptionSummaries {
+    type Event = OptionSummary;
+}
+
+/// Normalised Barter [`OptionSummary`] model containing the greeks & implied volatilities of a
+/// single option contract.
+///
+/// Note: `contract` is the exchange specific identifier of the option contract
+/// (eg/ "BTC-USD-241227-50000-C"), since each [`OptionSummary`] of an instrument family shares
+/// the same [`Instrument`](barter_integration::model::Instrument).
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OptionSummary {
+    pub contract: String,
+    pub delta: f64,
+    pub gamma: f64,
+    pub theta: f64,
+    pub vega: f64,
+    pub mark_volatility: f64,
+    pub bid_volatility: f64,
+    pub ask_volatility: f64,
+    pub forward_price: f64,
+    pub time: DateTime<Utc>,
+}
@@ -0,0 +1,115 @@
+use super::{SubKind, SubKindId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Ticker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Tickers;
+
+impl std::fmt::Display for Tickers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::Tickers)
+    }
+}
+
+impl SubKind for Tickers {
+    type Event = Ticker;
+}
+
+/// Normalised Barter [`Ticker`] model containing rolling 24h statistics.
+///
+/// Note: `volume_24h` is denominated in the base currency.
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Ticker {
+    pub last_price: f64,
+    pub open_24h: f64,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub volume_24h: f64,
+    pub quote_volume_24h: Option<f64>,
+    pub price_change_percent_24h: f64,
+    pub time: DateTime<Utc>,
+}
+
+impl Ticker {
+    /// Calculate the percentage price change between the provided 24h open price & last price.
+    ///
+    /// Returns `0.0` if the open price is zero (eg/ a newly listed instrument).
+    pub fn price_change_percent(open: f64, last: f64) -> f64 {
+        if open == 0.0 {
+            0.0
+        } else {
+            (last - open) / open * 100.0
+        }
+    }
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`IndexTicker`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Index instruments are identified by their base & quote (eg/ "btc", "usdt") with an
+/// [`InstrumentKind::Spot`](barter_integration::model::InstrumentKind).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct IndexTickers;
+
+impl std::fmt::Display for IndexTickers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::IndexTickers)
+    }
+}
+
+impl SubKind for IndexTickers {
+    type Event = IndexTicker;
+}
+
+/// Normalised Barter [`IndexTicker`] model containing the latest index price & rolling 24h
+/// statistics of an exchange index (eg/ the price of BTC aggregated from several spot markets).
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct IndexTicker {
+    pub index_price: f64,
+    pub open_24h: f64,
+    pub high_24h: f64,
+    pub low_24h: f64,
+    pub time: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ticker_price_change_percent() {
+        struct TestCase {
+            open: f64,
+            last: f64,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: price increase
+                open: 100.0,
+                last: 110.0,
+                expected: 10.0,
+            },
+            TestCase {
+                // TC1: price decrease
+                open: 200.0,
+                last: 150.0,
+                expected: -25.0,
+            },
+            TestCase {
+                // TC2: zero open price
+                open: 0.0,
+                last: 150.0,
+                expected: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = Ticker::price_change_percent(test.open, test.last);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,5 +1,6 @@
-use super::SubKind;
-use barter_integration::model::Side;
+use super::{number::Number, SubKind, SubKindId};
+use crate::exchange::Connector;
+use barter_integration::{error::SocketError, model::Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use serde::{Deserialize, Serialize};
 
@@ -8,15 +9,584 @@ use serde::{Deserialize, Serialize};
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
 pub struct PublicTrades;
 
+impl std::fmt::Display for PublicTrades {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::PublicTrades)
+    }
+}
+
 impl SubKind for PublicTrades {
     type Event = PublicTrade;
 }
 
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields aggregated [`PublicTrade`]
+/// [`MarketEvent<T>`](crate::event::MarketEvent) events.
+///
+/// Aggregated trades compress fills of the same taker order at the same price & side into a
+/// single [`PublicTrade`], significantly reducing the message rate of high volume markets. The
+/// [`PublicTrade`] `id` is the exchange aggregate trade id.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct PublicTradesAggregated;
+
+impl std::fmt::Display for PublicTradesAggregated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::PublicTradesAggregated)
+    }
+}
+
+impl SubKind for PublicTradesAggregated {
+    type Event = PublicTrade;
+}
+
+/// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields every individual
+/// [`PublicTrade`] [`MarketEvent<T>`](crate::event::MarketEvent), including each fill of an
+/// aggregated taker order.
+///
+/// Only supported by exchanges that publish full fidelity trades separately from their default
+/// trades channel (eg/ the Okx "trades-all" channel, whereas the Okx "trades" channel only
+/// publishes the first fill of an aggregation).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, DeSubKind, SerSubKind)]
+pub struct PublicTradesAll;
+
+impl std::fmt::Display for PublicTradesAll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", SubKindId::PublicTradesAll)
+    }
+}
+
+impl SubKind for PublicTradesAll {
+    type Event = PublicTrade;
+
+    fn validate_exchange<Exchange>(&self) -> Result<(), SocketError>
+    where
+        Exchange: Connector,
+    {
+        match Exchange::ID.supports(SubKindId::PublicTradesAll) {
+            true => Ok(()),
+            false => Err(SocketError::Unsupported {
+                entity: Exchange::ID.as_str(),
+                item: format!("{self:?}"),
+            }),
+        }
+    }
+}
+
 /// Normalised Barter [`PublicTrade`] model.
+///
+/// The [`PublicTrade`] `side` is always the taker (aggressor) [`Side`], ie/ an aggressive buy
+/// lifting the ask is a [`Side::Buy`], regardless of how the exchange encodes it. See
+/// [`TakerSide`] for the shared conversions used by every exchange.
+///
+/// Optional fields are only populated for exchanges that provide them, and are omitted when
+/// serialising if `None`, so consumers that only read the price, amount & side are unaffected.
+/// Note that [`PublicTrade`] is `Clone` but not `Copy`, since the `id` & order ids are `String`s.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PublicTrade {
     pub id: String,
-    pub price: f64,
-    pub amount: f64,
+    pub price: Number,
+    pub amount: Number,
+    /// Taker (aggressor) [`Side`]. Every supported exchange indicates the aggressor, so an
+    /// exchange that does not must not be added with a guessed [`Side`].
     pub side: Side,
+    /// Exchange order id of the buyer, if provided by the exchange (eg/ Binance spot, Coinbase).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub buyer_order_id: Option<String>,
+    /// Exchange order id of the seller, if provided by the exchange (eg/ Binance spot, Coinbase).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seller_order_id: Option<String>,
+    /// [`TakerOrderKind`] of the order that initiated the trade, if provided by the exchange
+    /// (eg/ Kraken).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taker_order_kind: Option<TakerOrderKind>,
+}
+
+/// Kind of the taker order that initiated a [`PublicTrade`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TakerOrderKind {
+    Market,
+    Limit,
+}
+
+/// Shared conversions from the various exchange aggressor encodings to the normalised taker
+/// (aggressor) [`Side`] of a [`PublicTrade`].
+///
+/// Implemented for [`Side`], so with this trait in scope the conversions read as
+/// `Side::from_buyer_is_maker(true)`.
+///
+/// | Encoding                 | Exchange (eg/)                          | Conversion                          |
+/// |--------------------------|-----------------------------------------|-------------------------------------|
+/// | "buyer is maker" flag    | Binance                                 | [`TakerSide::from_buyer_is_maker`]  |
+/// | taker side String        | Okx, Bybit, GateioSpot, Kucoin, Kraken  | [`TakerSide::from_taker_side`]      |
+/// | maker side String        | Coinbase                                | [`TakerSide::from_maker_side`]      |
+/// | sign of the traded amount| Bitfinex, GateioFutures                 | [`TakerSide::from_signed_amount`]   |
+/// | numeric taker code       | Bitstamp, Mexc                          | exchange specific deserialiser      |
+pub trait TakerSide: Sized {
+    /// Taker [`Side`] from a "buyer is maker" flag, where a maker buyer means the taker sold.
+    fn from_buyer_is_maker(buyer_is_maker: bool) -> Self;
+
+    /// Taker [`Side`] from an exchange taker side String (eg/ "buy", "Sell", "b", "ASK").
+    ///
+    /// Returns `None` if the String is not a recognised side.
+    fn from_taker_side(side: &str) -> Option<Self>;
+
+    /// Taker [`Side`] from an exchange maker side String (eg/ "sell"), where the taker is the
+    /// counterparty of the maker.
+    ///
+    /// Returns `None` if the String is not a recognised side.
+    fn from_maker_side(side: &str) -> Option<Self>;
+
+    /// Taker [`Side`] from the sign of a traded amount, where a positive amount (including
+    /// +0.0) is a buy and a negative amount is a sell.
+    fn from_signed_amount(amount: Number) -> Self;
+}
+
+impl TakerSide for Side {
+    fn from_buyer_is_maker(buyer_is_maker: bool) -> Self {
+        match buyer_is_maker {
+            true => Side::Sell,
+            false => Side::Buy,
+        }
+    }
+
+    fn from_taker_side(side: &str) -> Option<Self> {
+        match side.to_ascii_lowercase().as_str() {
+            "buy" | "b" | "bid" => Some(Side::Buy),
+            "sell" | "s" | "ask" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+
+    fn from_maker_side(side: &str) -> Option<Self> {
+        Self::from_taker_side(side).map(|maker| match maker {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        })
+    }
+
+    fn from_signed_amount(amount: Number) -> Self {
+        match amount.is_sign_positive() {
+            true => Side::Buy,
+            false => Side::Sell,
+        }
+    }
+}
+
+/// Deserialize a "buyer is maker" boolean field (eg/ Binance "m") as the taker [`Side`].
+///
+/// Variants:
+/// buyer_is_maker => Side::Sell
+/// !buyer_is_maker => Side::Buy
+pub fn de_side_from_buyer_is_maker<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <bool as Deserialize>::deserialize(deserializer).map(Side::from_buyer_is_maker)
+}
+
+/// Deserialize a taker side String field (eg/ "buy", "Sell", "ASK") as the taker [`Side`].
+pub fn de_side_from_taker_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let side = <std::borrow::Cow<'de, str> as Deserialize>::deserialize(deserializer)?;
+    Side::from_taker_side(&side).ok_or_else(|| {
+        serde::de::Error::invalid_value(serde::de::Unexpected::Str(&side), &"taker side")
+    })
+}
+
+/// Deserialize a maker side String field (eg/ Coinbase "side") as the taker [`Side`].
+pub fn de_side_from_maker_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let side = <std::borrow::Cow<'de, str> as Deserialize>::deserialize(deserializer)?;
+    Side::from_maker_side(&side).ok_or_else(|| {
+        serde::de::Error::invalid_value(serde::de::Unexpected::Str(&side), &"maker side")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::number::{from_f64, ZERO};
+
+    #[test]
+    fn test_public_trades_all_validate_exchange() {
+        use crate::exchange::{binance::spot::BinanceSpot, coinbase::Coinbase, okx::Okx};
+
+        struct TestCase {
+            input: Result<(), SocketError>,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Okx supports the trades-all channel
+                input: PublicTradesAll.validate_exchange::<Okx>(),
+                expected: true,
+            },
+            TestCase {
+                // TC1: BinanceSpot does not support PublicTradesAll
+                input: PublicTradesAll.validate_exchange::<BinanceSpot>(),
+                expected: false,
+            },
+            TestCase {
+                // TC2: Coinbase does not support PublicTradesAll
+                input: PublicTradesAll.validate_exchange::<Coinbase>(),
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(test.input.is_ok(), test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_taker_side_from_buyer_is_maker() {
+        // Buyer is maker, so the aggressor sold into the bid
+        assert_eq!(Side::from_buyer_is_maker(true), Side::Sell);
+        // Seller is maker, so the aggressor bought from the ask
+        assert_eq!(Side::from_buyer_is_maker(false), Side::Buy);
+    }
+
+    #[test]
+    fn test_taker_side_from_taker_side() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Side>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: lowercase buy
+                input: "buy",
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC1: capitalised Sell
+                input: "Sell",
+                expected: Some(Side::Sell),
+            },
+            TestCase {
+                // TC2: uppercase BUY
+                input: "BUY",
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC3: abbreviated b
+                input: "b",
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC4: abbreviated s
+                input: "s",
+                expected: Some(Side::Sell),
+            },
+            TestCase {
+                // TC5: BID taker is a buy
+                input: "BID",
+                expected: Some(Side::Buy),
+            },
+            TestCase {
+                // TC6: ASK taker is a sell
+                input: "ASK",
+                expected: Some(Side::Sell),
+            },
+            TestCase {
+                // TC7: unknown side
+                input: "unknown",
+                expected: None,
+            },
+            TestCase {
+                // TC8: empty side
+                input: "",
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                Side::from_taker_side(test.input),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_taker_side_from_maker_side() {
+        assert_eq!(Side::from_maker_side("sell"), Some(Side::Buy));
+        assert_eq!(Side::from_maker_side("buy"), Some(Side::Sell));
+        assert_eq!(Side::from_maker_side("unknown"), None);
+    }
+
+    #[test]
+    fn test_taker_side_from_signed_amount() {
+        assert_eq!(Side::from_signed_amount(from_f64(0.5)), Side::Buy);
+        assert_eq!(Side::from_signed_amount(ZERO), Side::Buy);
+        assert_eq!(Side::from_signed_amount(from_f64(-0.5)), Side::Sell);
+    }
+
+    #[test]
+    fn test_de_side() {
+        #[derive(Debug, Deserialize)]
+        struct Sides {
+            #[serde(deserialize_with = "de_side_from_buyer_is_maker")]
+            m: Side,
+            #[serde(deserialize_with = "de_side_from_taker_side")]
+            taker: Side,
+            #[serde(deserialize_with = "de_side_from_maker_side")]
+            maker: Side,
+        }
+
+        let sides =
+            serde_json::from_str::<Sides>(r#"{"m":false,"taker":"Buy","maker":"sell"}"#).unwrap();
+        assert_eq!(
+            (sides.m, sides.taker, sides.maker),
+            (Side::Buy, Side::Buy, Side::Buy)
+        );
+
+        assert!(
+            serde_json::from_str::<Sides>(r#"{"m":false,"taker":"up","maker":"sell"}"#).is_err()
+        );
+        assert!(
+            serde_json::from_str::<Sides>(r#"{"m":false,"taker":"buy","maker":"up"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_public_trade_serde_backwards_compatible() {
+        /// PublicTrade model used by older consumers prior to the addition of the optional fields.
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct LegacyPublicTrade {
+            id: String,
+            price: Number,
+            amount: Number,
+            side: Side,
+        }
+
+        let trade = |buyer_order_id: Option<&str>| PublicTrade {
+            id: "1".to_string(),
+            price: from_f64(100.0),
+            amount: from_f64(0.5),
+            side: Side::Buy,
+            buyer_order_id: buyer_order_id.map(str::to_string),
+            seller_order_id: None,
+            taker_order_kind: None,
+        };
+        let legacy = serde_json::json!({
+            "id": "1",
+            "price": from_f64(100.0),
+            "amount": from_f64(0.5),
+            "side": "Buy"
+        });
+
+        // Optional fields are omitted if None, so serialised output is unchanged
+        assert_eq!(serde_json::to_value(trade(None)).unwrap(), legacy);
+
+        // Enriched PublicTrade round trips, & older consumers ignore the new fields
+        let enriched = trade(Some("10108767791"));
+        let serialised = serde_json::to_string(&enriched).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PublicTrade>(&serialised).unwrap(),
+            enriched
+        );
+        assert_eq!(
+            serde_json::from_str::<LegacyPublicTrade>(&serialised).unwrap(),
+            LegacyPublicTrade {
+                id: "1".to_string(),
+                price: from_f64(100.0),
+                amount: from_f64(0.5),
+                side: Side::Buy,
+            }
+        );
+
+        // PublicTrades serialised by older producers deserialise w/ the new fields as None
+        assert_eq!(
+            serde_json::from_value::<PublicTrade>(legacy).unwrap(),
+            trade(None)
+        );
+    }
+
+    #[test]
+    fn test_public_trade_side_cross_exchange() {
+        use crate::{
+            event::MarketIter,
+            exchange::{
+                binance::trade::{BinanceAggTrade, BinanceTrade},
+                bitfinex::trade::BitfinexTrade,
+                bitstamp::trade::BitstampTrades,
+                bybit::trade::BybitTrades,
+                coinbase::trade::CoinbaseTrade,
+                deribit::trade::DeribitTrades,
+                gateio::{futures::trade::GateioFuturesTrades, spot::trade::GateioSpotTrade},
+                huobi::trade::HuobiTrades,
+                kraken::{futures::trade::KrakenFuturesTrades, trade::KrakenTrades},
+                kucoin::trade::KucoinTrades,
+                mexc::trade::MexcTrades,
+                okx::trade::OkxTrades,
+                upbit::trade::UpbitTrade,
+                ExchangeId,
+            },
+        };
+        use barter_integration::model::{Instrument, InstrumentKind};
+        use serde::de::DeserializeOwned;
+
+        /// Deserialise the exchange trade message & collect the normalised [`PublicTrade`] sides.
+        fn taker_sides<T>(exchange: ExchangeId, input: &str) -> Vec<Side>
+        where
+            T: DeserializeOwned,
+            MarketIter<PublicTrade>: From<(ExchangeId, Instrument, T)>,
+        {
+            let trade = serde_json::from_str::<T>(input).unwrap();
+            let instrument = Instrument::from(("btc", "usd", InstrumentKind::Spot));
+            MarketIter::<PublicTrade>::from((exchange, instrument, trade))
+                .0
+                .into_iter()
+                .map(|event| event.unwrap().kind.side)
+                .collect()
+        }
+
+        struct TestCase {
+            exchange: ExchangeId,
+            /// Taker buy order lifting the ask.
+            aggressive_buy: &'static str,
+            /// Taker sell order hitting the bid.
+            aggressive_sell: &'static str,
+            sides: fn(ExchangeId, &str) -> Vec<Side>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Binance "m" buyer is maker flag
+                exchange: ExchangeId::BinanceSpot,
+                aggressive_buy: r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"10000.19","q":"0.239","b":2,"a":3,"T":1649324825173,"m":false,"M":true}"#,
+                aggressive_sell: r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,"p":"10000.19","q":"0.239","b":2,"a":3,"T":1649324825173,"m":true,"M":true}"#,
+                sides: taker_sides::<BinanceTrade>,
+            },
+            TestCase {
+                // TC1: Binance aggregated trade "m" buyer is maker flag
+                exchange: ExchangeId::BinanceFuturesUsd,
+                aggressive_buy: r#"{"e":"aggTrade","E":1672515782136,"s":"BTCUSDT","a":1,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":false}"#,
+                aggressive_sell: r#"{"e":"aggTrade","E":1672515782136,"s":"BTCUSDT","a":1,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":true}"#,
+                sides: taker_sides::<BinanceAggTrade>,
+            },
+            TestCase {
+                // TC2: Bitfinex sign of the amount
+                exchange: ExchangeId::Bitfinex,
+                aggressive_buy: r#"[1225484398,1665452200022,0.08980641,19027.02807752]"#,
+                aggressive_sell: r#"[1225484398,1665452200022,-0.08980641,19027.02807752]"#,
+                sides: taker_sides::<BitfinexTrade>,
+            },
+            TestCase {
+                // TC3: Bitstamp "type" taker code
+                exchange: ExchangeId::Bitstamp,
+                aggressive_buy: r#"{"data":{"id":1,"amount":0.1,"price":28075,"type":0,"microtimestamp":"1680522427432000","buy_order_id":2,"sell_order_id":3},"channel":"live_trades_btcusd","event":"trade"}"#,
+                aggressive_sell: r#"{"data":{"id":1,"amount":0.1,"price":28075,"type":1,"microtimestamp":"1680522427432000","buy_order_id":2,"sell_order_id":3},"channel":"live_trades_btcusd","event":"trade"}"#,
+                sides: taker_sides::<BitstampTrades>,
+            },
+            TestCase {
+                // TC4: Bybit "S" taker side
+                exchange: ExchangeId::BybitPerpetualsUsd,
+                aggressive_buy: r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{"T":1672304486865,"s":"BTCUSDT","S":"Buy","v":"0.001","p":"16578.50","L":"PlusTick","i":"20f43950-d8dd-5b31-9112-a178eb6023af","BT":false}]}"#,
+                aggressive_sell: r#"{"topic":"publicTrade.BTCUSDT","type":"snapshot","ts":1672304486868,"data":[{"T":1672304486865,"s":"BTCUSDT","S":"Sell","v":"0.001","p":"16578.50","L":"PlusTick","i":"20f43950-d8dd-5b31-9112-a178eb6023af","BT":false}]}"#,
+                sides: taker_sides::<BybitTrades>,
+            },
+            TestCase {
+                // TC5: Coinbase "side" maker side, so an up-tick has a maker "sell"
+                exchange: ExchangeId::Coinbase,
+                aggressive_buy: r#"{"type":"match","trade_id":10,"sequence":50,"maker_order_id":"maker","taker_order_id":"taker","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"5.23512","price":"400.23","side":"sell"}"#,
+                aggressive_sell: r#"{"type":"match","trade_id":10,"sequence":50,"maker_order_id":"maker","taker_order_id":"taker","time":"2014-11-07T08:19:27.028459Z","product_id":"BTC-USD","size":"5.23512","price":"400.23","side":"buy"}"#,
+                sides: taker_sides::<CoinbaseTrade>,
+            },
+            TestCase {
+                // TC6: Deribit "direction" taker side
+                exchange: ExchangeId::Deribit,
+                aggressive_buy: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.raw","data":[{"trade_seq":1,"trade_id":"1","timestamp":1590484156350,"tick_direction":0,"price":8950.0,"mark_price":8948.9,"instrument_name":"BTC-PERPETUAL","index_price":8955.88,"direction":"buy","amount":10.0}]}}"#,
+                aggressive_sell: r#"{"jsonrpc":"2.0","method":"subscription","params":{"channel":"trades.BTC-PERPETUAL.raw","data":[{"trade_seq":1,"trade_id":"1","timestamp":1590484156350,"tick_direction":2,"price":8950.0,"mark_price":8948.9,"instrument_name":"BTC-PERPETUAL","index_price":8955.88,"direction":"sell","amount":10.0}]}}"#,
+                sides: taker_sides::<DeribitTrades>,
+            },
+            TestCase {
+                // TC7: GateioSpot "side" taker side
+                exchange: ExchangeId::GateioSpot,
+                aggressive_buy: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"buy","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705"}}"#,
+                aggressive_sell: r#"{"time":1606292218,"time_ms":1606292218231,"channel":"spot.trades","event":"update","result":{"id":309143071,"create_time":1606292218,"create_time_ms":"1606292218213.4578","side":"sell","currency_pair":"GT_USDT","amount":"16.47","price":"0.4705"}}"#,
+                sides: taker_sides::<GateioSpotTrade>,
+            },
+            TestCase {
+                // TC8: GateioFuturesUsd sign of the "size"
+                exchange: ExchangeId::GateioFuturesUsd,
+                aggressive_buy: r#"{"time":1669843487,"time_ms":1669843487733,"channel":"futures.trades","event":"update","result":[{"contract":"ETH_USDT","create_time":1669843487,"create_time_ms":1669843487724,"id":180276616,"price":"1287","size":3}]}"#,
+                aggressive_sell: r#"{"time":1669843487,"time_ms":1669843487733,"channel":"futures.trades","event":"update","result":[{"contract":"ETH_USDT","create_time":1669843487,"create_time_ms":1669843487724,"id":180276616,"price":"1287","size":-3}]}"#,
+                sides: taker_sides::<GateioFuturesTrades>,
+            },
+            TestCase {
+                // TC9: HuobiSpot "direction" taker side
+                exchange: ExchangeId::HuobiSpot,
+                aggressive_buy: r#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175,"tick":{"id":137005445109,"ts":1630994963173,"data":[{"ts":1630994963173,"tradeId":102523573486,"amount":0.006754,"price":52648.62,"direction":"buy"}]}}"#,
+                aggressive_sell: r#"{"ch":"market.btcusdt.trade.detail","ts":1630994963175,"tick":{"id":137005445109,"ts":1630994963173,"data":[{"ts":1630994963173,"tradeId":102523573486,"amount":0.006754,"price":52648.62,"direction":"sell"}]}}"#,
+                sides: taker_sides::<HuobiTrades>,
+            },
+            TestCase {
+                // TC10: Kraken abbreviated taker side
+                exchange: ExchangeId::Kraken,
+                aggressive_buy: r#"[0,[["5541.2","0.1","1534614057.321597","b","m",""]],"trade","XBT/USD"]"#,
+                aggressive_sell: r#"[0,[["5541.2","0.1","1534614057.321597","s","m",""]],"trade","XBT/USD"]"#,
+                sides: taker_sides::<KrakenTrades>,
+            },
+            TestCase {
+                // TC11: KrakenFutures "side" taker side
+                exchange: ExchangeId::KrakenFutures,
+                aggressive_buy: r#"{"feed":"trade","product_id":"PI_XBTUSD","uid":"uid-655509","side":"buy","type":"fill","seq":655509,"time":1612269657781,"qty":440,"price":34893.0}"#,
+                aggressive_sell: r#"{"feed":"trade","product_id":"PI_XBTUSD","uid":"uid-655509","side":"sell","type":"fill","seq":655509,"time":1612269657781,"qty":440,"price":34893.0}"#,
+                sides: taker_sides::<KrakenFuturesTrades>,
+            },
+            TestCase {
+                // TC12: KucoinSpot "side" taker side
+                exchange: ExchangeId::KucoinSpot,
+                aggressive_buy: r#"{"type":"message","topic":"/market/match:BTC-USDT","subject":"trade.l3match","data":{"side":"buy","price":"0.082","size":"0.01","tradeId":"5c24c5da03aa673885cd67aa","time":"1545913818099033203"}}"#,
+                aggressive_sell: r#"{"type":"message","topic":"/market/match:BTC-USDT","subject":"trade.l3match","data":{"side":"sell","price":"0.082","size":"0.01","tradeId":"5c24c5da03aa673885cd67aa","time":"1545913818099033203"}}"#,
+                sides: taker_sides::<KucoinTrades>,
+            },
+            TestCase {
+                // TC13: MexcSpot "tradeType" taker code (decoded from protobuf)
+                exchange: ExchangeId::MexcSpot,
+                aggressive_buy: r#"{"channel":"spot@public.deals.v3.api.pb@BTCUSDT","sendTime":1736409765051,"publicDeals":{"deals":[{"price":"93220.00","quantity":"0.04438243","tradeType":1,"time":1736409765046}]}}"#,
+                aggressive_sell: r#"{"channel":"spot@public.deals.v3.api.pb@BTCUSDT","sendTime":1736409765051,"publicDeals":{"deals":[{"price":"93220.00","quantity":"0.04438243","tradeType":2,"time":1736409765046}]}}"#,
+                sides: taker_sides::<MexcTrades>,
+            },
+            TestCase {
+                // TC14: Okx "side" taker side
+                exchange: ExchangeId::Okx,
+                aggressive_buy: r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306","side":"buy","ts":"1630048897897"}]}"#,
+                aggressive_sell: r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306","side":"sell","ts":"1630048897897"}]}"#,
+                sides: taker_sides::<OkxTrades>,
+            },
+            TestCase {
+                // TC15: UpbitSpot "ask_bid" taker side
+                exchange: ExchangeId::UpbitSpot,
+                aggressive_buy: r#"{"type":"trade","code":"KRW-BTC","timestamp":1676965262177,"trade_timestamp":1676965262139,"trade_price":31287000.0,"trade_volume":0.03993413,"ask_bid":"BID","sequential_id":1676965262139000,"stream_type":"REALTIME"}"#,
+                aggressive_sell: r#"{"type":"trade","code":"KRW-BTC","timestamp":1676965262177,"trade_timestamp":1676965262139,"trade_price":31287000.0,"trade_volume":0.03993413,"ask_bid":"ASK","sequential_id":1676965262139000,"stream_type":"REALTIME"}"#,
+                sides: taker_sides::<UpbitTrade>,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                (test.sides)(test.exchange, test.aggressive_buy),
+                vec![Side::Buy],
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                (test.sides)(test.exchange, test.aggressive_sell),
+                vec![Side::Sell],
+                "TC{} failed",
+                index
+            );
+        }
+    }
 }
@@ -0,0 +1,195 @@
+use crate::{
+    decoder::DecodingParser,
+    error::DataError,
+    event::MarketEvent,
+    exchange::Connector,
+    subscriber::mapper::{SubscriptionMapper, WebSocketSubMapper},
+    subscription::{Map, SubKind, Subscription},
+    transformer::ExchangeTransformer,
+    Identifier,
+};
+use barter_integration::{
+    error::SocketError,
+    model::Instrument,
+    protocol::{websocket::WsMessage, StreamParser},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::Debug,
+    path::{Path, PathBuf},
+};
+use tokio::sync::mpsc;
+
+/// Root directory of the barter-data fixtures, laid out as
+/// `tests/fixtures/<exchange>/<kind>/<name>.json`.
+pub const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Raw exchange frames of a single `<exchange>/<kind>` fixtures directory, keyed by file name
+/// without the ".json" extension (eg/ "spot_trade").
+///
+/// Fixture files are the verbatim frame sent by the exchange server, so malformed frames (eg/
+/// truncated JSON) are stored as is.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct Fixtures {
+    pub dir: PathBuf,
+    pub frames: BTreeMap<String, String>,
+}
+
+impl Fixtures {
+    /// Load the barter-data [`FIXTURES_DIR`] fixtures of the provided exchange & kind (eg/
+    /// "binance" & "trades").
+    ///
+    /// Panics if the fixtures directory cannot be read.
+    pub fn load(exchange: &str, kind: &str) -> Self {
+        Self::load_from(FIXTURES_DIR, exchange, kind).unwrap_or_else(|error| {
+            panic!("failed to load {exchange}/{kind} fixtures from {FIXTURES_DIR}: {error}")
+        })
+    }
+
+    /// Load the fixtures of the provided exchange & kind from the `root` fixtures directory (eg/
+    /// the fixtures of a downstream crate).
+    pub fn load_from(
+        root: impl AsRef<Path>,
+        exchange: &str,
+        kind: &str,
+    ) -> Result<Self, std::io::Error> {
+        let dir = root.as_ref().join(exchange).join(kind);
+
+        let mut frames = BTreeMap::new();
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path
+                .extension()
+                .map_or(true, |extension| extension != "json")
+            {
+                continue;
+            }
+
+            let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            frames.insert(name.to_owned(), std::fs::read_to_string(&path)?);
+        }
+
+        Ok(Self { dir, frames })
+    }
+
+    /// Raw frame of the fixture with the provided name.
+    ///
+    /// Panics if no such fixture exists, so renamed fixtures cannot silently skip a test.
+    pub fn get(&self, name: &str) -> &str {
+        self.frames.get(name).unwrap_or_else(|| {
+            panic!(
+                "fixture {name}.json does not exist in {}",
+                self.dir.display()
+            )
+        })
+    }
+}
+
+/// Parse a raw text frame into the exchange specific `Output` type, exactly as an
+/// [`ExchangeWsStream`](crate::ExchangeWsStream) of the exchange would.
+///
+/// Malformed frames yield a [`SocketError::Deserialise`] containing the frame.
+pub fn parse_frame<Exchange, Output>(frame: &str) -> Result<Output, SocketError>
+where
+    Exchange: Connector,
+    Output: DeserializeOwned,
+{
+    DecodingParser::<Exchange::Decoder>::parse(Ok(WsMessage::Text(frame.to_owned())))
+        .expect("text frames are always parsed")
+}
+
+/// Assert the provided value serialises to JSON that deserialises back to an equal value (eg/
+/// normalised [`MarketEvent`]s recorded & replayed via the
+/// [`StreamRecorder`](crate::streams::recorder::StreamRecorder)).
+///
+/// Returns the deserialised value.
+pub fn assert_deserialize_roundtrip<T>(value: &T) -> T
+where
+    T: Serialize + DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).expect("value serialises to JSON");
+    let actual = serde_json::from_str::<T>(&json)
+        .unwrap_or_else(|error| panic!("failed to deserialise {json}: {error}"));
+    assert_eq!(&actual, value, "deserialised value differs from {json}");
+    actual
+}
+
+/// Construct the [`Map<Instrument>`] used by the transformer of a connection actioning the
+/// provided [`Subscription`]s.
+pub fn instrument_map<Exchange, Kind>(
+    subscriptions: &[Subscription<Exchange, Kind>],
+) -> Map<Instrument>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Subscription<Exchange, Kind>: Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    WebSocketSubMapper::map(subscriptions).instrument_map
+}
+
+/// Feed the raw frames through a new exchange `Transformer` constructed with the prepared
+/// [`Map<Instrument>`], collecting every emitted [`MarketEvent`] & [`DataError`] in order.
+///
+/// Frames that fail to parse yield a [`DataError::Socket`], as they would from an
+/// [`ExchangeWsStream`](crate::ExchangeWsStream). Messages the transformer sends back to the
+/// exchange (eg/ OrderBook snapshot requests) are discarded.
+pub async fn transform_frames<Exchange, Kind, Transformer, Frames>(
+    instrument_map: Map<Instrument>,
+    frames: Frames,
+) -> Vec<Result<MarketEvent<Kind::Event>, DataError>>
+where
+    Exchange: Connector,
+    Kind: SubKind,
+    Transformer: ExchangeTransformer<Exchange, Kind>,
+    Frames: IntoIterator,
+    Frames::Item: AsRef<str>,
+{
+    let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+    let mut transformer = Transformer::new(ws_sink_tx, instrument_map)
+        .await
+        .expect("exchange transformer initialises");
+
+    let mut output = Vec::new();
+    for frame in frames {
+        match parse_frame::<Exchange, Transformer::Input>(frame.as_ref()) {
+            Ok(input) => output.extend(transformer.transform(input)),
+            Err(error) => output.push(Err(DataError::Socket(error))),
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixtures_load_from() {
+        let root =
+            std::env::temp_dir().join(format!("barter-data-fixtures-{}", std::process::id()));
+        let dir = root.join("exchange").join("trades");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("b.json"), r#"{"id":2}"#).unwrap();
+        std::fs::write(dir.join("a.json"), r#"{"id":1"#).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a fixture").unwrap();
+
+        let actual = Fixtures::load_from(&root, "exchange", "trades").unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        // Non ".json" files are ignored & malformed frames are loaded verbatim
+        assert_eq!(
+            actual.frames.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("a".to_string(), r#"{"id":1"#.to_string()),
+                ("b".to_string(), r#"{"id":2}"#.to_string()),
+            ]
+        );
+
+        // Missing fixtures directory
+        assert!(Fixtures::load_from(&root, "exchange", "candles").is_err());
+    }
+}
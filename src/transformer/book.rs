@@ -95,14 +95,18 @@ where
         // Construct OrderBookMap if all requests successful
         let book_map = sub_ids
             .into_iter()
-            .zip(init_order_books.into_iter())
+            .zip(init_order_books)
             .collect::<Map<InstrumentOrderBook<Updater>>>();
 
         Ok(Self {
             book_map,
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
         })
     }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.book_map.remove(subscription_ids);
+    }
 }
 
 impl<Exchange, Kind, Updater> Transformer for MultiBookTransformer<Exchange, Kind, Updater>
@@ -0,0 +1,846 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, StreamSelector},
+    streams::{
+        health::Heartbeat,
+        metrics::{MetricsHook, StreamMetrics},
+        raw::RawCapture,
+    },
+    subscriber::{
+        connection::ConnectionOptions,
+        validator::{SubscriptionAcks, SubscriptionOutcome},
+    },
+    subscription::{
+        candle::{Candle, CandleSource, CandlesFromTrades, VolumeDenomination},
+        number::{self, Number},
+        trade::{PublicTrade, PublicTrades},
+        Interval, Map, SubKind, Subscription,
+    },
+    Identifier, MarketStream,
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Exchange, Instrument, Side, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use chrono::{DateTime, Utc};
+use futures::{Stream, StreamExt};
+use std::{
+    collections::{HashMap, VecDeque},
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc,
+    time::{Instant, Sleep},
+};
+use tracing::debug;
+
+/// Grace period after an [`Interval`] boundary before the [`Candle`] ending at the boundary is
+/// closed by a [`TradeCandleAggregator`], allowing for trades that are received late.
+pub const TRADE_CANDLE_GRACE: Duration = Duration::from_secs(1);
+
+/// Aggregates the [`PublicTrade`]s of each [`Instrument`] & [`Interval`] series into closed
+/// OHLCV [`Candle`]s, aligned to the [`Interval::floor`] boundaries.
+///
+/// ### Notes
+/// - open is the first trade price, close the last, and high & low the extremes of the bucket.
+/// - volume is the sum of trade amounts, and `trade_count` the number of trades.
+/// - A bucket is closed once a trade of a later bucket is received, or once the `grace` period
+///   after its boundary has elapsed (see [`Self::expire`]), so candles still close during trade
+///   droughts.
+/// - Buckets without trades yield a zero volume [`Candle`] with open, high, low & close equal to
+///   the previous close, with [`Candle::synthetic`] set.
+/// - Series are only tracked once their first trade is received, since there is no previous
+///   close to synthesize from.
+/// - Trades of an already closed bucket are dropped.
+/// - Every yielded [`Candle`] has [`Candle::derived`] set.
+#[derive(Clone, PartialEq, Debug)]
+pub struct TradeCandleAggregator {
+    grace: Duration,
+    intervals: HashMap<Instrument, Vec<Interval>>,
+    series: HashMap<(Instrument, Interval), TradeCandleSeries>,
+}
+
+/// Aggregation state of a single [`Instrument`] & [`Interval`] series.
+#[derive(Clone, PartialEq, Debug)]
+struct TradeCandleSeries {
+    exchange: Exchange,
+    /// Start time of the open bucket.
+    start_time: DateTime<Utc>,
+    /// [`Candle`] aggregated from the trades of the open bucket, if any have been received.
+    candle: Option<Candle>,
+    /// Close of the most recent bucket.
+    previous_close: Number,
+}
+
+impl TradeCandleAggregator {
+    /// Construct a new [`TradeCandleAggregator`] for the provided [`Instrument`] & [`Interval`]
+    /// series, closing each bucket once the `grace` period after its boundary has elapsed.
+    pub fn new<Iter>(series: Iter, grace: Duration) -> Self
+    where
+        Iter: IntoIterator<Item = (Instrument, Interval)>,
+    {
+        let mut aggregator = Self {
+            grace,
+            intervals: HashMap::new(),
+            series: HashMap::new(),
+        };
+        aggregator.insert(series);
+        aggregator
+    }
+
+    /// Start aggregating the trades of the provided [`Instrument`] & [`Interval`] series, eg/
+    /// after subscribing on a live connection.
+    pub fn insert<Iter>(&mut self, series: Iter)
+    where
+        Iter: IntoIterator<Item = (Instrument, Interval)>,
+    {
+        for (instrument, interval) in series {
+            let instrument_intervals = self.intervals.entry(instrument).or_default();
+            if !instrument_intervals.contains(&interval) {
+                instrument_intervals.push(interval);
+            }
+        }
+    }
+
+    /// Time at which the earliest open bucket of every tracked series is due to be closed by
+    /// [`Self::expire`], if any.
+    pub fn next_deadline(&self) -> Option<DateTime<Utc>> {
+        let grace = self.grace();
+        self.series
+            .iter()
+            .map(|((_, interval), series)| next_start(*interval, series.start_time) + grace)
+            .min()
+    }
+
+    /// Update the [`TradeCandleAggregator`] with the next received [`PublicTrade`], returning
+    /// the [`Candle`]s of any buckets of its [`Instrument`] closed by it.
+    pub fn update(&mut self, trade: MarketEvent<PublicTrade>) -> Vec<MarketEvent<Candle>> {
+        let Some(intervals) = self.intervals.get(&trade.instrument) else {
+            return vec![];
+        };
+
+        let mut output = Vec::new();
+        for interval in intervals {
+            let start_time = interval.floor(trade.exchange_time);
+
+            let series = self
+                .series
+                .entry((trade.instrument.clone(), *interval))
+                .or_insert_with(|| TradeCandleSeries {
+                    exchange: trade.exchange.clone(),
+                    start_time,
+                    candle: None,
+                    previous_close: trade.kind.price,
+                });
+
+            // Close every bucket before the bucket of this trade
+            output.extend(series.advance(&trade.instrument, *interval, start_time));
+
+            if start_time < series.start_time {
+                debug!(
+                    instrument = %trade.instrument,
+                    %interval,
+                    exchange_time = %trade.exchange_time,
+                    "dropping PublicTrade of an already closed Candle",
+                );
+                continue;
+            }
+
+            series.aggregate(*interval, &trade);
+        }
+
+        output.sort_by_key(|event| (event.kind.close_time, event.kind.start_time));
+        output
+    }
+
+    /// Close every bucket whose boundary, plus the `grace` period, has elapsed at the provided
+    /// `now`, returning the [`Candle`]s of the closed buckets.
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Vec<MarketEvent<Candle>> {
+        let now = now - self.grace();
+
+        let mut output = Vec::new();
+        for ((instrument, interval), series) in self.series.iter_mut() {
+            output.extend(series.advance(instrument, *interval, interval.floor(now)));
+        }
+
+        output.sort_by_key(|event| (event.kind.close_time, event.kind.start_time));
+        output
+    }
+
+    /// Grace period as a [`chrono::Duration`].
+    fn grace(&self) -> chrono::Duration {
+        chrono::Duration::from_std(self.grace).unwrap_or_else(|_| chrono::Duration::zero())
+    }
+
+    /// Stop aggregating the trades of the provided [`Instrument`]s, eg/ after unsubscribing.
+    pub fn remove(&mut self, instruments: &[Instrument]) {
+        self.intervals
+            .retain(|instrument, _| !instruments.contains(instrument));
+        self.series
+            .retain(|(instrument, _), _| !instruments.contains(instrument));
+    }
+}
+
+impl TradeCandleSeries {
+    /// Close every bucket starting before the provided `until` boundary, returning their
+    /// [`Candle`]s.
+    fn advance(
+        &mut self,
+        instrument: &Instrument,
+        interval: Interval,
+        until: DateTime<Utc>,
+    ) -> Vec<MarketEvent<Candle>> {
+        let mut output = Vec::new();
+        while self.start_time < until {
+            let close_time = interval.close_time(self.start_time);
+            let candle = self.candle.take().unwrap_or(Candle {
+                start_time: self.start_time,
+                close_time,
+                open: self.previous_close,
+                high: self.previous_close,
+                low: self.previous_close,
+                close: self.previous_close,
+                volume: number::ZERO,
+                volume_denomination: VolumeDenomination::Base,
+                base_volume: Some(number::ZERO),
+                quote_volume: Some(number::ZERO),
+                taker_buy_volume: Some(number::ZERO),
+                taker_buy_quote_volume: Some(number::ZERO),
+                trade_count: 0,
+                closed: true,
+                source: CandleSource::LastTrade,
+                synthetic: true,
+                derived: true,
+            });
+
+            self.previous_close = candle.close;
+            self.start_time = next_start(interval, self.start_time);
+
+            output.push(MarketEvent {
+                exchange_time: close_time,
+                received_time: Utc::now(),
+                exchange: self.exchange.clone(),
+                instrument: instrument.clone(),
+                sequence: None,
+                tag: None,
+                kind: candle,
+            });
+        }
+        output
+    }
+
+    /// Aggregate the provided [`PublicTrade`] into the [`Candle`] of the open bucket.
+    fn aggregate(&mut self, interval: Interval, trade: &MarketEvent<PublicTrade>) {
+        let PublicTrade {
+            price,
+            amount,
+            side,
+            ..
+        } = trade.kind;
+        let (taker_buy_volume, taker_buy_quote_volume) = match side {
+            Side::Buy => (amount, price * amount),
+            Side::Sell => (number::ZERO, number::ZERO),
+        };
+
+        match &mut self.candle {
+            Some(candle) => {
+                candle.high = candle.high.max(price);
+                candle.low = candle.low.min(price);
+                candle.close = price;
+                candle.volume += amount;
+                candle.base_volume = candle.base_volume.map(|volume| volume + amount);
+                candle.quote_volume = candle.quote_volume.map(|volume| volume + price * amount);
+                candle.taker_buy_volume = candle
+                    .taker_buy_volume
+                    .map(|volume| volume + taker_buy_volume);
+                candle.taker_buy_quote_volume = candle
+                    .taker_buy_quote_volume
+                    .map(|volume| volume + taker_buy_quote_volume);
+                candle.trade_count += 1;
+            }
+            None => {
+                self.candle = Some(Candle {
+                    start_time: self.start_time,
+                    close_time: interval.close_time(self.start_time),
+                    open: price,
+                    high: price,
+                    low: price,
+                    close: price,
+                    volume: amount,
+                    volume_denomination: VolumeDenomination::Base,
+                    base_volume: Some(amount),
+                    quote_volume: Some(price * amount),
+                    taker_buy_volume: Some(taker_buy_volume),
+                    taker_buy_quote_volume: Some(taker_buy_quote_volume),
+                    trade_count: 1,
+                    closed: true,
+                    source: CandleSource::LastTrade,
+                    synthetic: false,
+                    derived: true,
+                })
+            }
+        }
+    }
+}
+
+/// Start time of the [`Interval`] boundary following the boundary starting at `start`.
+fn next_start(interval: Interval, start: DateTime<Utc>) -> DateTime<Utc> {
+    interval.close_time(start) + chrono::Duration::milliseconds(1)
+}
+
+/// [`MarketStream`] of [`CandlesFromTrades`], subscribing to the
+/// [`PublicTrades`] [`MarketStream`] of the exchange and aggregating the trades into closed
+/// [`Candle`]s via a [`TradeCandleAggregator`].
+///
+/// Open buckets are closed by a timer once their boundary has elapsed, even if no further trades
+/// are received.
+#[derive(Debug)]
+pub struct TradeCandleStream<Exchange, Trades> {
+    trades: Trades,
+    aggregator: TradeCandleAggregator,
+    instruments: HashMap<SubscriptionId, Instrument>,
+    buffer: VecDeque<MarketEvent<Candle>>,
+    deadline: Option<DateTime<Utc>>,
+    timer: Pin<Box<Sleep>>,
+    phantom: PhantomData<Exchange>,
+}
+
+impl<Exchange, Trades> TradeCandleStream<Exchange, Trades> {
+    /// Construct a new [`TradeCandleStream`] aggregating the trades yielded by the provided
+    /// `trades` stream via the [`TradeCandleAggregator`].
+    pub fn new(
+        trades: Trades,
+        aggregator: TradeCandleAggregator,
+        instruments: HashMap<SubscriptionId, Instrument>,
+    ) -> Self {
+        Self {
+            trades,
+            aggregator,
+            instruments,
+            buffer: VecDeque::new(),
+            deadline: None,
+            timer: Box::pin(tokio::time::sleep(Duration::ZERO)),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Re-arm the timer if the [`TradeCandleAggregator::next_deadline`] has changed.
+    fn reset_timer(&mut self) {
+        let deadline = self.aggregator.next_deadline();
+        if deadline == self.deadline {
+            return;
+        }
+
+        self.deadline = deadline;
+        if let Some(deadline) = deadline {
+            // Wait at least 1ms so a clock skewed deadline cannot be polled in a busy loop
+            let sleep = (deadline - Utc::now())
+                .to_std()
+                .unwrap_or_default()
+                .max(Duration::from_millis(1));
+            self.timer.as_mut().reset(Instant::now() + sleep);
+        }
+    }
+}
+
+impl<Exchange, Trades> Stream for TradeCandleStream<Exchange, Trades>
+where
+    Exchange: Unpin,
+    Trades: Stream<Item = Result<MarketEvent<PublicTrade>, DataError>> + Unpin,
+{
+    type Item = Result<MarketEvent<Candle>, DataError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(candle) = self.buffer.pop_front() {
+                return Poll::Ready(Some(Ok(candle)));
+            }
+
+            match self.trades.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(trade))) => {
+                    let candles = self.aggregator.update(trade);
+                    self.buffer.extend(candles);
+                    self.reset_timer();
+                    continue;
+                }
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {}
+            }
+
+            if self.deadline.is_none() || self.timer.as_mut().poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+
+            // Boundary elapsed without a trade of the next bucket, so close the open buckets
+            let candles = self.aggregator.expire(Utc::now());
+            self.buffer.extend(candles);
+            self.deadline = None;
+            self.reset_timer();
+        }
+    }
+}
+
+#[async_trait]
+impl<Exchange, Trades> MarketStream<Exchange, CandlesFromTrades>
+    for TradeCandleStream<Exchange, Trades>
+where
+    Exchange: Connector + Send + Sync + Unpin,
+    Trades: MarketStream<Exchange, PublicTrades>,
+    Subscription<Exchange, PublicTrades>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    async fn init(
+        subscriptions: &[Subscription<Exchange, CandlesFromTrades>],
+        acks: SubscriptionAcks,
+        options: &ConnectionOptions,
+    ) -> Result<(Self, SubscriptionOutcome), DataError>
+    where
+        Subscription<Exchange, CandlesFromTrades>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Subscribe to the PublicTrades of each unique Instrument, shared by every Interval
+        let mut trades = subscriptions
+            .iter()
+            .map(|subscription| {
+                Subscription::new(
+                    subscription.exchange.clone(),
+                    subscription.instrument.clone(),
+                    PublicTrades,
+                )
+            })
+            .collect::<Vec<_>>();
+        trades.sort_by(|a, b| a.instrument.cmp(&b.instrument));
+        trades.dedup_by(|a, b| a.instrument == b.instrument);
+
+        let (stream, outcome) = Trades::init(&trades, acks, options).await?;
+
+        let aggregator = TradeCandleAggregator::new(
+            subscriptions
+                .iter()
+                .map(|subscription| (subscription.instrument.clone(), subscription.kind.0)),
+            TRADE_CANDLE_GRACE,
+        );
+
+        let instruments = trades
+            .into_iter()
+            .map(|subscription| (subscription.subscription_id(), subscription.instrument))
+            .collect();
+
+        Ok((Self::new(stream, aggregator, instruments), outcome))
+    }
+
+    fn heartbeat(&self) -> Option<Heartbeat> {
+        self.trades.heartbeat()
+    }
+
+    fn set_metrics(&mut self, metrics: Arc<dyn StreamMetrics>) {
+        self.trades.set_metrics(metrics)
+    }
+
+    fn set_raw_capture(&mut self, capture: RawCapture) {
+        self.trades.set_raw_capture(capture)
+    }
+
+    fn unsubscribe(
+        &mut self,
+        subscription_ids: &[SubscriptionId],
+        requests: Vec<WsMessage>,
+    ) -> Result<(), DataError> {
+        let instruments = subscription_ids
+            .iter()
+            .filter_map(|id| self.instruments.remove(id))
+            .collect::<Vec<_>>();
+        self.aggregator.remove(&instruments);
+        self.reset_timer();
+
+        self.trades.unsubscribe(subscription_ids, requests)
+    }
+
+    fn subscribe(
+        &mut self,
+        subscriptions: &[Subscription<Exchange, CandlesFromTrades>],
+    ) -> Result<(), DataError>
+    where
+        Subscription<Exchange, CandlesFromTrades>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Subscribe to the PublicTrades of each Instrument whose trades are not yet aggregated
+        let mut trades = subscriptions
+            .iter()
+            .filter(|subscription| {
+                !self
+                    .instruments
+                    .values()
+                    .any(|i| *i == subscription.instrument)
+            })
+            .map(|subscription| {
+                Subscription::new(
+                    subscription.exchange.clone(),
+                    subscription.instrument.clone(),
+                    PublicTrades,
+                )
+            })
+            .collect::<Vec<_>>();
+        trades.sort_by(|a, b| a.instrument.cmp(&b.instrument));
+        trades.dedup_by(|a, b| a.instrument == b.instrument);
+
+        if !trades.is_empty() {
+            self.trades.subscribe(&trades)?;
+        }
+
+        self.aggregator.insert(
+            subscriptions
+                .iter()
+                .map(|subscription| (subscription.instrument.clone(), subscription.kind.0)),
+        );
+        self.instruments.extend(
+            trades
+                .into_iter()
+                .map(|subscription| (subscription.subscription_id(), subscription.instrument)),
+        );
+
+        Ok(())
+    }
+
+    fn close(&mut self) {
+        self.trades.close()
+    }
+}
+
+impl<Exchange> Identifier<Exchange::Channel> for Subscription<Exchange, CandlesFromTrades>
+where
+    Exchange: Connector,
+    Subscription<Exchange, PublicTrades>: Identifier<Exchange::Channel>,
+{
+    fn id(&self) -> Exchange::Channel {
+        Subscription::new(self.exchange.clone(), self.instrument.clone(), PublicTrades).id()
+    }
+}
+
+impl<Exchange> StreamSelector<CandlesFromTrades> for Exchange
+where
+    Exchange: StreamSelector<PublicTrades> + Send + Sync + Unpin,
+    Subscription<Exchange, PublicTrades>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    type Stream = TradeCandleStream<Exchange, <Exchange as StreamSelector<PublicTrades>>::Stream>;
+}
+
+/// [`ExchangeTransformer`] wrapper that yields only closed [`Candle`]s for exchanges that do not
+/// indicate if a candle update is final (eg/ [`Kraken`](crate::exchange::kraken::Kraken)),
+/// delegating the transformation of each update to the `Inner` [`ExchangeTransformer`].
+///
+/// Updates are coalesced per [`SubscriptionId`] (eg/ "ohlc-5|XBT/USD"), holding the latest update
+/// of the in-progress candle until an update for a later candle is received, at which point the
+/// held candle is emitted as closed.
+///
+/// ### Notes
+/// - Updates for an earlier candle than the one held are stale, and are dropped.
+/// - The final candle of a [`SubscriptionId`] is only emitted once the next candle starts.
+#[derive(Debug)]
+pub struct HeldCandleTransformer<Inner> {
+    pub inner: Inner,
+    in_progress: HashMap<SubscriptionId, MarketEvent<Candle>>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Inner> ExchangeTransformer<Exchange, Kind> for HeldCandleTransformer<Inner>
+where
+    Exchange: Send,
+    Kind: SubKind<Event = Candle> + Send,
+    Inner: ExchangeTransformer<Exchange, Kind> + Send,
+    Inner::Input: Identifier<Option<SubscriptionId>>,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            inner: Inner::new(ws_sink_tx, instrument_map).await?,
+            in_progress: HashMap::new(),
+        })
+    }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        self.inner.set_metrics(metrics);
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.inner.unsubscribe(subscription_ids);
+        for subscription_id in subscription_ids {
+            self.in_progress.remove(subscription_id);
+        }
+    }
+
+    fn subscribe(&mut self, instrument_map: Map<Instrument>) -> Result<(), DataError> {
+        self.inner.subscribe(instrument_map)
+    }
+}
+
+impl<Inner> Transformer for HeldCandleTransformer<Inner>
+where
+    Inner: Transformer<Output = MarketEvent<Candle>, Error = DataError>,
+    Inner::Input: Identifier<Option<SubscriptionId>>,
+{
+    type Error = DataError;
+    type Input = Inner::Input;
+    type Output = MarketEvent<Candle>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        self.inner
+            .transform(input)
+            .into_iter()
+            .filter_map(|result| {
+                let event = match result {
+                    Ok(event) => event,
+                    Err(error) => return Some(Err(error)),
+                };
+
+                match self.in_progress.get_mut(&subscription_id) {
+                    // Update for a later candle, so the held candle has closed
+                    Some(held) if event.kind.close_time > held.kind.close_time => {
+                        let mut closed = std::mem::replace(held, event);
+                        closed.kind.closed = true;
+                        Some(Ok(closed))
+                    }
+                    // Repeated update of the held in-progress candle
+                    Some(held) if event.kind.close_time == held.kind.close_time => {
+                        *held = event;
+                        None
+                    }
+                    // Stale update of an earlier candle
+                    Some(_) => None,
+                    // First update received for this SubscriptionId
+                    None => {
+                        self.in_progress.insert(subscription_id.clone(), event);
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::ExchangeId, subscription::number::from_f64};
+    use barter_integration::model::InstrumentKind;
+    use chrono::TimeZone;
+
+    fn instrument() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    fn time(ms: i64) -> DateTime<Utc> {
+        Utc.timestamp_millis_opt(ms).unwrap()
+    }
+
+    fn trade(ms: i64, price: f64, amount: f64, side: Side) -> MarketEvent<PublicTrade> {
+        MarketEvent {
+            exchange_time: time(ms),
+            received_time: time(ms),
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument: instrument(),
+            sequence: None,
+            tag: None,
+            kind: PublicTrade {
+                id: ms.to_string(),
+                price: from_f64(price),
+                amount: from_f64(amount),
+                side,
+                buyer_order_id: None,
+                seller_order_id: None,
+                taker_order_kind: None,
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn candle(
+        start_ms: i64,
+        ohlc: [f64; 4],
+        volume: f64,
+        quote_volume: f64,
+        taker_buy_volume: f64,
+        taker_buy_quote_volume: f64,
+        trade_count: u64,
+        synthetic: bool,
+    ) -> Candle {
+        let [open, high, low, close] = ohlc;
+        Candle {
+            start_time: time(start_ms),
+            close_time: time(start_ms + 59_999),
+            open: from_f64(open),
+            high: from_f64(high),
+            low: from_f64(low),
+            close: from_f64(close),
+            volume: from_f64(volume),
+            volume_denomination: VolumeDenomination::Base,
+            base_volume: Some(from_f64(volume)),
+            quote_volume: Some(from_f64(quote_volume)),
+            taker_buy_volume: Some(from_f64(taker_buy_volume)),
+            taker_buy_quote_volume: Some(from_f64(taker_buy_quote_volume)),
+            trade_count,
+            closed: true,
+            source: CandleSource::LastTrade,
+            synthetic,
+            derived: true,
+        }
+    }
+
+    #[test]
+    fn test_trade_candle_aggregator() {
+        struct TestCase {
+            input: Input,
+            expected: Vec<Candle>,
+        }
+
+        enum Input {
+            Trade(i64, f64, f64, Side),
+            Expire(i64),
+        }
+
+        let mut aggregator =
+            TradeCandleAggregator::new([(instrument(), Interval::Minute1)], Duration::from_secs(1));
+        assert_eq!(aggregator.next_deadline(), None);
+
+        let tests = vec![
+            TestCase {
+                // TC0: first trade of the 00:00 bucket opens the Candle
+                input: Input::Trade(10_000, 100.0, 1.0, Side::Buy),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: trade of the same bucket updates the open Candle
+                input: Input::Trade(30_000, 105.0, 2.0, Side::Sell),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: last trade of the 00:00 bucket
+                input: Input::Trade(50_000, 99.0, 1.0, Side::Buy),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: boundary elapsed within the grace period does not close the Candle
+                input: Input::Expire(60_500),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: trade of the 00:02 bucket closes the 00:00 Candle & yields a zero volume
+                // 00:01 Candle carrying the previous close
+                input: Input::Trade(130_000, 101.0, 3.0, Side::Buy),
+                expected: vec![
+                    candle(
+                        0,
+                        [100.0, 105.0, 99.0, 99.0],
+                        4.0,
+                        409.0,
+                        2.0,
+                        199.0,
+                        3,
+                        false,
+                    ),
+                    candle(60_000, [99.0; 4], 0.0, 0.0, 0.0, 0.0, 0, true),
+                ],
+            },
+            TestCase {
+                // TC5: late trade of an already closed bucket is dropped
+                input: Input::Trade(70_000, 1.0, 1.0, Side::Sell),
+                expected: vec![],
+            },
+            TestCase {
+                // TC6: trade drought past the 00:02 boundary & grace period closes the Candle
+                input: Input::Expire(181_000),
+                expected: vec![candle(
+                    120_000, [101.0; 4], 3.0, 303.0, 3.0, 303.0, 1, false,
+                )],
+            },
+            TestCase {
+                // TC7: continued trade drought yields zero volume Candles carrying the close
+                input: Input::Expire(241_000),
+                expected: vec![candle(180_000, [101.0; 4], 0.0, 0.0, 0.0, 0.0, 0, true)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = match test.input {
+                Input::Trade(ms, price, amount, side) => {
+                    aggregator.update(trade(ms, price, amount, side))
+                }
+                Input::Expire(now_ms) => aggregator.expire(time(now_ms)),
+            }
+            .into_iter()
+            .map(|event| {
+                assert_eq!(event.exchange_time, event.kind.close_time);
+                event.kind
+            })
+            .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+
+        assert_eq!(aggregator.next_deadline(), Some(time(301_000)));
+    }
+
+    #[test]
+    fn test_trade_candle_aggregator_intervals() {
+        let mut aggregator = TradeCandleAggregator::new(
+            [
+                (instrument(), Interval::Minute1),
+                (instrument(), Interval::Minute3),
+            ],
+            Duration::ZERO,
+        );
+
+        // Trades of an Instrument without CandlesFromTrades Subscriptions are ignored
+        let mut other = trade(0, 1.0, 1.0, Side::Buy);
+        other.instrument = Instrument::from(("eth", "usdt", InstrumentKind::Spot));
+        assert!(aggregator.update(other).is_empty());
+
+        aggregator.update(trade(0, 1.0, 1.0, Side::Buy));
+        aggregator.update(trade(120_000, 2.0, 1.0, Side::Buy));
+
+        let actual = aggregator
+            .expire(time(180_000))
+            .into_iter()
+            .map(|event| {
+                (
+                    event.kind.start_time,
+                    event.kind.close_time,
+                    event.kind.volume,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                (time(0), time(179_999), from_f64(2.0)),
+                (time(120_000), time(179_999), from_f64(1.0)),
+            ]
+        );
+
+        aggregator.remove(&[instrument()]);
+        assert_eq!(aggregator.next_deadline(), None);
+        assert!(aggregator
+            .update(trade(180_000, 1.0, 1.0, Side::Buy))
+            .is_empty());
+    }
+}
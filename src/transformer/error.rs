@@ -0,0 +1,281 @@
+use super::ExchangeTransformer;
+use crate::{
+    error::DataError,
+    event::MarketEvent,
+    streams::metrics::MetricsHook,
+    subscription::{Map, SubKind},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
+use serde::Deserialize;
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+
+/// Exchange specific WebSocket message that is either market data, or an error sent by the
+/// exchange server mid-stream (eg/ invalid subscription, rate limit ban).
+pub trait ExchangeMessage {
+    type Data;
+
+    /// Convert into the market [`Self::Data`], or the [`DataError::Exchange`] sent by the
+    /// exchange server.
+    fn into_data(self) -> Result<Self::Data, DataError>;
+}
+
+/// [`ExchangeTransformer`] wrapper that surfaces exchange error messages as
+/// [`DataError::Exchange`]s, delegating all market data to the `Inner` [`ExchangeTransformer`].
+///
+/// Fatal exchange errors (eg/ rate limit ban) are [`DataError::is_terminal`], causing the
+/// [`MarketStream`](crate::MarketStream) to re-initialise, whereas recoverable errors
+/// (eg/ bad individual subscription) are skipped.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ErrorTransformer<Inner, Message> {
+    pub inner: Inner,
+    metrics: MetricsHook,
+    phantom: PhantomData<Message>,
+}
+
+#[async_trait]
+impl<Exchange, Kind, Inner, Message> ExchangeTransformer<Exchange, Kind>
+    for ErrorTransformer<Inner, Message>
+where
+    Exchange: Send,
+    Kind: SubKind + Send,
+    Inner: ExchangeTransformer<Exchange, Kind> + Send,
+    Message: ExchangeMessage<Data = Inner::Input> + for<'de> Deserialize<'de> + Send,
+{
+    async fn new(
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        instrument_map: Map<Instrument>,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            inner: Inner::new(ws_sink_tx, instrument_map).await?,
+            metrics: MetricsHook::default(),
+            phantom: PhantomData,
+        })
+    }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        self.inner.set_metrics(metrics.clone());
+        self.metrics = metrics;
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.inner.unsubscribe(subscription_ids);
+    }
+
+    fn subscribe(&mut self, instrument_map: Map<Instrument>) -> Result<(), DataError> {
+        self.inner.subscribe(instrument_map)
+    }
+}
+
+impl<Inner, Message, Output> Transformer for ErrorTransformer<Inner, Message>
+where
+    Inner: Transformer<Output = MarketEvent<Output>, Error = DataError>,
+    Message: ExchangeMessage<Data = Inner::Input> + for<'de> Deserialize<'de>,
+{
+    type Error = DataError;
+    type Input = Message;
+    type Output = MarketEvent<Output>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input.into_data() {
+            Ok(data) => self.inner.transform(data).into_iter().collect(),
+            Err(error) => {
+                self.metrics.report_error(&error);
+                vec![Err(error)]
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::{
+            binance::{message::BinanceMessage, spot::BinanceSpot, trade::BinanceTrade},
+            bitstamp::{message::BitstampWsMessage, trade::BitstampTrades, Bitstamp},
+            okx::{message::OkxWsMessage, trade::OkxTrades, Okx},
+        },
+        subscription::trade::PublicTrades,
+        transformer::stateless::StatelessTransformer,
+    };
+    use barter_integration::{error::SocketError, model::InstrumentKind};
+    use std::collections::HashMap;
+
+    async fn transformer<Exchange, Inner, Message>(
+        subscription_id: &str,
+    ) -> ErrorTransformer<Inner, Message>
+    where
+        Exchange: Send,
+        Inner: ExchangeTransformer<Exchange, PublicTrades> + Send,
+        Message: ExchangeMessage<Data = Inner::Input> + for<'de> Deserialize<'de> + Send,
+    {
+        let instrument_map = Map(HashMap::from([(
+            SubscriptionId::from(subscription_id),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        )]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        <ErrorTransformer<Inner, Message> as ExchangeTransformer<Exchange, PublicTrades>>::new(
+            ws_sink_tx,
+            instrument_map,
+        )
+        .await
+        .unwrap()
+    }
+
+    struct TestCase {
+        input: &'static str,
+        expected: Result<usize, (&'static str, bool)>,
+    }
+
+    fn assert_transformed<T: std::fmt::Debug>(
+        index: usize,
+        actual: Vec<Result<MarketEvent<T>, DataError>>,
+        expected: Result<usize, (&'static str, bool)>,
+    ) {
+        match (actual.as_slice(), expected) {
+            (events, Ok(num_events)) => {
+                assert_eq!(events.len(), num_events, "TC{} failed", index);
+                assert!(events.iter().all(Result::is_ok), "TC{} failed", index);
+            }
+            (
+                [Err(DataError::Exchange {
+                    code: actual_code,
+                    fatal: actual_fatal,
+                    ..
+                })],
+                Err((code, fatal)),
+            ) => {
+                assert_eq!(actual_code, code, "TC{} failed", index);
+                assert_eq!(*actual_fatal, fatal, "TC{} failed", index);
+            }
+            (actual, expected) => {
+                panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_transformer_binance() {
+        let mut transformer = transformer::<
+            BinanceSpot,
+            StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade>,
+            BinanceMessage<BinanceTrade>,
+        >("@trade|BTCUSDT")
+        .await;
+
+        let tests = vec![
+            TestCase {
+                // TC0: trade is delegated to the inner transformer
+                input: r#"{"e":"trade","E":1649324825173,"s":"BTCUSDT","t":1000000000,"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1649324825173,"m":false,"M":true}"#,
+                expected: Ok(1),
+            },
+            TestCase {
+                // TC1: invalid request error is recoverable
+                input: r#"{"error":{"code":2,"msg":"Invalid request: unknown variant `subscribe`, expected one of `SUBSCRIBE`, `UNSUBSCRIBE`, `LIST_SUBSCRIPTIONS`, `SET_PROPERTY`, `GET_PROPERTY` at line 1 column 28"},"id":1}"#,
+                expected: Err(("2", false)),
+            },
+            TestCase {
+                // TC2: rate limit ban error is fatal
+                input: r#"{"id":1,"status":418,"error":{"code":-1003,"msg":"Way too much request weight used; IP banned until 1659146400000. Please use WebSocket Streams for live updates to avoid bans."}}"#,
+                expected: Err(("-1003", true)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<BinanceMessage<BinanceTrade>>(test.input).unwrap();
+            assert_transformed(index, transformer.transform(input), test.expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_transformer_okx() {
+        let mut transformer = transformer::<
+            Okx,
+            StatelessTransformer<Okx, PublicTrades, OkxTrades>,
+            OkxWsMessage<OkxTrades>,
+        >("trades|BTC-USDT")
+        .await;
+
+        let tests = vec![
+            TestCase {
+                // TC0: trades are delegated to the inner transformer
+                input: r#"{"arg":{"channel":"trades","instId":"BTC-USDT"},"data":[{"instId":"BTC-USDT","tradeId":"130639474","px":"42219.9","sz":"0.12060306","side":"buy","ts":"1630048897897"}]}"#,
+                expected: Ok(1),
+            },
+            TestCase {
+                // TC1: invalid request error is recoverable
+                input: r#"{"event":"error","code":"60012","msg":"Invalid request: {\"op\": \"subscribe\", \"argss\":[{ \"channel\" : \"trades\", \"instId\" : \"BTC-USDT\"}]}","connId":"a4d3ae55"}"#,
+                expected: Err(("60012", false)),
+            },
+            TestCase {
+                // TC2: non-existent channel error is recoverable
+                input: r#"{"event":"error","code":"60018","msg":"Wrong URL or channel:trades,instId:BTC-XYZ doesn't exist. Please use the correct URL, channel and parameters referring to API document.","connId":"a4d3ae55"}"#,
+                expected: Err(("60018", false)),
+            },
+            TestCase {
+                // TC3: requests too frequent error is fatal
+                input: r#"{"event":"error","code":"60014","msg":"Requests too frequent.","connId":"a4d3ae55"}"#,
+                expected: Err(("60014", true)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input = serde_json::from_str::<OkxWsMessage<OkxTrades>>(test.input).unwrap();
+            assert_transformed(index, transformer.transform(input), test.expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_error_transformer_bitstamp() {
+        let mut transformer = transformer::<
+            Bitstamp,
+            StatelessTransformer<Bitstamp, PublicTrades, BitstampTrades>,
+            BitstampWsMessage<BitstampTrades>,
+        >("live_trades|btcusd")
+        .await;
+
+        let tests = vec![
+            TestCase {
+                // TC0: trade is delegated to the inner transformer
+                input: r#"{"data":{"id":276012539,"amount":0.0051,"price":28075,"type":1,"microtimestamp":"1680522427432000","buy_order_id":1610404213260288,"sell_order_id":1610404214116353},"channel":"live_trades_btcusd","event":"trade"}"#,
+                expected: Ok(1),
+            },
+            TestCase {
+                // TC1: late subscription success is skipped by the inner transformer
+                input: r#"{"event":"bts:subscription_succeeded","channel":"live_trades_btcusd","data":{}}"#,
+                expected: Ok(0),
+            },
+            TestCase {
+                // TC2: bad subscription error is recoverable
+                input: r#"{"event":"bts:error","channel":"","data":{"code":null,"message":"Bad subscription string."}}"#,
+                expected: Err(("", false)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let input =
+                serde_json::from_str::<BitstampWsMessage<BitstampTrades>>(test.input).unwrap();
+            assert_transformed(index, transformer.transform(input), test.expected);
+        }
+
+        // Reconnect request is a terminal error, triggering the re-connection path
+        let input = serde_json::from_str::<BitstampWsMessage<BitstampTrades>>(
+            r#"{"event":"bts:request_reconnect","channel":"","data":""}"#,
+        )
+        .unwrap();
+        match transformer.transform(input).as_slice() {
+            [Err(error @ DataError::Socket(SocketError::Terminated(_)))] => {
+                assert!(error.is_terminal())
+            }
+            actual => panic!("expected terminal SocketError::Terminated, actual: {actual:?}"),
+        }
+    }
+}
@@ -1,15 +1,36 @@
 use crate::{
     error::DataError,
     event::MarketEvent,
+    streams::metrics::MetricsHook,
     subscription::{Map, SubKind},
 };
 use async_trait::async_trait;
-use barter_integration::{model::Instrument, protocol::websocket::WsMessage, Transformer};
+use barter_integration::{
+    error::SocketError,
+    model::{Instrument, SubscriptionId},
+    protocol::websocket::WsMessage,
+    Transformer,
+};
 use tokio::sync::mpsc;
 
 /// Generic OrderBook [`ExchangeTransformer`]s.
 pub mod book;
 
+/// Local aggregation of [`PublicTrades`](crate::subscription::trade::PublicTrades) into closed
+/// [`Candle`](crate::subscription::candle::Candle)s for
+/// [`CandlesFromTrades`](crate::subscription::candle::CandlesFromTrades) streams, and closed
+/// [`Candle`](crate::subscription::candle::Candle) detection for exchanges that do not indicate
+/// if a candle update is final.
+pub mod candle;
+
+/// [`ExchangeTransformer`] wrapper that surfaces exchange error messages as
+/// [`DataError::Exchange`]s.
+pub mod error;
+
+/// Reusable per [`SubscriptionId`](barter_integration::model::SubscriptionId) sequence & OrderBook
+/// update id gap detection.
+pub mod sequence;
+
 /// Generic stateless [`ExchangeTransformer`] often used for transforming
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
 pub mod stateless;
@@ -29,4 +50,27 @@ where
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
         instrument_map: Map<Instrument>,
     ) -> Result<Self, DataError>;
+
+    /// Install a [`MetricsHook`] that is reported to for every normalised event.
+    ///
+    /// Defaults to a no-op for transformers that do not support metrics.
+    fn set_metrics(&mut self, _: MetricsHook) {}
+
+    /// Stop transforming messages associated with the provided [`SubscriptionId`]s, removing
+    /// them from the [`Map`] used to identify incoming messages.
+    ///
+    /// Defaults to a no-op for transformers that do not support unsubscribing.
+    fn unsubscribe(&mut self, _: &[SubscriptionId]) {}
+
+    /// Start transforming messages associated with the [`SubscriptionId`]s of the provided
+    /// [`Map`], added to the connection after [`Self`] was constructed.
+    ///
+    /// Defaults to an unsupported error for transformers that require per [`SubscriptionId`]
+    /// initialisation (eg/ OrderBook snapshots).
+    fn subscribe(&mut self, _: Map<Instrument>) -> Result<(), DataError> {
+        Err(DataError::Socket(SocketError::Unsupported {
+            entity: std::any::type_name::<Self>(),
+            item: String::from("subscribe"),
+        }))
+    }
 }
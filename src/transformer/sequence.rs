@@ -0,0 +1,391 @@
+use crate::{error::DataError, subscription::book::OrderBook};
+use barter_integration::model::SubscriptionId;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Detects gaps in the [`MarketEvent`](crate::event::MarketEvent) `sequence` numbers of each
+/// [`SubscriptionId`].
+///
+/// The first sequence received for a [`SubscriptionId`] sets the expectation. Each subsequent
+/// sequence is expected to follow on directly, otherwise a [`DataError::SequenceGap`] is
+/// returned and the expectation is moved on to follow the received sequence. Stale or duplicate
+/// sequences are not considered gaps, and do not move the expectation backwards.
+///
+/// ### Notes
+/// Expectations must be [`reset`](Self::reset) after a re-connection, since events sent while
+/// disconnected are never received. The [`StatelessTransformer`](super::stateless::StatelessTransformer)
+/// does this implicitly, since a new transformer is constructed each time a
+/// [`MarketStream`](crate::MarketStream) is re-initialised.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Serialize)]
+pub struct SequenceValidator {
+    next: HashMap<SubscriptionId, u64>,
+}
+
+impl SequenceValidator {
+    /// Construct a new [`Self`] with no expectations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate the provided `sequence` follows on from the previous sequence received for the
+    /// [`SubscriptionId`].
+    pub fn validate(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        sequence: u64,
+    ) -> Result<(), DataError> {
+        let next = sequence.saturating_add(1);
+
+        match self.next.get_mut(subscription_id) {
+            // First sequence received for this SubscriptionId
+            None => {
+                self.next.insert(subscription_id.clone(), next);
+                Ok(())
+            }
+            // Stale or duplicate sequence
+            Some(expected) if sequence < *expected => Ok(()),
+            // Expected sequence
+            Some(expected) if sequence == *expected => {
+                *expected = next;
+                Ok(())
+            }
+            // Sequence gap
+            Some(expected) => {
+                let gap = DataError::SequenceGap {
+                    expected: *expected,
+                    actual: sequence,
+                };
+                *expected = next;
+                Err(gap)
+            }
+        }
+    }
+
+    /// Validate every sequence up to & including the provided `last` sequence has been received
+    /// for the [`SubscriptionId`] (eg/ as reported by an exchange heartbeat).
+    ///
+    /// Missed sequences yield a [`DataError::SequenceGap`] and the expectation is moved on to
+    /// follow `last`, so the missed sequences are only reported once.
+    pub fn validate_last(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        last: u64,
+    ) -> Result<(), DataError> {
+        let next = last.saturating_add(1);
+
+        match self.next.get_mut(subscription_id) {
+            // Nothing received for this SubscriptionId, so nothing can have been missed
+            None => {
+                self.next.insert(subscription_id.clone(), next);
+                Ok(())
+            }
+            // Every sequence up to & including last has been received
+            Some(expected) if next <= *expected => Ok(()),
+            // Sequence gap
+            Some(expected) => {
+                let gap = DataError::SequenceGap {
+                    expected: *expected,
+                    actual: next,
+                };
+                *expected = next;
+                Err(gap)
+            }
+        }
+    }
+
+    /// Reset all expectations, such that the next sequence received for each
+    /// [`SubscriptionId`] is accepted. Must be called after a re-connection notification.
+    pub fn reset(&mut self) {
+        self.next.clear();
+    }
+
+    /// Reset the expectation for the provided [`SubscriptionId`] only.
+    pub fn reset_subscription(&mut self, subscription_id: &SubscriptionId) {
+        self.next.remove(subscription_id);
+    }
+}
+
+/// Detects gaps in the exchange update id ranges of the [`OrderBook`] deltas of each
+/// [`SubscriptionId`], usable both by a live transformer and by tooling replaying persisted
+/// deltas.
+///
+/// A delta is continuous if its `first_update_id` is at or before the update id following the
+/// previous `last_update_id`, otherwise a [`DataError::SequenceGap`] is returned. Deltas without a
+/// `first_update_id` (eg/ snapshots) set the expectation without being checked, and
+/// [`OrderBook`]s without a `last_update_id` are ignored. Stale or overlapping deltas do not move
+/// the expectation backwards.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Serialize)]
+pub struct ContinuityChecker {
+    last_update_ids: HashMap<SubscriptionId, u64>,
+}
+
+impl ContinuityChecker {
+    /// Construct a new [`Self`] with no expectations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check the update id range of the provided [`OrderBook`] follows on from the previous
+    /// [`OrderBook`] received for the [`SubscriptionId`].
+    pub fn check(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        book: &OrderBook,
+    ) -> Result<(), DataError> {
+        self.check_range(subscription_id, book.first_update_id, book.last_update_id)
+    }
+
+    /// Check the provided update id range follows on from the previous range received for the
+    /// [`SubscriptionId`].
+    pub fn check_range(
+        &mut self,
+        subscription_id: &SubscriptionId,
+        first_update_id: Option<u64>,
+        last_update_id: Option<u64>,
+    ) -> Result<(), DataError> {
+        let Some(last_update_id) = last_update_id else {
+            return Ok(());
+        };
+
+        match (
+            self.last_update_ids.get_mut(subscription_id),
+            first_update_id,
+        ) {
+            // First range received for this SubscriptionId, or a snapshot
+            (None, _) | (Some(_), None) => {
+                self.last_update_ids
+                    .insert(subscription_id.clone(), last_update_id);
+                Ok(())
+            }
+            // Continuous, overlapping or stale range
+            (Some(previous), Some(first)) if first <= previous.saturating_add(1) => {
+                *previous = (*previous).max(last_update_id);
+                Ok(())
+            }
+            // Update id gap
+            (Some(previous), Some(first)) => {
+                let gap = DataError::SequenceGap {
+                    expected: previous.saturating_add(1),
+                    actual: first,
+                };
+                *previous = last_update_id;
+                Err(gap)
+            }
+        }
+    }
+
+    /// Reset all expectations, such that the next range received for each [`SubscriptionId`] is
+    /// accepted. Must be called after a re-connection notification.
+    pub fn reset(&mut self) {
+        self.last_update_ids.clear();
+    }
+
+    /// Reset the expectation for the provided [`SubscriptionId`] only.
+    pub fn reset_subscription(&mut self, subscription_id: &SubscriptionId) {
+        self.last_update_ids.remove(subscription_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequence_validator() {
+        struct TestCase {
+            input: (&'static str, u64),
+            expected: Result<(), (u64, u64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first sequence for BTC is accepted
+                input: ("btc", 10),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: next sequence for BTC is accepted
+                input: ("btc", 11),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: first sequence for ETH is accepted independently of BTC
+                input: ("eth", 500),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC3: duplicate sequence for BTC is not a gap
+                input: ("btc", 11),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC4: skipped sequences for BTC is a gap
+                input: ("btc", 15),
+                expected: Err((12, 15)),
+            },
+            TestCase {
+                // TC5: sequence following the gap for BTC is accepted
+                input: ("btc", 16),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC6: skipped sequences for ETH is a gap
+                input: ("eth", 502),
+                expected: Err((501, 502)),
+            },
+        ];
+
+        let mut validator = SequenceValidator::new();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (subscription_id, sequence) = test.input;
+            let actual = validator.validate(&SubscriptionId::from(subscription_id), sequence);
+            match (actual, test.expected) {
+                (Ok(()), Ok(())) => {
+                    // Test passed
+                }
+                (Err(DataError::SequenceGap { expected, actual }), Err(gap)) => {
+                    assert_eq!((expected, actual), gap, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sequence_validator_validate_last() {
+        let btc = SubscriptionId::from("btc");
+        let mut validator = SequenceValidator::new();
+
+        // Nothing received, so the last sequence sets the expectation
+        assert!(validator.validate_last(&btc, 9).is_ok());
+        assert!(validator.validate(&btc, 10).is_ok());
+
+        // Last sequence was received
+        assert!(validator.validate_last(&btc, 10).is_ok());
+
+        // Sequences 11 & 12 were never received
+        match validator.validate_last(&btc, 12) {
+            Err(DataError::SequenceGap { expected, actual }) => {
+                assert_eq!((expected, actual), (11, 13))
+            }
+            other => panic!("expected SequenceGap, got {other:?}"),
+        }
+
+        // Missed sequences are only reported once, and late arrivals are stale
+        assert!(validator.validate_last(&btc, 12).is_ok());
+        assert!(validator.validate(&btc, 11).is_ok());
+        assert!(validator.validate(&btc, 13).is_ok());
+    }
+
+    #[test]
+    fn test_sequence_validator_reset() {
+        let btc = SubscriptionId::from("btc");
+        let eth = SubscriptionId::from("eth");
+        let mut validator = SequenceValidator::new();
+        validator.validate(&btc, 1).unwrap();
+        validator.validate(&eth, 1).unwrap();
+
+        // Expectation is reset for a single SubscriptionId
+        validator.reset_subscription(&btc);
+        assert!(validator.validate(&btc, 100).is_ok());
+        assert!(validator.validate(&eth, 100).is_err());
+
+        // Expectations are reset for all SubscriptionIds after a re-connection
+        validator.reset();
+        assert!(validator.validate(&btc, 1000).is_ok());
+        assert!(validator.validate(&eth, 1000).is_ok());
+    }
+
+    #[test]
+    fn test_continuity_checker() {
+        struct TestCase {
+            input: (&'static str, Option<u64>, Option<u64>),
+            expected: Result<(), (u64, u64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first BTC snapshot sets the expectation
+                input: ("btc", None, Some(100)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: first BTC delta overlapping the snapshot is continuous
+                input: ("btc", Some(95), Some(110)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC2: next BTC delta following on directly is continuous
+                input: ("btc", Some(111), Some(120)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC3: first ETH delta is accepted independently of BTC
+                input: ("eth", Some(5000), Some(5001)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC4: stale BTC delta is not a gap
+                input: ("btc", Some(101), Some(110)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC5: BTC delta skipping update ids 121..=124 is a gap
+                input: ("btc", Some(125), Some(130)),
+                expected: Err((121, 125)),
+            },
+            TestCase {
+                // TC6: BTC delta following the gap is continuous
+                input: ("btc", Some(131), Some(140)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC7: book without update ids is ignored
+                input: ("btc", None, None),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC8: ETH delta skipping update id 5002 is a gap
+                input: ("eth", Some(5003), Some(5004)),
+                expected: Err((5002, 5003)),
+            },
+            TestCase {
+                // TC9: BTC snapshot after a resync moves the expectation on
+                input: ("btc", None, Some(500)),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC10: BTC delta following the snapshot is continuous
+                input: ("btc", Some(501), Some(501)),
+                expected: Ok(()),
+            },
+        ];
+
+        let mut checker = ContinuityChecker::new();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (subscription_id, first_update_id, last_update_id) = test.input;
+            let actual = checker.check_range(
+                &SubscriptionId::from(subscription_id),
+                first_update_id,
+                last_update_id,
+            );
+            match (actual, test.expected) {
+                (Ok(()), Ok(())) => {
+                    // Test passed
+                }
+                (Err(DataError::SequenceGap { expected, actual }), Err(gap)) => {
+                    assert_eq!((expected, actual), gap, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use super::ExchangeTransformer;
+use super::{sequence::SequenceValidator, ExchangeTransformer};
 use crate::{
     error::DataError,
     event::{MarketEvent, MarketIter},
     exchange::{Connector, ExchangeId},
+    streams::metrics::MetricsHook,
     subscription::{Map, SubKind},
     Identifier,
 };
@@ -20,9 +21,18 @@ use tokio::sync::mpsc;
 /// normalised Barter types. Often used with
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) or
 /// [`OrderBooksL1`](crate::subscription::book::OrderBooksL1) streams.
+///
+/// Events with a `sequence` are validated by a [`SequenceValidator`], yielding a
+/// [`DataError::SequenceGap`] before the event that follows a gap. Channel consumers receive it
+/// via [`Streams::errors`](crate::streams::Streams::errors).
+///
+/// Every transformed event is reported to the installed [`MetricsHook`], if any.
 #[derive(Clone, Eq, PartialEq, Debug, Serialize)]
 pub struct StatelessTransformer<Exchange, Kind, Input> {
     instrument_map: Map<Instrument>,
+    sequences: SequenceValidator,
+    #[serde(skip)]
+    metrics: MetricsHook,
     phantom: PhantomData<(Exchange, Kind, Input)>,
 }
 
@@ -41,9 +51,32 @@ where
     ) -> Result<Self, DataError> {
         Ok(Self {
             instrument_map,
-            phantom: PhantomData::default(),
+            sequences: SequenceValidator::new(),
+            metrics: MetricsHook::default(),
+            phantom: PhantomData,
         })
     }
+
+    fn set_metrics(&mut self, metrics: MetricsHook) {
+        self.metrics = metrics;
+    }
+
+    fn unsubscribe(&mut self, subscription_ids: &[SubscriptionId]) {
+        self.instrument_map.remove(subscription_ids);
+    }
+
+    fn subscribe(&mut self, instrument_map: Map<Instrument>) -> Result<(), DataError> {
+        self.instrument_map.extend(instrument_map.0);
+        Ok(())
+    }
+}
+
+impl<Exchange, Kind, Input> StatelessTransformer<Exchange, Kind, Input> {
+    /// Mutable reference to the [`SequenceValidator`], used by exchange specific wrappers that
+    /// receive sequence information outside of the transformed events (eg/ heartbeats).
+    pub fn sequences_mut(&mut self) -> &mut SequenceValidator {
+        &mut self.sequences
+    }
 }
 
 impl<Exchange, Kind, Input> Transformer for StatelessTransformer<Exchange, Kind, Input>
@@ -66,9 +99,124 @@ where
         };
 
         // Find Instrument associated with Input and transform
-        match self.instrument_map.find(&subscription_id) {
-            Ok(instrument) => MarketIter::<Kind::Event>::from((Exchange::ID, instrument, input)).0,
-            Err(unidentifiable) => vec![Err(DataError::Socket(unidentifiable))],
+        let mut output = match self.instrument_map.find_ref(&subscription_id) {
+            Ok(instrument) => {
+                MarketIter::<Kind::Event>::from((Exchange::ID, instrument.clone(), input)).0
+            }
+            Err(unidentifiable) => {
+                let error = DataError::Socket(unidentifiable);
+                self.metrics.report_error(&error);
+                return vec![Err(error)];
+            }
+        };
+
+        // Surface any sequence gaps before the event that follows the gap, only re-allocating
+        // the output if a gap is found (rare)
+        let gaps = output
+            .iter()
+            .enumerate()
+            .filter_map(|(index, event)| {
+                let sequence = event.as_ref().ok().and_then(|event| event.sequence)?;
+                self.sequences
+                    .validate(&subscription_id, sequence)
+                    .err()
+                    .map(|gap| (index, gap))
+            })
+            .collect::<Vec<_>>();
+
+        if !gaps.is_empty() {
+            let mut gaps = gaps.into_iter().peekable();
+            output = output
+                .into_iter()
+                .enumerate()
+                .flat_map(|(index, event)| {
+                    let gap = gaps.next_if(|(gap_index, _)| *gap_index == index);
+                    gap.map(|(_, gap)| Err(gap)).into_iter().chain(Some(event))
+                })
+                .collect();
+        }
+
+        self.metrics.report(&subscription_id, &output);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+        subscription::trade::PublicTrades,
+    };
+    use barter_integration::model::InstrumentKind;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_stateless_transformer_sequence_gap() {
+        let instrument_map = Map(HashMap::from([(
+            SubscriptionId::from("@trade|BTCUSDT"),
+            Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+        )]));
+        let (ws_sink_tx, _ws_sink_rx) = mpsc::unbounded_channel();
+
+        let mut transformer = <StatelessTransformer<BinanceSpot, PublicTrades, BinanceTrade> as ExchangeTransformer<BinanceSpot, PublicTrades>>::new(
+            ws_sink_tx,
+            instrument_map,
+        )
+        .await
+        .unwrap();
+
+        let trade = |id: u64| {
+            serde_json::from_str::<BinanceTrade>(&format!(
+                r#"{{"s":"BTCUSDT","T":1649324825173,"t":{id},"p":"1.0","q":"1.0","m":true}}"#
+            ))
+            .unwrap()
+        };
+
+        struct TestCase {
+            input: BinanceTrade,
+            expected_gap: Option<(u64, u64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first trade sets the expected sequence
+                input: trade(100),
+                expected_gap: None,
+            },
+            TestCase {
+                // TC1: next trade follows on
+                input: trade(101),
+                expected_gap: None,
+            },
+            TestCase {
+                // TC2: missed trades yield a SequenceGap before the event
+                input: trade(105),
+                expected_gap: Some((102, 105)),
+            },
+            TestCase {
+                // TC3: trade following the gap follows on
+                input: trade(106),
+                expected_gap: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let sequence = test.input.id;
+            let mut actual = transformer.transform(test.input).into_iter();
+
+            if let Some(gap) = test.expected_gap {
+                match actual.next() {
+                    Some(Err(DataError::SequenceGap { expected, actual })) => {
+                        assert_eq!((expected, actual), gap, "TC{} failed", index)
+                    }
+                    other => panic!("TC{index} failed: expected SequenceGap, got {other:?}"),
+                }
+            }
+
+            let event = actual.next().unwrap().unwrap();
+            assert_eq!(event.sequence, Some(sequence), "TC{} failed", index);
+            assert!(actual.next().is_none(), "TC{} failed", index);
         }
     }
 }